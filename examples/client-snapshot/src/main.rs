@@ -180,6 +180,17 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                 }
                 *certificate_validation_pb = None;
             }
+            MithrilEvent::AggregatorRequestRetried {
+                aggregator_request_id: _,
+                aggregator_endpoint,
+                attempt,
+                reason,
+            } => {
+                println!(
+                    "Retrying request on aggregator '{aggregator_endpoint}' (attempt {attempt}): {reason}"
+                );
+            }
+            _ => {}
         }
     }
 }
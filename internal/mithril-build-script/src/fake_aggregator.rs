@@ -3,7 +3,7 @@ use std::collections::BTreeSet;
 use std::fmt::Write as _;
 use std::fs;
 use std::fs::File;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde_json;
 
@@ -31,8 +31,14 @@ pub struct FakeAggregatorData {
 
     csds_list: FileContent,
     individual_csds: BTreeMap<ArtifactId, FileContent>,
+
+    snapshot_archives: BTreeMap<ArtifactId, PathBuf>,
 }
 
+/// Name of the sub folder containing the gzip snapshot archives, named `{digest}.tar.gz`, that
+/// the fake aggregator can serve.
+pub const SNAPSHOT_ARCHIVES_FOLDER: &str = "snapshot-archives";
+
 impl FakeAggregatorData {
     pub fn load_from_folder(folder: &Path) -> Self {
         let mut data = FakeAggregatorData::default();
@@ -88,6 +94,11 @@ impl FakeAggregatorData {
             }
         }
 
+        let archives_folder = folder.join(SNAPSHOT_ARCHIVES_FOLDER);
+        if archives_folder.is_dir() {
+            data.snapshot_archives = Self::read_snapshot_archives_folder(&archives_folder);
+        }
+
         data
     }
 
@@ -122,6 +133,10 @@ impl FakeAggregatorData {
                     "proof_transaction_hashes",
                     BTreeSet::from_iter(self.ctx_proofs.keys().cloned()),
                 ),
+                generate_ids_array(
+                    "snapshot_archive_digests",
+                    BTreeSet::from_iter(self.snapshot_archives.keys().cloned()),
+                ),
             ],
             false,
         )
@@ -170,6 +185,7 @@ impl FakeAggregatorData {
                     BTreeSet::from_iter(self.ctx_proofs.keys().cloned()),
                 ),
                 generate_artifact_getter("ctx_proofs", self.ctx_proofs),
+                generate_binary_artifact_getter("snapshot_archives", self.snapshot_archives),
             ],
             true,
         )
@@ -206,6 +222,23 @@ impl FakeAggregatorData {
 
         BTreeMap::from_iter(res.unwrap())
     }
+
+    fn read_snapshot_archives_folder(folder: &Path) -> BTreeMap<ArtifactId, PathBuf> {
+        crate::list_files_in_folder(folder)
+            .filter_map(|entry| {
+                let filename = entry.file_name().to_string_lossy().to_string();
+                let digest = filename.strip_suffix(".tar.gz")?.to_string();
+                let path = fs::canonicalize(entry.path()).unwrap_or_else(|_| {
+                    panic!(
+                        "Could not canonicalize file path: {}",
+                        entry.path().display()
+                    )
+                });
+
+                Some((digest, path))
+            })
+            .collect()
+    }
 }
 
 pub fn extract_csd_epochs(individual_csds: &BTreeMap<ArtifactId, FileContent>) -> Vec<String> {
@@ -275,6 +308,39 @@ pub fn generate_artifact_getter(
     )
 }
 
+// pub(crate) fn $fun_name() -> BTreeMap<String, &'static [u8]>
+pub fn generate_binary_artifact_getter(
+    fun_name: &str,
+    source_files: BTreeMap<ArtifactId, PathBuf>,
+) -> String {
+    let mut artifacts_list = String::new();
+
+    for (artifact_id, file_path) in source_files {
+        write!(
+            artifacts_list,
+            r###"
+        (
+            "{}",
+            include_bytes!(r#"{}"#).as_slice()
+        ),"###,
+            artifact_id,
+            file_path.display()
+        )
+        .unwrap();
+    }
+
+    format!(
+        r###"pub(crate) fn {}() -> BTreeMap<String, &'static [u8]> {{
+    [{}
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_owned(), v))
+    .collect()
+}}"###,
+        fun_name, artifacts_list
+    )
+}
+
 /// pub(crate) fn $fun_name() -> &'static str
 pub fn generate_list_getter(fun_name: &str, source_json: FileContent) -> String {
     format!(
@@ -344,6 +410,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn generate_binary_artifact_getter_include_the_files_bytes() {
+        assert_eq!(
+            generate_binary_artifact_getter(
+                "snapshot_archives",
+                BTreeMap::from([("abc".to_string(), PathBuf::from("/data/abc.tar.gz"))])
+            ),
+            r###"pub(crate) fn snapshot_archives() -> BTreeMap<String, &'static [u8]> {
+    [
+        (
+            "abc",
+            include_bytes!(r#"/data/abc.tar.gz"#).as_slice()
+        ),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_owned(), v))
+    .collect()
+}"###
+        );
+    }
+
+    #[test]
+    fn read_only_gzip_archives_in_snapshot_archives_folder() {
+        let dir = get_temp_dir("read_snapshot_archives_folder");
+        fs::write(dir.join("abc.tar.gz"), "archive").unwrap();
+        fs::write(dir.join("def.tar.zst"), "archive").unwrap();
+
+        let archives = FakeAggregatorData::read_snapshot_archives_folder(&dir);
+
+        assert_eq!(
+            BTreeMap::from([(
+                "abc".to_string(),
+                fs::canonicalize(dir.join("abc.tar.gz")).unwrap()
+            )]),
+            archives
+        );
+    }
+
     #[test]
     fn assemble_code_with_btree_use() {
         assert_eq!(
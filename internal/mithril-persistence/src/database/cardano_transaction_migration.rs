@@ -119,5 +119,80 @@ delete from block_range_root;
 vacuum;
  "#,
         ),
+        // Migration 10
+        // Add `cardano_tx_content` table to store the contents of the transactions, only filled
+        // by the nodes that enable the extended mode.
+        SqlMigration::new(
+            10,
+            r#"
+create table cardano_tx_content (
+    transaction_hash    text      not null,
+    block_number        integer   not null,
+    content             json      not null,
+    primary key (transaction_hash)
+);
+
+create index cardano_tx_content_block_number_index on cardano_tx_content(block_number);
+"#,
+        ),
+    ]
+}
+
+/// Get all the migrations required by this version of the software for a PostgreSQL database.
+///
+/// The PostgreSQL backend came after the SQLite one: its first migration creates at once the
+/// schema reached by the SQLite [migrations][get_migrations] at the same version. The following
+/// migrations must be added to both lists with the same version.
+pub fn get_postgres_migrations() -> Vec<SqlMigration> {
+    vec![
+        // Migration 10
+        // Create the schema of the SQLite database at version 10.
+        SqlMigration::new(
+            10,
+            r#"
+create table cardano_tx (
+    transaction_hash        text      not null,
+    block_number            bigint    not null,
+    slot_number             bigint    not null,
+    block_hash              text      not null,
+    primary key (transaction_hash)
+);
+
+create index block_number_index on cardano_tx(block_number);
+create index block_number_transaction_hash_index on cardano_tx(block_number, transaction_hash);
+
+create table block_range_root (
+    start         bigint    not null,
+    "end"         bigint    not null,
+    merkle_root   text      not null,
+    primary key (start, "end")
+);
+
+create table cardano_tx_content (
+    transaction_hash    text      not null,
+    block_number        bigint    not null,
+    content             text      not null,
+    primary key (transaction_hash)
+);
+
+create index cardano_tx_content_block_number_index on cardano_tx_content(block_number);
+"#,
+        ),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn postgres_migrations_reach_the_version_of_the_sqlite_migrations() {
+        let last_version =
+            |migrations: Vec<SqlMigration>| migrations.iter().map(|m| m.version).max();
+
+        assert_eq!(
+            last_version(get_migrations()),
+            last_version(get_postgres_migrations())
+        );
+    }
+}
@@ -0,0 +1,527 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use slog::{debug, info, Logger};
+
+use mithril_common::crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory};
+use mithril_common::entities::{BlockNumber, BlockRange, CardanoTransaction};
+use mithril_common::StdResult;
+
+use crate::database::repository::CardanoTransactionRepository;
+
+/// A stored block range root that differs from the one computed with the stored transactions
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockRangeRootMismatch {
+    /// The block range
+    pub block_range: BlockRange,
+
+    /// The root stored in the database
+    pub stored_root: MKTreeNode,
+
+    /// The root computed with the transactions stored in the database
+    pub computed_root: MKTreeNode,
+}
+
+/// Result of a check done by a [CardanoTransactionsIntegrityChecker]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CardanoTransactionsIntegrityReport {
+    /// Number of block ranges checked
+    pub checked_block_ranges: usize,
+
+    /// Block ranges which stored root doesn't match their stored transactions
+    pub mismatches: Vec<BlockRangeRootMismatch>,
+
+    /// Block ranges with stored transactions but without a stored root, with the root computed
+    /// with their transactions
+    pub gaps: Vec<(BlockRange, MKTreeNode)>,
+
+    /// Block ranges with a stored root but without any stored transactions
+    pub orphans: Vec<BlockRange>,
+}
+
+impl CardanoTransactionsIntegrityReport {
+    /// Returns `true` if no inconsistency was found
+    pub fn is_valid(&self) -> bool {
+        self.mismatches.is_empty() && self.gaps.is_empty() && self.orphans.is_empty()
+    }
+}
+
+impl Display for CardanoTransactionsIntegrityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Checked block ranges: {}", self.checked_block_ranges)?;
+        writeln!(f, "Mismatches: {}", self.mismatches.len())?;
+        for mismatch in &self.mismatches {
+            writeln!(
+                f,
+                "  * {:?}: stored root '{}', computed root '{}'",
+                mismatch.block_range,
+                mismatch.stored_root.to_hex(),
+                mismatch.computed_root.to_hex()
+            )?;
+        }
+        writeln!(f, "Gaps: {}", self.gaps.len())?;
+        for (block_range, _) in &self.gaps {
+            writeln!(f, "  * {block_range:?}")?;
+        }
+        writeln!(f, "Orphans: {}", self.orphans.len())?;
+        for block_range in &self.orphans {
+            writeln!(f, "  * {block_range:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that the block range roots stored in a Cardano transactions database are the ones a
+/// fresh import of the stored transactions would produce.
+///
+/// Only the block ranges below the highest stored block range root are checked, the ones above
+/// are not computed yet by the importer.
+pub struct CardanoTransactionsIntegrityChecker {
+    repository: Arc<CardanoTransactionRepository>,
+    number_of_blocks_to_keep: Option<BlockNumber>,
+    is_extended_mode_enabled: bool,
+    logger: Logger,
+}
+
+impl CardanoTransactionsIntegrityChecker {
+    /// Create a new instance of the `CardanoTransactionsIntegrityChecker`.
+    pub fn new(repository: Arc<CardanoTransactionRepository>) -> Self {
+        Self {
+            repository,
+            number_of_blocks_to_keep: None,
+            is_extended_mode_enabled: false,
+            logger: Logger::root(slog::Discard, slog::o!()),
+        }
+    }
+
+    /// Set the number of blocks kept when the transactions are pruned.
+    ///
+    /// The block ranges that may have been pruned are not checked as their transactions are not
+    /// all stored anymore.
+    pub fn with_number_of_blocks_to_keep(
+        mut self,
+        number_of_blocks_to_keep: Option<BlockNumber>,
+    ) -> Self {
+        self.number_of_blocks_to_keep = number_of_blocks_to_keep;
+        self
+    }
+
+    /// Enable the extended mode: the block range roots are computed with leaves committing to
+    /// the transactions contents, as done by the importer in this mode.
+    pub fn with_extended_mode(mut self, is_extended_mode_enabled: bool) -> Self {
+        self.is_extended_mode_enabled = is_extended_mode_enabled;
+        self
+    }
+
+    /// Set the logger to be used by the checker.
+    pub fn with_logger(mut self, logger: Logger) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    /// Recompute the block range roots with the stored transactions and compare them to the
+    /// stored ones.
+    pub async fn check(&self) -> StdResult<CardanoTransactionsIntegrityReport> {
+        let stored_block_range_roots: BTreeMap<BlockNumber, MKTreeNode> = self
+            .repository
+            .get_all_block_range_root()?
+            .into_iter()
+            .map(|record| (record.range.start, record.merkle_root))
+            .collect();
+        let mut report = CardanoTransactionsIntegrityReport::default();
+
+        let Some(highest_block_range) = self.repository.retrieve_highest_block_range_root().await?
+        else {
+            info!(self.logger, "No block range roots stored, nothing to check");
+            return Ok(report);
+        };
+        let lowest_block_number = match self.number_of_blocks_to_keep {
+            // Same threshold than the pruning, it can only grow with the highest block range
+            Some(number_of_blocks_to_keep) => {
+                highest_block_range.range.start - number_of_blocks_to_keep
+            }
+            None => BlockNumber(0),
+        };
+        debug!(
+            self.logger, "Checking block range roots";
+            "start_block" => *lowest_block_number, "end_block" => *highest_block_range.range.end,
+        );
+
+        for block_range in BlockRange::all_block_ranges_in(
+            lowest_block_number..=(highest_block_range.range.end - 1),
+        ) {
+            let leaves = self.get_leaves_in_block_range(&block_range).await?;
+            let computed_root = if leaves.is_empty() {
+                None
+            } else {
+                Some(MKTree::<MKTreeStoreInMemory>::new(&leaves)?.compute_root()?)
+            };
+
+            match (
+                stored_block_range_roots.get(&block_range.start),
+                computed_root,
+            ) {
+                (Some(stored_root), Some(computed_root)) if stored_root != &computed_root => {
+                    report.mismatches.push(BlockRangeRootMismatch {
+                        block_range,
+                        stored_root: stored_root.clone(),
+                        computed_root,
+                    });
+                }
+                (None, Some(computed_root)) => report.gaps.push((block_range, computed_root)),
+                (Some(_), None) => report.orphans.push(block_range),
+                _ => {}
+            }
+            report.checked_block_ranges += 1;
+        }
+
+        info!(
+            self.logger, "Block range roots checked";
+            "checked_block_ranges" => report.checked_block_ranges,
+            "mismatches" => report.mismatches.len(),
+            "gaps" => report.gaps.len(),
+            "orphans" => report.orphans.len(),
+        );
+
+        Ok(report)
+    }
+
+    async fn get_leaves_in_block_range(
+        &self,
+        block_range: &BlockRange,
+    ) -> StdResult<Vec<MKTreeNode>> {
+        let leaves = if self.is_extended_mode_enabled {
+            self.repository
+                .get_extended_transactions_in_range_blocks(block_range.start..block_range.end)
+                .await?
+                .iter()
+                .map(Into::into)
+                .collect()
+        } else {
+            self.repository
+                .get_transactions_in_range_blocks(block_range.start..block_range.end)
+                .await?
+                .into_iter()
+                .map(|record| CardanoTransaction::from(record).into())
+                .collect()
+        };
+
+        Ok(leaves)
+    }
+
+    /// Fix the inconsistencies of the given report: the mismatching and missing roots are
+    /// replaced by the computed ones and the orphaned roots are removed.
+    pub async fn repair(&self, report: &CardanoTransactionsIntegrityReport) -> StdResult<()> {
+        let block_ranges_to_remove = report
+            .mismatches
+            .iter()
+            .map(|mismatch| mismatch.block_range.clone())
+            .chain(report.orphans.iter().cloned())
+            .collect();
+        let block_range_roots_to_insert = report
+            .mismatches
+            .iter()
+            .map(|mismatch| (mismatch.block_range.clone(), mismatch.computed_root.clone()))
+            .chain(report.gaps.iter().cloned())
+            .collect();
+
+        self.repository
+            .replace_block_range_roots(block_ranges_to_remove, block_range_roots_to_insert)
+            .await?;
+        info!(self.logger, "Block range roots repaired");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{
+        CardanoTransactionContent, ExtendedCardanoTransaction, SlotNumber,
+    };
+
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::DatabaseConnectionPool;
+
+    use super::*;
+
+    fn build_transactions(block_numbers: &[u64]) -> Vec<CardanoTransaction> {
+        block_numbers
+            .iter()
+            .map(|block_number| {
+                CardanoTransaction::new(
+                    format!("tx_hash-{block_number}"),
+                    BlockNumber(*block_number),
+                    SlotNumber(block_number * 100),
+                    format!("block_hash-{block_number}"),
+                )
+            })
+            .collect()
+    }
+
+    fn compute_root(transactions: &[CardanoTransaction]) -> MKTreeNode {
+        MKTree::<MKTreeStoreInMemory>::new(transactions)
+            .unwrap()
+            .compute_root()
+            .unwrap()
+    }
+
+    /// Repository with one transaction in each of the four first block ranges and their roots
+    async fn build_consistent_repository() -> Arc<CardanoTransactionRepository> {
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+        )));
+        let transactions = build_transactions(&[1, 16, 31, 46]);
+        repository
+            .store_transactions(transactions.clone())
+            .await
+            .unwrap();
+        repository
+            .create_block_range_roots(
+                transactions
+                    .iter()
+                    .map(|tx| {
+                        (
+                            BlockRange::from_block_number(tx.block_number),
+                            compute_root(std::slice::from_ref(tx)),
+                        )
+                    })
+                    .collect(),
+            )
+            .await
+            .unwrap();
+
+        repository
+    }
+
+    #[tokio::test]
+    async fn report_nothing_when_no_block_range_roots_are_stored() {
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+        )));
+        repository
+            .store_transactions(build_transactions(&[1, 16]))
+            .await
+            .unwrap();
+
+        let report = CardanoTransactionsIntegrityChecker::new(repository)
+            .check()
+            .await
+            .unwrap();
+
+        assert_eq!(CardanoTransactionsIntegrityReport::default(), report);
+    }
+
+    #[tokio::test]
+    async fn report_is_valid_when_roots_match_the_stored_transactions() {
+        let repository = build_consistent_repository().await;
+        // Transactions above the highest block range root are not computed yet
+        repository
+            .store_transactions(build_transactions(&[61]))
+            .await
+            .unwrap();
+
+        let report = CardanoTransactionsIntegrityChecker::new(repository)
+            .check()
+            .await
+            .unwrap();
+
+        assert!(report.is_valid(), "report should be valid: {report}");
+        assert_eq!(4, report.checked_block_ranges);
+    }
+
+    #[tokio::test]
+    async fn report_mismatches_gaps_and_orphans() {
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+        )));
+        repository
+            .store_transactions(build_transactions(&[16, 31, 46]))
+            .await
+            .unwrap();
+        repository
+            .create_block_range_roots(vec![
+                (
+                    BlockRange::from_block_number(BlockNumber(1)),
+                    MKTreeNode::from_hex("AAAA").unwrap(),
+                ),
+                (
+                    BlockRange::from_block_number(BlockNumber(16)),
+                    MKTreeNode::from_hex("BBBB").unwrap(),
+                ),
+                (
+                    BlockRange::from_block_number(BlockNumber(46)),
+                    compute_root(&build_transactions(&[46])),
+                ),
+            ])
+            .await
+            .unwrap();
+
+        let report = CardanoTransactionsIntegrityChecker::new(repository)
+            .check()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            CardanoTransactionsIntegrityReport {
+                checked_block_ranges: 4,
+                mismatches: vec![BlockRangeRootMismatch {
+                    block_range: BlockRange::from_block_number(BlockNumber(16)),
+                    stored_root: MKTreeNode::from_hex("BBBB").unwrap(),
+                    computed_root: compute_root(&build_transactions(&[16])),
+                }],
+                gaps: vec![(
+                    BlockRange::from_block_number(BlockNumber(31)),
+                    compute_root(&build_transactions(&[31]))
+                )],
+                orphans: vec![BlockRange::from_block_number(BlockNumber(1))],
+            },
+            report
+        );
+    }
+
+    #[tokio::test]
+    async fn block_ranges_that_may_have_been_pruned_are_not_checked() {
+        let repository = build_consistent_repository().await;
+        repository.prune_transaction(BlockNumber(20)).await.unwrap();
+
+        let checker = CardanoTransactionsIntegrityChecker::new(repository.clone());
+        let report = checker.check().await.unwrap();
+        assert_eq!(
+            vec![
+                BlockRange::from_block_number(BlockNumber(1)),
+                BlockRange::from_block_number(BlockNumber(16))
+            ],
+            report.orphans
+        );
+
+        let report = checker
+            .with_number_of_blocks_to_keep(Some(BlockNumber(20)))
+            .check()
+            .await
+            .unwrap();
+        assert!(report.is_valid(), "report should be valid: {report}");
+        assert_eq!(2, report.checked_block_ranges);
+    }
+
+    #[tokio::test]
+    async fn repair_fix_all_the_reported_inconsistencies() {
+        let repository = build_consistent_repository().await;
+        repository
+            .replace_block_range_roots(
+                vec![
+                    BlockRange::from_block_number(BlockNumber(16)),
+                    BlockRange::from_block_number(BlockNumber(31)),
+                ],
+                vec![
+                    (
+                        BlockRange::from_block_number(BlockNumber(16)),
+                        MKTreeNode::from_hex("AAAA").unwrap(),
+                    ),
+                    (
+                        BlockRange::from_block_number(BlockNumber(61)),
+                        MKTreeNode::from_hex("BBBB").unwrap(),
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+        let checker = CardanoTransactionsIntegrityChecker::new(repository.clone());
+        let expected_block_range_roots = build_consistent_repository()
+            .await
+            .get_all_block_range_root()
+            .unwrap();
+
+        let report = checker.check().await.unwrap();
+        assert_eq!(
+            (1, 1, 1),
+            (
+                report.mismatches.len(),
+                report.gaps.len(),
+                report.orphans.len()
+            )
+        );
+
+        checker.repair(&report).await.unwrap();
+
+        let report = checker.check().await.unwrap();
+        assert!(report.is_valid(), "report should be valid: {report}");
+        assert_eq!(
+            expected_block_range_roots,
+            repository.get_all_block_range_root().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn check_and_repair_with_extended_mode_use_the_leaves_committing_to_the_contents() {
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+        )));
+        let extended_transactions: Vec<ExtendedCardanoTransaction> =
+            build_transactions(&[1, 16, 31])
+                .into_iter()
+                .map(|transaction| {
+                    let metadata_labels = vec![*transaction.block_number];
+                    ExtendedCardanoTransaction::new(
+                        transaction,
+                        CardanoTransactionContent {
+                            metadata_labels,
+                            ..CardanoTransactionContent::default()
+                        },
+                    )
+                })
+                .collect();
+        repository
+            .store_extended_transactions(extended_transactions.clone())
+            .await
+            .unwrap();
+        let extended_root = |transaction: &ExtendedCardanoTransaction| {
+            MKTree::<MKTreeStoreInMemory>::new(&[MKTreeNode::from(transaction)])
+                .unwrap()
+                .compute_root()
+                .unwrap()
+        };
+        repository
+            .create_block_range_roots(vec![
+                (
+                    BlockRange::from_block_number(BlockNumber(1)),
+                    extended_root(&extended_transactions[0]),
+                ),
+                (
+                    BlockRange::from_block_number(BlockNumber(16)),
+                    MKTreeNode::from_hex("AAAA").unwrap(),
+                ),
+                (
+                    BlockRange::from_block_number(BlockNumber(31)),
+                    extended_root(&extended_transactions[2]),
+                ),
+            ])
+            .await
+            .unwrap();
+        let checker =
+            CardanoTransactionsIntegrityChecker::new(repository.clone()).with_extended_mode(true);
+
+        let report = checker.check().await.unwrap();
+        assert_eq!(
+            vec![BlockRangeRootMismatch {
+                block_range: BlockRange::from_block_number(BlockNumber(16)),
+                stored_root: MKTreeNode::from_hex("AAAA").unwrap(),
+                computed_root: extended_root(&extended_transactions[1]),
+            }],
+            report.mismatches
+        );
+        assert_eq!(
+            (0, 0),
+            (report.gaps.len(), report.orphans.len()),
+            "report should only contain the mismatch: {report}"
+        );
+
+        checker.repair(&report).await.unwrap();
+
+        let report = checker.check().await.unwrap();
+        assert!(report.is_valid(), "report should be valid: {report}");
+        assert_eq!(3, report.checked_block_ranges);
+    }
+}
@@ -1,13 +1,15 @@
 use anyhow::anyhow;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use mithril_common::StdResult;
-use sqlite::{Row, Value};
+use sqlite::Value;
 use std::{
     cmp::Ordering,
     fmt::{Debug, Display},
 };
 
-use crate::sqlite::{HydrationError, Projection, Query, SourceAlias, SqLiteEntity, WhereCondition};
+use crate::sqlite::{
+    HydrationError, Projection, Query, Row, SourceAlias, SqLiteEntity, WhereCondition,
+};
 
 use super::DbVersion;
 
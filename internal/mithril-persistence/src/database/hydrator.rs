@@ -6,15 +6,15 @@ use mithril_common::entities::{
     BlockNumber, CardanoDbBeacon, Epoch, SignedEntityType, SignedEntityTypeDiscriminants,
 };
 
-use crate::sqlite::HydrationError;
+use crate::sqlite::{HydrationError, Row, RowIndex};
 
 /// Helper struct to hydrate common data.
 pub struct Hydrator;
 
 impl Hydrator {
     /// Read a signed entity beacon column from the database
-    pub fn read_signed_entity_beacon_column<U: sqlite::RowIndex + Clone>(
-        row: &sqlite::Row,
+    pub fn read_signed_entity_beacon_column<U: RowIndex + Clone>(
+        row: &Row,
         column_index: U,
     ) -> String {
         // We need to check first that the cell can be read as a string first
@@ -62,6 +62,14 @@ impl Hydrator {
                 })?;
                 SignedEntityType::CardanoStakeDistribution(epoch)
             }
+            SignedEntityTypeDiscriminants::CardanoDRepStakeDistribution => {
+                let epoch: Epoch = serde_json::from_str(beacon_str).map_err(|e| {
+                    HydrationError::InvalidData(format!(
+                        "Invalid Epoch JSON representation '{beacon_str}. Error: {e}'."
+                    ))
+                })?;
+                SignedEntityType::CardanoDRepStakeDistribution(epoch)
+            }
             SignedEntityTypeDiscriminants::CardanoImmutableFilesFull => {
                 let beacon: CardanoDbBeacon = serde_json::from_str(beacon_str).map_err(|e| {
                     HydrationError::InvalidData(format!(
@@ -2,6 +2,7 @@
 //! This module contains queries and entities shared between all application types.
 
 pub mod cardano_transaction_migration;
+mod cardano_transactions_integrity_checker;
 mod db_version;
 mod hydrator;
 pub(crate) mod query;
@@ -9,6 +10,7 @@ pub mod record;
 pub mod repository;
 mod version_checker;
 
+pub use cardano_transactions_integrity_checker::*;
 pub use db_version::*;
 pub use hydrator::Hydrator;
 pub use version_checker::{DatabaseVersionChecker, SqlMigration};
@@ -18,18 +20,31 @@ pub type DbVersion = i64;
 
 #[cfg(test)]
 pub mod test_helper {
-    use sqlite::ConnectionThreadSafe;
-
     use mithril_common::StdResult;
 
-    use crate::sqlite::{ConnectionBuilder, ConnectionOptions};
+    use crate::sqlite::{ConnectionBuilder, ConnectionOptions, DatabaseConnection};
+
+    /// Database without foreign key support with cardano db migrations applied.
+    ///
+    /// It's an in-memory SQLite database, or a new schema of the PostgreSQL database given by
+    /// the `MITHRIL_TEST_POSTGRES_URL` environment variable when the `postgres` feature is enabled.
+    pub fn cardano_tx_db_connection() -> StdResult<DatabaseConnection> {
+        #[cfg(feature = "postgres")]
+        if let Some(url) = crate::postgres::test_utils::test_database_url() {
+            let connection = crate::postgres::PostgresConnectionBuilder::open(&url)
+                .with_schema(&crate::postgres::test_utils::unique_test_schema())
+                .with_options(&[ConnectionOptions::ForceDisableForeignKeys])
+                .with_migrations(
+                    crate::database::cardano_transaction_migration::get_postgres_migrations(),
+                )
+                .build()?;
+            return Ok(connection.into());
+        }
 
-    /// In-memory sqlite database without foreign key support with cardano db migrations applied
-    pub fn cardano_tx_db_connection() -> StdResult<ConnectionThreadSafe> {
         let connection = ConnectionBuilder::open_memory()
             .with_options(&[ConnectionOptions::ForceDisableForeignKeys])
             .with_migrations(crate::database::cardano_transaction_migration::get_migrations())
             .build()?;
-        Ok(connection)
+        Ok(connection.into())
     }
 }
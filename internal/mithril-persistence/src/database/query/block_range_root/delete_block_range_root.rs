@@ -42,6 +42,21 @@ impl DeleteBlockRangeRootQuery {
             condition: WhereCondition::new("start >= ?*", vec![threshold]),
         })
     }
+
+    pub fn by_block_ranges(block_ranges: Vec<BlockRange>) -> StdResult<Self> {
+        let starts = block_ranges
+            .into_iter()
+            .map(|block_range| -> StdResult<Value> {
+                Ok(Value::Integer(block_range.start.try_into().with_context(
+                    || format!("Failed to convert block range start `{block_range:?}` to i64"),
+                )?))
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(Self {
+            condition: WhereCondition::where_in("start", starts),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -117,6 +132,26 @@ mod tests {
         parameterized_test_prune_block_range(BlockRange::LENGTH * 2 - 1, 3);
     }
 
+    #[test]
+    fn test_delete_by_block_ranges() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let dataset = block_range_root_dataset();
+        insert_block_range_roots(&connection, dataset.clone());
+
+        let query = DeleteBlockRangeRootQuery::by_block_ranges(vec![
+            dataset[0].range.clone(),
+            dataset[2].range.clone(),
+        ])
+        .unwrap();
+        let cursor = connection.fetch(query).unwrap();
+        assert_eq!(2, cursor.count());
+
+        let remaining_records: Vec<BlockRangeRootRecord> = connection
+            .fetch_collect(GetBlockRangeRootQuery::all())
+            .unwrap();
+        assert_eq!(vec![dataset[1].clone()], remaining_records);
+    }
+
     fn parameterized_test_prune_block_range(
         block_threshold: BlockNumber,
         delete_record_number: usize,
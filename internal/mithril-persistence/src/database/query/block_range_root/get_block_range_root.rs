@@ -28,7 +28,10 @@ impl GetBlockRangeRootQuery {
 
     pub fn highest() -> Self {
         Self {
-            condition: WhereCondition::new("end = (select max(end) from block_range_root)", vec![]),
+            condition: WhereCondition::new(
+                r#""end" = (select max("end") from block_range_root)"#,
+                vec![],
+            ),
         }
     }
 }
@@ -44,7 +47,9 @@ impl Query for GetBlockRangeRootQuery {
         let aliases = SourceAlias::new(&[("{:block_range_root:}", "block_range_root")]);
         let projection = Self::Entity::get_projection().expand(aliases);
 
-        format!("select {projection} from block_range_root where {condition} order by start, end")
+        format!(
+            r#"select {projection} from block_range_root where {condition} order by start, "end""#
+        )
     }
 }
 
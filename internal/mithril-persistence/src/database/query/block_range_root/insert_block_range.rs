@@ -15,7 +15,8 @@ pub struct InsertBlockRangeRootQuery {
 impl InsertBlockRangeRootQuery {
     /// Query that insert multiples records.
     pub fn insert_many(block_range_records: Vec<BlockRangeRootRecord>) -> StdResult<Self> {
-        let columns = "(start, end, merkle_root)";
+        // `end` is quoted since it is a reserved word in PostgreSQL
+        let columns = r#"(start, "end", merkle_root)"#;
         let values_columns: Vec<&str> = repeat("(?*, ?*, ?*)")
             .take(block_range_records.len())
             .collect();
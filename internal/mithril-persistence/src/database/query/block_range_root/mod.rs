@@ -9,12 +9,12 @@ pub use insert_block_range::*;
 #[cfg(test)]
 mod test_helper {
     use crate::database::record::BlockRangeRootRecord;
-    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+    use crate::sqlite::{ConnectionExtensions, DatabaseConnection};
 
     use super::*;
 
     pub fn insert_block_range_roots(
-        connection: &SqliteConnection,
+        connection: &DatabaseConnection,
         records: Vec<BlockRangeRootRecord>,
     ) {
         connection
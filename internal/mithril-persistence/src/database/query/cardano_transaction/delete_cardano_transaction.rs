@@ -55,13 +55,16 @@ impl DeleteCardanoTransactionQuery {
 mod tests {
     use crate::database::query::{GetCardanoTransactionQuery, InsertCardanoTransactionQuery};
     use crate::database::test_helper::cardano_tx_db_connection;
-    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+    use crate::sqlite::{ConnectionExtensions, DatabaseConnection};
 
     use super::*;
 
     use mithril_common::entities::SlotNumber;
 
-    fn insert_transactions(connection: &SqliteConnection, records: Vec<CardanoTransactionRecord>) {
+    fn insert_transactions(
+        connection: &DatabaseConnection,
+        records: Vec<CardanoTransactionRecord>,
+    ) {
         connection
             .fetch_first(InsertCardanoTransactionQuery::insert_many(records).unwrap())
             .unwrap();
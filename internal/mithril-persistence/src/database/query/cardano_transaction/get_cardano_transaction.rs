@@ -110,11 +110,14 @@ impl Query for GetCardanoTransactionQuery {
 mod tests {
     use crate::database::query::InsertCardanoTransactionQuery;
     use crate::database::test_helper::cardano_tx_db_connection;
-    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+    use crate::sqlite::{ConnectionExtensions, DatabaseConnection};
 
     use super::*;
 
-    fn insert_transactions(connection: &SqliteConnection, records: Vec<CardanoTransactionRecord>) {
+    fn insert_transactions(
+        connection: &DatabaseConnection,
+        records: Vec<CardanoTransactionRecord>,
+    ) {
         connection
             .fetch_first(InsertCardanoTransactionQuery::insert_many(records).unwrap())
             .unwrap();
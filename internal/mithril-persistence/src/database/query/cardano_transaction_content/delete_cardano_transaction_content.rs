@@ -0,0 +1,126 @@
+use anyhow::Context;
+use sqlite::Value;
+
+use mithril_common::entities::BlockNumber;
+use mithril_common::StdResult;
+
+use crate::database::record::CardanoTransactionContentRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete [CardanoTransactionContentRecord] from the sqlite database
+pub struct DeleteCardanoTransactionContentQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteCardanoTransactionContentQuery {
+    type Entity = CardanoTransactionContentRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:cardano_tx_content:}",
+            "cardano_tx_content",
+        )]));
+
+        format!("delete from cardano_tx_content where {condition} returning {projection}")
+    }
+}
+
+impl DeleteCardanoTransactionContentQuery {
+    pub fn below_block_number_threshold(block_number_threshold: BlockNumber) -> StdResult<Self> {
+        let threshold = Value::Integer(block_number_threshold.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("block_number < ?*", vec![threshold]),
+        })
+    }
+
+    pub fn above_block_number_threshold(block_number_threshold: BlockNumber) -> StdResult<Self> {
+        let threshold = Value::Integer(block_number_threshold.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("block_number > ?*", vec![threshold]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::CardanoTransactionContent;
+
+    use crate::database::query::{
+        GetCardanoTransactionContentQuery, InsertCardanoTransactionContentQuery,
+    };
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::{ConnectionExtensions, DatabaseConnection};
+
+    use super::*;
+
+    fn insert_test_contents(connection: &DatabaseConnection) {
+        let records = (10..=12)
+            .map(|block_number| CardanoTransactionContentRecord {
+                transaction_hash: format!("tx-hash-{block_number}"),
+                block_number: BlockNumber(block_number),
+                content: CardanoTransactionContent::default(),
+            })
+            .collect();
+        connection
+            .fetch_first(InsertCardanoTransactionContentQuery::insert_many(records).unwrap())
+            .unwrap();
+    }
+
+    fn stored_transactions_hashes(connection: &DatabaseConnection) -> Vec<String> {
+        connection
+            .fetch(GetCardanoTransactionContentQuery::all())
+            .unwrap()
+            .map(|record| record.transaction_hash)
+            .collect()
+    }
+
+    #[test]
+    fn prune_contents_below_the_given_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_test_contents(&connection);
+
+        let cursor = connection
+            .fetch(
+                DeleteCardanoTransactionContentQuery::below_block_number_threshold(BlockNumber(11))
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(1, cursor.count());
+
+        assert_eq!(
+            vec!["tx-hash-11", "tx-hash-12"],
+            stored_transactions_hashes(&connection)
+        );
+    }
+
+    #[test]
+    fn prune_contents_above_the_given_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_test_contents(&connection);
+
+        let cursor = connection
+            .fetch(
+                DeleteCardanoTransactionContentQuery::above_block_number_threshold(BlockNumber(11))
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(1, cursor.count());
+
+        assert_eq!(
+            vec!["tx-hash-10", "tx-hash-11"],
+            stored_transactions_hashes(&connection)
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::ops::Range;
+
+use sqlite::Value;
+
+use mithril_common::entities::{BlockNumber, TransactionHash};
+
+use crate::database::record::CardanoTransactionContentRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve [CardanoTransactionContentRecord] from the sqlite database.
+pub struct GetCardanoTransactionContentQuery {
+    condition: WhereCondition,
+}
+
+impl GetCardanoTransactionContentQuery {
+    #[cfg(test)]
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn by_transaction_hashes(transactions_hashes: Vec<TransactionHash>) -> Self {
+        let hashes_values = transactions_hashes.into_iter().map(Value::String).collect();
+
+        Self {
+            condition: WhereCondition::where_in("transaction_hash", hashes_values),
+        }
+    }
+
+    pub fn between_blocks(range: Range<BlockNumber>) -> Self {
+        let condition = WhereCondition::new(
+            "block_number >= ?*",
+            vec![Value::Integer(*range.start as i64)],
+        )
+        .and_where(WhereCondition::new(
+            "block_number < ?*",
+            vec![Value::Integer(*range.end as i64)],
+        ));
+
+        Self { condition }
+    }
+}
+
+impl Query for GetCardanoTransactionContentQuery {
+    type Entity = CardanoTransactionContentRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:cardano_tx_content:}", "cardano_tx_content")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from cardano_tx_content where {condition} order by block_number, transaction_hash"
+        )
+    }
+}
@@ -0,0 +1,126 @@
+use std::iter::repeat;
+
+use sqlite::Value;
+
+use mithril_common::StdResult;
+
+use crate::database::record::CardanoTransactionContentRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to insert [CardanoTransactionContentRecord] in the sqlite database
+pub struct InsertCardanoTransactionContentQuery {
+    condition: WhereCondition,
+}
+
+impl InsertCardanoTransactionContentQuery {
+    /// Query that insert multiples records.
+    pub fn insert_many(records: Vec<CardanoTransactionContentRecord>) -> StdResult<Self> {
+        let columns = "(transaction_hash, block_number, content)";
+        let values_columns: Vec<&str> = repeat("(?*, ?*, ?*)").take(records.len()).collect();
+
+        let values: StdResult<Vec<Value>> =
+            records.into_iter().try_fold(vec![], |mut vec, record| {
+                vec.append(&mut vec![
+                    Value::String(record.transaction_hash),
+                    Value::Integer(record.block_number.try_into()?),
+                    Value::String(serde_json::to_string(&record.content)?),
+                ]);
+                Ok(vec)
+            });
+        let condition = WhereCondition::new(
+            format!("{columns} values {}", values_columns.join(", ")).as_str(),
+            values?,
+        );
+
+        Ok(Self { condition })
+    }
+}
+
+impl Query for InsertCardanoTransactionContentQuery {
+    type Entity = CardanoTransactionContentRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:cardano_tx_content:}", "cardano_tx_content")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("insert or ignore into cardano_tx_content {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{BlockNumber, CardanoTransactionContent};
+
+    use crate::database::query::GetCardanoTransactionContentQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    fn content_record(
+        transaction_hash: &str,
+        block_number: u64,
+    ) -> CardanoTransactionContentRecord {
+        CardanoTransactionContentRecord {
+            transaction_hash: transaction_hash.to_string(),
+            block_number: BlockNumber(block_number),
+            content: CardanoTransactionContent {
+                metadata_labels: vec![block_number],
+                ..CardanoTransactionContent::default()
+            },
+        }
+    }
+
+    #[test]
+    fn insert_and_read_back_transactions_contents() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let records = vec![
+            content_record("tx-hash-1", 10),
+            content_record("tx-hash-2", 11),
+        ];
+
+        let inserted_records: Vec<CardanoTransactionContentRecord> = connection
+            .fetch_collect(
+                InsertCardanoTransactionContentQuery::insert_many(records.clone()).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(records, inserted_records);
+
+        let stored_records: Vec<CardanoTransactionContentRecord> = connection
+            .fetch_collect(GetCardanoTransactionContentQuery::by_transaction_hashes(
+                vec!["tx-hash-1".to_string(), "tx-hash-2".to_string()],
+            ))
+            .unwrap();
+        assert_eq!(records, stored_records);
+    }
+
+    #[test]
+    fn insert_ignore_already_stored_transactions_contents() {
+        let connection = cardano_tx_db_connection().unwrap();
+        connection
+            .fetch_first(
+                InsertCardanoTransactionContentQuery::insert_many(vec![content_record(
+                    "tx-hash-1",
+                    10,
+                )])
+                .unwrap(),
+            )
+            .unwrap();
+
+        let inserted_records: Vec<CardanoTransactionContentRecord> = connection
+            .fetch_collect(
+                InsertCardanoTransactionContentQuery::insert_many(vec![
+                    content_record("tx-hash-1", 10),
+                    content_record("tx-hash-2", 11),
+                ])
+                .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(vec![content_record("tx-hash-2", 11)], inserted_records);
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_cardano_transaction_content;
+mod get_cardano_transaction_content;
+mod insert_cardano_transaction_content;
+
+pub use delete_cardano_transaction_content::*;
+pub use get_cardano_transaction_content::*;
+pub use insert_cardano_transaction_content::*;
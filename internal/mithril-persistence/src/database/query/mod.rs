@@ -1,6 +1,8 @@
 //! Shared database queries
 mod block_range_root;
 mod cardano_transaction;
+mod cardano_transaction_content;
 
 pub use block_range_root::*;
 pub use cardano_transaction::*;
+pub use cardano_transaction_content::*;
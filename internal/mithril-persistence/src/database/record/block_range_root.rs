@@ -1,10 +1,8 @@
-use sqlite::Row;
-
 use mithril_common::crypto_helper::MKTreeNode;
 use mithril_common::entities::{BlockNumber, BlockRange};
 
 use crate::database::Hydrator;
-use crate::sqlite::{HydrationError, Projection, SqLiteEntity};
+use crate::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 /// Block range root record is the representation of block range with its merkle root precomputed.
 #[derive(Debug, PartialEq, Clone)]
@@ -69,15 +67,19 @@ impl SqLiteEntity for BlockRangeRootRecord {
 
 #[cfg(test)]
 mod tests {
-    use sqlite::Connection;
+    use sqlite::Value;
 
     use super::*;
 
     fn select_block_range_from_db(start: BlockNumber, end: BlockNumber, merkle_root: &str) -> Row {
-        let conn = Connection::open(":memory:").unwrap();
-        let query = format!("SELECT {start}, {end}, '{merkle_root}'");
-        let mut statement = conn.prepare(query).unwrap();
-        statement.iter().next().unwrap().unwrap()
+        Row::new(
+            Row::column_mapping(&["start", "end", "merkle_root"]),
+            vec![
+                Value::Integer(*start as i64),
+                Value::Integer(*end as i64),
+                Value::String(merkle_root.to_string()),
+            ],
+        )
     }
 
     #[test]
@@ -1,11 +1,10 @@
-use sqlite::Row;
-
 use mithril_common::entities::{
-    BlockHash, BlockNumber, CardanoTransaction, SlotNumber, TransactionHash,
+    BlockHash, BlockNumber, CardanoTransaction, ExtendedCardanoTransaction, SlotNumber,
+    TransactionHash,
 };
 
 use crate::database::Hydrator;
-use crate::sqlite::{HydrationError, Projection, SqLiteEntity};
+use crate::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 /// Cardano Transaction record is the representation of a cardano transaction.
 #[derive(Debug, PartialEq, Clone)]
@@ -51,6 +50,12 @@ impl From<CardanoTransaction> for CardanoTransactionRecord {
     }
 }
 
+impl From<ExtendedCardanoTransaction> for CardanoTransactionRecord {
+    fn from(other: ExtendedCardanoTransaction) -> Self {
+        other.transaction.into()
+    }
+}
+
 impl From<CardanoTransactionRecord> for CardanoTransaction {
     fn from(other: CardanoTransactionRecord) -> CardanoTransaction {
         CardanoTransaction {
@@ -0,0 +1,65 @@
+use mithril_common::entities::{
+    BlockNumber, CardanoTransactionContent, ExtendedCardanoTransaction, TransactionHash,
+};
+
+use crate::database::Hydrator;
+use crate::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
+
+/// Cardano Transaction Content record is the representation of the contents of a cardano
+/// transaction, only stored by the nodes that enable the extended mode.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CardanoTransactionContentRecord {
+    /// Unique hash of the transaction
+    pub transaction_hash: TransactionHash,
+
+    /// Block number of the transaction
+    pub block_number: BlockNumber,
+
+    /// Contents of the transaction
+    pub content: CardanoTransactionContent,
+}
+
+impl From<ExtendedCardanoTransaction> for CardanoTransactionContentRecord {
+    fn from(other: ExtendedCardanoTransaction) -> Self {
+        Self {
+            transaction_hash: other.transaction.transaction_hash,
+            block_number: other.transaction.block_number,
+            content: other.content,
+        }
+    }
+}
+
+impl SqLiteEntity for CardanoTransactionContentRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let transaction_hash = row.read::<&str, _>(0);
+        let block_number =
+            Hydrator::try_to_u64("cardano_tx_content.block_number", row.read::<i64, _>(1))?;
+        let content_string = row.read::<&str, _>(2);
+        let content = serde_json::from_str(content_string).map_err(|e| {
+            HydrationError::InvalidData(format!(
+                "Could not turn string '{content_string}' to CardanoTransactionContent. Error: {e}"
+            ))
+        })?;
+
+        Ok(Self {
+            transaction_hash: transaction_hash.to_string(),
+            block_number: BlockNumber(block_number),
+            content,
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            (
+                "transaction_hash",
+                "{:cardano_tx_content:}.transaction_hash",
+                "text",
+            ),
+            ("block_number", "{:cardano_tx_content:}.block_number", "int"),
+            ("content", "{:cardano_tx_content:}.content", "text"),
+        ])
+    }
+}
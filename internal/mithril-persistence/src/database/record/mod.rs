@@ -2,6 +2,8 @@
 
 mod block_range_root;
 mod cardano_transaction;
+mod cardano_transaction_content;
 
 pub use block_range_root::*;
 pub use cardano_transaction::*;
+pub use cardano_transaction_content::*;
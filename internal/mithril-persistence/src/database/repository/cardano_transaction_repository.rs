@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -6,29 +7,33 @@ use async_trait::async_trait;
 
 use mithril_common::crypto_helper::{MKTreeNode, MKTreeStorer};
 use mithril_common::entities::{
-    BlockHash, BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber, TransactionHash,
+    BlockHash, BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionContent, ChainPoint,
+    ExtendedCardanoTransaction, SlotNumber, TransactionHash,
 };
 use mithril_common::signable_builder::BlockRangeRootRetriever;
 use mithril_common::StdResult;
 
 use crate::database::query::{
-    DeleteBlockRangeRootQuery, DeleteCardanoTransactionQuery, GetBlockRangeRootQuery,
-    GetCardanoTransactionQuery, InsertBlockRangeRootQuery, InsertCardanoTransactionQuery,
+    DeleteBlockRangeRootQuery, DeleteCardanoTransactionContentQuery, DeleteCardanoTransactionQuery,
+    GetBlockRangeRootQuery, GetCardanoTransactionContentQuery, GetCardanoTransactionQuery,
+    InsertBlockRangeRootQuery, InsertCardanoTransactionContentQuery, InsertCardanoTransactionQuery,
 };
-use crate::database::record::{BlockRangeRootRecord, CardanoTransactionRecord};
-use crate::sqlite::{ConnectionExtensions, SqliteConnection, SqliteConnectionPool};
+use crate::database::record::{
+    BlockRangeRootRecord, CardanoTransactionContentRecord, CardanoTransactionRecord,
+};
+use crate::sqlite::{ConnectionExtensions, DatabaseConnection, DatabaseConnectionPool};
 
 /// ## Cardano transaction repository
 ///
 /// This is a business oriented layer to perform actions on the database through
 /// queries.
 pub struct CardanoTransactionRepository {
-    connection_pool: Arc<SqliteConnectionPool>,
+    connection_pool: Arc<DatabaseConnectionPool>,
 }
 
 impl CardanoTransactionRepository {
     /// Instantiate service
-    pub fn new(connection_pool: Arc<SqliteConnectionPool>) -> Self {
+    pub fn new(connection_pool: Arc<DatabaseConnectionPool>) -> Self {
         Self { connection_pool }
     }
 
@@ -93,7 +98,7 @@ impl CardanoTransactionRepository {
     async fn create_transactions_with_connection<T: Into<CardanoTransactionRecord>>(
         &self,
         transactions: Vec<T>,
-        connection: &SqliteConnection,
+        connection: &DatabaseConnection,
     ) -> StdResult<Vec<CardanoTransactionRecord>> {
         let records: Vec<CardanoTransactionRecord> =
             transactions.into_iter().map(|tx| tx.into()).collect();
@@ -215,6 +220,121 @@ impl CardanoTransactionRepository {
         Ok(())
     }
 
+    /// Store the given extended transactions, with their contents, in the database.
+    ///
+    /// The storage is done in chunks to avoid exceeding sqlite binding limitations.
+    pub async fn store_extended_transactions(
+        &self,
+        transactions: Vec<ExtendedCardanoTransaction>,
+    ) -> StdResult<()> {
+        const DB_TRANSACTION_SIZE: usize = 100000;
+        for transactions_in_db_transaction_chunk in transactions.chunks(DB_TRANSACTION_SIZE) {
+            let connection = self.connection_pool.connection()?;
+            let transaction = connection.begin_transaction()?;
+
+            // Chunk transactions to avoid an error when we exceed sqlite binding limitations
+            for transactions_in_chunk in transactions_in_db_transaction_chunk.chunks(100) {
+                self.create_transactions_with_connection(
+                    transactions_in_chunk.to_vec(),
+                    &connection,
+                )
+                .await
+                .with_context(|| "CardanoTransactionRepository can not store transactions")?;
+
+                let content_records: Vec<CardanoTransactionContentRecord> = transactions_in_chunk
+                    .iter()
+                    .cloned()
+                    .map(Into::into)
+                    .collect();
+                connection
+                    .fetch_first(InsertCardanoTransactionContentQuery::insert_many(
+                        content_records,
+                    )?)
+                    .with_context(|| {
+                        "CardanoTransactionRepository can not store transactions contents"
+                    })?;
+            }
+
+            transaction.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Get the [CardanoTransactionContentRecord] for the given transaction hashes.
+    ///
+    /// Only the transactions stored with [Self::store_extended_transactions] have a content.
+    pub async fn get_transactions_contents_by_hashes<T: Into<TransactionHash>>(
+        &self,
+        hashes: Vec<T>,
+    ) -> StdResult<Vec<CardanoTransactionContentRecord>> {
+        let query = GetCardanoTransactionContentQuery::by_transaction_hashes(
+            hashes.into_iter().map(Into::into).collect(),
+        );
+        self.connection_pool.connection()?.fetch_collect(query)
+    }
+
+    /// Return the transactions, with their contents, where block number is in the given range.
+    ///
+    /// Fails if one of the transactions has no content, ie if it was not stored with
+    /// [Self::store_extended_transactions].
+    pub async fn get_extended_transactions_in_range_blocks(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<ExtendedCardanoTransaction>> {
+        let connection = self.connection_pool.connection()?;
+        let transactions =
+            connection.fetch_collect(GetCardanoTransactionQuery::between_blocks(range.clone()))?;
+        let contents =
+            connection.fetch_collect(GetCardanoTransactionContentQuery::between_blocks(range))?;
+
+        Self::join_transactions_with_contents(transactions, contents)
+    }
+
+    /// Get the transactions, with their contents, for the given block ranges.
+    ///
+    /// Fails if one of the transactions has no content, ie if it was not stored with
+    /// [Self::store_extended_transactions].
+    pub async fn get_extended_transaction_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<ExtendedCardanoTransaction>> {
+        let mut transactions = vec![];
+        for block_range in block_ranges {
+            transactions.extend(
+                self.get_extended_transactions_in_range_blocks(block_range.start..block_range.end)
+                    .await?,
+            );
+        }
+
+        Ok(transactions)
+    }
+
+    fn join_transactions_with_contents(
+        transactions: Vec<CardanoTransactionRecord>,
+        contents: Vec<CardanoTransactionContentRecord>,
+    ) -> StdResult<Vec<ExtendedCardanoTransaction>> {
+        let mut contents: HashMap<TransactionHash, CardanoTransactionContent> = contents
+            .into_iter()
+            .map(|record| (record.transaction_hash, record.content))
+            .collect();
+
+        transactions
+            .into_iter()
+            .map(|transaction| {
+                let content = contents
+                    .remove(&transaction.transaction_hash)
+                    .with_context(|| {
+                        format!(
+                            "Transaction '{}' has no content, it must be imported again with the extended mode enabled",
+                            transaction.transaction_hash
+                        )
+                    })?;
+
+                Ok(ExtendedCardanoTransaction::new(transaction.into(), content))
+            })
+            .collect()
+    }
+
     /// Get the closest block number above a given slot number
     pub async fn get_closest_block_number_above_slot_number(
         &self,
@@ -265,15 +385,50 @@ impl CardanoTransactionRepository {
             .await?
         {
             let threshold = highest_block_range_start - number_of_blocks_to_keep;
+            let connection = self.connection_pool.connection()?;
+            let transaction = connection.begin_transaction()?;
             let query = DeleteCardanoTransactionQuery::below_block_number_threshold(threshold)?;
+            connection.fetch_first(query)?;
 
-            let connection = self.connection_pool.connection()?;
+            let query =
+                DeleteCardanoTransactionContentQuery::below_block_number_threshold(threshold)?;
             connection.fetch_first(query)?;
+            transaction.commit()?;
         }
 
         Ok(())
     }
 
+    /// Replace the stored block range roots of the given block ranges.
+    ///
+    /// The roots of `block_ranges_to_remove` are deleted then `block_range_roots_to_insert` are
+    /// stored, in a single database transaction.
+    pub async fn replace_block_range_roots(
+        &self,
+        block_ranges_to_remove: Vec<BlockRange>,
+        block_range_roots_to_insert: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()> {
+        let connection = self.connection_pool.connection()?;
+        let transaction = connection.begin_transaction()?;
+
+        // Chunk the queries to avoid an error when we exceed sqlite binding limitations
+        for block_ranges_in_chunk in block_ranges_to_remove.chunks(100) {
+            let query = DeleteBlockRangeRootQuery::by_block_ranges(block_ranges_in_chunk.to_vec())?;
+            connection.fetch_first(query)?;
+        }
+        for block_range_roots_in_chunk in block_range_roots_to_insert.chunks(100) {
+            let records: Vec<BlockRangeRootRecord> = block_range_roots_in_chunk
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect();
+            connection.fetch_first(InsertBlockRangeRootQuery::insert_many(records)?)?;
+        }
+        transaction.commit()?;
+
+        Ok(())
+    }
+
     /// Remove transactions and block range roots that are in a rolled-back fork
     ///
     /// * Remove transactions with block number strictly greater than the given block number
@@ -287,6 +442,10 @@ impl CardanoTransactionRepository {
         let query = DeleteCardanoTransactionQuery::above_block_number_threshold(block_number)?;
         connection.fetch_first(query)?;
 
+        let query =
+            DeleteCardanoTransactionContentQuery::above_block_number_threshold(block_number)?;
+        connection.fetch_first(query)?;
+
         let query =
             DeleteBlockRangeRootQuery::contains_or_above_block_number_threshold(block_number)?;
         connection.fetch_first(query)?;
@@ -337,7 +496,7 @@ mod tests {
     #[tokio::test]
     async fn repository_create_and_get_transaction() {
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+            DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
         ));
 
         repository
@@ -379,7 +538,7 @@ mod tests {
     #[tokio::test]
     async fn repository_get_transaction_by_hashes() {
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+            DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
         ));
 
         repository
@@ -510,7 +669,7 @@ mod tests {
     async fn repository_create_ignore_further_transactions_when_exists() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         repository
@@ -548,7 +707,7 @@ mod tests {
     async fn repository_store_transactions_and_get_stored_transactions() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let cardano_transactions = vec![
@@ -599,7 +758,7 @@ mod tests {
     async fn repository_get_all_stored_transactions() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let cardano_transactions = vec![
@@ -634,7 +793,7 @@ mod tests {
     async fn repository_store_transactions_doesnt_erase_existing_data() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         repository
@@ -670,7 +829,7 @@ mod tests {
     async fn repository_get_transaction_highest_chain_point_without_transactions_in_db() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let highest_beacon = repository
@@ -684,7 +843,7 @@ mod tests {
     async fn repository_get_transaction_highest_chain_point_with_transactions_in_db() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let cardano_transactions = vec![
@@ -725,7 +884,7 @@ mod tests {
     ) {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let cardano_transactions = vec![
@@ -771,7 +930,7 @@ mod tests {
     async fn repository_get_transactions_in_range_blocks() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let transactions = vec![
@@ -840,7 +999,7 @@ mod tests {
     async fn repository_get_transactions_by_block_ranges() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let transactions = vec![
@@ -933,7 +1092,7 @@ mod tests {
     async fn repository_get_closest_block_number_by_slot_number() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let transactions = vec![
@@ -958,7 +1117,7 @@ mod tests {
     async fn repository_store_block_range() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         repository
@@ -1000,7 +1159,7 @@ mod tests {
     async fn repository_store_block_range_with_existing_hash_doesnt_erase_existing_data() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
         let range = BlockRange::from_block_number(BlockNumber(0));
 
@@ -1028,11 +1187,75 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn repository_replace_block_range_roots() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build_from_connection(connection),
+        ));
+        repository
+            .create_block_range_roots(vec![
+                (
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    MKTreeNode::from_hex("AAAA").unwrap(),
+                ),
+                (
+                    BlockRange::from_block_number(BlockRange::LENGTH),
+                    MKTreeNode::from_hex("BBBB").unwrap(),
+                ),
+                (
+                    BlockRange::from_block_number(BlockRange::LENGTH * 2),
+                    MKTreeNode::from_hex("CCCC").unwrap(),
+                ),
+            ])
+            .await
+            .unwrap();
+
+        repository
+            .replace_block_range_roots(
+                vec![
+                    BlockRange::from_block_number(BlockRange::LENGTH),
+                    BlockRange::from_block_number(BlockRange::LENGTH * 2),
+                ],
+                vec![
+                    (
+                        BlockRange::from_block_number(BlockRange::LENGTH),
+                        MKTreeNode::from_hex("DDDD").unwrap(),
+                    ),
+                    (
+                        BlockRange::from_block_number(BlockRange::LENGTH * 3),
+                        MKTreeNode::from_hex("EEEE").unwrap(),
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let records: Vec<BlockRangeRootRecord> = repository.get_all_block_range_root().unwrap();
+        assert_eq!(
+            vec![
+                BlockRangeRootRecord {
+                    range: BlockRange::from_block_number(BlockNumber(0)),
+                    merkle_root: MKTreeNode::from_hex("AAAA").unwrap(),
+                },
+                BlockRangeRootRecord {
+                    range: BlockRange::from_block_number(BlockRange::LENGTH),
+                    merkle_root: MKTreeNode::from_hex("DDDD").unwrap(),
+                },
+                BlockRangeRootRecord {
+                    range: BlockRange::from_block_number(BlockRange::LENGTH * 3),
+                    merkle_root: MKTreeNode::from_hex("EEEE").unwrap(),
+                },
+            ],
+            records
+        );
+    }
+
     #[tokio::test]
     async fn repository_retrieve_block_range_roots_up_to() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
         let block_range_roots = vec![
             (
@@ -1067,7 +1290,7 @@ mod tests {
     async fn repository_retrieve_highest_block_range_roots() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
         let block_range_roots = vec![
             BlockRangeRootRecord {
@@ -1099,7 +1322,7 @@ mod tests {
     async fn repository_prune_transactions() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let cardano_transactions: Vec<CardanoTransactionRecord> = CardanoTransactionsBuilder::new()
@@ -1150,11 +1373,181 @@ mod tests {
         assert_eq!(28, transaction_result.len());
     }
 
+    mod extended_transactions {
+        use mithril_common::entities::CardanoTransactionContent;
+
+        use super::*;
+
+        fn extended_transaction(
+            tx_hash: &str,
+            block_number: BlockNumber,
+        ) -> ExtendedCardanoTransaction {
+            ExtendedCardanoTransaction::new(
+                CardanoTransaction::new(
+                    tx_hash,
+                    block_number,
+                    SlotNumber(*block_number * 10),
+                    format!("block-hash-{block_number}"),
+                ),
+                CardanoTransactionContent {
+                    metadata_labels: vec![*block_number],
+                    ..CardanoTransactionContent::default()
+                },
+            )
+        }
+
+        fn build_repository() -> CardanoTransactionRepository {
+            CardanoTransactionRepository::new(Arc::new(
+                DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+            ))
+        }
+
+        async fn stored_contents_hashes(repository: &CardanoTransactionRepository) -> Vec<String> {
+            repository
+                .get_transactions_contents_by_hashes(vec!["tx-hash-10", "tx-hash-20", "tx-hash-30"])
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|record| record.transaction_hash)
+                .collect()
+        }
+
+        #[tokio::test]
+        async fn store_extended_transactions_store_both_transactions_and_their_contents() {
+            let repository = build_repository();
+            let transactions = vec![
+                extended_transaction("tx-hash-10", BlockNumber(10)),
+                extended_transaction("tx-hash-20", BlockNumber(20)),
+            ];
+
+            repository
+                .store_extended_transactions(transactions.clone())
+                .await
+                .unwrap();
+
+            assert_eq!(
+                transactions
+                    .iter()
+                    .cloned()
+                    .map(CardanoTransactionRecord::from)
+                    .collect::<Vec<_>>(),
+                repository.get_all_transactions().await.unwrap()
+            );
+            assert_eq!(
+                transactions
+                    .into_iter()
+                    .map(CardanoTransactionContentRecord::from)
+                    .collect::<Vec<_>>(),
+                repository
+                    .get_transactions_contents_by_hashes(vec!["tx-hash-10", "tx-hash-20"])
+                    .await
+                    .unwrap()
+            );
+        }
+
+        #[tokio::test]
+        async fn get_extended_transactions_in_range_blocks_join_transactions_with_their_contents() {
+            let repository = build_repository();
+            let transactions = vec![
+                extended_transaction("tx-hash-10", BlockNumber(10)),
+                extended_transaction("tx-hash-20", BlockNumber(20)),
+                extended_transaction("tx-hash-30", BlockNumber(30)),
+            ];
+            repository
+                .store_extended_transactions(transactions.clone())
+                .await
+                .unwrap();
+
+            let extended_transactions = repository
+                .get_extended_transactions_in_range_blocks(BlockNumber(10)..BlockNumber(30))
+                .await
+                .unwrap();
+
+            assert_eq!(transactions[0..2].to_vec(), extended_transactions);
+        }
+
+        #[tokio::test]
+        async fn get_extended_transactions_in_range_blocks_fails_if_a_transaction_has_no_content() {
+            let repository = build_repository();
+            repository
+                .store_extended_transactions(vec![extended_transaction(
+                    "tx-hash-10",
+                    BlockNumber(10),
+                )])
+                .await
+                .unwrap();
+            repository
+                .create_transaction(
+                    "tx-hash-20",
+                    BlockNumber(20),
+                    SlotNumber(200),
+                    "block-hash-20",
+                )
+                .await
+                .unwrap();
+
+            repository
+                .get_extended_transactions_in_range_blocks(BlockNumber(10)..BlockNumber(30))
+                .await
+                .expect_err("Transactions without content should be rejected");
+        }
+
+        #[tokio::test]
+        async fn prune_transaction_also_prune_their_contents() {
+            let repository = build_repository();
+            repository
+                .store_extended_transactions(vec![
+                    extended_transaction("tx-hash-10", BlockNumber(10)),
+                    extended_transaction("tx-hash-20", BlockNumber(20)),
+                    extended_transaction("tx-hash-30", BlockNumber(30)),
+                ])
+                .await
+                .unwrap();
+            repository
+                .create_block_range_roots(vec![(
+                    BlockRange::from_block_number(BlockNumber(30)),
+                    MKTreeNode::from_hex("AAAA").unwrap(),
+                )])
+                .await
+                .unwrap();
+
+            repository.prune_transaction(BlockNumber(15)).await.unwrap();
+
+            assert_eq!(
+                vec!["tx-hash-20", "tx-hash-30"],
+                stored_contents_hashes(&repository).await
+            );
+        }
+
+        #[tokio::test]
+        async fn remove_rolled_back_transactions_also_remove_their_contents() {
+            let repository = build_repository();
+            repository
+                .store_extended_transactions(vec![
+                    extended_transaction("tx-hash-10", BlockNumber(10)),
+                    extended_transaction("tx-hash-20", BlockNumber(20)),
+                    extended_transaction("tx-hash-30", BlockNumber(30)),
+                ])
+                .await
+                .unwrap();
+
+            repository
+                .remove_rolled_back_transactions_and_block_range_by_block_number(BlockNumber(20))
+                .await
+                .unwrap();
+
+            assert_eq!(
+                vec!["tx-hash-10", "tx-hash-20"],
+                stored_contents_hashes(&repository).await
+            );
+        }
+    }
+
     #[tokio::test]
     async fn get_highest_start_block_number_for_block_range_roots() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let highest = repository
@@ -1189,7 +1582,7 @@ mod tests {
     async fn remove_transactions_and_block_range_greater_than_given_block_number() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         ));
 
         let cardano_transactions = vec![
@@ -1258,7 +1651,7 @@ mod tests {
         }
 
         let repository = CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+            DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
         ));
 
         repository
@@ -9,12 +9,12 @@ use super::{
     UpdateDatabaseVersionQuery,
 };
 
-use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+use crate::sqlite::ConnectionExtensions;
 
 /// Struct to perform application version check in the database.
-pub struct DatabaseVersionChecker<'conn> {
-    /// Pathbuf to the SQLite3 file.
-    connection: &'conn SqliteConnection,
+pub struct DatabaseVersionChecker<'conn, C: ConnectionExtensions> {
+    /// Connection to the database to check.
+    connection: &'conn C,
 
     /// Application type which vesion is verified.
     application_type: ApplicationNodeType,
@@ -26,12 +26,12 @@ pub struct DatabaseVersionChecker<'conn> {
     migrations: BTreeSet<SqlMigration>,
 }
 
-impl<'conn> DatabaseVersionChecker<'conn> {
+impl<'conn, C: ConnectionExtensions> DatabaseVersionChecker<'conn, C> {
     /// constructor
     pub fn new(
         logger: Logger,
         application_type: ApplicationNodeType,
-        connection: &'conn SqliteConnection,
+        connection: &'conn C,
     ) -> Self {
         let migrations = BTreeSet::new();
 
@@ -102,7 +102,7 @@ impl<'conn> DatabaseVersionChecker<'conn> {
     fn apply_migrations(
         &self,
         starting_version: &DatabaseVersion,
-        connection: &SqliteConnection,
+        connection: &C,
     ) -> StdResult<()> {
         for migration in &self
             .migrations
@@ -135,19 +135,11 @@ impl<'conn> DatabaseVersionChecker<'conn> {
         &self,
         application_type: &ApplicationNodeType,
     ) -> StdResult<()> {
-        let connection = self.connection;
-        let table_exists = connection.query_single_cell::<_, i64>(
-            "select exists(select name from sqlite_master where type='table' and name='db_version') as table_exists",
-            &[],
-        )? == 1;
-
-        if !table_exists {
-            let sql = format!("
-create table db_version (application_type text not null primary key, version integer not null, updated_at text not null);
-insert into db_version (application_type, version, updated_at) values ('{application_type}', 0, '{}');
+        let sql = format!("
+create table if not exists db_version (application_type text not null primary key, version integer not null, updated_at text not null);
+insert or ignore into db_version (application_type, version, updated_at) values ('{application_type}', 0, '{}');
 ", Utc::now().to_rfc3339());
-            connection.execute(sql)?;
-        }
+        self.connection.execute(sql)?;
 
         Ok(())
     }
@@ -201,6 +193,8 @@ mod tests {
     use sqlite::Connection;
     use std::path::PathBuf;
 
+    use crate::sqlite::SqliteConnection;
+
     use super::*;
 
     fn check_database_version(connection: &SqliteConnection, db_version: DbVersion) {
@@ -3,5 +3,7 @@
 //! Shared datatypes and traits used to for the persistence layer of Mithril nodes
 
 pub mod database;
+#[cfg(feature = "postgres")]
+pub mod postgres;
 pub mod sqlite;
 pub mod store;
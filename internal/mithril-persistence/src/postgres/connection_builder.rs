@@ -0,0 +1,117 @@
+use std::ops::Not;
+
+use anyhow::Context;
+use slog::Logger;
+
+use mithril_common::StdResult;
+
+use crate::database::{ApplicationNodeType, DatabaseVersionChecker, SqlMigration};
+use crate::postgres::PostgresConnection;
+use crate::sqlite::{ConnectionExtensions, ConnectionOptions};
+
+/// Builder of PostgreSQL connection
+///
+/// Each store of a node lives in its own schema of the database, the schema is created if it
+/// does not exist.
+pub struct PostgresConnectionBuilder {
+    url: String,
+    schema: String,
+    sql_migrations: Vec<SqlMigration>,
+    options: Vec<ConnectionOptions>,
+    node_type: ApplicationNodeType,
+    logger: Logger,
+}
+
+impl PostgresConnectionBuilder {
+    /// Builder of connection to the database at the given URL, using its `public` schema
+    pub fn open(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            schema: "public".to_string(),
+            sql_migrations: vec![],
+            options: vec![],
+            node_type: ApplicationNodeType::Signer,
+            logger: Logger::root(slog::Discard, slog::o!()),
+        }
+    }
+
+    /// Set the schema that holds the tables of the store
+    pub fn with_schema(mut self, schema: &str) -> Self {
+        self.schema = schema.to_string();
+        self
+    }
+
+    /// Set migrations to apply at build time
+    pub fn with_migrations(mut self, migrations: Vec<SqlMigration>) -> Self {
+        self.sql_migrations = migrations;
+        self
+    }
+
+    /// Set the [ConnectionOptions] to enabled on the connection.
+    ///
+    /// Only [ConnectionOptions::ForceDisableForeignKeys] has an effect: the write ahead log is
+    /// always used by PostgreSQL and foreign keys are always enforced unless disabled.
+    pub fn with_options(mut self, options: &[ConnectionOptions]) -> Self {
+        for option in options {
+            self.options.push(option.clone());
+        }
+        self
+    }
+
+    /// Set the logger to log to at build time
+    pub fn with_logger(mut self, logger: Logger) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    /// Set the node type (default: [ApplicationNodeType::Signer]).
+    pub fn with_node_type(mut self, node_type: ApplicationNodeType) -> Self {
+        self.node_type = node_type;
+        self
+    }
+
+    /// Build a connection based on the builder configuration
+    pub fn build(self) -> StdResult<PostgresConnection> {
+        let connection = PostgresConnection::open(&self.url)?;
+
+        connection
+            .execute(format!(
+                r#"create schema if not exists "{schema}"; set search_path to "{schema}";"#,
+                schema = self.schema
+            ))
+            .with_context(|| {
+                format!(
+                    "PostgreSQL initialization: could not use schema '{}'.",
+                    self.schema
+                )
+            })?;
+
+        if self
+            .options
+            .contains(&ConnectionOptions::ForceDisableForeignKeys)
+        {
+            // Foreign keys are implemented with triggers, that are not fired for replicas
+            connection
+                .execute("set session_replication_role = replica;")
+                .with_context(|| {
+                    "PostgreSQL initialization: could not disable FOREIGN KEY support."
+                })?;
+        }
+
+        if self.sql_migrations.is_empty().not() {
+            // Check database migrations
+            let mut db_checker =
+                DatabaseVersionChecker::new(self.logger, self.node_type, &connection);
+
+            for migration in self.sql_migrations {
+                db_checker.add_migration(migration);
+            }
+
+            db_checker
+                .apply()
+                .with_context(|| "Database migration error")?;
+        }
+
+        Ok(connection)
+    }
+}
@@ -0,0 +1,430 @@
+use mithril_common::StdResult;
+
+/// Translate SQL written for SQLite, as the queries of the [sqlite][crate::sqlite] module are,
+/// to the PostgreSQL dialect.
+///
+/// Only the SQLite specific constructs used by the Mithril queries are translated:
+/// * the `?NNN` and `?` parameters are replaced by `$NNN` parameters,
+/// * the `json_group_array` and `json_object` functions are replaced by their `json_agg` and
+///   `json_build_object` equivalents,
+/// * `insert or ignore` statements are replaced by `insert … on conflict do nothing` ones,
+/// * `insert or replace` statements are replaced by `insert … on conflict (<primary key>) do
+///   update` ones, `primary_key_of` gives the primary key columns of their table.
+pub(crate) fn translate_to_postgres<F>(sql: &str, mut primary_key_of: F) -> StdResult<String>
+where
+    F: FnMut(&str) -> StdResult<Vec<String>>,
+{
+    let mut translated_sql = String::with_capacity(sql.len());
+    let mut parameter_index = 0;
+
+    for statement in split_statements(sql) {
+        let statement = replace_parameters(statement, &mut parameter_index);
+        let statement = replace_functions(&statement);
+        translated_sql.push_str(&translate_insert_or(&statement, &mut primary_key_of)?);
+    }
+
+    Ok(translated_sql)
+}
+
+/// Part of a SQL text, tagged with its kind so the translation can skip the literals.
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Code(&'a str),
+    Literal(&'a str),
+}
+
+/// Split a SQL text in code and literals (quoted strings, quoted identifiers and comments).
+fn tokenize(sql: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut code_start = 0;
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((index, char)) = chars.next() {
+        let literal_end = match char {
+            '\'' | '"' => {
+                // A doubled quote is an escaped quote inside the literal
+                let mut end = sql.len();
+                while let Some((quote_index, quote_char)) = chars.next() {
+                    if quote_char == char {
+                        if chars.peek().map(|(_, c)| *c) == Some(char) {
+                            chars.next();
+                        } else {
+                            end = quote_index + 1;
+                            break;
+                        }
+                    }
+                }
+                Some(end)
+            }
+            '-' if chars.peek().map(|(_, c)| *c) == Some('-') => {
+                let end = sql[index..]
+                    .find('\n')
+                    .map(|offset| index + offset)
+                    .unwrap_or(sql.len());
+                while chars.peek().is_some_and(|(i, _)| *i < end) {
+                    chars.next();
+                }
+                Some(end)
+            }
+            _ => None,
+        };
+
+        if let Some(end) = literal_end {
+            if code_start < index {
+                tokens.push(Token::Code(&sql[code_start..index]));
+            }
+            tokens.push(Token::Literal(&sql[index..end]));
+            code_start = end;
+        }
+    }
+    if code_start < sql.len() {
+        tokens.push(Token::Code(&sql[code_start..]));
+    }
+
+    tokens
+}
+
+/// Split a SQL text in statements, each statement keeps its trailing `;`.
+fn split_statements(sql: &str) -> Vec<&str> {
+    let mut statements = vec![];
+    let mut statement_start = 0;
+    let mut offset = 0;
+
+    for token in tokenize(sql) {
+        if let Token::Code(code) = token {
+            for (index, _) in code.match_indices(';') {
+                let end = offset + index + 1;
+                statements.push(&sql[statement_start..end]);
+                statement_start = end;
+            }
+        }
+        offset += token_len(&token);
+    }
+    if !sql[statement_start..].trim().is_empty() {
+        statements.push(&sql[statement_start..]);
+    }
+
+    statements
+}
+
+fn token_len(token: &Token) -> usize {
+    match token {
+        Token::Code(text) | Token::Literal(text) => text.len(),
+    }
+}
+
+/// Replace the `?NNN` and `?` parameters by `$NNN` parameters.
+fn replace_parameters(statement: &str, parameter_index: &mut usize) -> String {
+    let mut translated = String::with_capacity(statement.len());
+
+    for token in tokenize(statement) {
+        match token {
+            Token::Literal(literal) => translated.push_str(literal),
+            Token::Code(code) => {
+                let mut chars = code.chars().peekable();
+                while let Some(char) = chars.next() {
+                    if char != '?' {
+                        translated.push(char);
+                        continue;
+                    }
+
+                    let mut digits = String::new();
+                    while let Some(digit) = chars.next_if(|c| c.is_ascii_digit()) {
+                        digits.push(digit);
+                    }
+                    match digits.parse::<usize>() {
+                        Ok(index) => *parameter_index = (*parameter_index).max(index),
+                        Err(_) => *parameter_index += 1,
+                    }
+                    translated.push('$');
+                    translated.push_str(&match digits.is_empty() {
+                        true => parameter_index.to_string(),
+                        false => digits,
+                    });
+                }
+            }
+        }
+    }
+
+    translated
+}
+
+/// SQLite functions and their PostgreSQL equivalent.
+const FUNCTIONS_TRANSLATIONS: [(&str, &str); 2] = [
+    ("json_group_array", "json_agg"),
+    ("json_object", "json_build_object"),
+];
+
+/// Replace the calls to the SQLite functions that have a PostgreSQL equivalent.
+fn replace_functions(statement: &str) -> String {
+    let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut translated = String::with_capacity(statement.len());
+
+    for token in tokenize(statement) {
+        match token {
+            Token::Literal(literal) => translated.push_str(literal),
+            Token::Code(code) => {
+                let mut remaining = code;
+                while let Some(word_start) = remaining.find(is_identifier_char) {
+                    translated.push_str(&remaining[..word_start]);
+                    remaining = &remaining[word_start..];
+                    let word_end = remaining
+                        .find(|c: char| !is_identifier_char(c))
+                        .unwrap_or(remaining.len());
+                    let (word, tail) = remaining.split_at(word_end);
+                    let postgres_function = FUNCTIONS_TRANSLATIONS
+                        .iter()
+                        .find(|(sqlite_function, _)| word.eq_ignore_ascii_case(sqlite_function))
+                        .filter(|_| tail.trim_start().starts_with('('))
+                        .map(|(_, postgres_function)| *postgres_function);
+
+                    translated.push_str(postgres_function.unwrap_or(word));
+                    remaining = tail;
+                }
+                translated.push_str(remaining);
+            }
+        }
+    }
+
+    translated
+}
+
+/// Translate an `insert or ignore` or `insert or replace` statement, other statements are
+/// returned as is.
+fn translate_insert_or<F>(statement: &str, primary_key_of: &mut F) -> StdResult<String>
+where
+    F: FnMut(&str) -> StdResult<Vec<String>>,
+{
+    let trimmed_statement = statement.trim_start();
+    let mut words = trimmed_statement.split_whitespace();
+    let conflict_action = match (
+        words.next().map(str::to_lowercase).as_deref(),
+        words.next().map(str::to_lowercase).as_deref(),
+        words.next().map(str::to_lowercase).as_deref(),
+        words.next().map(str::to_lowercase).as_deref(),
+    ) {
+        (Some("insert"), Some("or"), Some("ignore"), Some("into")) => ConflictAction::Ignore,
+        (Some("insert"), Some("or"), Some("replace"), Some("into")) => ConflictAction::Replace,
+        _ => return Ok(statement.to_string()),
+    };
+
+    // Remove the `or ignore`/`or replace` words, the statement then starts with `insert into`
+    let into_index = trimmed_statement.to_lowercase().find(" into").unwrap();
+    let statement = format!(
+        "{}{}{}",
+        &statement[..statement.len() - trimmed_statement.len()],
+        &trimmed_statement[.."insert".len()],
+        &trimmed_statement[into_index..]
+    );
+    let target = &statement[statement.to_lowercase().find(" into").unwrap() + " into".len()..];
+
+    let conflict_clause = match conflict_action {
+        ConflictAction::Ignore => "on conflict do nothing".to_string(),
+        ConflictAction::Replace => {
+            let table = target
+                .trim_start()
+                .split(|c: char| c.is_whitespace() || c == '(')
+                .next()
+                .unwrap_or_default();
+            let columns: Vec<String> = target
+                .find('(')
+                .and_then(|start| {
+                    target[start..]
+                        .find(')')
+                        .map(|end| &target[start + 1..start + end])
+                })
+                .map(|columns| {
+                    columns
+                        .split(',')
+                        .map(|column| column.trim().trim_matches('"').to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+            let primary_key = primary_key_of(table)?;
+            let updated_columns: Vec<String> = columns
+                .iter()
+                .filter(|column| !primary_key.contains(column))
+                .map(|column| format!(r#""{column}" = excluded."{column}""#))
+                .collect();
+
+            // Identifiers are quoted since some column names are reserved words in PostgreSQL
+            match updated_columns.is_empty() {
+                true => "on conflict do nothing".to_string(),
+                false => format!(
+                    "on conflict ({}) do update set {}",
+                    primary_key
+                        .iter()
+                        .map(|column| format!(r#""{column}""#))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    updated_columns.join(", ")
+                ),
+            }
+        }
+    };
+
+    Ok(insert_before_returning_clause(&statement, &conflict_clause))
+}
+
+enum ConflictAction {
+    Ignore,
+    Replace,
+}
+
+/// Insert the given clause before the `returning` clause of the statement, or at its end if it
+/// has no `returning` clause.
+fn insert_before_returning_clause(statement: &str, clause: &str) -> String {
+    let mut offset = 0;
+    let mut depth = 0;
+    let mut returning_index = None;
+
+    'tokens: for token in tokenize(statement) {
+        if let Token::Code(code) = token {
+            let lowercase_code = code.to_lowercase();
+            for (index, char) in lowercase_code.char_indices() {
+                match char {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ if depth == 0
+                        && lowercase_code[index..].starts_with("returning")
+                        && (index == 0
+                            || lowercase_code[..index].ends_with(char::is_whitespace)) =>
+                    {
+                        returning_index = Some(offset + index);
+                        break 'tokens;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        offset += token_len(&token);
+    }
+
+    let end_index = returning_index.unwrap_or_else(|| {
+        statement
+            .trim_end()
+            .strip_suffix(';')
+            .unwrap_or(statement.trim_end())
+            .len()
+    });
+    let (head, tail) = statement.split_at(end_index);
+    let separator = match head.ends_with(char::is_whitespace) {
+        true => "",
+        false => " ",
+    };
+    let tail_separator = match returning_index {
+        Some(_) => " ",
+        None => "",
+    };
+
+    format!("{head}{separator}{clause}{tail_separator}{tail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    fn translate(sql: &str) -> String {
+        translate_to_postgres(sql, |table| match table {
+            "stake_pool" => Ok(vec!["epoch".to_string(), "stake_pool_id".to_string()]),
+            _ => Err(anyhow!("unknown table '{table}'")),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn statements_without_sqlite_specific_constructs_are_not_modified() {
+        let sql = "select a, b from c where d = 'e' order by f desc;";
+
+        assert_eq!(sql, translate(sql));
+    }
+
+    #[test]
+    fn replace_numbered_and_anonymous_parameters() {
+        assert_eq!(
+            "select * from c where d = $1 and e in ($2, $3)",
+            translate("select * from c where d = ?1 and e in (?2, ?3)")
+        );
+        assert_eq!(
+            "select * from c where d = $1 and e = $2",
+            translate("select * from c where d = ? and e = ?")
+        );
+        assert_eq!(
+            "select * from c where d = $2 and e = $3",
+            translate("select * from c where d = ?2 and e = ?")
+        );
+    }
+
+    #[test]
+    fn do_not_replace_question_marks_in_literals_and_comments() {
+        assert_eq!(
+            "select '?1', \"?\" from c -- where ?\nwhere d = $1",
+            translate("select '?1', \"?\" from c -- where ?\nwhere d = ?1")
+        );
+        assert_eq!(
+            "select 'it''s ?' from c where d = $1",
+            translate("select 'it''s ?' from c where d = ?1")
+        );
+    }
+
+    #[test]
+    fn replace_sqlite_json_functions() {
+        assert_eq!(
+            "select json_agg(json_build_object('json_object', a.json_object)) from a;",
+            translate("select json_group_array(json_object('json_object', a.json_object)) from a;")
+        );
+    }
+
+    #[test]
+    fn translate_insert_or_ignore() {
+        assert_eq!(
+            "insert into cardano_tx (a, b) values ($1, $2), ($3, $4) on conflict do nothing returning a, b",
+            translate("insert or ignore into cardano_tx (a, b) values (?1, ?2), (?3, ?4) returning a, b")
+        );
+        assert_eq!(
+            "INSERT into db_version (a) values ('returning') on conflict do nothing;",
+            translate("INSERT OR IGNORE into db_version (a) values ('returning');")
+        );
+    }
+
+    #[test]
+    fn translate_insert_or_replace_using_the_primary_key_of_the_table() {
+        assert_eq!(
+            r#"insert into stake_pool (stake_pool_id, epoch, stake, created_at) values ($1, $2, $3, $4) on conflict ("epoch", "stake_pool_id") do update set "stake" = excluded."stake", "created_at" = excluded."created_at" returning stake_pool_id"#,
+            translate(
+                "insert or replace into stake_pool (stake_pool_id, epoch, stake, created_at) \
+                values (?1, ?2, ?3, ?4) returning stake_pool_id"
+            )
+        );
+    }
+
+    #[test]
+    fn translate_insert_or_replace_of_primary_key_columns_only() {
+        assert_eq!(
+            "insert into stake_pool (stake_pool_id, epoch) values ($1, $2) on conflict do nothing",
+            translate("insert or replace into stake_pool (stake_pool_id, epoch) values (?1, ?2)")
+        );
+    }
+
+    #[test]
+    fn translate_insert_or_replace_fails_if_the_primary_key_can_not_be_read() {
+        translate_to_postgres("insert or replace into unknown (a) values (?1)", |table| {
+            Err(anyhow!("unknown table '{table}'"))
+        })
+        .expect_err("Translation should fail if the primary key can not be read");
+    }
+
+    #[test]
+    fn translate_each_statement_of_a_batch() {
+        assert_eq!(
+            "create table a (b text); insert into a (b) values ('c;d') on conflict do nothing;\n\
+            insert into a (b) values ('e') on conflict do nothing;",
+            translate(
+                "create table a (b text); insert or ignore into a (b) values ('c;d');\n\
+                insert or ignore into a (b) values ('e');"
+            )
+        );
+    }
+}
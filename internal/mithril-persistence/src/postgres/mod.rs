@@ -0,0 +1,44 @@
+//! PostgreSQL module.
+//! This module provides a connection to a PostgreSQL database that runs the queries of the
+//! [sqlite][crate::sqlite] module Entity framework, translating them to the PostgreSQL dialect.
+mod connection;
+mod connection_builder;
+mod dialect;
+mod value;
+
+pub use connection::PostgresConnection;
+pub use connection_builder::PostgresConnectionBuilder;
+
+/// Helpers to run the tests against a PostgreSQL database
+pub mod test_utils {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    /// Environment variable holding the URL of the PostgreSQL database to run the tests against,
+    /// the tests use SQLite databases if it is not set.
+    pub const TEST_DATABASE_URL_ENV_VAR: &str = "MITHRIL_TEST_POSTGRES_URL";
+
+    /// URL of the PostgreSQL database to run the tests against, if any.
+    pub fn test_database_url() -> Option<String> {
+        std::env::var(TEST_DATABASE_URL_ENV_VAR)
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+    }
+
+    /// Name of a new schema, so each test database starts empty.
+    ///
+    /// Note: the schemas are not dropped after the tests, the database should be a disposable one.
+    pub fn unique_test_schema() -> String {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default();
+
+        format!(
+            "test_{}_{timestamp}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        )
+    }
+}
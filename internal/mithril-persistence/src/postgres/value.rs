@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use postgres::types::{private::BytesMut, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use sqlite::Value;
+
+use mithril_common::StdResult;
+
+use crate::sqlite::Row;
+
+/// Wrapper of the [sqlite::Value] used as parameters by the queries, so they can be sent to
+/// PostgreSQL.
+///
+/// PostgreSQL is strictly typed: the value is serialized to the type that the server expects for
+/// the parameter (ie: an integer is sent as an `int4` to an `integer` column and as a `bool` to
+/// a `boolean` column).
+#[derive(Debug)]
+pub(crate) struct PostgresValue(pub Value);
+
+impl ToSql for PostgresValue {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match &self.0 {
+            Value::Null => Ok(IsNull::Yes),
+            Value::Integer(value) => match *ty {
+                Type::BOOL => (*value != 0).to_sql(ty, out),
+                Type::INT2 => i16::try_from(*value)?.to_sql(ty, out),
+                Type::INT4 => i32::try_from(*value)?.to_sql(ty, out),
+                Type::FLOAT4 => (*value as f32).to_sql(ty, out),
+                Type::FLOAT8 => (*value as f64).to_sql(ty, out),
+                _ if <&str as ToSql>::accepts(ty) => value.to_string().to_sql(ty, out),
+                _ => value.to_sql(ty, out),
+            },
+            Value::Float(value) => match *ty {
+                Type::FLOAT4 => (*value as f32).to_sql(ty, out),
+                _ if <&str as ToSql>::accepts(ty) => value.to_string().to_sql(ty, out),
+                _ => value.to_sql(ty, out),
+            },
+            Value::String(value) => value.as_str().to_sql(ty, out),
+            Value::Binary(value) => value.as_slice().to_sql(ty, out),
+        }
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+/// Convert the rows read from PostgreSQL to backend independent [Row]s.
+pub(crate) fn to_rows(rows: Vec<postgres::Row>) -> StdResult<Vec<Row>> {
+    let column_mapping: Arc<HashMap<String, usize>> = match rows.first() {
+        Some(row) => Row::column_mapping(
+            &row.columns()
+                .iter()
+                .map(|column| column.name())
+                .collect::<Vec<_>>(),
+        ),
+        None => return Ok(vec![]),
+    };
+
+    rows.iter()
+        .map(|row| {
+            let values = (0..row.len())
+                .map(|index| read_value(row, index))
+                .collect::<StdResult<Vec<_>>>()?;
+            Ok(Row::new(column_mapping.clone(), values))
+        })
+        .collect()
+}
+
+/// Read a cell as a [sqlite::Value], the type used by the entities to hydrate themselves.
+pub(crate) fn read_value(row: &postgres::Row, index: usize) -> StdResult<Value> {
+    let column = &row.columns()[index];
+    let value = match *column.type_() {
+        Type::BOOL => row
+            .try_get::<_, Option<bool>>(index)?
+            .map(|value| Value::Integer(value.into())),
+        Type::INT2 => row
+            .try_get::<_, Option<i16>>(index)?
+            .map(|value| Value::Integer(value.into())),
+        Type::INT4 => row
+            .try_get::<_, Option<i32>>(index)?
+            .map(|value| Value::Integer(value.into())),
+        Type::INT8 => row.try_get::<_, Option<i64>>(index)?.map(Value::Integer),
+        Type::FLOAT4 => row
+            .try_get::<_, Option<f32>>(index)?
+            .map(|value| Value::Float(value.into())),
+        Type::FLOAT8 => row.try_get::<_, Option<f64>>(index)?.map(Value::Float),
+        Type::BYTEA => row.try_get::<_, Option<Vec<u8>>>(index)?.map(Value::Binary),
+        Type::JSON | Type::JSONB => row
+            .try_get::<_, Option<JsonText>>(index)?
+            .map(|json| Value::String(json.0)),
+        ref ty if <String as FromSql>::accepts(ty) => {
+            row.try_get::<_, Option<String>>(index)?.map(Value::String)
+        }
+        ref ty => {
+            return Err(anyhow!(
+                "Unsupported type '{ty}' for column '{}'",
+                column.name()
+            ))
+        }
+    };
+
+    Ok(value.unwrap_or(Value::Null))
+}
+
+/// Text of a `json` or `jsonb` cell, read as a string as SQLite does for its json values.
+struct JsonText(String);
+
+impl<'a> FromSql<'a> for JsonText {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        // The binary format of a `jsonb` value is its text prefixed by a version byte
+        let text = match *ty {
+            Type::JSONB => raw
+                .strip_prefix(&[1])
+                .ok_or("Unsupported jsonb binary format version")?,
+            _ => raw,
+        };
+
+        Ok(Self(std::str::from_utf8(text)?.to_string()))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::JSON | Type::JSONB)
+    }
+}
@@ -0,0 +1,171 @@
+use anyhow::anyhow;
+use slog::{debug, Logger};
+use sqlite::{ffi, Connection};
+use std::ffi::CStr;
+use std::path::Path;
+use std::time::Duration;
+
+use mithril_common::StdResult;
+
+use crate::sqlite::SqliteConnection;
+
+/// Delay before retrying a backup step when the source database is locked by a writer.
+const BUSY_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// The SqliteBackup copies a database while it is in use, using the SQLite online backup API.
+///
+/// The copy is a consistent snapshot of the source database: all its pages are copied within a
+/// single read transaction, which does not block the writers of a database in WAL mode.
+///
+/// see: <https://www.sqlite.org/backup.html>
+pub struct SqliteBackup<'a> {
+    connection: &'a SqliteConnection,
+    logger: Logger,
+}
+
+impl<'a> SqliteBackup<'a> {
+    /// Create a new instance of the `SqliteBackup`.
+    pub fn new(connection: &'a SqliteConnection) -> Self {
+        Self {
+            connection,
+            logger: Logger::root(slog::Discard, slog::o!()),
+        }
+    }
+
+    /// Set the logger to be used by the backup.
+    pub fn with_logger(mut self, logger: Logger) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    /// Copy the database to the given file, the file is overwritten if it exists.
+    pub fn backup_to(&self, destination: &Path) -> StdResult<()> {
+        debug!(
+            self.logger,
+            "SqliteBackup::Copying the database to '{}'",
+            destination.display()
+        );
+        let destination_connection = Connection::open_thread_safe(destination)?;
+
+        // SAFETY: both connections outlive the backup handle which is always released by
+        // `sqlite3_backup_finish` before returning.
+        unsafe {
+            let backup = ffi::sqlite3_backup_init(
+                destination_connection.as_raw(),
+                c"main".as_ptr(),
+                self.connection.as_raw(),
+                c"main".as_ptr(),
+            );
+            if backup.is_null() {
+                return Err(anyhow!(
+                    "SqliteBackup: could not start the backup: {}",
+                    error_message(destination_connection.as_raw())
+                ));
+            }
+
+            let step_result = loop {
+                match ffi::sqlite3_backup_step(backup, -1) {
+                    ffi::SQLITE_BUSY | ffi::SQLITE_LOCKED => std::thread::sleep(BUSY_RETRY_DELAY),
+                    code => break code,
+                }
+            };
+            let finish_result = ffi::sqlite3_backup_finish(backup);
+
+            if step_result != ffi::SQLITE_DONE {
+                return Err(anyhow!(
+                    "SqliteBackup: could not copy the database: {}",
+                    error_string(step_result)
+                ));
+            }
+            if finish_result != ffi::SQLITE_OK {
+                return Err(anyhow!(
+                    "SqliteBackup: could not complete the backup: {}",
+                    error_message(destination_connection.as_raw())
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Safety: `connection` must be a valid SQLite connection handle.
+unsafe fn error_message(connection: *mut ffi::sqlite3) -> String {
+    CStr::from_ptr(ffi::sqlite3_errmsg(connection))
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn error_string(code: std::ffi::c_int) -> String {
+    // SAFETY: `sqlite3_errstr` returns a static string for any result code.
+    unsafe { CStr::from_ptr(ffi::sqlite3_errstr(code)) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use crate::sqlite::{ConnectionBuilder, ConnectionExtensions, ConnectionOptions};
+
+    use super::*;
+
+    fn count_rows(connection: &SqliteConnection) -> i64 {
+        connection
+            .query_single_cell("select count(*) from test", &[])
+            .unwrap()
+    }
+
+    #[test]
+    fn backup_copies_a_database_in_use_in_wal_mode() {
+        let dir = TempDir::create("sqlite_backup", "backup_copies_a_database_in_use");
+        let source = ConnectionBuilder::open_file(&dir.join("source.sqlite3"))
+            .with_options(&[ConnectionOptions::EnableWriteAheadLog])
+            .build()
+            .unwrap();
+        source
+            .execute("create table test (id integer); insert into test values (1), (2), (3);")
+            .unwrap();
+
+        SqliteBackup::new(&source)
+            .backup_to(&dir.join("backup.sqlite3"))
+            .unwrap();
+        source.execute("insert into test values (4);").unwrap();
+
+        let backup = Connection::open_thread_safe(dir.join("backup.sqlite3")).unwrap();
+        assert_eq!(3, count_rows(&backup));
+        assert_eq!(4, count_rows(&source));
+    }
+
+    #[test]
+    fn backup_overwrites_an_existing_destination() {
+        let dir = TempDir::create("sqlite_backup", "backup_overwrites_an_existing_destination");
+        let destination = dir.join("backup.sqlite3");
+        {
+            let previous_backup = Connection::open_thread_safe(&destination).unwrap();
+            previous_backup
+                .execute("create table test (id integer); insert into test values (1);")
+                .unwrap();
+        }
+        let source = Connection::open_thread_safe(":memory:").unwrap();
+        source
+            .execute("create table test (id integer); insert into test values (1), (2);")
+            .unwrap();
+
+        SqliteBackup::new(&source).backup_to(&destination).unwrap();
+
+        let backup = Connection::open_thread_safe(&destination).unwrap();
+        assert_eq!(2, count_rows(&backup));
+    }
+
+    #[test]
+    fn backup_fails_if_the_destination_can_not_be_created() {
+        let dir = TempDir::create("sqlite_backup", "backup_fails_if_destination_invalid");
+        let source = Connection::open_thread_safe(":memory:").unwrap();
+
+        SqliteBackup::new(&source)
+            .backup_to(&dir.join("not_existing_dir").join("backup.sqlite3"))
+            .expect_err("Backup to a not existing directory should fail");
+    }
+}
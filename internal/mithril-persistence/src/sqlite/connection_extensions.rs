@@ -5,13 +5,20 @@ use mithril_common::StdResult;
 
 use crate::sqlite::{EntityCursor, Query, SqliteConnection, Transaction};
 
-/// Extension trait for the [SqliteConnection] type.
+/// Extension trait for the database connections ([SqliteConnection] and
+/// [DatabaseConnection][crate::sqlite::DatabaseConnection]).
 pub trait ConnectionExtensions {
     /// Begin a transaction on the connection.
     fn begin_transaction(&self) -> StdResult<Transaction>;
 
+    /// Execute the given sql statements, without reading their results.
+    fn execute<Q: AsRef<str>>(&self, sql: Q) -> StdResult<()>;
+
+    /// Execute the given sql statement with its parameters, without reading its results.
+    fn execute_with_params<Q: AsRef<str>>(&self, sql: Q, params: &[Value]) -> StdResult<()>;
+
     /// Execute the given sql query and return the value of the first cell read.
-    fn query_single_cell<Q: AsRef<str>, T: ReadableWithIndex>(
+    fn query_single_cell<Q: AsRef<str>, T: SingleCellValue>(
         &self,
         sql: Q,
         params: &[Value],
@@ -32,12 +39,69 @@ pub trait ConnectionExtensions {
     }
 }
 
+/// Type that can be read from a single cell with [ConnectionExtensions::query_single_cell].
+pub trait SingleCellValue: ReadableWithIndex {
+    /// Convert the value read from the cell.
+    fn from_value(value: Value) -> sqlite::Result<Self>;
+}
+
+macro_rules! implement_single_cell_value(
+    ($type:ty, $value:ident) => {
+        impl SingleCellValue for $type {
+            fn from_value(value: Value) -> sqlite::Result<Self> {
+                match value {
+                    Value::$value(value) => Ok(value),
+                    _ => Err(sqlite::Error {
+                        code: None,
+                        message: Some(format!(
+                            "failed to convert '{value:?}' to '{}'",
+                            stringify!($type)
+                        )),
+                    }),
+                }
+            }
+        }
+
+        impl SingleCellValue for Option<$type> {
+            fn from_value(value: Value) -> sqlite::Result<Self> {
+                match value {
+                    Value::Null => Ok(None),
+                    _ => <$type>::from_value(value).map(Some),
+                }
+            }
+        }
+    }
+);
+
+implement_single_cell_value!(i64, Integer);
+implement_single_cell_value!(f64, Float);
+implement_single_cell_value!(String, String);
+implement_single_cell_value!(Vec<u8>, Binary);
+
 impl ConnectionExtensions for SqliteConnection {
     fn begin_transaction(&self) -> StdResult<Transaction> {
-        Ok(Transaction::begin(self)?)
+        Transaction::begin(self)
+    }
+
+    fn execute<Q: AsRef<str>>(&self, sql: Q) -> StdResult<()> {
+        let sql = sql.as_ref();
+        sqlite::Connection::execute(self, sql).with_context(|| {
+            format!(
+                "Execute statements error: SQL=`{}`",
+                &sql.replace('\n', " ").trim()
+            )
+        })
+    }
+
+    fn execute_with_params<Q: AsRef<str>>(&self, sql: Q, params: &[Value]) -> StdResult<()> {
+        let mut statement = prepare_statement(self, sql.as_ref())?;
+        statement.bind(params)?;
+        while statement.next()? != sqlite::State::Done {}
+
+        Ok(())
     }
 
-    fn query_single_cell<Q: AsRef<str>, T: ReadableWithIndex>(
+    fn query_single_cell<Q: AsRef<str>, T: SingleCellValue>(
         &self,
         sql: Q,
         params: &[Value],
@@ -115,4 +179,24 @@ mod tests {
 
         assert_eq!(value, 45);
     }
+
+    #[test]
+    fn test_execute_with_params() {
+        let connection = Connection::open_thread_safe(":memory:").unwrap();
+        connection
+            .execute("create table query_test(value integer);")
+            .unwrap();
+
+        connection
+            .execute_with_params(
+                "insert into query_test(value) values (?1), (?2)",
+                &[Value::Integer(10), Value::Integer(45)],
+            )
+            .unwrap();
+
+        let value: i64 = connection
+            .query_single_cell("select sum(value) from query_test", &[])
+            .unwrap();
+        assert_eq!(value, 55);
+    }
 }
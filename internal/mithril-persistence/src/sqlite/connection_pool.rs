@@ -5,42 +5,42 @@ use mithril_common::{
     StdResult,
 };
 
-use crate::sqlite::SqliteConnection;
+use crate::sqlite::DatabaseConnection;
 
-/// SqliteConnection wrapper for a pooled connection
-pub struct SqlitePooledConnection(SqliteConnection);
+/// DatabaseConnection wrapper for a pooled connection
+pub struct DatabasePooledConnection(DatabaseConnection);
 
-impl SqlitePooledConnection {
-    /// Create a new SqlitePooledConnection
-    pub fn new(connection: SqliteConnection) -> Self {
-        Self(connection)
+impl DatabasePooledConnection {
+    /// Create a new DatabasePooledConnection
+    pub fn new<C: Into<DatabaseConnection>>(connection: C) -> Self {
+        Self(connection.into())
     }
 }
 
-impl Deref for SqlitePooledConnection {
-    type Target = SqliteConnection;
+impl Deref for DatabasePooledConnection {
+    type Target = DatabaseConnection;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl Reset for SqlitePooledConnection {}
+impl Reset for DatabasePooledConnection {}
 
-/// Pool of Sqlite connections
-pub struct SqliteConnectionPool {
-    connection_pool: ResourcePool<SqlitePooledConnection>,
+/// Pool of database connections
+pub struct DatabaseConnectionPool {
+    connection_pool: ResourcePool<DatabasePooledConnection>,
 }
 
-impl SqliteConnectionPool {
+impl DatabaseConnectionPool {
     /// Create a new pool with the given size by calling the given builder function
-    pub fn build(
+    pub fn build<C: Into<DatabaseConnection>>(
         size: usize,
-        builder: impl Fn() -> StdResult<SqliteConnection>,
+        builder: impl Fn() -> StdResult<C>,
     ) -> StdResult<Self> {
-        let mut connections: Vec<SqlitePooledConnection> = Vec::with_capacity(size);
+        let mut connections: Vec<DatabasePooledConnection> = Vec::with_capacity(size);
         for _count in 0..size {
-            connections.push(SqlitePooledConnection::new(builder()?));
+            connections.push(DatabasePooledConnection::new(builder()?));
         }
 
         Ok(Self {
@@ -49,7 +49,7 @@ impl SqliteConnectionPool {
     }
 
     /// Get a connection from the pool
-    pub fn connection(&self) -> StdResult<ResourcePoolItem<SqlitePooledConnection>> {
+    pub fn connection(&self) -> StdResult<ResourcePoolItem<DatabasePooledConnection>> {
         let timeout = Duration::from_millis(1000);
         let connection = self.connection_pool.acquire_resource(timeout)?;
 
@@ -57,8 +57,8 @@ impl SqliteConnectionPool {
     }
 
     /// Returns a single resource pool connection
-    pub fn build_from_connection(connection: SqliteConnection) -> Self {
-        let connection_pool = ResourcePool::new(1, vec![SqlitePooledConnection::new(connection)]);
+    pub fn build_from_connection<C: Into<DatabaseConnection>>(connection: C) -> Self {
+        let connection_pool = ResourcePool::new(1, vec![DatabasePooledConnection::new(connection)]);
 
         Self { connection_pool }
     }
@@ -71,7 +71,7 @@ mod tests {
 
     #[test]
     fn can_build_pool_of_given_size() {
-        let pool = SqliteConnectionPool::build(10, cardano_tx_db_connection).unwrap();
+        let pool = DatabaseConnectionPool::build(10, cardano_tx_db_connection).unwrap();
 
         assert_eq!(pool.connection_pool.size(), 10);
     }
@@ -1,26 +1,62 @@
 use sqlite::CursorWithOwnership;
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
-use super::SqLiteEntity;
+use mithril_common::StdResult;
+
+use super::{Row, SqLiteEntity};
 
 /// Database query result Iterator wrapper. This wrapper allows to call entity
 /// hydration for each extracted result.
 pub struct EntityCursor<'a, T> {
-    cursor: CursorWithOwnership<'a>,
+    rows: RowSource<'a>,
     phantom: PhantomData<T>,
 }
 
+enum RowSource<'a> {
+    Sqlite {
+        cursor: CursorWithOwnership<'a>,
+        column_mapping: Arc<HashMap<String, usize>>,
+    },
+    Fetched(std::vec::IntoIter<Row>),
+    Streamed(Box<dyn Iterator<Item = StdResult<Row>> + 'a>),
+}
+
 impl<'a, T> EntityCursor<'a, T> {
     /// [EntityCursor] constructor.
     pub fn new(cursor: CursorWithOwnership<'a>) -> Self {
+        let column_mapping = Row::column_mapping(cursor.column_names());
+        Self {
+            rows: RowSource::Sqlite {
+                cursor,
+                column_mapping,
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    /// [EntityCursor] constructor for rows that have already been fetched from the database.
+    pub fn from_rows(rows: Vec<Row>) -> Self {
         Self {
-            cursor,
+            rows: RowSource::Fetched(rows.into_iter()),
+            phantom: PhantomData,
+        }
+    }
+
+    /// [EntityCursor] constructor for rows read one after the other from the database.
+    pub fn from_row_results<I>(rows: I) -> Self
+    where
+        I: Iterator<Item = StdResult<Row>> + 'a,
+    {
+        Self {
+            rows: RowSource::Streamed(Box::new(rows)),
             phantom: PhantomData,
         }
     }
 }
 
-impl<'a, T> Iterator for EntityCursor<'a, T>
+impl<T> Iterator for EntityCursor<'_, T>
 where
     T: SqLiteEntity,
 {
@@ -30,8 +66,21 @@ where
     /// This iterator will crash the application if an error occures during this process.
     /// This is intended because it prevents inconsistent data to spread accross the application.
     fn next(&mut self) -> Option<T> {
-        self.cursor
-            .next()
-            .map(|res| T::hydrate(res.map_err(|e| panic!("{e}")).unwrap()).unwrap())
+        let row = match &mut self.rows {
+            RowSource::Sqlite {
+                cursor,
+                column_mapping,
+            } => cursor
+                .try_next()
+                .map_err(|e| panic!("{e}"))
+                .unwrap()
+                .map(|values| Row::new(column_mapping.clone(), values)),
+            RowSource::Fetched(rows) => rows.next(),
+            RowSource::Streamed(rows) => {
+                rows.next().transpose().map_err(|e| panic!("{e}")).unwrap()
+            }
+        };
+
+        row.map(|row| T::hydrate(row).unwrap())
     }
 }
@@ -0,0 +1,117 @@
+use sqlite::Value;
+
+use mithril_common::StdResult;
+
+#[cfg(feature = "postgres")]
+use crate::postgres::PostgresConnection;
+use crate::sqlite::{
+    ConnectionExtensions, EntityCursor, Query, SingleCellValue, SqliteConnection, Transaction,
+};
+
+/// Connection to the database of a node, whatever its backend.
+pub enum DatabaseConnection {
+    /// Connection to a SQLite database
+    Sqlite(SqliteConnection),
+
+    /// Connection to a PostgreSQL database
+    #[cfg(feature = "postgres")]
+    Postgres(PostgresConnection),
+}
+
+impl DatabaseConnection {
+    /// Get the underlying SQLite connection, `None` if the database is not a SQLite one.
+    ///
+    /// Useful for the SQLite specific operations, like vacuuming the database file.
+    pub fn as_sqlite(&self) -> Option<&SqliteConnection> {
+        match self {
+            Self::Sqlite(connection) => Some(connection),
+            #[cfg(feature = "postgres")]
+            Self::Postgres(_) => None,
+        }
+    }
+}
+
+impl From<SqliteConnection> for DatabaseConnection {
+    fn from(connection: SqliteConnection) -> Self {
+        Self::Sqlite(connection)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<PostgresConnection> for DatabaseConnection {
+    fn from(connection: PostgresConnection) -> Self {
+        Self::Postgres(connection)
+    }
+}
+
+impl ConnectionExtensions for DatabaseConnection {
+    fn begin_transaction(&self) -> StdResult<Transaction<'_>> {
+        match self {
+            Self::Sqlite(connection) => connection.begin_transaction(),
+            #[cfg(feature = "postgres")]
+            Self::Postgres(connection) => connection.begin_transaction(),
+        }
+    }
+
+    fn execute<Q: AsRef<str>>(&self, sql: Q) -> StdResult<()> {
+        match self {
+            Self::Sqlite(connection) => ConnectionExtensions::execute(connection, sql),
+            #[cfg(feature = "postgres")]
+            Self::Postgres(connection) => connection.execute(sql),
+        }
+    }
+
+    fn execute_with_params<Q: AsRef<str>>(&self, sql: Q, params: &[Value]) -> StdResult<()> {
+        match self {
+            Self::Sqlite(connection) => connection.execute_with_params(sql, params),
+            #[cfg(feature = "postgres")]
+            Self::Postgres(connection) => connection.execute_with_params(sql, params),
+        }
+    }
+
+    fn query_single_cell<Q: AsRef<str>, T: SingleCellValue>(
+        &self,
+        sql: Q,
+        params: &[Value],
+    ) -> StdResult<T> {
+        match self {
+            Self::Sqlite(connection) => connection.query_single_cell(sql, params),
+            #[cfg(feature = "postgres")]
+            Self::Postgres(connection) => connection.query_single_cell(sql, params),
+        }
+    }
+
+    fn fetch<Q: Query>(&self, query: Q) -> StdResult<EntityCursor<'_, Q::Entity>> {
+        match self {
+            Self::Sqlite(connection) => connection.fetch(query),
+            #[cfg(feature = "postgres")]
+            Self::Postgres(connection) => connection.fetch(query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlite::Connection;
+
+    use super::*;
+
+    #[test]
+    fn execute_and_query_through_a_sqlite_database_connection() {
+        let connection: DatabaseConnection =
+            Connection::open_thread_safe(":memory:").unwrap().into();
+        connection
+            .execute("create table query_test(value integer); insert into query_test(value) values (4), (2);")
+            .unwrap();
+
+        let value: i64 = connection
+            .query_single_cell(
+                "select sum(value) from query_test where value > ?1",
+                &[Value::Integer(0)],
+            )
+            .unwrap();
+
+        assert_eq!(6, value);
+        assert!(connection.as_sqlite().is_some());
+    }
+}
@@ -1,9 +1,8 @@
-use sqlite::Row;
 use thiserror::Error;
 
-use super::Projection;
+use super::{Projection, Row};
 
-/// SqLite hydration error
+/// Entity hydration error
 #[derive(Error, Debug)]
 pub enum HydrationError {
     /// data do not conform to expectations
@@ -11,7 +10,7 @@ pub enum HydrationError {
     InvalidData(String),
 }
 
-/// How to hydrate an entity from a SQLite result row
+/// How to hydrate an entity from a database result row
 pub trait SqLiteEntity {
     /// This method is intended to be used when creating new instances from SQL
     /// result rows. This is the place to grab data, check consistency and types
@@ -2,27 +2,36 @@
 //! This module provides a minimal yet useful Entity framework on top of SQLite
 //! with ability to perform any SQL query possible and hydrate results in Rust
 //! structs.
+//!
+//! The entities and queries can also be used with a PostgreSQL database through a
+//! [DatabaseConnection] when the `postgres` feature is enabled.
+mod backup;
 mod cleaner;
 mod condition;
 mod connection_builder;
 mod connection_extensions;
 mod connection_pool;
 mod cursor;
+mod database_connection;
 mod entity;
 mod projection;
 mod query;
+mod row;
 mod source_alias;
 mod transaction;
 
+pub use backup::SqliteBackup;
 pub use cleaner::{SqliteCleaner, SqliteCleaningTask};
 pub use condition::{GetAllCondition, WhereCondition};
 pub use connection_builder::{ConnectionBuilder, ConnectionOptions};
-pub use connection_extensions::ConnectionExtensions;
-pub use connection_pool::{SqliteConnectionPool, SqlitePooledConnection};
+pub use connection_extensions::{ConnectionExtensions, SingleCellValue};
+pub use connection_pool::{DatabaseConnectionPool, DatabasePooledConnection};
 pub use cursor::EntityCursor;
+pub use database_connection::DatabaseConnection;
 pub use entity::{HydrationError, SqLiteEntity};
 pub use projection::{Projection, ProjectionField};
 pub use query::Query;
+pub use row::{Row, RowIndex};
 pub use source_alias::SourceAlias;
 pub use transaction::Transaction;
 
@@ -45,18 +54,18 @@ mod test {
     use sqlite::Connection;
 
     #[test]
-    fn sqlite_version_should_be_3_42_or_more() {
+    fn sqlite_version_should_be_3_44_or_more() {
         let connection = Connection::open_thread_safe(":memory:").unwrap();
         let mut statement = connection.prepare("select sqlite_version()").unwrap();
         let cursor = statement.iter().next().unwrap().unwrap();
         let db_version = cursor.read::<&str, _>(0);
         let version = semver::Version::parse(db_version)
             .expect("Sqlite version should be parsable to semver");
-        let requirement = semver::VersionReq::parse(">=3.42.0").unwrap();
+        let requirement = semver::VersionReq::parse(">=3.44.0").unwrap();
 
         assert!(
             requirement.matches(&version),
-            "Sqlite version {} is lower than 3.42.0",
+            "Sqlite version {} is lower than 3.44.0",
             version
         )
     }
@@ -21,7 +21,7 @@ mod tests {
     use sqlite::{Connection, Value};
 
     use crate::sqlite::{
-        ConnectionExtensions, GetAllCondition, Projection, SourceAlias, SqliteConnection,
+        ConnectionExtensions, GetAllCondition, Projection, Row, SourceAlias, SqliteConnection,
     };
 
     use super::super::{entity::HydrationError, SqLiteEntity};
@@ -36,7 +36,7 @@ mod tests {
     }
 
     impl SqLiteEntity for TestEntity {
-        fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError> {
+        fn hydrate(row: Row) -> Result<Self, HydrationError> {
             Ok(TestEntity {
                 text_data: row.read::<&str, _>(0).to_string(),
                 real_data: row.read::<f64, _>(1),
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use sqlite::{Error, Result, Value};
+
+/// Result row of a query.
+///
+/// It exposes the same API than [sqlite::Row] but does not depend on the database backend the
+/// row was read from, so entities can be hydrated the same way with all backends.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    column_mapping: Arc<HashMap<String, usize>>,
+    values: Vec<Value>,
+}
+
+/// A type suitable for indexing columns in a [Row].
+pub trait RowIndex: Debug + Display {
+    /// Check if the index is present in a row.
+    fn contains(&self, row: &Row) -> bool;
+
+    /// Identify the ordinal position.
+    ///
+    /// The first column has index 0.
+    fn index(self, row: &Row) -> usize;
+}
+
+impl Row {
+    /// [Row] factory
+    ///
+    /// The `column_mapping` associates the name of the columns to their position in `values`,
+    /// it should be shared between all the rows of a query result.
+    pub fn new(column_mapping: Arc<HashMap<String, usize>>, values: Vec<Value>) -> Self {
+        Self {
+            column_mapping,
+            values,
+        }
+    }
+
+    /// Compute the column mapping shared by the rows of a query result from its column names.
+    pub fn column_mapping<T: AsRef<str>>(column_names: &[T]) -> Arc<HashMap<String, usize>> {
+        Arc::new(
+            column_names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| (name.as_ref().to_string(), index))
+                .collect(),
+        )
+    }
+
+    /// Check if the row contains a column.
+    ///
+    /// In case of integer indices, the first column has index 0.
+    pub fn contains<T: RowIndex>(&self, column: T) -> bool {
+        column.contains(self)
+    }
+
+    /// Read the value in a column.
+    ///
+    /// In case of integer indices, the first column has index 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column can not be read.
+    pub fn read<'l, T, U>(&'l self, column: U) -> T
+    where
+        T: TryFrom<&'l Value, Error = Error>,
+        U: RowIndex,
+    {
+        self.try_read(column).unwrap()
+    }
+
+    /// Take the value from a column.
+    ///
+    /// In case of integer indices, the first column has index 0. Any subsequent invocation will
+    /// result in `Value::Null`.
+    pub fn take<U: RowIndex>(&mut self, column: U) -> Value {
+        let index = column.index(self);
+        std::mem::take(&mut self.values[index])
+    }
+
+    /// Try to read the value in a column.
+    ///
+    /// In case of integer indices, the first column has index 0.
+    pub fn try_read<'l, T, U>(&'l self, column: U) -> Result<T>
+    where
+        T: TryFrom<&'l Value, Error = Error>,
+        U: RowIndex,
+    {
+        if !column.contains(self) {
+            return Err(Error {
+                code: None,
+                message: Some(format!("the index is out of range ({column})")),
+            });
+        }
+        T::try_from(&self.values[column.index(self)])
+    }
+}
+
+impl From<Row> for Vec<Value> {
+    fn from(row: Row) -> Self {
+        row.values
+    }
+}
+
+impl RowIndex for &str {
+    fn contains(&self, row: &Row) -> bool {
+        row.column_mapping.contains_key(*self)
+    }
+
+    fn index(self, row: &Row) -> usize {
+        debug_assert!(RowIndex::contains(&self, row), "the index is out of range");
+        row.column_mapping[self]
+    }
+}
+
+impl RowIndex for usize {
+    fn contains(&self, row: &Row) -> bool {
+        self < &row.values.len()
+    }
+
+    fn index(self, row: &Row) -> usize {
+        debug_assert!(RowIndex::contains(&self, row), "the index is out of range");
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row() -> Row {
+        Row::new(
+            Row::column_mapping(&["text_data", "integer_data", "maybe_null"]),
+            vec![
+                Value::String("text".to_string()),
+                Value::Integer(42),
+                Value::Null,
+            ],
+        )
+    }
+
+    #[test]
+    fn read_columns_by_index_or_by_name() {
+        let row = row();
+
+        assert_eq!("text", row.read::<&str, _>(0));
+        assert_eq!(42, row.read::<i64, _>("integer_data"));
+        assert_eq!(None, row.read::<Option<i64>, _>("maybe_null"));
+    }
+
+    #[test]
+    fn try_read_fails_if_the_column_does_not_exist_or_has_another_type() {
+        let row = row();
+
+        row.try_read::<i64, _>(3)
+            .expect_err("Reading an out of range index should fail");
+        row.try_read::<i64, _>("unknown")
+            .expect_err("Reading an unknown column should fail");
+        row.try_read::<i64, _>("text_data")
+            .expect_err("Reading a text column as an integer should fail");
+    }
+
+    #[test]
+    fn take_a_value_replace_it_by_null() {
+        let mut row = row();
+
+        assert_eq!(Value::Integer(42), row.take(1));
+        assert_eq!(Value::Null, row.take(1));
+    }
+}
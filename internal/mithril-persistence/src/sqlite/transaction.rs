@@ -1,22 +1,42 @@
+use mithril_common::StdResult;
+
+#[cfg(feature = "postgres")]
+use crate::postgres::PostgresConnection;
 use crate::sqlite::SqliteConnection;
 
-/// Sqlite transaction wrapper.
+/// Database transaction wrapper.
 ///
 /// Transactions are automatically rolled back if this struct object is dropped and
 /// the transaction was not committed.
 pub struct Transaction<'a> {
-    connection: &'a SqliteConnection,
+    connection: TransactionConnection<'a>,
     // An active transaction is one that has yet to be committed or rolled back.
     is_active: bool,
 }
 
+enum TransactionConnection<'a> {
+    Sqlite(&'a SqliteConnection),
+    #[cfg(feature = "postgres")]
+    Postgres(&'a PostgresConnection),
+}
+
 impl<'a> Transaction<'a> {
     const BEGIN_TRANSACTION: &'static str = "BEGIN TRANSACTION";
     const COMMIT_TRANSACTION: &'static str = "COMMIT TRANSACTION";
     const ROLLBACK_TRANSACTION: &'static str = "ROLLBACK TRANSACTION";
 
     /// Begin a new transaction.
-    pub fn begin(connection: &'a SqliteConnection) -> Result<Self, sqlite::Error> {
+    pub fn begin(connection: &'a SqliteConnection) -> StdResult<Self> {
+        Self::begin_on(TransactionConnection::Sqlite(connection))
+    }
+
+    /// Begin a new transaction on a PostgreSQL connection.
+    #[cfg(feature = "postgres")]
+    pub fn begin_postgres(connection: &'a PostgresConnection) -> StdResult<Self> {
+        Self::begin_on(TransactionConnection::Postgres(connection))
+    }
+
+    fn begin_on(connection: TransactionConnection<'a>) -> StdResult<Self> {
         connection.execute(Self::BEGIN_TRANSACTION)?;
         Ok(Self {
             connection,
@@ -25,16 +45,16 @@ impl<'a> Transaction<'a> {
     }
 
     /// Commit the transaction.
-    pub fn commit(mut self) -> Result<(), sqlite::Error> {
+    pub fn commit(mut self) -> StdResult<()> {
         self.execute(Self::COMMIT_TRANSACTION)
     }
 
     /// Rollback the transaction.
-    pub fn rollback(mut self) -> Result<(), sqlite::Error> {
+    pub fn rollback(mut self) -> StdResult<()> {
         self.execute(Self::ROLLBACK_TRANSACTION)
     }
 
-    fn execute(&mut self, command: &str) -> Result<(), sqlite::Error> {
+    fn execute(&mut self, command: &str) -> StdResult<()> {
         if self.is_active {
             self.is_active = false;
             self.connection.execute(command)?;
@@ -43,6 +63,19 @@ impl<'a> Transaction<'a> {
     }
 }
 
+impl TransactionConnection<'_> {
+    fn execute(&self, command: &str) -> StdResult<()> {
+        match self {
+            Self::Sqlite(connection) => connection.execute(command)?,
+            #[cfg(feature = "postgres")]
+            Self::Postgres(connection) => {
+                crate::sqlite::ConnectionExtensions::execute(*connection, command)?
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Drop for Transaction<'_> {
     fn drop(&mut self) {
         // Unwrap should not happen here, otherwise it would mean that we have not handled
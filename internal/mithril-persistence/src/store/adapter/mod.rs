@@ -1,11 +1,16 @@
 //! Define a generic way to store data with the [Store Adapter][store_adapter::StoreAdapter], with
-//! an adapter [in memory][MemoryAdapter] and another [sqlite][SQLiteAdapter].
+//! an adapter [in memory][MemoryAdapter], another [sqlite][SQLiteAdapter] and, with the `postgres`
+//! feature, a [PostgreSQL][PostgresAdapter] one.
 
 mod memory_adapter;
+#[cfg(feature = "postgres")]
+mod postgres_adapter;
 mod sqlite_adapter;
 mod store_adapter;
 
 pub use memory_adapter::MemoryAdapter;
+#[cfg(feature = "postgres")]
+pub use postgres_adapter::PostgresAdapter;
 pub use sqlite_adapter::{SQLiteAdapter, SQLiteResultIterator};
 pub use store_adapter::*;
 
@@ -0,0 +1,244 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
+use sqlite::Value;
+use std::{marker::PhantomData, sync::Arc};
+
+use super::{AdapterError, StoreAdapter};
+use crate::postgres::PostgresConnection;
+use crate::sqlite::{ConnectionExtensions, Row};
+
+type Result<T> = std::result::Result<T, AdapterError>;
+
+/// Store adapter for PostgreSQL, the counterpart of the [SQLiteAdapter][super::SQLiteAdapter].
+pub struct PostgresAdapter<K, V> {
+    connection: Arc<PostgresConnection>,
+    table: String,
+    key: PhantomData<K>,
+    value: PhantomData<V>,
+}
+
+impl<K, V> PostgresAdapter<K, V>
+where
+    K: Serialize + DeserializeOwned,
+    V: DeserializeOwned,
+{
+    /// Create a new PostgresAdapter instance, the table is created if it does not exist.
+    pub fn new(table_name: &str, connection: Arc<PostgresConnection>) -> Result<Self> {
+        // The `rowid` column keeps the insertion order, as the SQLite implicit row id does
+        connection
+            .execute(format!(
+                "create table if not exists {table_name} (key_hash text primary key, \
+                key text not null, value text not null, \
+                rowid bigint generated always as identity)"
+            ))
+            .map_err(AdapterError::InitializationError)?;
+
+        Ok(Self {
+            connection,
+            table: table_name.to_owned(),
+            key: PhantomData,
+            value: PhantomData,
+        })
+    }
+
+    fn get_hash_from_key(&self, key: &K) -> Result<String> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.serialize_key(key)?);
+        let checksum = hasher.finalize();
+
+        Ok(hex::encode(checksum))
+    }
+
+    fn serialize_key(&self, key: &K) -> Result<String> {
+        serde_json::to_string(&key).map_err(|e| {
+            AdapterError::GeneralError(
+                anyhow!(e).context("Postgres adapter: Serde error while serializing store key"),
+            )
+        })
+    }
+
+    fn query_for_key(&self, sql: String, key: &K) -> Result<Vec<Row>> {
+        self.connection
+            .query(sql, vec![Value::String(self.get_hash_from_key(key)?)])
+            .map_err(AdapterError::QueryError)
+    }
+
+    fn read_json<T: DeserializeOwned>(row: &Row, column: &str) -> Result<T> {
+        let json = row
+            .try_read::<&str, _>(column)
+            .map_err(|e| AdapterError::ParsingDataError(e.into()))?;
+
+        serde_json::from_str(json).map_err(|e| AdapterError::ParsingDataError(e.into()))
+    }
+
+    fn read_maybe_one_value(rows: Vec<Row>) -> Result<Option<V>> {
+        rows.first()
+            .map(|row| Self::read_json(row, "value"))
+            .transpose()
+    }
+}
+
+#[async_trait]
+impl<K, V> StoreAdapter for PostgresAdapter<K, V>
+where
+    K: Send + Sync + Serialize + DeserializeOwned,
+    V: Send + Sync + Serialize + DeserializeOwned,
+{
+    type Key = K;
+    type Record = V;
+
+    async fn store_record(&mut self, key: &Self::Key, record: &Self::Record) -> Result<()> {
+        let sql = format!(
+            "insert into {} (key_hash, key, value) values (?1, ?2, ?3) on conflict (key_hash) do update set value = excluded.value",
+            self.table
+        );
+        let value = serde_json::to_string(record).map_err(|e| {
+            AdapterError::GeneralError(
+                anyhow!(e)
+                    .context("Postgres adapter error: could not serialize value before insertion"),
+            )
+        })?;
+
+        self.connection
+            .execute_with_params(
+                sql,
+                &[
+                    Value::String(self.get_hash_from_key(key)?),
+                    Value::String(self.serialize_key(key)?),
+                    Value::String(value),
+                ],
+            )
+            .map_err(AdapterError::QueryError)
+    }
+
+    async fn get_record(&self, key: &Self::Key) -> Result<Option<Self::Record>> {
+        let sql = format!("select value from {} where key_hash = ?1", self.table);
+        let rows = self.query_for_key(sql, key)?;
+
+        Self::read_maybe_one_value(rows)
+    }
+
+    async fn record_exists(&self, key: &Self::Key) -> Result<bool> {
+        let sql = format!("select value from {} where key_hash = ?1", self.table);
+
+        Ok(!self.query_for_key(sql, key)?.is_empty())
+    }
+
+    async fn get_last_n_records(&self, how_many: usize) -> Result<Vec<(Self::Key, Self::Record)>> {
+        let sql = format!(
+            "select key, value from {} order by rowid desc limit ?1",
+            self.table
+        );
+        let rows = self
+            .connection
+            .query(sql, vec![Value::Integer(how_many as i64)])
+            .map_err(AdapterError::QueryError)?;
+
+        rows.iter()
+            .map(|row| Ok((Self::read_json(row, "key")?, Self::read_json(row, "value")?)))
+            .collect()
+    }
+
+    async fn remove(&mut self, key: &Self::Key) -> Result<Option<Self::Record>> {
+        let sql = format!(
+            "delete from {} where key_hash = ?1 returning value",
+            self.table
+        );
+        let rows = self.query_for_key(sql, key)?;
+
+        Self::read_maybe_one_value(rows)
+    }
+
+    async fn get_iter(&self) -> Result<Box<dyn Iterator<Item = Self::Record> + '_>> {
+        let sql = format!("select value from {} order by rowid desc", self.table);
+        let rows = self
+            .connection
+            .query(sql, vec![])
+            .map_err(AdapterError::QueryError)?;
+        let records = rows
+            .iter()
+            .map(|row| Self::read_json(row, "value"))
+            .collect::<Result<Vec<V>>>()?;
+
+        Ok(Box::new(records.into_iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::test_utils::{test_database_url, unique_test_schema};
+    use crate::postgres::PostgresConnectionBuilder;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "key_value_store";
+
+    /// Adapter on a new schema of the test database, `None` if no test database is configured.
+    fn init_db() -> Option<PostgresAdapter<u64, String>> {
+        let url = test_database_url()?;
+        let connection = PostgresConnectionBuilder::open(&url)
+            .with_schema(&unique_test_schema())
+            .build()
+            .unwrap();
+
+        Some(PostgresAdapter::new(TABLE_NAME, Arc::new(connection)).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_store_and_get_record() {
+        let Some(mut adapter) = init_db() else {
+            return;
+        };
+        adapter.store_record(&1, &"one".to_string()).await.unwrap();
+        adapter.store_record(&2, &"two".to_string()).await.unwrap();
+        adapter.store_record(&1, &"zwei".to_string()).await.unwrap();
+
+        assert_eq!(
+            Some("zwei".to_string()),
+            adapter.get_record(&1).await.unwrap()
+        );
+        assert_eq!(
+            Some("two".to_string()),
+            adapter.get_record(&2).await.unwrap()
+        );
+        assert_eq!(None, adapter.get_record(&3).await.unwrap());
+        assert!(adapter.record_exists(&1).await.unwrap());
+        assert!(!adapter.record_exists(&3).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_last_n_records_and_iterator_in_reverse_insertion_order() {
+        let Some(mut adapter) = init_db() else {
+            return;
+        };
+        adapter.store_record(&1, &"one".to_string()).await.unwrap();
+        adapter.store_record(&2, &"two".to_string()).await.unwrap();
+        adapter
+            .store_record(&3, &"three".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![(3, "three".to_string()), (2, "two".to_string())],
+            adapter.get_last_n_records(2).await.unwrap()
+        );
+        assert_eq!(
+            vec!["three".to_string(), "two".to_string(), "one".to_string()],
+            adapter.get_iter().await.unwrap().collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remove() {
+        let Some(mut adapter) = init_db() else {
+            return;
+        };
+        adapter.store_record(&1, &"one".to_string()).await.unwrap();
+
+        assert_eq!(Some("one".to_string()), adapter.remove(&1).await.unwrap());
+        assert_eq!(None, adapter.remove(&1).await.unwrap());
+        assert_eq!(None, adapter.get_record(&1).await.unwrap());
+    }
+}
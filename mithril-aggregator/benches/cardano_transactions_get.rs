@@ -7,7 +7,7 @@ use mithril_aggregator::services::TransactionStore;
 use mithril_common::entities::{BlockNumber, CardanoTransaction, SlotNumber};
 use mithril_common::test_utils::TempDir;
 use mithril_persistence::database::repository::CardanoTransactionRepository;
-use mithril_persistence::sqlite::{ConnectionBuilder, SqliteConnectionPool};
+use mithril_persistence::sqlite::{ConnectionBuilder, DatabaseConnectionPool};
 
 fn cardano_tx_db_connection(db_file_name: &str) -> ConnectionThreadSafe {
     let db_path =
@@ -46,7 +46,7 @@ async fn init_db(nb_transaction_in_db: usize) -> CardanoTransactionRepository {
     let transactions = generate_transactions(nb_transaction_in_db);
     let connection = cardano_tx_db_connection(&format!("cardano_tx-{nb_transaction_in_db}.db",));
     let repository = CardanoTransactionRepository::new(Arc::new(
-        SqliteConnectionPool::build_from_connection(connection),
+        DatabaseConnectionPool::build_from_connection(connection),
     ));
     repository.store_transactions(transactions).await.unwrap();
 
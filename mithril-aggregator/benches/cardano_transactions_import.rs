@@ -1,11 +1,16 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use sqlite::ConnectionThreadSafe;
+use std::path::Path;
 use std::sync::Arc;
 
-use mithril_common::entities::{BlockNumber, CardanoTransaction, SlotNumber};
-use mithril_common::test_utils::TempDir;
+use mithril_aggregator::services::CardanoTransactionsImporter;
+use mithril_common::cardano_block_scanner::{DumbBlockScanner, ScannedBlock};
+use mithril_common::entities::{BlockNumber, BlockRange, CardanoTransaction, SlotNumber};
+use mithril_common::signable_builder::TransactionsImporter;
+use mithril_common::test_utils::{CardanoTransactionsBuilder, TempDir};
+use mithril_common::StdResult;
 use mithril_persistence::database::repository::CardanoTransactionRepository;
-use mithril_persistence::sqlite::{ConnectionBuilder, SqliteConnectionPool};
+use mithril_persistence::sqlite::{ConnectionBuilder, ConnectionOptions, DatabaseConnectionPool};
 
 fn cardano_tx_db_connection() -> ConnectionThreadSafe {
     let db_path =
@@ -46,7 +51,7 @@ fn bench_store_transactions(c: &mut Criterion) {
         bencher.to_async(&runtime).iter(|| async {
             let connection = cardano_tx_db_connection();
             let repository = CardanoTransactionRepository::new(Arc::new(
-                SqliteConnectionPool::build_from_connection(connection),
+                DatabaseConnectionPool::build_from_connection(connection),
             ));
             repository.store_transactions(transactions.clone()).await
         });
@@ -55,9 +60,90 @@ fn bench_store_transactions(c: &mut Criterion) {
     group.finish();
 }
 
+fn open_cardano_tx_db(db_path: &Path) -> StdResult<ConnectionThreadSafe> {
+    // The workers read the database while the importer writes to it, as in the aggregator
+    ConnectionBuilder::open_file(db_path)
+        .with_options(&[ConnectionOptions::EnableWriteAheadLog])
+        .with_migrations(
+            mithril_persistence::database::cardano_transaction_migration::get_migrations(),
+        )
+        .build()
+}
+
+/// Group the transactions of a synthetic chain by block, as a block scanner would yield them.
+fn generate_scanned_blocks(transactions: Vec<CardanoTransaction>) -> Vec<ScannedBlock> {
+    let mut blocks: Vec<ScannedBlock> = vec![];
+    for transaction in transactions {
+        match blocks.last_mut() {
+            Some(block) if block.block_number == transaction.block_number => {
+                block.transactions_hashes.push(transaction.transaction_hash);
+            }
+            _ => blocks.push(ScannedBlock::new(
+                transaction.block_hash,
+                transaction.block_number,
+                transaction.slot_number,
+                vec![transaction.transaction_hash],
+            )),
+        }
+    }
+
+    blocks
+}
+
+fn bench_import_transactions(c: &mut Criterion) {
+    const NB_BLOCK_RANGES: usize = 2_000;
+    const MAX_ROLL_FORWARDS_PER_POLL: usize = 1_000;
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let blocks = generate_scanned_blocks(
+        CardanoTransactionsBuilder::new()
+            .max_transactions_per_block(20)
+            .blocks_per_block_range(*BlockRange::LENGTH as usize)
+            .build_block_ranges(NB_BLOCK_RANGES),
+    );
+    let up_to_block_number = blocks.last().unwrap().block_number;
+
+    let mut group = c.benchmark_group("Import transactions");
+    for workers in [1, 2, 4, 8] {
+        group.bench_with_input(
+            BenchmarkId::new("block_range_roots_workers", workers),
+            &workers,
+            |bencher, &workers| {
+                bencher.to_async(&runtime).iter(|| async {
+                    let db_dir_name = format!("bench_import_transactions_{workers}_workers");
+                    let db_path = TempDir::create("aggregator_benches", db_dir_name.as_str())
+                        .join("cardano_tx.db");
+                    if db_path.exists() {
+                        std::fs::remove_file(&db_path).unwrap();
+                    }
+                    let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+                        DatabaseConnectionPool::build(workers + 1, || open_cardano_tx_db(&db_path))
+                            .unwrap(),
+                    )));
+                    let block_scanner = DumbBlockScanner::new().forwards(
+                        blocks
+                            .chunks(MAX_ROLL_FORWARDS_PER_POLL)
+                            .map(|chunk| chunk.to_vec())
+                            .collect(),
+                    );
+                    let importer = CardanoTransactionsImporter::new(
+                        Arc::new(block_scanner),
+                        repository,
+                        slog::Logger::root(slog::Discard, slog::o!()),
+                    )
+                    .with_block_range_roots_workers(workers);
+
+                    importer.import(up_to_block_number).await
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(10);
-    targets = bench_store_transactions
+    targets = bench_store_transactions, bench_import_transactions
 }
 criterion_main!(benches);
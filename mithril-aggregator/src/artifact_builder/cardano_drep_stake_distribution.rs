@@ -0,0 +1,101 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use mithril_common::{
+    entities::{CardanoDRepStakeDistribution, Certificate, Epoch},
+    signable_builder::DRepStakeDistributionRetriever,
+    StdResult,
+};
+
+use crate::ArtifactBuilder;
+
+/// A [CardanoDRepStakeDistribution] artifact builder
+pub struct CardanoDRepStakeDistributionArtifactBuilder {
+    drep_stake_distribution_retriever: Arc<dyn DRepStakeDistributionRetriever>,
+}
+
+impl CardanoDRepStakeDistributionArtifactBuilder {
+    /// CardanoDRepStakeDistribution artifact builder factory
+    pub fn new(drep_stake_distribution_retriever: Arc<dyn DRepStakeDistributionRetriever>) -> Self {
+        Self {
+            drep_stake_distribution_retriever,
+        }
+    }
+}
+
+#[async_trait]
+impl ArtifactBuilder<Epoch, CardanoDRepStakeDistribution>
+    for CardanoDRepStakeDistributionArtifactBuilder
+{
+    async fn compute_artifact(
+        &self,
+        epoch: Epoch,
+        _certificate: &Certificate,
+    ) -> StdResult<CardanoDRepStakeDistribution> {
+        let drep_stake_distribution = self
+            .drep_stake_distribution_retriever
+            .retrieve(epoch)
+            .await?
+            .ok_or_else(|| anyhow!("No DRep stake distribution found for epoch '{}'", epoch))?;
+
+        Ok(CardanoDRepStakeDistribution::new(
+            epoch,
+            drep_stake_distribution,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::{entities::DRepStakeDistribution, test_utils::fake_data};
+    use mockall::{mock, predicate::eq};
+
+    use super::*;
+
+    mock! {
+        pub DRepStakeDistributionRetrieverImpl {}
+
+        #[async_trait]
+        impl DRepStakeDistributionRetriever for DRepStakeDistributionRetrieverImpl {
+            async fn retrieve(&self, epoch: Epoch) -> StdResult<Option<DRepStakeDistribution>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn compute_artifact_returns_valid_artifact_and_retrieve_without_epoch_offset() {
+        let epoch = Epoch(1);
+        let certificate = fake_data::certificate("whatever".to_string());
+        let drep_stake_distribution = DRepStakeDistribution::from([("drep-123".to_string(), 123)]);
+        let drep_stake_distribution_clone = drep_stake_distribution.clone();
+        let mut mock_retriever = MockDRepStakeDistributionRetrieverImpl::new();
+        mock_retriever
+            .expect_retrieve()
+            .with(eq(epoch))
+            .return_once(move |_| Ok(Some(drep_stake_distribution_clone)));
+        let builder = CardanoDRepStakeDistributionArtifactBuilder::new(Arc::new(mock_retriever));
+
+        let cardano_drep_stake_distribution =
+            builder.compute_artifact(epoch, &certificate).await.unwrap();
+
+        let expected = CardanoDRepStakeDistribution::new(epoch, drep_stake_distribution);
+        assert_eq!(cardano_drep_stake_distribution, expected);
+    }
+
+    #[tokio::test]
+    async fn compute_artifact_returns_error_if_no_drep_stakes_found_for_epoch() {
+        let epoch = Epoch(1);
+        let certificate = fake_data::certificate("whatever".to_string());
+        let mut mock_retriever = MockDRepStakeDistributionRetrieverImpl::new();
+        mock_retriever
+            .expect_retrieve()
+            .with(eq(epoch))
+            .return_once(move |_| Ok(None));
+        let builder = CardanoDRepStakeDistributionArtifactBuilder::new(Arc::new(mock_retriever));
+
+        builder
+            .compute_artifact(epoch, &certificate)
+            .await
+            .expect_err("Should return error");
+    }
+}
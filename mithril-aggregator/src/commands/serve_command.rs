@@ -7,9 +7,10 @@ use std::time::Duration;
 use std::{net::IpAddr, path::PathBuf};
 use tokio::{sync::oneshot, task::JoinSet};
 
-use crate::{dependency_injection::DependenciesBuilder, Configuration};
-
-const SQLITE_MONITORING_FILE: &str = "monitoring.sqlite3";
+use crate::{
+    dependency_injection::{DependenciesBuilder, SQLITE_MONITORING_FILE},
+    Configuration,
+};
 
 /// Server runtime mode
 #[derive(Parser, Debug, Clone)]
@@ -111,15 +112,38 @@ impl ServeCommand {
                 .unwrap()
         });
 
+        // start the leader election, the leadership is refreshed well before the lease expires
+        let leader_election_service = dependencies_builder
+            .get_leader_election_service()
+            .await
+            .with_context(|| "Dependencies Builder can not create leader election service")?;
+        let mut join_set = JoinSet::new();
+        if config.is_high_availability_enabled() {
+            // elect the leader before starting the services that only run on the leader
+            if let Err(error) = leader_election_service.refresh_leadership().await {
+                warn!("Failed to refresh the aggregator leadership"; "error" => ?error);
+            }
+            let refresh_interval =
+                Duration::from_millis(config.high_availability_leader_lease_duration) / 3;
+            let leader_election_service = leader_election_service.clone();
+            join_set.spawn(async move {
+                loop {
+                    if let Err(error) = leader_election_service.refresh_leadership().await {
+                        warn!("Failed to refresh the aggregator leadership"; "error" => ?error);
+                    }
+                    tokio::time::sleep(refresh_interval).await;
+                }
+            });
+        }
+
         // start the aggregator runtime
         let mut runtime = dependencies_builder
             .create_aggregator_runner()
             .await
             .with_context(|| "Dependencies Builder can not create aggregator runner")?;
-        let mut join_set = JoinSet::new();
         join_set.spawn(async move { runtime.run().await.map_err(|e| e.to_string()) });
 
-        // start the cardano transactions preloader
+        // start the cardano transactions preloader, it only preloads on the leader
         let cardano_transactions_preloader = dependencies_builder
             .create_cardano_transactions_preloader()
             .await
@@ -194,6 +218,10 @@ impl ServeCommand {
             preload_task.abort();
         }
 
+        if let Err(error) = leader_election_service.release_leadership().await {
+            warn!("Failed to release the aggregator leadership"; "error" => ?error);
+        }
+
         info!("Event store is finishing...");
         event_store_thread.await.unwrap();
         println!("Services stopped, exiting.");
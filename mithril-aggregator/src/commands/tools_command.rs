@@ -1,15 +1,16 @@
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use clap::{Parser, Subcommand};
 use config::{builder::DefaultState, ConfigBuilder};
 use mithril_common::StdResult;
+use mithril_persistence::database::CardanoTransactionsIntegrityChecker;
 use mithril_persistence::sqlite::{SqliteCleaner, SqliteCleaningTask};
 use slog_scope::debug;
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use crate::{
     database::repository::{CertificateRepository, SignedEntityStore},
     dependency_injection::DependenciesBuilder,
-    tools::CertificatesHashMigrator,
+    tools::{CertificatesHashMigrator, DatabaseBackupTools},
     Configuration,
 };
 
@@ -36,12 +37,33 @@ pub enum ToolsSubCommand {
     /// Since it will modify the aggregator sqlite database it's strongly recommended to backup it
     /// before running this command.
     RecomputeCertificatesHash(RecomputeCertificatesHashCommand),
+
+    /// Check that the block range roots stored in the Cardano transactions database are the ones
+    /// computed from the stored transactions.
+    ///
+    /// Reports the roots that don't match their transactions, the block ranges with transactions
+    /// but without a root (gaps) and the roots without transactions (orphans).
+    CheckCardanoTransactions(CheckCardanoTransactionsCommand),
+
+    /// Backup the aggregator databases in a gzipped tar archive.
+    ///
+    /// The backup can be done while the aggregator is running.
+    Backup(BackupCommand),
+
+    /// Restore the aggregator databases from an archive made by the `backup` command.
+    ///
+    /// The versions of the databases of the archive are checked before they replace the
+    /// current ones. The aggregator must be stopped while the databases are restored.
+    Restore(RestoreCommand),
 }
 
 impl ToolsSubCommand {
     pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> StdResult<()> {
         match self {
             Self::RecomputeCertificatesHash(cmd) => cmd.execute(config_builder).await,
+            Self::CheckCardanoTransactions(cmd) => cmd.execute(config_builder).await,
+            Self::Backup(cmd) => cmd.execute(config_builder).await,
+            Self::Restore(cmd) => cmd.execute(config_builder).await,
         }
     }
 }
@@ -61,7 +83,7 @@ impl RecomputeCertificatesHashCommand {
         println!("Recomputing all certificate hash",);
         let mut dependencies_builder = DependenciesBuilder::new(config.clone());
         let connection = dependencies_builder
-            .get_sqlite_connection()
+            .get_database_connection()
             .await
             .with_context(|| "Dependencies Builder can not get sqlite connection")?;
         let migrator = CertificatesHashMigrator::new(
@@ -74,10 +96,141 @@ impl RecomputeCertificatesHashCommand {
             .await
             .with_context(|| "recompute-certificates-hash: database migration error")?;
 
-        SqliteCleaner::new(&connection)
-            .with_tasks(&[SqliteCleaningTask::Vacuum])
-            .run()
-            .with_context(|| "recompute-certificates-hash: database vacuum error")?;
+        if let Some(connection) = connection.as_sqlite() {
+            SqliteCleaner::new(connection)
+                .with_tasks(&[SqliteCleaningTask::Vacuum])
+                .run()
+                .with_context(|| "recompute-certificates-hash: database vacuum error")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Check Cardano transactions command.
+#[derive(Parser, Debug, Clone)]
+pub struct CheckCardanoTransactionsCommand {
+    /// Replace the inconsistent block range roots by the ones computed from the stored
+    /// transactions.
+    ///
+    /// Since it will modify the Cardano transactions database it's strongly recommended to
+    /// backup it before running this command.
+    #[clap(long)]
+    repair: bool,
+}
+
+impl CheckCardanoTransactionsCommand {
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> StdResult<()> {
+        let config: Configuration = config_builder
+            .build()
+            .with_context(|| "configuration build error")?
+            .try_deserialize()
+            .with_context(|| "configuration deserialize error")?;
+        debug!("CHECK CARDANO TRANSACTIONS command"; "config" => format!("{config:?}"));
+        println!("Checking the Cardano transactions block range roots");
+        let mut dependencies_builder = DependenciesBuilder::new(config.clone());
+        let transaction_repository = dependencies_builder
+            .get_transaction_repository()
+            .await
+            .with_context(|| "Dependencies Builder can not get transaction repository")?;
+        let checker = CardanoTransactionsIntegrityChecker::new(transaction_repository)
+            .with_extended_mode(config.enable_cardano_transactions_extended_mode)
+            .with_logger(slog_scope::logger());
+
+        let report = checker
+            .check()
+            .await
+            .with_context(|| "check-cardano-transactions: integrity check error")?;
+        println!("{report}");
+
+        if report.is_valid() {
+            println!("The Cardano transactions block range roots are consistent");
+        } else if self.repair {
+            checker
+                .repair(&report)
+                .await
+                .with_context(|| "check-cardano-transactions: repair error")?;
+            println!("The Cardano transactions block range roots have been repaired");
+        } else {
+            return Err(anyhow!(
+                "The Cardano transactions block range roots are inconsistent, run the command with `--repair` to fix them"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Backup databases command.
+#[derive(Parser, Debug, Clone)]
+pub struct BackupCommand {
+    /// Path of the archive to create.
+    #[clap(long)]
+    archive: PathBuf,
+}
+
+impl BackupCommand {
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> StdResult<()> {
+        let config: Configuration = config_builder
+            .build()
+            .with_context(|| "configuration build error")?
+            .try_deserialize()
+            .with_context(|| "configuration deserialize error")?;
+        debug!("BACKUP command"; "config" => format!("{config:?}"));
+        if config.database_url.is_some() {
+            return Err(anyhow!(
+                "The backup command only supports the SQLite databases, use the PostgreSQL tools to backup the database"
+            ));
+        }
+        println!(
+            "Backing up the aggregator databases to '{}'",
+            self.archive.display()
+        );
+
+        let manifest = DatabaseBackupTools::new(config.get_sqlite_dir())
+            .backup(&self.archive)
+            .with_context(|| "backup: database backup error")?;
+        for database in &manifest.databases {
+            println!("Backed up database '{}'", database.file_name);
+        }
+
+        Ok(())
+    }
+}
+
+/// Restore databases command.
+#[derive(Parser, Debug, Clone)]
+pub struct RestoreCommand {
+    /// Path of the archive made by the `backup` command.
+    #[clap(long)]
+    archive: PathBuf,
+}
+
+impl RestoreCommand {
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> StdResult<()> {
+        let config: Configuration = config_builder
+            .build()
+            .with_context(|| "configuration build error")?
+            .try_deserialize()
+            .with_context(|| "configuration deserialize error")?;
+        debug!("RESTORE command"; "config" => format!("{config:?}"));
+        if config.database_url.is_some() {
+            return Err(anyhow!(
+                "The restore command only supports the SQLite databases, use the PostgreSQL tools to restore the database"
+            ));
+        }
+        println!(
+            "Restoring the aggregator databases from '{}'",
+            self.archive.display()
+        );
+
+        let manifest = DatabaseBackupTools::new(config.get_sqlite_dir())
+            .restore(&self.archive)
+            .with_context(|| "restore: database restore error")?;
+        println!(
+            "Restored the databases backed up on {} by aggregator version {}",
+            manifest.created_at, manifest.aggregator_version
+        );
 
         Ok(())
     }
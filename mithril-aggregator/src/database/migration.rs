@@ -804,5 +804,288 @@ pragma foreign_key_check;
 pragma foreign_keys=true;
         "#,
         ),
+        // Migration 29
+        // Add the `leader_lock` table, used to elect the leader of aggregators sharing the same database
+        SqlMigration::new(
+            29,
+            r#"
+create table leader_lock (
+    leader_lock_id  integer     not null check (leader_lock_id = 1),
+    holder          text        not null,
+    expires_at      text        not null,
+    primary key (leader_lock_id)
+);
+"#,
+        ),
+        // Migration 30
+        // Add the `signed_entity_type` record for 'CardanoDRepStakeDistribution'
+        SqlMigration::new(
+            30,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (4, 'Cardano DRep Stake Distribution');
+"#,
+        ),
+    ]
+}
+
+/// Get all the migrations required by this version of the software for a PostgreSQL database.
+///
+/// The PostgreSQL backend came after the SQLite one: its first migration creates at once the
+/// schema reached by the SQLite [migrations][get_migrations] at the same version. The following
+/// migrations must be added to both lists with the same version.
+pub fn get_postgres_migrations() -> Vec<SqlMigration> {
+    vec![
+        // Migration 29
+        // Create the schema of the SQLite database at version 29.
+        // The `rowid` columns replace the SQLite implicit row id used to sort by insertion order.
+        SqlMigration::new(
+            29,
+            r#"
+create table signed_entity_type (
+    signed_entity_type_id       bigint      not null,
+    name                        text        not null,
+    primary key (signed_entity_type_id)
+);
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (0, 'Mithril Stake Distribution'),
+            (1, 'Cardano Stake Distribution'),
+            (2, 'Full Cardano Immutable Files'),
+            (3, 'Cardano Transactions');
+
+create table epoch_setting (
+    epoch_setting_id                    bigint      not null,
+    protocol_parameters                 text        not null,
+    cardano_transactions_signing_config text        not null,
+    primary key (epoch_setting_id)
+);
+
+create table signer (
+    signer_id                   text        not null,
+    pool_ticker                 text,
+    created_at                  text        not null,
+    updated_at                  text        not null,
+    last_registered_at          text,
+    rowid                       bigint      generated always as identity,
+    primary key (signer_id)
+);
+
+create table signer_registration (
+    signer_id                   text        not null,
+    epoch_setting_id            bigint      not null,
+    verification_key            text        not null,
+    verification_key_signature  text,
+    operational_certificate     text,
+    kes_period                  bigint,
+    stake                       bigint,
+    created_at                  text        not null,
+    rowid                       bigint      generated always as identity,
+    primary key (epoch_setting_id, signer_id),
+    foreign key (epoch_setting_id) references epoch_setting(epoch_setting_id),
+    foreign key (signer_id) references signer(signer_id)
+);
+create index signer_registration_epoch_setting_id_index on signer_registration(epoch_setting_id);
+create index signer_registration_signer_id_index on signer_registration(signer_id);
+
+create table open_message (
+    open_message_id             text        not null,
+    epoch_setting_id            bigint      not null,
+    beacon                      text        not null,
+    signed_entity_type_id       bigint      not null,
+    created_at                  text        not null,
+    protocol_message            text        not null,
+    is_certified                boolean     not null default false,
+    is_expired                  boolean     not null default false,
+    expires_at                  text,
+    rowid                       bigint      generated always as identity,
+    primary key (open_message_id),
+    foreign key (epoch_setting_id) references epoch_setting(epoch_setting_id),
+    foreign key (signed_entity_type_id) references signed_entity_type(signed_entity_type_id)
+);
+create unique index open_message_unique_index on open_message(signed_entity_type_id, beacon);
+create index open_message_epoch_setting_id_index on open_message(epoch_setting_id);
+create index open_message_signed_entity_type_id_index on open_message(signed_entity_type_id);
+
+create table single_signature (
+    open_message_id                 text        not null,
+    signer_id                       text        not null,
+    registration_epoch_setting_id   bigint      not null,
+    lottery_indexes                 text        not null,
+    signature                       text        not null,
+    created_at                      text        not null,
+    primary key (open_message_id, signer_id, registration_epoch_setting_id),
+    foreign key (open_message_id) references open_message(open_message_id) on delete cascade,
+    foreign key (signer_id, registration_epoch_setting_id) references signer_registration(signer_id, epoch_setting_id)
+);
+create index single_signature_open_message_id_index on single_signature(open_message_id);
+create index single_signature_signer_id_index on single_signature(signer_id);
+create index single_signature_registration_epoch_setting_id_index on single_signature(registration_epoch_setting_id);
+
+create table stake_pool (
+    stake_pool_id               text        not null,
+    epoch                       bigint      not null,
+    stake                       bigint      not null,
+    created_at                  text        not null,
+    primary key (epoch, stake_pool_id)
+);
+
+create table certificate (
+    certificate_id              text        not null,
+    parent_certificate_id       text,
+    message                     text        not null,
+    signature                   text        not null,
+    aggregate_verification_key  text        not null,
+    epoch                       bigint      not null,
+    network                     text        not null,
+    immutable_file_number       bigint      not null,
+    signed_entity_type_id       bigint      not null,
+    signed_entity_beacon        text        not null,
+    protocol_version            text        not null,
+    protocol_parameters         text        not null,
+    protocol_message            text        not null,
+    signers                     text        not null,
+    initiated_at                text        not null,
+    sealed_at                   text        not null,
+    rowid                       bigint      generated always as identity,
+    primary key (certificate_id),
+    foreign key (parent_certificate_id) references certificate(certificate_id),
+    foreign key (signed_entity_type_id) references signed_entity_type(signed_entity_type_id)
+);
+create index epoch_index on certificate(epoch);
+create index certificate_parent_certificate_id_index on certificate(parent_certificate_id);
+
+create table signed_entity (
+    signed_entity_id            text        not null,
+    signed_entity_type_id       bigint      not null,
+    certificate_id              text        not null,
+    beacon                      text        not null,
+    created_at                  text        not null,
+    artifact                    text        not null,
+    rowid                       bigint      generated always as identity,
+    primary key (signed_entity_id),
+    foreign key (signed_entity_type_id) references signed_entity_type(signed_entity_type_id),
+    foreign key (certificate_id) references certificate(certificate_id)
+);
+create unique index signed_entity_unique_index on signed_entity(signed_entity_type_id, beacon);
+create index signed_entity_signed_entity_type_id_index on signed_entity(signed_entity_type_id);
+create index signed_entity_certificate_id_index on signed_entity(certificate_id);
+
+create table buffered_single_signature (
+    signed_entity_type_id           bigint      not null,
+    party_id                        text        not null,
+    lottery_indexes                 text        not null,
+    signature                       text        not null,
+    created_at                      text        not null,
+    rowid                           bigint      generated always as identity,
+    primary key (signed_entity_type_id, party_id)
+);
+create index buffered_single_signature_signed_entity_type_id on buffered_single_signature(signed_entity_type_id);
+create index buffered_single_signature_party_id_index on buffered_single_signature(party_id);
+
+create table pending_certificate (
+    key_hash                    text        not null,
+    key                         text        not null,
+    value                       text        not null,
+    rowid                       bigint      generated always as identity,
+    primary key (key_hash)
+);
+
+create table leader_lock (
+    leader_lock_id              bigint      not null check (leader_lock_id = 1),
+    holder                      text        not null,
+    expires_at                  text        not null,
+    primary key (leader_lock_id)
+);
+"#,
+        ),
+        // Migration 30
+        // Add the `signed_entity_type` record for 'CardanoDRepStakeDistribution'
+        SqlMigration::new(
+            30,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (4, 'Cardano DRep Stake Distribution');
+"#,
+        ),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mithril_common::entities::{ProtocolMessage, SignedEntityConfig, TimePoint};
+    use mithril_persistence::sqlite::{ConnectionBuilder, ConnectionOptions, DatabaseConnection};
+
+    use crate::database::repository::OpenMessageRepository;
+    use crate::database::test_helper::insert_epoch_settings;
+
+    use super::*;
+
+    #[test]
+    fn postgres_migrations_reach_the_version_of_the_sqlite_migrations() {
+        let last_version =
+            |migrations: Vec<SqlMigration>| migrations.iter().map(|m| m.version).max();
+
+        assert_eq!(
+            last_version(get_migrations()),
+            last_version(get_postgres_migrations())
+        );
+    }
+
+    async fn assert_open_messages_of_all_signed_entity_types_can_be_stored(
+        connection: Arc<DatabaseConnection>,
+    ) {
+        let time_point = TimePoint::dummy();
+        insert_epoch_settings(&connection, &[*time_point.epoch]).unwrap();
+        let signed_entity_config = SignedEntityConfig::dummy();
+        let repository = OpenMessageRepository::new(connection);
+
+        for discriminant in SignedEntityTypeDiscriminants::all() {
+            let signed_entity_type = signed_entity_config
+                .time_point_to_signed_entity(discriminant, &time_point)
+                .unwrap();
+
+            repository
+                .create_open_message(
+                    time_point.epoch,
+                    &signed_entity_type,
+                    &ProtocolMessage::new(),
+                )
+                .await
+                .unwrap_or_else(|e| {
+                    panic!("open message for {discriminant:?} should be stored: {e:?}")
+                });
+        }
+    }
+
+    #[tokio::test]
+    async fn open_messages_of_all_signed_entity_types_can_be_stored_with_foreign_keys_enabled() {
+        let connection = ConnectionBuilder::open_memory()
+            .with_options(&[ConnectionOptions::EnableForeignKeys])
+            .with_migrations(get_migrations())
+            .build()
+            .unwrap();
+
+        assert_open_messages_of_all_signed_entity_types_can_be_stored(Arc::new(connection.into()))
+            .await;
+    }
+
+    #[cfg(feature = "postgres")]
+    #[tokio::test]
+    async fn open_messages_of_all_signed_entity_types_can_be_stored_in_postgres() {
+        use mithril_persistence::postgres::{test_utils, PostgresConnectionBuilder};
+
+        let Some(url) = test_utils::test_database_url() else {
+            return;
+        };
+        let connection = PostgresConnectionBuilder::open(&url)
+            .with_schema(&test_utils::unique_test_schema())
+            .with_migrations(get_postgres_migrations())
+            .build()
+            .unwrap();
+
+        assert_open_messages_of_all_signed_entity_types_can_be_stored(Arc::new(connection.into()))
+            .await;
+    }
+}
@@ -0,0 +1,150 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlite::Value;
+
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::LeaderLockRecord;
+
+/// Query to acquire, or renew, the leader lock in the sqlite database
+///
+/// The lock is acquired only if it's free, expired, or already held by the same holder, in which
+/// case its expiration date is extended. No record is returned if the lock is held by another
+/// holder.
+pub struct AcquireLeaderLockQuery {
+    condition: WhereCondition,
+}
+
+impl AcquireLeaderLockQuery {
+    pub fn acquire(holder: &str, now: DateTime<Utc>, expires_at: DateTime<Utc>) -> Self {
+        // Dates are stored with a fixed precision so they can be compared as strings
+        let condition = WhereCondition::new(
+            "(leader_lock_id, holder, expires_at) values (1, ?*, ?*) \
+            on conflict (leader_lock_id) do update set holder = excluded.holder, expires_at = excluded.expires_at \
+            where leader_lock.holder = excluded.holder or leader_lock.expires_at < ?*",
+            vec![
+                Value::String(holder.to_string()),
+                Value::String(expires_at.to_rfc3339_opts(SecondsFormat::Micros, true)),
+                Value::String(now.to_rfc3339_opts(SecondsFormat::Micros, true)),
+            ],
+        );
+
+        Self { condition }
+    }
+}
+
+impl Query for AcquireLeaderLockQuery {
+    type Entity = LeaderLockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection()
+            .expand(SourceAlias::new(&[("{:leader_lock:}", "leader_lock")]));
+
+        format!("insert into leader_lock {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn acquire_free_lock() {
+        let connection = main_db_connection().unwrap();
+        let now = Utc::now();
+
+        let record = connection
+            .fetch_first(AcquireLeaderLockQuery::acquire(
+                "aggregator-1",
+                now,
+                now + Duration::seconds(30),
+            ))
+            .unwrap();
+
+        assert_eq!(Some("aggregator-1".to_string()), record.map(|r| r.holder));
+    }
+
+    #[test]
+    fn renew_lock_held_by_the_same_holder() {
+        let connection = main_db_connection().unwrap();
+        let now = Utc::now();
+        connection
+            .fetch_first(AcquireLeaderLockQuery::acquire(
+                "aggregator-1",
+                now,
+                now + Duration::seconds(30),
+            ))
+            .unwrap();
+
+        let record = connection
+            .fetch_first(AcquireLeaderLockQuery::acquire(
+                "aggregator-1",
+                now + Duration::seconds(10),
+                now + Duration::seconds(40),
+            ))
+            .unwrap()
+            .expect("Lock should be renewed");
+
+        assert_eq!("aggregator-1", record.holder);
+        assert_eq!(
+            (now + Duration::seconds(40)).timestamp_micros(),
+            record.expires_at.timestamp_micros()
+        );
+    }
+
+    #[test]
+    fn cant_acquire_lock_held_by_another_holder() {
+        let connection = main_db_connection().unwrap();
+        let now = Utc::now();
+        connection
+            .fetch_first(AcquireLeaderLockQuery::acquire(
+                "aggregator-1",
+                now,
+                now + Duration::seconds(30),
+            ))
+            .unwrap();
+
+        let record = connection
+            .fetch_first(AcquireLeaderLockQuery::acquire(
+                "aggregator-2",
+                now + Duration::seconds(10),
+                now + Duration::seconds(40),
+            ))
+            .unwrap();
+
+        assert_eq!(None, record);
+    }
+
+    #[test]
+    fn acquire_expired_lock_held_by_another_holder() {
+        let connection = main_db_connection().unwrap();
+        let now = Utc::now();
+        connection
+            .fetch_first(AcquireLeaderLockQuery::acquire(
+                "aggregator-1",
+                now,
+                now + Duration::seconds(30),
+            ))
+            .unwrap();
+
+        let record = connection
+            .fetch_first(AcquireLeaderLockQuery::acquire(
+                "aggregator-2",
+                now + Duration::seconds(31),
+                now + Duration::seconds(61),
+            ))
+            .unwrap();
+
+        assert_eq!(Some("aggregator-2".to_string()), record.map(|r| r.holder));
+    }
+}
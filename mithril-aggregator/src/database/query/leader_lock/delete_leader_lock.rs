@@ -0,0 +1,70 @@
+use sqlite::Value;
+
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::LeaderLockRecord;
+
+/// Query to release the leader lock from the sqlite database
+pub struct DeleteLeaderLockQuery {
+    condition: WhereCondition,
+}
+
+impl DeleteLeaderLockQuery {
+    pub fn held_by(holder: &str) -> Self {
+        Self {
+            condition: WhereCondition::new("holder = ?*", vec![Value::String(holder.to_string())]),
+        }
+    }
+}
+
+impl Query for DeleteLeaderLockQuery {
+    type Entity = LeaderLockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:leader_lock:}", "leader_lock")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("delete from leader_lock where {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::query::AcquireLeaderLockQuery;
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn release_lock_only_if_held_by_the_given_holder() {
+        let connection = main_db_connection().unwrap();
+        let now = Utc::now();
+        connection
+            .fetch_first(AcquireLeaderLockQuery::acquire(
+                "aggregator-1",
+                now,
+                now + Duration::seconds(30),
+            ))
+            .unwrap();
+
+        let deleted_record = connection
+            .fetch_first(DeleteLeaderLockQuery::held_by("aggregator-2"))
+            .unwrap();
+        assert_eq!(None, deleted_record);
+
+        let deleted_record = connection
+            .fetch_first(DeleteLeaderLockQuery::held_by("aggregator-1"))
+            .unwrap();
+        assert_eq!(
+            Some("aggregator-1".to_string()),
+            deleted_record.map(|r| r.holder)
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod acquire_leader_lock;
+mod delete_leader_lock;
+
+pub use acquire_leader_lock::*;
+pub use delete_leader_lock::*;
@@ -2,6 +2,7 @@
 mod buffered_single_signature;
 mod certificate;
 mod epoch_settings;
+mod leader_lock;
 mod open_message;
 mod signed_entity;
 mod signer;
@@ -12,6 +13,7 @@ mod stake_pool;
 pub use buffered_single_signature::*;
 pub use certificate::*;
 pub use epoch_settings::*;
+pub use leader_lock::*;
 pub use open_message::*;
 pub use signed_entity::*;
 pub use signer::*;
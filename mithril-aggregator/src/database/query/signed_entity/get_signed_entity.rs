@@ -73,6 +73,19 @@ impl GetSignedEntityRecordQuery {
             ),
         }
     }
+
+    pub fn cardano_drep_stake_distribution_by_epoch(epoch: Epoch) -> Self {
+        let signed_entity_type_id =
+            SignedEntityTypeDiscriminants::CardanoDRepStakeDistribution.index() as i64;
+        let epoch = *epoch as i64;
+
+        Self {
+            condition: WhereCondition::new(
+                "signed_entity_type_id = ?* and beacon = ?*",
+                vec![Value::Integer(signed_entity_type_id), Value::Integer(epoch)],
+            ),
+        }
+    }
 }
 
 impl Query for GetSignedEntityRecordQuery {
@@ -98,8 +111,7 @@ mod tests {
         entities::{CardanoDbBeacon, SignedEntityType},
         test_utils::fake_data,
     };
-    use mithril_persistence::sqlite::ConnectionExtensions;
-    use sqlite::ConnectionThreadSafe;
+    use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 
     use crate::database::test_helper::{insert_signed_entities, main_db_connection};
 
@@ -107,7 +119,7 @@ mod tests {
 
     fn create_database_with_cardano_stake_distributions<T: Into<SignedEntityRecord>>(
         cardano_stake_distributions: Vec<T>,
-    ) -> (ConnectionThreadSafe, Vec<SignedEntityRecord>) {
+    ) -> (DatabaseConnection, Vec<SignedEntityRecord>) {
         let records = cardano_stake_distributions
             .into_iter()
             .map(|cardano_stake_distribution| cardano_stake_distribution.into())
@@ -118,7 +130,7 @@ mod tests {
         (connection, records)
     }
 
-    fn create_database(records: &[SignedEntityRecord]) -> ConnectionThreadSafe {
+    fn create_database(records: &[SignedEntityRecord]) -> DatabaseConnection {
         let connection = main_db_connection().unwrap();
         insert_signed_entities(&connection, records.to_vec()).unwrap();
         connection
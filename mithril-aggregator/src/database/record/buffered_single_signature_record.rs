@@ -4,7 +4,7 @@ use mithril_common::entities::{
     HexEncodedSingleSignature, LotteryIndex, SignedEntityTypeDiscriminants, SingleSignatures,
 };
 use mithril_common::{StdError, StdResult};
-use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 /// `BufferedSingleSignatureRecord` record is the representation of a buffered single_signature
 /// that may be used for upcoming open messages.
@@ -93,7 +93,7 @@ impl TryFrom<BufferedSingleSignatureRecord> for SingleSignatures {
 }
 
 impl SqLiteEntity for BufferedSingleSignatureRecord {
-    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
         Self: Sized,
     {
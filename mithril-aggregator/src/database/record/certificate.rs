@@ -14,7 +14,7 @@ use mithril_common::messages::{
 use mithril_common::test_utils::{fake_data, fake_keys};
 use mithril_persistence::{
     database::Hydrator,
-    sqlite::{HydrationError, Projection, SqLiteEntity},
+    sqlite::{HydrationError, Projection, Row, SqLiteEntity},
 };
 
 era_deprecate!("Remove immutable_file_number");
@@ -303,7 +303,7 @@ impl From<CertificateRecord> for CertificateListItemMessage {
 }
 
 impl SqLiteEntity for CertificateRecord {
-    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
         Self: Sized,
     {
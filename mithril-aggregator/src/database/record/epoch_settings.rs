@@ -1,5 +1,5 @@
 use mithril_common::entities::{CardanoTransactionsSigningConfig, Epoch, ProtocolParameters};
-use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 use crate::entities::AggregatorEpochSettings;
 
@@ -26,7 +26,7 @@ impl From<EpochSettingsRecord> for AggregatorEpochSettings {
 }
 
 impl SqLiteEntity for EpochSettingsRecord {
-    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
         Self: Sized,
     {
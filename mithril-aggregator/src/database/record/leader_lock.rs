@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
+
+/// Leader lock record is the representation of the lock held by the leader of the aggregators
+/// sharing the same database.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LeaderLockRecord {
+    /// Identifier of the aggregator holding the lock
+    pub holder: String,
+
+    /// Date and time after which the lock can be acquired by another aggregator
+    pub expires_at: DateTime<Utc>,
+}
+
+impl SqLiteEntity for LeaderLockRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let holder = row.read::<&str, _>(0).to_string();
+        let expires_at = row.read::<&str, _>(1);
+
+        let record = Self {
+            holder,
+            expires_at: DateTime::parse_from_rfc3339(expires_at)
+                .map_err(|e| {
+                    HydrationError::InvalidData(format!(
+                        "Could not turn string '{expires_at}' to rfc3339 Datetime. Error: {e}"
+                    ))
+                })?
+                .with_timezone(&Utc),
+        };
+
+        Ok(record)
+    }
+
+    fn get_projection() -> Projection {
+        let mut projection = Projection::default();
+        projection.add_field("holder", "{:leader_lock:}.holder", "text");
+        projection.add_field("expires_at", "{:leader_lock:}.expires_at", "text");
+
+        projection
+    }
+}
@@ -3,6 +3,7 @@
 mod buffered_single_signature_record;
 mod certificate;
 mod epoch_settings;
+mod leader_lock;
 mod open_message;
 mod open_message_with_single_signatures;
 mod signed_entity;
@@ -14,6 +15,7 @@ mod stake_pool;
 pub use buffered_single_signature_record::*;
 pub use certificate::*;
 pub use epoch_settings::*;
+pub use leader_lock::*;
 pub use open_message::*;
 pub use open_message_with_single_signatures::*;
 pub use signed_entity::*;
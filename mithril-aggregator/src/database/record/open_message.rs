@@ -1,10 +1,9 @@
 use chrono::{DateTime, Utc};
-use sqlite::Row;
 use uuid::Uuid;
 
 use mithril_common::entities::{Epoch, ProtocolMessage, SignedEntityType};
 use mithril_persistence::database::Hydrator;
-use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 /// ## OpenMessage
 ///
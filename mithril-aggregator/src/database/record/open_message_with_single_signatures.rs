@@ -1,9 +1,8 @@
 use chrono::{DateTime, Utc};
-use sqlite::Row;
 use uuid::Uuid;
 
 use mithril_common::entities::{Epoch, ProtocolMessage, SignedEntityType, SingleSignatures};
-use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 use crate::database::record::OpenMessageRecord;
 
@@ -112,7 +111,7 @@ impl SqLiteEntity for OpenMessageWithSingleSignaturesRecord {
             ("expires_at", "{:open_message:}.expires_at", "text"),
             (
                 "single_signatures",
-                "case when {:single_signature:}.signer_id is null then json('[]') \
+                "case when count({:single_signature:}.signer_id) = 0 then json('[]') \
 else json_group_array( \
     json_object( \
         'party_id', {:single_signature:}.signer_id, \
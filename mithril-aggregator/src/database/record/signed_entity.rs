@@ -3,11 +3,15 @@ use serde::{Deserialize, Serialize};
 
 use mithril_common::crypto_helper::ProtocolParameters;
 use mithril_common::entities::{
-    BlockNumber, Epoch, SignedEntity, SignedEntityType, Snapshot, StakeDistribution,
+    BlockNumber, DRepStakeDistribution, Epoch, SignedEntity, SignedEntityType, Snapshot,
+    StakeDistribution,
 };
 #[cfg(test)]
-use mithril_common::entities::{CardanoStakeDistribution, MithrilStakeDistribution};
+use mithril_common::entities::{
+    CardanoDRepStakeDistribution, CardanoStakeDistribution, MithrilStakeDistribution,
+};
 use mithril_common::messages::{
+    CardanoDRepStakeDistributionListItemMessage, CardanoDRepStakeDistributionMessage,
     CardanoStakeDistributionListItemMessage, CardanoStakeDistributionMessage,
     CardanoTransactionSnapshotListItemMessage, CardanoTransactionSnapshotMessage,
     MithrilStakeDistributionListItemMessage, MithrilStakeDistributionMessage,
@@ -16,7 +20,7 @@ use mithril_common::messages::{
 use mithril_common::signable_builder::Artifact;
 use mithril_common::StdError;
 use mithril_persistence::database::Hydrator;
-use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 /// SignedEntity record is the representation of a stored signed_entity.
 #[derive(Debug, PartialEq, Clone)]
@@ -44,6 +48,13 @@ impl From<CardanoStakeDistribution> for SignedEntityRecord {
     }
 }
 
+#[cfg(test)]
+impl From<CardanoDRepStakeDistribution> for SignedEntityRecord {
+    fn from(cardano_drep_stake_distribution: CardanoDRepStakeDistribution) -> Self {
+        SignedEntityRecord::from_cardano_drep_stake_distribution(cardano_drep_stake_distribution)
+    }
+}
+
 #[cfg(test)]
 impl From<MithrilStakeDistribution> for SignedEntityRecord {
     fn from(mithril_stake_distribution: MithrilStakeDistribution) -> Self {
@@ -99,6 +110,24 @@ impl SignedEntityRecord {
         }
     }
 
+    pub(crate) fn from_cardano_drep_stake_distribution(
+        cardano_drep_stake_distribution: CardanoDRepStakeDistribution,
+    ) -> Self {
+        let entity = serde_json::to_string(&cardano_drep_stake_distribution).unwrap();
+
+        SignedEntityRecord {
+            signed_entity_id: cardano_drep_stake_distribution.hash.clone(),
+            signed_entity_type: SignedEntityType::CardanoDRepStakeDistribution(
+                cardano_drep_stake_distribution.epoch,
+            ),
+            certificate_id: format!("certificate-{}", cardano_drep_stake_distribution.hash),
+            artifact: entity,
+            created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+
     pub(crate) fn fake_records(number_if_records: usize) -> Vec<SignedEntityRecord> {
         use mithril_common::test_utils::fake_data;
 
@@ -328,8 +357,50 @@ impl TryFrom<SignedEntityRecord> for CardanoStakeDistributionListItemMessage {
     }
 }
 
+impl TryFrom<SignedEntityRecord> for CardanoDRepStakeDistributionMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoDRepStakeDistribution {
+            hash: String,
+            drep_stake_distribution: DRepStakeDistribution,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoDRepStakeDistribution>(&value.artifact)?;
+        let cardano_drep_stake_distribution_message = CardanoDRepStakeDistributionMessage {
+            epoch: value.signed_entity_type.get_epoch(),
+            drep_stake_distribution: artifact.drep_stake_distribution,
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(cardano_drep_stake_distribution_message)
+    }
+}
+
+impl TryFrom<SignedEntityRecord> for CardanoDRepStakeDistributionListItemMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoDRepStakeDistribution {
+            hash: String,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoDRepStakeDistribution>(&value.artifact)?;
+        let message = CardanoDRepStakeDistributionListItemMessage {
+            epoch: value.signed_entity_type.get_epoch(),
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
 impl SqLiteEntity for SignedEntityRecord {
-    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
         Self: Sized,
     {
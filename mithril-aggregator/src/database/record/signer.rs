@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 
-use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 /// Signer record is the representation of a stored signer.
 #[derive(Debug, PartialEq, Clone)]
@@ -45,7 +45,7 @@ impl SignerRecord {
 }
 
 impl SqLiteEntity for SignerRecord {
-    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
         Self: Sized,
     {
@@ -5,7 +5,7 @@ use mithril_common::entities::{
     Epoch, HexEncodedOpCert, HexEncodedVerificationKey, HexEncodedVerificationKeySignature, Signer,
     SignerWithStake, Stake,
 };
-use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 /// SignerRegistration record is the representation of a stored signer_registration.
 #[derive(Debug, PartialEq, Clone)]
@@ -88,7 +88,7 @@ impl From<SignerRegistrationRecord> for SignerWithStake {
 }
 
 impl SqLiteEntity for SignerRegistrationRecord {
-    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
         Self: Sized,
     {
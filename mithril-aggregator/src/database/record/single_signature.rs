@@ -3,7 +3,7 @@ use uuid::Uuid;
 
 use mithril_common::entities::{Epoch, HexEncodedSingleSignature, LotteryIndex, SingleSignatures};
 use mithril_common::{StdError, StdResult};
-use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 /// SingleSignature record is the representation of a stored single_signature.
 #[derive(Debug, PartialEq, Clone)]
@@ -62,7 +62,7 @@ impl TryFrom<SingleSignatureRecord> for SingleSignatures {
 }
 
 impl SqLiteEntity for SingleSignatureRecord {
-    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
         Self: Sized,
     {
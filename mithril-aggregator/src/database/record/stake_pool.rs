@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 
 use mithril_common::entities::{Epoch, PartyId};
-use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+use mithril_persistence::sqlite::{HydrationError, Projection, Row, SqLiteEntity};
 
 /// Stake pool as read from Chain.
 #[derive(Debug, PartialEq)]
@@ -20,7 +20,7 @@ pub struct StakePool {
 }
 
 impl SqLiteEntity for StakePool {
-    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
         Self: Sized,
     {
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use mithril_common::entities::{SignedEntityTypeDiscriminants, SingleSignatures};
 use mithril_common::{StdError, StdResult};
-use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 
 use crate::database::query::{
     DeleteBufferedSingleSignatureQuery, GetBufferedSingleSignatureQuery,
@@ -15,12 +15,12 @@ use crate::services::BufferedSingleSignatureStore;
 
 /// An implementation of [BufferedSingleSignatureStore] that uses a SQLite database.
 pub struct BufferedSingleSignatureRepository {
-    connection: Arc<SqliteConnection>,
+    connection: Arc<DatabaseConnection>,
 }
 
 impl BufferedSingleSignatureRepository {
     /// Creates a new [BufferedSingleSignatureRepository] instance.
-    pub fn new(connection_pool: Arc<SqliteConnection>) -> Self {
+    pub fn new(connection_pool: Arc<DatabaseConnection>) -> Self {
         Self {
             connection: connection_pool,
         }
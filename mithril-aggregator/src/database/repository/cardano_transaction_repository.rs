@@ -4,7 +4,8 @@ use async_trait::async_trait;
 
 use mithril_common::crypto_helper::MKTreeNode;
 use mithril_common::entities::{
-    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber, TransactionHash,
+    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, ExtendedCardanoTransaction,
+    SlotNumber, TransactionHash,
 };
 use mithril_common::StdResult;
 use mithril_persistence::database::repository::CardanoTransactionRepository;
@@ -26,6 +27,13 @@ impl TransactionStore for CardanoTransactionRepository {
         self.store_transactions(transactions).await
     }
 
+    async fn store_extended_transactions(
+        &self,
+        transactions: Vec<ExtendedCardanoTransaction>,
+    ) -> StdResult<()> {
+        self.store_extended_transactions(transactions).await
+    }
+
     async fn get_transactions_in_range(
         &self,
         range: Range<BlockNumber>,
@@ -37,6 +45,13 @@ impl TransactionStore for CardanoTransactionRepository {
         })
     }
 
+    async fn get_extended_transactions_in_range(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<ExtendedCardanoTransaction>> {
+        self.get_extended_transactions_in_range_blocks(range).await
+    }
+
     async fn store_block_range_roots(
         &self,
         block_ranges: Vec<(BlockRange, MKTreeNode)>,
@@ -84,4 +99,12 @@ impl TransactionsRetriever for CardanoTransactionRepository {
                     .collect::<Vec<CardanoTransaction>>()
             })
     }
+
+    async fn get_extended_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<ExtendedCardanoTransaction>> {
+        self.get_extended_transaction_by_block_ranges(block_ranges)
+            .await
+    }
 }
@@ -2,12 +2,11 @@ use std::sync::Arc;
 
 use anyhow::anyhow;
 use async_trait::async_trait;
-use sqlite::ConnectionThreadSafe;
 
 use mithril_common::certificate_chain::{CertificateRetriever, CertificateRetrieverError};
 use mithril_common::entities::{Certificate, Epoch};
 use mithril_common::StdResult;
-use mithril_persistence::sqlite::ConnectionExtensions;
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 
 use crate::database::query::{
     DeleteCertificateQuery, GetCertificateRecordQuery, InsertCertificateRecordQuery,
@@ -17,12 +16,12 @@ use crate::database::record::CertificateRecord;
 
 /// Database frontend API for Certificate queries.
 pub struct CertificateRepository {
-    connection: Arc<ConnectionThreadSafe>,
+    connection: Arc<DatabaseConnection>,
 }
 
 impl CertificateRepository {
     /// Instantiate a new repository
-    pub fn new(connection: Arc<ConnectionThreadSafe>) -> Self {
+    pub fn new(connection: Arc<DatabaseConnection>) -> Self {
         Self { connection }
     }
 
@@ -135,7 +134,7 @@ mod tests {
 
     use super::*;
 
-    fn insert_golden_certificate(connection: &ConnectionThreadSafe) {
+    fn insert_golden_certificate(connection: &DatabaseConnection) {
         connection
             .execute(r#"
             -- genesis certificate
@@ -240,7 +239,7 @@ mod tests {
         let (certificates, _) = setup_certificate_chain(5, 2);
         let expected_hash = certificates[0].hash.clone();
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         insert_certificate_records(&connection, certificates.clone());
 
         let repository: CertificateRepository = CertificateRepository::new(connection);
@@ -263,7 +262,7 @@ mod tests {
     async fn repository_get_latest_certificates() {
         let (certificates, _) = setup_certificate_chain(5, 2);
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         insert_certificate_records(&connection, certificates.clone());
 
         let repository = CertificateRepository::new(connection);
@@ -279,7 +278,7 @@ mod tests {
     #[tokio::test]
     async fn get_master_certificate_no_certificate_recorded_returns_none() {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
 
         let repository: CertificateRepository = CertificateRepository::new(connection);
         let certificate = repository
@@ -293,7 +292,7 @@ mod tests {
     #[tokio::test]
     async fn get_master_certificate_one_cert_in_current_epoch_recorded_returns_that_one() {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let certificate = CertificateRecord::dummy_genesis("1", Epoch(1), 1);
         let expected_certificate: Certificate = certificate.clone().into();
         insert_certificate_records(&connection, vec![certificate]);
@@ -312,7 +311,7 @@ mod tests {
     async fn get_master_certificate_multiple_cert_in_current_epoch_returns_first_of_current_epoch()
     {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let certificates = vec![
             CertificateRecord::dummy_genesis("1", Epoch(1), 1),
             CertificateRecord::dummy_db_snapshot("2", "1", Epoch(1), 2),
@@ -335,7 +334,7 @@ mod tests {
     async fn get_master_certificate_multiple_cert_in_previous_epoch_none_in_the_current_returns_first_of_previous_epoch(
     ) {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let certificates = vec![
             CertificateRecord::dummy_genesis("1", Epoch(1), 1),
             CertificateRecord::dummy_db_snapshot("2", "1", Epoch(1), 2),
@@ -358,7 +357,7 @@ mod tests {
     async fn get_master_certificate_multiple_cert_in_previous_one_cert_in_current_epoch_returns_one_in_current_epoch(
     ) {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let certificates = vec![
             CertificateRecord::dummy_genesis("1", Epoch(1), 1),
             CertificateRecord::dummy_db_snapshot("2", "1", Epoch(1), 2),
@@ -382,7 +381,7 @@ mod tests {
     async fn get_master_certificate_multiple_cert_in_previous_multiple_in_current_epoch_returns_first_of_current_epoch(
     ) {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let certificates = vec![
             CertificateRecord::dummy_genesis("1", Epoch(1), 1),
             CertificateRecord::dummy_db_snapshot("2", "1", Epoch(1), 2),
@@ -407,7 +406,7 @@ mod tests {
     async fn get_master_certificate_multiple_cert_in_penultimate_epoch_none_in_previous_returns_none(
     ) {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let certificates = vec![
             CertificateRecord::dummy_genesis("1", Epoch(1), 1),
             CertificateRecord::dummy_db_snapshot("2", "1", Epoch(1), 2),
@@ -428,7 +427,7 @@ mod tests {
     async fn get_master_certificate_second_genesis_after_multiple_cert_in_current_epoch_returns_last_genesis(
     ) {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let certificates = vec![
             CertificateRecord::dummy_genesis("1", Epoch(1), 1),
             CertificateRecord::dummy_db_snapshot("2", "1", Epoch(1), 2),
@@ -452,7 +451,7 @@ mod tests {
     async fn get_master_certificate_second_genesis_after_multiple_cert_in_multiple_epochs_returns_last_genesis(
     ) {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let certificates = vec![
             CertificateRecord::dummy_genesis("1", Epoch(1), 1),
             CertificateRecord::dummy_db_snapshot("2", "1", Epoch(1), 2),
@@ -478,7 +477,7 @@ mod tests {
     async fn get_master_certificate_new_genesis_after_multiple_cert_in_previous_epoch_returns_last_genesis(
     ) {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let certificates = vec![
             CertificateRecord::dummy_genesis("1", Epoch(1), 1),
             CertificateRecord::dummy_db_snapshot("2", "1", Epoch(1), 2),
@@ -504,7 +503,7 @@ mod tests {
         let expected_certificate_id = &certificates[2].hash;
         let epoch = &certificates[2].epoch;
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         insert_certificate_records(&connection, certificates.clone());
 
         let repository: CertificateRepository = CertificateRepository::new(connection);
@@ -521,7 +520,7 @@ mod tests {
     async fn save_certificate() {
         let (certificates, _) = setup_certificate_chain(5, 3);
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let repository: CertificateRepository = CertificateRepository::new(connection);
         let certificate = repository
             .create_certificate(certificates[4].clone())
@@ -530,7 +529,7 @@ mod tests {
 
         assert_eq!(certificates[4].hash, certificate.hash);
         {
-            let connection = deps.get_sqlite_connection().await.unwrap();
+            let connection = deps.get_database_connection().await.unwrap();
             let cert = connection
                 .fetch_first(GetCertificateRecordQuery::by_certificate_id(
                     &certificates[4].hash,
@@ -545,7 +544,7 @@ mod tests {
     #[tokio::test]
     async fn delete_only_given_certificates() {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
-        let connection = deps.get_sqlite_connection().await.unwrap();
+        let connection = deps.get_database_connection().await.unwrap();
         let repository = CertificateRepository::new(connection.clone());
         let records = vec![
             CertificateRecord::dummy_genesis("1", Epoch(1), 1),
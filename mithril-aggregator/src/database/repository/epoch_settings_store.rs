@@ -4,9 +4,8 @@ use async_trait::async_trait;
 
 use mithril_common::entities::{CardanoTransactionsSigningConfig, Epoch, ProtocolParameters};
 use mithril_common::StdResult;
-use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 use mithril_persistence::store::adapter::AdapterError;
-use sqlite::Value;
 
 use crate::database::query::{
     DeleteEpochSettingsQuery, GetEpochSettingsQuery, UpdateEpochSettingsQuery,
@@ -16,7 +15,7 @@ use crate::EpochSettingsStorer;
 
 /// Service to deal with epoch settings (read & write).
 pub struct EpochSettingsStore {
-    connection: Arc<SqliteConnection>,
+    connection: Arc<DatabaseConnection>,
 
     /// Number of epochs before previous records will be pruned at the next call to
     /// [save_protocol_parameters][EpochSettingStore::save_protocol_parameters].
@@ -25,7 +24,7 @@ pub struct EpochSettingsStore {
 
 impl EpochSettingsStore {
     /// Create a new EpochSettings store
-    pub fn new(connection: Arc<SqliteConnection>, retention_limit: Option<u64>) -> Self {
+    pub fn new(connection: Arc<DatabaseConnection>, retention_limit: Option<u64>) -> Self {
         Self {
             connection,
             retention_limit,
@@ -41,17 +40,12 @@ impl EpochSettingsStore {
         let query = r#"
             update epoch_setting 
             set cardano_transactions_signing_config = ?
-            where cardano_transactions_signing_config == '{}'"#;
+            where cardano_transactions_signing_config = '{}'"#;
 
-        let mut statement = self.connection.prepare(query)?;
-        statement.bind::<&[(_, Value)]>(&[(
-            1,
-            serde_json::to_string(&cardano_signing_config)?.into(),
-        )])?;
-
-        statement.next()?;
-
-        Ok(())
+        self.connection.execute_with_params(
+            query,
+            &[serde_json::to_string(&cardano_signing_config)?.into()],
+        )
     }
 }
 
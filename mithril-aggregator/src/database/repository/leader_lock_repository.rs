@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
+
+use crate::database::query::{AcquireLeaderLockQuery, DeleteLeaderLockQuery};
+use crate::services::LeaderLockStore;
+
+/// An implementation of [LeaderLockStore] that uses a SQLite database.
+pub struct LeaderLockRepository {
+    connection: Arc<DatabaseConnection>,
+}
+
+impl LeaderLockRepository {
+    /// Creates a new [LeaderLockRepository] instance.
+    pub fn new(connection: Arc<DatabaseConnection>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl LeaderLockStore for LeaderLockRepository {
+    async fn acquire_or_renew(
+        &self,
+        holder: &str,
+        now: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> StdResult<bool> {
+        let record = self
+            .connection
+            .fetch_first(AcquireLeaderLockQuery::acquire(holder, now, expires_at))?;
+
+        Ok(record.is_some_and(|r| r.holder == holder))
+    }
+
+    async fn release(&self, holder: &str) -> StdResult<()> {
+        self.connection
+            .fetch_first(DeleteLeaderLockQuery::held_by(holder))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn only_one_holder_can_hold_the_lock_until_it_expires_or_is_released() {
+        let repository = LeaderLockRepository::new(Arc::new(main_db_connection().unwrap()));
+        let now = Utc::now();
+        let lease = Duration::seconds(30);
+
+        assert!(repository
+            .acquire_or_renew("aggregator-1", now, now + lease)
+            .await
+            .unwrap());
+        assert!(!repository
+            .acquire_or_renew("aggregator-2", now, now + lease)
+            .await
+            .unwrap());
+
+        repository.release("aggregator-1").await.unwrap();
+
+        assert!(repository
+            .acquire_or_renew("aggregator-2", now, now + lease)
+            .await
+            .unwrap());
+        assert!(!repository
+            .acquire_or_renew("aggregator-1", now, now + lease)
+            .await
+            .unwrap());
+    }
+}
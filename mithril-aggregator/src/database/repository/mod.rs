@@ -3,6 +3,7 @@ mod buffered_single_signature_repository;
 mod cardano_transaction_repository;
 mod certificate_repository;
 mod epoch_settings_store;
+mod leader_lock_repository;
 mod open_message_repository;
 mod signed_entity_store;
 mod signer_registration_store;
@@ -13,6 +14,7 @@ mod stake_pool_store;
 pub use buffered_single_signature_repository::*;
 pub use certificate_repository::*;
 pub use epoch_settings_store::*;
+pub use leader_lock_repository::*;
 pub use open_message_repository::*;
 pub use signed_entity_store::*;
 pub use signer_registration_store::*;
@@ -4,7 +4,7 @@ use chrono::Utc;
 
 use mithril_common::entities::{Epoch, ProtocolMessage, SignedEntityType};
 use mithril_common::StdResult;
-use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 
 use crate::database::query::{
     DeleteOpenMessageQuery, GetOpenMessageQuery, GetOpenMessageWithSingleSignaturesQuery,
@@ -17,12 +17,12 @@ use crate::database::record::{OpenMessageRecord, OpenMessageWithSingleSignatures
 /// This is a business oriented layer to perform actions on the database through
 /// queries.
 pub struct OpenMessageRepository {
-    connection: Arc<SqliteConnection>,
+    connection: Arc<DatabaseConnection>,
 }
 
 impl OpenMessageRepository {
     /// Instantiate service
-    pub fn new(connection: Arc<SqliteConnection>) -> Self {
+    pub fn new(connection: Arc<DatabaseConnection>) -> Self {
         Self { connection }
     }
 
@@ -114,13 +114,13 @@ mod tests {
 
     use super::*;
 
-    async fn get_connection() -> Arc<SqliteConnection> {
+    async fn get_connection() -> Arc<DatabaseConnection> {
         let connection = main_db_connection().unwrap();
         insert_epoch_settings(&connection, &[1, 2]).unwrap();
         Arc::new(connection)
     }
 
-    fn insert_golden_open_message_with_signature(connection: &SqliteConnection) {
+    fn insert_golden_open_message_with_signature(connection: &DatabaseConnection) {
         connection
             .execute(
                 r#"
@@ -133,8 +133,8 @@ mod tests {
                     '{ "message_parts": {
                         "next_aggregate_verification_key":"7b226d745f636f6d6d69746d656e74223a7b22726f6f74223a5b3131312c3230352c3133392c3131322c32382c392c3233382c3134382c3133342c302c3230372c3233302c3234312c3130352c3135372c3131302c3232362c3131342c32362c35332c3136362c3235342c3230382c3132372c3231362c3230362c3230302c34382c35352c32312c3231372c31335d2c226e725f6c6561766573223a332c22686173686572223a6e756c6c7d2c22746f74616c5f7374616b65223a32383439323639303636317d"
                     }}',
-                    1,
-                    0,
+                    true,
+                    false,
                     '2021-07-27T01:02:44.505640275+00:00'
                 );
 
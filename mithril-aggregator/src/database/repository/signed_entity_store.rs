@@ -5,7 +5,7 @@ use async_trait::async_trait;
 
 use mithril_common::entities::{Epoch, SignedEntityTypeDiscriminants};
 use mithril_common::StdResult;
-use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 
 use crate::database::query::{
     GetSignedEntityRecordQuery, InsertSignedEntityRecordQuery, UpdateSignedEntityQuery,
@@ -50,6 +50,12 @@ pub trait SignedEntityStorer: Sync + Send {
         epoch: Epoch,
     ) -> StdResult<Option<SignedEntityRecord>>;
 
+    /// Get Cardano DRep stake distribution signed entity by epoch
+    async fn get_cardano_drep_stake_distribution_signed_entity_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<Option<SignedEntityRecord>>;
+
     /// Perform an update for all the given signed entities.
     async fn update_signed_entities(
         &self,
@@ -59,12 +65,12 @@ pub trait SignedEntityStorer: Sync + Send {
 
 /// Service to deal with signed_entity (read & write).
 pub struct SignedEntityStore {
-    connection: Arc<SqliteConnection>,
+    connection: Arc<DatabaseConnection>,
 }
 
 impl SignedEntityStore {
     /// Create a new SignedEntityStoreAdapter service
-    pub fn new(connection: Arc<SqliteConnection>) -> Self {
+    pub fn new(connection: Arc<DatabaseConnection>) -> Self {
         Self { connection }
     }
 }
@@ -141,6 +147,15 @@ impl SignedEntityStorer for SignedEntityStore {
             .fetch_first(GetSignedEntityRecordQuery::cardano_stake_distribution_by_epoch(epoch))
     }
 
+    async fn get_cardano_drep_stake_distribution_signed_entity_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<Option<SignedEntityRecord>> {
+        self.connection.fetch_first(
+            GetSignedEntityRecordQuery::cardano_drep_stake_distribution_by_epoch(epoch),
+        )
+    }
+
     async fn update_signed_entities(
         &self,
         signed_entities: Vec<SignedEntityRecord>,
@@ -173,7 +188,7 @@ mod tests {
 
     use super::*;
 
-    fn insert_golden_signed_entities(connection: &SqliteConnection) {
+    fn insert_golden_signed_entities(connection: &DatabaseConnection) {
         connection
             .execute(r#"
             -- Cardano immutable file full
@@ -360,4 +375,41 @@ mod tests {
 
         assert_eq!(Some(expected_record), record);
     }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_signed_entity_by_epoch_when_nothing_found() {
+        let connection = main_db_connection().unwrap();
+        let store = SignedEntityStore::new(Arc::new(connection));
+
+        let record = store
+            .get_cardano_drep_stake_distribution_signed_entity_by_epoch(Epoch(4))
+            .await
+            .unwrap();
+
+        assert_eq!(None, record);
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_signed_entity_by_epoch_when_signed_entity_found_for_epoch(
+    ) {
+        let cardano_drep_stake_distribution = fake_data::cardano_drep_stake_distribution(Epoch(4));
+        let cardano_stake_distribution = fake_data::cardano_stake_distribution(Epoch(4));
+
+        let expected_record: SignedEntityRecord = cardano_drep_stake_distribution.into();
+
+        let connection = main_db_connection().unwrap();
+        insert_signed_entities(
+            &connection,
+            vec![cardano_stake_distribution.into(), expected_record.clone()],
+        )
+        .unwrap();
+        let store = SignedEntityStore::new(Arc::new(connection));
+
+        let record = store
+            .get_cardano_drep_stake_distribution_signed_entity_by_epoch(Epoch(4))
+            .await
+            .unwrap();
+
+        assert_eq!(Some(expected_record), record);
+    }
 }
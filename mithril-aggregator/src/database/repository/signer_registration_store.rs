@@ -6,7 +6,7 @@ use async_trait::async_trait;
 
 use mithril_common::entities::{Epoch, PartyId, Signer, SignerWithStake};
 use mithril_common::StdResult;
-use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 use mithril_persistence::store::adapter::AdapterError;
 
 use crate::database::query::{
@@ -18,12 +18,12 @@ use crate::VerificationKeyStorer;
 
 /// Service to deal with signer_registration (read & write).
 pub struct SignerRegistrationStore {
-    connection: Arc<SqliteConnection>,
+    connection: Arc<DatabaseConnection>,
 }
 
 impl SignerRegistrationStore {
     /// Create a new [SignerRegistrationStore] service
-    pub fn new(connection: Arc<SqliteConnection>) -> Self {
+    pub fn new(connection: Arc<DatabaseConnection>) -> Self {
         Self { connection }
     }
 }
@@ -117,7 +117,7 @@ mod tests {
 
     use super::*;
 
-    fn insert_golden_signer_registration(connection: &SqliteConnection) {
+    fn insert_golden_signer_registration(connection: &DatabaseConnection) {
         connection
             .execute(
                 r#"
@@ -7,7 +7,7 @@ use chrono::Utc;
 use mockall::automock;
 
 use mithril_common::StdResult;
-use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 
 use crate::database::query::{
     GetSignerRecordQuery, ImportSignerRecordQuery, RegisterSignerRecordQuery,
@@ -25,12 +25,12 @@ pub trait SignerGetter: Sync + Send {
 
 /// Service to deal with signer (read & write).
 pub struct SignerStore {
-    connection: Arc<SqliteConnection>,
+    connection: Arc<DatabaseConnection>,
 }
 
 impl SignerStore {
     /// Create a new SignerStore service
-    pub fn new(connection: Arc<SqliteConnection>) -> Self {
+    pub fn new(connection: Arc<DatabaseConnection>) -> Self {
         Self { connection }
     }
 
@@ -2,19 +2,19 @@ use std::sync::Arc;
 
 use mithril_common::entities::SingleSignatures;
 use mithril_common::StdResult;
-use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 
 use crate::database::query::UpdateSingleSignatureRecordQuery;
 use crate::database::record::{OpenMessageRecord, SingleSignatureRecord};
 
 /// Service to deal with single_signature (read & write).
 pub struct SingleSignatureRepository {
-    connection: Arc<SqliteConnection>,
+    connection: Arc<DatabaseConnection>,
 }
 
 impl SingleSignatureRepository {
     /// Create a new SingleSignatureStoreAdapter service
-    pub fn new(connection: Arc<SqliteConnection>) -> Self {
+    pub fn new(connection: Arc<DatabaseConnection>) -> Self {
         Self { connection }
     }
 
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use mithril_common::entities::{Epoch, StakeDistribution};
 use mithril_common::signable_builder::StakeDistributionRetriever;
 use mithril_common::StdResult;
-use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+use mithril_persistence::sqlite::{ConnectionExtensions, DatabaseConnection};
 use mithril_persistence::store::adapter::AdapterError;
 use mithril_persistence::store::StakeStorer;
 
@@ -18,7 +18,7 @@ use crate::database::record::StakePool;
 
 /// Service to deal with stake pools (read & write).
 pub struct StakePoolStore {
-    connection: Arc<SqliteConnection>,
+    connection: Arc<DatabaseConnection>,
 
     /// Number of epochs before previous records will be pruned at the next call to
     /// [save_protocol_parameters][StakePoolStore::save_stakes].
@@ -27,7 +27,7 @@ pub struct StakePoolStore {
 
 impl StakePoolStore {
     /// Create a new StakePool service
-    pub fn new(connection: Arc<SqliteConnection>, retention_limit: Option<u64>) -> Self {
+    pub fn new(connection: Arc<DatabaseConnection>, retention_limit: Option<u64>) -> Self {
         Self {
             connection,
             retention_limit,
@@ -1,5 +1,5 @@
 use chrono::Utc;
-use sqlite::{ConnectionThreadSafe, Value};
+use sqlite::Value;
 use std::path::Path;
 use uuid::Uuid;
 
@@ -7,8 +7,12 @@ use mithril_common::entities::{
     BlockNumber, CardanoTransactionsSigningConfig, ProtocolParameters, SignerWithStake,
 };
 use mithril_common::{entities::Epoch, test_utils::fake_keys, StdError, StdResult};
+#[cfg(feature = "postgres")]
+use mithril_persistence::database::{ApplicationNodeType, SqlMigration};
+#[cfg(feature = "postgres")]
+use mithril_persistence::postgres::{test_utils, PostgresConnectionBuilder};
 use mithril_persistence::sqlite::{
-    ConnectionBuilder, ConnectionExtensions, ConnectionOptions, Query, SqliteConnection,
+    ConnectionBuilder, ConnectionExtensions, ConnectionOptions, DatabaseConnection, Query,
 };
 
 use crate::database::query::{
@@ -23,35 +27,57 @@ use crate::database::record::{
 };
 use crate::entities::AggregatorEpochSettings;
 
-/// In-memory sqlite database without foreign key support with migrations applied
-pub fn main_db_connection() -> StdResult<SqliteConnection> {
-    let builder = ConnectionBuilder::open_memory();
-    build_main_db_connection(builder)
+/// Database without foreign key support with migrations applied.
+///
+/// It's an in-memory sqlite database, or a new schema of the PostgreSQL database given by the
+/// `MITHRIL_TEST_POSTGRES_URL` environment variable when the `postgres` feature is enabled.
+pub fn main_db_connection() -> StdResult<DatabaseConnection> {
+    #[cfg(feature = "postgres")]
+    if let Some(url) = test_utils::test_database_url() {
+        return build_postgres_test_connection(
+            &url,
+            crate::database::migration::get_postgres_migrations(),
+        );
+    }
+
+    build_main_db_connection(ConnectionBuilder::open_memory())
 }
 
 /// File sqlite database without foreign key support with migrations applied and WAL activated
-pub fn main_db_file_connection(db_path: &Path) -> StdResult<SqliteConnection> {
+pub fn main_db_file_connection(db_path: &Path) -> StdResult<DatabaseConnection> {
     let builder = ConnectionBuilder::open_file(db_path)
         .with_options(&[ConnectionOptions::EnableWriteAheadLog]);
     build_main_db_connection(builder)
 }
 
-fn build_main_db_connection(connection_builder: ConnectionBuilder) -> StdResult<SqliteConnection> {
+fn build_main_db_connection(
+    connection_builder: ConnectionBuilder,
+) -> StdResult<DatabaseConnection> {
     let connection = connection_builder
         .with_options(&[ConnectionOptions::ForceDisableForeignKeys])
         .with_migrations(crate::database::migration::get_migrations())
         .build()?;
-    Ok(connection)
+    Ok(connection.into())
 }
 
-/// In-memory sqlite database without foreign key support with cardano db migrations applied
-pub fn cardano_tx_db_connection() -> StdResult<SqliteConnection> {
-    let builder = ConnectionBuilder::open_memory();
-    build_cardano_tx_db_connection(builder)
+/// Database without foreign key support with cardano db migrations applied.
+///
+/// It's an in-memory sqlite database, or a new schema of the PostgreSQL database given by the
+/// `MITHRIL_TEST_POSTGRES_URL` environment variable when the `postgres` feature is enabled.
+pub fn cardano_tx_db_connection() -> StdResult<DatabaseConnection> {
+    #[cfg(feature = "postgres")]
+    if let Some(url) = test_utils::test_database_url() {
+        return build_postgres_test_connection(
+            &url,
+            mithril_persistence::database::cardano_transaction_migration::get_postgres_migrations(),
+        );
+    }
+
+    build_cardano_tx_db_connection(ConnectionBuilder::open_memory())
 }
 
 /// File sqlite database without foreign key support with cardano db migrations applied and WAL activated
-pub fn cardano_tx_db_file_connection(db_path: &Path) -> StdResult<SqliteConnection> {
+pub fn cardano_tx_db_file_connection(db_path: &Path) -> StdResult<DatabaseConnection> {
     let builder = ConnectionBuilder::open_file(db_path)
         .with_options(&[ConnectionOptions::EnableWriteAheadLog]);
     build_cardano_tx_db_connection(builder)
@@ -59,14 +85,28 @@ pub fn cardano_tx_db_file_connection(db_path: &Path) -> StdResult<SqliteConnecti
 
 fn build_cardano_tx_db_connection(
     connection_builder: ConnectionBuilder,
-) -> StdResult<SqliteConnection> {
+) -> StdResult<DatabaseConnection> {
     let connection = connection_builder
         .with_options(&[ConnectionOptions::ForceDisableForeignKeys])
         .with_migrations(
             mithril_persistence::database::cardano_transaction_migration::get_migrations(),
         )
         .build()?;
-    Ok(connection)
+    Ok(connection.into())
+}
+
+#[cfg(feature = "postgres")]
+fn build_postgres_test_connection(
+    url: &str,
+    migrations: Vec<SqlMigration>,
+) -> StdResult<DatabaseConnection> {
+    let connection = PostgresConnectionBuilder::open(url)
+        .with_schema(&test_utils::unique_test_schema())
+        .with_node_type(ApplicationNodeType::Aggregator)
+        .with_options(&[ConnectionOptions::ForceDisableForeignKeys])
+        .with_migrations(migrations)
+        .build()?;
+    Ok(connection.into())
 }
 
 pub fn setup_single_signature_records(
@@ -97,7 +137,7 @@ pub fn setup_single_signature_records(
 }
 
 pub fn insert_single_signatures_in_db(
-    connection: &SqliteConnection,
+    connection: &DatabaseConnection,
     single_signature_records: Vec<SingleSignatureRecord>,
 ) -> StdResult<()> {
     if single_signature_records.is_empty() {
@@ -116,37 +156,26 @@ pub fn insert_single_signatures_in_db(
     };
 
     for single_signature_record in single_signature_records {
-        let mut statement = connection.prepare(&query)?;
-
-        statement
-            .bind::<&[(_, Value)]>(&[
-                (
-                    1,
-                    single_signature_record.open_message_id.to_string().into(),
-                ),
-                (2, single_signature_record.signer_id.into()),
-                (
-                    3,
-                    Value::Integer(*single_signature_record.registration_epoch_settings_id as i64),
-                ),
-                (
-                    4,
-                    serde_json::to_string(&single_signature_record.lottery_indexes)
-                        .unwrap()
-                        .into(),
-                ),
-                (5, single_signature_record.signature.into()),
-                (6, single_signature_record.created_at.to_rfc3339().into()),
-            ])
-            .unwrap();
-        statement.next().unwrap();
+        connection.execute_with_params(
+            &query,
+            &[
+                single_signature_record.open_message_id.to_string().into(),
+                single_signature_record.signer_id.into(),
+                Value::Integer(*single_signature_record.registration_epoch_settings_id as i64),
+                serde_json::to_string(&single_signature_record.lottery_indexes)
+                    .unwrap()
+                    .into(),
+                single_signature_record.signature.into(),
+                single_signature_record.created_at.to_rfc3339().into(),
+            ],
+        )?;
     }
 
     Ok(())
 }
 
 pub fn insert_buffered_single_signatures(
-    connection: &SqliteConnection,
+    connection: &DatabaseConnection,
     buffered_signature_records: Vec<BufferedSingleSignatureRecord>,
 ) -> StdResult<()> {
     if buffered_signature_records.is_empty() {
@@ -165,26 +194,23 @@ pub fn insert_buffered_single_signatures(
     };
 
     for record in buffered_signature_records {
-        let mut statement = connection.prepare(&query)?;
-
-        statement.bind::<&[(_, Value)]>(&[
-            (
-                1,
+        connection.execute_with_params(
+            &query,
+            &[
                 Value::Integer(record.signed_entity_type_id.index() as i64),
-            ),
-            (2, record.party_id.into()),
-            (3, serde_json::to_string(&record.lottery_indexes)?.into()),
-            (4, record.signature.into()),
-            (5, record.created_at.to_rfc3339().into()),
-        ])?;
-        statement.next()?;
+                record.party_id.into(),
+                serde_json::to_string(&record.lottery_indexes)?.into(),
+                record.signature.into(),
+                record.created_at.to_rfc3339().into(),
+            ],
+        )?;
     }
 
     Ok(())
 }
 
 pub fn insert_certificate_records<T: Into<CertificateRecord>>(
-    connection: &ConnectionThreadSafe,
+    connection: &DatabaseConnection,
     records: Vec<T>,
 ) {
     let _ = connection
@@ -195,7 +221,7 @@ pub fn insert_certificate_records<T: Into<CertificateRecord>>(
 }
 
 pub fn insert_epoch_settings(
-    connection: &SqliteConnection,
+    connection: &DatabaseConnection,
     epoch_to_insert_settings: &[u64],
 ) -> StdResult<()> {
     let query = {
@@ -226,25 +252,20 @@ pub fn insert_epoch_settings(
             )
         })
     {
-        let mut statement = connection.prepare(&query)?;
-        statement
-            .bind::<&[(_, Value)]>(&[
-                (1, Value::Integer(*epoch as i64)),
-                (
-                    2,
-                    serde_json::to_string(&protocol_parameters).unwrap().into(),
-                ),
-                (3, serde_json::to_string(&signing_config).unwrap().into()),
-            ])
-            .unwrap();
-
-        statement.next().unwrap();
+        connection.execute_with_params(
+            &query,
+            &[
+                Value::Integer(*epoch as i64),
+                serde_json::to_string(&protocol_parameters).unwrap().into(),
+                serde_json::to_string(&signing_config).unwrap().into(),
+            ],
+        )?;
     }
     Ok(())
 }
 
 pub fn insert_signed_entities(
-    connection: &SqliteConnection,
+    connection: &DatabaseConnection,
     signed_entity_records: Vec<SignedEntityRecord>,
 ) -> StdResult<()> {
     if signed_entity_records.is_empty() {
@@ -262,38 +283,30 @@ pub fn insert_signed_entities(
     };
 
     for signed_entity_record in signed_entity_records {
-        let mut statement = connection.prepare(&query)?;
-        statement
-            .bind::<&[(_, Value)]>(&[
-                (1, signed_entity_record.signed_entity_id.into()),
-                (
-                    2,
-                    i64::try_from(signed_entity_record.signed_entity_type.index())
-                        .unwrap()
-                        .into(),
-                ),
-                (3, signed_entity_record.certificate_id.into()),
-                (
-                    4,
-                    signed_entity_record
-                        .signed_entity_type
-                        .get_json_beacon()
-                        .unwrap()
-                        .into(),
-                ),
-                (5, signed_entity_record.artifact.into()),
-                (6, signed_entity_record.created_at.to_rfc3339().into()),
-            ])
-            .unwrap();
-
-        statement.next().unwrap();
+        connection.execute_with_params(
+            &query,
+            &[
+                signed_entity_record.signed_entity_id.into(),
+                i64::try_from(signed_entity_record.signed_entity_type.index())
+                    .unwrap()
+                    .into(),
+                signed_entity_record.certificate_id.into(),
+                signed_entity_record
+                    .signed_entity_type
+                    .get_json_beacon()
+                    .unwrap()
+                    .into(),
+                signed_entity_record.artifact.into(),
+                signed_entity_record.created_at.to_rfc3339().into(),
+            ],
+        )?;
     }
 
     Ok(())
 }
 
 pub fn insert_signers(
-    connection: &SqliteConnection,
+    connection: &DatabaseConnection,
     signer_records: Vec<SignerRecord>,
 ) -> Result<(), StdError> {
     if signer_records.is_empty() {
@@ -311,36 +324,29 @@ pub fn insert_signers(
     };
 
     for signer_record in signer_records {
-        let mut statement = connection.prepare(&query)?;
-        statement
-            .bind::<&[(_, Value)]>(&[
-                (1, signer_record.signer_id.into()),
-                (
-                    2,
-                    signer_record
-                        .pool_ticker
-                        .map(Value::String)
-                        .unwrap_or(Value::Null),
-                ),
-                (3, signer_record.created_at.to_rfc3339().into()),
-                (4, signer_record.updated_at.to_rfc3339().into()),
-                (
-                    5,
-                    signer_record
-                        .last_registered_at
-                        .map(|d| Value::String(d.to_rfc3339()))
-                        .unwrap_or(Value::Null),
-                ),
-            ])
-            .unwrap();
-        statement.next().unwrap();
+        connection.execute_with_params(
+            &query,
+            &[
+                signer_record.signer_id.into(),
+                signer_record
+                    .pool_ticker
+                    .map(Value::String)
+                    .unwrap_or(Value::Null),
+                signer_record.created_at.to_rfc3339().into(),
+                signer_record.updated_at.to_rfc3339().into(),
+                signer_record
+                    .last_registered_at
+                    .map(|d| Value::String(d.to_rfc3339()))
+                    .unwrap_or(Value::Null),
+            ],
+        )?;
     }
 
     Ok(())
 }
 
 pub fn insert_signer_registrations(
-    connection: &SqliteConnection,
+    connection: &DatabaseConnection,
     signer_with_stakes_by_epoch: Vec<(Epoch, Vec<SignerWithStake>)>,
 ) -> StdResult<()> {
     if signer_with_stakes_by_epoch.is_empty() {
@@ -371,48 +377,31 @@ pub fn insert_signer_registrations(
         for signer_with_stake in signer_with_stakes {
             let signer_registration_record =
                 SignerRegistrationRecord::from_signer_with_stake(signer_with_stake, epoch);
-            let mut statement = connection.prepare(&query)?;
-            statement
-                .bind::<&[(_, Value)]>(&[
-                    (1, signer_registration_record.signer_id.into()),
-                    (
-                        2,
-                        Value::Integer(*signer_registration_record.epoch_settings_id as i64),
-                    ),
-                    (3, signer_registration_record.verification_key.into()),
-                    (
-                        4,
-                        signer_registration_record
-                            .verification_key_signature
-                            .map(Value::String)
-                            .unwrap_or(Value::Null),
-                    ),
-                    (
-                        5,
-                        signer_registration_record
-                            .operational_certificate
-                            .map(Value::String)
-                            .unwrap_or(Value::Null),
-                    ),
-                    (
-                        6,
-                        signer_registration_record
-                            .kes_period
-                            .map(|k| Value::Integer(k as i64))
-                            .unwrap_or(Value::Null),
-                    ),
-                    (
-                        7,
-                        signer_registration_record
-                            .stake
-                            .map(|s| Value::Integer(s as i64))
-                            .unwrap_or(Value::Null),
-                    ),
-                    (8, signer_registration_record.created_at.to_rfc3339().into()),
-                ])
-                .unwrap();
-
-            statement.next().unwrap();
+            connection.execute_with_params(
+                &query,
+                &[
+                    signer_registration_record.signer_id.into(),
+                    Value::Integer(*signer_registration_record.epoch_settings_id as i64),
+                    signer_registration_record.verification_key.into(),
+                    signer_registration_record
+                        .verification_key_signature
+                        .map(Value::String)
+                        .unwrap_or(Value::Null),
+                    signer_registration_record
+                        .operational_certificate
+                        .map(Value::String)
+                        .unwrap_or(Value::Null),
+                    signer_registration_record
+                        .kes_period
+                        .map(|k| Value::Integer(k as i64))
+                        .unwrap_or(Value::Null),
+                    signer_registration_record
+                        .stake
+                        .map(|s| Value::Integer(s as i64))
+                        .unwrap_or(Value::Null),
+                    signer_registration_record.created_at.to_rfc3339().into(),
+                ],
+            )?;
         }
     }
 
@@ -420,7 +409,7 @@ pub fn insert_signer_registrations(
 }
 
 pub fn insert_stake_pool(
-    connection: &SqliteConnection,
+    connection: &DatabaseConnection,
     epoch_to_insert_stake_pools: &[i64],
 ) -> StdResult<()> {
     let query = {
@@ -442,16 +431,15 @@ pub fn insert_stake_pool(
             ("pool3", *epoch, 1200 - (epoch - 1) * 50),
         ]
     }) {
-        let mut statement = connection.prepare(&query)?;
-        statement
-            .bind::<&[(_, Value)]>(&[
-                (1, pool_id.to_string().into()),
-                (2, Value::Integer(epoch)),
-                (3, Value::Integer(stake)),
-                (4, Utc::now().to_rfc3339().into()),
-            ])
-            .unwrap();
-        statement.next().unwrap();
+        connection.execute_with_params(
+            &query,
+            &[
+                pool_id.to_string().into(),
+                Value::Integer(epoch),
+                Value::Integer(stake),
+                Utc::now().to_rfc3339().into(),
+            ],
+        )?;
     }
 
     Ok(())
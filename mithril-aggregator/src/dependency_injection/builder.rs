@@ -1,6 +1,6 @@
 use anyhow::Context;
 use semver::Version;
-use slog::Logger;
+use slog::{warn, Logger};
 use std::sync::Arc;
 use tokio::{
     sync::{
@@ -13,10 +13,10 @@ use warp::Filter;
 
 use mithril_common::{
     api_version::APIVersionProvider,
-    cardano_block_scanner::{BlockScanner, CardanoBlockScanner},
-    cardano_transactions_preloader::{
-        CardanoTransactionsPreloader, CardanoTransactionsPreloaderActivation,
+    cardano_block_scanner::{
+        BlockScanner, BlockScannerType, CardanoBlockScanner, ImmutableFileBlockScanner,
     },
+    cardano_transactions_preloader::CardanoTransactionsPreloader,
     certificate_chain::{CertificateVerifier, MithrilCertificateVerifier},
     chain_observer::{CardanoCliRunner, ChainObserver, ChainObserverBuilder, FakeObserver},
     chain_reader::{ChainBlockReader, PallasChainReader},
@@ -38,8 +38,9 @@ use mithril_common::{
         EraChecker, EraMarker, EraReader, EraReaderAdapter, SupportedEra,
     },
     signable_builder::{
-        CardanoImmutableFilesFullSignableBuilder, CardanoStakeDistributionSignableBuilder,
-        CardanoTransactionsSignableBuilder, MithrilSignableBuilderService,
+        CardanoDRepStakeDistributionSignableBuilder, CardanoImmutableFilesFullSignableBuilder,
+        CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
+        ChainObserverDRepStakeDistributionRetriever, MithrilSignableBuilderService,
         MithrilStakeDistributionSignableBuilder, SignableBuilderService, SignableSeedBuilder,
         TransactionsImporter,
     },
@@ -48,43 +49,71 @@ use mithril_common::{
 };
 use mithril_persistence::{
     database::{repository::CardanoTransactionRepository, ApplicationNodeType, SqlMigration},
-    sqlite::{ConnectionBuilder, ConnectionOptions, SqliteConnection, SqliteConnectionPool},
+    sqlite::{
+        ConnectionBuilder, ConnectionExtensions, ConnectionOptions, DatabaseConnection,
+        DatabaseConnectionPool, SqliteConnection,
+    },
     store::adapter::{MemoryAdapter, SQLiteAdapter, StoreAdapter},
 };
+#[cfg(feature = "postgres")]
+use mithril_persistence::{
+    postgres::{PostgresConnection, PostgresConnectionBuilder},
+    store::adapter::PostgresAdapter,
+};
 
 use super::{DependenciesBuilderError, EpochServiceWrapper, Result};
 use crate::{
     artifact_builder::{
-        CardanoImmutableFilesFullArtifactBuilder, CardanoStakeDistributionArtifactBuilder,
-        CardanoTransactionsArtifactBuilder, MithrilStakeDistributionArtifactBuilder,
+        CardanoDRepStakeDistributionArtifactBuilder, CardanoImmutableFilesFullArtifactBuilder,
+        CardanoStakeDistributionArtifactBuilder, CardanoTransactionsArtifactBuilder,
+        MithrilStakeDistributionArtifactBuilder,
     },
     configuration::ExecutionEnvironment,
     database::repository::{
         BufferedSingleSignatureRepository, CertificateRepository, EpochSettingsStore,
-        OpenMessageRepository, SignedEntityStore, SignedEntityStorer, SignerRegistrationStore,
-        SignerStore, SingleSignatureRepository, StakePoolStore,
+        LeaderLockRepository, OpenMessageRepository, SignedEntityStore, SignedEntityStorer,
+        SignerRegistrationStore, SignerStore, SingleSignatureRepository, StakePoolStore,
     },
     entities::AggregatorEpochSettings,
     event_store::{EventMessage, EventStore, TransmitterService},
     http_server::routes::router,
     services::{
         AggregatorSignableSeedBuilder, AggregatorUpkeepService, BufferedCertifierService,
-        CardanoTransactionsImporter, CertifierService, MessageService, MithrilCertifierService,
-        MithrilEpochService, MithrilMessageService, MithrilProverService,
-        MithrilSignedEntityService, MithrilStakeDistributionService, ProverService,
-        SignedEntityService, StakeDistributionService, UpkeepService,
+        CardanoTransactionsImporter, CertifierService,
+        LeaderCardanoTransactionsPreloaderActivation, LeaderElectionService, MessageService,
+        MithrilCertifierService, MithrilEpochService, MithrilLeaderElectionService,
+        MithrilMessageService, MithrilProverService, MithrilSignedEntityService,
+        MithrilStakeDistributionService, ProverService, SignedEntityService,
+        StakeDistributionService, StandaloneLeaderElectionService, StoreRetentionPruner,
+        UpkeepService,
     },
     tools::{CExplorerSignerRetriever, GcpFileUploader, GenesisToolsDependency, SignersImporter},
     AggregatorConfig, AggregatorRunner, AggregatorRuntime, CertificatePendingStore,
     CompressedArchiveSnapshotter, Configuration, DependencyContainer, DumbSnapshotUploader,
-    DumbSnapshotter, EpochSettingsStorer, LocalSnapshotUploader, MithrilSignerRegisterer,
-    MultiSigner, MultiSignerImpl, RemoteSnapshotUploader, SingleSignatureAuthenticator,
-    SnapshotUploader, SnapshotUploaderType, Snapshotter, SnapshotterCompressionAlgorithm,
-    VerificationKeyStorer,
+    DumbSnapshotter, EpochSettingsStorer, FileProtocolParametersScheduleProvider,
+    LocalSnapshotUploader, MKTreeStoreSqlite, MithrilSignerRegisterer, MultiSigner,
+    MultiSignerImpl, RemoteSnapshotUploader, SingleSignatureAuthenticator, SnapshotUploader,
+    SnapshotUploaderType, Snapshotter, SnapshotterCompressionAlgorithm, VerificationKeyStorer,
 };
 
-const SQLITE_FILE: &str = "aggregator.sqlite3";
-const SQLITE_FILE_CARDANO_TRANSACTION: &str = "cardano-transaction.sqlite3";
+pub(crate) const SQLITE_FILE: &str = "aggregator.sqlite3";
+pub(crate) const SQLITE_FILE_CARDANO_TRANSACTION: &str = "cardano-transaction.sqlite3";
+pub(crate) const SQLITE_MONITORING_FILE: &str = "monitoring.sqlite3";
+const SQLITE_FILE_PROVER_CACHE: &str = "prover-cache.sqlite3";
+#[cfg(feature = "postgres")]
+const POSTGRES_SCHEMA: &str = "aggregator";
+#[cfg(feature = "postgres")]
+const POSTGRES_SCHEMA_CARDANO_TRANSACTION: &str = "cardano_transaction";
+
+/// A configured database URL can't be used without the `postgres` feature, fail instead of
+/// silently falling back to the SQLite files.
+#[cfg(not(feature = "postgres"))]
+fn postgres_backend_disabled_error() -> DependenciesBuilderError {
+    DependenciesBuilderError::Initialization {
+        message: "PostgreSQL initialization: a `database_url` is configured but the aggregator was built without the `postgres` feature.".to_string(),
+        error: None,
+    }
+}
 
 /// ## Dependencies container builder
 ///
@@ -104,11 +133,11 @@ pub struct DependenciesBuilder {
     /// Signed entity configuration
     pub signed_entity_config: Option<SignedEntityConfig>,
 
-    /// SQLite database connection
-    pub sqlite_connection: Option<Arc<SqliteConnection>>,
+    /// Database connection, to a SQLite database or to a PostgreSQL one if configured
+    pub database_connection: Option<Arc<DatabaseConnection>>,
 
-    /// Cardano transactions SQLite database connection pool
-    pub sqlite_connection_cardano_transaction_pool: Option<Arc<SqliteConnectionPool>>,
+    /// Cardano transactions database connection pool
+    pub cardano_transaction_connection_pool: Option<Arc<DatabaseConnectionPool>>,
 
     /// Stake Store used by the StakeDistributionService
     /// It shall be a private dependency.
@@ -239,6 +268,9 @@ pub struct DependenciesBuilder {
 
     /// Single signer authenticator
     pub single_signer_authenticator: Option<Arc<SingleSignatureAuthenticator>>,
+
+    /// Leader election service
+    pub leader_election_service: Option<Arc<dyn LeaderElectionService>>,
 }
 
 impl DependenciesBuilder {
@@ -247,8 +279,8 @@ impl DependenciesBuilder {
         Self {
             configuration,
             signed_entity_config: None,
-            sqlite_connection: None,
-            sqlite_connection_cardano_transaction_pool: None,
+            database_connection: None,
+            cardano_transaction_connection_pool: None,
             stake_store: None,
             snapshot_uploader: None,
             multi_signer: None,
@@ -291,6 +323,7 @@ impl DependenciesBuilder {
             transactions_importer: None,
             upkeep_service: None,
             single_signer_authenticator: None,
+            leader_election_service: None,
         }
     }
 
@@ -334,39 +367,120 @@ impl DependenciesBuilder {
                 error: Some(e),
             })?;
 
+        if self.configuration.is_high_availability_enabled() {
+            // The database is shared with the other aggregators of the cluster, wait for their
+            // writes to complete instead of failing immediately.
+            connection
+                .execute("pragma busy_timeout = 5000;")
+                .map_err(|e| DependenciesBuilderError::Initialization {
+                    message: "SQLite initialization: failed to set busy timeout.".to_string(),
+                    error: Some(e),
+                })?;
+        }
+
         Ok(connection)
     }
 
+    #[cfg(feature = "postgres")]
+    fn build_postgres_connection(
+        &self,
+        database_url: &str,
+        schema: &str,
+        migrations: Vec<SqlMigration>,
+    ) -> Result<PostgresConnection> {
+        let logger = self.get_logger()?;
+
+        PostgresConnectionBuilder::open(database_url)
+            .with_schema(schema)
+            .with_node_type(ApplicationNodeType::Aggregator)
+            .with_logger(logger.clone())
+            .with_migrations(migrations)
+            .build()
+            .map_err(|e| DependenciesBuilderError::Initialization {
+                message: "PostgreSQL initialization: failed to build connection.".to_string(),
+                error: Some(e),
+            })
+    }
+
     async fn drop_sqlite_connections(&self) {
-        if let Some(connection) = &self.sqlite_connection {
+        if let Some(connection) = self
+            .database_connection
+            .as_ref()
+            .and_then(|connection| connection.as_sqlite())
+        {
             let _ = connection.execute("pragma analysis_limit=400; pragma optimize;");
         }
 
-        if let Some(pool) = &self.sqlite_connection_cardano_transaction_pool {
+        if let Some(pool) = &self.cardano_transaction_connection_pool {
             if let Ok(connection) = pool.connection() {
-                let _ = connection.execute("pragma analysis_limit=400; pragma optimize;");
+                if let Some(connection) = connection.as_sqlite() {
+                    let _ = connection.execute("pragma analysis_limit=400; pragma optimize;");
+                }
             }
         }
     }
 
-    /// Get SQLite connection
-    pub async fn get_sqlite_connection(&mut self) -> Result<Arc<SqliteConnection>> {
-        if self.sqlite_connection.is_none() {
-            self.sqlite_connection = Some(Arc::new(self.build_sqlite_connection(
-                SQLITE_FILE,
-                crate::database::migration::get_migrations(),
-            )?));
+    /// Get the database connection, to the PostgreSQL database if an URL is configured or to
+    /// the SQLite database otherwise
+    pub async fn get_database_connection(&mut self) -> Result<Arc<DatabaseConnection>> {
+        if self.database_connection.is_none() {
+            let connection: DatabaseConnection = match &self.configuration.database_url {
+                #[cfg(not(feature = "postgres"))]
+                Some(_) => return Err(postgres_backend_disabled_error()),
+                #[cfg(feature = "postgres")]
+                Some(database_url) => self
+                    .build_postgres_connection(
+                        database_url,
+                        POSTGRES_SCHEMA,
+                        crate::database::migration::get_postgres_migrations(),
+                    )?
+                    .into(),
+                None => self
+                    .build_sqlite_connection(
+                        SQLITE_FILE,
+                        crate::database::migration::get_migrations(),
+                    )?
+                    .into(),
+            };
+            self.database_connection = Some(Arc::new(connection));
         }
 
-        Ok(self.sqlite_connection.as_ref().cloned().unwrap())
+        Ok(self.database_connection.as_ref().cloned().unwrap())
     }
 
-    async fn build_sqlite_connection_cardano_transaction_pool(
+    async fn build_cardano_transaction_connection_pool(
         &mut self,
-    ) -> Result<Arc<SqliteConnectionPool>> {
+    ) -> Result<Arc<DatabaseConnectionPool>> {
         let connection_pool_size = self
             .configuration
             .cardano_transactions_database_connection_pool_size;
+
+        #[cfg(not(feature = "postgres"))]
+        if self.configuration.database_url.is_some() {
+            return Err(postgres_backend_disabled_error());
+        }
+
+        #[cfg(feature = "postgres")]
+        if let Some(database_url) = &self.configuration.database_url {
+            let _connection = self.build_postgres_connection(
+                database_url,
+                POSTGRES_SCHEMA_CARDANO_TRANSACTION,
+                mithril_persistence::database::cardano_transaction_migration::get_postgres_migrations(),
+            )?;
+
+            let connection_pool = Arc::new(DatabaseConnectionPool::build(
+                connection_pool_size,
+                || {
+                    self.build_postgres_connection(database_url, POSTGRES_SCHEMA_CARDANO_TRANSACTION, vec![])
+                    .with_context(|| {
+                        "Dependencies Builder can not build PostgreSQL connection for Cardano transactions"
+                    })
+                },
+            )?);
+
+            return Ok(connection_pool);
+        }
+
         // little hack to apply migrations to the cardano transaction database
         // todo: add capacity to create a connection pool to the `ConnectionBuilder`
         let _connection = self.build_sqlite_connection(
@@ -375,29 +489,28 @@ impl DependenciesBuilder {
             // Don't vacuum the Cardano transactions database as it can be very large
         )?;
 
-        let connection_pool = Arc::new(SqliteConnectionPool::build(connection_pool_size, || {
-            self.build_sqlite_connection(SQLITE_FILE_CARDANO_TRANSACTION, vec![])
+        let connection_pool =
+            Arc::new(DatabaseConnectionPool::build(connection_pool_size, || {
+                self.build_sqlite_connection(SQLITE_FILE_CARDANO_TRANSACTION, vec![])
                 .with_context(|| {
                     "Dependencies Builder can not build SQLite connection for Cardano transactions"
                 })
-        })?);
+            })?);
 
         Ok(connection_pool)
     }
 
-    /// Get SQLite connection pool for the cardano transactions store
-    pub async fn get_sqlite_connection_cardano_transaction_pool(
+    /// Get the database connection pool for the cardano transactions store
+    pub async fn get_cardano_transaction_connection_pool(
         &mut self,
-    ) -> Result<Arc<SqliteConnectionPool>> {
-        if self.sqlite_connection_cardano_transaction_pool.is_none() {
-            self.sqlite_connection_cardano_transaction_pool = Some(
-                self.build_sqlite_connection_cardano_transaction_pool()
-                    .await?,
-            );
+    ) -> Result<Arc<DatabaseConnectionPool>> {
+        if self.cardano_transaction_connection_pool.is_none() {
+            self.cardano_transaction_connection_pool =
+                Some(self.build_cardano_transaction_connection_pool().await?);
         }
 
         Ok(self
-            .sqlite_connection_cardano_transaction_pool
+            .cardano_transaction_connection_pool
             .as_ref()
             .cloned()
             .unwrap())
@@ -405,7 +518,7 @@ impl DependenciesBuilder {
 
     async fn build_stake_store(&mut self) -> Result<Arc<StakePoolStore>> {
         let stake_pool_store = Arc::new(StakePoolStore::new(
-            self.get_sqlite_connection().await?,
+            self.get_database_connection().await?,
             self.configuration.safe_epoch_retention_limit(),
         ));
 
@@ -481,15 +594,44 @@ impl DependenciesBuilder {
             .environment
         {
             ExecutionEnvironment::Production => {
-                let adapter =
-                    SQLiteAdapter::new("pending_certificate", self.get_sqlite_connection().await?)
-                        .map_err(|e| DependenciesBuilderError::Initialization {
-                            message: "Cannot create SQLite adapter for PendingCertificate Store."
-                                .to_string(),
-                            error: Some(e.into()),
-                        })?;
-
-                Box::new(adapter)
+                // Apply the migrations of the database before using one of its tables, the
+                // adapter has its own connection to it.
+                self.get_database_connection().await?;
+
+                match &self.configuration.database_url {
+                    #[cfg(not(feature = "postgres"))]
+                    Some(_) => return Err(postgres_backend_disabled_error()),
+                    #[cfg(feature = "postgres")]
+                    Some(database_url) => {
+                        let connection =
+                            self.build_postgres_connection(database_url, POSTGRES_SCHEMA, vec![])?;
+                        let adapter =
+                            PostgresAdapter::new("pending_certificate", Arc::new(connection))
+                                .map_err(|e| {
+                                    DependenciesBuilderError::Initialization {
+                                message:
+                                    "Cannot create PostgreSQL adapter for PendingCertificate Store."
+                                        .to_string(),
+                                error: Some(e.into()),
+                            }
+                                })?;
+
+                        Box::new(adapter)
+                    }
+                    None => {
+                        let connection = self.build_sqlite_connection(SQLITE_FILE, vec![])?;
+                        let adapter =
+                            SQLiteAdapter::new("pending_certificate", Arc::new(connection))
+                                .map_err(|e| DependenciesBuilderError::Initialization {
+                                    message:
+                                        "Cannot create SQLite adapter for PendingCertificate Store."
+                                            .to_string(),
+                                    error: Some(e.into()),
+                                })?;
+
+                        Box::new(adapter)
+                    }
+                }
             }
             _ => {
                 let adapter = MemoryAdapter::new(None).map_err(|e| {
@@ -517,7 +659,7 @@ impl DependenciesBuilder {
 
     async fn build_certificate_repository(&mut self) -> Result<Arc<CertificateRepository>> {
         Ok(Arc::new(CertificateRepository::new(
-            self.get_sqlite_connection().await?,
+            self.get_database_connection().await?,
         )))
     }
 
@@ -532,7 +674,7 @@ impl DependenciesBuilder {
 
     async fn build_open_message_repository(&mut self) -> Result<Arc<OpenMessageRepository>> {
         Ok(Arc::new(OpenMessageRepository::new(
-            self.get_sqlite_connection().await?,
+            self.get_database_connection().await?,
         )))
     }
 
@@ -547,7 +689,7 @@ impl DependenciesBuilder {
 
     async fn build_verification_key_store(&mut self) -> Result<Arc<dyn VerificationKeyStorer>> {
         Ok(Arc::new(SignerRegistrationStore::new(
-            self.get_sqlite_connection().await?,
+            self.get_database_connection().await?,
         )))
     }
 
@@ -562,7 +704,7 @@ impl DependenciesBuilder {
 
     async fn build_epoch_settings_storer(&mut self) -> Result<Arc<dyn EpochSettingsStorer>> {
         let epoch_settings_store = EpochSettingsStore::new(
-            self.get_sqlite_connection().await?,
+            self.get_database_connection().await?,
             self.configuration.safe_epoch_retention_limit(),
         );
         let current_epoch = self
@@ -730,8 +872,7 @@ impl DependenciesBuilder {
 
     async fn build_transaction_repository(&mut self) -> Result<Arc<CardanoTransactionRepository>> {
         let transaction_store = CardanoTransactionRepository::new(
-            self.get_sqlite_connection_cardano_transaction_pool()
-                .await?,
+            self.get_cardano_transaction_connection_pool().await?,
         );
 
         Ok(Arc::new(transaction_store))
@@ -753,7 +894,8 @@ impl DependenciesBuilder {
             &self.configuration.cardano_node_socket_path,
             self.configuration.get_network()?,
             self.get_logger()?,
-        );
+        )
+        .with_extended_mode(self.configuration.enable_cardano_transactions_extended_mode);
 
         Ok(Arc::new(Mutex::new(chain_block_reader)))
     }
@@ -768,14 +910,31 @@ impl DependenciesBuilder {
     }
 
     async fn build_block_scanner(&mut self) -> Result<Arc<dyn BlockScanner>> {
-        let block_scanner = CardanoBlockScanner::new(
-            self.get_chain_block_reader().await?,
-            self.configuration
-                .cardano_transactions_block_streamer_max_roll_forwards_per_poll,
-            self.get_logger()?,
-        );
+        let chain_block_reader = self.get_chain_block_reader().await?;
+        let max_roll_forwards_per_poll = self
+            .configuration
+            .cardano_transactions_block_streamer_max_roll_forwards_per_poll;
+        let block_scanner: Arc<dyn BlockScanner> = match self
+            .configuration
+            .cardano_transactions_block_scanner_type
+        {
+            BlockScannerType::ChainSync => Arc::new(CardanoBlockScanner::new(
+                chain_block_reader,
+                max_roll_forwards_per_poll,
+                self.get_logger()?,
+            )),
+            BlockScannerType::ImmutableFiles => Arc::new(
+                ImmutableFileBlockScanner::new(
+                    &self.configuration.db_directory,
+                    chain_block_reader,
+                    max_roll_forwards_per_poll,
+                    self.get_logger()?,
+                )
+                .with_extended_mode(self.configuration.enable_cardano_transactions_extended_mode),
+            ),
+        };
 
-        Ok(Arc::new(block_scanner))
+        Ok(block_scanner)
     }
 
     /// Block scanner
@@ -1081,7 +1240,7 @@ impl DependenciesBuilder {
     }
 
     async fn build_signer_store(&mut self) -> Result<Arc<SignerStore>> {
-        let signer_store = Arc::new(SignerStore::new(self.get_sqlite_connection().await?));
+        let signer_store = Arc::new(SignerStore::new(self.get_database_connection().await?));
 
         Ok(signer_store)
     }
@@ -1119,6 +1278,10 @@ impl DependenciesBuilder {
         let cardano_stake_distribution_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(self.get_stake_store().await?),
         );
+        let cardano_drep_stake_distribution_builder =
+            Arc::new(CardanoDRepStakeDistributionSignableBuilder::new(Arc::new(
+                ChainObserverDRepStakeDistributionRetriever::new(self.get_chain_observer().await?),
+            )));
         let era_checker = self.get_era_checker().await?;
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             era_checker,
@@ -1127,6 +1290,7 @@ impl DependenciesBuilder {
             immutable_signable_builder,
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
+            cardano_drep_stake_distribution_builder,
         ));
 
         Ok(signable_builder_service)
@@ -1184,6 +1348,10 @@ impl DependenciesBuilder {
         let stake_store = self.get_stake_store().await?;
         let cardano_stake_distribution_artifact_builder =
             Arc::new(CardanoStakeDistributionArtifactBuilder::new(stake_store));
+        let cardano_drep_stake_distribution_artifact_builder =
+            Arc::new(CardanoDRepStakeDistributionArtifactBuilder::new(Arc::new(
+                ChainObserverDRepStakeDistributionRetriever::new(self.get_chain_observer().await?),
+            )));
         let signed_entity_service = Arc::new(MithrilSignedEntityService::new(
             signed_entity_storer,
             mithril_stake_distribution_artifact_builder,
@@ -1191,18 +1359,26 @@ impl DependenciesBuilder {
             cardano_transactions_artifact_builder,
             self.get_signed_entity_lock().await?,
             cardano_stake_distribution_artifact_builder,
+            cardano_drep_stake_distribution_artifact_builder,
         ));
 
         // Compute the cache pool for prover service
         // This is done here to avoid circular dependencies between the prover service and the signed entity service
-        // TODO: Make this part of a warmup phase of the aggregator?
+        // The cache is computed in the background from the persisted block range roots so that it does not delay
+        // the aggregator startup, its readiness is advertised by the prover service.
         if let Some(signed_entity) = signed_entity_service
             .get_last_cardano_transaction_snapshot()
             .await?
         {
-            prover_service
-                .compute_cache(signed_entity.artifact.block_number)
-                .await?;
+            let logger = self.get_logger()?;
+            tokio::spawn(async move {
+                if let Err(error) = prover_service
+                    .compute_cache(signed_entity.artifact.block_number)
+                    .await
+                {
+                    warn!(logger, "Prover cache warm start failed"; "error" => ?error);
+                }
+            });
         }
 
         Ok(signed_entity_service)
@@ -1223,11 +1399,25 @@ impl DependenciesBuilder {
 
         let epoch_settings = self.get_epoch_settings_configuration()?;
 
-        let epoch_service = Arc::new(RwLock::new(MithrilEpochService::new(
+        let mut epoch_service = MithrilEpochService::new(
             epoch_settings,
             epoch_settings_storer,
             verification_key_store,
-        )));
+        );
+        if let Some(schedule_file) = &self.configuration.protocol_parameters_schedule_file {
+            // Fail early if the schedule is invalid
+            let schedule_provider = FileProtocolParametersScheduleProvider::try_new(
+                schedule_file.clone(),
+                self.get_logger()?,
+            )
+            .map_err(|e| DependenciesBuilderError::Initialization {
+                message: "can not load the protocol parameters schedule".to_string(),
+                error: Some(e),
+            })?;
+            epoch_service = epoch_service
+                .with_protocol_parameters_schedule_provider(Arc::new(schedule_provider));
+        }
+        let epoch_service = Arc::new(RwLock::new(epoch_service));
 
         Ok(epoch_service)
     }
@@ -1242,8 +1432,9 @@ impl DependenciesBuilder {
     }
 
     async fn build_signed_entity_storer(&mut self) -> Result<Arc<dyn SignedEntityStorer>> {
-        let signed_entity_storer =
-            Arc::new(SignedEntityStore::new(self.get_sqlite_connection().await?));
+        let signed_entity_storer = Arc::new(SignedEntityStore::new(
+            self.get_database_connection().await?,
+        ));
 
         Ok(signed_entity_storer)
     }
@@ -1271,11 +1462,18 @@ impl DependenciesBuilder {
     }
 
     async fn build_transactions_importer(&mut self) -> Result<Arc<dyn TransactionsImporter>> {
-        let transactions_importer = Arc::new(CardanoTransactionsImporter::new(
-            self.get_block_scanner().await?,
-            self.get_transaction_repository().await?,
-            self.get_logger()?,
-        ));
+        let transactions_importer = Arc::new(
+            CardanoTransactionsImporter::new(
+                self.get_block_scanner().await?,
+                self.get_transaction_repository().await?,
+                self.get_logger()?,
+            )
+            .with_extended_mode(self.configuration.enable_cardano_transactions_extended_mode)
+            .with_block_range_roots_workers(
+                self.configuration
+                    .cardano_transactions_import_block_range_roots_workers,
+            ),
+        );
 
         Ok(transactions_importer)
     }
@@ -1288,12 +1486,49 @@ impl DependenciesBuilder {
         Ok(self.transactions_importer.as_ref().cloned().unwrap())
     }
 
+    async fn build_store_retention_pruner(&mut self) -> Result<Option<Arc<StoreRetentionPruner>>> {
+        let Some(policies) = self.configuration.retention_policies.clone() else {
+            return Ok(None);
+        };
+
+        let event_store_connection = match policies.event_store_max_age_in_days {
+            Some(_) => {
+                let connection = sqlite::Connection::open_thread_safe(
+                    self.configuration
+                        .get_sqlite_dir()
+                        .join(SQLITE_MONITORING_FILE),
+                )
+                .map_err(|e| DependenciesBuilderError::Initialization {
+                    message: "Event store connection initialization failed.".to_string(),
+                    error: Some(e.into()),
+                })?;
+                // The event store is written concurrently by its own connection
+                connection
+                    .execute("pragma busy_timeout = 5000;")
+                    .map_err(|e| DependenciesBuilderError::Initialization {
+                        message: "Event store connection: failed to set busy timeout.".to_string(),
+                        error: Some(e),
+                    })?;
+                Some(Arc::new(connection))
+            }
+            None => None,
+        };
+
+        Ok(Some(Arc::new(StoreRetentionPruner::new(
+            self.get_database_connection().await?,
+            event_store_connection,
+            self.get_ticker_service().await?,
+            policies,
+            self.get_logger()?,
+        ))))
+    }
+
     async fn build_upkeep_service(&mut self) -> Result<Arc<dyn UpkeepService>> {
         let upkeep_service = Arc::new(AggregatorUpkeepService::new(
-            self.get_sqlite_connection().await?,
-            self.get_sqlite_connection_cardano_transaction_pool()
-                .await?,
+            self.get_database_connection().await?,
+            self.get_cardano_transaction_connection_pool().await?,
             self.get_signed_entity_lock().await?,
+            self.build_store_retention_pruner().await?,
             self.get_logger()?,
         ));
 
@@ -1308,6 +1543,34 @@ impl DependenciesBuilder {
         Ok(self.upkeep_service.as_ref().cloned().unwrap())
     }
 
+    async fn build_leader_election_service(&mut self) -> Result<Arc<dyn LeaderElectionService>> {
+        let leader_election_service: Arc<dyn LeaderElectionService> = match &self
+            .configuration
+            .high_availability_node_id
+        {
+            Some(node_id) => Arc::new(MithrilLeaderElectionService::new(
+                node_id.clone(),
+                Duration::from_millis(self.configuration.high_availability_leader_lease_duration),
+                Arc::new(LeaderLockRepository::new(
+                    self.get_database_connection().await?,
+                )),
+                self.get_logger()?,
+            )),
+            None => Arc::new(StandaloneLeaderElectionService),
+        };
+
+        Ok(leader_election_service)
+    }
+
+    /// [LeaderElectionService] service
+    pub async fn get_leader_election_service(&mut self) -> Result<Arc<dyn LeaderElectionService>> {
+        if self.leader_election_service.is_none() {
+            self.leader_election_service = Some(self.build_leader_election_service().await?);
+        }
+
+        Ok(self.leader_election_service.as_ref().cloned().unwrap())
+    }
+
     async fn build_single_signature_authenticator(
         &mut self,
     ) -> Result<Arc<SingleSignatureAuthenticator>> {
@@ -1344,9 +1607,9 @@ impl DependenciesBuilder {
         let dependency_manager = DependencyContainer {
             config: self.configuration.clone(),
             signed_entity_config: self.get_signed_entity_config()?,
-            sqlite_connection: self.get_sqlite_connection().await?,
-            sqlite_connection_cardano_transaction_pool: self
-                .get_sqlite_connection_cardano_transaction_pool()
+            database_connection: self.get_database_connection().await?,
+            cardano_transaction_connection_pool: self
+                .get_cardano_transaction_connection_pool()
                 .await?,
             stake_store: self.get_stake_store().await?,
             snapshot_uploader: self.get_snapshot_uploader().await?,
@@ -1384,6 +1647,7 @@ impl DependenciesBuilder {
             signed_entity_type_lock: self.get_signed_entity_lock().await?,
             upkeep_service: self.get_upkeep_service().await?,
             single_signer_authenticator: self.get_single_signature_authenticator().await?,
+            leader_election_service: self.get_leader_election_service().await?,
         };
 
         Ok(dependency_manager)
@@ -1443,7 +1707,10 @@ impl DependenciesBuilder {
                 .security_parameter,
             self.get_chain_observer().await?,
             self.get_logger()?,
-            Arc::new(CardanoTransactionsPreloaderActivation::new(activation)),
+            Arc::new(LeaderCardanoTransactionsPreloaderActivation::new(
+                activation,
+                self.get_leader_election_service().await?,
+            )),
         );
 
         Ok(Arc::new(cardano_transactions_preloader))
@@ -1477,7 +1744,8 @@ impl DependenciesBuilder {
             CExplorerSignerRetriever::new(cexplorer_pools_url, Some(Duration::from_secs(30)))?;
         let persister = self.get_signer_store().await?;
 
-        Ok(SignersImporter::new(Arc::new(retriever), persister))
+        Ok(SignersImporter::new(Arc::new(retriever), persister)
+            .with_leader_election_service(self.get_leader_election_service().await?))
     }
 
     /// Create [TickerService] instance.
@@ -1505,10 +1773,10 @@ impl DependenciesBuilder {
         let cardano_network = self.configuration.get_network().with_context(|| {
             "Dependencies Builder can not get Cardano network while building the chain observer"
         })?;
-        let sqlite_connection = self.get_sqlite_connection().await?;
+        let database_connection = self.get_database_connection().await?;
         let open_message_repository = self.get_open_message_repository().await?;
         let single_signature_repository =
-            Arc::new(SingleSignatureRepository::new(sqlite_connection.clone()));
+            Arc::new(SingleSignatureRepository::new(database_connection.clone()));
         let certificate_repository = self.get_certificate_repository().await?;
         let certificate_verifier = self.get_certificate_verifier().await?;
         let genesis_verifier = self.get_genesis_verifier().await?;
@@ -1532,7 +1800,7 @@ impl DependenciesBuilder {
 
         Ok(Arc::new(BufferedCertifierService::new(
             certifier,
-            Arc::new(BufferedSingleSignatureRepository::new(sqlite_connection)),
+            Arc::new(BufferedSingleSignatureRepository::new(database_connection)),
             self.get_logger()?,
         )))
     }
@@ -1549,7 +1817,7 @@ impl DependenciesBuilder {
     /// build HTTP message service
     pub async fn build_message_service(&mut self) -> Result<Arc<dyn MessageService>> {
         let certificate_repository = Arc::new(CertificateRepository::new(
-            self.get_sqlite_connection().await?,
+            self.get_database_connection().await?,
         ));
         let signed_entity_storer = self.get_signed_entity_storer().await?;
         let service = MithrilMessageService::new(certificate_repository, signed_entity_storer);
@@ -1573,15 +1841,44 @@ impl DependenciesBuilder {
             .cardano_transactions_prover_cache_pool_size;
         let transaction_retriever = self.get_transaction_repository().await?;
         let block_range_root_retriever = self.get_transaction_repository().await?;
+        let is_extended_mode_enabled = self.configuration.enable_cardano_transactions_extended_mode;
         let logger = self.get_logger()?;
-        let prover_service = MithrilProverService::<MKTreeStoreInMemory>::new(
-            transaction_retriever,
-            block_range_root_retriever,
-            mk_map_pool_size,
-            logger,
-        );
+        let prover_service: Arc<dyn ProverService> = match self.configuration.environment {
+            ExecutionEnvironment::Test
+                if self.configuration.data_stores_directory.to_string_lossy() == ":memory:" =>
+            {
+                Arc::new(
+                    MithrilProverService::<MKTreeStoreInMemory>::new(
+                        transaction_retriever,
+                        block_range_root_retriever,
+                        mk_map_pool_size,
+                        logger,
+                    )
+                    .with_extended_mode(is_extended_mode_enabled),
+                )
+            }
+            _ => {
+                // The prover cache is specific to each aggregator of a high availability cluster
+                let cache_file = match &self.configuration.high_availability_node_id {
+                    Some(node_id) => format!("prover-cache-{node_id}.sqlite3"),
+                    None => SQLITE_FILE_PROVER_CACHE.to_string(),
+                };
+                let cache_store =
+                    MKTreeStoreSqlite::open(&self.configuration.get_sqlite_dir().join(cache_file))?;
+                Arc::new(
+                    MithrilProverService::<MKTreeStoreSqlite>::new(
+                        transaction_retriever,
+                        block_range_root_retriever,
+                        mk_map_pool_size,
+                        logger,
+                    )
+                    .with_extended_mode(is_extended_mode_enabled)
+                    .with_cache_store(Arc::new(cache_store)),
+                )
+            }
+        };
 
-        Ok(Arc::new(prover_service))
+        Ok(prover_service)
     }
 
     /// [ProverService] service
@@ -1,4 +1,4 @@
-use mithril_persistence::sqlite::SqliteConnectionPool;
+use mithril_persistence::sqlite::DatabaseConnectionPool;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -19,7 +19,7 @@ use mithril_common::{
     test_utils::MithrilFixture,
     TickerService,
 };
-use mithril_persistence::{sqlite::SqliteConnection, store::StakeStorer};
+use mithril_persistence::{sqlite::DatabaseConnection, store::StakeStorer};
 
 use crate::{
     configuration::*,
@@ -31,8 +31,8 @@ use crate::{
     event_store::{EventMessage, TransmitterService},
     multi_signer::MultiSigner,
     services::{
-        CertifierService, EpochService, MessageService, ProverService, SignedEntityService,
-        StakeDistributionService, TransactionStore, UpkeepService,
+        CertifierService, EpochService, LeaderElectionService, MessageService, ProverService,
+        SignedEntityService, StakeDistributionService, TransactionStore, UpkeepService,
     },
     signer_registerer::SignerRecorder,
     snapshot_uploaders::SnapshotUploader,
@@ -51,14 +51,14 @@ pub struct DependencyContainer {
     /// Signed entity configuration.
     pub signed_entity_config: SignedEntityConfig,
 
-    /// SQLite database connection
+    /// Database connection, to a SQLite database or to a PostgreSQL one if configured
     ///
     /// This is not a real service, but it is needed to instantiate all store
     /// services. Should be a private dependency.
-    pub sqlite_connection: Arc<SqliteConnection>,
+    pub database_connection: Arc<DatabaseConnection>,
 
-    /// Cardano transactions SQLite database connection pool
-    pub sqlite_connection_cardano_transaction_pool: Arc<SqliteConnectionPool>,
+    /// Cardano transactions database connection pool
+    pub cardano_transaction_connection_pool: Arc<DatabaseConnectionPool>,
 
     /// Stake Store used by the StakeDistributionService
     /// It shall be a private dependency.
@@ -168,6 +168,9 @@ pub struct DependencyContainer {
 
     /// Single signer authenticator
     pub single_signer_authenticator: Arc<SingleSignatureAuthenticator>,
+
+    /// Leader election service
+    pub leader_election_service: Arc<dyn LeaderElectionService>,
 }
 
 #[doc(hidden)]
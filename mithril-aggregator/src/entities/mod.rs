@@ -4,11 +4,13 @@
 //!
 mod aggregator_epoch_settings;
 mod open_message;
+mod protocol_parameters_schedule;
 mod signer_registration_message;
 mod signer_ticker_message;
 
 pub use aggregator_epoch_settings::AggregatorEpochSettings;
 pub use open_message::OpenMessage;
+pub use protocol_parameters_schedule::{ProtocolParametersSchedule, ScheduledProtocolParameters};
 pub use signer_registration_message::{
     SignerRegistrationsListItemMessage, SignerRegistrationsMessage,
 };
@@ -0,0 +1,235 @@
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use mithril_common::entities::{Epoch, ProtocolParameters};
+use mithril_common::StdResult;
+
+/// Protocol parameters that are effective from a given epoch
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledProtocolParameters {
+    /// Epoch from which the protocol parameters are effective
+    pub from_epoch: Epoch,
+
+    /// Protocol parameters
+    pub protocol_parameters: ProtocolParameters,
+}
+
+/// Declarative schedule of the protocol parameters
+///
+/// Each entry of the schedule defines the protocol parameters effective from its epoch until the
+/// epoch of the next entry.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct ProtocolParametersSchedule {
+    entries: Vec<ScheduledProtocolParameters>,
+}
+
+impl ProtocolParametersSchedule {
+    /// Create a new schedule, entries are ordered by epoch
+    ///
+    /// Fails if two entries are effective from the same epoch, or if the protocol parameters of
+    /// an entry are invalid.
+    pub fn new(mut entries: Vec<ScheduledProtocolParameters>) -> StdResult<Self> {
+        for entry in &entries {
+            Self::validate_protocol_parameters(&entry.protocol_parameters).with_context(|| {
+                format!(
+                    "Protocol parameters schedule has invalid protocol parameters for epoch {}",
+                    entry.from_epoch
+                )
+            })?;
+        }
+        entries.sort_by_key(|entry| entry.from_epoch);
+        if let Some(duplicate) = entries
+            .windows(2)
+            .find(|pair| pair[0].from_epoch == pair[1].from_epoch)
+        {
+            return Err(anyhow!(
+                "Protocol parameters schedule has several entries for epoch {}",
+                duplicate[0].from_epoch
+            ));
+        }
+
+        Ok(Self { entries })
+    }
+
+    fn validate_protocol_parameters(protocol_parameters: &ProtocolParameters) -> StdResult<()> {
+        if protocol_parameters.k == 0 {
+            return Err(anyhow!("k must be greater than 0"));
+        }
+        if protocol_parameters.m <= protocol_parameters.k {
+            return Err(anyhow!(
+                "m ({}) must be greater than k ({})",
+                protocol_parameters.m,
+                protocol_parameters.k
+            ));
+        }
+        if !(protocol_parameters.phi_f > 0.0 && protocol_parameters.phi_f <= 1.0) {
+            return Err(anyhow!(
+                "phi_f ({}) must be greater than 0 and lower than or equal to 1",
+                protocol_parameters.phi_f
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Load a schedule from a JSON file containing a list of [ScheduledProtocolParameters]
+    pub fn from_file(path: &Path) -> StdResult<Self> {
+        let content = std::fs::read_to_string(path).with_context(|| {
+            format!(
+                "Could not read protocol parameters schedule file '{}'",
+                path.display()
+            )
+        })?;
+        serde_json::from_str(&content).with_context(|| {
+            format!(
+                "Could not parse protocol parameters schedule file '{}'",
+                path.display()
+            )
+        })
+    }
+
+    /// Get the protocol parameters effective at the given epoch, if any
+    pub fn protocol_parameters_at(&self, epoch: Epoch) -> Option<&ProtocolParameters> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.from_epoch <= epoch)
+            .map(|entry| &entry.protocol_parameters)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolParametersSchedule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let entries = Vec::<ScheduledProtocolParameters>::deserialize(deserializer)?;
+
+        Self::new(entries).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    fn scheduled(from_epoch: u64, k: u64) -> ScheduledProtocolParameters {
+        ScheduledProtocolParameters {
+            from_epoch: Epoch(from_epoch),
+            protocol_parameters: ProtocolParameters::new(k, 100, 0.65),
+        }
+    }
+
+    #[test]
+    fn get_protocol_parameters_effective_at_epoch() {
+        let schedule =
+            ProtocolParametersSchedule::new(vec![scheduled(20, 2), scheduled(10, 1)]).unwrap();
+
+        assert_eq!(None, schedule.protocol_parameters_at(Epoch(9)));
+        assert_eq!(
+            Some(&ProtocolParameters::new(1, 100, 0.65)),
+            schedule.protocol_parameters_at(Epoch(10))
+        );
+        assert_eq!(
+            Some(&ProtocolParameters::new(1, 100, 0.65)),
+            schedule.protocol_parameters_at(Epoch(19))
+        );
+        assert_eq!(
+            Some(&ProtocolParameters::new(2, 100, 0.65)),
+            schedule.protocol_parameters_at(Epoch(20))
+        );
+        assert_eq!(
+            Some(&ProtocolParameters::new(2, 100, 0.65)),
+            schedule.protocol_parameters_at(Epoch(500))
+        );
+    }
+
+    #[test]
+    fn empty_schedule_has_no_protocol_parameters() {
+        let schedule = ProtocolParametersSchedule::default();
+
+        assert_eq!(None, schedule.protocol_parameters_at(Epoch(10)));
+    }
+
+    #[test]
+    fn schedule_with_several_entries_for_the_same_epoch_is_invalid() {
+        ProtocolParametersSchedule::new(vec![scheduled(10, 1), scheduled(10, 2)])
+            .expect_err("Schedule with duplicated epochs should be invalid");
+    }
+
+    #[test]
+    fn schedule_with_invalid_protocol_parameters_is_invalid() {
+        let scheduled_with = |k: u64, m: u64, phi_f: f64| ScheduledProtocolParameters {
+            from_epoch: Epoch(10),
+            protocol_parameters: ProtocolParameters::new(k, m, phi_f),
+        };
+
+        ProtocolParametersSchedule::new(vec![scheduled_with(1, 100, 1.0)])
+            .expect("Schedule with valid protocol parameters should be valid");
+        for (k, m, phi_f) in [
+            (0, 100, 0.65),
+            (100, 100, 0.65),
+            (101, 100, 0.65),
+            (5, 100, 0.0),
+            (5, 100, -0.1),
+            (5, 100, 1.1),
+            (5, 100, f64::NAN),
+        ] {
+            ProtocolParametersSchedule::new(vec![scheduled(5, 1), scheduled_with(k, m, phi_f)])
+                .expect_err(&format!(
+                "Schedule with protocol parameters k={k}, m={m}, phi_f={phi_f} should be invalid"
+            ));
+        }
+    }
+
+    #[test]
+    fn load_schedule_from_json_file() {
+        let path = TempDir::create(
+            "protocol_parameters_schedule",
+            "load_schedule_from_json_file",
+        )
+        .join("schedule.json");
+        std::fs::write(
+            &path,
+            r#"[
+                { "from_epoch": 30, "protocol_parameters": { "k": 3, "m": 300, "phi_f": 0.3 } },
+                { "from_epoch": 10, "protocol_parameters": { "k": 1, "m": 100, "phi_f": 0.1 } }
+            ]"#,
+        )
+        .unwrap();
+
+        let schedule = ProtocolParametersSchedule::from_file(&path).unwrap();
+
+        assert_eq!(
+            ProtocolParametersSchedule::new(vec![
+                ScheduledProtocolParameters {
+                    from_epoch: Epoch(10),
+                    protocol_parameters: ProtocolParameters::new(1, 100, 0.1),
+                },
+                ScheduledProtocolParameters {
+                    from_epoch: Epoch(30),
+                    protocol_parameters: ProtocolParameters::new(3, 300, 0.3),
+                },
+            ])
+            .unwrap(),
+            schedule
+        );
+    }
+
+    #[test]
+    fn load_schedule_from_invalid_json_file_fails() {
+        let path = TempDir::create(
+            "protocol_parameters_schedule",
+            "load_schedule_from_invalid_json_file_fails",
+        )
+        .join("schedule.json");
+        std::fs::write(&path, r#"{ "from_epoch": 30 }"#).unwrap();
+
+        ProtocolParametersSchedule::from_file(&path)
+            .expect_err("Loading an invalid schedule file should fail");
+    }
+}
@@ -5,7 +5,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{
-    ConnectionExtensions, HydrationError, Projection, Query, SourceAlias, SqLiteEntity,
+    ConnectionExtensions, HydrationError, Projection, Query, Row, SourceAlias, SqLiteEntity,
     SqliteConnection, WhereCondition,
 };
 
@@ -67,7 +67,7 @@ pub struct Event {
 }
 
 impl SqLiteEntity for Event {
-    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
         Self: Sized,
     {
@@ -1,3 +1,4 @@
+pub mod cardano_drep_stake_distribution;
 pub mod cardano_stake_distribution;
 pub mod cardano_transaction;
 pub mod mithril_stake_distribution;
@@ -95,6 +95,12 @@ mod handlers {
                 .await,
             "proof_cardano_transaction::error"
         ) {
+            Some(_) if !prover_service.is_cache_ready() => {
+                warn!("proof_cardano_transaction::service_unavailable");
+                Ok(reply::service_unavailable(
+                    "Cardano transactions prover cache is not ready yet",
+                ))
+            }
             Some(signed_entity) => {
                 let message = unwrap_to_internal_server_error!(
                     build_response_message(prover_service, signed_entity, sanitized_hashes).await,
@@ -210,6 +216,9 @@ mod tests {
         dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
 
         let mut mock_prover_service = MockProverService::new();
+        mock_prover_service
+            .expect_is_cache_ready()
+            .return_const(true);
         mock_prover_service
             .expect_compute_transactions_proofs()
             .returning(|_, _| Ok(vec![CardanoTransactionsSetProof::dummy()]));
@@ -271,6 +280,51 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn proof_cardano_transaction_service_unavailable_while_prover_cache_is_not_ready() {
+        let config = Configuration::new_sample();
+        let mut builder = DependenciesBuilder::new(config);
+        let mut dependency_manager = builder.build_dependency_container().await.unwrap();
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_last_cardano_transaction_snapshot()
+            .returning(|| Ok(Some(SignedEntity::<CardanoTransactionsSnapshot>::dummy())));
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+        let mut mock_prover_service = MockProverService::new();
+        mock_prover_service
+            .expect_is_cache_ready()
+            .return_const(false);
+        mock_prover_service
+            .expect_compute_transactions_proofs()
+            .never();
+        dependency_manager.prover_service = Arc::new(mock_prover_service);
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-transaction";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "/{SERVER_BASE_PATH}{path}?transaction_hashes={},{}",
+                fake_data::transaction_hashes()[0],
+                fake_data::transaction_hashes()[1]
+            ))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::SERVICE_UNAVAILABLE,
+        )
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn proof_cardano_transaction_ko() {
         let config = Configuration::new_sample();
@@ -350,6 +404,9 @@ mod tests {
 
         let mut mock_prover_service = MockProverService::new();
         let txs_expected = vec![tx.clone()];
+        mock_prover_service
+            .expect_is_cache_ready()
+            .return_const(true);
         mock_prover_service
             .expect_compute_transactions_proofs()
             .withf(move |_, transaction_hashes| transaction_hashes == txs_expected)
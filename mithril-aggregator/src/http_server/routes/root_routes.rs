@@ -21,7 +21,8 @@ fn root(
         .and(middlewares::with_signed_entity_config(
             dependency_manager.clone(),
         ))
-        .and(middlewares::with_config(dependency_manager))
+        .and(middlewares::with_config(dependency_manager.clone()))
+        .and(middlewares::with_prover_service(dependency_manager))
         .and_then(handlers::root)
 }
 
@@ -38,6 +39,7 @@ mod handlers {
     };
 
     use crate::http_server::routes::reply::json;
+    use crate::services::ProverService;
     use crate::{unwrap_to_internal_server_error, Configuration};
 
     /// Root
@@ -45,6 +47,7 @@ mod handlers {
         api_version_provider: Arc<APIVersionProvider>,
         signed_entity_config: SignedEntityConfig,
         configuration: Configuration,
+        prover_service: Arc<dyn ProverService>,
     ) -> Result<impl warp::Reply, Infallible> {
         debug!("⇄ HTTP SERVER: root");
 
@@ -68,6 +71,7 @@ mod handlers {
                 Some(CardanoTransactionsProverCapabilities {
                     max_hashes_allowed_by_request: configuration
                         .cardano_transactions_prover_max_hashes_allowed_by_request,
+                    is_cache_ready: Some(prover_service.is_cache_ready()),
                 });
 
             capabilities.cardano_transactions_signing_config =
@@ -88,6 +92,7 @@ mod handlers {
 #[cfg(test)]
 mod tests {
     use crate::http_server::SERVER_BASE_PATH;
+    use crate::services::MockProverService;
     use crate::{initialize_dependencies, DependencyContainer};
     use mithril_common::entities::{
         BlockNumber, CardanoTransactionsSigningConfig, SignedEntityTypeDiscriminants,
@@ -197,6 +202,9 @@ mod tests {
         dependency_manager
             .config
             .cardano_transactions_signing_config = signing_config.clone();
+        let mut prover_service = MockProverService::new();
+        prover_service.expect_is_cache_ready().return_const(true);
+        dependency_manager.prover_service = Arc::new(prover_service);
 
         let response = request()
             .method(method)
@@ -212,7 +220,8 @@ mod tests {
         assert_eq!(
             response_body.capabilities.cardano_transactions_prover,
             Some(CardanoTransactionsProverCapabilities {
-                max_hashes_allowed_by_request: 99
+                max_hashes_allowed_by_request: 99,
+                is_cache_ready: Some(true),
             })
         );
         assert_eq!(
@@ -233,4 +242,47 @@ mod tests {
         )
         .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_root_route_advertise_prover_cache_not_ready() {
+        let method = Method::GET.as_str();
+        let path = "/";
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager
+            .signed_entity_config
+            .allowed_discriminants =
+            BTreeSet::from([SignedEntityTypeDiscriminants::CardanoTransactions]);
+        let mut prover_service = MockProverService::new();
+        prover_service.expect_is_cache_ready().return_const(false);
+        dependency_manager.prover_service = Arc::new(prover_service);
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        let response_body: AggregatorFeaturesMessage =
+            serde_json::from_slice(response.body()).unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response_body
+                .capabilities
+                .cardano_transactions_prover
+                .and_then(|prover| prover.is_cache_ready),
+            Some(false)
+        );
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
 }
@@ -1,8 +1,9 @@
 use crate::http_server::routes::{
-    artifact_routes, certificate_routes, epoch_routes, root_routes, signatures_routes,
+    artifact_routes, certificate_routes, epoch_routes, reply, root_routes, signatures_routes,
     signer_routes, statistics_routes,
 };
 use crate::http_server::SERVER_BASE_PATH;
+use crate::services::LeaderElectionService;
 use crate::DependencyContainer;
 
 use mithril_common::api_version::APIVersionProvider;
@@ -27,6 +28,11 @@ pub struct VersionParseError;
 
 impl Reject for VersionParseError {}
 
+#[derive(Debug)]
+pub struct NotLeaderError;
+
+impl Reject for NotLeaderError {}
+
 /// Routes
 pub fn routes(
     dependency_manager: Arc<DependencyContainer>,
@@ -41,6 +47,9 @@ pub fn routes(
             dependency_manager.api_version_provider.clone(),
         ))
         .and(warp::path(SERVER_BASE_PATH))
+        .and(write_requests_must_target_leader(
+            dependency_manager.leader_election_service.clone(),
+        ))
         .and(
             certificate_routes::routes(dependency_manager.clone())
                 .or(artifact_routes::snapshot::routes(
@@ -52,6 +61,9 @@ pub fn routes(
                 .or(artifact_routes::cardano_stake_distribution::routes(
                     dependency_manager.clone(),
                 ))
+                .or(artifact_routes::cardano_drep_stake_distribution::routes(
+                    dependency_manager.clone(),
+                ))
                 .or(artifact_routes::cardano_transaction::routes(
                     dependency_manager.clone(),
                 ))
@@ -108,9 +120,31 @@ fn header_must_be(
         .untuple_one()
 }
 
-pub async fn handle_custom(reject: Rejection) -> Result<impl Reply, Rejection> {
+/// Write requests verification: only the leader of a high availability cluster accepts them
+fn write_requests_must_target_leader(
+    leader_election_service: Arc<dyn LeaderElectionService>,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::method()
+        .and(warp::any().map(move || leader_election_service.clone()))
+        .and_then(
+            move |method: Method, leader_election_service: Arc<dyn LeaderElectionService>| async move {
+                if method == Method::POST && !leader_election_service.is_leader() {
+                    Err(warp::reject::custom(NotLeaderError))
+                } else {
+                    Ok(())
+                }
+            },
+        )
+        .untuple_one()
+}
+
+pub async fn handle_custom(reject: Rejection) -> Result<Box<dyn Reply>, Rejection> {
     if reject.find::<VersionMismatchError>().is_some() {
-        Ok(StatusCode::PRECONDITION_FAILED)
+        Ok(Box::new(StatusCode::PRECONDITION_FAILED))
+    } else if reject.find::<NotLeaderError>().is_some() {
+        Ok(reply::service_unavailable(
+            "This aggregator is not the leader of its cluster".to_string(),
+        ))
     } else {
         Err(reject)
     }
@@ -126,8 +160,54 @@ mod tests {
         era::{EraChecker, SupportedEra},
     };
 
+    use crate::services::MockLeaderElectionService;
+
     use super::*;
 
+    fn leader_election_service(is_leader: bool) -> Arc<dyn LeaderElectionService> {
+        let mut leader_election_service = MockLeaderElectionService::new();
+        leader_election_service
+            .expect_is_leader()
+            .return_const(is_leader);
+
+        Arc::new(leader_election_service)
+    }
+
+    #[tokio::test]
+    async fn test_write_request_on_follower_is_rejected() {
+        let filters = write_requests_must_target_leader(leader_election_service(false));
+        let rejection = warp::test::request()
+            .method("POST")
+            .path("/aggregator/whatever")
+            .filter(&filters)
+            .await
+            .expect_err("write request on a follower should be rejected");
+
+        assert!(rejection.find::<NotLeaderError>().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_read_request_on_follower_is_accepted() {
+        let filters = write_requests_must_target_leader(leader_election_service(false));
+        warp::test::request()
+            .method("GET")
+            .path("/aggregator/whatever")
+            .filter(&filters)
+            .await
+            .expect("read request on a follower should not be rejected");
+    }
+
+    #[tokio::test]
+    async fn test_write_request_on_leader_is_accepted() {
+        let filters = write_requests_must_target_leader(leader_election_service(true));
+        warp::test::request()
+            .method("POST")
+            .path("/aggregator/whatever")
+            .filter(&filters)
+            .await
+            .expect("write request on the leader should not be rejected");
+    }
+
     #[tokio::test]
     async fn test_no_version() {
         let era_checker = EraChecker::new(SupportedEra::dummy(), Epoch(1));
@@ -53,7 +53,9 @@ pub use snapshotter::{
     SnapshotterCompressionAlgorithm,
 };
 pub use store::{
-    CertificatePendingStore, EpochSettingsStorer, VerificationKeyStore, VerificationKeyStorer,
+    CertificatePendingStore, EpochSettingsStorer, FileProtocolParametersScheduleProvider,
+    MKTreeStoreSqlite, ProtocolParametersScheduleProvider, VerificationKeyStore,
+    VerificationKeyStorer,
 };
 pub use tools::{
     CExplorerSignerRetriever, SignersImporter, SignersImporterPersister, SignersImporterRetriever,
@@ -1,5 +1,7 @@
 mod from_register_signature;
 mod from_register_signer;
+mod to_cardano_drep_stake_distribution_list_message;
+mod to_cardano_drep_stake_distribution_message;
 mod to_cardano_stake_distribution_list_message;
 mod to_cardano_stake_distribution_message;
 mod to_cardano_transaction_list_message;
@@ -14,6 +16,10 @@ mod to_snapshot_message;
 pub use from_register_signature::FromRegisterSingleSignatureAdapter;
 pub use from_register_signer::FromRegisterSignerAdapter;
 #[cfg(test)]
+pub use to_cardano_drep_stake_distribution_list_message::ToCardanoDRepStakeDistributionListMessageAdapter;
+#[cfg(test)]
+pub use to_cardano_drep_stake_distribution_message::ToCardanoDRepStakeDistributionMessageAdapter;
+#[cfg(test)]
 pub use to_cardano_stake_distribution_list_message::ToCardanoStakeDistributionListMessageAdapter;
 #[cfg(test)]
 pub use to_cardano_stake_distribution_message::ToCardanoStakeDistributionMessageAdapter;
@@ -0,0 +1,56 @@
+use mithril_common::entities::{CardanoDRepStakeDistribution, SignedEntity};
+use mithril_common::messages::{
+    CardanoDRepStakeDistributionListItemMessage, CardanoDRepStakeDistributionListMessage,
+    ToMessageAdapter,
+};
+
+/// Adapter to convert a list of [CardanoDRepStakeDistribution] to [CardanoDRepStakeDistributionListMessage] instances
+#[allow(dead_code)]
+pub struct ToCardanoDRepStakeDistributionListMessageAdapter;
+
+impl
+    ToMessageAdapter<
+        Vec<SignedEntity<CardanoDRepStakeDistribution>>,
+        CardanoDRepStakeDistributionListMessage,
+    > for ToCardanoDRepStakeDistributionListMessageAdapter
+{
+    /// Method to trigger the conversion
+    fn adapt(
+        snapshots: Vec<SignedEntity<CardanoDRepStakeDistribution>>,
+    ) -> CardanoDRepStakeDistributionListMessage {
+        snapshots
+            .into_iter()
+            .map(|entity| CardanoDRepStakeDistributionListItemMessage {
+                epoch: entity.signed_entity_type.get_epoch(),
+                hash: entity.artifact.hash,
+                certificate_hash: entity.certificate_id,
+                created_at: entity.created_at,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adapt_ok() {
+        let signed_entity = SignedEntity::<CardanoDRepStakeDistribution>::dummy();
+        let cardano_drep_stake_distribution_list_message_expected =
+            vec![CardanoDRepStakeDistributionListItemMessage {
+                epoch: signed_entity.artifact.epoch,
+                hash: signed_entity.artifact.hash.clone(),
+                certificate_hash: signed_entity.certificate_id.clone(),
+                created_at: signed_entity.created_at,
+            }];
+
+        let cardano_drep_stake_distribution_list_message =
+            ToCardanoDRepStakeDistributionListMessageAdapter::adapt(vec![signed_entity]);
+
+        assert_eq!(
+            cardano_drep_stake_distribution_list_message_expected,
+            cardano_drep_stake_distribution_list_message
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use mithril_common::entities::{CardanoDRepStakeDistribution, SignedEntity};
+use mithril_common::messages::{CardanoDRepStakeDistributionMessage, ToMessageAdapter};
+
+/// Adapter to convert [CardanoDRepStakeDistribution] to [CardanoDRepStakeDistributionMessage] instances
+#[allow(dead_code)]
+pub struct ToCardanoDRepStakeDistributionMessageAdapter;
+
+impl
+    ToMessageAdapter<
+        SignedEntity<CardanoDRepStakeDistribution>,
+        CardanoDRepStakeDistributionMessage,
+    > for ToCardanoDRepStakeDistributionMessageAdapter
+{
+    /// Method to trigger the conversion
+    fn adapt(
+        from: SignedEntity<CardanoDRepStakeDistribution>,
+    ) -> CardanoDRepStakeDistributionMessage {
+        CardanoDRepStakeDistributionMessage {
+            epoch: from.signed_entity_type.get_epoch(),
+            hash: from.artifact.hash,
+            certificate_hash: from.certificate_id,
+            drep_stake_distribution: from.artifact.drep_stake_distribution,
+            created_at: from.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adapt_ok() {
+        let signed_entity = SignedEntity::<CardanoDRepStakeDistribution>::dummy();
+        let cardano_drep_stake_distribution_message_expected =
+            CardanoDRepStakeDistributionMessage {
+                epoch: signed_entity.artifact.epoch,
+                hash: signed_entity.artifact.hash.clone(),
+                certificate_hash: signed_entity.certificate_id.clone(),
+                drep_stake_distribution: signed_entity.artifact.drep_stake_distribution.clone(),
+                created_at: signed_entity.created_at,
+            };
+
+        let cardano_drep_stake_distribution_message =
+            ToCardanoDRepStakeDistributionMessageAdapter::adapt(signed_entity);
+
+        assert_eq!(
+            cardano_drep_stake_distribution_message_expected,
+            cardano_drep_stake_distribution_message
+        );
+    }
+}
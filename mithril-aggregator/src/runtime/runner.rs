@@ -41,6 +41,15 @@ impl AggregatorConfig {
 /// It exposes all the methods needed by the state machine.
 #[async_trait]
 pub trait AggregatorRunnerTrait: Sync + Send {
+    /// Check if this aggregator is the leader of its cluster and must run the certification.
+    async fn is_leader(&self) -> StdResult<bool>;
+
+    /// Renew the leadership of this aggregator in the shared database before persisting a
+    /// certificate, so that it can not be written by an aggregator that lost the leadership.
+    ///
+    /// Returns `true` if this aggregator is still the leader.
+    async fn confirm_leadership(&self) -> StdResult<bool>;
+
     /// Return the current [TimePoint] from the chain
     async fn get_time_point_from_chain(&self) -> StdResult<TimePoint>;
 
@@ -165,6 +174,21 @@ impl AggregatorRunner {
 #[cfg_attr(test, automock)]
 #[async_trait]
 impl AggregatorRunnerTrait for AggregatorRunner {
+    async fn is_leader(&self) -> StdResult<bool> {
+        debug!("RUNNER: is leader");
+
+        Ok(self.dependencies.leader_election_service.is_leader())
+    }
+
+    async fn confirm_leadership(&self) -> StdResult<bool> {
+        debug!("RUNNER: confirm leadership");
+
+        self.dependencies
+            .leader_election_service
+            .refresh_leadership()
+            .await
+    }
+
     /// Return the current time point from the chain
     async fn get_time_point_from_chain(&self) -> StdResult<TimePoint> {
         debug!("RUNNER: get time point from chain");
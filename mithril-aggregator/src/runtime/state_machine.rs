@@ -162,6 +162,35 @@ impl AggregatorRuntime {
         info!("================================================================================");
         info!("STATE MACHINE: new cycle: {}", self.state);
 
+        if !self
+            .runner
+            .is_leader()
+            .await
+            .with_context(|| "AggregatorRuntime can not check if it is the leader")?
+        {
+            // Another aggregator of the cluster is running the certification, restart from
+            // scratch when the leadership is acquired since the shared stores may have changed.
+            // Going back to IDLE without a time point makes the new leader rebuild its epoch
+            // data, including the signer registration round, which is only kept in memory.
+            info!("→ This aggregator is not the leader, skipping cycle");
+            if !matches!(
+                self.state,
+                AggregatorState::Idle(IdleState {
+                    current_time_point: None
+                })
+            ) {
+                info!(
+                    "→ This aggregator lost the leadership, closing its signer registration round"
+                );
+                self.runner.close_signer_registration_round().await?;
+            }
+            self.state = AggregatorState::Idle(IdleState {
+                current_time_point: None,
+            });
+
+            return Ok(());
+        }
+
         match self.state.clone() {
             AggregatorState::Idle(state) => {
                 let last_time_point = self.runner.get_time_point_from_chain().await.with_context(
@@ -316,6 +345,25 @@ impl AggregatorRuntime {
         state: SigningState,
     ) -> Result<ReadyState, RuntimeError> {
         trace!("launching transition from SIGNING to READY state");
+        // Fence the certificate write: the leadership is renewed right before persisting it so
+        // that an aggregator that lost the lock does not write a certificate concurrently
+        // with the new leader.
+        let is_still_leader =
+            self.runner
+                .confirm_leadership()
+                .await
+                .map_err(|e| RuntimeError::KeepState {
+                    message: "transiting SIGNING → READY: failed to confirm the leadership"
+                        .to_string(),
+                    nested_error: Some(e),
+                })?;
+        if !is_still_leader {
+            return Err(RuntimeError::ReInit {
+                message: "transiting SIGNING → READY: the leadership was lost before creating the certificate"
+                    .to_string(),
+                nested_error: None,
+            });
+        }
         let certificate = self
             .runner
             .create_certificate(&state.open_message.signed_entity_type)
@@ -417,8 +465,10 @@ mod tests {
 
     async fn init_runtime(
         init_state: Option<AggregatorState>,
-        runner: MockAggregatorRunner,
+        mut runner: MockAggregatorRunner,
     ) -> AggregatorRuntime {
+        runner.expect_is_leader().returning(|| Ok(true));
+        runner.expect_confirm_leadership().returning(|| Ok(true));
         AggregatorRuntime::new(
             AggregatorConfig::new(Duration::from_millis(20), SignedEntityConfig::dummy()),
             init_state,
@@ -806,4 +856,154 @@ mod tests {
 
         assert_eq!("idle".to_string(), runtime.get_state());
     }
+
+    #[tokio::test]
+    async fn follower_does_not_run_the_certification_and_goes_back_to_idle() {
+        let mut runner = MockAggregatorRunner::new();
+        runner.expect_is_leader().once().returning(|| Ok(false));
+        runner.expect_get_time_point_from_chain().never();
+        runner
+            .expect_close_signer_registration_round()
+            .once()
+            .returning(|| Ok(()));
+
+        let mut runtime = AggregatorRuntime::new(
+            AggregatorConfig::new(Duration::from_millis(20), SignedEntityConfig::dummy()),
+            Some(AggregatorState::Ready(ReadyState {
+                current_time_point: TimePoint::dummy(),
+            })),
+            Arc::new(runner),
+        )
+        .await
+        .unwrap();
+        runtime.cycle().await.unwrap();
+
+        assert_eq!("idle".to_string(), runtime.get_state());
+    }
+
+    #[tokio::test]
+    async fn follower_already_idle_does_not_close_the_signer_registration_round_again() {
+        let mut runner = MockAggregatorRunner::new();
+        runner.expect_is_leader().once().returning(|| Ok(false));
+        runner.expect_close_signer_registration_round().never();
+
+        let mut runtime = AggregatorRuntime::new(
+            AggregatorConfig::new(Duration::from_millis(20), SignedEntityConfig::dummy()),
+            Some(AggregatorState::Idle(IdleState {
+                current_time_point: None,
+            })),
+            Arc::new(runner),
+        )
+        .await
+        .unwrap();
+        runtime.cycle().await.unwrap();
+
+        assert_eq!("idle".to_string(), runtime.get_state());
+    }
+
+    #[tokio::test]
+    async fn new_leader_reopens_the_signer_registration_round_after_a_takeover() {
+        let mut runner = MockAggregatorRunner::new();
+        let mut seq = mockall::Sequence::new();
+        runner
+            .expect_is_leader()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|| Ok(false));
+        runner
+            .expect_close_signer_registration_round()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|| Ok(()));
+        runner
+            .expect_is_leader()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|| Ok(true));
+        runner
+            .expect_get_time_point_from_chain()
+            .once()
+            .returning(|| Ok(TimePoint::dummy()));
+        runner
+            .expect_close_signer_registration_round()
+            .once()
+            .returning(|| Ok(()));
+        runner
+            .expect_update_era_checker()
+            .once()
+            .returning(|_| Ok(()));
+        runner
+            .expect_inform_new_epoch()
+            .once()
+            .returning(|_| Ok(()));
+        runner
+            .expect_update_stake_distribution()
+            .once()
+            .returning(|_| Ok(()));
+        runner.expect_upkeep().once().returning(|| Ok(()));
+        runner
+            .expect_open_signer_registration_round()
+            .with(predicate::eq(TimePoint::dummy()))
+            .once()
+            .returning(|_| Ok(()));
+        runner
+            .expect_update_epoch_settings()
+            .once()
+            .returning(|| Ok(()));
+        runner
+            .expect_precompute_epoch_data()
+            .once()
+            .returning(|| Ok(()));
+        runner
+            .expect_is_certificate_chain_valid()
+            .once()
+            .returning(|_| Ok(()));
+
+        let mut runtime = AggregatorRuntime::new(
+            AggregatorConfig::new(Duration::from_millis(20), SignedEntityConfig::dummy()),
+            Some(AggregatorState::Ready(ReadyState {
+                current_time_point: TimePoint::dummy(),
+            })),
+            Arc::new(runner),
+        )
+        .await
+        .unwrap();
+        runtime.cycle().await.unwrap();
+        assert_eq!("idle".to_string(), runtime.get_state());
+
+        runtime.cycle().await.unwrap();
+        assert_eq!("ready".to_string(), runtime.get_state());
+    }
+
+    #[tokio::test]
+    async fn signing_does_not_create_the_certificate_if_the_leadership_is_lost() {
+        let mut runner = MockAggregatorRunner::new();
+        runner
+            .expect_get_time_point_from_chain()
+            .once()
+            .returning(|| Ok(TimePoint::dummy()));
+        runner
+            .expect_get_current_open_message_for_signed_entity_type()
+            .once()
+            .returning(|_| Ok(Some(OpenMessage::dummy())));
+        runner
+            .expect_confirm_leadership()
+            .once()
+            .returning(|| Ok(false));
+        runner.expect_create_certificate().never();
+        let state = SigningState {
+            current_time_point: TimePoint::dummy(),
+            open_message: OpenMessage::dummy(),
+        };
+        let mut runtime = init_runtime(Some(AggregatorState::Signing(state)), runner).await;
+        let err = runtime
+            .cycle()
+            .await
+            .expect_err("cycle should have returned an error");
+
+        match err {
+            RuntimeError::ReInit { .. } => (),
+            _ => panic!("ReInit error expected, got {err:?}."),
+        };
+    }
 }
@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::mem;
 use std::ops::Range;
 use std::sync::Arc;
@@ -5,12 +6,12 @@ use std::sync::Arc;
 use anyhow::Context;
 use async_trait::async_trait;
 use slog::{debug, Logger};
-use tokio::{runtime::Handle, task};
+use tokio::{runtime::Handle, task, task::JoinHandle};
 
 use mithril_common::cardano_block_scanner::{BlockScanner, ChainScannedBlocks};
 use mithril_common::crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory};
 use mithril_common::entities::{
-    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber,
+    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, ExtendedCardanoTransaction, SlotNumber,
 };
 use mithril_common::signable_builder::TransactionsImporter;
 use mithril_common::StdResult;
@@ -28,12 +29,24 @@ pub trait TransactionStore: Send + Sync {
     /// Store list of transactions
     async fn store_transactions(&self, transactions: Vec<CardanoTransaction>) -> StdResult<()>;
 
+    /// Store list of transactions with their contents
+    async fn store_extended_transactions(
+        &self,
+        transactions: Vec<ExtendedCardanoTransaction>,
+    ) -> StdResult<()>;
+
     /// Get transactions in an interval of blocks
     async fn get_transactions_in_range(
         &self,
         range: Range<BlockNumber>,
     ) -> StdResult<Vec<CardanoTransaction>>;
 
+    /// Get transactions, with their contents, in an interval of blocks
+    async fn get_extended_transactions_in_range(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<ExtendedCardanoTransaction>>;
+
     /// Store list of block ranges with their corresponding merkle root
     async fn store_block_range_roots(
         &self,
@@ -50,11 +63,19 @@ pub trait TransactionStore: Send + Sync {
     ) -> StdResult<()>;
 }
 
+/// Default number of workers computing the block range roots concurrently
+pub const DEFAULT_BLOCK_RANGE_ROOTS_WORKERS: usize = 1;
+
 /// Import and store [CardanoTransaction].
+///
+/// The import is pipelined: while the blocks are streamed and their transactions stored, the roots
+/// of the block ranges that are completed are computed concurrently by a pool of workers.
 #[derive(Clone)]
 pub struct CardanoTransactionsImporter {
     block_scanner: Arc<dyn BlockScanner>,
     transaction_store: Arc<dyn TransactionStore>,
+    is_extended_mode_enabled: bool,
+    block_range_roots_workers: usize,
     logger: Logger,
 }
 
@@ -68,20 +89,47 @@ impl CardanoTransactionsImporter {
         Self {
             block_scanner,
             transaction_store,
+            is_extended_mode_enabled: false,
+            block_range_roots_workers: DEFAULT_BLOCK_RANGE_ROOTS_WORKERS,
             logger,
         }
     }
 
+    /// Enable the extended mode: the contents of the transactions are stored alongside them and
+    /// the block range roots are computed with leaves that commit to these contents.
+    pub fn with_extended_mode(mut self, is_extended_mode_enabled: bool) -> Self {
+        self.is_extended_mode_enabled = is_extended_mode_enabled;
+        self
+    }
+
+    /// Set the number of workers computing the block range roots concurrently (at least one).
+    pub fn with_block_range_roots_workers(mut self, block_range_roots_workers: usize) -> Self {
+        self.block_range_roots_workers = block_range_roots_workers.max(1);
+        self
+    }
+
+    async fn start_block_range_roots_worker_pool(&self) -> StdResult<BlockRangeRootsWorkerPool> {
+        BlockRangeRootsWorkerPool::start(
+            self.transaction_store.clone(),
+            self.block_range_roots_workers,
+            self.is_extended_mode_enabled,
+        )
+        .await
+    }
+
     async fn import_transactions(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
         let from = self.transaction_store.get_highest_beacon().await?;
-        self.parse_and_store_transactions_not_imported_yet(from, up_to_beacon)
-            .await
+        let mut worker_pool = self.start_block_range_roots_worker_pool().await?;
+        self.parse_and_store_transactions_not_imported_yet(from, up_to_beacon, &mut worker_pool)
+            .await?;
+        worker_pool.finish().await
     }
 
     async fn parse_and_store_transactions_not_imported_yet(
         &self,
         from: Option<ChainPoint>,
         until: BlockNumber,
+        worker_pool: &mut BlockRangeRootsWorkerPool,
     ) -> StdResult<()> {
         if from.as_ref().is_some_and(|f| f.block_number >= until) {
             debug!(
@@ -100,7 +148,24 @@ impl CardanoTransactionsImporter {
 
         while let Some(blocks) = streamer.poll_next().await? {
             match blocks {
+                ChainScannedBlocks::RollForwards(forward_blocks)
+                    if self.is_extended_mode_enabled =>
+                {
+                    let last_block_number = forward_blocks.last().map(|b| b.block_number);
+                    let parsed_transactions: Vec<ExtendedCardanoTransaction> = forward_blocks
+                        .into_iter()
+                        .flat_map(|b| b.into_extended_transactions())
+                        .collect();
+
+                    self.transaction_store
+                        .store_extended_transactions(parsed_transactions)
+                        .await?;
+                    worker_pool
+                        .compute_completed_block_ranges(last_block_number, until)
+                        .await?;
+                }
                 ChainScannedBlocks::RollForwards(forward_blocks) => {
+                    let last_block_number = forward_blocks.last().map(|b| b.block_number);
                     let parsed_transactions: Vec<CardanoTransaction> = forward_blocks
                         .into_iter()
                         .flat_map(|b| b.into_transactions())
@@ -109,11 +174,18 @@ impl CardanoTransactionsImporter {
                     self.transaction_store
                         .store_transactions(parsed_transactions)
                         .await?;
+                    worker_pool
+                        .compute_completed_block_ranges(last_block_number, until)
+                        .await?;
                 }
                 ChainScannedBlocks::RollBackward(slot_number) => {
+                    // The roots being computed must be stored before the rollback so the ones
+                    // of the rolled back block ranges are removed
+                    worker_pool.finish().await?;
                     self.transaction_store
                         .remove_rolled_back_transactions_and_block_range(slot_number)
                         .await?;
+                    *worker_pool = self.start_block_range_roots_worker_pool().await?;
                 }
             }
         }
@@ -122,52 +194,157 @@ impl CardanoTransactionsImporter {
     }
 
     async fn import_block_ranges(&self, until: BlockNumber) -> StdResult<()> {
-        let block_ranges = match self.transaction_store.get_highest_block_range().await?.map(
-            |highest_stored_block_range| {
-                BlockRange::all_block_ranges_in(
-                    BlockRange::start(highest_stored_block_range.end)..=(until),
-                )
-            },
-        ) {
-            // No block range root stored yet, start from the beginning
-            None => BlockRange::all_block_ranges_in(BlockNumber(0)..=(until)),
-            // Not enough block to form at least one block range
-            Some(ranges) if ranges.is_empty() => return Ok(()),
-            Some(ranges) => ranges,
-        };
-
+        let mut worker_pool = self.start_block_range_roots_worker_pool().await?;
         debug!(
             self.logger, "TransactionsImporter - computing Block Range Roots";
-            "start_block" => *block_ranges.start(), "end_block" => *block_ranges.end(),
+            "start_block" => *worker_pool.next_block_range_start, "end_block" => *until,
         );
+        worker_pool.compute_up_to(until).await?;
+        worker_pool.finish().await
+    }
+}
 
-        let mut block_ranges_with_merkle_root: Vec<(BlockRange, MKTreeNode)> = vec![];
-        for block_range in block_ranges {
-            let transactions = self
-                .transaction_store
-                .get_transactions_in_range(block_range.start..block_range.end)
-                .await?;
+type BlockRangeRootWorkerHandle = JoinHandle<StdResult<Option<(BlockRange, MKTreeNode)>>>;
+
+/// Pool of workers that compute the merkle roots of consecutive block ranges concurrently.
+///
+/// The roots are stored in the order of their block ranges, so the highest stored block range is
+/// always preceded by all the lower ones even if the import is interrupted.
+struct BlockRangeRootsWorkerPool {
+    transaction_store: Arc<dyn TransactionStore>,
+    workers: usize,
+    is_extended_mode_enabled: bool,
+    next_block_range_start: BlockNumber,
+    running_workers: VecDeque<BlockRangeRootWorkerHandle>,
+    computed_block_range_roots: Vec<(BlockRange, MKTreeNode)>,
+}
+
+impl BlockRangeRootsWorkerPool {
+    const STORE_BATCH_SIZE: usize = 100;
+
+    async fn start(
+        transaction_store: Arc<dyn TransactionStore>,
+        workers: usize,
+        is_extended_mode_enabled: bool,
+    ) -> StdResult<Self> {
+        let next_block_range_start = match transaction_store.get_highest_block_range().await? {
+            Some(highest_stored_block_range) => BlockRange::start(highest_stored_block_range.end),
+            // No block range root stored yet, start from the beginning
+            None => BlockNumber(0),
+        };
+
+        Ok(Self {
+            transaction_store,
+            workers,
+            is_extended_mode_enabled,
+            next_block_range_start,
+            running_workers: VecDeque::new(),
+            computed_block_range_roots: vec![],
+        })
+    }
 
-            if transactions.is_empty() {
-                continue;
+    /// Compute the block ranges completed by the storage of the blocks up to the given last block
+    /// number, ie the ones that end at or below it, without exceeding `until`.
+    async fn compute_completed_block_ranges(
+        &mut self,
+        last_block_number: Option<BlockNumber>,
+        until: BlockNumber,
+    ) -> StdResult<()> {
+        match last_block_number {
+            Some(last_block_number) if last_block_number > BlockNumber(0) => {
+                self.compute_up_to((last_block_number - 1).min(until)).await
             }
+            _ => Ok(()),
+        }
+    }
 
-            let merkle_root = MKTree::<MKTreeStoreInMemory>::new(&transactions)?.compute_root()?;
-            block_ranges_with_merkle_root.push((block_range, merkle_root));
+    /// Dispatch the computation of the block ranges strictly contained between the last
+    /// dispatched block range and the given block number to the workers.
+    async fn compute_up_to(&mut self, until: BlockNumber) -> StdResult<()> {
+        for block_range in BlockRange::all_block_ranges_in(self.next_block_range_start..=until) {
+            if self.running_workers.len() >= self.workers {
+                self.collect_oldest_worker().await?;
+            }
+
+            self.next_block_range_start = block_range.end;
+            let transaction_store = self.transaction_store.clone();
+            let is_extended_mode_enabled = self.is_extended_mode_enabled;
+            self.running_workers
+                .push_back(task::spawn_blocking(move || {
+                    Handle::current().block_on(Self::compute_block_range_root(
+                        transaction_store,
+                        block_range,
+                        is_extended_mode_enabled,
+                    ))
+                }));
+        }
 
-            if block_ranges_with_merkle_root.len() >= 100 {
-                let block_ranges_with_merkle_root_save =
-                    mem::take(&mut block_ranges_with_merkle_root);
-                self.transaction_store
-                    .store_block_range_roots(block_ranges_with_merkle_root_save)
-                    .await?;
+        Ok(())
+    }
+
+    async fn compute_block_range_root(
+        transaction_store: Arc<dyn TransactionStore>,
+        block_range: BlockRange,
+        is_extended_mode_enabled: bool,
+    ) -> StdResult<Option<(BlockRange, MKTreeNode)>> {
+        let leaves: Vec<MKTreeNode> = if is_extended_mode_enabled {
+            transaction_store
+                .get_extended_transactions_in_range(block_range.start..block_range.end)
+                .await?
+                .iter()
+                .map(Into::into)
+                .collect()
+        } else {
+            transaction_store
+                .get_transactions_in_range(block_range.start..block_range.end)
+                .await?
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        };
+
+        if leaves.is_empty() {
+            return Ok(None);
+        }
+
+        let merkle_root = MKTree::<MKTreeStoreInMemory>::new(&leaves)?.compute_root()?;
+        Ok(Some((block_range, merkle_root)))
+    }
+
+    async fn collect_oldest_worker(&mut self) -> StdResult<()> {
+        if let Some(worker) = self.running_workers.pop_front() {
+            let block_range_root = worker
+                .await
+                .with_context(|| "TransactionsImporter - block range root worker crashed")??;
+            self.computed_block_range_roots.extend(block_range_root);
+
+            if self.computed_block_range_roots.len() >= Self::STORE_BATCH_SIZE {
+                self.store_computed_block_range_roots().await?;
             }
         }
 
+        Ok(())
+    }
+
+    async fn store_computed_block_range_roots(&mut self) -> StdResult<()> {
+        if self.computed_block_range_roots.is_empty() {
+            return Ok(());
+        }
+
+        let block_range_roots = mem::take(&mut self.computed_block_range_roots);
         self.transaction_store
-            .store_block_range_roots(block_ranges_with_merkle_root)
+            .store_block_range_roots(block_range_roots)
             .await
     }
+
+    /// Wait for all the running workers and store the roots that were computed.
+    async fn finish(&mut self) -> StdResult<()> {
+        while !self.running_workers.is_empty() {
+            self.collect_oldest_worker().await?;
+        }
+
+        self.store_computed_block_range_roots().await
+    }
 }
 
 #[async_trait]
@@ -194,12 +371,17 @@ mod tests {
     use mockall::mock;
 
     use mithril_common::cardano_block_scanner::{
-        BlockStreamer, DumbBlockScanner, DumbBlockStreamer, ScannedBlock,
+        BlockStreamer, CardanoBlockScanner, DumbBlockScanner, DumbBlockStreamer, ScannedBlock,
     };
+    use mithril_common::chain_reader::FakeChainReader;
     use mithril_common::crypto_helper::MKTree;
-    use mithril_common::entities::{BlockNumber, BlockRangesSequence, SlotNumber};
+    use mithril_common::entities::{
+        BlockNumber, BlockRangesSequence, CardanoTransactionContent, SlotNumber,
+    };
+    use mithril_common::test_utils::ForkingChainSimulator;
     use mithril_persistence::database::repository::CardanoTransactionRepository;
-    use mithril_persistence::sqlite::SqliteConnectionPool;
+    use mithril_persistence::database::CardanoTransactionsIntegrityChecker;
+    use mithril_persistence::sqlite::DatabaseConnectionPool;
 
     use crate::database::test_helper::cardano_tx_db_connection;
     use crate::test_tools::TestLogger;
@@ -266,7 +448,7 @@ mod tests {
     async fn if_nothing_stored_parse_and_store_all_transactions() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         let blocks = vec![
@@ -306,11 +488,148 @@ mod tests {
         assert_eq!(expected_transactions, stored_transactions);
     }
 
+    #[tokio::test]
+    async fn if_extended_mode_enabled_parse_and_store_transactions_with_their_contents() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build_from_connection(connection),
+        )));
+
+        let content = CardanoTransactionContent {
+            metadata_labels: vec![674],
+            ..CardanoTransactionContent::default()
+        };
+        let blocks = vec![ScannedBlock::new(
+            "block_hash-1",
+            BlockNumber(10),
+            SlotNumber(15),
+            vec!["tx_hash-1"],
+        )
+        .with_transactions_contents(vec![content.clone()])];
+        let expected_transactions = into_transactions(&blocks);
+
+        let importer = {
+            let scanner = DumbBlockScanner::new().forwards(vec![blocks]);
+            CardanoTransactionsImporter::new_for_test(Arc::new(scanner), repository.clone())
+                .with_extended_mode(true)
+        };
+
+        importer
+            .import_transactions(BlockNumber(1000))
+            .await
+            .expect("Transactions Importer should succeed");
+
+        let stored_transactions = repository.get_all().await.unwrap();
+        assert_eq!(expected_transactions, stored_transactions);
+
+        let stored_contents = repository
+            .get_transactions_contents_by_hashes(vec!["tx_hash-1"])
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![content],
+            stored_contents
+                .into_iter()
+                .map(|record| record.content)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn if_extended_mode_enabled_block_range_roots_commit_to_the_transactions_contents() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build_from_connection(connection),
+        )));
+
+        let content = CardanoTransactionContent {
+            metadata_labels: vec![674],
+            ..CardanoTransactionContent::default()
+        };
+        let blocks = vec![ScannedBlock::new(
+            "block_hash-1",
+            BlockNumber(10),
+            SlotNumber(15),
+            vec!["tx_hash-1"],
+        )
+        .with_transactions_contents(vec![content.clone()])];
+        let extended_transaction = ExtendedCardanoTransaction::new(
+            CardanoTransaction::new("tx_hash-1", BlockNumber(10), SlotNumber(15), "block_hash-1"),
+            content,
+        );
+
+        let importer = {
+            let scanner = DumbBlockScanner::new().forwards(vec![blocks]);
+            CardanoTransactionsImporter::new_for_test(Arc::new(scanner), repository.clone())
+                .with_extended_mode(true)
+        };
+
+        importer
+            .import_transactions(BlockNumber(1000))
+            .await
+            .expect("Transactions Importer should succeed");
+        importer
+            .import_block_ranges(BlockNumber(1000))
+            .await
+            .expect("Transactions Importer should succeed");
+
+        let expected_root =
+            MKTree::<MKTreeStoreInMemory>::new(&[MKTreeNode::from(&extended_transaction)])
+                .unwrap()
+                .compute_root()
+                .unwrap();
+        let block_range_roots: Vec<(BlockRange, MKTreeNode)> = repository
+            .get_all_block_range_root()
+            .unwrap()
+            .into_iter()
+            .map(|record| (record.range, record.merkle_root))
+            .collect();
+        assert_eq!(
+            vec![(
+                BlockRange::from_block_number(BlockNumber(10)),
+                expected_root
+            )],
+            block_range_roots
+        );
+    }
+
+    #[tokio::test]
+    async fn if_extended_mode_disabled_transactions_contents_are_not_stored() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build_from_connection(connection),
+        )));
+
+        let blocks = vec![ScannedBlock::new(
+            "block_hash-1",
+            BlockNumber(10),
+            SlotNumber(15),
+            vec!["tx_hash-1"],
+        )
+        .with_transactions_contents(vec![CardanoTransactionContent::default()])];
+
+        let importer = {
+            let scanner = DumbBlockScanner::new().forwards(vec![blocks]);
+            CardanoTransactionsImporter::new_for_test(Arc::new(scanner), repository.clone())
+        };
+
+        importer
+            .import_transactions(BlockNumber(1000))
+            .await
+            .expect("Transactions Importer should succeed");
+
+        let stored_contents = repository
+            .get_transactions_contents_by_hashes(vec!["tx_hash-1"])
+            .await
+            .unwrap();
+        assert!(stored_contents.is_empty());
+    }
+
     #[tokio::test]
     async fn if_nothing_stored_parse_and_store_all_block_ranges() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         let up_to_block_number = BlockRange::LENGTH * 5;
@@ -348,7 +667,7 @@ mod tests {
     async fn if_theres_gap_between_two_stored_block_ranges_it_can_still_compute_their_root() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         let up_to_block_number = BlockRange::LENGTH * 4;
@@ -388,7 +707,7 @@ mod tests {
     async fn if_all_block_ranges_computed_nothing_computed_and_stored() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         let importer = CardanoTransactionsImporter::new_for_test(
@@ -413,7 +732,7 @@ mod tests {
         let up_to_block_number = BlockNumber(12);
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
         let scanner = DumbBlockScanner::new().forwards(vec![vec![
             ScannedBlock::new(
@@ -453,7 +772,7 @@ mod tests {
     async fn if_half_transactions_are_already_stored_the_other_half_is_parsed_and_stored() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         let highest_stored_chain_point =
@@ -516,7 +835,7 @@ mod tests {
     async fn if_half_block_ranges_are_stored_the_other_half_is_computed_and_stored() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         let up_to_block_number = BlockRange::LENGTH * 4;
@@ -579,7 +898,7 @@ mod tests {
     async fn can_compute_block_ranges_up_to_the_strict_end_of_a_block_range() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         // Transactions for all blocks in the (15..=29) interval
@@ -611,7 +930,7 @@ mod tests {
     async fn can_compute_block_ranges_even_if_last_blocks_in_range_dont_have_transactions() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         // For the block range (15..=29) we only have transactions in the 10 first blocks (15..=24)
@@ -689,7 +1008,7 @@ mod tests {
     async fn compute_block_range_merkle_root() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         // 2 block ranges worth of blocks with one more block that should be ignored for merkle root computation
@@ -753,7 +1072,8 @@ mod tests {
 
         let (importer, repository) = {
             let connection = cardano_tx_db_connection().unwrap();
-            let connection_pool = Arc::new(SqliteConnectionPool::build_from_connection(connection));
+            let connection_pool =
+                Arc::new(DatabaseConnectionPool::build_from_connection(connection));
             let repository = Arc::new(CardanoTransactionRepository::new(connection_pool));
             let importer = CardanoTransactionsImporter::new_for_test(
                 Arc::new(DumbBlockScanner::new().forwards(vec![blocks.clone()])),
@@ -782,7 +1102,7 @@ mod tests {
     async fn when_rollbackward_should_remove_transactions() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         let expected_remaining_transactions = ScannedBlock::new(
@@ -828,7 +1148,7 @@ mod tests {
     async fn when_rollbackward_should_remove_block_ranges() {
         let connection = cardano_tx_db_connection().unwrap();
         let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
-            SqliteConnectionPool::build_from_connection(connection),
+            DatabaseConnectionPool::build_from_connection(connection),
         )));
 
         let expected_remaining_block_ranges = vec![
@@ -896,6 +1216,126 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn roots_of_block_ranges_completed_while_importing_transactions_are_computed() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build_from_connection(connection),
+        )));
+
+        // 3 completed block ranges and a last one that is not completed yet
+        let blocks = build_blocks(BlockNumber(0), BlockRange::LENGTH * 3 + 2);
+        let importer = CardanoTransactionsImporter::new_for_test(
+            Arc::new(
+                DumbBlockScanner::new().forwards(
+                    blocks
+                        .chunks(*BlockRange::LENGTH as usize / 2)
+                        .map(|chunk| chunk.to_vec())
+                        .collect(),
+                ),
+            ),
+            repository.clone(),
+        )
+        .with_block_range_roots_workers(2);
+
+        importer
+            .import_transactions(BlockNumber(10_000))
+            .await
+            .expect("Transactions Importer should succeed");
+
+        let block_range_roots = repository.get_all_block_range_root().unwrap();
+        assert_eq!(
+            BlockRange::all_block_ranges_in(BlockNumber(0)..=(BlockRange::LENGTH * 3 - 1))
+                .into_vec(),
+            block_range_roots
+                .into_iter()
+                .map(|r| r.range)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn roots_computed_by_several_workers_are_the_same_as_the_ones_computed_by_one_worker() {
+        async fn import_block_range_roots(
+            blocks: &[ScannedBlock],
+            workers: usize,
+        ) -> Vec<(BlockRange, MKTreeNode)> {
+            let connection = cardano_tx_db_connection().unwrap();
+            let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+                DatabaseConnectionPool::build_from_connection(connection),
+            )));
+            let importer = CardanoTransactionsImporter::new_for_test(
+                Arc::new(DumbBlockScanner::new().forwards(vec![blocks.to_vec()])),
+                repository.clone(),
+            )
+            .with_block_range_roots_workers(workers);
+
+            importer
+                .import(BlockRange::LENGTH * 250)
+                .await
+                .expect("Transactions Importer should succeed");
+
+            repository
+                .get_all_block_range_root()
+                .unwrap()
+                .into_iter()
+                .map(|r| r.into())
+                .collect()
+        }
+
+        // More block ranges than the batch size of the stored roots
+        let blocks = build_blocks(BlockNumber(0), BlockRange::LENGTH * 250 + 1);
+
+        let sequential_block_range_roots = import_block_range_roots(&blocks, 1).await;
+        let parallel_block_range_roots = import_block_range_roots(&blocks, 4).await;
+
+        assert_eq!(250, sequential_block_range_roots.len());
+        assert_eq!(sequential_block_range_roots, parallel_block_range_roots);
+    }
+
+    #[tokio::test]
+    async fn importing_a_randomly_forking_chain_keeps_the_block_range_roots_consistent() {
+        for seed in 0..5 {
+            let connection = cardano_tx_db_connection().unwrap();
+            let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+                DatabaseConnectionPool::build_from_connection(connection),
+            )));
+            let integrity_checker = CardanoTransactionsIntegrityChecker::new(repository.clone());
+            // Rollbacks can span several block ranges
+            let mut chain_simulator = ForkingChainSimulator::new(seed, *BlockRange::LENGTH * 4);
+
+            for round in 0..10 {
+                let chain_reader = FakeChainReader::new(chain_simulator.next_round(100));
+                let block_scanner = CardanoBlockScanner::new(
+                    Arc::new(tokio::sync::Mutex::new(chain_reader)),
+                    10,
+                    TestLogger::stdout(),
+                );
+                let importer = CardanoTransactionsImporter::new_for_test(
+                    Arc::new(block_scanner),
+                    repository.clone(),
+                )
+                .with_block_range_roots_workers(4);
+
+                importer
+                    .import(chain_simulator.tip_block_number())
+                    .await
+                    .expect("Transactions Importer should succeed");
+
+                let report = integrity_checker.check().await.unwrap();
+                assert!(
+                    report.is_valid(),
+                    "seed {seed}, round {round}: block range roots are inconsistent:\n{report}"
+                );
+                assert_eq!(
+                    chain_simulator.transactions(),
+                    repository.get_all().await.unwrap(),
+                    "seed {seed}, round {round}: stored transactions are not the ones of the chain"
+                );
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_import_is_non_blocking() {
         static COUNTER: AtomicUsize = AtomicUsize::new(0);
@@ -958,6 +1398,14 @@ mod tests {
                 Ok(())
             }
 
+            async fn store_extended_transactions(
+                &self,
+                _: Vec<ExtendedCardanoTransaction>,
+            ) -> StdResult<()> {
+                self.block_thread();
+                Ok(())
+            }
+
             async fn get_transactions_in_range(
                 &self,
                 _: Range<BlockNumber>,
@@ -966,6 +1414,14 @@ mod tests {
                 Ok(vec![])
             }
 
+            async fn get_extended_transactions_in_range(
+                &self,
+                _: Range<BlockNumber>,
+            ) -> StdResult<Vec<ExtendedCardanoTransaction>> {
+                self.block_thread();
+                Ok(vec![])
+            }
+
             async fn store_block_range_roots(
                 &self,
                 _: Vec<(BlockRange, MKTreeNode)>,
@@ -394,7 +394,7 @@ mod tests {
             network: CardanoNetwork,
             mut dependency_builder: DependenciesBuilder,
         ) -> Self {
-            let connection = dependency_builder.get_sqlite_connection().await.unwrap();
+            let connection = dependency_builder.get_database_connection().await.unwrap();
             let open_message_repository = Arc::new(OpenMessageRepository::new(connection.clone()));
             let single_signature_repository =
                 Arc::new(SingleSignatureRepository::new(connection.clone()));
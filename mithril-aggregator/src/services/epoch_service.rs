@@ -12,7 +12,7 @@ use mithril_common::protocol::{MultiSigner as ProtocolMultiSigner, SignerBuilder
 use mithril_common::StdResult;
 
 use crate::entities::AggregatorEpochSettings;
-use crate::{EpochSettingsStorer, VerificationKeyStorer};
+use crate::{EpochSettingsStorer, ProtocolParametersScheduleProvider, VerificationKeyStorer};
 
 /// Errors dedicated to the CertifierService.
 #[derive(Debug, Error)]
@@ -118,6 +118,8 @@ struct ComputedEpochData {
 pub struct MithrilEpochService {
     /// Epoch settings that will be inserted when inform_epoch is called
     future_epoch_settings: AggregatorEpochSettings,
+    /// Schedule of the protocol parameters that overrides the ones of the future epoch settings
+    protocol_parameters_schedule_provider: Option<Arc<dyn ProtocolParametersScheduleProvider>>,
     epoch_data: Option<EpochData>,
     computed_epoch_data: Option<ComputedEpochData>,
     epoch_settings_storer: Arc<dyn EpochSettingsStorer>,
@@ -133,6 +135,7 @@ impl MithrilEpochService {
     ) -> Self {
         Self {
             future_epoch_settings,
+            protocol_parameters_schedule_provider: None,
             epoch_data: None,
             computed_epoch_data: None,
             epoch_settings_storer,
@@ -140,6 +143,19 @@ impl MithrilEpochService {
         }
     }
 
+    /// Set the provider of the schedule of the protocol parameters to apply when inserting
+    /// future epoch settings.
+    ///
+    /// For epochs not covered by the schedule, the protocol parameters of the future epoch
+    /// settings are used.
+    pub fn with_protocol_parameters_schedule_provider(
+        mut self,
+        provider: Arc<dyn ProtocolParametersScheduleProvider>,
+    ) -> Self {
+        self.protocol_parameters_schedule_provider = Some(provider);
+        self
+    }
+
     async fn get_signers_with_stake_at_epoch(
         &self,
         signer_retrieval_epoch: Epoch,
@@ -168,19 +184,39 @@ impl MithrilEpochService {
         Ok(epoch_settings)
     }
 
+    async fn compute_future_epoch_settings(
+        &self,
+        recording_epoch: Epoch,
+    ) -> StdResult<AggregatorEpochSettings> {
+        let mut epoch_settings = self.future_epoch_settings.clone();
+
+        if let Some(provider) = &self.protocol_parameters_schedule_provider {
+            let schedule = provider
+                .get_schedule()
+                .await
+                .with_context(|| "Epoch service failed to get the protocol parameters schedule")?;
+            if let Some(protocol_parameters) = schedule.protocol_parameters_at(recording_epoch) {
+                epoch_settings.protocol_parameters = protocol_parameters.clone();
+            }
+        }
+
+        Ok(epoch_settings)
+    }
+
     async fn insert_future_epoch_settings(&self, actual_epoch: Epoch) -> StdResult<()> {
         let recording_epoch = actual_epoch.offset_to_epoch_settings_recording_epoch();
+        let epoch_settings = self.compute_future_epoch_settings(recording_epoch).await?;
 
         debug!(
             "EpochService: inserting epoch settings in epoch {}",
             recording_epoch;
-            "epoch_settings" => ?self.future_epoch_settings
+            "epoch_settings" => ?epoch_settings
         );
 
         self.epoch_settings_storer
             .save_epoch_settings(
                 recording_epoch,
-                self.future_epoch_settings.clone(),
+                epoch_settings,
             )
             .await
             .with_context(|| format!("Epoch service failed to insert future_epoch_settings to epoch {recording_epoch}"))
@@ -597,8 +633,9 @@ mod tests {
     use mithril_persistence::store::adapter::MemoryAdapter;
     use std::collections::{BTreeSet, HashMap};
 
+    use crate::entities::{ProtocolParametersSchedule, ScheduledProtocolParameters};
     use crate::services::epoch_service::tests::ServiceBuilderParameters::WithFutureProtocolParameters;
-    use crate::store::FakeEpochSettingsStorer;
+    use crate::store::{FakeEpochSettingsStorer, MockProtocolParametersScheduleProvider};
     use crate::VerificationKeyStore;
 
     use super::*;
@@ -912,6 +949,126 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn update_epoch_settings_insert_protocol_parameters_from_schedule_in_the_store() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let future_protocol_parameters = ProtocolParameters::new(6, 89, 0.124);
+        let scheduled_protocol_parameters = ProtocolParameters::new(7, 90, 0.3);
+        let epoch = Epoch(4);
+        let recording_epoch = epoch.offset_to_epoch_settings_recording_epoch();
+        let schedule = ProtocolParametersSchedule::new(vec![
+            ScheduledProtocolParameters {
+                from_epoch: recording_epoch,
+                protocol_parameters: scheduled_protocol_parameters.clone(),
+            },
+            ScheduledProtocolParameters {
+                from_epoch: recording_epoch + 1,
+                protocol_parameters: ProtocolParameters::new(8, 91, 0.4),
+            },
+        ])
+        .unwrap();
+        let mut service = build_service(
+            epoch,
+            &fixture,
+            &[WithFutureProtocolParameters(future_protocol_parameters)],
+        )
+        .await
+        .with_protocol_parameters_schedule_provider(Arc::new(schedule));
+
+        service
+            .inform_epoch(epoch)
+            .await
+            .expect("inform_epoch should not fail");
+        service
+            .update_epoch_settings()
+            .await
+            .expect("update_epoch_settings should not fail");
+
+        let inserted_epoch_settings = service
+            .epoch_settings_storer
+            .get_epoch_settings(recording_epoch)
+            .await
+            .unwrap()
+            .expect("epoch settings should have been inserted");
+
+        assert_eq!(
+            inserted_epoch_settings.protocol_parameters,
+            scheduled_protocol_parameters
+        );
+    }
+
+    #[tokio::test]
+    async fn update_epoch_settings_insert_future_protocol_parameters_if_not_covered_by_schedule() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let future_protocol_parameters = ProtocolParameters::new(6, 89, 0.124);
+        let epoch = Epoch(4);
+        let recording_epoch = epoch.offset_to_epoch_settings_recording_epoch();
+        let schedule = ProtocolParametersSchedule::new(vec![ScheduledProtocolParameters {
+            from_epoch: recording_epoch + 1,
+            protocol_parameters: ProtocolParameters::new(8, 91, 0.4),
+        }])
+        .unwrap();
+        let mut service = build_service(
+            epoch,
+            &fixture,
+            &[WithFutureProtocolParameters(
+                future_protocol_parameters.clone(),
+            )],
+        )
+        .await
+        .with_protocol_parameters_schedule_provider(Arc::new(schedule));
+
+        service
+            .inform_epoch(epoch)
+            .await
+            .expect("inform_epoch should not fail");
+        service
+            .update_epoch_settings()
+            .await
+            .expect("update_epoch_settings should not fail");
+
+        let inserted_epoch_settings = service
+            .epoch_settings_storer
+            .get_epoch_settings(recording_epoch)
+            .await
+            .unwrap()
+            .expect("epoch settings should have been inserted");
+
+        assert_eq!(
+            inserted_epoch_settings.protocol_parameters,
+            future_protocol_parameters
+        );
+    }
+
+    #[tokio::test]
+    async fn update_epoch_settings_fails_if_the_schedule_can_not_be_retrieved() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let epoch = Epoch(4);
+        let mut schedule_provider = MockProtocolParametersScheduleProvider::new();
+        schedule_provider
+            .expect_get_schedule()
+            .returning(|| Err(anyhow::anyhow!("invalid schedule")));
+        let mut service = build_service(epoch, &fixture, &[])
+            .await
+            .with_protocol_parameters_schedule_provider(Arc::new(schedule_provider));
+
+        service
+            .inform_epoch(epoch)
+            .await
+            .expect("inform_epoch should not fail");
+        service
+            .update_epoch_settings()
+            .await
+            .expect_err("update_epoch_settings should fail");
+
+        assert!(service
+            .epoch_settings_storer
+            .get_epoch_settings(epoch.offset_to_epoch_settings_recording_epoch())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
     #[tokio::test]
     async fn cant_get_data_if_inform_epoch_has_not_been_called() {
         let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
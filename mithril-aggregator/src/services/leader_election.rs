@@ -0,0 +1,303 @@
+//! ## Leader Election Service
+//!
+//! This service elects the leader of the aggregators running in high availability mode, ie:
+//! several aggregators sharing the same database.
+//!
+//! Only the leader runs the state machine that produces the certificates, the other aggregators
+//! (followers) only serve the read-only routes of the HTTP server. The leadership is held with a
+//! lock in the shared database that must be renewed before its lease expires, if the leader fails
+//! to do so a follower takes over.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use slog::{info, warn, Logger};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use mithril_common::cardano_transactions_preloader::CardanoTransactionsPreloaderChecker;
+use mithril_common::StdResult;
+
+/// Store of the lock held by the leader of the aggregators sharing the same database.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait LeaderLockStore: Sync + Send {
+    /// Try to acquire the lock for the given holder, or to extend it if the holder already holds
+    /// it, until the given expiration date.
+    ///
+    /// Returns `true` if the given holder holds the lock.
+    async fn acquire_or_renew(
+        &self,
+        holder: &str,
+        now: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> StdResult<bool>;
+
+    /// Release the lock if it's held by the given holder.
+    async fn release(&self, holder: &str) -> StdResult<()>;
+}
+
+/// Define the service responsible for the leader election of the aggregators.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait LeaderElectionService: Send + Sync {
+    /// Acquire the leadership, or renew it if this aggregator is already the leader.
+    ///
+    /// Returns `true` if this aggregator is the leader.
+    async fn refresh_leadership(&self) -> StdResult<bool>;
+
+    /// Check if this aggregator is the leader, as of the last refresh of its leadership.
+    fn is_leader(&self) -> bool;
+
+    /// Step down so that another aggregator can take over without waiting for the lease to expire.
+    async fn release_leadership(&self) -> StdResult<()>;
+}
+
+/// Leader election service of an aggregator that runs alone, it's always the leader.
+#[derive(Default)]
+pub struct StandaloneLeaderElectionService;
+
+#[async_trait]
+impl LeaderElectionService for StandaloneLeaderElectionService {
+    async fn refresh_leadership(&self) -> StdResult<bool> {
+        Ok(true)
+    }
+
+    fn is_leader(&self) -> bool {
+        true
+    }
+
+    async fn release_leadership(&self) -> StdResult<()> {
+        Ok(())
+    }
+}
+
+/// Leader election service of an aggregator that runs in high availability mode.
+pub struct MithrilLeaderElectionService {
+    node_id: String,
+    lease_duration: Duration,
+    leader_lock_store: Arc<dyn LeaderLockStore>,
+    leadership_expires_at: RwLock<Option<Instant>>,
+    logger: Logger,
+}
+
+impl MithrilLeaderElectionService {
+    /// Create a new instance of the leader election service.
+    pub fn new(
+        node_id: String,
+        lease_duration: Duration,
+        leader_lock_store: Arc<dyn LeaderLockStore>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            node_id,
+            lease_duration,
+            leader_lock_store,
+            leadership_expires_at: RwLock::new(None),
+            logger,
+        }
+    }
+
+    fn set_leadership_expires_at(&self, expires_at: Option<Instant>) {
+        let mut leadership_expires_at = self.leadership_expires_at.write().unwrap();
+        *leadership_expires_at = expires_at;
+    }
+}
+
+#[async_trait]
+impl LeaderElectionService for MithrilLeaderElectionService {
+    async fn refresh_leadership(&self) -> StdResult<bool> {
+        // The lease is computed from the start of the refresh so this aggregator never considers
+        // itself as the leader after the expiration date of the lock in the database.
+        let refresh_started_at = Instant::now();
+        let now = Utc::now();
+        let expires_at = now + chrono::Duration::from_std(self.lease_duration)?;
+        let was_leader = self.is_leader();
+
+        let is_leader = self
+            .leader_lock_store
+            .acquire_or_renew(&self.node_id, now, expires_at)
+            .await?;
+
+        if is_leader {
+            if !was_leader {
+                info!(self.logger, "LeaderElection: this aggregator is now the leader"; "node_id" => &self.node_id);
+            }
+            self.set_leadership_expires_at(Some(refresh_started_at + self.lease_duration));
+        } else {
+            if was_leader {
+                warn!(self.logger, "LeaderElection: this aggregator is not the leader anymore"; "node_id" => &self.node_id);
+            }
+            self.set_leadership_expires_at(None);
+        }
+
+        Ok(is_leader)
+    }
+
+    fn is_leader(&self) -> bool {
+        self.leadership_expires_at
+            .read()
+            .unwrap()
+            .is_some_and(|expires_at| Instant::now() < expires_at)
+    }
+
+    async fn release_leadership(&self) -> StdResult<()> {
+        self.set_leadership_expires_at(None);
+        self.leader_lock_store.release(&self.node_id).await?;
+        info!(self.logger, "LeaderElection: leadership released"; "node_id" => &self.node_id);
+
+        Ok(())
+    }
+}
+
+/// Activation of the Cardano transactions preloader that only preloads on the leader: the
+/// followers share the leader database and must not import transactions into it.
+pub struct LeaderCardanoTransactionsPreloaderActivation {
+    activation: bool,
+    leader_election_service: Arc<dyn LeaderElectionService>,
+}
+
+impl LeaderCardanoTransactionsPreloaderActivation {
+    /// Create a new instance of `LeaderCardanoTransactionsPreloaderActivation`
+    pub fn new(activation: bool, leader_election_service: Arc<dyn LeaderElectionService>) -> Self {
+        Self {
+            activation,
+            leader_election_service,
+        }
+    }
+}
+
+#[async_trait]
+impl CardanoTransactionsPreloaderChecker for LeaderCardanoTransactionsPreloaderActivation {
+    async fn is_activated(&self) -> StdResult<bool> {
+        Ok(self.activation && self.leader_election_service.is_leader())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::database::repository::LeaderLockRepository;
+    use crate::database::test_helper::main_db_connection;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    fn build_service(
+        node_id: &str,
+        lease_duration: Duration,
+        leader_lock_store: Arc<dyn LeaderLockStore>,
+    ) -> MithrilLeaderElectionService {
+        MithrilLeaderElectionService::new(
+            node_id.to_string(),
+            lease_duration,
+            leader_lock_store,
+            TestLogger::stdout(),
+        )
+    }
+
+    #[tokio::test]
+    async fn standalone_aggregator_is_always_the_leader() {
+        let service = StandaloneLeaderElectionService;
+
+        assert!(service.is_leader());
+        assert!(service.refresh_leadership().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn is_not_leader_before_first_refresh() {
+        let service = build_service(
+            "aggregator-1",
+            Duration::from_secs(30),
+            Arc::new(MockLeaderLockStore::new()),
+        );
+
+        assert!(!service.is_leader());
+    }
+
+    #[tokio::test]
+    async fn only_one_aggregator_is_leader_when_sharing_the_same_database() {
+        let store = Arc::new(LeaderLockRepository::new(Arc::new(
+            main_db_connection().unwrap(),
+        )));
+        let service_1 = build_service("aggregator-1", Duration::from_secs(30), store.clone());
+        let service_2 = build_service("aggregator-2", Duration::from_secs(30), store.clone());
+
+        assert!(service_1.refresh_leadership().await.unwrap());
+        assert!(!service_2.refresh_leadership().await.unwrap());
+        assert!(service_1.refresh_leadership().await.unwrap());
+
+        assert!(service_1.is_leader());
+        assert!(!service_2.is_leader());
+    }
+
+    #[tokio::test]
+    async fn follower_takes_over_when_leader_releases_its_leadership() {
+        let store = Arc::new(LeaderLockRepository::new(Arc::new(
+            main_db_connection().unwrap(),
+        )));
+        let service_1 = build_service("aggregator-1", Duration::from_secs(30), store.clone());
+        let service_2 = build_service("aggregator-2", Duration::from_secs(30), store.clone());
+        service_1.refresh_leadership().await.unwrap();
+
+        service_1.release_leadership().await.unwrap();
+
+        assert!(!service_1.is_leader());
+        assert!(service_2.refresh_leadership().await.unwrap());
+        assert!(!service_1.refresh_leadership().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn follower_takes_over_when_leader_lease_expires() {
+        let store = Arc::new(LeaderLockRepository::new(Arc::new(
+            main_db_connection().unwrap(),
+        )));
+        let service_1 = build_service("aggregator-1", Duration::from_millis(50), store.clone());
+        let service_2 = build_service("aggregator-2", Duration::from_millis(50), store.clone());
+        service_1.refresh_leadership().await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(!service_1.is_leader());
+        assert!(service_2.refresh_leadership().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn leadership_is_kept_until_lease_expiration_if_the_lock_can_not_be_renewed() {
+        let mut store = MockLeaderLockStore::new();
+        store
+            .expect_acquire_or_renew()
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        store
+            .expect_acquire_or_renew()
+            .returning(|_, _, _| Err(anyhow!("database error")));
+        let service = build_service("aggregator-1", Duration::from_secs(30), Arc::new(store));
+        service.refresh_leadership().await.unwrap();
+
+        service
+            .refresh_leadership()
+            .await
+            .expect_err("refresh_leadership should fail");
+
+        assert!(service.is_leader());
+    }
+
+    #[tokio::test]
+    async fn preloader_is_only_activated_on_the_leader() {
+        let build_activation = |activation: bool, is_leader: bool| {
+            let mut leader_election_service = MockLeaderElectionService::new();
+            leader_election_service
+                .expect_is_leader()
+                .return_const(is_leader);
+            LeaderCardanoTransactionsPreloaderActivation::new(
+                activation,
+                Arc::new(leader_election_service),
+            )
+        };
+
+        assert!(build_activation(true, true).is_activated().await.unwrap());
+        assert!(!build_activation(true, false).is_activated().await.unwrap());
+        assert!(!build_activation(false, true).is_activated().await.unwrap());
+    }
+}
@@ -8,6 +8,7 @@ use thiserror::Error;
 use mithril_common::{
     entities::{Epoch, SignedEntityTypeDiscriminants},
     messages::{
+        CardanoDRepStakeDistributionListMessage, CardanoDRepStakeDistributionMessage,
         CardanoStakeDistributionListMessage, CardanoStakeDistributionMessage,
         CardanoTransactionSnapshotListMessage, CardanoTransactionSnapshotMessage,
         CertificateListMessage, CertificateMessage, MithrilStakeDistributionListMessage,
@@ -93,6 +94,24 @@ pub trait MessageService: Sync + Send {
         &self,
         limit: usize,
     ) -> StdResult<CardanoStakeDistributionListMessage>;
+
+    /// Return the information regarding the Cardano DRep stake distribution for the given identifier.
+    async fn get_cardano_drep_stake_distribution_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoDRepStakeDistributionMessage>>;
+
+    /// Return the information regarding the Cardano DRep stake distribution for the given epoch.
+    async fn get_cardano_drep_stake_distribution_message_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<Option<CardanoDRepStakeDistributionMessage>>;
+
+    /// Return the list of the last Cardano DRep stake distributions message
+    async fn get_cardano_drep_stake_distribution_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoDRepStakeDistributionListMessage>;
 }
 
 /// Implementation of the [MessageService]
@@ -242,6 +261,43 @@ impl MessageService for MithrilMessageService {
 
         entities.into_iter().map(|i| i.try_into()).collect()
     }
+
+    async fn get_cardano_drep_stake_distribution_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoDRepStakeDistributionMessage>> {
+        let signed_entity = self
+            .signed_entity_storer
+            .get_signed_entity(signed_entity_id)
+            .await?;
+
+        signed_entity.map(|v| v.try_into()).transpose()
+    }
+
+    async fn get_cardano_drep_stake_distribution_message_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<Option<CardanoDRepStakeDistributionMessage>> {
+        let signed_entity = self
+            .signed_entity_storer
+            .get_cardano_drep_stake_distribution_signed_entity_by_epoch(epoch)
+            .await?;
+
+        signed_entity.map(|v| v.try_into()).transpose()
+    }
+
+    async fn get_cardano_drep_stake_distribution_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoDRepStakeDistributionListMessage> {
+        let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoDRepStakeDistribution;
+        let entities = self
+            .signed_entity_storer
+            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .await?;
+
+        entities.into_iter().map(|i| i.try_into()).collect()
+    }
 }
 
 #[cfg(test)]
@@ -249,8 +305,8 @@ mod tests {
     use std::sync::Arc;
 
     use mithril_common::entities::{
-        CardanoStakeDistribution, CardanoTransactionsSnapshot, Certificate, Epoch,
-        MithrilStakeDistribution, SignedEntity, SignedEntityType, Snapshot,
+        CardanoDRepStakeDistribution, CardanoStakeDistribution, CardanoTransactionsSnapshot,
+        Certificate, Epoch, MithrilStakeDistribution, SignedEntity, SignedEntityType, Snapshot,
     };
     use mithril_common::messages::ToMessageAdapter;
     use mithril_common::test_utils::MithrilFixtureBuilder;
@@ -259,10 +315,12 @@ mod tests {
     use crate::database::repository::MockSignedEntityStorer;
     use crate::dependency_injection::DependenciesBuilder;
     use crate::message_adapters::{
-        ToCardanoStakeDistributionListMessageAdapter, ToCardanoStakeDistributionMessageAdapter,
-        ToCardanoTransactionListMessageAdapter, ToCardanoTransactionMessageAdapter,
-        ToMithrilStakeDistributionListMessageAdapter, ToMithrilStakeDistributionMessageAdapter,
-        ToSnapshotListMessageAdapter, ToSnapshotMessageAdapter,
+        ToCardanoDRepStakeDistributionListMessageAdapter,
+        ToCardanoDRepStakeDistributionMessageAdapter, ToCardanoStakeDistributionListMessageAdapter,
+        ToCardanoStakeDistributionMessageAdapter, ToCardanoTransactionListMessageAdapter,
+        ToCardanoTransactionMessageAdapter, ToMithrilStakeDistributionListMessageAdapter,
+        ToMithrilStakeDistributionMessageAdapter, ToSnapshotListMessageAdapter,
+        ToSnapshotMessageAdapter,
     };
     use crate::Configuration;
 
@@ -680,4 +738,134 @@ mod tests {
 
         assert_eq!(message, response);
     }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution() {
+        let entity = SignedEntity::<CardanoDRepStakeDistribution>::dummy();
+        let record = SignedEntityRecord {
+            signed_entity_id: entity.signed_entity_id.clone(),
+            signed_entity_type: SignedEntityType::CardanoDRepStakeDistribution(
+                entity.artifact.epoch,
+            ),
+            certificate_id: entity.certificate_id.clone(),
+            artifact: serde_json::to_string(&entity.artifact).unwrap(),
+            created_at: entity.created_at,
+        };
+        let message = ToCardanoDRepStakeDistributionMessageAdapter::adapt(entity);
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        let mut storer = MockSignedEntityStorer::new();
+        storer
+            .expect_get_signed_entity()
+            .return_once(|_| Ok(Some(record)))
+            .once();
+        dep_builder.signed_entity_storer = Some(Arc::new(storer));
+        let service = dep_builder.get_message_service().await.unwrap();
+        let response = service
+            .get_cardano_drep_stake_distribution_message("whatever")
+            .await
+            .unwrap()
+            .expect("A CardanoDRepStakeDistributionMessage was expected.");
+
+        assert_eq!(message, response);
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_not_exist() {
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        let mut storer = MockSignedEntityStorer::new();
+        storer
+            .expect_get_signed_entity()
+            .return_once(|_| Ok(None))
+            .once();
+        dep_builder.signed_entity_storer = Some(Arc::new(storer));
+        let service = dep_builder.get_message_service().await.unwrap();
+        let response = service
+            .get_cardano_drep_stake_distribution_message("whatever")
+            .await
+            .unwrap();
+
+        assert!(response.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_by_epoch() {
+        let entity = SignedEntity::<CardanoDRepStakeDistribution>::dummy();
+        let record = SignedEntityRecord {
+            signed_entity_id: entity.signed_entity_id.clone(),
+            signed_entity_type: SignedEntityType::CardanoDRepStakeDistribution(
+                entity.artifact.epoch,
+            ),
+            certificate_id: entity.certificate_id.clone(),
+            artifact: serde_json::to_string(&entity.artifact).unwrap(),
+            created_at: entity.created_at,
+        };
+        let message = ToCardanoDRepStakeDistributionMessageAdapter::adapt(entity);
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        let mut storer = MockSignedEntityStorer::new();
+        storer
+            .expect_get_cardano_drep_stake_distribution_signed_entity_by_epoch()
+            .return_once(|_| Ok(Some(record)))
+            .once();
+        dep_builder.signed_entity_storer = Some(Arc::new(storer));
+        let service = dep_builder.get_message_service().await.unwrap();
+        let response = service
+            .get_cardano_drep_stake_distribution_message_by_epoch(Epoch(999))
+            .await
+            .unwrap()
+            .expect("A CardanoDRepStakeDistributionMessage was expected.");
+
+        assert_eq!(message, response);
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_by_epoch_not_exist() {
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        let mut storer = MockSignedEntityStorer::new();
+        storer
+            .expect_get_cardano_drep_stake_distribution_signed_entity_by_epoch()
+            .return_once(|_| Ok(None))
+            .once();
+        dep_builder.signed_entity_storer = Some(Arc::new(storer));
+        let service = dep_builder.get_message_service().await.unwrap();
+        let response = service
+            .get_cardano_drep_stake_distribution_message_by_epoch(Epoch(999))
+            .await
+            .unwrap();
+
+        assert!(response.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_list_message() {
+        let entity = SignedEntity::<CardanoDRepStakeDistribution>::dummy();
+        let records = vec![SignedEntityRecord {
+            signed_entity_id: entity.signed_entity_id.clone(),
+            signed_entity_type: SignedEntityType::CardanoDRepStakeDistribution(
+                entity.artifact.epoch,
+            ),
+            certificate_id: entity.certificate_id.clone(),
+            artifact: serde_json::to_string(&entity.artifact).unwrap(),
+            created_at: entity.created_at,
+        }];
+        let message = ToCardanoDRepStakeDistributionListMessageAdapter::adapt(vec![entity]);
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        let mut storer = MockSignedEntityStorer::new();
+        storer
+            .expect_get_last_signed_entities_by_type()
+            .return_once(|_, _| Ok(records))
+            .once();
+        dep_builder.signed_entity_storer = Some(Arc::new(storer));
+        let service = dep_builder.get_message_service().await.unwrap();
+        let response = service
+            .get_cardano_drep_stake_distribution_list_message(10)
+            .await
+            .unwrap();
+
+        assert_eq!(message, response);
+    }
 }
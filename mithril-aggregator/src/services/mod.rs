@@ -12,19 +12,23 @@
 mod cardano_transactions_importer;
 mod certifier;
 mod epoch_service;
+mod leader_election;
 mod message;
 mod prover;
 mod signable_builder;
 mod signed_entity;
 mod stake_distribution;
+mod store_retention;
 mod upkeep;
 
 pub use cardano_transactions_importer::*;
 pub use certifier::*;
 pub use epoch_service::*;
+pub use leader_election::*;
 pub use message::*;
 pub use prover::*;
 pub use signable_builder::*;
 pub use signed_entity::*;
 pub use stake_distribution::*;
+pub use store_retention::*;
 pub use upkeep::*;
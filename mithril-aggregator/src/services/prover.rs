@@ -1,16 +1,21 @@
 use async_trait::async_trait;
 use rayon::prelude::*;
-use slog::{debug, info, Logger};
+use slog::{debug, info, warn, Logger};
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::Duration,
 };
+use tokio::sync::Mutex;
 
 use mithril_common::{
-    crypto_helper::{MKMap, MKMapNode, MKTree, MKTreeStorer},
+    crypto_helper::{MKMap, MKMapNode, MKTree, MKTreeNode, MKTreeStorer},
     entities::{
-        BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionsSetProof, TransactionHash,
+        BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionsSetProof,
+        ExtendedCardanoTransaction, TransactionHash,
     },
     resource_pool::ResourcePool,
     signable_builder::BlockRangeRootRetriever,
@@ -30,6 +35,9 @@ pub trait ProverService: Sync + Send {
 
     /// Compute the cache
     async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()>;
+
+    /// Check if the cache has been computed and the prover is ready to compute proofs
+    fn is_cache_ready(&self) -> bool;
 }
 
 /// Transactions retriever
@@ -48,6 +56,25 @@ pub trait TransactionsRetriever: Sync + Send {
         &self,
         block_ranges: Vec<BlockRange>,
     ) -> StdResult<Vec<CardanoTransaction>>;
+
+    /// Get by block ranges, with the contents of the transactions
+    async fn get_extended_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<ExtendedCardanoTransaction>>;
+}
+
+/// Store that persists the Merkle tree of the block range roots Merkle map used by the prover cache
+///
+/// It allows the prover to reuse the Merkle tree nodes computed before a restart, and to only
+/// append the block range roots that were not yet included in the tree.
+#[cfg_attr(test, mockall::automock)]
+pub trait ProverCacheStore<S: MKTreeStorer>: Sync + Send {
+    /// Get a Merkle tree storer that holds the persisted nodes
+    fn get_storer(&self) -> StdResult<S>;
+
+    /// Truncate the persisted Merkle tree to its first `total_leaves` leaves
+    fn truncate(&self, total_leaves: usize) -> StdResult<()>;
 }
 
 /// Mithril prover
@@ -55,6 +82,10 @@ pub struct MithrilProverService<S: MKTreeStorer> {
     transaction_retriever: Arc<dyn TransactionsRetriever>,
     block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
     mk_map_pool: ResourcePool<MKMap<BlockRange, MKMapNode<BlockRange, S>, S>>,
+    cache_store: Option<Arc<dyn ProverCacheStore<S>>>,
+    cache_up_to: Mutex<Option<BlockNumber>>,
+    cache_ready: AtomicBool,
+    is_extended_mode_enabled: bool,
     logger: Logger,
 }
 
@@ -70,10 +101,92 @@ impl<S: MKTreeStorer> MithrilProverService<S> {
             transaction_retriever,
             block_range_root_retriever,
             mk_map_pool: ResourcePool::new(mk_map_pool_size, vec![]),
+            cache_store: None,
+            cache_up_to: Mutex::new(None),
+            cache_ready: AtomicBool::new(false),
+            is_extended_mode_enabled: false,
             logger,
         }
     }
 
+    /// Enable the extended mode: the proofs are computed with the leaves that commit to the
+    /// contents of the transactions, as the block range roots computed by the importer.
+    pub fn with_extended_mode(mut self, is_extended_mode_enabled: bool) -> Self {
+        self.is_extended_mode_enabled = is_extended_mode_enabled;
+        self
+    }
+
+    /// Persist the Merkle tree of the cache in the given store, so that it can be reused after a restart
+    pub fn with_cache_store(mut self, cache_store: Arc<dyn ProverCacheStore<S>>) -> Self {
+        self.cache_store = Some(cache_store);
+        self
+    }
+
+    /// Compute the block range roots Merkle map, reusing and extending the Merkle tree persisted
+    /// in the cache store
+    async fn compute_merkle_map_from_cache_store(
+        &self,
+        cache_store: &dyn ProverCacheStore<S>,
+        up_to: BlockNumber,
+    ) -> StdResult<MKMap<BlockRange, MKMapNode<BlockRange, S>, S>> {
+        let block_range_roots = self
+            .block_range_root_retriever
+            .retrieve_block_range_roots(up_to)
+            .await?
+            .map(|(block_range, root)| (block_range, root.into()))
+            .collect::<Vec<(_, MKMapNode<_, S>)>>();
+        let total_block_range_roots = block_range_roots.len();
+        // The storer must be released before truncating the cache store, which can not be
+        // truncated while it is still referenced
+        let total_persisted_leaves = cache_store.get_storer()?.try_total_leaves()?;
+        if total_persisted_leaves > total_block_range_roots {
+            // Some block range roots have been rolled back since the tree was persisted
+            self.drain_mk_map_pool()?;
+            cache_store.truncate(total_block_range_roots)?;
+        }
+
+        match MKMap::new_from_tree(
+            block_range_roots.clone(),
+            MKTree::new_from_storer(cache_store.get_storer()?)?,
+        ) {
+            Ok(mk_map) => {
+                debug!(
+                    self.logger,
+                    "Prover reused the persisted Merkle tree of the cache";
+                    "persisted_leaves" => total_persisted_leaves.min(total_block_range_roots),
+                    "appended_leaves" => total_block_range_roots.saturating_sub(total_persisted_leaves),
+                );
+
+                Ok(mk_map)
+            }
+            Err(error) => {
+                warn!(
+                    self.logger,
+                    "Prover persisted Merkle tree of the cache does not match the block range roots, it will be recomputed";
+                    "error" => ?error
+                );
+                self.drain_mk_map_pool()?;
+                cache_store.truncate(0)?;
+                MKMap::new_from_tree(
+                    block_range_roots,
+                    MKTree::new_from_storer(cache_store.get_storer()?)?,
+                )
+            }
+        }
+    }
+
+    /// Drain the Merkle map pool, the resources currently acquired are discarded when given back.
+    ///
+    /// The Merkle maps of the pool share the storer of the cache store, which must be drained
+    /// before truncating the cache store.
+    fn drain_mk_map_pool(&self) -> StdResult<u64> {
+        let discriminant_new = self.mk_map_pool.discriminant()? + 1;
+        self.mk_map_pool.set_discriminant(discriminant_new)?;
+        self.mk_map_pool.clear();
+
+        Ok(discriminant_new)
+    }
+
     async fn get_block_ranges(
         &self,
         transaction_hashes: &[TransactionHash],
@@ -91,24 +204,63 @@ impl<S: MKTreeStorer> MithrilProverService<S> {
         Ok(block_ranges.into_iter().collect::<Vec<_>>())
     }
 
-    /// Get all the transactions of the block ranges
-    async fn get_all_transactions_for_block_ranges(
+    /// Get the Merkle tree leaves of all the transactions of the block ranges, and the hashes of
+    /// their contents if the extended mode is enabled
+    async fn get_all_leaves_for_block_ranges(
         &self,
         block_ranges: &[BlockRange],
-    ) -> StdResult<HashMap<BlockRange, Vec<CardanoTransaction>>> {
-        let mut block_ranges_map = HashMap::new();
-        let transactions = self
-            .transaction_retriever
-            .get_by_block_ranges(block_ranges.to_vec())
-            .await?;
-        for transaction in transactions {
-            let block_range = BlockRange::from_block_number(transaction.block_number);
-            let block_range_transactions: &mut Vec<_> =
-                block_ranges_map.entry(block_range).or_insert(vec![]);
-            block_range_transactions.push(transaction)
+    ) -> StdResult<(
+        HashMap<BlockRange, Vec<MKTreeNode>>,
+        HashMap<TransactionHash, String>,
+    )> {
+        let mut block_ranges_map: HashMap<BlockRange, Vec<MKTreeNode>> = HashMap::new();
+        let mut contents_hashes = HashMap::new();
+        if self.is_extended_mode_enabled {
+            let transactions = self
+                .transaction_retriever
+                .get_extended_by_block_ranges(block_ranges.to_vec())
+                .await?;
+            for transaction in transactions {
+                let block_range =
+                    BlockRange::from_block_number(transaction.transaction.block_number);
+                let transaction_hash = transaction.transaction.transaction_hash;
+                let content_hash = transaction.content.compute_hash();
+                block_ranges_map.entry(block_range).or_default().push(
+                    ExtendedCardanoTransaction::compute_leaf(&transaction_hash, &content_hash),
+                );
+                contents_hashes.insert(transaction_hash, content_hash);
+            }
+        } else {
+            let transactions = self
+                .transaction_retriever
+                .get_by_block_ranges(block_ranges.to_vec())
+                .await?;
+            for transaction in transactions {
+                let block_range = BlockRange::from_block_number(transaction.block_number);
+                block_ranges_map
+                    .entry(block_range)
+                    .or_default()
+                    .push(transaction.into());
+            }
         }
 
-        Ok(block_ranges_map)
+        Ok((block_ranges_map, contents_hashes))
+    }
+
+    /// Compute the leaf of a transaction to prove, `None` if its content is unknown in the
+    /// extended mode
+    fn compute_leaf_to_prove(
+        &self,
+        transaction_hash: &TransactionHash,
+        contents_hashes: &HashMap<TransactionHash, String>,
+    ) -> Option<MKTreeNode> {
+        if self.is_extended_mode_enabled {
+            contents_hashes.get(transaction_hash).map(|content_hash| {
+                ExtendedCardanoTransaction::compute_leaf(transaction_hash, content_hash)
+            })
+        } else {
+            Some(transaction_hash.as_str().into())
+        }
     }
 }
 
@@ -121,15 +273,15 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
     ) -> StdResult<Vec<CardanoTransactionsSetProof>> {
         // 1 - Compute the set of block ranges with transactions to prove
         let block_ranges_transactions = self.get_block_ranges(transaction_hashes, up_to).await?;
-        let block_range_transactions = self
-            .get_all_transactions_for_block_ranges(&block_ranges_transactions)
+        let (block_range_leaves, contents_hashes) = self
+            .get_all_leaves_for_block_ranges(&block_ranges_transactions)
             .await?;
 
         // 2 - Compute block ranges sub Merkle trees
-        let mk_trees: StdResult<Vec<(BlockRange, MKTree<S>)>> = block_range_transactions
+        let mk_trees: StdResult<Vec<(BlockRange, MKTree<S>)>> = block_range_leaves
             .into_iter()
-            .map(|(block_range, transactions)| {
-                let mk_tree = MKTree::new(&transactions)?;
+            .map(|(block_range, leaves)| {
+                let mk_tree = MKTree::new(&leaves)?;
                 Ok((block_range, mk_tree))
             })
             .collect();
@@ -145,18 +297,36 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
         }
 
         // 5 - Compute the proof for all transactions
-        if let Ok(mk_proof) = mk_map.compute_proof(transaction_hashes) {
+        let transactions_leaves: Vec<(&TransactionHash, MKTreeNode)> = transaction_hashes
+            .iter()
+            .filter_map(|hash| {
+                self.compute_leaf_to_prove(hash, &contents_hashes)
+                    .map(|leaf| (hash, leaf))
+            })
+            .collect();
+        let leaves_to_prove: Vec<MKTreeNode> = transactions_leaves
+            .iter()
+            .map(|(_, leaf)| leaf.clone())
+            .collect();
+        if let Ok(mk_proof) = mk_map.compute_proof(&leaves_to_prove) {
             self.mk_map_pool.give_back_resource_pool_item(mk_map)?;
             let mk_proof_leaves = mk_proof.leaves();
-            let transaction_hashes_certified: Vec<TransactionHash> = transaction_hashes
+            let transaction_hashes_certified: Vec<TransactionHash> = transactions_leaves
+                .into_iter()
+                .filter(|(_, leaf)| mk_proof_leaves.contains(leaf))
+                .map(|(hash, _)| hash.to_owned())
+                .collect();
+            let transactions_contents_hashes: Vec<String> = transaction_hashes_certified
                 .iter()
-                .filter(|hash| mk_proof_leaves.contains(&hash.as_str().into()))
-                .cloned()
+                .filter_map(|hash| contents_hashes.get(hash).cloned())
                 .collect();
 
             Ok(vec![CardanoTransactionsSetProof::new(
                 transaction_hashes_certified,
                 mk_proof,
+            )
+            .with_transactions_contents_hashes(
+                transactions_contents_hashes,
             )])
         } else {
             Ok(vec![])
@@ -164,16 +334,34 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
     }
 
     async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()> {
+        // Only one computation of the cache at a time, the cache is never computed for a lower block number
+        let mut cache_up_to = self.cache_up_to.lock().await;
+        if cache_up_to.is_some_and(|cache_up_to| cache_up_to > up_to) {
+            debug!(
+                self.logger,
+                "Prover skips computing the Merkle map pool resource since it is already computed for a higher block number";
+                "up_to_block_number" => *up_to,
+            );
+            return Ok(());
+        }
+
         let pool_size = self.mk_map_pool.size();
         info!(
             self.logger,
             "Prover starts computing the Merkle map pool resource of size {pool_size}";
             "up_to_block_number" => *up_to,
         );
-        let mk_map_cache = self
-            .block_range_root_retriever
-            .compute_merkle_map_from_block_range_roots(up_to)
-            .await?;
+        let mk_map_cache = match &self.cache_store {
+            Some(cache_store) => {
+                self.compute_merkle_map_from_cache_store(cache_store.as_ref(), up_to)
+                    .await?
+            }
+            None => {
+                self.block_range_root_retriever
+                    .compute_merkle_map_from_block_range_roots(up_to)
+                    .await?
+            }
+        };
         let mk_maps_new = (1..=pool_size)
             .into_par_iter()
             .map(|i| {
@@ -185,9 +373,7 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
             })
             .collect::<Vec<MKMap<_, _, _>>>();
         debug!(self.logger, "Prover is draining the Merkle map pool");
-        let discriminant_new = self.mk_map_pool.discriminant()? + 1;
-        self.mk_map_pool.set_discriminant(discriminant_new)?;
-        self.mk_map_pool.clear();
+        let discriminant_new = self.drain_mk_map_pool()?;
         debug!(
             self.logger,
             "Prover is giving back new resources to the Merkle map pool"
@@ -199,6 +385,8 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
                     .give_back_resource(mk_map, discriminant_new)
             })
             .collect::<StdResult<Vec<_>>>()?;
+        *cache_up_to = Some(up_to);
+        self.cache_ready.store(true, Ordering::Relaxed);
         info!(
             self.logger,
             "Prover completed computing the Merkle map pool resource of size {pool_size}"
@@ -206,6 +394,10 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
 
         Ok(())
     }
+
+    fn is_cache_ready(&self) -> bool {
+        self.cache_ready.load(Ordering::Relaxed)
+    }
 }
 
 #[cfg(test)]
@@ -214,7 +406,7 @@ mod tests {
     use mithril_common::crypto_helper::{
         MKMap, MKMapNode, MKTreeNode, MKTreeStoreInMemory, MKTreeStorer,
     };
-    use mithril_common::entities::CardanoTransaction;
+    use mithril_common::entities::{CardanoTransaction, CardanoTransactionContent};
     use mithril_common::test_utils::CardanoTransactionsBuilder;
     use mockall::mock;
     use mockall::predicate::eq;
@@ -312,6 +504,23 @@ mod tests {
             .unwrap()
         }
 
+        pub fn compute_block_range_roots_from_block_ranges_map(
+            block_ranges_map: &BTreeMap<BlockRange, Vec<CardanoTransaction>>,
+        ) -> Vec<(BlockRange, MKTreeNode)> {
+            block_ranges_map
+                .iter()
+                .map(|(block_range, transactions)| {
+                    (
+                        block_range.to_owned(),
+                        MKTree::<MKTreeStoreInMemory>::new(transactions)
+                            .unwrap()
+                            .compute_root()
+                            .unwrap(),
+                    )
+                })
+                .collect()
+        }
+
         pub fn compute_beacon_from_transactions(
             transactions: &[CardanoTransaction],
         ) -> BlockNumber {
@@ -428,6 +637,154 @@ mod tests {
         transactions_set_proof[0].verify().unwrap();
     }
 
+    #[tokio::test]
+    async fn compute_proof_with_the_leaves_committing_to_the_contents_if_extended_mode_enabled() {
+        let transactions = CardanoTransactionsBuilder::new()
+            .max_transactions_per_block(1)
+            .blocks_per_block_range(3)
+            .build_block_ranges(5);
+        let extended_transactions: Vec<ExtendedCardanoTransaction> = transactions
+            .iter()
+            .enumerate()
+            .map(|(index, transaction)| {
+                ExtendedCardanoTransaction::new(
+                    transaction.clone(),
+                    CardanoTransactionContent {
+                        metadata_labels: vec![index as u64],
+                        ..CardanoTransactionContent::default()
+                    },
+                )
+            })
+            .collect();
+        let transactions_to_prove =
+            test_data::filter_transactions_for_indices(&[1, 2, 4], &transactions);
+        let test_data = test_data::build_test_data(&transactions_to_prove, &transactions);
+        let prover = build_prover::<_, _, MKTreeStoreInMemory>(
+            |transaction_retriever_mock| {
+                let transactions_to_prove = transactions_to_prove.clone();
+                transaction_retriever_mock
+                    .expect_get_by_hashes()
+                    .return_once(move |_, _| Ok(transactions_to_prove));
+
+                let block_ranges_to_prove = test_data.block_ranges_to_prove.clone();
+                let extended_transactions_in_block_ranges_to_prove: Vec<_> = extended_transactions
+                    .iter()
+                    .filter(|t| {
+                        block_ranges_to_prove
+                            .contains(&BlockRange::from_block_number(t.transaction.block_number))
+                    })
+                    .cloned()
+                    .collect();
+                transaction_retriever_mock
+                    .expect_get_extended_by_block_ranges()
+                    .with(eq(block_ranges_to_prove))
+                    .return_once(move |_| Ok(extended_transactions_in_block_ranges_to_prove));
+            },
+            |block_range_root_retriever_mock| {
+                let extended_transactions = extended_transactions.clone();
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(move |_| {
+                        let mut block_ranges_leaves: BTreeMap<BlockRange, Vec<MKTreeNode>> =
+                            BTreeMap::new();
+                        for transaction in &extended_transactions {
+                            block_ranges_leaves
+                                .entry(BlockRange::from_block_number(
+                                    transaction.transaction.block_number,
+                                ))
+                                .or_default()
+                                .push(transaction.into());
+                        }
+
+                        MKMap::new_from_iter(block_ranges_leaves.into_iter().map(
+                            |(block_range, leaves)| {
+                                (
+                                    block_range,
+                                    MKMapNode::TreeNode(
+                                        MKTree::<MKTreeStoreInMemory>::new(&leaves)
+                                            .unwrap()
+                                            .compute_root()
+                                            .unwrap(),
+                                    ),
+                                )
+                            },
+                        ))
+                    });
+            },
+        )
+        .with_extended_mode(true);
+        prover.compute_cache(test_data.beacon).await.unwrap();
+
+        let transactions_set_proof = prover
+            .compute_transactions_proofs(test_data.beacon, &test_data.transaction_hashes_to_prove)
+            .await
+            .unwrap();
+
+        assert_eq!(transactions_set_proof.len(), 1);
+        assert_eq!(
+            transactions_set_proof[0].transactions_hashes(),
+            test_data.transaction_hashes_to_prove
+        );
+        assert_eq!(
+            transactions_set_proof[0].transactions_contents_hashes(),
+            [1, 2, 4]
+                .iter()
+                .map(|index| extended_transactions[*index].content.compute_hash())
+                .collect::<Vec<_>>()
+        );
+        transactions_set_proof[0].verify().unwrap();
+    }
+
+    #[tokio::test]
+    async fn cache_is_ready_only_after_it_has_been_computed() {
+        let prover = build_prover::<_, _, MKTreeStoreInMemory>(
+            |_transaction_retriever_mock| {},
+            |block_range_root_retriever_mock| {
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(|_| Ok(MKMap::new(&[]).unwrap()));
+            },
+        );
+        assert!(!prover.is_cache_ready());
+
+        prover.compute_cache(BlockNumber(10)).await.unwrap();
+
+        assert!(prover.is_cache_ready());
+    }
+
+    #[tokio::test]
+    async fn cache_is_not_ready_if_its_computation_fails() {
+        let prover = build_prover::<_, _, MKTreeStoreInMemory>(
+            |_transaction_retriever_mock| {},
+            |block_range_root_retriever_mock| {
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(|_| Err(anyhow!("Error")));
+            },
+        );
+
+        prover.compute_cache(BlockNumber(10)).await.unwrap_err();
+
+        assert!(!prover.is_cache_ready());
+    }
+
+    #[tokio::test]
+    async fn cache_is_not_computed_for_a_lower_block_number_than_the_current_one() {
+        let prover = build_prover::<_, _, MKTreeStoreInMemory>(
+            |_transaction_retriever_mock| {},
+            |block_range_root_retriever_mock| {
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .with(eq(BlockNumber(20)))
+                    .times(1)
+                    .returning(|_| Ok(MKMap::new(&[]).unwrap()));
+            },
+        );
+
+        prover.compute_cache(BlockNumber(20)).await.unwrap();
+        prover.compute_cache(BlockNumber(10)).await.unwrap();
+    }
+
     #[tokio::test]
     async fn cant_compute_proof_for_not_yet_certified_transaction() {
         let transactions = CardanoTransactionsBuilder::new()
@@ -606,6 +963,207 @@ mod tests {
             .expect_err("Should have failed because of transaction retriever failure");
     }
 
+    mod cache_store {
+        use std::collections::VecDeque;
+        use std::path::{Path, PathBuf};
+
+        use mithril_common::crypto_helper::MKTreeLeafIndexer;
+        use mithril_common::test_utils::TempDir;
+
+        use crate::MKTreeStoreSqlite;
+
+        use super::*;
+
+        fn build_prover_with_cache_store(
+            block_range_roots: Vec<(BlockRange, MKTreeNode)>,
+            cache_store_path: &Path,
+        ) -> MithrilProverService<MKTreeStoreSqlite> {
+            let cache_store = MKTreeStoreSqlite::open(cache_store_path).unwrap();
+            build_prover(
+                |_transaction_retriever_mock| {},
+                |block_range_root_retriever_mock| {
+                    block_range_root_retriever_mock
+                        .expect_retrieve_block_range_roots()
+                        .return_once(move |_| Ok(Box::new(block_range_roots.into_iter())));
+                },
+            )
+            .with_cache_store(Arc::new(cache_store))
+        }
+
+        /// Build a prover whose block range root retriever returns the given block range roots,
+        /// one set per computation of the cache.
+        fn build_prover_with_cache_store_and_successive_block_range_roots(
+            successive_block_range_roots: Vec<Vec<(BlockRange, MKTreeNode)>>,
+            cache_store_path: &Path,
+        ) -> MithrilProverService<MKTreeStoreSqlite> {
+            let cache_store = MKTreeStoreSqlite::open(cache_store_path).unwrap();
+            let successive_block_range_roots =
+                std::sync::Mutex::new(VecDeque::from(successive_block_range_roots));
+            build_prover(
+                |_transaction_retriever_mock| {},
+                |block_range_root_retriever_mock| {
+                    block_range_root_retriever_mock
+                        .expect_retrieve_block_range_roots()
+                        .returning(move |_| {
+                            let block_range_roots = successive_block_range_roots
+                                .lock()
+                                .unwrap()
+                                .pop_front()
+                                .expect("no more block range roots to retrieve");
+                            Ok(Box::new(block_range_roots.into_iter()))
+                        });
+                },
+            )
+            .with_cache_store(Arc::new(cache_store))
+        }
+
+        async fn compute_cache_root(
+            prover: &MithrilProverService<MKTreeStoreSqlite>,
+            up_to: BlockNumber,
+        ) -> MKTreeNode {
+            prover.compute_cache(up_to).await.unwrap();
+            let mk_map = prover
+                .mk_map_pool
+                .acquire_resource(Duration::from_millis(1000))
+                .unwrap();
+
+            mk_map.compute_root().unwrap()
+        }
+
+        fn compute_expected_root(block_range_roots: &[(BlockRange, MKTreeNode)]) -> MKTreeNode {
+            MKMap::<_, MKMapNode<_, MKTreeStoreInMemory>, MKTreeStoreInMemory>::new_from_iter(
+                block_range_roots
+                    .iter()
+                    .map(|(block_range, root)| (block_range.to_owned(), root.to_owned().into())),
+            )
+            .unwrap()
+            .compute_root()
+            .unwrap()
+        }
+
+        fn cache_store_path(test_name: &str) -> PathBuf {
+            TempDir::create("prover_cache_store", test_name).join("cache.sqlite3")
+        }
+
+        fn count_persisted_leaves(cache_store_path: &Path) -> usize {
+            MKTreeStoreSqlite::open(cache_store_path)
+                .unwrap()
+                .try_total_leaves()
+                .unwrap()
+        }
+
+        fn build_block_range_roots(total_block_ranges: usize) -> Vec<(BlockRange, MKTreeNode)> {
+            let transactions = CardanoTransactionsBuilder::new()
+                .max_transactions_per_block(1)
+                .blocks_per_block_range(3)
+                .build_block_ranges(total_block_ranges);
+
+            test_data::compute_block_range_roots_from_block_ranges_map(
+                &test_data::transactions_group_by_block_range(&transactions),
+            )
+        }
+
+        #[tokio::test]
+        async fn compute_cache_persists_and_extends_merkle_tree_in_cache_store() {
+            let cache_store_path =
+                cache_store_path("compute_cache_persists_and_extends_merkle_tree_in_cache_store");
+            let block_range_roots = build_block_range_roots(5);
+
+            let prover =
+                build_prover_with_cache_store(block_range_roots[0..3].to_vec(), &cache_store_path);
+            let root = compute_cache_root(&prover, BlockNumber(10)).await;
+            assert_eq!(compute_expected_root(&block_range_roots[0..3]), root);
+            assert_eq!(3, count_persisted_leaves(&cache_store_path));
+
+            let prover =
+                build_prover_with_cache_store(block_range_roots.clone(), &cache_store_path);
+            let root = compute_cache_root(&prover, BlockNumber(20)).await;
+            assert_eq!(compute_expected_root(&block_range_roots), root);
+            assert_eq!(5, count_persisted_leaves(&cache_store_path));
+        }
+
+        #[tokio::test]
+        async fn compute_cache_truncates_rolled_back_block_ranges_from_cache_store() {
+            let cache_store_path = cache_store_path(
+                "compute_cache_truncates_rolled_back_block_ranges_from_cache_store",
+            );
+            let block_range_roots = build_block_range_roots(5);
+
+            let prover =
+                build_prover_with_cache_store(block_range_roots.clone(), &cache_store_path);
+            compute_cache_root(&prover, BlockNumber(20)).await;
+            assert_eq!(5, count_persisted_leaves(&cache_store_path));
+
+            let prover =
+                build_prover_with_cache_store(block_range_roots[0..3].to_vec(), &cache_store_path);
+            let root = compute_cache_root(&prover, BlockNumber(10)).await;
+            assert_eq!(compute_expected_root(&block_range_roots[0..3]), root);
+            assert_eq!(3, count_persisted_leaves(&cache_store_path));
+        }
+
+        #[tokio::test]
+        async fn compute_cache_twice_on_the_same_prover_truncates_rolled_back_block_ranges() {
+            let cache_store_path = cache_store_path(
+                "compute_cache_twice_on_the_same_prover_truncates_rolled_back_block_ranges",
+            );
+            let block_range_roots = build_block_range_roots(5);
+            let prover = build_prover_with_cache_store_and_successive_block_range_roots(
+                vec![block_range_roots.clone(), block_range_roots[0..3].to_vec()],
+                &cache_store_path,
+            );
+
+            compute_cache_root(&prover, BlockNumber(20)).await;
+            assert_eq!(5, count_persisted_leaves(&cache_store_path));
+
+            let root = compute_cache_root(&prover, BlockNumber(30)).await;
+            assert_eq!(compute_expected_root(&block_range_roots[0..3]), root);
+            assert_eq!(3, count_persisted_leaves(&cache_store_path));
+        }
+
+        #[tokio::test]
+        async fn compute_cache_twice_on_the_same_prover_recomputes_cache_store_not_matching_block_ranges(
+        ) {
+            let cache_store_path = cache_store_path(
+                "compute_cache_twice_on_the_same_prover_recomputes_cache_store_not_matching_block_ranges",
+            );
+            let block_range_roots = build_block_range_roots(5);
+            let rolled_back_block_range_roots = build_block_range_roots(3)
+                .into_iter()
+                .map(|(block_range, root)| {
+                    (block_range, format!("rolled-back-{}", root.to_hex()).into())
+                })
+                .collect::<Vec<_>>();
+            let prover = build_prover_with_cache_store_and_successive_block_range_roots(
+                vec![block_range_roots, rolled_back_block_range_roots.clone()],
+                &cache_store_path,
+            );
+
+            compute_cache_root(&prover, BlockNumber(20)).await;
+            assert_eq!(5, count_persisted_leaves(&cache_store_path));
+
+            let root = compute_cache_root(&prover, BlockNumber(30)).await;
+            assert_eq!(compute_expected_root(&rolled_back_block_range_roots), root);
+            assert_eq!(3, count_persisted_leaves(&cache_store_path));
+        }
+
+        #[tokio::test]
+        async fn compute_cache_recomputes_cache_store_not_matching_block_ranges() {
+            let cache_store_path =
+                cache_store_path("compute_cache_recomputes_cache_store_not_matching_block_ranges");
+            let block_range_roots = build_block_range_roots(3);
+            MKTree::new_from_storer(MKTreeStoreSqlite::open(&cache_store_path).unwrap())
+                .unwrap()
+                .append(&["leaf-1", "leaf-2"])
+                .unwrap();
+
+            let prover =
+                build_prover_with_cache_store(block_range_roots.clone(), &cache_store_path);
+            let root = compute_cache_root(&prover, BlockNumber(10)).await;
+            assert_eq!(compute_expected_root(&block_range_roots), root);
+            assert_eq!(3, count_persisted_leaves(&cache_store_path));
+        }
+    }
+
     #[tokio::test]
     async fn cant_compute_proof_if_block_range_root_retriever_fails() {
         let transactions = CardanoTransactionsBuilder::new()
@@ -11,9 +11,9 @@ use tokio::task::JoinHandle;
 
 use mithril_common::{
     entities::{
-        BlockNumber, CardanoDbBeacon, CardanoStakeDistribution, CardanoTransactionsSnapshot,
-        Certificate, Epoch, MithrilStakeDistribution, SignedEntity, SignedEntityType,
-        SignedEntityTypeDiscriminants, Snapshot,
+        BlockNumber, CardanoDRepStakeDistribution, CardanoDbBeacon, CardanoStakeDistribution,
+        CardanoTransactionsSnapshot, Certificate, Epoch, MithrilStakeDistribution, SignedEntity,
+        SignedEntityType, SignedEntityTypeDiscriminants, Snapshot,
     },
     signable_builder::Artifact,
     signed_entity_type_lock::SignedEntityTypeLock,
@@ -75,6 +75,13 @@ pub trait SignedEntityService: Send + Sync {
         &self,
         total: usize,
     ) -> StdResult<Vec<SignedEntity<CardanoStakeDistribution>>>;
+
+    /// Return a list of signed Cardano DRep stake distribution order by creation
+    /// date descending.
+    async fn get_last_signed_cardano_drep_stake_distributions(
+        &self,
+        total: usize,
+    ) -> StdResult<Vec<SignedEntity<CardanoDRepStakeDistribution>>>;
 }
 
 /// Mithril ArtifactBuilder Service
@@ -90,6 +97,8 @@ pub struct MithrilSignedEntityService {
     signed_entity_type_lock: Arc<SignedEntityTypeLock>,
     cardano_stake_distribution_artifact_builder:
         Arc<dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>>,
+    cardano_drep_stake_distribution_artifact_builder:
+        Arc<dyn ArtifactBuilder<Epoch, CardanoDRepStakeDistribution>>,
 }
 
 impl MithrilSignedEntityService {
@@ -109,6 +118,9 @@ impl MithrilSignedEntityService {
         cardano_stake_distribution_artifact_builder: Arc<
             dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>,
         >,
+        cardano_drep_stake_distribution_artifact_builder: Arc<
+            dyn ArtifactBuilder<Epoch, CardanoDRepStakeDistribution>,
+        >,
     ) -> Self {
         Self {
             signed_entity_storer,
@@ -117,6 +129,7 @@ impl MithrilSignedEntityService {
             cardano_transactions_artifact_builder,
             signed_entity_type_lock,
             cardano_stake_distribution_artifact_builder,
+            cardano_drep_stake_distribution_artifact_builder,
         }
     }
 
@@ -210,6 +223,16 @@ impl MithrilSignedEntityService {
                         )
                     })?,
             )),
+            SignedEntityType::CardanoDRepStakeDistribution(epoch) => Ok(Arc::new(
+                self.cardano_drep_stake_distribution_artifact_builder
+                    .compute_artifact(epoch, certificate)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Signed Entity Service can not compute artifact for entity type: '{signed_entity_type}'"
+                        )
+                    })?,
+            )),
         }
     }
 
@@ -384,6 +407,25 @@ impl SignedEntityService for MithrilSignedEntityService {
 
         Ok(signed_entities)
     }
+
+    async fn get_last_signed_cardano_drep_stake_distributions(
+        &self,
+        total: usize,
+    ) -> StdResult<Vec<SignedEntity<CardanoDRepStakeDistribution>>> {
+        let signed_entities_records = self
+            .get_last_signed_entities(
+                total,
+                &SignedEntityTypeDiscriminants::CardanoDRepStakeDistribution,
+            )
+            .await?;
+        let mut signed_entities: Vec<SignedEntity<CardanoDRepStakeDistribution>> = Vec::new();
+
+        for record in signed_entities_records {
+            signed_entities.push(record.try_into()?);
+        }
+
+        Ok(signed_entities)
+    }
 }
 
 #[cfg(test)]
@@ -440,6 +482,8 @@ mod tests {
             MockArtifactBuilder<BlockNumber, CardanoTransactionsSnapshot>,
         mock_cardano_stake_distribution_artifact_builder:
             MockArtifactBuilder<Epoch, CardanoStakeDistribution>,
+        mock_cardano_drep_stake_distribution_artifact_builder:
+            MockArtifactBuilder<Epoch, CardanoDRepStakeDistribution>,
     }
 
     impl MockDependencyInjector {
@@ -462,6 +506,10 @@ mod tests {
                     Epoch,
                     CardanoStakeDistribution,
                 >::new(),
+                mock_cardano_drep_stake_distribution_artifact_builder: MockArtifactBuilder::<
+                    Epoch,
+                    CardanoDRepStakeDistribution,
+                >::new(),
             }
         }
 
@@ -473,6 +521,7 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(SignedEntityTypeLock::default()),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
+                Arc::new(self.mock_cardano_drep_stake_distribution_artifact_builder),
             )
         }
 
@@ -524,6 +573,7 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(SignedEntityTypeLock::default()),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
+                Arc::new(self.mock_cardano_drep_stake_distribution_artifact_builder),
             )
         }
 
@@ -642,6 +692,45 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn build_cardano_drep_stake_distribution_artifact_when_given_cardano_drep_stake_distribution_entity_type(
+    ) {
+        let mut mock_container = MockDependencyInjector::new();
+
+        let cardano_drep_stake_distribution_expected =
+            fake_data::cardano_drep_stake_distribution(Epoch(1));
+
+        mock_container
+            .mock_cardano_drep_stake_distribution_artifact_builder
+            .expect_compute_artifact()
+            .times(1)
+            .returning(|_, _| Ok(fake_data::cardano_drep_stake_distribution(Epoch(1))));
+
+        let artifact_builder_service = mock_container.build_artifact_builder_service();
+
+        let certificate = fake_data::certificate("hash".to_string());
+        let signed_entity_type = SignedEntityType::CardanoDRepStakeDistribution(Epoch(1));
+        let artifact = artifact_builder_service
+            .compute_artifact(signed_entity_type.clone(), &certificate)
+            .await
+            .unwrap();
+
+        assert_expected(&cardano_drep_stake_distribution_expected, &artifact);
+    }
+
+    #[tokio::test]
+    async fn should_store_the_artifact_when_creating_artifact_for_a_cardano_drep_stake_distribution(
+    ) {
+        generic_test_that_the_artifact_is_stored(
+            SignedEntityType::CardanoDRepStakeDistribution(Epoch(1)),
+            fake_data::cardano_drep_stake_distribution(Epoch(1)),
+            &|mock_injector| {
+                &mut mock_injector.mock_cardano_drep_stake_distribution_artifact_builder
+            },
+        )
+        .await;
+    }
+
     #[tokio::test]
     async fn build_snapshot_artifact_when_given_cardano_immutable_files_full_entity_type() {
         let mut mock_container = MockDependencyInjector::new();
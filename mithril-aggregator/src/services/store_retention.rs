@@ -0,0 +1,510 @@
+//! ## Store Retention Pruner
+//!
+//! This service enforces the [retention policies][RetentionPoliciesConfiguration] of the
+//! aggregator stores, it is run by the [upkeep service][crate::services::AggregatorUpkeepService].
+//!
+//! Each policy deletes the records of a store matching a condition, or only counts them in
+//! dry-run mode. The records still referenced by other records are kept.
+
+use chrono::{DateTime, Duration, Utc};
+use slog::{info, Logger};
+use sqlite::Value;
+use std::sync::Arc;
+
+use mithril_common::entities::Epoch;
+use mithril_common::{StdResult, TickerService};
+use mithril_persistence::sqlite::{
+    ConnectionExtensions, DatabaseConnection, SqliteConnection, WhereCondition,
+};
+
+use crate::configuration::RetentionPoliciesConfiguration;
+
+/// Minimum number of epochs kept by the epoch based policies.
+const MINIMUM_EPOCHS_RETENTION: u64 = 3;
+
+/// Number of records deleted from a store, or that would be deleted in dry-run mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionReportEntry {
+    /// Name of the table of the store.
+    pub table: &'static str,
+
+    /// Number of records deleted.
+    pub records: i64,
+}
+
+/// Report of an enforcement of the retention policies.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetentionReport {
+    /// If set, the records of the report were not deleted.
+    pub dry_run: bool,
+
+    /// Records deleted by store, in the order the policies were enforced.
+    pub entries: Vec<RetentionReportEntry>,
+}
+
+/// Records of a table to delete.
+struct RetentionRule {
+    table: &'static str,
+    condition: WhereCondition,
+}
+
+/// Enforce the retention policies of the aggregator stores.
+pub struct StoreRetentionPruner {
+    main_db_connection: Arc<DatabaseConnection>,
+    event_store_connection: Option<Arc<SqliteConnection>>,
+    ticker_service: Arc<dyn TickerService>,
+    policies: RetentionPoliciesConfiguration,
+    logger: Logger,
+}
+
+impl StoreRetentionPruner {
+    /// Create a new instance of the store retention pruner.
+    ///
+    /// The event store policy is only enforced if a connection to the event store is given.
+    pub fn new(
+        main_db_connection: Arc<DatabaseConnection>,
+        event_store_connection: Option<Arc<SqliteConnection>>,
+        ticker_service: Arc<dyn TickerService>,
+        policies: RetentionPoliciesConfiguration,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            main_db_connection,
+            event_store_connection,
+            ticker_service,
+            policies,
+            logger,
+        }
+    }
+
+    /// Delete the records that are out of the retention policies, or only count them in dry-run
+    /// mode.
+    pub async fn prune(&self) -> StdResult<RetentionReport> {
+        let current_epoch = self.ticker_service.get_current_epoch().await?;
+        let mut report = RetentionReport {
+            dry_run: self.policies.dry_run,
+            entries: vec![],
+        };
+
+        for rule in self.main_db_rules(current_epoch) {
+            report
+                .entries
+                .push(self.enforce(self.main_db_connection.as_ref(), rule)?);
+        }
+        if let Some(connection) = &self.event_store_connection {
+            // The event table is created when the first event is persisted
+            let event_table_count: i64 = connection.query_single_cell(
+                "select count(*) from sqlite_master where type = 'table' and name = 'event'",
+                &[],
+            )?;
+            let rules = match event_table_count {
+                0 => vec![],
+                _ => self.event_store_rules(Utc::now()),
+            };
+            for rule in rules {
+                report
+                    .entries
+                    .push(self.enforce(connection.as_ref(), rule)?);
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn main_db_rules(&self, current_epoch: Epoch) -> Vec<RetentionRule> {
+        let epoch_threshold = |epochs_to_keep: u64| {
+            let threshold = current_epoch
+                .0
+                .saturating_sub(epochs_to_keep.max(MINIMUM_EPOCHS_RETENTION));
+            Value::Integer(threshold as i64)
+        };
+        let mut rules = vec![];
+
+        // Registrations are deleted before the epoch settings they reference
+        if let Some(epochs) = self.policies.signer_registration_epochs {
+            rules.push(RetentionRule {
+                table: "signer_registration",
+                condition: WhereCondition::new(
+                    "epoch_setting_id < ?* and not exists (select 1 from single_signature \
+                    where single_signature.signer_id = signer_registration.signer_id \
+                    and single_signature.registration_epoch_setting_id = signer_registration.epoch_setting_id)",
+                    vec![epoch_threshold(epochs)],
+                ),
+            });
+        }
+        if let Some(epochs) = self.policies.stake_pool_epochs {
+            rules.push(RetentionRule {
+                table: "stake_pool",
+                condition: WhereCondition::new("epoch < ?*", vec![epoch_threshold(epochs)]),
+            });
+        }
+        if let Some(epochs) = self.policies.epoch_settings_epochs {
+            rules.push(RetentionRule {
+                table: "epoch_setting",
+                condition: WhereCondition::new(
+                    "epoch_setting_id < ?* \
+                    and not exists (select 1 from open_message where open_message.epoch_setting_id = epoch_setting.epoch_setting_id) \
+                    and not exists (select 1 from signer_registration where signer_registration.epoch_setting_id = epoch_setting.epoch_setting_id)",
+                    vec![epoch_threshold(epochs)],
+                ),
+            });
+        }
+        if self.policies.delete_certified_buffered_single_signatures {
+            rules.push(RetentionRule {
+                table: "buffered_single_signature",
+                condition: WhereCondition::new(
+                    "exists (select 1 from open_message \
+                    where open_message.signed_entity_type_id = buffered_single_signature.signed_entity_type_id \
+                    and open_message.is_certified = true \
+                    and open_message.created_at > buffered_single_signature.created_at)",
+                    vec![],
+                ),
+            });
+        }
+
+        rules
+    }
+
+    fn event_store_rules(&self, now: DateTime<Utc>) -> Vec<RetentionRule> {
+        match self.policies.event_store_max_age_in_days {
+            Some(days) => vec![RetentionRule {
+                table: "event",
+                condition: WhereCondition::new(
+                    "created_at < ?*",
+                    vec![Value::String(
+                        (now - Duration::days(days as i64)).to_rfc3339(),
+                    )],
+                ),
+            }],
+            None => vec![],
+        }
+    }
+
+    fn enforce<C: ConnectionExtensions>(
+        &self,
+        connection: &C,
+        rule: RetentionRule,
+    ) -> StdResult<RetentionReportEntry> {
+        let (condition, parameters) = rule.condition.expand();
+        // The records are counted and deleted in the same transaction so the reported number is
+        // the one of the deleted records
+        let transaction = connection.begin_transaction()?;
+        let records: i64 = connection.query_single_cell(
+            format!("select count(*) from {} where {condition}", rule.table),
+            &parameters,
+        )?;
+
+        if self.policies.dry_run {
+            info!(
+                self.logger, "StoreRetentionPruner::Dry run: records would be deleted";
+                "table" => rule.table, "records" => records
+            );
+        } else if records > 0 {
+            connection.execute_with_params(
+                format!("delete from {} where {condition}", rule.table),
+                &parameters,
+            )?;
+            info!(
+                self.logger, "StoreRetentionPruner::Records deleted";
+                "table" => rule.table, "records" => records
+            );
+        }
+        transaction.commit()?;
+
+        Ok(RetentionReportEntry {
+            table: rule.table,
+            records,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::chain_observer::FakeObserver;
+    use mithril_common::digesters::DumbImmutableFileObserver;
+    use mithril_common::entities::{ChainPoint, SignedEntityTypeDiscriminants, TimePoint};
+    use mithril_common::test_utils::fake_data;
+    use mithril_common::MithrilTickerService;
+
+    use crate::database::record::BufferedSingleSignatureRecord;
+    use crate::database::test_helper::{
+        insert_buffered_single_signatures, insert_epoch_settings, insert_signer_registrations,
+        insert_stake_pool, main_db_connection,
+    };
+    use crate::event_store::{EventMessage, EventPersister};
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    fn ticker_service(current_epoch: u64) -> Arc<dyn TickerService> {
+        Arc::new(MithrilTickerService::new(
+            Arc::new(FakeObserver::new(Some(TimePoint::new(
+                current_epoch,
+                1,
+                ChainPoint::dummy(),
+            )))),
+            Arc::new(DumbImmutableFileObserver::default()),
+        ))
+    }
+
+    fn count(connection: &impl ConnectionExtensions, table: &str) -> i64 {
+        connection
+            .query_single_cell(format!("select count(*) from {table}"), &[])
+            .unwrap()
+    }
+
+    fn insert_certified_open_message(
+        connection: &DatabaseConnection,
+        discriminant: SignedEntityTypeDiscriminants,
+        created_at: DateTime<Utc>,
+    ) {
+        connection
+            .execute_with_params(
+                "insert into open_message (open_message_id, epoch_setting_id, beacon, signed_entity_type_id, protocol_message, is_certified, created_at) \
+                values (?1, 1, '{}', ?2, '{}', true, ?3)",
+                &[
+                    Value::String(uuid::Uuid::new_v4().to_string()),
+                    Value::Integer(discriminant.index() as i64),
+                    Value::String(created_at.to_rfc3339()),
+                ],
+            )
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn prune_nothing_without_policies() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        insert_stake_pool(&connection, &[1, 2, 3, 4, 5, 6]).unwrap();
+        let pruner = StoreRetentionPruner::new(
+            connection.clone(),
+            None,
+            ticker_service(6),
+            RetentionPoliciesConfiguration::default(),
+            TestLogger::stdout(),
+        );
+
+        let report = pruner.prune().await.unwrap();
+
+        assert_eq!(RetentionReport::default(), report);
+        assert_eq!(18, count(connection.as_ref(), "stake_pool"));
+    }
+
+    #[tokio::test]
+    async fn prune_records_older_than_the_epochs_to_keep() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        insert_stake_pool(&connection, &[1, 2, 3, 4, 5, 6]).unwrap();
+        insert_epoch_settings(&connection, &[1, 2, 3, 4, 5, 6]).unwrap();
+        insert_signer_registrations(
+            &connection,
+            (1..=6)
+                .map(|epoch| (Epoch(epoch), fake_data::signers_with_stakes(2)))
+                .collect(),
+        )
+        .unwrap();
+        let pruner = StoreRetentionPruner::new(
+            connection.clone(),
+            None,
+            ticker_service(6),
+            RetentionPoliciesConfiguration {
+                signer_registration_epochs: Some(4),
+                stake_pool_epochs: Some(3),
+                epoch_settings_epochs: Some(3),
+                ..RetentionPoliciesConfiguration::default()
+            },
+            TestLogger::stdout(),
+        );
+
+        let report = pruner.prune().await.unwrap();
+
+        assert_eq!(
+            RetentionReport {
+                dry_run: false,
+                entries: vec![
+                    RetentionReportEntry {
+                        table: "signer_registration",
+                        records: 2,
+                    },
+                    RetentionReportEntry {
+                        table: "stake_pool",
+                        records: 6,
+                    },
+                    // The settings of the epoch 2 are kept since registrations still reference them
+                    RetentionReportEntry {
+                        table: "epoch_setting",
+                        records: 1,
+                    },
+                ],
+            },
+            report
+        );
+        assert_eq!(10, count(connection.as_ref(), "signer_registration"));
+        assert_eq!(12, count(connection.as_ref(), "stake_pool"));
+        assert_eq!(5, count(connection.as_ref(), "epoch_setting"));
+    }
+
+    #[tokio::test]
+    async fn epoch_based_policies_keep_at_least_three_epochs() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        insert_stake_pool(&connection, &[1, 2, 3, 4, 5, 6]).unwrap();
+        let pruner = StoreRetentionPruner::new(
+            connection.clone(),
+            None,
+            ticker_service(6),
+            RetentionPoliciesConfiguration {
+                stake_pool_epochs: Some(1),
+                ..RetentionPoliciesConfiguration::default()
+            },
+            TestLogger::stdout(),
+        );
+
+        pruner.prune().await.unwrap();
+
+        assert_eq!(12, count(connection.as_ref(), "stake_pool"));
+    }
+
+    #[tokio::test]
+    async fn prune_buffered_single_signatures_older_than_a_certified_open_message() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        let mut old_signatures = BufferedSingleSignatureRecord::fakes(&[
+            (
+                "party_1",
+                SignedEntityTypeDiscriminants::CardanoTransactions,
+            ),
+            (
+                "party_2",
+                SignedEntityTypeDiscriminants::MithrilStakeDistribution,
+            ),
+        ]);
+        for signature in old_signatures.iter_mut() {
+            signature.created_at = Utc::now() - Duration::minutes(10);
+        }
+        insert_buffered_single_signatures(&connection, old_signatures).unwrap();
+        insert_buffered_single_signatures(
+            &connection,
+            BufferedSingleSignatureRecord::fakes(&[(
+                "party_3",
+                SignedEntityTypeDiscriminants::CardanoTransactions,
+            )]),
+        )
+        .unwrap();
+        insert_certified_open_message(
+            &connection,
+            SignedEntityTypeDiscriminants::CardanoTransactions,
+            Utc::now() - Duration::minutes(5),
+        );
+        let pruner = StoreRetentionPruner::new(
+            connection.clone(),
+            None,
+            ticker_service(6),
+            RetentionPoliciesConfiguration {
+                delete_certified_buffered_single_signatures: true,
+                ..RetentionPoliciesConfiguration::default()
+            },
+            TestLogger::stdout(),
+        );
+
+        let report = pruner.prune().await.unwrap();
+
+        assert_eq!(
+            vec![RetentionReportEntry {
+                table: "buffered_single_signature",
+                records: 1,
+            }],
+            report.entries
+        );
+        assert_eq!(2, count(connection.as_ref(), "buffered_single_signature"));
+    }
+
+    #[tokio::test]
+    async fn prune_events_older_than_the_maximum_age() {
+        let event_store_connection =
+            Arc::new(sqlite::Connection::open_thread_safe(":memory:").unwrap());
+        EventPersister::new(event_store_connection.clone())
+            .persist(EventMessage::new("source", "action", "{}"))
+            .unwrap();
+        for created_at in [
+            Utc::now() - Duration::days(40),
+            Utc::now() - Duration::days(31),
+            Utc::now() - Duration::days(2),
+        ] {
+            event_store_connection
+                .execute_with_params(
+                    "insert into event (source, action, content, created_at) values ('source', 'action', '{}', ?1)",
+                    &[Value::String(created_at.to_rfc3339())],
+                )
+                .unwrap();
+        }
+        let pruner = StoreRetentionPruner::new(
+            Arc::new(main_db_connection().unwrap()),
+            Some(event_store_connection.clone()),
+            ticker_service(6),
+            RetentionPoliciesConfiguration {
+                event_store_max_age_in_days: Some(30),
+                ..RetentionPoliciesConfiguration::default()
+            },
+            TestLogger::stdout(),
+        );
+
+        let report = pruner.prune().await.unwrap();
+
+        assert_eq!(
+            vec![RetentionReportEntry {
+                table: "event",
+                records: 2,
+            }],
+            report.entries
+        );
+        assert_eq!(2, count(event_store_connection.as_ref(), "event"));
+    }
+
+    #[tokio::test]
+    async fn dry_run_reports_the_records_without_deleting_them() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        insert_stake_pool(&connection, &[1, 2, 3, 4, 5, 6]).unwrap();
+        let pruner = StoreRetentionPruner::new(
+            connection.clone(),
+            None,
+            ticker_service(6),
+            RetentionPoliciesConfiguration {
+                stake_pool_epochs: Some(3),
+                dry_run: true,
+                ..RetentionPoliciesConfiguration::default()
+            },
+            TestLogger::stdout(),
+        );
+
+        let report = pruner.prune().await.unwrap();
+
+        assert_eq!(
+            RetentionReport {
+                dry_run: true,
+                entries: vec![RetentionReportEntry {
+                    table: "stake_pool",
+                    records: 6,
+                }],
+            },
+            report
+        );
+        assert_eq!(18, count(connection.as_ref(), "stake_pool"));
+    }
+
+    #[tokio::test]
+    async fn skip_the_event_store_until_its_table_is_created() {
+        let event_store_connection =
+            Arc::new(sqlite::Connection::open_thread_safe(":memory:").unwrap());
+        let pruner = StoreRetentionPruner::new(
+            Arc::new(main_db_connection().unwrap()),
+            Some(event_store_connection),
+            ticker_service(6),
+            RetentionPoliciesConfiguration {
+                event_store_max_age_in_days: Some(30),
+                ..RetentionPoliciesConfiguration::default()
+            },
+            TestLogger::stdout(),
+        );
+
+        let report = pruner.prune().await.unwrap();
+
+        assert_eq!(Vec::<RetentionReportEntry>::new(), report.entries);
+    }
+}
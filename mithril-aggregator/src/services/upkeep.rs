@@ -3,7 +3,9 @@
 //! This service is responsible for the upkeep of the application.
 //!
 //! It is in charge of the following tasks:
-//! * free up space by executing vacuum and WAL checkpoint on the database
+//! * enforce the retention policies of the stores with the [StoreRetentionPruner]
+//! * free up space by executing vacuum and WAL checkpoint on the database, PostgreSQL databases
+//!   are left to the autovacuum of their server
 
 use std::sync::Arc;
 
@@ -14,9 +16,11 @@ use slog::{info, Logger};
 use mithril_common::signed_entity_type_lock::SignedEntityTypeLock;
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{
-    SqliteCleaner, SqliteCleaningTask, SqliteConnection, SqliteConnectionPool,
+    DatabaseConnection, DatabaseConnectionPool, SqliteCleaner, SqliteCleaningTask,
 };
 
+use crate::services::StoreRetentionPruner;
+
 /// Define the service responsible for the upkeep of the application.
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
@@ -30,24 +34,27 @@ pub trait UpkeepService: Send + Sync {
 /// To ensure that connections are cleaned up properly, it creates new connections itself
 /// instead of relying on a connection pool or a shared connection.
 pub struct AggregatorUpkeepService {
-    main_db_connection: Arc<SqliteConnection>,
-    cardano_tx_connection_pool: Arc<SqliteConnectionPool>,
+    main_db_connection: Arc<DatabaseConnection>,
+    cardano_tx_connection_pool: Arc<DatabaseConnectionPool>,
     signed_entity_type_lock: Arc<SignedEntityTypeLock>,
+    store_retention_pruner: Option<Arc<StoreRetentionPruner>>,
     logger: Logger,
 }
 
 impl AggregatorUpkeepService {
     /// Create a new instance of the aggregator upkeep service.
     pub fn new(
-        main_db_connection: Arc<SqliteConnection>,
-        cardano_tx_connection_pool: Arc<SqliteConnectionPool>,
+        main_db_connection: Arc<DatabaseConnection>,
+        cardano_tx_connection_pool: Arc<DatabaseConnectionPool>,
         signed_entity_type_lock: Arc<SignedEntityTypeLock>,
+        store_retention_pruner: Option<Arc<StoreRetentionPruner>>,
         logger: Logger,
     ) -> Self {
         Self {
             main_db_connection,
             cardano_tx_connection_pool,
             signed_entity_type_lock,
+            store_retention_pruner,
             logger,
         }
     }
@@ -61,30 +68,46 @@ impl AggregatorUpkeepService {
             return Ok(());
         }
 
+        // Prune the stores first so the space of the deleted records is reclaimed by the vacuum
+        if let Some(store_retention_pruner) = &self.store_retention_pruner {
+            info!(
+                self.logger,
+                "UpkeepService::Enforcing stores retention policies"
+            );
+            store_retention_pruner
+                .prune()
+                .await
+                .with_context(|| "Stores retention policies enforcement failed")?;
+        }
+
         let main_db_connection = self.main_db_connection.clone();
         let cardano_tx_db_connection_pool = self.cardano_tx_connection_pool.clone();
         let db_upkeep_logger = self.logger.clone();
 
         // Run the database upkeep tasks in another thread to avoid blocking the tokio runtime
         let db_upkeep_thread = tokio::task::spawn_blocking(move || -> StdResult<()> {
-            info!(db_upkeep_logger, "UpkeepService::Cleaning main database");
-            SqliteCleaner::new(&main_db_connection)
-                .with_logger(db_upkeep_logger.clone())
-                .with_tasks(&[
-                    SqliteCleaningTask::Vacuum,
-                    SqliteCleaningTask::WalCheckpointTruncate,
-                ])
-                .run()?;
+            if let Some(main_db_connection) = main_db_connection.as_sqlite() {
+                info!(db_upkeep_logger, "UpkeepService::Cleaning main database");
+                SqliteCleaner::new(main_db_connection)
+                    .with_logger(db_upkeep_logger.clone())
+                    .with_tasks(&[
+                        SqliteCleaningTask::Vacuum,
+                        SqliteCleaningTask::WalCheckpointTruncate,
+                    ])
+                    .run()?;
+            }
 
-            info!(
-                db_upkeep_logger,
-                "UpkeepService::Cleaning cardano transactions database"
-            );
             let cardano_tx_db_connection = cardano_tx_db_connection_pool.connection()?;
-            SqliteCleaner::new(&cardano_tx_db_connection)
-                .with_logger(db_upkeep_logger.clone())
-                .with_tasks(&[SqliteCleaningTask::WalCheckpointTruncate])
-                .run()?;
+            if let Some(cardano_tx_db_connection) = cardano_tx_db_connection.as_sqlite() {
+                info!(
+                    db_upkeep_logger,
+                    "UpkeepService::Cleaning cardano transactions database"
+                );
+                SqliteCleaner::new(cardano_tx_db_connection)
+                    .with_logger(db_upkeep_logger.clone())
+                    .with_tasks(&[SqliteCleaningTask::WalCheckpointTruncate])
+                    .run()?;
+            }
 
             Ok(())
         });
@@ -111,12 +134,17 @@ impl UpkeepService for AggregatorUpkeepService {
 
 #[cfg(test)]
 mod tests {
-    use mithril_common::entities::SignedEntityTypeDiscriminants;
+    use mithril_common::chain_observer::FakeObserver;
+    use mithril_common::digesters::DumbImmutableFileObserver;
+    use mithril_common::entities::{ChainPoint, SignedEntityTypeDiscriminants, TimePoint};
     use mithril_common::test_utils::TempDir;
+    use mithril_common::MithrilTickerService;
+    use mithril_persistence::sqlite::ConnectionExtensions;
 
+    use crate::configuration::RetentionPoliciesConfiguration;
     use crate::database::test_helper::{
-        cardano_tx_db_connection, cardano_tx_db_file_connection, main_db_connection,
-        main_db_file_connection,
+        cardano_tx_db_connection, cardano_tx_db_file_connection, insert_stake_pool,
+        main_db_connection, main_db_file_connection,
     };
     use crate::test_tools::TestLogger;
 
@@ -140,10 +168,11 @@ mod tests {
         {
             let service = AggregatorUpkeepService::new(
                 Arc::new(main_db_connection),
-                Arc::new(SqliteConnectionPool::build_from_connection(
+                Arc::new(DatabaseConnectionPool::build_from_connection(
                     cardano_tx_connection,
                 )),
                 Arc::new(SignedEntityTypeLock::default()),
+                None,
                 TestLogger::file(&log_path),
             );
 
@@ -183,8 +212,9 @@ mod tests {
         {
             let service = AggregatorUpkeepService::new(
                 Arc::new(main_db_connection().unwrap()),
-                Arc::new(SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap()),
+                Arc::new(DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap()),
                 signed_entity_type_lock.clone(),
+                None,
                 TestLogger::file(&log_path),
             );
             service.run().await.expect("Upkeep service failed");
@@ -203,4 +233,41 @@ mod tests {
             0,
         );
     }
+
+    #[tokio::test]
+    async fn test_enforce_stores_retention_policies() {
+        let main_db_connection = Arc::new(main_db_connection().unwrap());
+        insert_stake_pool(&main_db_connection, &[1, 2, 3, 4, 5, 6]).unwrap();
+        let store_retention_pruner = StoreRetentionPruner::new(
+            main_db_connection.clone(),
+            None,
+            Arc::new(MithrilTickerService::new(
+                Arc::new(FakeObserver::new(Some(TimePoint::new(
+                    6,
+                    1,
+                    ChainPoint::dummy(),
+                )))),
+                Arc::new(DumbImmutableFileObserver::default()),
+            )),
+            RetentionPoliciesConfiguration {
+                stake_pool_epochs: Some(3),
+                ..RetentionPoliciesConfiguration::default()
+            },
+            TestLogger::stdout(),
+        );
+
+        let service = AggregatorUpkeepService::new(
+            main_db_connection.clone(),
+            Arc::new(DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap()),
+            Arc::new(SignedEntityTypeLock::default()),
+            Some(Arc::new(store_retention_pruner)),
+            TestLogger::stdout(),
+        );
+        service.run().await.expect("Upkeep service failed");
+
+        let remaining_stake_pools: i64 = main_db_connection
+            .query_single_cell("select count(*) from stake_pool", &[])
+            .unwrap();
+        assert_eq!(12, remaining_stake_pools);
+    }
 }
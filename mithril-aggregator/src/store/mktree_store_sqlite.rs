@@ -0,0 +1,392 @@
+use std::{iter::repeat_n, path::Path, sync::Arc};
+
+use anyhow::{anyhow, Context};
+use mithril_common::{
+    crypto_helper::{Bytes, MKTreeLeafIndexer, MKTreeLeafPosition, MKTreeNode, MKTreeStorer},
+    StdResult,
+};
+
+use crate::services::ProverCacheStore;
+
+/// A Merkle tree store with Sqlite backend
+/// * This store is used to store the Merkle tree nodes and the positions of its leaves in a Sqlite database.
+/// * A store created with [MKTreeStorer::build] is kept in memory, whereas a store opened with
+///   [MKTreeStoreSqlite::open] is persisted on disk and can be reopened after a restart of the aggregator.
+/// * Clones of the store share the same underlying database connection.
+#[derive(Clone)]
+pub struct MKTreeStoreSqlite {
+    inner_store: Arc<sqlite::ConnectionThreadSafe>,
+}
+
+impl MKTreeStoreSqlite {
+    /// Open (or create if it does not exist) a store persisted in the given Sqlite file
+    pub fn open(path: &Path) -> StdResult<Self> {
+        Ok(Self {
+            inner_store: Arc::new(Self::create_connection(path).with_context(|| {
+                format!(
+                    "MKTreeStoreSqlite failed to open database at path '{}'",
+                    path.display()
+                )
+            })?),
+        })
+    }
+
+    fn create_connection<T: AsRef<Path>>(path: T) -> StdResult<sqlite::ConnectionThreadSafe> {
+        let connection = sqlite::Connection::open_thread_safe(path)?;
+        connection.execute("pragma journal_mode = wal; pragma synchronous = normal;")?;
+        connection.execute(
+            "create table if not exists merkle_tree (
+                position integer,
+                element blob,
+                primary key (position)
+            );
+            create table if not exists merkle_tree_leaf (
+                position integer,
+                element blob not null unique,
+                primary key (position)
+            );",
+        )?;
+
+        Ok(connection)
+    }
+
+    /// Truncate the store so that it only contains the first `total_leaves` leaves of the
+    /// Merkle tree, and the nodes computed from them.
+    ///
+    /// The store can only be truncated when no clone of it is alive, otherwise the Merkle trees
+    /// built on top of the clones would be left with dangling nodes.
+    pub fn truncate(&self, total_leaves: usize) -> StdResult<()> {
+        let total_references = Arc::strong_count(&self.inner_store);
+        if total_references > 1 {
+            return Err(anyhow!(
+                "MKTreeStoreSqlite can not be truncated while it is still referenced by {} other storer(s)",
+                total_references - 1
+            ));
+        }
+
+        let query = "SELECT position FROM merkle_tree_leaf ORDER BY position LIMIT 1 OFFSET ?";
+        let mut statement = self.inner_store.prepare(query)?;
+        statement.bind((1, total_leaves as i64))?;
+        if let sqlite::State::Row = statement.next()? {
+            // The first leaf to remove was appended at the position equal to the size of
+            // the Merkle tree computed with the `total_leaves` first leaves.
+            let position = statement.read::<i64, _>("position")?;
+            for table in ["merkle_tree", "merkle_tree_leaf"] {
+                let query = format!("DELETE FROM {table} WHERE position >= ?");
+                let mut statement = self.inner_store.prepare(query)?;
+                statement.bind((1, position))?;
+                statement.next()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_element_at_position(&self, position: u64) -> StdResult<Option<Arc<MKTreeNode>>> {
+        let query = "SELECT element FROM merkle_tree WHERE position = ?";
+        let mut statement = self.inner_store.prepare(query)?;
+        statement.bind((1, position as i64))?;
+        let result = if let sqlite::State::Row = statement.next()? {
+            Some(Arc::new(MKTreeNode::new(
+                statement.read::<Bytes, _>("element")?,
+            )))
+        } else {
+            None
+        };
+
+        Ok(result)
+    }
+
+    fn insert_elements_from_position(
+        &self,
+        position: u64,
+        elements: Vec<Arc<MKTreeNode>>,
+    ) -> StdResult<()> {
+        let values_columns: Vec<&str> = repeat_n("(?, ?)", elements.len()).collect();
+        let values: Vec<sqlite::Value> = elements
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, elem)| {
+                vec![
+                    sqlite::Value::Integer((position + i as u64) as i64),
+                    sqlite::Value::Binary((**elem).to_vec()),
+                ]
+            })
+            .collect();
+        let query = format!(
+            "INSERT INTO merkle_tree(position, element) VALUES {}",
+            values_columns.join(", ")
+        );
+        let mut statement = self.inner_store.prepare(query)?;
+        statement.bind::<&[(_, sqlite::Value)]>(
+            values
+                .into_iter()
+                .enumerate()
+                .map(|(i, v)| (i + 1, v))
+                .collect::<Vec<_>>()
+                .as_slice(),
+        )?;
+        statement.next()?;
+
+        Ok(())
+    }
+
+    fn insert_leaf_at_position(&self, position: u64, leaf: Arc<MKTreeNode>) -> StdResult<()> {
+        let query = "INSERT OR REPLACE INTO merkle_tree_leaf(position, element) VALUES (?, ?)";
+        let mut statement = self.inner_store.prepare(query)?;
+        statement.bind((1, position as i64))?;
+        statement.bind((2, (*leaf).to_vec().as_slice()))?;
+        statement.next()?;
+
+        Ok(())
+    }
+
+    fn get_leaf_position_of_element(&self, leaf: &MKTreeNode) -> StdResult<Option<u64>> {
+        let query = "SELECT position FROM merkle_tree_leaf WHERE element = ?";
+        let mut statement = self.inner_store.prepare(query)?;
+        statement.bind((1, leaf.to_vec().as_slice()))?;
+        let result = if let sqlite::State::Row = statement.next()? {
+            Some(statement.read::<i64, _>("position")? as u64)
+        } else {
+            None
+        };
+
+        Ok(result)
+    }
+
+    fn count_leaves(&self) -> StdResult<usize> {
+        let query = "SELECT count(*) AS total FROM merkle_tree_leaf";
+        let mut statement = self.inner_store.prepare(query)?;
+        statement.next()?;
+
+        Ok(statement.read::<i64, _>("total")? as usize)
+    }
+
+    fn get_all_leaves(&self) -> StdResult<Vec<MKTreeNode>> {
+        let query = "SELECT element FROM merkle_tree_leaf ORDER BY position";
+        let mut statement = self.inner_store.prepare(query)?;
+        let mut leaves = vec![];
+        while let sqlite::State::Row = statement.next()? {
+            leaves.push(MKTreeNode::new(statement.read::<Bytes, _>("element")?));
+        }
+
+        Ok(leaves)
+    }
+}
+
+impl MKTreeLeafIndexer for MKTreeStoreSqlite {
+    fn set_leaf_position(&self, pos: MKTreeLeafPosition, node: Arc<MKTreeNode>) -> StdResult<()> {
+        self.insert_leaf_at_position(pos, node)
+            .with_context(|| format!("MKTreeStoreSqlite failed to insert leaf at position {pos}"))
+    }
+
+    fn get_leaf_position(&self, node: &MKTreeNode) -> Option<MKTreeLeafPosition> {
+        // The infallible variant treats a failure as a missing leaf, callers that need to tell
+        // them apart must use `try_get_leaf_position`
+        self.try_get_leaf_position(node).ok().flatten()
+    }
+
+    fn total_leaves(&self) -> usize {
+        // The infallible variant treats a failure as an empty tree, callers that need to tell
+        // them apart must use `try_total_leaves`
+        self.try_total_leaves().unwrap_or_default()
+    }
+
+    fn leaves(&self) -> Vec<MKTreeNode> {
+        // The infallible variant treats a failure as an empty tree, callers that need to tell
+        // them apart must use `try_leaves`
+        self.try_leaves().unwrap_or_default()
+    }
+
+    fn try_get_leaf_position(&self, node: &MKTreeNode) -> StdResult<Option<MKTreeLeafPosition>> {
+        self.get_leaf_position_of_element(node)
+            .with_context(|| "MKTreeStoreSqlite failed to retrieve leaf position")
+    }
+
+    fn try_total_leaves(&self) -> StdResult<usize> {
+        self.count_leaves()
+            .with_context(|| "MKTreeStoreSqlite failed to count leaves")
+    }
+
+    fn try_leaves(&self) -> StdResult<Vec<MKTreeNode>> {
+        self.get_all_leaves()
+            .with_context(|| "MKTreeStoreSqlite failed to retrieve leaves")
+    }
+}
+
+impl MKTreeStorer for MKTreeStoreSqlite {
+    fn build() -> StdResult<Self> {
+        Ok(Self {
+            inner_store: Arc::new(Self::create_connection(":memory:")?),
+        })
+    }
+
+    fn get_elem(&self, pos: u64) -> StdResult<Option<Arc<MKTreeNode>>> {
+        self.get_element_at_position(pos).with_context(|| {
+            format!("MKTreeStoreSqlite failed to retrieve element at position {pos}")
+        })
+    }
+
+    fn append(&self, pos: u64, elems: Vec<Arc<MKTreeNode>>) -> StdResult<()> {
+        self.insert_elements_from_position(pos, elems)
+            .with_context(|| {
+                format!("MKTreeStoreSqlite failed to insert elements from position {pos}")
+            })
+    }
+}
+
+impl ProverCacheStore<MKTreeStoreSqlite> for MKTreeStoreSqlite {
+    fn get_storer(&self) -> StdResult<MKTreeStoreSqlite> {
+        Ok(self.clone())
+    }
+
+    fn truncate(&self, total_leaves: usize) -> StdResult<()> {
+        self.truncate(total_leaves).with_context(|| {
+            format!("MKTreeStoreSqlite failed to truncate to {total_leaves} leaves")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::{crypto_helper::MKTree, test_utils::TempDir};
+
+    use super::*;
+
+    fn generate_leaves(total_leaves: usize) -> Vec<MKTreeNode> {
+        (0..total_leaves)
+            .map(|i| format!("test-{i}").into())
+            .collect()
+    }
+
+    #[test]
+    fn test_golden_merkle_root() {
+        let leaves = vec!["golden-1", "golden-2", "golden-3", "golden-4", "golden-5"];
+        let mktree =
+            MKTree::<MKTreeStoreSqlite>::new(&leaves).expect("MKTree creation should not fail");
+        let mkroot = mktree
+            .compute_root()
+            .expect("MKRoot generation should not fail");
+
+        assert_eq!(
+            "3bbced153528697ecde7345a22e50115306478353619411523e804f2323fd921",
+            mkroot.to_hex()
+        );
+    }
+
+    #[test]
+    fn should_compute_proof_with_leaves_indexed_in_store() {
+        let leaves = generate_leaves(10);
+        let mktree = MKTree::<MKTreeStoreSqlite>::new(&leaves).unwrap();
+
+        assert_eq!(leaves.len(), mktree.total_leaves());
+        assert_eq!(leaves, mktree.leaves());
+        assert!(mktree.contains(&leaves[4]));
+        mktree
+            .compute_proof(&leaves[2..6])
+            .unwrap()
+            .verify()
+            .unwrap();
+    }
+
+    #[test]
+    fn should_reopen_persisted_merkle_tree_and_extend_it() {
+        let path = TempDir::create("mktree_store_sqlite", "should_reopen_persisted_merkle_tree")
+            .join("mktree.sqlite3");
+        let leaves = generate_leaves(20);
+        {
+            let store = MKTreeStoreSqlite::open(&path).unwrap();
+            let mut mktree = MKTree::new_from_storer(store).unwrap();
+            mktree.append(&leaves[0..12]).unwrap();
+        }
+
+        let store = MKTreeStoreSqlite::open(&path).unwrap();
+        let mut mktree = MKTree::new_from_storer(store).unwrap();
+        assert_eq!(12, mktree.total_leaves());
+        mktree.append(&leaves[12..]).unwrap();
+
+        let mktree_expected = MKTree::<MKTreeStoreSqlite>::new(&leaves).unwrap();
+        assert_eq!(
+            mktree_expected.compute_root().unwrap(),
+            mktree.compute_root().unwrap()
+        );
+        mktree
+            .compute_proof(&leaves[10..14])
+            .unwrap()
+            .verify()
+            .unwrap();
+    }
+
+    #[test]
+    fn should_truncate_persisted_merkle_tree() {
+        let leaves = generate_leaves(20);
+        let store = MKTreeStoreSqlite::build().unwrap();
+        {
+            let mut mktree = MKTree::new_from_storer(store.clone()).unwrap();
+            mktree.append(&leaves).unwrap();
+        }
+
+        store.truncate(7).unwrap();
+        let mut mktree = MKTree::new_from_storer(store.clone()).unwrap();
+        assert_eq!(7, mktree.total_leaves());
+        assert_eq!(
+            MKTree::<MKTreeStoreSqlite>::new(&leaves[0..7])
+                .unwrap()
+                .compute_root()
+                .unwrap(),
+            mktree.compute_root().unwrap()
+        );
+
+        mktree.append(&leaves[7..]).unwrap();
+        assert_eq!(
+            MKTree::<MKTreeStoreSqlite>::new(&leaves)
+                .unwrap()
+                .compute_root()
+                .unwrap(),
+            mktree.compute_root().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_not_truncate_store_still_referenced_by_a_merkle_tree() {
+        let leaves = generate_leaves(10);
+        let store = MKTreeStoreSqlite::build().unwrap();
+        let mut mktree = MKTree::new_from_storer(store.clone()).unwrap();
+        mktree.append(&leaves).unwrap();
+
+        store
+            .truncate(5)
+            .expect_err("Truncating a store still referenced by a Merkle tree should fail");
+        assert_eq!(10, mktree.total_leaves());
+
+        drop(mktree);
+        store.truncate(5).unwrap();
+        assert_eq!(5, store.total_leaves());
+    }
+
+    #[test]
+    fn should_propagate_database_errors_in_fallible_leaf_indexer_variants() {
+        let leaves = generate_leaves(5);
+        let store = MKTreeStoreSqlite::build().unwrap();
+        {
+            let mut mktree = MKTree::new_from_storer(store.clone()).unwrap();
+            mktree.append(&leaves).unwrap();
+        }
+        store
+            .inner_store
+            .execute("drop table merkle_tree_leaf")
+            .unwrap();
+
+        store
+            .try_total_leaves()
+            .expect_err("try_total_leaves should fail");
+        store.try_leaves().expect_err("try_leaves should fail");
+        store
+            .try_get_leaf_position(&leaves[0])
+            .expect_err("try_get_leaf_position should fail");
+        assert!(
+            MKTree::new_from_storer(store).is_err(),
+            "MKTree restoration should fail"
+        );
+    }
+}
@@ -1,14 +1,22 @@
 mod epoch_settings_storer;
+mod mktree_store_sqlite;
 mod pending_certificate_store;
+mod protocol_parameters_schedule_provider;
 mod verification_key_store;
 
 pub use epoch_settings_storer::EpochSettingsStorer;
+pub use mktree_store_sqlite::MKTreeStoreSqlite;
 pub use pending_certificate_store::CertificatePendingStore;
+pub use protocol_parameters_schedule_provider::{
+    FileProtocolParametersScheduleProvider, ProtocolParametersScheduleProvider,
+};
 pub use verification_key_store::{VerificationKeyStore, VerificationKeyStorer};
 
 #[cfg(test)]
 pub use epoch_settings_storer::FakeEpochSettingsStorer;
 #[cfg(test)]
+pub use protocol_parameters_schedule_provider::MockProtocolParametersScheduleProvider;
+#[cfg(test)]
 pub use verification_key_store::test_suite as verification_key_store_test_suite;
 #[cfg(test)]
 pub(crate) use verification_key_store::test_verification_key_storer;
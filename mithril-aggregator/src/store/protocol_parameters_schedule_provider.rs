@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use slog::{error, Logger};
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+
+use mithril_common::StdResult;
+
+use crate::entities::ProtocolParametersSchedule;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Provide the [protocol parameters schedule][ProtocolParametersSchedule] to apply when
+/// computing the settings of the future epochs.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ProtocolParametersScheduleProvider: Sync + Send {
+    /// Get the current protocol parameters schedule
+    async fn get_schedule(&self) -> StdResult<ProtocolParametersSchedule>;
+}
+
+/// A [ProtocolParametersScheduleProvider] that reads the schedule from a JSON file.
+///
+/// The file is read each time the schedule is requested, so that it can be updated without
+/// restarting the aggregator. If the file can not be read or holds an invalid schedule, the error
+/// is logged and the last valid schedule is used.
+pub struct FileProtocolParametersScheduleProvider {
+    path: PathBuf,
+    last_valid_schedule: RwLock<ProtocolParametersSchedule>,
+    logger: Logger,
+}
+
+impl FileProtocolParametersScheduleProvider {
+    /// Create a new instance, loading the schedule from the file
+    ///
+    /// Fails if the file can not be read or holds an invalid schedule.
+    pub fn try_new(path: PathBuf, logger: Logger) -> StdResult<Self> {
+        let schedule = ProtocolParametersSchedule::from_file(&path)?;
+
+        Ok(Self {
+            path,
+            last_valid_schedule: RwLock::new(schedule),
+            logger,
+        })
+    }
+}
+
+#[async_trait]
+impl ProtocolParametersScheduleProvider for FileProtocolParametersScheduleProvider {
+    async fn get_schedule(&self) -> StdResult<ProtocolParametersSchedule> {
+        match ProtocolParametersSchedule::from_file(&self.path) {
+            Ok(schedule) => {
+                *self.last_valid_schedule.write().await = schedule.clone();
+                Ok(schedule)
+            }
+            Err(error) => {
+                error!(
+                    self.logger,
+                    "Could not reload the protocol parameters schedule, the last valid one is used";
+                    "path" => self.path.display(),
+                    "error" => ?error
+                );
+                Ok(self.last_valid_schedule.read().await.clone())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ProtocolParametersScheduleProvider for ProtocolParametersSchedule {
+    async fn get_schedule(&self) -> StdResult<ProtocolParametersSchedule> {
+        Ok(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{Epoch, ProtocolParameters};
+    use mithril_common::test_utils::TempDir;
+
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    fn schedule_path(test_name: &str) -> PathBuf {
+        TempDir::create("protocol_parameters_schedule_provider", test_name).join("schedule.json")
+    }
+
+    #[tokio::test]
+    async fn file_provider_reads_the_up_to_date_schedule() {
+        let path = schedule_path("file_provider_reads_the_up_to_date_schedule");
+        std::fs::write(
+            &path,
+            r#"[{ "from_epoch": 10, "protocol_parameters": { "k": 1, "m": 100, "phi_f": 0.1 } }]"#,
+        )
+        .unwrap();
+        let provider =
+            FileProtocolParametersScheduleProvider::try_new(path.clone(), TestLogger::stdout())
+                .unwrap();
+
+        let schedule = provider.get_schedule().await.unwrap();
+        assert_eq!(
+            Some(&ProtocolParameters::new(1, 100, 0.1)),
+            schedule.protocol_parameters_at(Epoch(12))
+        );
+
+        std::fs::write(
+            &path,
+            r#"[
+                { "from_epoch": 10, "protocol_parameters": { "k": 1, "m": 100, "phi_f": 0.1 } },
+                { "from_epoch": 12, "protocol_parameters": { "k": 2, "m": 200, "phi_f": 0.2 } }
+            ]"#,
+        )
+        .unwrap();
+        let schedule = provider.get_schedule().await.unwrap();
+        assert_eq!(
+            Some(&ProtocolParameters::new(2, 200, 0.2)),
+            schedule.protocol_parameters_at(Epoch(12))
+        );
+    }
+
+    #[test]
+    fn file_provider_creation_fails_with_an_invalid_schedule() {
+        let path = schedule_path("file_provider_creation_fails_with_an_invalid_schedule");
+        std::fs::write(
+            &path,
+            r#"[{ "from_epoch": 10, "protocol_parameters": { "k": 0, "m": 100, "phi_f": 0.1 } }]"#,
+        )
+        .unwrap();
+
+        let result = FileProtocolParametersScheduleProvider::try_new(path, TestLogger::stdout());
+
+        assert!(
+            result.is_err(),
+            "Creating a provider with an invalid schedule should fail"
+        );
+    }
+
+    #[tokio::test]
+    async fn file_provider_keeps_the_last_valid_schedule_if_the_file_becomes_invalid() {
+        let path = schedule_path(
+            "file_provider_keeps_the_last_valid_schedule_if_the_file_becomes_invalid",
+        );
+        std::fs::write(
+            &path,
+            r#"[{ "from_epoch": 10, "protocol_parameters": { "k": 1, "m": 100, "phi_f": 0.1 } }]"#,
+        )
+        .unwrap();
+        let provider =
+            FileProtocolParametersScheduleProvider::try_new(path.clone(), TestLogger::stdout())
+                .unwrap();
+        let last_valid_schedule = provider.get_schedule().await.unwrap();
+
+        std::fs::write(
+            &path,
+            r#"[{ "from_epoch": 12, "protocol_parameters": { "k": 2, "m": 1, "phi_f": 0.2 } }]"#,
+        )
+        .unwrap();
+        assert_eq!(last_valid_schedule, provider.get_schedule().await.unwrap());
+
+        std::fs::write(&path, "not a schedule").unwrap();
+        assert_eq!(last_valid_schedule, provider.get_schedule().await.unwrap());
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(last_valid_schedule, provider.get_schedule().await.unwrap());
+    }
+}
@@ -194,27 +194,29 @@ mod test {
         Epoch, ImmutableFileNumber, SignedEntityConfig, SignedEntityType,
         SignedEntityTypeDiscriminants as Type, TimePoint,
     };
-    use mithril_persistence::sqlite::{ConnectionBuilder, ConnectionOptions, SqliteConnection};
+    use mithril_persistence::sqlite::{ConnectionBuilder, ConnectionOptions, DatabaseConnection};
 
     use crate::database::record::{CertificateRecord, SignedEntityRecord};
     use crate::database::repository::SignedEntityStore;
 
     use super::*;
 
-    fn connection_with_foreign_key_support() -> SqliteConnection {
+    fn connection_with_foreign_key_support() -> DatabaseConnection {
         ConnectionBuilder::open_memory()
             .with_migrations(crate::database::migration::get_migrations())
             .with_options(&[ConnectionOptions::EnableForeignKeys])
             .build()
             .unwrap()
+            .into()
     }
 
-    fn connection_without_foreign_key_support() -> SqliteConnection {
+    fn connection_without_foreign_key_support() -> DatabaseConnection {
         ConnectionBuilder::open_memory()
             .with_migrations(crate::database::migration::get_migrations())
             .with_options(&[ConnectionOptions::ForceDisableForeignKeys])
             .build()
             .unwrap()
+            .into()
     }
 
     /// Note: If we want to create CardanoTransaction test certificate then another method
@@ -270,6 +272,9 @@ mod test {
                     SignedEntityType::CardanoStakeDistribution(epoch) => {
                         format!("cardano-stake-distribution-{epoch}")
                     }
+                    SignedEntityType::CardanoDRepStakeDistribution(epoch) => {
+                        format!("cardano-drep-stake-distribution-{epoch}")
+                    }
                     SignedEntityType::CardanoImmutableFilesFull(beacon) => {
                         format!("snapshot-{}-{}", beacon.epoch, beacon.immutable_file_number)
                     }
@@ -292,7 +297,7 @@ mod test {
     }
 
     async fn fill_certificates_and_signed_entities_in_db(
-        connection: Arc<SqliteConnection>,
+        connection: Arc<DatabaseConnection>,
         certificates: &[Certificate],
     ) -> StdResult<Vec<(Certificate, Option<SignedEntityRecord>)>> {
         let certificate_repository: CertificateRepository =
@@ -402,7 +407,7 @@ mod test {
     }
 
     async fn get_certificates_and_signed_entities(
-        connection: Arc<SqliteConnection>,
+        connection: Arc<DatabaseConnection>,
     ) -> StdResult<Vec<(Certificate, Option<SignedEntityRecord>)>> {
         let mut result = vec![];
         let certificate_repository: CertificateRepository =
@@ -429,7 +434,7 @@ mod test {
     }
 
     async fn run_migration_test(
-        sqlite_connection: Arc<SqliteConnection>,
+        sqlite_connection: Arc<DatabaseConnection>,
         certificates: Vec<Certificate>,
     ) {
         // Arrange
@@ -0,0 +1,561 @@
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use slog_scope::{debug, info, warn};
+use sqlite::{Connection, OpenFlags};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use mithril_common::StdResult;
+use mithril_persistence::database::{
+    ApplicationNodeType, DatabaseVersionChecker, DbVersion, GetDatabaseVersionQuery, SqlMigration,
+};
+use mithril_persistence::sqlite::{ConnectionExtensions, SqliteBackup, SqliteConnection};
+
+use crate::dependency_injection::{
+    SQLITE_FILE, SQLITE_FILE_CARDANO_TRANSACTION, SQLITE_MONITORING_FILE,
+};
+
+/// Name of the file describing the content of a backup archive.
+const BACKUP_MANIFEST_FILE: &str = "backup-manifest.json";
+
+/// Version of the layout of the backup archives, to increase when it changes.
+const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Name of the directory, in the data stores directory, where an archive is unpacked before
+/// its databases are swapped in.
+const RESTORE_STAGING_DIRECTORY: &str = ".restore-staging";
+
+/// Prefix of the prover cache databases, there is one per aggregator of a high availability
+/// cluster.
+const PROVER_CACHE_FILE_PREFIX: &str = "prover-cache";
+
+/// Description of the content of a backup archive.
+///
+/// The databases are copied one after the other: each one is consistent on its own but they are
+/// not snapshotted at the same time, so a database may contain data written after the copy of
+/// the previous ones.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseBackupManifest {
+    /// Version of the layout of the archive.
+    pub format_version: u32,
+
+    /// Version of the aggregator that made the backup.
+    pub aggregator_version: String,
+
+    /// Date of the backup.
+    pub created_at: DateTime<Utc>,
+
+    /// Databases included in the archive.
+    pub databases: Vec<DatabaseBackupEntry>,
+}
+
+/// A database included in a backup archive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseBackupEntry {
+    /// Name of the database file in the data stores directory.
+    pub file_name: String,
+
+    /// Version of the database structure, `None` for the databases without migrations.
+    pub db_version: Option<DbVersion>,
+}
+
+/// A database of the aggregator with the migrations that give its structure, if any.
+struct AggregatorDatabase {
+    file_name: &'static str,
+    migrations: Option<fn() -> Vec<SqlMigration>>,
+    /// The backup fails if a required database is missing, the other ones are skipped.
+    is_required: bool,
+}
+
+const AGGREGATOR_DATABASES: [AggregatorDatabase; 3] = [
+    AggregatorDatabase {
+        file_name: SQLITE_FILE,
+        migrations: Some(crate::database::migration::get_migrations),
+        is_required: true,
+    },
+    AggregatorDatabase {
+        file_name: SQLITE_FILE_CARDANO_TRANSACTION,
+        migrations: Some(
+            mithril_persistence::database::cardano_transaction_migration::get_migrations,
+        ),
+        is_required: false,
+    },
+    AggregatorDatabase {
+        file_name: SQLITE_MONITORING_FILE,
+        migrations: None,
+        is_required: false,
+    },
+];
+
+/// Tools to backup the aggregator SQLite databases while it runs and to restore them.
+///
+/// The prover cache databases are not included since they are rebuilt from the Cardano
+/// transactions, they are removed when the databases are restored.
+pub struct DatabaseBackupTools {
+    data_stores_directory: PathBuf,
+}
+
+impl DatabaseBackupTools {
+    /// [DatabaseBackupTools] factory
+    pub fn new(data_stores_directory: PathBuf) -> Self {
+        Self {
+            data_stores_directory,
+        }
+    }
+
+    /// Copy the databases of the data stores directory in a gzipped tar archive along with a
+    /// [manifest][DatabaseBackupManifest] describing them.
+    ///
+    /// Each database is a consistent snapshot even if the aggregator is writing to it, but the
+    /// databases are snapshotted one after the other and not as a whole.
+    pub fn backup(&self, archive_path: &Path) -> StdResult<DatabaseBackupManifest> {
+        let archive_name = archive_path
+            .file_name()
+            .ok_or_else(|| anyhow!("Invalid archive path: '{}'", archive_path.display()))?;
+        let staging_directory =
+            archive_path.with_file_name(format!("{}.staging", archive_name.to_string_lossy()));
+        recreate_directory(&staging_directory)?;
+
+        let result = self
+            .backup_databases(&staging_directory)
+            .and_then(|manifest| {
+                write_archive(&staging_directory, &manifest, archive_path)?;
+                Ok(manifest)
+            });
+        fs::remove_dir_all(&staging_directory).with_context(|| {
+            format!(
+                "Could not remove the backup staging directory: '{}'",
+                staging_directory.display()
+            )
+        })?;
+
+        result
+    }
+
+    fn backup_databases(&self, staging_directory: &Path) -> StdResult<DatabaseBackupManifest> {
+        let mut databases = vec![];
+
+        for database in &AGGREGATOR_DATABASES {
+            let source_path = self.data_stores_directory.join(database.file_name);
+            if !source_path.exists() && database.is_required {
+                return Err(anyhow!(
+                    "Database backup: missing database '{}', the aggregator databases must be SQLite files of the data stores directory",
+                    source_path.display()
+                ));
+            }
+            if !source_path.exists() {
+                warn!("Database backup: skipping missing database"; "file" => database.file_name);
+                continue;
+            }
+
+            info!("Database backup: copying database"; "file" => database.file_name);
+            let source_connection = Connection::open_thread_safe_with_flags(
+                &source_path,
+                OpenFlags::new().with_read_only(),
+            )
+            .with_context(|| format!("Could not open database: '{}'", source_path.display()))?;
+            let backup_path = staging_directory.join(database.file_name);
+            SqliteBackup::new(&source_connection)
+                .with_logger(slog_scope::logger())
+                .backup_to(&backup_path)
+                .with_context(|| format!("Could not backup database: '{}'", database.file_name))?;
+
+            let db_version = match database.migrations {
+                Some(_) => Some(read_db_version(&Connection::open_thread_safe(
+                    &backup_path,
+                )?)?),
+                None => None,
+            };
+            databases.push(DatabaseBackupEntry {
+                file_name: database.file_name.to_string(),
+                db_version,
+            });
+        }
+
+        Ok(DatabaseBackupManifest {
+            format_version: BACKUP_FORMAT_VERSION,
+            aggregator_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at: Utc::now(),
+            databases,
+        })
+    }
+
+    /// Replace the databases of the data stores directory by the ones of the given archive.
+    ///
+    /// The databases of the archive are checked with a [DatabaseVersionChecker], and upgraded
+    /// if they are older than this software, before any database is replaced. The prover cache
+    /// databases are removed since they don't match the restored Cardano transactions anymore.
+    ///
+    /// ⚠ The aggregator must be stopped while the databases are restored ⚠
+    pub fn restore(&self, archive_path: &Path) -> StdResult<DatabaseBackupManifest> {
+        let staging_directory = self.data_stores_directory.join(RESTORE_STAGING_DIRECTORY);
+        recreate_directory(&staging_directory)?;
+
+        let result = self
+            .unpack_and_check_archive(archive_path, &staging_directory)
+            .and_then(|manifest| {
+                self.swap_databases(&staging_directory, &manifest)?;
+                self.remove_prover_caches()?;
+                Ok(manifest)
+            });
+        fs::remove_dir_all(&staging_directory).with_context(|| {
+            format!(
+                "Could not remove the restore staging directory: '{}'",
+                staging_directory.display()
+            )
+        })?;
+
+        result
+    }
+
+    fn unpack_and_check_archive(
+        &self,
+        archive_path: &Path,
+        staging_directory: &Path,
+    ) -> StdResult<DatabaseBackupManifest> {
+        info!("Database restore: unpacking archive"; "archive" => archive_path.display());
+        let archive_file = File::open(archive_path)
+            .with_context(|| format!("Could not open archive: '{}'", archive_path.display()))?;
+        tar::Archive::new(GzDecoder::new(archive_file))
+            .unpack(staging_directory)
+            .with_context(|| format!("Could not unpack archive: '{}'", archive_path.display()))?;
+
+        let manifest_file = File::open(staging_directory.join(BACKUP_MANIFEST_FILE))
+            .with_context(|| "The archive does not contain a backup manifest")?;
+        let manifest: DatabaseBackupManifest = serde_json::from_reader(manifest_file)
+            .with_context(|| "Could not parse the backup manifest")?;
+        if manifest.format_version != BACKUP_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Unsupported backup format version '{}', expected '{BACKUP_FORMAT_VERSION}'",
+                manifest.format_version
+            ));
+        }
+
+        for entry in &manifest.databases {
+            let database = AGGREGATOR_DATABASES
+                .iter()
+                .find(|d| d.file_name == entry.file_name)
+                .ok_or_else(|| anyhow!("Unknown database in the archive: '{}'", entry.file_name))?;
+            let database_path = staging_directory.join(database.file_name);
+            if !database_path.exists() {
+                return Err(anyhow!(
+                    "Database '{}' is listed in the manifest but not in the archive",
+                    entry.file_name
+                ));
+            }
+
+            if let Some(get_migrations) = database.migrations {
+                debug!("Database restore: checking database version"; "file" => database.file_name);
+                let connection = Connection::open_thread_safe(&database_path)?;
+                let db_version = read_db_version(&connection)?;
+                if Some(db_version) != entry.db_version {
+                    return Err(anyhow!(
+                        "Database '{}' version '{db_version}' does not match the manifest version '{:?}'",
+                        entry.file_name,
+                        entry.db_version
+                    ));
+                }
+
+                let mut db_checker = DatabaseVersionChecker::new(
+                    slog_scope::logger(),
+                    ApplicationNodeType::Aggregator,
+                    &connection,
+                );
+                for migration in get_migrations() {
+                    db_checker.add_migration(migration);
+                }
+                db_checker.apply().with_context(|| {
+                    format!("Database '{}' can not be restored", entry.file_name)
+                })?;
+            }
+        }
+
+        Ok(manifest)
+    }
+
+    fn swap_databases(
+        &self,
+        staging_directory: &Path,
+        manifest: &DatabaseBackupManifest,
+    ) -> StdResult<()> {
+        for entry in &manifest.databases {
+            info!("Database restore: replacing database"; "file" => &entry.file_name);
+            let database_path = self.data_stores_directory.join(&entry.file_name);
+
+            // Leftover WAL files of the replaced database would be applied to the restored one
+            for suffix in ["-wal", "-shm"] {
+                let path = self
+                    .data_stores_directory
+                    .join(format!("{}{suffix}", entry.file_name));
+                if path.exists() {
+                    fs::remove_file(&path)
+                        .with_context(|| format!("Could not remove '{}'", path.display()))?;
+                }
+            }
+            fs::rename(staging_directory.join(&entry.file_name), &database_path)
+                .with_context(|| format!("Could not replace '{}'", database_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    fn remove_prover_caches(&self) -> StdResult<()> {
+        let entries = fs::read_dir(&self.data_stores_directory).with_context(|| {
+            format!(
+                "Could not read directory: '{}'",
+                self.data_stores_directory.display()
+            )
+        })?;
+
+        for entry in entries {
+            let path = entry?.path();
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let is_prover_cache_file = file_name.starts_with(PROVER_CACHE_FILE_PREFIX)
+                && [".sqlite3", ".sqlite3-wal", ".sqlite3-shm"]
+                    .iter()
+                    .any(|extension| file_name.ends_with(extension));
+            if path.is_file() && is_prover_cache_file {
+                info!("Database restore: removing prover cache"; "file" => &file_name);
+                fs::remove_file(&path)
+                    .with_context(|| format!("Could not remove '{}'", path.display()))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn recreate_directory(path: &Path) -> StdResult<()> {
+    if path.exists() {
+        fs::remove_dir_all(path)
+            .with_context(|| format!("Could not remove directory: '{}'", path.display()))?;
+    }
+    fs::create_dir_all(path)
+        .with_context(|| format!("Could not create directory: '{}'", path.display()))
+}
+
+fn read_db_version(connection: &SqliteConnection) -> StdResult<DbVersion> {
+    connection
+        .fetch_first(GetDatabaseVersionQuery::get_application_version(
+            &ApplicationNodeType::Aggregator,
+        ))?
+        .map(|db_version| db_version.version)
+        .ok_or_else(|| anyhow!("The database has no version"))
+}
+
+fn write_archive(
+    staging_directory: &Path,
+    manifest: &DatabaseBackupManifest,
+    archive_path: &Path,
+) -> StdResult<()> {
+    let manifest_path = staging_directory.join(BACKUP_MANIFEST_FILE);
+    fs::write(&manifest_path, serde_json::to_vec_pretty(manifest)?)
+        .with_context(|| "Could not write the backup manifest")?;
+
+    let archive_file = File::create(archive_path)
+        .with_context(|| format!("Could not create archive: '{}'", archive_path.display()))?;
+    let mut tar = tar::Builder::new(GzEncoder::new(archive_file, Compression::default()));
+    tar.append_path_with_name(&manifest_path, BACKUP_MANIFEST_FILE)?;
+    for entry in &manifest.databases {
+        tar.append_path_with_name(staging_directory.join(&entry.file_name), &entry.file_name)
+            .with_context(|| format!("Could not add '{}' to the archive", entry.file_name))?;
+    }
+    tar.into_inner()
+        .with_context(|| "Could not write the archive")?
+        .finish()
+        .with_context(|| "Could not finish the archive compression")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+    use mithril_persistence::sqlite::ConnectionBuilder;
+
+    use super::*;
+
+    fn create_aggregator_databases(data_stores_directory: &Path) {
+        fs::create_dir_all(data_stores_directory).unwrap();
+        ConnectionBuilder::open_file(&data_stores_directory.join(SQLITE_FILE))
+            .with_node_type(ApplicationNodeType::Aggregator)
+            .with_migrations(crate::database::migration::get_migrations())
+            .build()
+            .unwrap();
+        ConnectionBuilder::open_file(&data_stores_directory.join(SQLITE_FILE_CARDANO_TRANSACTION))
+            .with_node_type(ApplicationNodeType::Aggregator)
+            .with_migrations(
+                mithril_persistence::database::cardano_transaction_migration::get_migrations(),
+            )
+            .build()
+            .unwrap();
+    }
+
+    fn table_exists(database_path: &Path, table: &str) -> bool {
+        let connection = Connection::open_thread_safe(database_path).unwrap();
+        let count: i64 = connection
+            .query_single_cell(
+                "select count(*) from sqlite_master where type = 'table' and name = ?",
+                &[sqlite::Value::String(table.to_string())],
+            )
+            .unwrap();
+        count > 0
+    }
+
+    #[test]
+    fn backup_skips_missing_databases_and_records_the_versions_in_the_manifest() {
+        let dir = TempDir::create("database_backup", "backup_skips_missing_databases");
+        create_aggregator_databases(&dir.join("stores"));
+
+        let manifest = DatabaseBackupTools::new(dir.join("stores"))
+            .backup(&dir.join("backup.tar.gz"))
+            .unwrap();
+
+        let max_version =
+            |migrations: Vec<SqlMigration>| migrations.iter().map(|m| m.version).max();
+        assert_eq!(
+            vec![
+                DatabaseBackupEntry {
+                    file_name: SQLITE_FILE.to_string(),
+                    db_version: max_version(crate::database::migration::get_migrations()),
+                },
+                DatabaseBackupEntry {
+                    file_name: SQLITE_FILE_CARDANO_TRANSACTION.to_string(),
+                    db_version: max_version(
+                        mithril_persistence::database::cardano_transaction_migration::get_migrations()
+                    ),
+                },
+            ],
+            manifest.databases
+        );
+        assert!(dir.join("backup.tar.gz").exists());
+        assert!(!dir.join("backup.tar.gz.staging").exists());
+    }
+
+    #[test]
+    fn backup_fails_if_the_main_database_is_missing() {
+        let dir = TempDir::create("database_backup", "backup_fails_if_main_database_missing");
+        fs::create_dir_all(dir.join("stores")).unwrap();
+
+        let error = DatabaseBackupTools::new(dir.join("stores"))
+            .backup(&dir.join("backup.tar.gz"))
+            .expect_err("backup should fail without the main database");
+
+        assert!(
+            error.to_string().contains(SQLITE_FILE),
+            "unexpected error: {error:?}"
+        );
+        assert!(!dir.join("backup.tar.gz").exists());
+        assert!(!dir.join("backup.tar.gz.staging").exists());
+    }
+
+    #[test]
+    fn restore_replaces_the_databases_by_the_ones_of_the_backup() {
+        let dir = TempDir::create("database_backup", "restore_replaces_the_databases");
+        let stores_directory = dir.join("stores");
+        create_aggregator_databases(&stores_directory);
+        let tools = DatabaseBackupTools::new(stores_directory.clone());
+        let backup_manifest = tools.backup(&dir.join("backup.tar.gz")).unwrap();
+
+        Connection::open_thread_safe(stores_directory.join(SQLITE_FILE))
+            .unwrap()
+            .execute("create table created_after_backup (id integer)")
+            .unwrap();
+        let restored_manifest = tools.restore(&dir.join("backup.tar.gz")).unwrap();
+
+        assert_eq!(backup_manifest, restored_manifest);
+        assert!(!table_exists(
+            &stores_directory.join(SQLITE_FILE),
+            "created_after_backup"
+        ));
+        assert!(table_exists(
+            &stores_directory.join(SQLITE_FILE),
+            "db_version"
+        ));
+        assert!(!stores_directory.join(RESTORE_STAGING_DIRECTORY).exists());
+    }
+
+    #[test]
+    fn restore_removes_the_prover_caches() {
+        let dir = TempDir::create("database_backup", "restore_removes_the_prover_caches");
+        let stores_directory = dir.join("stores");
+        create_aggregator_databases(&stores_directory);
+        let tools = DatabaseBackupTools::new(stores_directory.clone());
+        tools.backup(&dir.join("backup.tar.gz")).unwrap();
+        let prover_cache_files = [
+            "prover-cache.sqlite3",
+            "prover-cache.sqlite3-wal",
+            "prover-cache.sqlite3-shm",
+            "prover-cache-node-1.sqlite3",
+            "prover-cache-node-1.sqlite3-wal",
+        ];
+        for file_name in prover_cache_files {
+            fs::write(stores_directory.join(file_name), "cache").unwrap();
+        }
+        fs::write(stores_directory.join("prover-cache.txt"), "not a cache").unwrap();
+
+        tools.restore(&dir.join("backup.tar.gz")).unwrap();
+
+        for file_name in prover_cache_files {
+            assert!(
+                !stores_directory.join(file_name).exists(),
+                "'{file_name}' should have been removed"
+            );
+        }
+        assert!(stores_directory.join("prover-cache.txt").exists());
+        assert!(stores_directory.join(SQLITE_FILE).exists());
+    }
+
+    #[test]
+    fn restore_fails_without_replacing_anything_if_a_database_is_newer_than_the_software() {
+        let dir = TempDir::create("database_backup", "restore_fails_if_database_is_newer");
+        let stores_directory = dir.join("stores");
+        create_aggregator_databases(&stores_directory);
+        let tools = DatabaseBackupTools::new(stores_directory.clone());
+        Connection::open_thread_safe(stores_directory.join(SQLITE_FILE_CARDANO_TRANSACTION))
+            .unwrap()
+            .execute("update db_version set version = 9999")
+            .unwrap();
+        tools.backup(&dir.join("backup.tar.gz")).unwrap();
+
+        Connection::open_thread_safe(stores_directory.join(SQLITE_FILE))
+            .unwrap()
+            .execute("create table created_after_backup (id integer)")
+            .unwrap();
+        tools
+            .restore(&dir.join("backup.tar.gz"))
+            .expect_err("Restoring a database newer than the software should fail");
+
+        assert!(table_exists(
+            &stores_directory.join(SQLITE_FILE),
+            "created_after_backup"
+        ));
+        assert!(!stores_directory.join(RESTORE_STAGING_DIRECTORY).exists());
+    }
+
+    #[test]
+    fn restore_fails_if_the_archive_has_no_manifest() {
+        let dir = TempDir::create("database_backup", "restore_fails_without_manifest");
+        let archive_path = dir.join("invalid.tar.gz");
+        let mut tar = tar::Builder::new(GzEncoder::new(
+            File::create(&archive_path).unwrap(),
+            Compression::default(),
+        ));
+        let content = b"not a database";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_cksum();
+        tar.append_data(&mut header, SQLITE_FILE, &content[..])
+            .unwrap();
+        tar.into_inner().unwrap().finish().unwrap();
+
+        DatabaseBackupTools::new(dir.join("stores"))
+            .restore(&archive_path)
+            .expect_err("Restoring an archive without manifest should fail");
+    }
+}
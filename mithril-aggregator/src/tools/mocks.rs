@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use mithril_common::chain_observer::{ChainAddress, ChainObserver, ChainObserverError, TxDatum};
 use mithril_common::crypto_helper::{KESPeriod, OpCert};
-use mithril_common::entities::{ChainPoint, Epoch, StakeDistribution};
+use mithril_common::entities::{ChainPoint, DRepStakeDistribution, Epoch, StakeDistribution};
 use mockall::mock;
 
 mock! {
@@ -22,6 +22,10 @@ mock! {
             &self,
         ) -> Result<Option<StakeDistribution>, ChainObserverError>;
 
+        async fn get_current_drep_stake_distribution(
+            &self,
+        ) -> Result<Option<DRepStakeDistribution>, ChainObserverError>;
+
         async fn get_current_kes_period(
             &self,
             opcert: &OpCert,
@@ -1,4 +1,5 @@
 mod certificates_hash_migrator;
+mod database_backup;
 mod digest_helpers;
 mod era;
 mod genesis;
@@ -9,6 +10,7 @@ mod signer_importer;
 mod single_signature_authenticator;
 
 pub use certificates_hash_migrator::CertificatesHashMigrator;
+pub use database_backup::DatabaseBackupTools;
 pub use digest_helpers::extract_digest_from_path;
 pub use era::EraTools;
 pub use genesis::{GenesisTools, GenesisToolsDependency};
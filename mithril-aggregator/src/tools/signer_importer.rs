@@ -9,6 +9,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::database::repository::SignerStore;
+use crate::services::{LeaderElectionService, StandaloneLeaderElectionService};
 
 #[cfg(test)]
 use mockall::automock;
@@ -20,6 +21,7 @@ pub type PoolTicker = String;
 pub struct SignersImporter {
     retriever: Arc<dyn SignersImporterRetriever>,
     persister: Arc<dyn SignersImporterPersister>,
+    leader_election_service: Arc<dyn LeaderElectionService>,
 }
 
 impl SignersImporter {
@@ -31,9 +33,20 @@ impl SignersImporter {
         Self {
             retriever,
             persister,
+            leader_election_service: Arc::new(StandaloneLeaderElectionService),
         }
     }
 
+    /// Only import the signers when this aggregator is the leader of its cluster, the followers
+    /// share the leader database and must not write to it.
+    pub fn with_leader_election_service(
+        mut self,
+        leader_election_service: Arc<dyn LeaderElectionService>,
+    ) -> Self {
+        self.leader_election_service = leader_election_service;
+        self
+    }
+
     /// Import and persist the signers
     pub async fn run(&self) -> StdResult<()> {
         info!("🔧 Signer Importer: starting");
@@ -48,13 +61,23 @@ impl SignersImporter {
             .with_context(|| "Failed to persist retrieved data into the database")
     }
 
-    /// Start a loop that call [run][Self::run] at the given time interval.
+    /// Import and persist the signers if this aggregator is the leader of its cluster
+    pub async fn run_if_leader(&self) -> StdResult<()> {
+        if !self.leader_election_service.is_leader() {
+            info!("🔧 Signer Importer: this aggregator is not the leader, skipping import");
+            return Ok(());
+        }
+
+        self.run().await
+    }
+
+    /// Start a loop that call [run_if_leader][Self::run_if_leader] at the given time interval.
     pub async fn run_forever(&self, run_interval: Duration) {
         let mut interval = tokio::time::interval(run_interval);
 
         loop {
             interval.tick().await;
-            if let Err(error) = self.run().await {
+            if let Err(error) = self.run_if_leader().await {
                 warn!("Signer retriever failed: Error: «{:?}».", error);
             }
             info!(
@@ -187,11 +210,12 @@ mod tests {
 
     use mithril_common::test_utils::test_http_server::test_http_server;
     use mithril_common::StdResult;
-    use mithril_persistence::sqlite::SqliteConnection;
+    use mithril_persistence::sqlite::DatabaseConnection;
 
     use crate::database::repository::{SignerGetter, SignerStore};
     use crate::database::test_helper::main_db_connection;
     use crate::http_server::routes::reply;
+    use crate::services::MockLeaderElectionService;
 
     use super::*;
 
@@ -218,7 +242,7 @@ mod tests {
     }
 
     async fn fill_signer_db(
-        connection: Arc<SqliteConnection>,
+        connection: Arc<DatabaseConnection>,
         test_signers: &[TestSigner],
     ) -> StdResult<()> {
         let store = SignerStore::new(connection);
@@ -232,7 +256,9 @@ mod tests {
         Ok(())
     }
 
-    async fn get_all_signers(connection: Arc<SqliteConnection>) -> StdResult<BTreeSet<TestSigner>> {
+    async fn get_all_signers(
+        connection: Arc<DatabaseConnection>,
+    ) -> StdResult<BTreeSet<TestSigner>> {
         let store = SignerStore::new(connection);
 
         let signers = store
@@ -461,4 +487,47 @@ mod tests {
             ])
         );
     }
+
+    #[tokio::test]
+    async fn follower_does_not_import_signers() {
+        let mut retriever = MockSignersImporterRetriever::new();
+        retriever.expect_retrieve().never();
+        let mut persister = MockSignersImporterPersister::new();
+        persister.expect_persist().never();
+        let mut leader_election_service = MockLeaderElectionService::new();
+        leader_election_service
+            .expect_is_leader()
+            .once()
+            .return_const(false);
+
+        let importer = SignersImporter::new(Arc::new(retriever), Arc::new(persister))
+            .with_leader_election_service(Arc::new(leader_election_service));
+        importer
+            .run_if_leader()
+            .await
+            .expect("running importer should not fail");
+    }
+
+    #[tokio::test]
+    async fn leader_imports_signers() {
+        let mut retriever = MockSignersImporterRetriever::new();
+        retriever
+            .expect_retrieve()
+            .once()
+            .returning(|| Ok(HashMap::from([("pool1".to_string(), None)])));
+        let mut persister = MockSignersImporterPersister::new();
+        persister.expect_persist().once().returning(|_| Ok(()));
+        let mut leader_election_service = MockLeaderElectionService::new();
+        leader_election_service
+            .expect_is_leader()
+            .once()
+            .return_const(true);
+
+        let importer = SignersImporter::new(Arc::new(retriever), Arc::new(persister))
+            .with_leader_election_service(Arc::new(leader_election_service));
+        importer
+            .run_if_leader()
+            .await
+            .expect("running importer should not fail");
+    }
 }
@@ -138,6 +138,15 @@ impl AggregatorObserver {
                     .await?
                     .first()
                     .map(|s| &s.signed_entity_type)),
+            SignedEntityType::CardanoDRepStakeDistribution(_) => {
+                Ok(Some(signed_entity_type_expected)
+                    == self
+                        .signed_entity_service
+                        .get_last_signed_cardano_drep_stake_distributions(1)
+                        .await?
+                        .first()
+                        .map(|s| &s.signed_entity_type))
+            }
         }
     }
 }
@@ -15,7 +15,7 @@ use crate::{
     configuration::ConfigParameters,
     utils::{
         CardanoDbDownloadChecker, CardanoDbUtils, ExpanderUtils, IndicatifFeedbackReceiver,
-        ProgressOutputType, ProgressPrinter,
+        LedgerStateBootstrapReport, ProgressOutputType, ProgressPrinter,
     },
 };
 use mithril_client::{
@@ -63,7 +63,7 @@ impl CardanoDbDownloadCommand {
         } else {
             ProgressOutputType::Tty
         };
-        let progress_printer = ProgressPrinter::new(progress_output_type, 5);
+        let progress_printer = ProgressPrinter::new(progress_output_type, 6);
         let client = client_builder(&params)?
             .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
                 progress_output_type,
@@ -128,7 +128,10 @@ impl CardanoDbDownloadCommand {
         )
         .await?;
 
-        Self::log_download_information(&db_dir, &cardano_db_message, self.json)?;
+        let bootstrap_report =
+            Self::check_ledger_state_bootstrap(6, &progress_printer, &db_dir, &cardano_db_message)?;
+
+        Self::log_download_information(&db_dir, &cardano_db_message, &bootstrap_report, self.json)?;
 
         Ok(())
     }
@@ -255,9 +258,37 @@ impl CardanoDbDownloadCommand {
         Ok(())
     }
 
+    fn check_ledger_state_bootstrap(
+        step_number: u16,
+        progress_printer: &ProgressPrinter,
+        db_dir: &Path,
+        cardano_db: &Snapshot,
+    ) -> MithrilResult<LedgerStateBootstrapReport> {
+        progress_printer.report_step(
+            step_number,
+            "Checking that a Cardano node can bootstrap from the cardano db…",
+        )?;
+        let report = CardanoDbDownloadChecker::check_ledger_state_bootstrap(
+            db_dir,
+            cardano_db.beacon.immutable_file_number,
+        )
+        .with_context(|| {
+            format!(
+                "Can not check the ledger state bootstrap of the cardano db in directory: '{}'",
+                db_dir.display()
+            )
+        })?;
+        for issue in &report.issues {
+            progress_printer.report_step(step_number, &format!("Warning: {issue}"))?;
+        }
+
+        Ok(report)
+    }
+
     fn log_download_information(
         db_dir: &Path,
         cardano_db: &Snapshot,
+        bootstrap_report: &LedgerStateBootstrapReport,
         json_output: bool,
     ) -> MithrilResult<()> {
         let canonicalized_filepath = &db_dir.canonicalize().with_context(|| {
@@ -269,9 +300,12 @@ impl CardanoDbDownloadCommand {
 
         if json_output {
             println!(
-                r#"{{"timestamp": "{}", "db_directory": "{}"}}"#,
-                Utc::now().to_rfc3339(),
-                canonicalized_filepath.display()
+                "{}",
+                serde_json::json!({
+                    "timestamp": Utc::now().to_rfc3339(),
+                    "db_directory": canonicalized_filepath,
+                    "ledger_state_bootstrap": bootstrap_report,
+                })
             );
         } else {
             let cardano_node_version = cardano_db
@@ -295,6 +329,16 @@ impl CardanoDbDownloadCommand {
                 cardano_db.beacon.network,
                 cardano_node_version
             );
+
+            if !bootstrap_report.can_bootstrap() {
+                println!(
+                    "Warning: a Cardano node may not be able to bootstrap from the files in the directory '{}':",
+                    db_dir.display()
+                );
+                for issue in &bootstrap_report.issues {
+                    println!("    - {issue}");
+                }
+            }
         }
 
         Ok(())
@@ -2,10 +2,12 @@
 mod download;
 mod list;
 mod show;
+mod verify;
 
 pub use download::*;
 pub use list::*;
 pub use show::*;
+pub use verify::*;
 
 use clap::Subcommand;
 use config::{builder::DefaultState, ConfigBuilder};
@@ -21,6 +23,10 @@ pub enum CardanoDbCommands {
     /// Download a Cardano db snapshot and verify its associated certificate
     #[clap(arg_required_else_help = true)]
     Download(CardanoDbDownloadCommand),
+
+    /// Verify an already restored Cardano db against a cardano db snapshot and its associated certificate
+    #[clap(arg_required_else_help = true)]
+    Verify(CardanoDbVerifyCommand),
 }
 
 /// Cardano db snapshots
@@ -41,6 +47,7 @@ impl CardanoDbCommands {
         match self {
             Self::Download(cmd) => cmd.execute(config_builder).await,
             Self::Snapshot(cmd) => cmd.execute(config_builder).await,
+            Self::Verify(cmd) => cmd.execute(config_builder).await,
         }
     }
 }
@@ -0,0 +1,268 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use config::{builder::DefaultState, ConfigBuilder, Map, Source, Value, ValueKind};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    commands::client_builder,
+    configuration::ConfigParameters,
+    utils::{ExpanderUtils, IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter},
+};
+use mithril_client::{
+    common::{HexEncodedDigest, ImmutableFileName},
+    snapshot_client::LocalDbVerificationReport,
+    MithrilResult,
+};
+
+/// Clap command to verify an already restored Cardano db against a certified cardano db
+/// snapshot, without downloading it again.
+#[derive(Parser, Debug, Clone)]
+pub struct CardanoDbVerifyCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Digest of the cardano db snapshot to verify against. Use the `snapshot list` command to
+    /// get that information.
+    ///
+    /// If `latest` is specified as digest, the command will verify against the latest cardano db.
+    digest: String,
+
+    /// Directory of the Cardano node database to verify, ie the directory that contains the
+    /// `immutable` directory.
+    #[clap(long)]
+    db_dir: PathBuf,
+
+    /// JSON file of reference digests of the immutable files, ie written with
+    /// `--dump-digests-file` from a database known to be valid, used to report which immutable
+    /// files differ.
+    #[clap(long)]
+    reference_digests_file: Option<PathBuf>,
+
+    /// JSON file where the computed digests of the immutable files will be written.
+    #[clap(long)]
+    dump_digests_file: Option<PathBuf>,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+}
+
+impl CardanoDbVerifyCommand {
+    /// Command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.add_source(self.clone()).build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let reference_digests = self
+            .reference_digests_file
+            .as_deref()
+            .map(Self::read_digests_file)
+            .transpose()?;
+
+        let progress_output_type = if self.json {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 3);
+        let client = client_builder(&params)?
+            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+            )))
+            .build()?;
+
+        let get_list_of_artifact_ids = || async {
+            let cardano_dbs = client.snapshot().list().await.with_context(|| {
+                "Can not get the list of artifacts while retrieving the latest cardano db digest"
+            })?;
+
+            Ok(cardano_dbs
+                .iter()
+                .map(|cardano_db| cardano_db.digest.to_owned())
+                .collect::<Vec<String>>())
+        };
+
+        progress_printer.report_step(1, "Fetching the cardano db snapshot…")?;
+        let cardano_db_message = client
+            .snapshot()
+            .get(
+                &ExpanderUtils::expand_eventual_id_alias(&self.digest, get_list_of_artifact_ids())
+                    .await?,
+            )
+            .await?
+            .with_context(|| format!("Can not get the cardano db for digest: '{}'", self.digest))?;
+
+        progress_printer.report_step(
+            2,
+            "Fetching the certificate and verifying the certificate chain…",
+        )?;
+        let certificate = client
+            .certificate()
+            .verify_chain(&cardano_db_message.certificate_hash)
+            .await
+            .with_context(|| {
+                format!(
+                    "Can not verify the certificate chain from certificate_hash: '{}'",
+                    &cardano_db_message.certificate_hash
+                )
+            })?;
+
+        progress_printer.report_step(
+            3,
+            "Computing the immutable files digests of the database and verifying them…",
+        )?;
+        let report = client
+            .snapshot()
+            .verify_local_db(&certificate, &self.db_dir, reference_digests.as_ref())
+            .await
+            .with_context(|| {
+                format!(
+                    "Can not verify the database in directory: '{}'",
+                    self.db_dir.display()
+                )
+            })?;
+
+        if let Some(dump_digests_file) = &self.dump_digests_file {
+            std::fs::write(
+                dump_digests_file,
+                serde_json::to_string_pretty(&report.immutable_files_digests)?,
+            )
+            .with_context(|| {
+                format!(
+                    "Can not write the immutable files digests to file: '{}'",
+                    dump_digests_file.display()
+                )
+            })?;
+        }
+
+        self.log_verification_report(&report)?;
+
+        if !report.is_valid {
+            return Err(anyhow!(
+                "The database in directory '{}' is not signed by the certificate '{}' of the cardano db '{}'",
+                self.db_dir.display(),
+                report.certificate_hash,
+                cardano_db_message.digest
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn read_digests_file(
+        digests_file: &Path,
+    ) -> MithrilResult<BTreeMap<ImmutableFileName, HexEncodedDigest>> {
+        let content = std::fs::read_to_string(digests_file).with_context(|| {
+            format!(
+                "Can not read the digests file: '{}'",
+                digests_file.display()
+            )
+        })?;
+
+        serde_json::from_str(&content).with_context(|| {
+            format!(
+                "Can not deserialize the digests file: '{}'",
+                digests_file.display()
+            )
+        })
+    }
+
+    fn log_verification_report(&self, report: &LocalDbVerificationReport) -> MithrilResult<()> {
+        if self.json {
+            println!(
+                "{}",
+                serde_json::json!({
+                    "db_dir": self.db_dir,
+                    "certificate_hash": report.certificate_hash,
+                    "immutable_file_number": report.immutable_file_number,
+                    "expected_digest": report.expected_digest,
+                    "computed_digest": report.computed_digest,
+                    "is_valid": report.is_valid,
+                    "missing_immutable_files": report.missing_immutable_files,
+                    "differing_immutable_files": report.differing_immutable_files,
+                    "dump_digests_file": self.dump_digests_file,
+                })
+            );
+        } else {
+            let status = if report.is_valid { "valid" } else { "INVALID" };
+            println!(
+                r###"Database in directory '{}' is {status} against certificate '{}' (up to immutable file number {}).
+
+Expected digest: {}
+Computed digest: {}"###,
+                self.db_dir.display(),
+                report.certificate_hash,
+                report.immutable_file_number,
+                report.expected_digest.as_deref().unwrap_or("-"),
+                report.computed_digest,
+            );
+            if !report.missing_immutable_files.is_empty() {
+                println!(
+                    "Missing immutable files numbers: {:?}",
+                    report.missing_immutable_files
+                );
+            }
+            if !report.differing_immutable_files.is_empty() {
+                println!(
+                    "Immutable files differing from the reference digests: {}",
+                    report.differing_immutable_files.join(", ")
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Source for CardanoDbVerifyCommand {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        let mut map = Map::new();
+        let namespace = "clap arguments".to_string();
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(genesis_verification_key)),
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn read_digests_file_written_with_dump_digests_file_format() {
+        let dir = TempDir::create(
+            "client-cli-cardano-db-verify",
+            "read_digests_file_written_with_dump_digests_file_format",
+        );
+        let digests_file = dir.join("digests.json");
+        let digests = BTreeMap::from([
+            ("00001.chunk".to_string(), "digest-1".to_string()),
+            ("00001.primary".to_string(), "digest-2".to_string()),
+        ]);
+        std::fs::write(
+            &digests_file,
+            serde_json::to_string_pretty(&digests).unwrap(),
+        )
+        .unwrap();
+
+        let read_digests = CardanoDbVerifyCommand::read_digests_file(&digests_file).unwrap();
+
+        assert_eq!(digests, read_digests);
+    }
+}
@@ -0,0 +1,33 @@
+//! Commands for the Cardano DRep Stake Distribution artifact
+mod download;
+mod list;
+
+pub use download::*;
+pub use list::*;
+
+use clap::Subcommand;
+use config::{builder::DefaultState, ConfigBuilder};
+use mithril_client::MithrilResult;
+
+/// Cardano DRep Stake Distribution management (alias: cdsd)
+#[derive(Subcommand, Debug, Clone)]
+#[command(about = "[unstable] Cardano DRep stake distribution management (alias: cdsd)")]
+pub enum CardanoDRepStakeDistributionCommands {
+    /// List certified Cardano DRep Stake Distributions
+    #[clap(arg_required_else_help = false)]
+    List(CardanoDRepStakeDistributionListCommand),
+
+    /// Download and verify the given Cardano DRep Stake Distribution
+    #[clap(arg_required_else_help = true)]
+    Download(CardanoDRepStakeDistributionDownloadCommand),
+}
+
+impl CardanoDRepStakeDistributionCommands {
+    /// Execute Cardano DRep Stake Distribution command
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        match self {
+            Self::List(cmd) => cmd.execute(config_builder).await,
+            Self::Download(cmd) => cmd.execute(config_builder).await,
+        }
+    }
+}
@@ -11,7 +11,7 @@ use crate::utils::{ExpanderUtils, IndicatifFeedbackReceiver, ProgressOutputType,
 use crate::{commands::client_builder, configuration::ConfigParameters};
 use mithril_client::common::Epoch;
 use mithril_client::Client;
-use mithril_client::{CardanoStakeDistribution, MessageBuilder, MithrilResult};
+use mithril_client::{CardanoStakeDistribution, MithrilResult};
 
 /// Download and verify a Cardano stake distribution information.
 #[derive(Parser, Debug, Clone)]
@@ -98,8 +98,10 @@ impl CardanoStakeDistributionDownloadCommand {
             3,
             "Verify that the Cardano stake distribution is signed in the associated certificate",
         )?;
-        let message = MessageBuilder::new()
-            .compute_cardano_stake_distribution_message(&certificate, &cardano_stake_distribution)
+        let message = client
+            .cardano_stake_distribution()
+            .compute_message(&certificate, &cardano_stake_distribution)
+            .await
             .with_context(|| {
                 "Can not compute the message for the given Cardano stake distribution"
             })?;
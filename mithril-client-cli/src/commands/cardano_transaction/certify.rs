@@ -3,11 +3,17 @@ use clap::Parser;
 use cli_table::{print_stdout, Cell, Table};
 use config::{builder::DefaultState, ConfigBuilder, Map, Source, Value, ValueKind};
 use slog_scope::debug;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use mithril_client::{
-    common::TransactionHash, CardanoTransactionsProofs, MessageBuilder, MithrilCertificate,
-    MithrilResult, VerifiedCardanoTransactions, VerifyCardanoTransactionsProofsError,
+    cardano_transaction_client::AggregatedCardanoTransactionsProofs, common::TransactionHash,
+    Client, MessageBuilder, MithrilCertificate, MithrilResult, VerifiedCardanoTransactions,
+    VerifyCardanoTransactionsProofsError,
 };
 
 use crate::utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter};
@@ -25,8 +31,13 @@ pub struct CardanoTransactionsCertifyCommand {
     genesis_verification_key: Option<String>,
 
     /// Hashes of the transactions to certify.
-    #[clap(value_delimiter = ',', required = true)]
+    #[clap(value_delimiter = ',', required_unless_present = "transactions_file")]
     transactions_hashes: Vec<String>,
+
+    /// File to read the hashes of the transactions to certify from, one by line or comma
+    /// separated, use `-` to read them from the standard input.
+    #[clap(long, conflicts_with = "transactions_hashes")]
+    transactions_file: Option<PathBuf>,
 }
 
 impl CardanoTransactionsCertifyCommand {
@@ -47,61 +58,106 @@ impl CardanoTransactionsCertifyCommand {
             )))
             .build()?;
 
-        progress_printer.report_step(1, "Fetching a proof for the given transactions…")?;
-        let cardano_transaction_proof = client
+        let transactions_hashes = match &self.transactions_file {
+            Some(transactions_file) => Self::read_transactions_hashes_file(transactions_file)?,
+            None => self.transactions_hashes.clone(),
+        };
+
+        progress_printer.report_step(1, "Fetching the proofs for the given transactions…")?;
+        let cardano_transaction_proofs = client
             .cardano_transaction()
-            .get_proofs(&self.transactions_hashes)
+            .get_proofs_by_chunks(futures::stream::iter(transactions_hashes))
             .await
-            .with_context(|| {
-                format!(
-                    "Can not get proof from aggregator, transactions hashes: '{:?}'",
-                    self.transactions_hashes
-                )
-            })?;
+            .with_context(|| "Can not get proofs from aggregator")?;
         debug!(
-            "Got Proof from aggregator, proof: {:?}",
-            cardano_transaction_proof
+            "Got Proofs from aggregator, proofs: {:?}",
+            cardano_transaction_proofs
         );
 
         let verified_transactions =
-            Self::verify_proof_validity(2, &progress_printer, &cardano_transaction_proof)?;
+            Self::verify_proof_validity(2, &progress_printer, &client, &cardano_transaction_proofs)
+                .await?;
 
         progress_printer.report_step(
             3,
-            "Fetching the associated certificate and verifying the certificate chain…",
+            "Fetching the associated certificates and verifying the certificate chains…",
         )?;
-        let certificate = client
-            .certificate()
-            .verify_chain(&cardano_transaction_proof.certificate_hash)
-            .await
-            .with_context(|| {
-                format!(
-                    "Can not verify the certificate chain from certificate_hash: '{}'",
-                    verified_transactions.certificate_hash()
-                )
-            })?;
+        let mut certificates = vec![];
+        for verified in &verified_transactions {
+            let certificate = client
+                .certificate()
+                .verify_chain(verified.certificate_hash())
+                .await
+                .with_context(|| {
+                    format!(
+                        "Can not verify the certificate chain from certificate_hash: '{}'",
+                        verified.certificate_hash()
+                    )
+                })?;
+            certificates.push(certificate);
+        }
 
-        Self::verify_proof_match_certificate(
+        progress_printer.report_step(
             4,
-            &progress_printer,
-            &certificate,
-            &verified_transactions,
+            "Verify that the proofs are signed in the associated certificates",
         )?;
+        for (certificate, verified) in certificates.iter().zip(&verified_transactions) {
+            Self::verify_proof_match_certificate(certificate, verified)?;
+        }
 
         Self::log_certify_information(
             &verified_transactions,
-            &cardano_transaction_proof.non_certified_transactions,
+            &cardano_transaction_proofs.non_certified_transactions(),
             self.json,
         )
     }
 
-    fn verify_proof_validity(
+    /// Read transactions hashes separated by new lines or commas from a file or the standard input.
+    fn read_transactions_hashes_file(transactions_file: &Path) -> MithrilResult<Vec<String>> {
+        let reader: Box<dyn Read> = if transactions_file == Path::new("-") {
+            Box::new(std::io::stdin())
+        } else {
+            Box::new(std::fs::File::open(transactions_file).with_context(|| {
+                format!(
+                    "Can not open the transactions file: '{}'",
+                    transactions_file.display()
+                )
+            })?)
+        };
+        Self::read_transactions_hashes(reader)
+    }
+
+    fn read_transactions_hashes<R: Read>(reader: R) -> MithrilResult<Vec<String>> {
+        let mut transactions_hashes = vec![];
+        for line in BufReader::new(reader).lines() {
+            let line = line.with_context(|| "Can not read the transactions hashes")?;
+            transactions_hashes.extend(
+                line.split(',')
+                    .map(str::trim)
+                    .filter(|hash| !hash.is_empty())
+                    .map(String::from),
+            );
+        }
+
+        if transactions_hashes.is_empty() {
+            return Err(anyhow!("No transaction hash to certify was given"));
+        }
+
+        Ok(transactions_hashes)
+    }
+
+    async fn verify_proof_validity(
         step_number: u16,
         progress_printer: &ProgressPrinter,
-        cardano_transaction_proof: &CardanoTransactionsProofs,
-    ) -> MithrilResult<VerifiedCardanoTransactions> {
-        progress_printer.report_step(step_number, "Verifying the proof…")?;
-        match cardano_transaction_proof.verify() {
+        client: &Client,
+        cardano_transaction_proofs: &AggregatedCardanoTransactionsProofs,
+    ) -> MithrilResult<Vec<VerifiedCardanoTransactions>> {
+        progress_printer.report_step(step_number, "Verifying the proofs…")?;
+        match client
+            .cardano_transaction()
+            .verify_proofs(cardano_transaction_proofs)
+            .await
+        {
             Ok(verified_transactions) => Ok(verified_transactions),
             Err(VerifyCardanoTransactionsProofsError::NoCertifiedTransaction) => Err(anyhow!(
                 "Mithril could not certify any of the given transactions.
@@ -113,15 +169,9 @@ Mithril may not have signed those transactions yet, please try again later."
     }
 
     fn verify_proof_match_certificate(
-        step_number: u16,
-        progress_printer: &ProgressPrinter,
         certificate: &MithrilCertificate,
         verified_transactions: &VerifiedCardanoTransactions,
     ) -> MithrilResult<()> {
-        progress_printer.report_step(
-            step_number,
-            "Verify that the proof is signed in the associated certificate",
-        )?;
         let message = MessageBuilder::new()
             .compute_cardano_transactions_proofs_message(certificate, verified_transactions);
         if !certificate.match_message(&message) {
@@ -135,19 +185,30 @@ Mithril may not have signed those transactions yet, please try again later."
     }
 
     fn log_certify_information(
-        verified_transactions: &VerifiedCardanoTransactions,
+        verified_transactions: &[VerifiedCardanoTransactions],
         non_certified_transactions: &[TransactionHash],
         json_output: bool,
     ) -> MithrilResult<()> {
+        let certified_transactions: Vec<&TransactionHash> = verified_transactions
+            .iter()
+            .flat_map(|verified| verified.certified_transactions())
+            .collect();
+
         if json_output {
             println!(
-                r#"{{"certified_transactions": {}, "non_certified_transactions": {}}}"#,
-                serde_json::to_string(verified_transactions.certified_transactions())?,
+                r#"{{"certified_transactions": {}, "non_certified_transactions": {}, "certificate_hashes": {}}}"#,
+                serde_json::to_string(&certified_transactions)?,
                 serde_json::to_string(non_certified_transactions)?,
+                serde_json::to_string(
+                    &verified_transactions
+                        .iter()
+                        .map(|verified| verified.certificate_hash())
+                        .collect::<Vec<_>>()
+                )?,
             );
         } else {
             println!(
-                r###"Cardano transactions proof has been successfully signed in the associated Mithril certificate."###,
+                r###"Cardano transactions proofs have been successfully signed in the associated Mithril certificates."###,
             );
 
             if !non_certified_transactions.is_empty() {
@@ -157,8 +218,7 @@ No proof could be computed for some Cardano transactions. Mithril may not have s
                 );
             }
 
-            let result_table = verified_transactions
-                .certified_transactions()
+            let result_table = certified_transactions
                 .iter()
                 .map(|tx| {
                     vec![
@@ -201,3 +261,25 @@ impl Source for CardanoTransactionsCertifyCommand {
         Ok(map)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_transactions_hashes_separated_by_new_lines_or_commas() {
+        let content = "tx-1\ntx-2, tx-3\n\n  tx-4  \n";
+
+        let transactions_hashes =
+            CardanoTransactionsCertifyCommand::read_transactions_hashes(content.as_bytes())
+                .unwrap();
+
+        assert_eq!(vec!["tx-1", "tx-2", "tx-3", "tx-4"], transactions_hashes);
+    }
+
+    #[test]
+    fn read_transactions_hashes_fails_without_hashes() {
+        CardanoTransactionsCertifyCommand::read_transactions_hashes("\n \n".as_bytes())
+            .expect_err("Reading an empty list of transactions hashes should fail");
+    }
+}
@@ -0,0 +1,103 @@
+use clap::Parser;
+use cli_table::{format::Justify, print_stdout, Cell, Table, TableStruct};
+use config::{builder::DefaultState, ConfigBuilder};
+use std::collections::HashMap;
+
+use crate::{commands::client_builder_with_fallback_genesis_key, configuration::ConfigParameters};
+use mithril_client::{MithrilCertificateListItem, MithrilResult};
+
+/// Certificate LIST command
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateListCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+}
+
+impl CertificateListCommand {
+    /// Main command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let client = client_builder_with_fallback_genesis_key(&params)?.build()?;
+        let certificates = client.certificate().list().await?;
+
+        if self.json {
+            println!("{}", serde_json::to_string(&certificates)?);
+        } else {
+            print_stdout(Self::certificates_table(&certificates))?;
+        }
+
+        Ok(())
+    }
+
+    fn certificates_table(certificates: &[MithrilCertificateListItem]) -> TableStruct {
+        certificates
+            .iter()
+            .map(|item| {
+                vec![
+                    format!("{}", item.epoch).cell(),
+                    item.hash.clone().cell(),
+                    item.previous_hash.clone().cell(),
+                    item.signed_entity_type.to_string().cell(),
+                    item.metadata.total_signers.cell(),
+                    item.metadata.sealed_at.to_string().cell(),
+                ]
+            })
+            .collect::<Vec<_>>()
+            .table()
+            .title(vec![
+                "Epoch".cell(),
+                "Hash".cell(),
+                "Previous Hash".cell(),
+                "Signed Entity Type".cell(),
+                "Signers".cell(),
+                "Sealed".cell().justify(Justify::Right),
+            ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cli_table::ColorChoice;
+    use mithril_client::common::Epoch;
+
+    use super::*;
+
+    fn certificates() -> Vec<MithrilCertificateListItem> {
+        vec![
+            MithrilCertificateListItem {
+                hash: "hash-2".to_string(),
+                previous_hash: "hash-1".to_string(),
+                epoch: Epoch(12),
+                ..MithrilCertificateListItem::dummy()
+            },
+            MithrilCertificateListItem {
+                hash: "hash-1".to_string(),
+                previous_hash: "hash-0".to_string(),
+                epoch: Epoch(11),
+                ..MithrilCertificateListItem::dummy()
+            },
+        ]
+    }
+
+    #[test]
+    fn certificates_table_has_a_line_per_certificate() {
+        let table = CertificateListCommand::certificates_table(&certificates())
+            .color_choice(ColorChoice::Never)
+            .display()
+            .unwrap()
+            .to_string();
+
+        let line_of = |epoch: u64| {
+            table
+                .lines()
+                .find(|line| line.starts_with(&format!("| {epoch} ")))
+                .unwrap_or_else(|| panic!("no line for epoch {epoch} in:\n{table}"))
+                .to_string()
+        };
+        assert!(table.contains(" Previous Hash "));
+        assert!(line_of(12).contains(" hash-2 ") && line_of(12).contains(" hash-1 "));
+        assert!(line_of(11).contains(" hash-1 ") && line_of(11).contains(" hash-0 "));
+    }
+}
@@ -0,0 +1,39 @@
+//! Commands for the certificates
+mod list;
+mod show;
+mod verify;
+
+pub use list::*;
+pub use show::*;
+pub use verify::*;
+
+use clap::Subcommand;
+use config::{builder::DefaultState, ConfigBuilder};
+use mithril_client::MithrilResult;
+
+/// Certificate management (alias: cert)
+#[derive(Subcommand, Debug, Clone)]
+pub enum CertificateCommands {
+    /// List certificates
+    #[clap(arg_required_else_help = false)]
+    List(CertificateListCommand),
+
+    /// Show information about a certificate
+    #[clap(arg_required_else_help = true)]
+    Show(CertificateShowCommand),
+
+    /// Verify the certificate chain of the given certificate
+    #[clap(arg_required_else_help = true)]
+    Verify(CertificateVerifyCommand),
+}
+
+impl CertificateCommands {
+    /// Execute certificate command
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        match self {
+            Self::List(cmd) => cmd.execute(config_builder).await,
+            Self::Show(cmd) => cmd.execute(config_builder).await,
+            Self::Verify(cmd) => cmd.execute(config_builder).await,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use cli_table::{print_stdout, Cell, Table, TableStruct};
+use config::{builder::DefaultState, ConfigBuilder};
+use std::collections::HashMap;
+
+use crate::{
+    commands::client_builder_with_fallback_genesis_key, configuration::ConfigParameters,
+    utils::ExpanderUtils,
+};
+use mithril_client::{MithrilCertificate, MithrilResult};
+
+/// Clap command to show a given certificate
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateShowCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Certificate hash.
+    ///
+    /// If `latest` is specified as hash, the command will return the latest certificate.
+    hash: String,
+}
+
+impl CertificateShowCommand {
+    /// Certificate Show command
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let client = client_builder_with_fallback_genesis_key(&params)?.build()?;
+
+        let get_list_of_certificate_hashes = || async {
+            let certificates = client.certificate().list().await.with_context(|| {
+                "Can not get the list of certificates while retrieving the latest certificate hash"
+            })?;
+
+            Ok(certificates
+                .iter()
+                .map(|certificate| certificate.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+
+        let certificate = client
+            .certificate()
+            .get(
+                &ExpanderUtils::expand_eventual_id_alias(
+                    &self.hash,
+                    get_list_of_certificate_hashes(),
+                )
+                .await?,
+            )
+            .await?
+            .ok_or_else(|| anyhow!("Certificate not found for hash: '{}'", &self.hash))?;
+
+        if self.json {
+            println!("{}", serde_json::to_string(&certificate)?);
+        } else {
+            print_stdout(Self::certificate_table(&certificate))?
+        }
+
+        Ok(())
+    }
+
+    fn certificate_table(certificate: &MithrilCertificate) -> TableStruct {
+        vec![
+            vec!["Hash".cell(), certificate.hash.clone().cell()],
+            vec![
+                "Previous Hash".cell(),
+                certificate.previous_hash.clone().cell(),
+            ],
+            vec!["Epoch".cell(), format!("{}", certificate.epoch).cell()],
+            vec![
+                "Signed Entity Type".cell(),
+                format!("{:?}", certificate.signed_entity_type).cell(),
+            ],
+            vec![
+                "Network".cell(),
+                certificate.metadata.network.clone().cell(),
+            ],
+            vec![
+                "Protocol Version".cell(),
+                certificate.metadata.protocol_version.clone().cell(),
+            ],
+            vec![
+                "Protocol Parameters".cell(),
+                format!("{:?}", certificate.metadata.protocol_parameters).cell(),
+            ],
+            vec!["Signers".cell(), certificate.metadata.signers.len().cell()],
+            vec![
+                "Initiated".cell(),
+                certificate.metadata.initiated_at.to_string().cell(),
+            ],
+            vec![
+                "Sealed".cell(),
+                certificate.metadata.sealed_at.to_string().cell(),
+            ],
+            vec![
+                "Signed Message".cell(),
+                certificate.signed_message.clone().cell(),
+            ],
+            vec![
+                "Genesis".cell(),
+                (!certificate.genesis_signature.is_empty()).cell(),
+            ],
+        ]
+        .table()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cli_table::ColorChoice;
+
+    use super::*;
+
+    fn certificate() -> MithrilCertificate {
+        MithrilCertificate {
+            hash: "hash-2".to_string(),
+            previous_hash: "hash-1".to_string(),
+            signed_message: "signed-message".to_string(),
+            genesis_signature: String::new(),
+            ..MithrilCertificate::dummy()
+        }
+    }
+
+    #[test]
+    fn certificate_table_has_a_line_per_field() {
+        let table = CertificateShowCommand::certificate_table(&certificate())
+            .color_choice(ColorChoice::Never)
+            .display()
+            .unwrap()
+            .to_string();
+
+        let line_of = |field: &str| {
+            table
+                .lines()
+                .find(|line| line.starts_with(&format!("| {field} ")))
+                .unwrap_or_else(|| panic!("no line for field '{field}' in:\n{table}"))
+                .to_string()
+        };
+        assert!(line_of("Hash").contains(" hash-2 "));
+        assert!(line_of("Previous Hash").contains(" hash-1 "));
+        assert!(line_of("Signed Message").contains(" signed-message "));
+        assert!(line_of("Genesis").contains(" false "));
+    }
+}
@@ -0,0 +1,224 @@
+use anyhow::Context;
+use clap::Parser;
+use config::{builder::DefaultState, ConfigBuilder, Map, Source, Value, ValueKind};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter};
+use crate::{commands::client_builder, configuration::ConfigParameters, utils::ExpanderUtils};
+use mithril_client::{
+    response_cache::MemoryAggregatorResponseCache, MithrilCertificate, MithrilResult,
+};
+
+/// Name of the file listing the hashes of the dumped certificate chain
+const CERTIFICATE_CHAIN_FILENAME: &str = "certificate_chain.json";
+
+/// Verify the certificate chain of a certificate, and optionally dump the full chain to a
+/// directory.
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateVerifyCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Hash of the certificate to verify.
+    ///
+    /// If `latest` is specified as hash, the command will verify the latest certificate.
+    hash: String,
+
+    /// Directory where the verified certificate chain will be dumped, one JSON file per
+    /// certificate.
+    #[clap(long)]
+    dump_dir: Option<PathBuf>,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+}
+
+impl CertificateVerifyCommand {
+    /// Main command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.add_source(self.clone()).build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+
+        let progress_output_type = if self.json {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let total_steps = if self.dump_dir.is_some() { 2 } else { 1 };
+        let progress_printer = ProgressPrinter::new(progress_output_type, total_steps);
+        let mut builder = client_builder(&params)?.add_feedback_receiver(Arc::new(
+            IndicatifFeedbackReceiver::new(progress_output_type),
+        ));
+        if self.dump_dir.is_some() && params.get("response_cache_dir").is_none() {
+            // The dumped certificate chain is served from the certificates fetched to verify it
+            builder = builder
+                .with_aggregator_response_cache(Arc::new(MemoryAggregatorResponseCache::new()));
+        }
+        let client = builder.build()?;
+
+        let get_list_of_certificate_hashes = || async {
+            let certificates = client.certificate().list().await.with_context(|| {
+                "Can not get the list of certificates while retrieving the latest certificate hash"
+            })?;
+
+            Ok(certificates
+                .iter()
+                .map(|certificate| certificate.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+        let certificate_hash =
+            ExpanderUtils::expand_eventual_id_alias(&self.hash, get_list_of_certificate_hashes())
+                .await?;
+
+        progress_printer.report_step(1, "Fetching the certificate and verifying its chain…")?;
+        let certificate_chain = match &self.dump_dir {
+            Some(_) => {
+                client
+                    .certificate()
+                    .get_verified_chain(&certificate_hash)
+                    .await
+            }
+            None => client
+                .certificate()
+                .verify_chain(&certificate_hash)
+                .await
+                .map(|certificate| vec![certificate]),
+        }
+        .with_context(|| {
+            format!(
+                "Can not verify the certificate chain from certificate_hash: '{certificate_hash}'"
+            )
+        })?;
+        let certificate = &certificate_chain[0];
+
+        if let Some(dump_dir) = &self.dump_dir {
+            progress_printer.report_step(2, "Dumping the certificate chain to the directory…")?;
+            Self::write_certificate_chain(dump_dir, &certificate_chain).with_context(|| {
+                format!(
+                    "Can not dump the certificate chain to directory: '{}'",
+                    dump_dir.display()
+                )
+            })?;
+        }
+
+        if self.json {
+            println!(
+                r#"{{"certificate_hash": "{}", "dump_dir": {}}}"#,
+                certificate.hash,
+                serde_json::to_string(&self.dump_dir)?
+            );
+        } else {
+            println!(
+                "Certificate chain of certificate '{}' has been verified.",
+                certificate.hash
+            );
+            if let Some(dump_dir) = &self.dump_dir {
+                println!(
+                    "Certificate chain has been dumped to '{}'.",
+                    dump_dir.display()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write each certificate of the chain in its own JSON file, along with a file listing the
+    /// hashes of the chain from its tip to the genesis certificate.
+    fn write_certificate_chain(
+        dump_dir: &Path,
+        certificate_chain: &[MithrilCertificate],
+    ) -> MithrilResult<()> {
+        std::fs::create_dir_all(dump_dir)?;
+
+        for certificate in certificate_chain {
+            std::fs::write(
+                dump_dir.join(format!("certificate-{}.json", certificate.hash)),
+                serde_json::to_string(certificate)?,
+            )?;
+        }
+
+        let certificate_hashes: Vec<&str> = certificate_chain
+            .iter()
+            .map(|certificate| certificate.hash.as_str())
+            .collect();
+        std::fs::write(
+            dump_dir.join(CERTIFICATE_CHAIN_FILENAME),
+            serde_json::to_string(&certificate_hashes)?,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Source for CertificateVerifyCommand {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        let mut map = Map::new();
+        let namespace = "clap arguments".to_string();
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(genesis_verification_key)),
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn write_certificate_chain_dumps_each_certificate_and_the_chain_hashes() {
+        let dump_dir = TempDir::create(
+            "client-cli-certificate",
+            "write_certificate_chain_dumps_each_certificate_and_the_chain_hashes",
+        );
+        let certificate_chain = vec![
+            MithrilCertificate {
+                hash: "hash-2".to_string(),
+                previous_hash: "hash-1".to_string(),
+                ..MithrilCertificate::dummy()
+            },
+            MithrilCertificate {
+                hash: "hash-1".to_string(),
+                previous_hash: String::new(),
+                genesis_signature: "genesis-signature".to_string(),
+                ..MithrilCertificate::dummy()
+            },
+        ];
+
+        CertificateVerifyCommand::write_certificate_chain(&dump_dir, &certificate_chain).unwrap();
+
+        let certificate_hashes: Vec<String> = serde_json::from_str(
+            &std::fs::read_to_string(dump_dir.join(CERTIFICATE_CHAIN_FILENAME)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(vec!["hash-2", "hash-1"], certificate_hashes);
+        for certificate in certificate_chain {
+            let dumped_certificate: MithrilCertificate = serde_json::from_str(
+                &std::fs::read_to_string(
+                    dump_dir.join(format!("certificate-{}.json", certificate.hash)),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(certificate, dumped_certificate);
+        }
+    }
+}
@@ -9,7 +9,6 @@ use std::{
 
 use crate::utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter};
 use crate::{commands::client_builder, configuration::ConfigParameters, utils::ExpanderUtils};
-use mithril_client::MessageBuilder;
 use mithril_client::MithrilResult;
 
 /// Download and verify a Mithril Stake Distribution information. If the
@@ -112,8 +111,10 @@ impl MithrilStakeDistributionDownloadCommand {
             3,
             "Verify that the Mithril stake distribution is signed in the associated certificate",
         )?;
-        let message = MessageBuilder::new()
-            .compute_mithril_stake_distribution_message(&certificate, &mithril_stake_distribution)
+        let message = client
+            .mithril_stake_distribution()
+            .compute_message(&certificate, &mithril_stake_distribution)
+            .await
             .with_context(|| {
                 "Can not compute the message for the given Mithril stake distribution"
             })?;
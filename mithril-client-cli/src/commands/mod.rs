@@ -4,24 +4,68 @@
 //!
 
 pub mod cardano_db;
+pub mod cardano_drep_stake_distribution;
 pub mod cardano_stake_distribution;
 pub mod cardano_transaction;
+pub mod certificate;
 mod deprecation;
 pub mod mithril_stake_distribution;
+pub mod verification_bundle;
 
 pub use deprecation::{DeprecatedCommand, Deprecation};
 
-use mithril_client::{ClientBuilder, MithrilResult};
+use anyhow::Context;
+use mithril_client::{
+    response_cache::FsAggregatorResponseCache, ClientBuilder, ClientOptions, MithrilResult,
+};
 use slog_scope::logger;
+use std::{path::Path, sync::Arc};
 
 use crate::configuration::ConfigParameters;
 
 pub(crate) fn client_builder(params: &ConfigParameters) -> MithrilResult<ClientBuilder> {
-    let builder = ClientBuilder::aggregator(
-        &params.require("aggregator_endpoint")?,
-        &params.require("genesis_verification_key")?,
-    )
-    .with_logger(logger());
+    aggregators_client_builder(params, &params.require("genesis_verification_key")?)
+}
+
+/// Create a [ClientBuilder] for the aggregators of the comma separated `aggregator_endpoint`
+/// parameter, cross-checked if the `required_aggregators_agreement` parameter is set and
+/// caching their responses if the `response_cache_dir` parameter is set.
+fn aggregators_client_builder(
+    params: &ConfigParameters,
+    genesis_verification_key: &str,
+) -> MithrilResult<ClientBuilder> {
+    let aggregator_endpoint = params.require("aggregator_endpoint")?;
+    let endpoints: Vec<&str> = aggregator_endpoint
+        .split(',')
+        .map(str::trim)
+        .filter(|endpoint| !endpoint.is_empty())
+        .collect();
+
+    let mut options = ClientOptions::default();
+    if let Some(required_agreement) = params.get("required_aggregators_agreement") {
+        options = options.with_required_aggregators_agreement(
+            required_agreement.parse().with_context(|| {
+                format!("Invalid required aggregators agreement, it must be a positive integer: '{required_agreement}'")
+            })?,
+        );
+    }
+
+    if let Some(lists_response_cache_ttl) = params.get("lists_response_cache_ttl") {
+        options = options.with_lists_response_cache_ttl_seconds(
+            lists_response_cache_ttl.parse().with_context(|| {
+                format!("Invalid lists response cache ttl, it must be a number of seconds: '{lists_response_cache_ttl}'")
+            })?,
+        );
+    }
+
+    let mut builder = ClientBuilder::aggregators(&endpoints, genesis_verification_key)
+        .with_options(options)
+        .with_logger(logger());
+    if let Some(response_cache_dir) = params.get("response_cache_dir") {
+        builder = builder.with_aggregator_response_cache(Arc::new(FsAggregatorResponseCache::new(
+            Path::new(&response_cache_dir),
+        )?));
+    }
 
     Ok(builder)
 }
@@ -35,14 +79,50 @@ pub(crate) fn client_builder_with_fallback_genesis_key(
         382c32322c35392c3230362c3130352c3233312c3135302c3231352c33302c37382c3231322c37362c31362c323\
         5322c3138302c37322c3133342c3133372c3234372c3136312c36385d";
 
-    let builder = ClientBuilder::aggregator(
-        &params.require("aggregator_endpoint")?,
+    aggregators_client_builder(
+        params,
         &params.get_or(
             "genesis_verification_key",
             fallback_genesis_verification_key,
         ),
     )
-    .with_logger(logger());
+}
 
-    Ok(builder)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_builder_fails_if_the_required_aggregators_agreement_is_not_an_integer() {
+        let params = ConfigParameters::build(&[
+            (
+                "aggregator_endpoint",
+                "http://aggregator1,http://aggregator2",
+            ),
+            ("genesis_verification_key", "key"),
+            ("required_aggregators_agreement", "two"),
+        ]);
+
+        client_builder(&params)
+            .err()
+            .expect("An invalid required aggregators agreement should fail");
+    }
+
+    #[test]
+    fn client_builder_creates_the_response_cache_directory() {
+        let cache_dir = mithril_common::test_utils::TempDir::create(
+            "client-cli-commands",
+            "client_builder_creates_the_response_cache_directory",
+        )
+        .join("cache");
+        let params = ConfigParameters::build(&[
+            ("aggregator_endpoint", "http://aggregator"),
+            ("genesis_verification_key", "key"),
+            ("response_cache_dir", cache_dir.to_str().unwrap()),
+        ]);
+
+        client_builder(&params).unwrap();
+
+        assert!(cache_dir.is_dir());
+    }
 }
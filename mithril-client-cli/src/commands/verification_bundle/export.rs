@@ -0,0 +1,251 @@
+use anyhow::{anyhow, Context};
+use clap::{Parser, ValueEnum};
+use config::{builder::DefaultState, ConfigBuilder, Map, Source, Value, ValueKind};
+use std::{collections::HashMap, future::Future, path::PathBuf, sync::Arc};
+
+use crate::utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter};
+use crate::{commands::client_builder, configuration::ConfigParameters, utils::ExpanderUtils};
+use mithril_client::{
+    verification_bundle::{OfflineVerifier, VerifiableArtifact, VerificationBundle},
+    Client, MithrilResult,
+};
+
+/// Type of artifact that can be exported in a verification bundle
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportableArtifactType {
+    /// A Mithril stake distribution, identified by its hash
+    MithrilStakeDistribution,
+    /// A Cardano stake distribution, identified by its hash
+    CardanoStakeDistribution,
+    /// A Cardano DRep stake distribution, identified by its hash
+    CardanoDrepStakeDistribution,
+    /// Proofs of membership of Cardano transactions, identified by the transactions hashes
+    CardanoTransaction,
+}
+
+/// Export a verification bundle of an artifact: the artifact, its certificate chain down to the
+/// genesis certificate and the genesis verification key.
+///
+/// The bundle is verified before being written, and can then be verified offline with the
+/// `verification-bundle verify` command.
+#[derive(Parser, Debug, Clone)]
+pub struct VerificationBundleExportCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Type of the artifact to export.
+    #[clap(value_enum)]
+    artifact_type: ExportableArtifactType,
+
+    /// Identifiers of the artifact to export.
+    ///
+    /// The hash of the artifact for stake distributions, `latest` can be used to export the
+    /// latest one, or the hashes of the transactions to certify for Cardano transactions.
+    #[clap(value_delimiter = ',', required = true)]
+    identifiers: Vec<String>,
+
+    /// Path of the file where the verification bundle will be written.
+    #[clap(long, default_value = "verification-bundle.json")]
+    output: PathBuf,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+}
+
+impl VerificationBundleExportCommand {
+    /// Main command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.add_source(self.clone()).build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let genesis_verification_key = params.require("genesis_verification_key")?;
+
+        let progress_output_type = if self.json {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 4);
+        let client = client_builder(&params)?
+            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+            )))
+            .build()?;
+
+        progress_printer.report_step(1, "Fetching the artifact…")?;
+        let artifact = self.fetch_artifact(&client).await?;
+
+        progress_printer.report_step(
+            2,
+            "Fetching the certificate and verifying the certificate chain…",
+        )?;
+        let certificate_hash = artifact.certificate_hash().to_string();
+        client
+            .certificate()
+            .verify_chain(&certificate_hash)
+            .await
+            .with_context(|| {
+                format!(
+                    "Can not verify the certificate chain from certificate_hash: '{certificate_hash}'"
+                )
+            })?;
+        let certificate_chain = client
+            .certificate()
+            .get_chain(&certificate_hash)
+            .await
+            .with_context(|| {
+                format!("Can not fetch the certificate chain of certificate: '{certificate_hash}'")
+            })?;
+
+        progress_printer.report_step(3, "Verifying the verification bundle…")?;
+        let bundle = VerificationBundle {
+            genesis_verification_key: genesis_verification_key.clone(),
+            artifact,
+            certificate_chain,
+        };
+        OfflineVerifier::new(&genesis_verification_key)?
+            .verify(&bundle)
+            .await
+            .with_context(|| "The verification bundle is invalid")?;
+
+        progress_printer.report_step(4, "Writing the verification bundle to a file…")?;
+        std::fs::write(&self.output, serde_json::to_string(&bundle)?).with_context(|| {
+            format!(
+                "Can not write the verification bundle to file: '{}'",
+                self.output.display()
+            )
+        })?;
+
+        if self.json {
+            println!(
+                r#"{{"certificate_hash": "{}", "certificate_chain_length": {}, "filepath": {}}}"#,
+                certificate_hash,
+                bundle.certificate_chain.len(),
+                serde_json::to_string(&self.output)?
+            );
+        } else {
+            println!(
+                "Verification bundle of the artifact signed by certificate '{}' has been exported to '{}'.",
+                certificate_hash,
+                self.output.display()
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_artifact(&self, client: &Client) -> MithrilResult<VerifiableArtifact> {
+        match self.artifact_type {
+            ExportableArtifactType::CardanoTransaction => {
+                let proofs = client
+                    .cardano_transaction()
+                    .get_proofs(&self.identifiers)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Can not get proof from aggregator, transactions hashes: '{:?}'",
+                            self.identifiers
+                        )
+                    })?;
+                if proofs.certified_transactions.is_empty() {
+                    return Err(anyhow!(
+                        "Mithril could not certify any of the given transactions."
+                    ));
+                }
+
+                Ok(VerifiableArtifact::CardanoTransactionsProofs(proofs))
+            }
+            ExportableArtifactType::MithrilStakeDistribution => {
+                let hash = self
+                    .expand_artifact_hash(async {
+                        let list = client.mithril_stake_distribution().list().await?;
+                        Ok(list.into_iter().map(|item| item.hash).collect())
+                    })
+                    .await?;
+                let artifact = client
+                    .mithril_stake_distribution()
+                    .get(&hash)
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow!("Mithril stake distribution not found for hash: '{hash}'")
+                    })?;
+
+                Ok(VerifiableArtifact::MithrilStakeDistribution(artifact))
+            }
+            ExportableArtifactType::CardanoStakeDistribution => {
+                let hash = self
+                    .expand_artifact_hash(async {
+                        let list = client.cardano_stake_distribution().list().await?;
+                        Ok(list.into_iter().map(|item| item.hash).collect())
+                    })
+                    .await?;
+                let artifact = client
+                    .cardano_stake_distribution()
+                    .get(&hash)
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow!("Cardano stake distribution not found for hash: '{hash}'")
+                    })?;
+
+                Ok(VerifiableArtifact::CardanoStakeDistribution(artifact))
+            }
+            ExportableArtifactType::CardanoDrepStakeDistribution => {
+                let hash = self
+                    .expand_artifact_hash(async {
+                        let list = client.cardano_drep_stake_distribution().list().await?;
+                        Ok(list.into_iter().map(|item| item.hash).collect())
+                    })
+                    .await?;
+                let artifact = client
+                    .cardano_drep_stake_distribution()
+                    .get(&hash)
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow!("Cardano DRep stake distribution not found for hash: '{hash}'")
+                    })?;
+
+                Ok(VerifiableArtifact::CardanoDRepStakeDistribution(artifact))
+            }
+        }
+    }
+
+    async fn expand_artifact_hash(
+        &self,
+        get_list_of_artifact_hashes: impl Future<Output = MithrilResult<Vec<String>>>,
+    ) -> MithrilResult<String> {
+        let [identifier] = self.identifiers.as_slice() else {
+            return Err(anyhow!(
+                "Exactly one artifact hash is expected for a '{:?}' artifact",
+                self.artifact_type
+            ));
+        };
+
+        ExpanderUtils::expand_eventual_id_alias(identifier, async {
+            get_list_of_artifact_hashes.await.with_context(|| {
+                "Can not get the list of artifacts while retrieving the latest artifact hash"
+            })
+        })
+        .await
+    }
+}
+
+impl Source for VerificationBundleExportCommand {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        let mut map = Map::new();
+        let namespace = "clap arguments".to_string();
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(genesis_verification_key)),
+            );
+        }
+
+        Ok(map)
+    }
+}
@@ -0,0 +1,32 @@
+//! Commands for the verification bundles
+mod export;
+mod verify;
+
+pub use export::*;
+pub use verify::*;
+
+use clap::Subcommand;
+use config::{builder::DefaultState, ConfigBuilder};
+use mithril_client::MithrilResult;
+
+/// Verification bundle management (alias: vb)
+#[derive(Subcommand, Debug, Clone)]
+pub enum VerificationBundleCommands {
+    /// Export a verification bundle of an artifact that can be verified offline
+    #[clap(arg_required_else_help = true)]
+    Export(VerificationBundleExportCommand),
+
+    /// Verify a verification bundle offline
+    #[clap(arg_required_else_help = true)]
+    Verify(VerificationBundleVerifyCommand),
+}
+
+impl VerificationBundleCommands {
+    /// Execute verification bundle command
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        match self {
+            Self::Export(cmd) => cmd.execute(config_builder).await,
+            Self::Verify(cmd) => cmd.execute(config_builder).await,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::Context;
+use clap::Parser;
+use config::{builder::DefaultState, ConfigBuilder, Map, Source, Value, ValueKind};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::configuration::ConfigParameters;
+use mithril_client::{
+    verification_bundle::{OfflineVerifier, VerificationBundle},
+    MithrilResult,
+};
+
+/// Verify a verification bundle offline: no aggregator is contacted.
+///
+/// The certificate chain of the bundle is checked against the given genesis verification key
+/// and the artifact of the bundle must be signed by the first certificate of the chain.
+#[derive(Parser, Debug, Clone)]
+pub struct VerificationBundleVerifyCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Path of the verification bundle file to verify.
+    bundle_file: PathBuf,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+}
+
+impl VerificationBundleVerifyCommand {
+    /// Main command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.add_source(self.clone()).build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let genesis_verification_key = params.require("genesis_verification_key")?;
+
+        let bundle = Self::read_bundle(&self.bundle_file)?;
+        let certificate = OfflineVerifier::new(&genesis_verification_key)?
+            .verify(&bundle)
+            .await
+            .with_context(|| {
+                format!(
+                    "Verification bundle '{}' is invalid",
+                    self.bundle_file.display()
+                )
+            })?;
+
+        if self.json {
+            println!(
+                r#"{{"certificate_hash": "{}", "epoch": {}, "certificate_chain_length": {}}}"#,
+                certificate.hash,
+                certificate.epoch,
+                bundle.certificate_chain.len()
+            );
+        } else {
+            println!(
+                "Verification bundle '{}' is valid: its artifact is signed by certificate '{}' (epoch {}).",
+                self.bundle_file.display(),
+                certificate.hash,
+                certificate.epoch
+            );
+        }
+
+        Ok(())
+    }
+
+    fn read_bundle(bundle_file: &Path) -> MithrilResult<VerificationBundle> {
+        let content = std::fs::read_to_string(bundle_file).with_context(|| {
+            format!(
+                "Can not read the verification bundle file: '{}'",
+                bundle_file.display()
+            )
+        })?;
+
+        serde_json::from_str(&content).with_context(|| {
+            format!(
+                "Can not deserialize the verification bundle file: '{}'",
+                bundle_file.display()
+            )
+        })
+    }
+}
+
+impl Source for VerificationBundleVerifyCommand {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        let mut map = Map::new();
+        let namespace = "clap arguments".to_string();
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(genesis_verification_key)),
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn read_bundle_fails_if_the_file_is_not_a_verification_bundle() {
+        let dir = TempDir::create(
+            "client-cli-verification-bundle",
+            "read_bundle_fails_if_the_file_is_not_a_verification_bundle",
+        );
+        let bundle_file = dir.join("bundle.json");
+        std::fs::write(&bundle_file, r#"{"not": "a bundle"}"#).unwrap();
+
+        VerificationBundleVerifyCommand::read_bundle(&bundle_file)
+            .expect_err("Reading an invalid verification bundle should fail");
+    }
+}
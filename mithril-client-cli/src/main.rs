@@ -15,9 +15,12 @@ use mithril_client::MithrilResult;
 use mithril_doc::{Documenter, GenerateDocCommands, StructDoc};
 
 use mithril_client_cli::commands::{
-    cardano_db::CardanoDbCommands, cardano_stake_distribution::CardanoStakeDistributionCommands,
-    cardano_transaction::CardanoTransactionCommands,
-    mithril_stake_distribution::MithrilStakeDistributionCommands, DeprecatedCommand, Deprecation,
+    cardano_db::CardanoDbCommands,
+    cardano_drep_stake_distribution::CardanoDRepStakeDistributionCommands,
+    cardano_stake_distribution::CardanoStakeDistributionCommands,
+    cardano_transaction::CardanoTransactionCommands, certificate::CertificateCommands,
+    mithril_stake_distribution::MithrilStakeDistributionCommands,
+    verification_bundle::VerificationBundleCommands, DeprecatedCommand, Deprecation,
 };
 use mithril_client_cli::ClapError;
 
@@ -66,10 +69,32 @@ pub struct Args {
     pub config_directory: PathBuf,
 
     /// Override configuration Aggregator endpoint URL.
+    ///
+    /// Several comma separated endpoints can be given, they are used in order and the client
+    /// fails over to the next one when an aggregator is unavailable.
     #[clap(long, env = "AGGREGATOR_ENDPOINT")]
     #[example = "`https://aggregator.pre-release-preview.api.mithril.network/aggregator`"]
     aggregator_endpoint: Option<String>,
 
+    /// Number of aggregators that must return the same certificate or artifact for it to be
+    /// accepted, when several aggregator endpoints are given.
+    #[clap(long, env = "REQUIRED_AGGREGATORS_AGREEMENT")]
+    #[example = "`2`"]
+    required_aggregators_agreement: Option<usize>,
+
+    /// Directory where the aggregator responses are cached between runs.
+    ///
+    /// Certificates and artifacts never expire, lists of the latest artifacts expire after
+    /// `--lists-response-cache-ttl` seconds.
+    #[clap(long, env = "RESPONSE_CACHE_DIR")]
+    #[example = "`./mithril-client-cache`"]
+    response_cache_dir: Option<PathBuf>,
+
+    /// Time to live, in seconds, of the cached lists of the latest artifacts.
+    #[clap(long, env = "LISTS_RESPONSE_CACHE_TTL")]
+    #[example = "`60`"]
+    lists_response_cache_ttl: Option<u64>,
+
     /// Enable JSON output for logs displayed according to verbosity level
     #[clap(long)]
     log_format_json: bool,
@@ -176,6 +201,41 @@ impl Source for Args {
             );
         }
 
+        if let Some(response_cache_dir) = self.response_cache_dir.clone() {
+            map.insert(
+                "response_cache_dir".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(response_cache_dir.to_str().ok_or_else(|| {
+                        config::ConfigError::Message(format!(
+                            "Could not read response cache directory: '{}'.",
+                            response_cache_dir.display()
+                        ))
+                    })?),
+                ),
+            );
+        }
+
+        if let Some(lists_response_cache_ttl) = self.lists_response_cache_ttl {
+            map.insert(
+                "lists_response_cache_ttl".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(lists_response_cache_ttl.to_string()),
+                ),
+            );
+        }
+
+        if let Some(required_aggregators_agreement) = self.required_aggregators_agreement {
+            map.insert(
+                "required_aggregators_agreement".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(required_aggregators_agreement.to_string()),
+                ),
+            );
+        }
+
         Ok(map)
     }
 }
@@ -194,6 +254,15 @@ enum ArtifactCommands {
     #[clap(subcommand, alias("csd"))]
     CardanoStakeDistribution(CardanoStakeDistributionCommands),
 
+    #[clap(subcommand, name = "cardano-drep-stake-distribution", alias("cdsd"))]
+    CardanoDRepStakeDistribution(CardanoDRepStakeDistributionCommands),
+
+    #[clap(subcommand, alias("cert"))]
+    Certificate(CertificateCommands),
+
+    #[clap(subcommand, alias("vb"))]
+    VerificationBundle(VerificationBundleCommands),
+
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
 }
@@ -227,6 +296,27 @@ impl ArtifactCommands {
                     cmd.execute(config_builder).await
                 }
             }
+            Self::CardanoDRepStakeDistribution(cmd) => {
+                if !unstable_enabled {
+                    Err(anyhow!(Self::unstable_flag_missing_message(
+                        "cardano-drep-stake-distribution",
+                        "list"
+                    )))
+                } else {
+                    cmd.execute(config_builder).await
+                }
+            }
+            Self::Certificate(cmd) => cmd.execute(config_builder).await,
+            Self::VerificationBundle(cmd) => {
+                if !unstable_enabled {
+                    Err(anyhow!(Self::unstable_flag_missing_message(
+                        "verification-bundle",
+                        "verify verification-bundle.json"
+                    )))
+                } else {
+                    cmd.execute(config_builder).await
+                }
+            }
             Self::GenerateDoc(cmd) => cmd
                 .execute(&mut Args::command())
                 .map_err(|message| anyhow!(message)),
@@ -293,4 +383,40 @@ mod tests {
             .to_string()
             .contains("subcommand is only accepted using the --unstable flag."));
     }
+
+    #[tokio::test]
+    async fn fail_if_cardano_drep_stake_distribution_command_is_used_without_unstable_flag() {
+        let args =
+            Args::try_parse_from(["mithril-client", "cardano-drep-stake-distribution", "list"])
+                .unwrap();
+
+        let error = args
+            .execute()
+            .await
+            .expect_err("Should fail if unstable flag missing");
+
+        assert!(error
+            .to_string()
+            .contains("subcommand is only accepted using the --unstable flag."));
+    }
+
+    #[tokio::test]
+    async fn fail_if_verification_bundle_command_is_used_without_unstable_flag() {
+        let args = Args::try_parse_from([
+            "mithril-client",
+            "verification-bundle",
+            "verify",
+            "verification-bundle.json",
+        ])
+        .unwrap();
+
+        let error = args
+            .execute()
+            .await
+            .expect_err("Should fail if unstable flag missing");
+
+        assert!(error
+            .to_string()
+            .contains("subcommand is only accepted using the --unstable flag."));
+    }
 }
@@ -1,14 +1,34 @@
 use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
     fs,
+    io::{Read, Seek, SeekFrom},
     ops::Not,
     path::{Path, PathBuf},
 };
 
 use anyhow::Context;
 use human_bytes::human_bytes;
+use serde::Serialize;
 use thiserror::Error;
 
-use mithril_client::{common::CompressionAlgorithm, MithrilError, MithrilResult};
+use mithril_client::{
+    common::{
+        CompressionAlgorithm, ImmutableFile, ImmutableFileListingError, ImmutableFileNumber,
+        SlotNumber,
+    },
+    MithrilError, MithrilResult,
+};
+
+/// Extensions of the trio of files that make an immutable file.
+const IMMUTABLE_FILE_EXTENSIONS: [&str; 3] = ["chunk", "primary", "secondary"];
+
+/// Size of an entry of the secondary index of an immutable file.
+const SECONDARY_INDEX_ENTRY_SIZE: u64 = 56;
+
+/// Offset in an entry of the secondary index of the slot number of its block, stored on the
+/// last 8 bytes of the entry.
+const SECONDARY_INDEX_ENTRY_SLOT_OFFSET: usize = 48;
 
 /// Checks to apply before downloading a Cardano Db archive to a given directory.
 pub struct CardanoDbDownloadChecker;
@@ -42,6 +62,98 @@ pub enum CardanoDbDownloadCheckerError {
     UnpackDirectoryIsNotWritable(PathBuf, #[source] MithrilError),
 }
 
+/// Issue found in a restored Cardano db that prevents a Cardano node to bootstrap from it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "issue", rename_all = "snake_case")]
+pub enum LedgerStateBootstrapIssue {
+    /// The `immutable` directory could not be found.
+    MissingImmutableDirectory,
+
+    /// None of the chunk, primary and secondary files of an immutable file were found.
+    MissingImmutableFile {
+        /// Number of the missing immutable file
+        immutable_file_number: ImmutableFileNumber,
+    },
+
+    /// Some of the chunk, primary and secondary files of an immutable file are missing.
+    IncompleteImmutableFile {
+        /// Number of the incomplete immutable file
+        immutable_file_number: ImmutableFileNumber,
+
+        /// Extensions of the missing files
+        missing_extensions: Vec<String>,
+    },
+
+    /// No ledger snapshot was found in the `ledger` directory.
+    MissingLedgerSnapshot,
+
+    /// The most recent ledger snapshot is ahead of the last block of the immutable files, the
+    /// node would not be able to use it.
+    LedgerSnapshotAheadOfImmutableFiles {
+        /// Slot of the most recent ledger snapshot
+        ledger_snapshot_slot: SlotNumber,
+
+        /// Slot of the last block of the immutable files
+        last_immutable_slot: SlotNumber,
+    },
+
+    /// The `volatile` directory could not be found.
+    MissingVolatileDirectory,
+}
+
+impl Display for LedgerStateBootstrapIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingImmutableDirectory => write!(f, "the 'immutable' directory is missing"),
+            Self::MissingImmutableFile {
+                immutable_file_number,
+            } => write!(f, "the immutable file {immutable_file_number} is missing"),
+            Self::IncompleteImmutableFile {
+                immutable_file_number,
+                missing_extensions,
+            } => write!(
+                f,
+                "the immutable file {immutable_file_number} is incomplete, missing: {}",
+                missing_extensions.join(", ")
+            ),
+            Self::MissingLedgerSnapshot => {
+                write!(f, "no ledger snapshot found in the 'ledger' directory")
+            }
+            Self::LedgerSnapshotAheadOfImmutableFiles {
+                ledger_snapshot_slot,
+                last_immutable_slot,
+            } => write!(
+                f,
+                "the ledger snapshot at slot {ledger_snapshot_slot} is ahead of the last immutable block at slot {last_immutable_slot}"
+            ),
+            Self::MissingVolatileDirectory => write!(f, "the 'volatile' directory is missing"),
+        }
+    }
+}
+
+/// Report of the check that a Cardano node can bootstrap from a restored Cardano db.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LedgerStateBootstrapReport {
+    /// Number of the last immutable file certified for the Cardano db
+    pub immutable_file_number: ImmutableFileNumber,
+
+    /// Slot of the most recent ledger snapshot, if any
+    pub ledger_snapshot_slot: Option<SlotNumber>,
+
+    /// Slot of the last block of the immutable files, if it could be read
+    pub last_immutable_slot: Option<SlotNumber>,
+
+    /// Issues found, empty if a node can bootstrap from the Cardano db
+    pub issues: Vec<LedgerStateBootstrapIssue>,
+}
+
+impl LedgerStateBootstrapReport {
+    /// Check if a Cardano node can bootstrap from the Cardano db
+    pub fn can_bootstrap(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
 impl CardanoDbDownloadChecker {
     /// Ensure that the given path exist, create it otherwise
     pub fn ensure_dir_exist(pathdir: &Path) -> MithrilResult<()> {
@@ -129,6 +241,185 @@ impl CardanoDbDownloadChecker {
         }
         Ok(())
     }
+
+    /// Check, after its download, that a Cardano node can bootstrap from the Cardano db restored
+    /// in the given directory: all the immutable files up to the given number are complete,
+    /// a ledger snapshot not ahead of the immutable files exists and the volatile directory
+    /// exists.
+    pub fn check_ledger_state_bootstrap(
+        db_dir: &Path,
+        immutable_file_number: ImmutableFileNumber,
+    ) -> MithrilResult<LedgerStateBootstrapReport> {
+        let mut issues = vec![];
+
+        let last_immutable_slot = match ImmutableFile::list_completed_in_dir(db_dir) {
+            Ok(immutable_files) => {
+                issues.extend(Self::check_immutable_files_completeness(
+                    &immutable_files,
+                    immutable_file_number,
+                ));
+                match immutable_files.last() {
+                    Some(last_immutable_file) => Self::read_immutable_files_last_slot(
+                        last_immutable_file.path.parent().unwrap_or(db_dir),
+                        last_immutable_file.number,
+                    )?,
+                    None => None,
+                }
+            }
+            Err(ImmutableFileListingError::MissingImmutableFolder(_)) => {
+                issues.push(LedgerStateBootstrapIssue::MissingImmutableDirectory);
+                None
+            }
+            Err(error) => {
+                return Err(error).with_context(|| {
+                    format!(
+                        "Could not list the immutable files of directory '{}'",
+                        db_dir.display()
+                    )
+                })
+            }
+        };
+
+        let ledger_snapshot_slot = Self::find_last_ledger_snapshot_slot(&db_dir.join("ledger"))?;
+        match (ledger_snapshot_slot, last_immutable_slot) {
+            (None, _) => issues.push(LedgerStateBootstrapIssue::MissingLedgerSnapshot),
+            (Some(ledger_snapshot_slot), Some(last_immutable_slot))
+                if ledger_snapshot_slot > last_immutable_slot =>
+            {
+                issues.push(
+                    LedgerStateBootstrapIssue::LedgerSnapshotAheadOfImmutableFiles {
+                        ledger_snapshot_slot,
+                        last_immutable_slot,
+                    },
+                )
+            }
+            _ => {}
+        }
+
+        if db_dir.join("volatile").is_dir().not() {
+            issues.push(LedgerStateBootstrapIssue::MissingVolatileDirectory);
+        }
+
+        Ok(LedgerStateBootstrapReport {
+            immutable_file_number,
+            ledger_snapshot_slot,
+            last_immutable_slot,
+            issues,
+        })
+    }
+
+    fn check_immutable_files_completeness(
+        immutable_files: &[ImmutableFile],
+        immutable_file_number: ImmutableFileNumber,
+    ) -> Vec<LedgerStateBootstrapIssue> {
+        let mut extensions_by_number: BTreeMap<ImmutableFileNumber, Vec<String>> = BTreeMap::new();
+        for immutable_file in immutable_files {
+            if let Some(extension) = immutable_file.path.extension() {
+                extensions_by_number
+                    .entry(immutable_file.number)
+                    .or_default()
+                    .push(extension.to_string_lossy().to_string());
+            }
+        }
+
+        (0..=immutable_file_number)
+            .filter_map(|number| match extensions_by_number.get(&number) {
+                None => Some(LedgerStateBootstrapIssue::MissingImmutableFile {
+                    immutable_file_number: number,
+                }),
+                Some(extensions) => {
+                    let missing_extensions: Vec<String> = IMMUTABLE_FILE_EXTENSIONS
+                        .iter()
+                        .filter(|extension| extensions.iter().any(|e| e == *extension).not())
+                        .map(|extension| extension.to_string())
+                        .collect();
+
+                    missing_extensions.is_empty().not().then_some(
+                        LedgerStateBootstrapIssue::IncompleteImmutableFile {
+                            immutable_file_number: number,
+                            missing_extensions,
+                        },
+                    )
+                }
+            })
+            .collect()
+    }
+
+    /// Read the slot of the last block of the immutable files, the last completed immutable file
+    /// may be followed by the ongoing one that is also restored.
+    fn read_immutable_files_last_slot(
+        immutable_dir: &Path,
+        last_completed_number: ImmutableFileNumber,
+    ) -> MithrilResult<Option<SlotNumber>> {
+        for number in [last_completed_number + 1, last_completed_number] {
+            let secondary_index = immutable_dir.join(format!("{number:05}.secondary"));
+            if let Some(slot) = Self::read_secondary_index_last_slot(&secondary_index)? {
+                return Ok(Some(slot));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn read_secondary_index_last_slot(secondary_index: &Path) -> MithrilResult<Option<SlotNumber>> {
+        if secondary_index.is_file().not() {
+            return Ok(None);
+        }
+
+        let mut file = fs::File::open(secondary_index).with_context(|| {
+            format!(
+                "Could not open the secondary index '{}'",
+                secondary_index.display()
+            )
+        })?;
+        let entries_count = file.metadata()?.len() / SECONDARY_INDEX_ENTRY_SIZE;
+        if entries_count == 0 {
+            return Ok(None);
+        }
+
+        let mut entry = [0u8; SECONDARY_INDEX_ENTRY_SIZE as usize];
+        file.seek(SeekFrom::Start(
+            (entries_count - 1) * SECONDARY_INDEX_ENTRY_SIZE,
+        ))
+        .and_then(|_| file.read_exact(&mut entry))
+        .with_context(|| {
+            format!(
+                "Could not read the last entry of the secondary index '{}'",
+                secondary_index.display()
+            )
+        })?;
+        let mut slot = [0u8; 8];
+        slot.copy_from_slice(&entry[SECONDARY_INDEX_ENTRY_SLOT_OFFSET..]);
+
+        Ok(Some(SlotNumber(u64::from_be_bytes(slot))))
+    }
+
+    /// Ledger snapshots are named after their slot, optionally followed by a `_` and a suffix.
+    fn find_last_ledger_snapshot_slot(ledger_dir: &Path) -> MithrilResult<Option<SlotNumber>> {
+        if ledger_dir.is_dir().not() {
+            return Ok(None);
+        }
+
+        let last_slot = fs::read_dir(ledger_dir)
+            .with_context(|| {
+                format!(
+                    "Could not list the ledger directory '{}'",
+                    ledger_dir.display()
+                )
+            })?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|name| name.split('_').next())
+                    .and_then(|slot| slot.parse::<u64>().ok())
+            })
+            .max()
+            .map(SlotNumber);
+
+        Ok(last_slot)
+    }
 }
 
 #[cfg(test)]
@@ -231,6 +522,162 @@ mod test {
         );
     }
 
+    mod ledger_state_bootstrap {
+        use super::*;
+
+        struct DbDirBuilder {
+            db_dir: PathBuf,
+        }
+
+        impl DbDirBuilder {
+            fn new(name: &str) -> Self {
+                let db_dir = create_temporary_empty_directory(name).join("db");
+                fs::create_dir_all(db_dir.join("immutable")).unwrap();
+                fs::create_dir_all(db_dir.join("ledger")).unwrap();
+                fs::create_dir_all(db_dir.join("volatile")).unwrap();
+
+                Self { db_dir }
+            }
+
+            /// Write the trio of an immutable file, its secondary index containing a single
+            /// block at the given slot.
+            fn with_immutable_file(self, number: ImmutableFileNumber, last_slot: u64) -> Self {
+                let immutable_dir = self.db_dir.join("immutable");
+                fs::write(immutable_dir.join(format!("{number:05}.chunk")), "chunk").unwrap();
+                fs::write(
+                    immutable_dir.join(format!("{number:05}.primary")),
+                    "primary",
+                )
+                .unwrap();
+                let mut secondary_entry = vec![0u8; SECONDARY_INDEX_ENTRY_SLOT_OFFSET];
+                secondary_entry.extend(last_slot.to_be_bytes());
+                fs::write(
+                    immutable_dir.join(format!("{number:05}.secondary")),
+                    secondary_entry,
+                )
+                .unwrap();
+
+                self
+            }
+
+            fn with_ledger_snapshot(self, name: &str) -> Self {
+                fs::write(self.db_dir.join("ledger").join(name), "ledger").unwrap();
+                self
+            }
+
+            fn remove(self, path: &str) -> Self {
+                let path = self.db_dir.join(path);
+                if path.is_dir() {
+                    fs::remove_dir_all(path).unwrap();
+                } else {
+                    fs::remove_file(path).unwrap();
+                }
+                self
+            }
+
+            fn build(self) -> PathBuf {
+                self.db_dir
+            }
+        }
+
+        #[test]
+        fn report_no_issue_for_a_complete_db() {
+            let db_dir = DbDirBuilder::new("ledger_state_bootstrap_complete_db")
+                .with_immutable_file(0, 10)
+                .with_immutable_file(1, 20)
+                .with_immutable_file(2, 30)
+                .with_ledger_snapshot("25")
+                .with_ledger_snapshot("12_db-analyser")
+                .build();
+
+            let report = CardanoDbDownloadChecker::check_ledger_state_bootstrap(&db_dir, 1)
+                .expect("check_ledger_state_bootstrap should not fail");
+
+            assert_eq!(
+                LedgerStateBootstrapReport {
+                    immutable_file_number: 1,
+                    ledger_snapshot_slot: Some(SlotNumber(25)),
+                    last_immutable_slot: Some(SlotNumber(30)),
+                    issues: vec![],
+                },
+                report
+            );
+            assert!(report.can_bootstrap());
+        }
+
+        #[test]
+        fn report_missing_and_incomplete_immutable_files() {
+            let db_dir = DbDirBuilder::new("ledger_state_bootstrap_incomplete_immutable_files")
+                .with_immutable_file(0, 10)
+                .with_immutable_file(2, 30)
+                .with_immutable_file(3, 40)
+                .with_ledger_snapshot("25")
+                .remove("immutable/00002.primary")
+                .build();
+
+            let report = CardanoDbDownloadChecker::check_ledger_state_bootstrap(&db_dir, 2)
+                .expect("check_ledger_state_bootstrap should not fail");
+
+            assert_eq!(
+                vec![
+                    LedgerStateBootstrapIssue::MissingImmutableFile {
+                        immutable_file_number: 1
+                    },
+                    LedgerStateBootstrapIssue::IncompleteImmutableFile {
+                        immutable_file_number: 2,
+                        missing_extensions: vec!["primary".to_string()]
+                    },
+                ],
+                report.issues
+            );
+        }
+
+        #[test]
+        fn report_missing_immutable_ledger_and_volatile_directories() {
+            let db_dir = DbDirBuilder::new("ledger_state_bootstrap_missing_directories")
+                .remove("immutable")
+                .remove("ledger")
+                .remove("volatile")
+                .build();
+
+            let report = CardanoDbDownloadChecker::check_ledger_state_bootstrap(&db_dir, 1)
+                .expect("check_ledger_state_bootstrap should not fail");
+
+            assert_eq!(
+                vec![
+                    LedgerStateBootstrapIssue::MissingImmutableDirectory,
+                    LedgerStateBootstrapIssue::MissingLedgerSnapshot,
+                    LedgerStateBootstrapIssue::MissingVolatileDirectory,
+                ],
+                report.issues
+            );
+            assert!(!report.can_bootstrap());
+        }
+
+        #[test]
+        fn report_ledger_snapshot_ahead_of_immutable_files() {
+            let db_dir = DbDirBuilder::new("ledger_state_bootstrap_ledger_snapshot_ahead")
+                .with_immutable_file(0, 10)
+                .with_immutable_file(1, 20)
+                .with_ledger_snapshot("15")
+                .with_ledger_snapshot("35")
+                .build();
+
+            let report = CardanoDbDownloadChecker::check_ledger_state_bootstrap(&db_dir, 0)
+                .expect("check_ledger_state_bootstrap should not fail");
+
+            assert_eq!(
+                vec![
+                    LedgerStateBootstrapIssue::LedgerSnapshotAheadOfImmutableFiles {
+                        ledger_snapshot_slot: SlotNumber(35),
+                        last_immutable_slot: SlotNumber(20),
+                    }
+                ],
+                report.issues
+            );
+        }
+    }
+
     // Those test are not on Windows because `set_readonly` is ignored for directories on Windows 7+
     // https://doc.rust-lang.org/std/fs/struct.Permissions.html#method.set_readonly
     #[cfg(not(target_os = "windows"))]
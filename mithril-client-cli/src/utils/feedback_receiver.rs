@@ -12,6 +12,7 @@ use mithril_client::feedback::{FeedbackReceiver, MithrilEvent};
 pub struct IndicatifFeedbackReceiver {
     download_progress_reporter: RwLock<Option<DownloadProgressReporter>>,
     certificate_validation_pb: RwLock<Option<ProgressBar>>,
+    transactions_proofs_pb: RwLock<Option<ProgressBar>>,
     output_type: ProgressOutputType,
 }
 
@@ -21,6 +22,7 @@ impl IndicatifFeedbackReceiver {
         Self {
             download_progress_reporter: RwLock::new(None),
             certificate_validation_pb: RwLock::new(None),
+            transactions_proofs_pb: RwLock::new(None),
             output_type,
         }
     }
@@ -95,6 +97,69 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                 }
                 *certificate_validation_pb = None;
             }
+            MithrilEvent::CardanoTransactionsProofsRetrievalStarted {
+                proofs_retrieval_id: _,
+            }
+            | MithrilEvent::CardanoTransactionsProofsVerificationStarted {
+                proofs_verification_id: _,
+            } => {
+                let pb = if self.output_type == ProgressOutputType::Tty {
+                    ProgressBar::new_spinner()
+                } else {
+                    ProgressBar::hidden()
+                };
+                let mut transactions_proofs_pb = self.transactions_proofs_pb.write().await;
+                *transactions_proofs_pb = Some(pb);
+            }
+            MithrilEvent::CardanoTransactionsProofsChunkRetrieved {
+                proofs_retrieval_id: _,
+                chunk_transactions_count: _,
+                retrieved_transactions_count,
+            } => {
+                let transactions_proofs_pb = self.transactions_proofs_pb.read().await;
+                if let Some(progress_bar) = transactions_proofs_pb.as_ref() {
+                    progress_bar.set_message(format!(
+                        "Proofs of {retrieved_transactions_count} transactions retrieved"
+                    ));
+                    progress_bar.tick();
+                }
+            }
+            MithrilEvent::CardanoTransactionsProofsVerified {
+                proofs_verification_id: _,
+                certificate_hash,
+                certified_transactions_count: _,
+            } => {
+                let transactions_proofs_pb = self.transactions_proofs_pb.read().await;
+                if let Some(progress_bar) = transactions_proofs_pb.as_ref() {
+                    progress_bar.set_message(format!(
+                        "Proofs signed by certificate '{certificate_hash}' are valid"
+                    ));
+                    progress_bar.tick();
+                }
+            }
+            MithrilEvent::CardanoTransactionsProofsRetrievalCompleted {
+                proofs_retrieval_id: _,
+                certified_transactions_count,
+                non_certified_transactions_count,
+            } => {
+                let mut transactions_proofs_pb = self.transactions_proofs_pb.write().await;
+                if let Some(progress_bar) = transactions_proofs_pb.as_ref() {
+                    progress_bar.finish_with_message(format!(
+                        "Proofs retrieved: {certified_transactions_count} certified transactions, {non_certified_transactions_count} non certified transactions"
+                    ));
+                }
+                *transactions_proofs_pb = None;
+            }
+            MithrilEvent::CardanoTransactionsProofsVerificationCompleted {
+                proofs_verification_id: _,
+            } => {
+                let mut transactions_proofs_pb = self.transactions_proofs_pb.write().await;
+                if let Some(progress_bar) = transactions_proofs_pb.as_ref() {
+                    progress_bar.finish_with_message("Proofs verified");
+                }
+                *transactions_proofs_pb = None;
+            }
+            _ => {}
         }
     }
 }
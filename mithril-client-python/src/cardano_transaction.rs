@@ -0,0 +1,127 @@
+use pyo3::prelude::*;
+use serde::Serialize;
+use std::sync::Arc;
+
+use mithril_client::{cardano_transaction_client, certificate_client, MessageBuilder};
+
+use crate::conversion::to_python;
+use crate::errors::{to_py_err, MessageMismatchError, ProofVerificationError};
+
+/// Transactions certified by [CardanoTransactionClient::certify].
+#[derive(Serialize)]
+struct CertifiedTransactions {
+    certificate_hashes: Vec<String>,
+    certified_transactions: Vec<String>,
+    non_certified_transactions: Vec<String>,
+}
+
+/// Client to fetch the Cardano transactions snapshots and certify Cardano transactions.
+#[pyclass(module = "mithril_client")]
+pub struct CardanoTransactionClient {
+    client: Arc<cardano_transaction_client::CardanoTransactionClient>,
+    certificate_client: Arc<certificate_client::CertificateClient>,
+}
+
+impl CardanoTransactionClient {
+    pub(crate) fn new(
+        client: Arc<cardano_transaction_client::CardanoTransactionClient>,
+        certificate_client: Arc<certificate_client::CertificateClient>,
+    ) -> Self {
+        Self {
+            client,
+            certificate_client,
+        }
+    }
+}
+
+#[pymethods]
+impl CardanoTransactionClient {
+    /// Fetch the list of the latest Cardano transactions snapshots.
+    fn list_snapshots<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let snapshots = client.list_snapshots().await.map_err(to_py_err)?;
+            to_python(&snapshots)
+        })
+    }
+
+    /// Fetch the Cardano transactions snapshot with the given hash, `None` if it does not exist.
+    fn get_snapshot<'py>(&self, py: Python<'py>, hash: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let snapshot = client.get_snapshot(&hash).await.map_err(to_py_err)?;
+            to_python(&snapshot)
+        })
+    }
+
+    /// Fetch the proofs of membership of the given transactions, without verifying them.
+    fn get_proofs<'py>(
+        &self,
+        py: Python<'py>,
+        transactions_hashes: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let proofs = client
+                .get_proofs(&transactions_hashes)
+                .await
+                .map_err(to_py_err)?;
+            to_python(&proofs)
+        })
+    }
+
+    /// Certify the given transactions: fetch their proofs of membership, verify them and verify
+    /// that they are signed by certificates with a valid chain.
+    ///
+    /// Return the hashes of the certificates, of the certified transactions and of the
+    /// transactions that could not be certified, ie not yet signed.
+    ///
+    /// Raise a `ProofVerificationError` if a proof is invalid, a `MessageMismatchError` if a
+    /// proof does not match its certificate and a `CertificateChainVerificationError` if a
+    /// certificate chain is invalid.
+    fn certify<'py>(
+        &self,
+        py: Python<'py>,
+        transactions_hashes: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let certificate_client = self.certificate_client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let proofs = client
+                .get_proofs_by_chunks(futures::stream::iter(transactions_hashes))
+                .await
+                .map_err(to_py_err)?;
+            let verified_transactions = client
+                .verify_proofs(&proofs)
+                .await
+                .map_err(|e| ProofVerificationError::new_err(format!("{e:?}")))?;
+
+            let mut certified_transactions = vec![];
+            for verified in &verified_transactions {
+                let certificate = certificate_client
+                    .verify_chain(verified.certificate_hash())
+                    .await
+                    .map_err(to_py_err)?;
+                let message = MessageBuilder::new()
+                    .compute_cardano_transactions_proofs_message(&certificate, verified);
+                if !certificate.match_message(&message) {
+                    return Err(MessageMismatchError::new_err(format!(
+                        "Proof and certificate don't match (certificate hash = '{}')",
+                        certificate.hash
+                    )));
+                }
+                certified_transactions.extend_from_slice(verified.certified_transactions());
+            }
+
+            to_python(&CertifiedTransactions {
+                certificate_hashes: proofs
+                    .certificate_hashes()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+                certified_transactions,
+                non_certified_transactions: proofs.non_certified_transactions(),
+            })
+        })
+    }
+}
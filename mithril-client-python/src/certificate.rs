@@ -0,0 +1,56 @@
+use pyo3::prelude::*;
+use std::sync::Arc;
+
+use crate::conversion::to_python;
+use crate::errors::to_py_err;
+
+/// Client to fetch the certificates and verify their chain.
+#[pyclass(module = "mithril_client")]
+pub struct CertificateClient {
+    client: Arc<mithril_client::certificate_client::CertificateClient>,
+}
+
+impl CertificateClient {
+    pub(crate) fn new(client: Arc<mithril_client::certificate_client::CertificateClient>) -> Self {
+        Self { client }
+    }
+}
+
+#[pymethods]
+impl CertificateClient {
+    /// Fetch the list of the latest certificates.
+    fn list<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let certificates = client.list().await.map_err(to_py_err)?;
+            to_python(&certificates)
+        })
+    }
+
+    /// Fetch the certificate with the given hash, `None` if it does not exist.
+    fn get<'py>(&self, py: Python<'py>, certificate_hash: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let certificate = client.get(&certificate_hash).await.map_err(to_py_err)?;
+            to_python(&certificate)
+        })
+    }
+
+    /// Verify the chain of the certificate with the given hash and return the certificate.
+    ///
+    /// Raise a `CertificateChainVerificationError` if the chain is invalid.
+    fn verify_chain<'py>(
+        &self,
+        py: Python<'py>,
+        certificate_hash: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let certificate = client
+                .verify_chain(&certificate_hash)
+                .await
+                .map_err(to_py_err)?;
+            to_python(&certificate)
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use pyo3::{exceptions::PyTypeError, prelude::*};
+use std::collections::HashMap;
+
+use mithril_client::{ClientBuilder, ClientOptions};
+
+use crate::cardano_transaction::CardanoTransactionClient;
+use crate::certificate::CertificateClient;
+use crate::errors::to_py_err;
+use crate::stake_distribution::{CardanoStakeDistributionClient, MithrilStakeDistributionClient};
+
+/// Client that fetches and verifies the artifacts certified by Mithril aggregators.
+///
+/// `aggregator_endpoint` is either the endpoint of an aggregator or a list of endpoints that
+/// are queried in order, failing over to the next one when an aggregator is unavailable.
+#[pyclass(module = "mithril_client")]
+pub struct Client {
+    client: mithril_client::Client,
+}
+
+#[pymethods]
+impl Client {
+    #[new]
+    #[pyo3(signature = (aggregator_endpoint, genesis_verification_key, http_headers=None, required_aggregators_agreement=None))]
+    fn new(
+        aggregator_endpoint: &Bound<'_, PyAny>,
+        genesis_verification_key: &str,
+        http_headers: Option<HashMap<String, String>>,
+        required_aggregators_agreement: Option<usize>,
+    ) -> PyResult<Self> {
+        let aggregator_endpoints: Vec<String> = match aggregator_endpoint.extract::<String>() {
+            Ok(endpoint) => vec![endpoint],
+            Err(_) => aggregator_endpoint.extract().map_err(|_| {
+                PyTypeError::new_err("aggregator_endpoint must be a string or a list of strings")
+            })?,
+        };
+        let aggregator_endpoints: Vec<&str> =
+            aggregator_endpoints.iter().map(String::as_str).collect();
+
+        let mut options = ClientOptions::new(http_headers);
+        if let Some(agreement) = required_aggregators_agreement {
+            options = options.with_required_aggregators_agreement(agreement);
+        }
+        let client = ClientBuilder::aggregators(&aggregator_endpoints, genesis_verification_key)
+            .with_options(options)
+            .build()
+            .map_err(to_py_err)?;
+
+        Ok(Self { client })
+    }
+
+    /// Get the client for the certificates.
+    fn certificate(&self) -> CertificateClient {
+        CertificateClient::new(self.client.certificate())
+    }
+
+    /// Get the client for the Cardano transactions.
+    fn cardano_transaction(&self) -> CardanoTransactionClient {
+        CardanoTransactionClient::new(self.client.cardano_transaction(), self.client.certificate())
+    }
+
+    /// Get the client for the Mithril stake distributions.
+    fn mithril_stake_distribution(&self) -> MithrilStakeDistributionClient {
+        MithrilStakeDistributionClient::new(
+            self.client.mithril_stake_distribution(),
+            self.client.certificate(),
+        )
+    }
+
+    /// Get the client for the Cardano stake distributions.
+    fn cardano_stake_distribution(&self) -> CardanoStakeDistributionClient {
+        CardanoStakeDistributionClient::new(
+            self.client.cardano_stake_distribution(),
+            self.client.certificate(),
+        )
+    }
+}
@@ -0,0 +1,15 @@
+use pyo3::{prelude::*, types::PyModule};
+use serde::Serialize;
+
+/// Convert a serializable value to its Python equivalent, made of `dict`, `list`, `str`, `int`,
+/// `float`, `bool` and `None`, the same way than `json.loads` would.
+pub(crate) fn to_python<T: Serialize>(value: &T) -> PyResult<PyObject> {
+    let json = serde_json::to_string(value)
+        .map_err(|e| crate::errors::MithrilError::new_err(format!("{e:?}")))?;
+
+    Python::with_gil(|py| {
+        Ok(PyModule::import(py, "json")?
+            .call_method1("loads", (json,))?
+            .unbind())
+    })
+}
@@ -0,0 +1,49 @@
+use pyo3::{create_exception, exceptions::PyException, PyErr};
+
+use mithril_client::MithrilError as ClientError;
+use mithril_common::certificate_chain::CertificateVerifierError;
+use mithril_common::crypto_helper::ProtocolGenesisError;
+
+create_exception!(
+    mithril_client,
+    MithrilError,
+    PyException,
+    "Base class of the errors raised by the Mithril client."
+);
+create_exception!(
+    mithril_client,
+    VerificationError,
+    MithrilError,
+    "Raised when a certified artifact can't be verified."
+);
+create_exception!(
+    mithril_client,
+    CertificateChainVerificationError,
+    VerificationError,
+    "Raised when the chain of a certificate is invalid."
+);
+create_exception!(
+    mithril_client,
+    ProofVerificationError,
+    VerificationError,
+    "Raised when the proof of a set of Cardano transactions is invalid."
+);
+create_exception!(
+    mithril_client,
+    MessageMismatchError,
+    VerificationError,
+    "Raised when the message computed from an artifact does not match its certificate."
+);
+
+/// Convert an error of the Mithril client to the matching Python exception.
+pub(crate) fn to_py_err(error: ClientError) -> PyErr {
+    let message = format!("{error:?}");
+    if error
+        .chain()
+        .any(|cause| cause.is::<CertificateVerifierError>() || cause.is::<ProtocolGenesisError>())
+    {
+        CertificateChainVerificationError::new_err(message)
+    } else {
+        MithrilError::new_err(message)
+    }
+}
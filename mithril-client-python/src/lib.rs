@@ -0,0 +1,48 @@
+//! Python bindings of the 'mithril-client' library
+#![warn(missing_docs)]
+
+mod cardano_transaction;
+mod certificate;
+mod client;
+mod conversion;
+mod errors;
+mod stake_distribution;
+
+use pyo3::prelude::*;
+
+pub use cardano_transaction::CardanoTransactionClient;
+pub use certificate::CertificateClient;
+pub use client::Client;
+pub use stake_distribution::{CardanoStakeDistributionClient, MithrilStakeDistributionClient};
+
+/// Mithril client Python module
+#[pymodule]
+#[pyo3(name = "mithril_client")]
+fn mithril_client(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add_class::<Client>()?;
+    m.add_class::<CertificateClient>()?;
+    m.add_class::<CardanoTransactionClient>()?;
+    m.add_class::<MithrilStakeDistributionClient>()?;
+    m.add_class::<CardanoStakeDistributionClient>()?;
+
+    m.add("MithrilError", py.get_type::<errors::MithrilError>())?;
+    m.add(
+        "VerificationError",
+        py.get_type::<errors::VerificationError>(),
+    )?;
+    m.add(
+        "CertificateChainVerificationError",
+        py.get_type::<errors::CertificateChainVerificationError>(),
+    )?;
+    m.add(
+        "ProofVerificationError",
+        py.get_type::<errors::ProofVerificationError>(),
+    )?;
+    m.add(
+        "MessageMismatchError",
+        py.get_type::<errors::MessageMismatchError>(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use pyo3::prelude::*;
+use std::sync::Arc;
+
+use mithril_client::{
+    cardano_stake_distribution_client, certificate_client, common::Epoch,
+    mithril_stake_distribution_client, MessageBuilder, MithrilCertificate, MithrilResult,
+};
+use mithril_common::entities::ProtocolMessage;
+
+use crate::conversion::to_python;
+use crate::errors::{to_py_err, MessageMismatchError};
+
+/// Verify the chain of the given certificate then check that it signs the message computed from
+/// the artifact.
+async fn verify_certified_message<F>(
+    certificate_client: &certificate_client::CertificateClient,
+    certificate_hash: &str,
+    compute_message: F,
+) -> PyResult<()>
+where
+    F: FnOnce(&MithrilCertificate) -> MithrilResult<ProtocolMessage>,
+{
+    let certificate = certificate_client
+        .verify_chain(certificate_hash)
+        .await
+        .map_err(to_py_err)?;
+    let message = compute_message(&certificate).map_err(to_py_err)?;
+    if !certificate.match_message(&message) {
+        return Err(MessageMismatchError::new_err(format!(
+            "Certificate and message did not match: hash = '{}'",
+            certificate.hash
+        )));
+    }
+
+    Ok(())
+}
+
+/// Client to fetch and verify the Mithril stake distributions.
+#[pyclass(module = "mithril_client")]
+pub struct MithrilStakeDistributionClient {
+    client: Arc<mithril_stake_distribution_client::MithrilStakeDistributionClient>,
+    certificate_client: Arc<certificate_client::CertificateClient>,
+}
+
+impl MithrilStakeDistributionClient {
+    pub(crate) fn new(
+        client: Arc<mithril_stake_distribution_client::MithrilStakeDistributionClient>,
+        certificate_client: Arc<certificate_client::CertificateClient>,
+    ) -> Self {
+        Self {
+            client,
+            certificate_client,
+        }
+    }
+}
+
+#[pymethods]
+impl MithrilStakeDistributionClient {
+    /// Fetch the list of the latest Mithril stake distributions.
+    fn list<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let stake_distributions = client.list().await.map_err(to_py_err)?;
+            to_python(&stake_distributions)
+        })
+    }
+
+    /// Fetch the Mithril stake distribution with the given hash, `None` if it does not exist.
+    fn get<'py>(&self, py: Python<'py>, hash: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let stake_distribution = client.get(&hash).await.map_err(to_py_err)?;
+            to_python(&stake_distribution)
+        })
+    }
+
+    /// Fetch the Mithril stake distribution with the given hash and verify that it's signed by
+    /// a certificate with a valid chain.
+    ///
+    /// Raise a `MessageMismatchError` if the stake distribution does not match its certificate
+    /// and a `CertificateChainVerificationError` if the certificate chain is invalid.
+    fn verify<'py>(&self, py: Python<'py>, hash: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let certificate_client = self.certificate_client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let stake_distribution =
+                client.get(&hash).await.map_err(to_py_err)?.ok_or_else(|| {
+                    to_py_err(anyhow::anyhow!(
+                        "No Mithril stake distribution found for hash: '{hash}'"
+                    ))
+                })?;
+            verify_certified_message(
+                &certificate_client,
+                &stake_distribution.certificate_hash,
+                |certificate| {
+                    MessageBuilder::new().compute_mithril_stake_distribution_message(
+                        certificate,
+                        &stake_distribution,
+                    )
+                },
+            )
+            .await?;
+
+            to_python(&stake_distribution)
+        })
+    }
+}
+
+/// Client to fetch and verify the Cardano stake distributions.
+#[pyclass(module = "mithril_client")]
+pub struct CardanoStakeDistributionClient {
+    client: Arc<cardano_stake_distribution_client::CardanoStakeDistributionClient>,
+    certificate_client: Arc<certificate_client::CertificateClient>,
+}
+
+impl CardanoStakeDistributionClient {
+    pub(crate) fn new(
+        client: Arc<cardano_stake_distribution_client::CardanoStakeDistributionClient>,
+        certificate_client: Arc<certificate_client::CertificateClient>,
+    ) -> Self {
+        Self {
+            client,
+            certificate_client,
+        }
+    }
+}
+
+#[pymethods]
+impl CardanoStakeDistributionClient {
+    /// Fetch the list of the latest Cardano stake distributions.
+    fn list<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let stake_distributions = client.list().await.map_err(to_py_err)?;
+            to_python(&stake_distributions)
+        })
+    }
+
+    /// Fetch the Cardano stake distribution with the given hash, `None` if it does not exist.
+    fn get<'py>(&self, py: Python<'py>, hash: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let stake_distribution = client.get(&hash).await.map_err(to_py_err)?;
+            to_python(&stake_distribution)
+        })
+    }
+
+    /// Fetch the Cardano stake distribution of the given epoch, `None` if it does not exist.
+    fn get_by_epoch<'py>(&self, py: Python<'py>, epoch: u64) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let stake_distribution = client.get_by_epoch(Epoch(epoch)).await.map_err(to_py_err)?;
+            to_python(&stake_distribution)
+        })
+    }
+
+    /// Fetch the Cardano stake distribution with the given hash and verify that it's signed by
+    /// a certificate with a valid chain.
+    ///
+    /// Raise a `MessageMismatchError` if the stake distribution does not match its certificate
+    /// and a `CertificateChainVerificationError` if the certificate chain is invalid.
+    fn verify<'py>(&self, py: Python<'py>, hash: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let certificate_client = self.certificate_client.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let stake_distribution =
+                client.get(&hash).await.map_err(to_py_err)?.ok_or_else(|| {
+                    to_py_err(anyhow::anyhow!(
+                        "No Cardano stake distribution found for hash: '{hash}'"
+                    ))
+                })?;
+            verify_certified_message(
+                &certificate_client,
+                &stake_distribution.certificate_hash,
+                |certificate| {
+                    MessageBuilder::new().compute_cardano_stake_distribution_message(
+                        certificate,
+                        &stake_distribution,
+                    )
+                },
+            )
+            .await?;
+
+            to_python(&stake_distribution)
+        })
+    }
+}
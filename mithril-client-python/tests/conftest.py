@@ -0,0 +1,71 @@
+import json
+import os
+from pathlib import Path
+
+import pytest
+
+from mithril_client import Client
+
+REPOSITORY_ROOT = Path(__file__).resolve().parents[2]
+FAKE_AGGREGATOR_DATA_DIR = (
+    REPOSITORY_ROOT / "mithril-test-lab" / "mithril-aggregator-fake" / "default_data"
+)
+FAKE_AGGREGATOR_ENDPOINT = os.environ.get(
+    "FAKE_AGGREGATOR_ENDPOINT", "http://127.0.0.1:8000/aggregator"
+)
+# Genesis verification key that signs the certificates of the fake aggregator default data
+GENESIS_VERIFICATION_KEY = "5b33322c3235332c3138362c3230312c3137372c31312c3131372c3133352c3138372c3136372c3138312c3138382c32322c35392c3230362c3130352c3233312c3135302c3231352c33302c37382c3231322c37362c31362c3235322c3138302c37322c3133342c3133372c3234372c3136312c36385d"
+# Another genesis verification key, that did not sign the fake aggregator certificates
+OTHER_GENESIS_VERIFICATION_KEY = (
+    REPOSITORY_ROOT / "mithril-infra" / "configuration" / "release-mainnet" / "genesis.vkey"
+).read_text().strip()
+
+
+def load_fake_aggregator_data(file_name):
+    with open(FAKE_AGGREGATOR_DATA_DIR / file_name) as file:
+        return json.load(file)
+
+
+@pytest.fixture
+def aggregator_endpoint():
+    return FAKE_AGGREGATOR_ENDPOINT
+
+
+@pytest.fixture
+def genesis_verification_key():
+    return GENESIS_VERIFICATION_KEY
+
+
+@pytest.fixture
+def client():
+    return Client(FAKE_AGGREGATOR_ENDPOINT, GENESIS_VERIFICATION_KEY)
+
+
+@pytest.fixture
+def client_with_other_genesis_key():
+    return Client(FAKE_AGGREGATOR_ENDPOINT, OTHER_GENESIS_VERIFICATION_KEY)
+
+
+@pytest.fixture
+def certificate_hashes():
+    return [item["hash"] for item in load_fake_aggregator_data("certificates-list.json")]
+
+
+@pytest.fixture
+def mithril_stake_distributions():
+    return load_fake_aggregator_data("mithril-stake-distributions-list.json")
+
+
+@pytest.fixture
+def cardano_stake_distributions():
+    return load_fake_aggregator_data("cardano-stake-distributions-list.json")
+
+
+@pytest.fixture
+def cardano_transactions_snapshot_hashes():
+    return [item["hash"] for item in load_fake_aggregator_data("ctx-snapshots-list.json")]
+
+
+@pytest.fixture
+def certified_transactions_hashes():
+    return list(load_fake_aggregator_data("ctx-proofs.json").keys())
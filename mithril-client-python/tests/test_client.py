@@ -0,0 +1,165 @@
+import pytest
+
+from mithril_client import (
+    CertificateChainVerificationError,
+    Client,
+    MithrilError,
+    VerificationError,
+)
+
+
+async def test_client_with_several_aggregators(aggregator_endpoint, genesis_verification_key):
+    client = Client(
+        [aggregator_endpoint, aggregator_endpoint],
+        genesis_verification_key,
+        required_aggregators_agreement=2,
+    )
+
+    assert await client.certificate().list()
+
+
+def test_client_rejects_an_invalid_aggregator_endpoint_type(genesis_verification_key):
+    with pytest.raises(TypeError):
+        Client(42, genesis_verification_key)
+
+
+def test_verification_errors_are_mithril_errors():
+    assert issubclass(CertificateChainVerificationError, VerificationError)
+    assert issubclass(VerificationError, MithrilError)
+    assert issubclass(MithrilError, Exception)
+
+
+class TestCertificate:
+    async def test_list(self, client, certificate_hashes):
+        certificates = await client.certificate().list()
+
+        assert [certificate["hash"] for certificate in certificates] == certificate_hashes
+
+    async def test_get(self, client, certificate_hashes):
+        certificate = await client.certificate().get(certificate_hashes[0])
+
+        assert certificate["hash"] == certificate_hashes[0]
+
+    async def test_get_unknown_certificate_returns_none(self, client):
+        assert await client.certificate().get("whatever") is None
+
+    async def test_verify_chain(self, client, certificate_hashes):
+        certificate = await client.certificate().verify_chain(certificate_hashes[0])
+
+        assert certificate["hash"] == certificate_hashes[0]
+
+    async def test_verify_chain_of_unknown_certificate_raises_a_mithril_error(self, client):
+        with pytest.raises(MithrilError) as error:
+            await client.certificate().verify_chain("whatever")
+
+        assert not isinstance(error.value, VerificationError)
+
+    async def test_verify_chain_with_another_genesis_key_raises_a_verification_error(
+        self, client_with_other_genesis_key, certificate_hashes
+    ):
+        with pytest.raises(CertificateChainVerificationError):
+            await client_with_other_genesis_key.certificate().verify_chain(
+                certificate_hashes[0]
+            )
+
+
+class TestMithrilStakeDistribution:
+    async def test_list(self, client, mithril_stake_distributions):
+        stake_distributions = await client.mithril_stake_distribution().list()
+
+        assert len(stake_distributions) == len(mithril_stake_distributions)
+
+    async def test_get(self, client, mithril_stake_distributions):
+        hash = mithril_stake_distributions[0]["hash"]
+
+        stake_distribution = await client.mithril_stake_distribution().get(hash)
+
+        assert stake_distribution["hash"] == hash
+
+    async def test_get_unknown_stake_distribution_returns_none(self, client):
+        assert await client.mithril_stake_distribution().get("whatever") is None
+
+    async def test_verify(self, client, mithril_stake_distributions):
+        hash = mithril_stake_distributions[0]["hash"]
+
+        stake_distribution = await client.mithril_stake_distribution().verify(hash)
+
+        assert stake_distribution["hash"] == hash
+
+    async def test_verify_with_another_genesis_key_raises_a_verification_error(
+        self, client_with_other_genesis_key, mithril_stake_distributions
+    ):
+        with pytest.raises(VerificationError):
+            await client_with_other_genesis_key.mithril_stake_distribution().verify(
+                mithril_stake_distributions[0]["hash"]
+            )
+
+
+class TestCardanoStakeDistribution:
+    async def test_list(self, client, cardano_stake_distributions):
+        stake_distributions = await client.cardano_stake_distribution().list()
+
+        assert len(stake_distributions) == len(cardano_stake_distributions)
+
+    async def test_get(self, client, cardano_stake_distributions):
+        hash = cardano_stake_distributions[0]["hash"]
+
+        stake_distribution = await client.cardano_stake_distribution().get(hash)
+
+        assert stake_distribution["hash"] == hash
+
+    async def test_get_by_epoch(self, client, cardano_stake_distributions):
+        epoch = cardano_stake_distributions[0]["epoch"]
+
+        stake_distribution = await client.cardano_stake_distribution().get_by_epoch(epoch)
+
+        assert stake_distribution["epoch"] == epoch
+
+    async def test_verify(self, client, cardano_stake_distributions):
+        hash = cardano_stake_distributions[0]["hash"]
+
+        stake_distribution = await client.cardano_stake_distribution().verify(hash)
+
+        assert stake_distribution["hash"] == hash
+
+
+class TestCardanoTransaction:
+    async def test_list_snapshots(self, client, cardano_transactions_snapshot_hashes):
+        snapshots = await client.cardano_transaction().list_snapshots()
+
+        assert [snapshot["hash"] for snapshot in snapshots] == (
+            cardano_transactions_snapshot_hashes
+        )
+
+    async def test_get_snapshot(self, client, cardano_transactions_snapshot_hashes):
+        hash = cardano_transactions_snapshot_hashes[0]
+
+        snapshot = await client.cardano_transaction().get_snapshot(hash)
+
+        assert snapshot["hash"] == hash
+
+    async def test_get_proofs(self, client, certified_transactions_hashes):
+        proofs = await client.cardano_transaction().get_proofs(
+            certified_transactions_hashes[:1]
+        )
+
+        assert proofs["certified_transactions"][0]["transactions_hashes"] == (
+            certified_transactions_hashes[:1]
+        )
+
+    async def test_certify(self, client, certified_transactions_hashes):
+        transactions_hashes = certified_transactions_hashes[:3]
+
+        result = await client.cardano_transaction().certify(transactions_hashes)
+
+        assert sorted(result["certified_transactions"]) == sorted(transactions_hashes)
+        assert result["non_certified_transactions"] == []
+        assert len(result["certificate_hashes"]) >= 1
+
+    async def test_certify_with_another_genesis_key_raises_a_verification_error(
+        self, client_with_other_genesis_key, certified_transactions_hashes
+    ):
+        with pytest.raises(CertificateChainVerificationError):
+            await client_with_other_genesis_key.cardano_transaction().certify(
+                certified_transactions_hashes[:1]
+            )
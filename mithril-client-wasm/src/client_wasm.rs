@@ -4,12 +4,15 @@ use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
 use mithril_client::{
-    common::Epoch,
-    feedback::{FeedbackReceiver, MithrilEvent},
+    common::{Epoch, ProtocolMessagePartKey},
+    feedback::{FeedbackReceiver, FeedbackSender, MithrilEvent},
     CardanoTransactionsProofs, Client, ClientBuilder, ClientOptions, MessageBuilder,
-    MithrilCertificate,
+    MithrilCertificate, MithrilResult, Snapshot,
 };
 
+use crate::indexed_db_response_cache::IndexedDbAggregatorResponseCache;
+use crate::opfs::OpfsDirectory;
+use crate::opfs_snapshot_downloader::OpfsSnapshotDownloader;
 use crate::WasmResult;
 
 #[wasm_bindgen]
@@ -58,6 +61,8 @@ impl From<MithrilEvent> for MithrilEventWasm {
 pub struct MithrilClient {
     client: Client,
 
+    feedback_sender: FeedbackSender,
+
     /// Allow usage of unstable functions
     unstable: bool,
 }
@@ -71,7 +76,27 @@ impl MithrilClient {
         genesis_verification_key: &str,
         options: JsValue,
     ) -> MithrilClient {
-        let feedback_receiver = Arc::new(JSBroadcastChannelFeedbackReceiver::new("mithril-client"));
+        Self::new_with_aggregators(
+            vec![aggregator_endpoint.to_string()],
+            genesis_verification_key,
+            options,
+        )
+    }
+
+    /// Constructor for wasm client that uses several aggregators, in order, failing over to
+    /// the next one when an aggregator is unavailable.
+    ///
+    /// Set the `required_aggregators_agreement` option to require that a number of aggregators
+    /// return the same certificate or artifact.
+    #[wasm_bindgen]
+    pub fn new_with_aggregators(
+        aggregator_endpoints: Vec<String>,
+        genesis_verification_key: &str,
+        options: JsValue,
+    ) -> MithrilClient {
+        let feedback_receiver: Arc<dyn FeedbackReceiver> =
+            Arc::new(JSBroadcastChannelFeedbackReceiver::new("mithril-client"));
+        let feedback_sender = FeedbackSender::new(&[feedback_receiver.clone()]);
 
         let client_options = if options.is_undefined() {
             ClientOptions::default()
@@ -81,14 +106,27 @@ impl MithrilClient {
                 .unwrap()
         };
         let unstable = client_options.unstable;
-        let client = ClientBuilder::aggregator(aggregator_endpoint, genesis_verification_key)
-            .add_feedback_receiver(feedback_receiver)
+        let aggregator_endpoints: Vec<&str> =
+            aggregator_endpoints.iter().map(String::as_str).collect();
+        let mut client_builder =
+            ClientBuilder::aggregators(&aggregator_endpoints, genesis_verification_key)
+                .add_feedback_receiver(feedback_receiver);
+        if client_options.enable_response_cache {
+            client_builder = client_builder.with_aggregator_response_cache(Arc::new(
+                IndexedDbAggregatorResponseCache::new("mithril-client"),
+            ));
+        }
+        let client = client_builder
             .with_options(client_options)
             .build()
             .map_err(|err| format!("{err:?}"))
             .unwrap();
 
-        MithrilClient { client, unstable }
+        MithrilClient {
+            client,
+            feedback_sender,
+            unstable,
+        }
     }
 
     /// Call the client to get a snapshot from a digest
@@ -160,8 +198,11 @@ impl MithrilClient {
             serde_wasm_bindgen::from_value(certificate).map_err(|err| format!("{err:?}"))?;
         let stake_distribution =
             serde_wasm_bindgen::from_value(stake_distribution).map_err(|err| format!("{err:?}"))?;
-        let result = MessageBuilder::new()
-            .compute_mithril_stake_distribution_message(&certificate, &stake_distribution)
+        let result = self
+            .client
+            .mithril_stake_distribution()
+            .compute_message(&certificate, &stake_distribution)
+            .await
             .map_err(|err| format!("{err:?}"))?;
 
         Ok(serde_wasm_bindgen::to_value(&result)?)
@@ -397,12 +438,178 @@ impl MithrilClient {
             serde_wasm_bindgen::from_value(certificate).map_err(|err| format!("{err:?}"))?;
         let cardano_stake_distribution = serde_wasm_bindgen::from_value(cardano_stake_distribution)
             .map_err(|err| format!("{err:?}"))?;
-        let result = MessageBuilder::new()
-            .compute_cardano_stake_distribution_message(&certificate, &cardano_stake_distribution)
+        let result = self
+            .client
+            .cardano_stake_distribution()
+            .compute_message(&certificate, &cardano_stake_distribution)
+            .await
             .map_err(|err| format!("{err:?}"))?;
 
         Ok(serde_wasm_bindgen::to_value(&result)?)
     }
+
+    /// Call the client to get a cardano DRep stake distribution from a hash
+    ///
+    /// Warning: this function is unstable and may be modified in the future
+    #[wasm_bindgen]
+    pub async fn get_cardano_drep_stake_distribution(&self, hash: &str) -> WasmResult {
+        self.guard_unstable()?;
+
+        let result = self
+            .client
+            .cardano_drep_stake_distribution()
+            .get(hash)
+            .await
+            .map_err(|err| format!("{err:?}"))?
+            .ok_or(JsValue::from_str(&format!(
+                "No cardano DRep stake distribution found for hash: '{hash}'"
+            )))?;
+
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
+    /// Call the client to get a cardano DRep stake distribution from an epoch
+    /// The epoch represents the epoch at which the Cardano DRep stake distribution is computed by the Cardano node
+    ///
+    /// Warning: this function is unstable and may be modified in the future
+    #[wasm_bindgen]
+    pub async fn get_cardano_drep_stake_distribution_by_epoch(&self, epoch: u64) -> WasmResult {
+        self.guard_unstable()?;
+
+        let result = self
+            .client
+            .cardano_drep_stake_distribution()
+            .get_by_epoch(Epoch(epoch))
+            .await
+            .map_err(|err| format!("{err:?}"))?
+            .ok_or(JsValue::from_str(&format!(
+                "No cardano DRep stake distribution found for epoch: '{epoch}'"
+            )))?;
+
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
+    /// Call the client for the list of available cardano DRep stake distributions
+    ///
+    /// Warning: this function is unstable and may be modified in the future
+    #[wasm_bindgen]
+    pub async fn list_cardano_drep_stake_distributions(&self) -> WasmResult {
+        self.guard_unstable()?;
+
+        let result = self
+            .client
+            .cardano_drep_stake_distribution()
+            .list()
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
+    /// Call the client to compute a cardano DRep stake distribution message
+    ///
+    /// Warning: this function is unstable and may be modified in the future
+    #[wasm_bindgen]
+    pub async fn compute_cardano_drep_stake_distribution_message(
+        &self,
+        certificate: JsValue,
+        cardano_drep_stake_distribution: JsValue,
+    ) -> WasmResult {
+        self.guard_unstable()?;
+
+        let certificate =
+            serde_wasm_bindgen::from_value(certificate).map_err(|err| format!("{err:?}"))?;
+        let cardano_drep_stake_distribution =
+            serde_wasm_bindgen::from_value(cardano_drep_stake_distribution)
+                .map_err(|err| format!("{err:?}"))?;
+        let result = self
+            .client
+            .cardano_drep_stake_distribution()
+            .compute_message(&certificate, &cardano_drep_stake_distribution)
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
+    /// Call the client to download a snapshot into a directory of the browser Origin Private
+    /// File System, computing its digest while it's streamed and verifying it against its
+    /// certificate
+    ///
+    /// Only gzip compressed snapshots are supported, the download progress is reported with
+    /// the other events. The directory is removed if the snapshot can't be verified.
+    ///
+    /// Warning: this function is unstable and may be modified in the future
+    #[wasm_bindgen]
+    pub async fn download_snapshot_to_opfs(
+        &self,
+        digest: &str,
+        target_directory: &str,
+    ) -> WasmResult {
+        self.guard_unstable()?;
+        if target_directory.trim_matches('/').is_empty() {
+            return Err(JsValue::from_str(
+                "The target directory must not be the root of the Origin Private File System",
+            ));
+        }
+
+        let snapshot = self
+            .client
+            .snapshot()
+            .get(digest)
+            .await
+            .map_err(|err| format!("{err:?}"))?
+            .ok_or(JsValue::from_str(&format!(
+                "No snapshot found for digest: '{digest}'"
+            )))?;
+        let certificate = self
+            .client
+            .certificate()
+            .verify_chain(&snapshot.certificate_hash)
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+        let root = OpfsDirectory::open_root()
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+
+        if let Err(err) = self
+            .download_snapshot_to_opfs_and_verify(&snapshot, &certificate, &root, target_directory)
+            .await
+        {
+            let _ = root.remove_entry(target_directory).await;
+            return Err(format!("{err:?}").into());
+        }
+
+        Ok(serde_wasm_bindgen::to_value(&snapshot)?)
+    }
+
+    async fn download_snapshot_to_opfs_and_verify(
+        &self,
+        snapshot: &Snapshot,
+        certificate: &MithrilCertificate,
+        root: &OpfsDirectory,
+        target_directory: &str,
+    ) -> MithrilResult<()> {
+        let directory = root.get_or_create_directory(target_directory).await?;
+        let computed_digest = OpfsSnapshotDownloader::new(self.feedback_sender.clone())
+            .download_unpack(snapshot, &directory)
+            .await?;
+
+        let mut message = certificate.protocol_message.clone();
+        message.set_message_part(
+            ProtocolMessagePartKey::SnapshotDigest,
+            computed_digest.clone(),
+        );
+        if !certificate.match_message(&message) {
+            return Err(anyhow::anyhow!(
+                "The digest '{computed_digest}' computed from the downloaded snapshot does not match the certificate '{}' of the snapshot '{}'",
+                certificate.hash,
+                snapshot.digest
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -447,6 +654,47 @@ mod tests {
 
     wasm_bindgen_test_configure!(run_in_browser);
 
+    #[wasm_bindgen_test]
+    async fn build_mithril_client_with_several_aggregators() {
+        let aggregator_endpoint = format!(
+            "http://{}:{}/aggregator",
+            FAKE_AGGREGATOR_IP, FAKE_AGGREGATOR_PORT
+        );
+        let options = ClientOptions::new(None).with_required_aggregators_agreement(2);
+        let options_js_value = serde_wasm_bindgen::to_value(&options).unwrap();
+        let client = MithrilClient::new_with_aggregators(
+            vec![aggregator_endpoint.clone(), aggregator_endpoint],
+            GENESIS_VERIFICATION_KEY,
+            options_js_value,
+        );
+
+        client
+            .get_mithril_certificate(test_data::certificate_hashes()[0])
+            .await
+            .expect("get_mithril_certificate should return a value");
+    }
+
+    #[wasm_bindgen_test]
+    async fn get_mithril_certificate_twice_with_response_cache() {
+        let options = ClientOptions::new(None).with_response_cache(true);
+        let options_js_value = serde_wasm_bindgen::to_value(&options).unwrap();
+        let client = MithrilClient::new(
+            &format!(
+                "http://{}:{}/aggregator",
+                FAKE_AGGREGATOR_IP, FAKE_AGGREGATOR_PORT
+            ),
+            GENESIS_VERIFICATION_KEY,
+            options_js_value,
+        );
+
+        for _ in 0..2 {
+            client
+                .get_mithril_certificate(test_data::certificate_hashes()[0])
+                .await
+                .expect("get_mithril_certificate should return a value");
+        }
+    }
+
     #[wasm_bindgen_test]
     fn build_mithril_client_with_custom_http_header() {
         let mut http_headers = HashMap::new();
@@ -814,4 +1062,40 @@ mod tests {
         serde_wasm_bindgen::from_value::<ProtocolMessage>(message_js_value)
             .expect("conversion should not fail");
     }
+
+    #[wasm_bindgen_test]
+    async fn download_snapshot_to_opfs_should_unpack_and_verify_the_snapshot() {
+        let digest = test_data::snapshot_archive_digests()[0];
+        let target_directory = "download_snapshot_to_opfs_should_unpack_and_verify_the_snapshot";
+
+        let snapshot_js_value = get_mithril_client_unstable()
+            .download_snapshot_to_opfs(digest, target_directory)
+            .await
+            .expect("download_snapshot_to_opfs should not fail");
+        let snapshot = serde_wasm_bindgen::from_value::<Snapshot>(snapshot_js_value)
+            .expect("conversion should not fail");
+        assert_eq!(snapshot.digest, digest);
+
+        let root = OpfsDirectory::open_root().await.unwrap();
+        root.remove_entry(&format!("{target_directory}/immutable/00000.chunk"))
+            .await
+            .expect("the immutable files of the snapshot should have been unpacked");
+        root.remove_entry(target_directory).await.unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn download_snapshot_to_opfs_should_fail_with_unknown_digest() {
+        get_mithril_client_unstable()
+            .download_snapshot_to_opfs("whatever", "cardano-db")
+            .await
+            .expect_err("download_snapshot_to_opfs should fail");
+    }
+
+    #[wasm_bindgen_test]
+    async fn download_snapshot_to_opfs_should_fail_if_the_target_is_the_opfs_root() {
+        get_mithril_client_unstable()
+            .download_snapshot_to_opfs(test_data::snapshot_digests()[0], "/")
+            .await
+            .expect_err("download_snapshot_to_opfs should fail");
+    }
 }
@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use mithril_client::{
+    response_cache::{AggregatorResponseCache, CachedResponse},
+    MithrilResult,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbFactory, IdbRequest, IdbTransactionMode};
+
+const OBJECT_STORE_NAME: &str = "aggregator_responses";
+
+/// An [AggregatorResponseCache] that keeps the responses in the IndexedDB of the browser, so
+/// they are kept across page loads.
+pub struct IndexedDbAggregatorResponseCache {
+    database_name: String,
+}
+
+impl IndexedDbAggregatorResponseCache {
+    /// Constructs a new `IndexedDbAggregatorResponseCache` that uses the given IndexedDB database.
+    pub fn new(database_name: &str) -> Self {
+        Self {
+            database_name: database_name.to_string(),
+        }
+    }
+
+    fn js_error(context: &str, error: JsValue) -> anyhow::Error {
+        anyhow::anyhow!("{context}: {error:?}")
+    }
+
+    /// Wait for the completion of an IndexedDB request and return its result.
+    async fn request_result(request: &IdbRequest) -> Result<JsValue, JsValue> {
+        let promise = js_sys::Promise::new(&mut |resolve, reject| {
+            let success_request = request.clone();
+            request.set_onsuccess(Some(
+                wasm_bindgen::closure::Closure::once_into_js(move |_: web_sys::Event| {
+                    let _ = resolve.call1(
+                        &JsValue::NULL,
+                        &success_request.result().unwrap_or(JsValue::UNDEFINED),
+                    );
+                })
+                .unchecked_ref(),
+            ));
+            let error_request = request.clone();
+            request.set_onerror(Some(
+                wasm_bindgen::closure::Closure::once_into_js(move |_: web_sys::Event| {
+                    let error = error_request
+                        .error()
+                        .ok()
+                        .flatten()
+                        .map(JsValue::from)
+                        .unwrap_or(JsValue::UNDEFINED);
+                    let _ = reject.call1(&JsValue::NULL, &error);
+                })
+                .unchecked_ref(),
+            ));
+        });
+
+        JsFuture::from(promise).await
+    }
+
+    async fn open_database(&self) -> MithrilResult<IdbDatabase> {
+        // Use the global scope instead of the window so the cache is also available in workers
+        let factory: IdbFactory = js_sys::Reflect::get(&js_sys::global(), &"indexedDB".into())
+            .map_err(|e| Self::js_error("IndexedDB is not available", e))?
+            .dyn_into()
+            .map_err(|e| Self::js_error("IndexedDB is not available", e))?;
+        let open_request = factory
+            .open_with_u32(&self.database_name, 1)
+            .map_err(|e| Self::js_error("Could not open the IndexedDB database", e))?;
+
+        let upgrade_request = open_request.clone();
+        open_request.set_onupgradeneeded(Some(
+            wasm_bindgen::closure::Closure::once_into_js(move |_: web_sys::Event| {
+                if let Ok(database) = upgrade_request.result() {
+                    let database: IdbDatabase = database.unchecked_into();
+                    let _ = database.create_object_store(OBJECT_STORE_NAME);
+                }
+            })
+            .unchecked_ref(),
+        ));
+
+        let database = Self::request_result(&open_request)
+            .await
+            .map_err(|e| Self::js_error("Could not open the IndexedDB database", e))?;
+
+        Ok(database.unchecked_into())
+    }
+}
+
+#[async_trait(?Send)]
+impl AggregatorResponseCache for IndexedDbAggregatorResponseCache {
+    async fn get(&self, key: &str) -> MithrilResult<Option<CachedResponse>> {
+        let database = self.open_database().await?;
+        let request = database
+            .transaction_with_str(OBJECT_STORE_NAME)
+            .and_then(|transaction| transaction.object_store(OBJECT_STORE_NAME))
+            .and_then(|store| store.get(&JsValue::from_str(key)))
+            .map_err(|e| Self::js_error("Could not read the IndexedDB cache", e))?;
+        let value = Self::request_result(&request)
+            .await
+            .map_err(|e| Self::js_error("Could not read the IndexedDB cache", e))?;
+        database.close();
+
+        match value.as_string() {
+            Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn store(&self, key: &str, response: &CachedResponse) -> MithrilResult<()> {
+        let value = JsValue::from_str(&serde_json::to_string(response)?);
+        let database = self.open_database().await?;
+        let request = database
+            .transaction_with_str_and_mode(OBJECT_STORE_NAME, IdbTransactionMode::Readwrite)
+            .and_then(|transaction| transaction.object_store(OBJECT_STORE_NAME))
+            .and_then(|store| store.put_with_key(&value, &JsValue::from_str(key)))
+            .map_err(|e| Self::js_error("Could not write to the IndexedDB cache", e))?;
+        Self::request_result(&request)
+            .await
+            .map_err(|e| Self::js_error("Could not write to the IndexedDB cache", e))?;
+        database.close();
+
+        Ok(())
+    }
+}
@@ -3,6 +3,10 @@
 #![cfg_attr(target_family = "wasm", warn(missing_docs))]
 
 mod client_wasm;
+mod indexed_db_response_cache;
+mod opfs;
+mod opfs_snapshot_downloader;
+mod streaming_unpacker;
 
 pub use client_wasm::MithrilClient;
 
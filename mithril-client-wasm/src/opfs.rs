@@ -0,0 +1,130 @@
+use anyhow::anyhow;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    FileSystemDirectoryHandle, FileSystemFileHandle, FileSystemGetDirectoryOptions,
+    FileSystemGetFileOptions, FileSystemRemoveOptions, FileSystemWritableFileStream,
+    StorageManager,
+};
+
+use mithril_client::MithrilResult;
+
+fn js_error(context: &str, error: JsValue) -> anyhow::Error {
+    anyhow!("{context}: {error:?}")
+}
+
+/// A directory of the Origin Private File System (OPFS) of the browser.
+#[derive(Clone)]
+pub(crate) struct OpfsDirectory {
+    handle: FileSystemDirectoryHandle,
+}
+
+impl OpfsDirectory {
+    /// Open the root directory of the Origin Private File System.
+    pub async fn open_root() -> MithrilResult<Self> {
+        // Use the global scope instead of the window so the OPFS is also available in workers
+        let storage: StorageManager = js_sys::Reflect::get(&js_sys::global(), &"navigator".into())
+            .and_then(|navigator| js_sys::Reflect::get(&navigator, &"storage".into()))
+            .and_then(|storage| storage.dyn_into())
+            .map_err(|e| js_error("The browser storage is not available", e))?;
+        let handle = JsFuture::from(storage.get_directory())
+            .await
+            .map_err(|e| js_error("Could not open the Origin Private File System", e))?;
+
+        Ok(Self {
+            handle: handle.unchecked_into(),
+        })
+    }
+
+    /// Get the sub directory at the given relative path, creating it and its parents if needed.
+    pub async fn get_or_create_directory(&self, path: &str) -> MithrilResult<Self> {
+        let options = FileSystemGetDirectoryOptions::new();
+        options.set_create(true);
+        let mut handle = self.handle.clone();
+        for name in path.split('/').filter(|name| !name.is_empty()) {
+            handle = JsFuture::from(handle.get_directory_handle_with_options(name, &options))
+                .await
+                .map_err(|e| js_error(&format!("Could not create directory '{path}'"), e))?
+                .unchecked_into();
+        }
+
+        Ok(Self { handle })
+    }
+
+    /// Create, or truncate if it exists, the file at the given relative path and open it for
+    /// writing.
+    pub async fn create_file(&self, path: &str) -> MithrilResult<OpfsFileWriter> {
+        let (directory, filename) = match path.rsplit_once('/') {
+            Some((directory, filename)) => {
+                (self.get_or_create_directory(directory).await?, filename)
+            }
+            None => (self.clone(), path),
+        };
+        let options = FileSystemGetFileOptions::new();
+        options.set_create(true);
+        let file: FileSystemFileHandle = JsFuture::from(
+            directory
+                .handle
+                .get_file_handle_with_options(filename, &options),
+        )
+        .await
+        .map_err(|e| js_error(&format!("Could not create file '{path}'"), e))?
+        .unchecked_into();
+        let stream = JsFuture::from(file.create_writable())
+            .await
+            .map_err(|e| js_error(&format!("Could not open file '{path}' for writing"), e))?;
+
+        Ok(OpfsFileWriter {
+            path: path.to_string(),
+            stream: stream.unchecked_into(),
+        })
+    }
+
+    /// Remove the entry at the given relative path, and all its content if it's a directory.
+    pub async fn remove_entry(&self, path: &str) -> MithrilResult<()> {
+        let path = path.trim_matches('/');
+        let (directory, name) = match path.rsplit_once('/') {
+            Some((directory, name)) => (self.get_or_create_directory(directory).await?, name),
+            None => (self.clone(), path),
+        };
+        let options = FileSystemRemoveOptions::new();
+        options.set_recursive(true);
+        JsFuture::from(directory.handle.remove_entry_with_options(name, &options))
+            .await
+            .map_err(|e| js_error(&format!("Could not remove '{path}'"), e))?;
+
+        Ok(())
+    }
+}
+
+/// A file of the Origin Private File System opened for writing.
+///
+/// The written content is only visible once the file is [closed][OpfsFileWriter::close].
+pub(crate) struct OpfsFileWriter {
+    path: String,
+    stream: FileSystemWritableFileStream,
+}
+
+impl OpfsFileWriter {
+    /// Append the given data to the file.
+    pub async fn write(&self, data: &[u8]) -> MithrilResult<()> {
+        let promise = self
+            .stream
+            .write_with_u8_array(data)
+            .map_err(|e| js_error(&format!("Could not write to file '{}'", self.path), e))?;
+        JsFuture::from(promise)
+            .await
+            .map_err(|e| js_error(&format!("Could not write to file '{}'", self.path), e))?;
+
+        Ok(())
+    }
+
+    /// Close the file, committing its content.
+    pub async fn close(self) -> MithrilResult<()> {
+        JsFuture::from(self.stream.close())
+            .await
+            .map_err(|e| js_error(&format!("Could not close file '{}'", self.path), e))?;
+
+        Ok(())
+    }
+}
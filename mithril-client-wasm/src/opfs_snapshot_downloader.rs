@@ -0,0 +1,149 @@
+use anyhow::{anyhow, Context};
+use futures::StreamExt;
+use reqwest::StatusCode;
+
+use mithril_client::{
+    common::{CompressionAlgorithm, HexEncodedDigest},
+    feedback::{FeedbackSender, MithrilEvent},
+    MithrilResult, Snapshot,
+};
+
+use crate::opfs::{OpfsDirectory, OpfsFileWriter};
+use crate::streaming_unpacker::{StreamingSnapshotDigester, StreamingTarGzUnpacker, UnpackedEntry};
+
+/// Download a snapshot archive and unpack it into a directory of the Origin Private File
+/// System of the browser, computing the snapshot digest while the archive is streamed.
+pub(crate) struct OpfsSnapshotDownloader {
+    http_client: reqwest::Client,
+    feedback_sender: FeedbackSender,
+}
+
+impl OpfsSnapshotDownloader {
+    /// Constructs a new `OpfsSnapshotDownloader`.
+    pub fn new(feedback_sender: FeedbackSender) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            feedback_sender,
+        }
+    }
+
+    /// Download and unpack the given snapshot into the given directory, trying its locations in
+    /// order, then return the digest computed from the unpacked immutable files.
+    pub async fn download_unpack(
+        &self,
+        snapshot: &Snapshot,
+        target_directory: &OpfsDirectory,
+    ) -> MithrilResult<HexEncodedDigest> {
+        if snapshot.compression_algorithm.unwrap_or_default() != CompressionAlgorithm::Gzip {
+            return Err(anyhow!(
+                "Only gzip compressed snapshots can be unpacked in the browser, the snapshot '{}' is compressed with '{}'",
+                snapshot.digest,
+                snapshot.compression_algorithm.unwrap_or_default()
+            ));
+        }
+
+        for location in &snapshot.locations {
+            let response = match self.http_client.get(location).send().await {
+                Ok(response) if response.status() == StatusCode::OK => response,
+                _ => continue,
+            };
+
+            let download_id = MithrilEvent::new_snapshot_download_id();
+            self.feedback_sender
+                .send_event(MithrilEvent::SnapshotDownloadStarted {
+                    digest: snapshot.digest.clone(),
+                    download_id: download_id.clone(),
+                    size: snapshot.size,
+                })
+                .await;
+            let digest = self
+                .unpack_response(response, snapshot, target_directory, &download_id)
+                .await
+                .with_context(|| {
+                    format!("Could not download and unpack the snapshot from location '{location}'")
+                })?;
+            self.feedback_sender
+                .send_event(MithrilEvent::SnapshotDownloadCompleted { download_id })
+                .await;
+
+            return Ok(digest);
+        }
+
+        Err(anyhow!(
+            "Could not find a working download location for the snapshot digest '{}', tried location: {{'{}'}}.",
+            snapshot.digest,
+            snapshot.locations.join(", ")
+        ))
+    }
+
+    async fn unpack_response(
+        &self,
+        response: reqwest::Response,
+        snapshot: &Snapshot,
+        target_directory: &OpfsDirectory,
+        download_id: &str,
+    ) -> MithrilResult<HexEncodedDigest> {
+        let mut unpacker = StreamingTarGzUnpacker::new();
+        let mut digester = StreamingSnapshotDigester::new(snapshot.beacon.clone());
+        let mut current_file = None;
+        let mut downloaded_bytes = 0;
+        let mut stream = response.bytes_stream();
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.with_context(|| "Could not read the snapshot archive")?;
+            let entries = unpacker.unpack_chunk(&chunk)?;
+            Self::write_entries(entries, target_directory, &mut current_file, &mut digester)
+                .await?;
+
+            downloaded_bytes += chunk.len() as u64;
+            self.feedback_sender
+                .send_event(MithrilEvent::SnapshotDownloadProgress {
+                    download_id: download_id.to_owned(),
+                    downloaded_bytes,
+                    size: snapshot.size,
+                })
+                .await;
+        }
+        let entries = unpacker.finish()?;
+        Self::write_entries(entries, target_directory, &mut current_file, &mut digester).await?;
+
+        if digester.immutable_files_count() == 0 {
+            return Err(anyhow!(
+                "The snapshot archive does not contain any immutable file"
+            ));
+        }
+
+        Ok(digester.compute_digest())
+    }
+
+    async fn write_entries(
+        entries: Vec<UnpackedEntry>,
+        target_directory: &OpfsDirectory,
+        current_file: &mut Option<OpfsFileWriter>,
+        digester: &mut StreamingSnapshotDigester,
+    ) -> MithrilResult<()> {
+        for entry in entries {
+            digester.update(&entry);
+            match entry {
+                UnpackedEntry::Directory { path } => {
+                    target_directory.get_or_create_directory(&path).await?;
+                }
+                UnpackedEntry::FileStart { path, .. } => {
+                    *current_file = Some(target_directory.create_file(&path).await?);
+                }
+                UnpackedEntry::FileData(data) => {
+                    if let Some(file) = current_file {
+                        file.write(&data).await?;
+                    }
+                }
+                UnpackedEntry::FileEnd => {
+                    if let Some(file) = current_file.take() {
+                        file.close().await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
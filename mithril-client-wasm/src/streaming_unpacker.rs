@@ -0,0 +1,551 @@
+use anyhow::anyhow;
+use flate2::write::GzDecoder;
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, io::Write};
+
+use mithril_client::{
+    common::{CardanoDbBeacon, HexEncodedDigest, ImmutableFileName, ImmutableFileNumber},
+    MithrilResult,
+};
+
+const TAR_BLOCK_SIZE: usize = 512;
+const IMMUTABLE_DIRECTORY_NAME: &str = "immutable";
+const IMMUTABLE_FILE_EXTENSIONS: [&str; 3] = ["chunk", "primary", "secondary"];
+
+/// An entry read from a snapshot archive by a [StreamingTarGzUnpacker].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum UnpackedEntry {
+    /// A directory, its path is relative to the root of the archive
+    Directory { path: String },
+
+    /// The beginning of a regular file, its path is relative to the root of the archive
+    FileStart { path: String, size: u64 },
+
+    /// A part of the content of the file that was started by the last [UnpackedEntry::FileStart]
+    FileData(Vec<u8>),
+
+    /// The end of the file that was started by the last [UnpackedEntry::FileStart]
+    FileEnd,
+}
+
+/// Unpack a gzip compressed tar archive pushed chunk by chunk, so it can be unpacked while it's
+/// downloaded without the need to keep it whole in memory nor to write it on a file system.
+pub(crate) struct StreamingTarGzUnpacker {
+    decoder: GzDecoder<Vec<u8>>,
+    tar_parser: TarStreamParser,
+}
+
+impl StreamingTarGzUnpacker {
+    /// Constructs a new `StreamingTarGzUnpacker`.
+    pub fn new() -> Self {
+        Self {
+            decoder: GzDecoder::new(Vec::new()),
+            tar_parser: TarStreamParser::new(),
+        }
+    }
+
+    /// Decompress the given chunk of the archive and return the entries that it completes.
+    pub fn unpack_chunk(&mut self, chunk: &[u8]) -> MithrilResult<Vec<UnpackedEntry>> {
+        self.decoder
+            .write_all(chunk)
+            .map_err(|e| anyhow!(e).context("Could not decompress the snapshot archive"))?;
+        let decompressed = std::mem::take(self.decoder.get_mut());
+
+        self.tar_parser.parse(&decompressed)
+    }
+
+    /// Signal the end of the archive, fails if it was truncated.
+    pub fn finish(mut self) -> MithrilResult<Vec<UnpackedEntry>> {
+        self.decoder
+            .try_finish()
+            .map_err(|e| anyhow!(e).context("Could not decompress the snapshot archive"))?;
+        let decompressed = std::mem::take(self.decoder.get_mut());
+        let entries = self.tar_parser.parse(&decompressed)?;
+
+        if !self.tar_parser.is_complete() {
+            return Err(anyhow!("The snapshot archive is truncated"));
+        }
+
+        Ok(entries)
+    }
+}
+
+enum TarParserState {
+    Header,
+    FileData {
+        remaining: u64,
+        padding: u64,
+    },
+    Metadata {
+        kind: u8,
+        remaining: u64,
+        padding: u64,
+        content: Vec<u8>,
+    },
+    Skip {
+        remaining: u64,
+    },
+    End,
+}
+
+/// Minimal push based parser of the ustar/GNU tar formats, which are the formats of the snapshot
+/// archives, supporting only regular files and directories.
+struct TarStreamParser {
+    buffer: Vec<u8>,
+    state: TarParserState,
+    long_path: Option<String>,
+}
+
+impl TarStreamParser {
+    fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            state: TarParserState::Header,
+            long_path: None,
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        matches!(self.state, TarParserState::End)
+            || (matches!(self.state, TarParserState::Header) && self.buffer.is_empty())
+    }
+
+    fn parse(&mut self, data: &[u8]) -> MithrilResult<Vec<UnpackedEntry>> {
+        let mut entries = vec![];
+        self.buffer.extend_from_slice(data);
+        let mut position = 0;
+
+        loop {
+            let available = (self.buffer.len() - position) as u64;
+            match &mut self.state {
+                TarParserState::End => {
+                    position = self.buffer.len();
+                    break;
+                }
+                TarParserState::Header => {
+                    if available < TAR_BLOCK_SIZE as u64 {
+                        break;
+                    }
+                    let mut block = [0; TAR_BLOCK_SIZE];
+                    block.copy_from_slice(&self.buffer[position..position + TAR_BLOCK_SIZE]);
+                    position += TAR_BLOCK_SIZE;
+                    self.state = self.parse_header(&block, &mut entries)?;
+                }
+                TarParserState::FileData { remaining, padding } => {
+                    let read = available.min(*remaining);
+                    if read == 0 && *remaining > 0 {
+                        break;
+                    }
+                    if read > 0 {
+                        entries.push(UnpackedEntry::FileData(
+                            self.buffer[position..position + read as usize].to_vec(),
+                        ));
+                        position += read as usize;
+                        *remaining -= read;
+                    }
+                    if *remaining == 0 {
+                        entries.push(UnpackedEntry::FileEnd);
+                        self.state = TarParserState::Skip {
+                            remaining: *padding,
+                        };
+                    }
+                }
+                TarParserState::Metadata {
+                    kind,
+                    remaining,
+                    padding,
+                    content,
+                } => {
+                    let read = available.min(*remaining);
+                    content.extend_from_slice(&self.buffer[position..position + read as usize]);
+                    position += read as usize;
+                    *remaining -= read;
+                    if *remaining > 0 {
+                        break;
+                    }
+                    self.long_path = match *kind {
+                        b'L' => Some(Self::parse_str(content)),
+                        _ => Self::parse_pax_path(content),
+                    };
+                    self.state = TarParserState::Skip {
+                        remaining: *padding,
+                    };
+                }
+                TarParserState::Skip { remaining } => {
+                    let read = available.min(*remaining);
+                    position += read as usize;
+                    *remaining -= read;
+                    if *remaining > 0 {
+                        break;
+                    }
+                    self.state = TarParserState::Header;
+                }
+            }
+        }
+
+        self.buffer.drain(..position);
+
+        Ok(entries)
+    }
+
+    fn parse_header(
+        &mut self,
+        block: &[u8],
+        entries: &mut Vec<UnpackedEntry>,
+    ) -> MithrilResult<TarParserState> {
+        // The end of a tar archive is marked by empty blocks
+        if block.iter().all(|b| *b == 0) {
+            return Ok(TarParserState::End);
+        }
+
+        let expected_checksum = Self::parse_number(&block[148..156])?;
+        let checksum: u64 = block
+            .iter()
+            .enumerate()
+            .map(|(i, b)| match i {
+                // The checksum field itself is summed as if it was filled with spaces
+                148..156 => b' ' as u64,
+                _ => *b as u64,
+            })
+            .sum();
+        if checksum != expected_checksum {
+            return Err(anyhow!(
+                "Invalid tar header checksum in the snapshot archive"
+            ));
+        }
+
+        let size = Self::parse_number(&block[124..136])?;
+        let padding =
+            (TAR_BLOCK_SIZE as u64 - size % TAR_BLOCK_SIZE as u64) % TAR_BLOCK_SIZE as u64;
+        let kind = block[156];
+
+        match kind {
+            b'L' | b'x' => {
+                return Ok(TarParserState::Metadata {
+                    kind,
+                    remaining: size,
+                    padding,
+                    content: vec![],
+                })
+            }
+            b'0' | b'\0' | b'7' | b'5' => {}
+            // Other entries (links, devices, global headers, ...) are not part of a snapshot
+            _ => {
+                self.long_path = None;
+                return Ok(TarParserState::Skip {
+                    remaining: size + padding,
+                });
+            }
+        }
+
+        let path = match self.long_path.take() {
+            Some(path) => path,
+            None => {
+                let name = Self::parse_str(&block[0..100]);
+                // Only the POSIX ustar format has a prefix field
+                match &block[257..263] {
+                    b"ustar\0" if block[345] != 0 => {
+                        format!("{}/{name}", Self::parse_str(&block[345..500]))
+                    }
+                    _ => name,
+                }
+            }
+        };
+        let path = Self::sanitize_path(&path)?;
+
+        if kind == b'5' {
+            entries.push(UnpackedEntry::Directory { path });
+            return Ok(TarParserState::Skip {
+                remaining: size + padding,
+            });
+        }
+
+        entries.push(UnpackedEntry::FileStart { path, size });
+        if size == 0 {
+            entries.push(UnpackedEntry::FileEnd);
+            return Ok(TarParserState::Header);
+        }
+
+        Ok(TarParserState::FileData {
+            remaining: size,
+            padding,
+        })
+    }
+
+    fn parse_str(field: &[u8]) -> String {
+        let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+        String::from_utf8_lossy(&field[..end]).to_string()
+    }
+
+    fn parse_number(field: &[u8]) -> MithrilResult<u64> {
+        // GNU base-256 encoding, used for numbers that do not fit in the octal field
+        if field[0] & 0x80 != 0 {
+            return Ok(field[1..]
+                .iter()
+                .fold((field[0] & 0x7f) as u64, |n, b| (n << 8) | *b as u64));
+        }
+
+        let octal = Self::parse_str(field);
+        let octal = octal.trim_matches(|c: char| c == ' ' || c == '\0');
+        if octal.is_empty() {
+            return Ok(0);
+        }
+
+        u64::from_str_radix(octal, 8).map_err(|e| {
+            anyhow!(e).context("Invalid number in a tar header of the snapshot archive")
+        })
+    }
+
+    fn parse_pax_path(content: &[u8]) -> Option<String> {
+        // Each pax record is formatted as "<length> <key>=<value>\n"
+        String::from_utf8_lossy(content)
+            .lines()
+            .filter_map(|record| record.split_once(' ').map(|(_, key_value)| key_value))
+            .find_map(|key_value| key_value.strip_prefix("path=").map(str::to_string))
+    }
+
+    fn sanitize_path(path: &str) -> MithrilResult<String> {
+        if path.starts_with('/') || path.split('/').any(|component| component == "..") {
+            return Err(anyhow!(
+                "Unsafe path in the snapshot archive, it would be unpacked outside of the target directory: '{path}'"
+            ));
+        }
+
+        Ok(path
+            .split('/')
+            .filter(|component| !component.is_empty() && *component != ".")
+            .collect::<Vec<_>>()
+            .join("/"))
+    }
+}
+
+/// Compute the digest of a snapshot from the content of its immutable files while they are
+/// unpacked, following the same algorithm than the `CardanoImmutableDigester`.
+pub(crate) struct StreamingSnapshotDigester {
+    beacon: CardanoDbBeacon,
+    current_file: Option<(ImmutableFileNumber, ImmutableFileName, Sha256)>,
+    immutable_files_digests: BTreeMap<(ImmutableFileNumber, ImmutableFileName), HexEncodedDigest>,
+}
+
+impl StreamingSnapshotDigester {
+    /// Constructs a new `StreamingSnapshotDigester` for the given beacon.
+    pub fn new(beacon: CardanoDbBeacon) -> Self {
+        Self {
+            beacon,
+            current_file: None,
+            immutable_files_digests: BTreeMap::new(),
+        }
+    }
+
+    /// Update the digest with the given unpacked entry.
+    pub fn update(&mut self, entry: &UnpackedEntry) {
+        match entry {
+            UnpackedEntry::FileStart { path, .. } => {
+                self.current_file = Self::parse_immutable_file(path)
+                    .filter(|(number, _)| *number <= self.beacon.immutable_file_number)
+                    .map(|(number, filename)| (number, filename, Sha256::new()));
+            }
+            UnpackedEntry::FileData(data) => {
+                if let Some((_, _, hasher)) = &mut self.current_file {
+                    hasher.update(data);
+                }
+            }
+            UnpackedEntry::FileEnd => {
+                if let Some((number, filename, hasher)) = self.current_file.take() {
+                    self.immutable_files_digests
+                        .insert((number, filename), hex::encode(hasher.finalize()));
+                }
+            }
+            UnpackedEntry::Directory { .. } => {}
+        }
+    }
+
+    /// Number of immutable files that have been digested.
+    pub fn immutable_files_count(&self) -> usize {
+        self.immutable_files_digests.len()
+    }
+
+    /// Compute the digest of the snapshot from the digests of its immutable files.
+    pub fn compute_digest(&self) -> HexEncodedDigest {
+        let mut hasher = Sha256::new();
+        hasher.update(self.beacon.compute_hash().as_bytes());
+        for digest in self.immutable_files_digests.values() {
+            hasher.update(digest);
+        }
+
+        hex::encode(hasher.finalize())
+    }
+
+    fn parse_immutable_file(path: &str) -> Option<(ImmutableFileNumber, ImmutableFileName)> {
+        let (directory, filename) = path.rsplit_once('/')?;
+        if directory.rsplit('/').next() != Some(IMMUTABLE_DIRECTORY_NAME) {
+            return None;
+        }
+        let (stem, extension) = filename.rsplit_once('.')?;
+        if !IMMUTABLE_FILE_EXTENSIONS.contains(&extension) {
+            return None;
+        }
+
+        stem.parse::<ImmutableFileNumber>()
+            .ok()
+            .map(|number| (number, filename.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flate2::{write::GzEncoder, Compression};
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    fn tar_header(path: &str, kind: u8, size: usize) -> Vec<u8> {
+        let mut header = vec![0; TAR_BLOCK_SIZE];
+        header[..path.len()].copy_from_slice(path.as_bytes());
+        header[100..107].copy_from_slice(b"0000644");
+        header[124..135].copy_from_slice(format!("{size:011o}").as_bytes());
+        header[156] = kind;
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+        header[148..156].copy_from_slice(b"        ");
+        let checksum: u64 = header.iter().map(|b| *b as u64).sum();
+        header[148..155].copy_from_slice(format!("{checksum:06o}\0").as_bytes());
+
+        header
+    }
+
+    fn tar_gz_archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut tar = vec![];
+        for (path, content) in files {
+            if path.ends_with('/') {
+                tar.extend(tar_header(path, b'5', 0));
+                continue;
+            }
+            tar.extend(tar_header(path, b'0', content.len()));
+            tar.extend_from_slice(content);
+            tar.resize(tar.len().div_ceil(TAR_BLOCK_SIZE) * TAR_BLOCK_SIZE, 0);
+        }
+        tar.extend(vec![0; TAR_BLOCK_SIZE * 2]);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&tar).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn unpack_by_chunks(archive: &[u8], chunk_size: usize) -> MithrilResult<Vec<UnpackedEntry>> {
+        let mut unpacker = StreamingTarGzUnpacker::new();
+        let mut entries = vec![];
+        for chunk in archive.chunks(chunk_size) {
+            entries.extend(unpacker.unpack_chunk(chunk)?);
+        }
+        entries.extend(unpacker.finish()?);
+
+        Ok(entries)
+    }
+
+    /// Merge the consecutive data entries so the result doesn't depend on the chunks size
+    fn merge_file_data(entries: Vec<UnpackedEntry>) -> Vec<UnpackedEntry> {
+        let mut merged: Vec<UnpackedEntry> = vec![];
+        for entry in entries {
+            match (merged.last_mut(), entry) {
+                (Some(UnpackedEntry::FileData(previous)), UnpackedEntry::FileData(data)) => {
+                    previous.extend(data)
+                }
+                (_, entry) => merged.push(entry),
+            }
+        }
+
+        merged
+    }
+
+    #[wasm_bindgen_test]
+    fn unpack_archive_whatever_the_chunks_size() {
+        let big_content = vec![7; 1500];
+        let archive = tar_gz_archive(&[
+            ("db/", b""),
+            ("db/immutable/", b""),
+            ("db/immutable/00001.chunk", &big_content),
+            ("./db/protocolMagicId", b"42"),
+            ("db/empty", b""),
+        ]);
+        let expected = vec![
+            UnpackedEntry::Directory {
+                path: "db".to_string(),
+            },
+            UnpackedEntry::Directory {
+                path: "db/immutable".to_string(),
+            },
+            UnpackedEntry::FileStart {
+                path: "db/immutable/00001.chunk".to_string(),
+                size: 1500,
+            },
+            UnpackedEntry::FileData(big_content.clone()),
+            UnpackedEntry::FileEnd,
+            UnpackedEntry::FileStart {
+                path: "db/protocolMagicId".to_string(),
+                size: 2,
+            },
+            UnpackedEntry::FileData(b"42".to_vec()),
+            UnpackedEntry::FileEnd,
+            UnpackedEntry::FileStart {
+                path: "db/empty".to_string(),
+                size: 0,
+            },
+            UnpackedEntry::FileEnd,
+        ];
+
+        for chunk_size in [1, 100, 512, archive.len()] {
+            let entries = unpack_by_chunks(&archive, chunk_size).unwrap();
+
+            assert_eq!(
+                expected,
+                merge_file_data(entries),
+                "chunk size: {chunk_size}"
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn unpack_truncated_archive_fails() {
+        let archive = tar_gz_archive(&[("db/immutable/00001.chunk", &[1; 2000])]);
+
+        unpack_by_chunks(&archive[..archive.len() / 2], 100)
+            .expect_err("Unpacking a truncated archive should fail");
+    }
+
+    #[wasm_bindgen_test]
+    fn unpack_archive_with_a_path_outside_of_the_target_directory_fails() {
+        let archive = tar_gz_archive(&[("db/../../outside", b"content")]);
+
+        unpack_by_chunks(&archive, 100)
+            .expect_err("Unpacking an archive with an unsafe path should fail");
+    }
+
+    #[wasm_bindgen_test]
+    fn digest_only_immutable_files_up_to_the_beacon_immutable_file_number() {
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 1);
+        let files: [(&str, &[u8]); 5] = [
+            ("db/immutable/00001.secondary", b"secondary-1"),
+            ("db/immutable/00001.chunk", b"chunk-1"),
+            ("db/immutable/00001.primary", b"primary-1"),
+            ("db/immutable/00002.chunk", b"chunk-2"),
+            ("db/ledger/1", b"ledger"),
+        ];
+        let mut digester = StreamingSnapshotDigester::new(beacon.clone());
+
+        for entry in unpack_by_chunks(&tar_gz_archive(&files), 10).unwrap() {
+            digester.update(&entry);
+        }
+
+        let mut expected_hasher = Sha256::new();
+        expected_hasher.update(beacon.compute_hash().as_bytes());
+        for content in [b"chunk-1".as_slice(), b"primary-1", b"secondary-1"] {
+            expected_hasher.update(hex::encode(Sha256::digest(content)));
+        }
+        assert_eq!(3, digester.immutable_files_count());
+        assert_eq!(
+            hex::encode(expected_hasher.finalize()),
+            digester.compute_digest()
+        );
+    }
+}
@@ -10,12 +10,14 @@
 use anyhow::{anyhow, Context};
 use async_recursion::async_recursion;
 use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::{Response, StatusCode, Url};
 use semver::Version;
-use slog::{debug, Logger};
+use slog::{debug, warn, Logger};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::sync::RwLock;
 
@@ -24,6 +26,8 @@ use mithril_common::MITHRIL_API_VERSION_HEADER;
 
 #[cfg(feature = "unstable")]
 use crate::common::Epoch;
+use crate::feedback::{FeedbackSender, MithrilEvent};
+use crate::response_cache::{AggregatorResponseCache, CachedResponse};
 use crate::{MithrilError, MithrilResult};
 
 /// Error tied with the Aggregator client
@@ -44,11 +48,19 @@ pub enum AggregatorClientError {
     /// HTTP subsystem error
     #[error("HTTP subsystem error")]
     SubsystemError(#[source] MithrilError),
+
+    /// Error raised when not enough aggregators returned the same response to a cross-checked
+    /// request.
+    #[error("Aggregators disagreement")]
+    AggregatorsDisagreement(#[source] MithrilError),
 }
 
 /// What can be read from an [AggregatorClient].
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum AggregatorRequest {
+    /// Get the [features][mithril_common::messages::AggregatorFeaturesMessage] advertised by
+    /// the aggregator on its root route
+    GetAggregatorFeatures,
     /// Get a specific [certificate][crate::MithrilCertificate] from the aggregator
     GetCertificate {
         /// Hash of the certificate to retrieve
@@ -112,12 +124,31 @@ pub enum AggregatorRequest {
     /// Lists the aggregator [Cardano stake distribution][crate::CardanoStakeDistribution]
     #[cfg(feature = "unstable")]
     ListCardanoStakeDistributions,
+
+    /// Get a specific [Cardano DRep stake distribution][crate::CardanoDRepStakeDistribution] from the aggregator by hash
+    #[cfg(feature = "unstable")]
+    GetCardanoDRepStakeDistribution {
+        /// Hash of the Cardano DRep stake distribution to retrieve
+        hash: String,
+    },
+
+    /// Get a specific [Cardano DRep stake distribution][crate::CardanoDRepStakeDistribution] from the aggregator by epoch
+    #[cfg(feature = "unstable")]
+    GetCardanoDRepStakeDistributionByEpoch {
+        /// Epoch at which the Cardano DRep stake distribution is computed by the Cardano node
+        epoch: Epoch,
+    },
+
+    /// Lists the aggregator [Cardano DRep stake distribution][crate::CardanoDRepStakeDistribution]
+    #[cfg(feature = "unstable")]
+    ListCardanoDRepStakeDistributions,
 }
 
 impl AggregatorRequest {
     /// Get the request route relative to the aggregator root endpoint.
     pub fn route(&self) -> String {
         match self {
+            AggregatorRequest::GetAggregatorFeatures => String::new(),
             AggregatorRequest::GetCertificate { hash } => {
                 format!("certificate/{hash}")
             }
@@ -162,6 +193,18 @@ impl AggregatorRequest {
             AggregatorRequest::ListCardanoStakeDistributions => {
                 "artifact/cardano-stake-distributions".to_string()
             }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::GetCardanoDRepStakeDistribution { hash } => {
+                format!("artifact/cardano-drep-stake-distribution/{hash}")
+            }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::GetCardanoDRepStakeDistributionByEpoch { epoch } => {
+                format!("artifact/cardano-drep-stake-distribution/epoch/{epoch}")
+            }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::ListCardanoDRepStakeDistributions => {
+                "artifact/cardano-drep-stake-distributions".to_string()
+            }
         }
     }
 
@@ -174,6 +217,38 @@ impl AggregatorRequest {
             _ => None,
         }
     }
+
+    /// Check if the request targets an identified certificate or artifact, which response
+    /// never changes once created.
+    pub fn is_content_addressed(&self) -> bool {
+        match self {
+            AggregatorRequest::GetCertificate { .. }
+            | AggregatorRequest::GetMithrilStakeDistribution { .. }
+            | AggregatorRequest::GetSnapshot { .. } => true,
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::GetCardanoTransactionSnapshot { .. }
+            | AggregatorRequest::GetCardanoStakeDistribution { .. }
+            | AggregatorRequest::GetCardanoStakeDistributionByEpoch { .. }
+            | AggregatorRequest::GetCardanoDRepStakeDistribution { .. }
+            | AggregatorRequest::GetCardanoDRepStakeDistributionByEpoch { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Check if the request lists the latest certificates or artifacts, which response changes
+    /// over time.
+    pub fn is_latest_list(&self) -> bool {
+        match self {
+            AggregatorRequest::ListCertificates
+            | AggregatorRequest::ListMithrilStakeDistributions
+            | AggregatorRequest::ListSnapshots => true,
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::ListCardanoTransactionSnapshots
+            | AggregatorRequest::ListCardanoStakeDistributions
+            | AggregatorRequest::ListCardanoDRepStakeDistributions => true,
+            _ => false,
+        }
+    }
 }
 
 /// API that defines a client for the Aggregator
@@ -200,6 +275,9 @@ pub struct AggregatorHTTPClient {
     api_versions: Arc<RwLock<Vec<Version>>>,
     logger: Logger,
     http_headers: HeaderMap,
+    response_cache: Option<Arc<dyn AggregatorResponseCache>>,
+    lists_response_cache_ttl: Duration,
+    feedback_sender: FeedbackSender,
 }
 
 impl AggregatorHTTPClient {
@@ -241,9 +319,85 @@ impl AggregatorHTTPClient {
             api_versions: Arc::new(RwLock::new(api_versions)),
             logger,
             http_headers,
+            response_cache: None,
+            lists_response_cache_ttl: Duration::ZERO,
+            feedback_sender: FeedbackSender::new(&[]),
         })
     }
 
+    /// Send an [event][MithrilEvent] to the given sender each time a request is retried.
+    pub fn with_feedback_sender(mut self, feedback_sender: FeedbackSender) -> Self {
+        self.feedback_sender = feedback_sender;
+        self
+    }
+
+    /// Keep the responses in the given cache: responses of content-addressed requests never
+    /// expire, responses of requests that list the latest artifacts expire after the given
+    /// time to live.
+    pub fn with_response_cache(
+        mut self,
+        response_cache: Arc<dyn AggregatorResponseCache>,
+        lists_response_cache_ttl: Duration,
+    ) -> Self {
+        self.response_cache = Some(response_cache);
+        self.lists_response_cache_ttl = lists_response_cache_ttl;
+        self
+    }
+
+    /// Get the cached response of the request, failing to read the cache is not an error
+    /// since the response can be fetched from the aggregator.
+    async fn get_cached_content(&self, url: &Url) -> Option<String> {
+        let response_cache = self.response_cache.as_ref()?;
+        match response_cache.get(url.as_str()).await {
+            Ok(Some(response)) if !response.is_expired(Utc::now()) => {
+                debug!(
+                    self.logger,
+                    "GET url='{url}' served from the response cache."
+                );
+                Some(response.content)
+            }
+            Ok(_) => None,
+            Err(error) => {
+                warn!(self.logger, "Could not read the response cache"; "url" => %url, "error" => ?error);
+                None
+            }
+        }
+    }
+
+    async fn store_cached_content(&self, url: &Url, request: &AggregatorRequest, content: &str) {
+        let Some(response_cache) = &self.response_cache else {
+            return;
+        };
+        let expires_at = if request.is_content_addressed() {
+            None
+        } else if request.is_latest_list() && !self.lists_response_cache_ttl.is_zero() {
+            match TimeDelta::from_std(self.lists_response_cache_ttl) {
+                Ok(ttl) => Some(Utc::now() + ttl),
+                Err(_) => None,
+            }
+        } else {
+            return;
+        };
+
+        if let Err(error) = response_cache
+            .store(url.as_str(), &CachedResponse::new(content, expires_at))
+            .await
+        {
+            warn!(self.logger, "Could not store the response in the cache"; "url" => %url, "error" => ?error);
+        }
+    }
+
+    async fn send_retry_event(&self, aggregator_request_id: &str, attempt: usize, reason: String) {
+        self.feedback_sender
+            .send_event(MithrilEvent::AggregatorRequestRetried {
+                aggregator_request_id: aggregator_request_id.to_string(),
+                aggregator_endpoint: self.aggregator_endpoint.to_string(),
+                attempt,
+                reason,
+            })
+            .await;
+    }
+
     /// Computes the current api version
     async fn compute_current_api_version(&self) -> Option<Version> {
         self.api_versions.read().await.first().cloned()
@@ -271,7 +425,12 @@ impl AggregatorHTTPClient {
     /// Perform a HTTP GET request on the Aggregator and return the given JSON
     #[cfg_attr(target_family = "wasm", async_recursion(?Send))]
     #[cfg_attr(not(target_family = "wasm"), async_recursion)]
-    async fn get(&self, url: Url) -> Result<Response, AggregatorClientError> {
+    async fn get(
+        &self,
+        url: Url,
+        aggregator_request_id: &str,
+        attempt: usize,
+    ) -> Result<Response, AggregatorClientError> {
         debug!(self.logger, "GET url='{url}'.");
         let request_builder = self.http_client.get(url.clone());
         let current_api_version = self
@@ -296,10 +455,14 @@ impl AggregatorHTTPClient {
         match response.status() {
             StatusCode::OK => Ok(response),
             StatusCode::PRECONDITION_FAILED => {
-                if self.discard_current_api_version().await.is_some()
-                    && !self.api_versions.read().await.is_empty()
-                {
-                    return self.get(url).await;
+                if let Some(discarded_api_version) = self.discard_current_api_version().await {
+                    self.send_retry_event(
+                        aggregator_request_id,
+                        attempt + 1,
+                        format!("API version mismatch with version '{discarded_api_version}'"),
+                    )
+                    .await;
+                    return self.get(url, aggregator_request_id, attempt + 1).await;
                 }
 
                 Err(self.handle_api_error(response.headers()).await)
@@ -314,7 +477,13 @@ impl AggregatorHTTPClient {
 
     #[cfg_attr(target_family = "wasm", async_recursion(?Send))]
     #[cfg_attr(not(target_family = "wasm"), async_recursion)]
-    async fn post(&self, url: Url, json: &str) -> Result<Response, AggregatorClientError> {
+    async fn post(
+        &self,
+        url: Url,
+        json: &str,
+        aggregator_request_id: &str,
+        attempt: usize,
+    ) -> Result<Response, AggregatorClientError> {
         debug!(self.logger, "POST url='{url}' json='{json}'.");
         let request_builder = self.http_client.post(url.to_owned()).body(json.to_owned());
         let current_api_version = self
@@ -339,10 +508,16 @@ impl AggregatorHTTPClient {
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => Ok(response),
             StatusCode::PRECONDITION_FAILED => {
-                if self.discard_current_api_version().await.is_some()
-                    && !self.api_versions.read().await.is_empty()
-                {
-                    return self.post(url, json).await;
+                if let Some(discarded_api_version) = self.discard_current_api_version().await {
+                    self.send_retry_event(
+                        aggregator_request_id,
+                        attempt + 1,
+                        format!("API version mismatch with version '{discarded_api_version}'"),
+                    )
+                    .await;
+                    return self
+                        .post(url, json, aggregator_request_id, attempt + 1)
+                        .await;
                 }
 
                 Err(self.handle_api_error(response.headers()).await)
@@ -419,14 +594,24 @@ impl AggregatorClient for AggregatorHTTPClient {
         &self,
         request: AggregatorRequest,
     ) -> Result<String, AggregatorClientError> {
-        let response = self.get(self.get_url_for_route(&request.route())?).await?;
+        let url = self.get_url_for_route(&request.route())?;
+        if let Some(content) = self.get_cached_content(&url).await {
+            return Ok(content);
+        }
+
+        let response = self
+            .get(url.clone(), &MithrilEvent::new_aggregator_request_id(), 1)
+            .await?;
         let content = format!("{response:?}");
 
-        response.text().await.map_err(|e| {
+        let body = response.text().await.map_err(|e| {
             AggregatorClientError::SubsystemError(anyhow!(e).context(format!(
                 "Could not find a JSON body in the response '{content}'."
             )))
-        })
+        })?;
+        self.store_cached_content(&url, &request, &body).await;
+
+        Ok(body)
     }
 
     async fn post_content(
@@ -437,6 +622,8 @@ impl AggregatorClient for AggregatorHTTPClient {
             .post(
                 self.get_url_for_route(&request.route())?,
                 &request.get_body().unwrap_or_default(),
+                &MithrilEvent::new_aggregator_request_id(),
+                1,
             )
             .await?;
 
@@ -533,6 +720,11 @@ mod tests {
 
     #[test]
     fn deduce_routes_from_request() {
+        assert_eq!(
+            "".to_string(),
+            AggregatorRequest::GetAggregatorFeatures.route()
+        );
+
         assert_eq!(
             "certificate/abc".to_string(),
             AggregatorRequest::GetCertificate {
@@ -628,6 +820,25 @@ mod tests {
                 "artifact/cardano-stake-distributions".to_string(),
                 AggregatorRequest::ListCardanoStakeDistributions.route()
             );
+
+            assert_eq!(
+                "artifact/cardano-drep-stake-distribution/abc".to_string(),
+                AggregatorRequest::GetCardanoDRepStakeDistribution {
+                    hash: "abc".to_string()
+                }
+                .route()
+            );
+
+            assert_eq!(
+                "artifact/cardano-drep-stake-distribution/epoch/123".to_string(),
+                AggregatorRequest::GetCardanoDRepStakeDistributionByEpoch { epoch: Epoch(123) }
+                    .route()
+            );
+
+            assert_eq!(
+                "artifact/cardano-drep-stake-distributions".to_string(),
+                AggregatorRequest::ListCardanoDRepStakeDistributions.route()
+            );
         }
     }
 
@@ -804,6 +1015,49 @@ mod tests {
             .expect("should have run with a fallback version");
     }
 
+    #[tokio::test]
+    async fn test_client_send_an_event_when_retrying_with_a_fallback_version() {
+        let bad_version = "0.0.0";
+        let good_version = "1.0.0";
+
+        let aggregator = MockServer::start();
+        let feedback_receiver = Arc::new(crate::feedback::StackFeedbackReceiver::new());
+        let client = setup_client(
+            &aggregator.url(""),
+            vec![
+                Version::parse(bad_version).unwrap(),
+                Version::parse(good_version).unwrap(),
+            ],
+            None,
+        )
+        .with_feedback_sender(FeedbackSender::new(&[feedback_receiver.clone()]));
+        aggregator.mock(|when, then| {
+            when.header(MITHRIL_API_VERSION_HEADER, bad_version);
+            then.status(StatusCode::PRECONDITION_FAILED.as_u16())
+                .header(MITHRIL_API_VERSION_HEADER, bad_version);
+        });
+        aggregator.mock(|when, then| {
+            when.header(MITHRIL_API_VERSION_HEADER, good_version);
+            then.status(StatusCode::OK.as_u16());
+        });
+
+        client
+            .get_content(AggregatorRequest::ListCertificates)
+            .await
+            .expect("should have run with a fallback version");
+
+        let events = feedback_receiver.stacked_events();
+        assert_eq!(
+            vec![MithrilEvent::AggregatorRequestRetried {
+                aggregator_request_id: events[0].event_id().to_string(),
+                aggregator_endpoint: aggregator.url("/"),
+                attempt: 2,
+                reason: format!("API version mismatch with version '{bad_version}'"),
+            }],
+            events
+        );
+    }
+
     #[tokio::test]
     async fn test_client_with_custom_headers() {
         let mut http_headers = HashMap::new();
@@ -826,4 +1080,135 @@ mod tests {
             .await
             .expect("GET request should succeed");
     }
+
+    mod response_cache {
+        use chrono::TimeDelta;
+
+        use crate::response_cache::MemoryAggregatorResponseCache;
+
+        use super::*;
+
+        fn setup_server_and_client_with_cache(
+            lists_response_cache_ttl: Duration,
+        ) -> (
+            MockServer,
+            AggregatorHTTPClient,
+            Arc<MemoryAggregatorResponseCache>,
+        ) {
+            let (server, client) = setup_server_and_client();
+            let cache = Arc::new(MemoryAggregatorResponseCache::new());
+            let client = client.with_response_cache(cache.clone(), lists_response_cache_ttl);
+
+            (server, client, cache)
+        }
+
+        #[tokio::test]
+        async fn content_addressed_response_is_fetched_once_and_never_expires() {
+            let (aggregator, client, cache) =
+                setup_server_and_client_with_cache(Duration::from_secs(60));
+            let request = AggregatorRequest::GetCertificate {
+                hash: "abc".to_string(),
+            };
+            let mock = aggregator.mock(|when, then| {
+                when.path("/certificate/abc");
+                then.status(StatusCode::OK.as_u16()).body("certificate");
+            });
+
+            for _ in 0..2 {
+                let content = client.get_content(request.clone()).await.unwrap();
+                assert_eq!("certificate", content);
+            }
+
+            mock.assert_hits(1);
+            let cached_response = cache
+                .get(client.get_url_for_route(&request.route()).unwrap().as_str())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(None, cached_response.expires_at);
+        }
+
+        #[tokio::test]
+        async fn latest_list_response_is_fetched_again_once_expired() {
+            let (aggregator, client, cache) =
+                setup_server_and_client_with_cache(Duration::from_secs(60));
+            let url = client
+                .get_url_for_route(&AggregatorRequest::ListCertificates.route())
+                .unwrap();
+            let mock = aggregator.mock(|when, then| {
+                when.path("/certificates");
+                then.status(StatusCode::OK.as_u16()).body("[new]");
+            });
+
+            cache
+                .store(
+                    url.as_str(),
+                    &CachedResponse::new("[cached]", Some(Utc::now() + TimeDelta::seconds(60))),
+                )
+                .await
+                .unwrap();
+            let content = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+            assert_eq!("[cached]", content);
+            mock.assert_hits(0);
+
+            cache
+                .store(
+                    url.as_str(),
+                    &CachedResponse::new("[cached]", Some(Utc::now() - TimeDelta::seconds(1))),
+                )
+                .await
+                .unwrap();
+            let content = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+            assert_eq!("[new]", content);
+            mock.assert_hits(1);
+        }
+
+        #[tokio::test]
+        async fn latest_list_response_is_not_cached_with_a_zero_ttl() {
+            let (aggregator, client, _cache) = setup_server_and_client_with_cache(Duration::ZERO);
+            let mock = aggregator.mock(|when, then| {
+                when.path("/certificates");
+                then.status(StatusCode::OK.as_u16()).body("[]");
+            });
+
+            for _ in 0..2 {
+                client
+                    .get_content(AggregatorRequest::ListCertificates)
+                    .await
+                    .unwrap();
+            }
+
+            mock.assert_hits(2);
+        }
+
+        #[tokio::test]
+        async fn error_responses_are_not_cached() {
+            let (aggregator, client, cache) =
+                setup_server_and_client_with_cache(Duration::from_secs(60));
+            let request = AggregatorRequest::GetCertificate {
+                hash: "abc".to_string(),
+            };
+            aggregator.mock(|when, then| {
+                when.path("/certificate/abc");
+                then.status(StatusCode::NOT_FOUND.as_u16());
+            });
+
+            client
+                .get_content(request.clone())
+                .await
+                .expect_err("A not found certificate should return an error");
+
+            let cached_response = cache
+                .get(client.get_url_for_route(&request.route()).unwrap().as_str())
+                .await
+                .unwrap();
+            assert_eq!(None, cached_response);
+        }
+    }
 }
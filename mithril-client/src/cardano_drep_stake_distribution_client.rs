@@ -0,0 +1,415 @@
+//! A client to retrieve Cardano DRep stake distributions data from an Aggregator.
+//!
+//! In order to do so it defines a [CardanoDRepStakeDistributionClient] which exposes the following features:
+//!  - [get][CardanoDRepStakeDistributionClient::get]: get a Cardano DRep stake distribution data from its hash
+//!  - [get_by_epoch][CardanoDRepStakeDistributionClient::get_by_epoch]: get a Cardano DRep stake distribution data from its epoch
+//!  - [list][CardanoDRepStakeDistributionClient::list]: get the list of available Cardano DRep stake distribution
+//!  - [compute_message][CardanoDRepStakeDistributionClient::compute_message]: compute the message of a Cardano DRep stake distribution signed by a certificate
+//!
+//! # Get a Cardano DRep stake distribution
+//!
+//! To get a Cardano DRep stake distribution using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_drep_stake_distribution = client.cardano_drep_stake_distribution().get("CARDANO_DREP_STAKE_DISTRIBUTION_HASH").await?.unwrap();
+//!
+//! println!(
+//!     "Cardano DRep stake distribution hash={}, epoch={}, drep_stake_distribution={:?}",
+//!     cardano_drep_stake_distribution.hash,
+//!     cardano_drep_stake_distribution.epoch,
+//!     cardano_drep_stake_distribution.drep_stake_distribution
+//! );
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # List available Cardano DRep stake distributions
+//!
+//! To list available Cardano DRep stake distributions using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_drep_stake_distributions = client.cardano_drep_stake_distribution().list().await?;
+//!
+//! for cardano_drep_stake_distribution in cardano_drep_stake_distributions {
+//!     println!("Cardano DRep stake distribution hash={}, epoch={}", cardano_drep_stake_distribution.hash, cardano_drep_stake_distribution.epoch);
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # Get a Cardano DRep stake distribution by epoch
+//!
+//! To get a Cardano DRep stake distribution by epoch using the [ClientBuilder][crate::client::ClientBuilder].
+//! The epoch represents the epoch at which the Cardano DRep stake distribution is computed by the Cardano node
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//! use mithril_client::common::Epoch;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_drep_stake_distribution = client.cardano_drep_stake_distribution().get_by_epoch(Epoch(520)).await?.unwrap();
+//!
+//! println!(
+//!     "Cardano DRep stake distribution hash={}, epoch={}, drep_stake_distribution={:?}",
+//!     cardano_drep_stake_distribution.hash,
+//!     cardano_drep_stake_distribution.epoch,
+//!     cardano_drep_stake_distribution.drep_stake_distribution
+//! );
+//! #    Ok(())
+//! # }
+//! ```
+
+use anyhow::Context;
+use std::sync::Arc;
+
+use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::common::Epoch;
+use crate::feedback::{FeedbackSender, MithrilEvent, StakeDistributionType};
+use crate::common::ProtocolMessage;
+use crate::{
+    CardanoDRepStakeDistribution, CardanoDRepStakeDistributionListItem, MessageBuilder, MithrilCertificate, MithrilResult,
+};
+
+/// HTTP client for CardanoDRepStakeDistribution API from the Aggregator
+pub struct CardanoDRepStakeDistributionClient {
+    aggregator_client: Arc<dyn AggregatorClient>,
+    feedback_sender: FeedbackSender,
+}
+
+impl CardanoDRepStakeDistributionClient {
+    /// Constructs a new `CardanoDRepStakeDistribution`.
+    pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
+        Self {
+            aggregator_client,
+            feedback_sender: FeedbackSender::new(&[]),
+        }
+    }
+
+    /// Send the [events][MithrilEvent] of the stake distribution retrievals and message
+    /// computations to the given sender.
+    pub fn with_feedback_sender(mut self, feedback_sender: FeedbackSender) -> Self {
+        self.feedback_sender = feedback_sender;
+        self
+    }
+
+    /// Fetch a list of signed CardanoDRepStakeDistribution
+    pub async fn list(&self) -> MithrilResult<Vec<CardanoDRepStakeDistributionListItem>> {
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::ListCardanoDRepStakeDistributions)
+            .await
+            .with_context(|| "CardanoDRepStakeDistribution client can not get the artifact list")?;
+        let items = serde_json::from_str::<Vec<CardanoDRepStakeDistributionListItem>>(&response)
+            .with_context(|| "CardanoDRepStakeDistribution client can not deserialize artifact list")?;
+
+        Ok(items)
+    }
+
+    /// Get the given Cardano DRep stake distribution data by hash.
+    pub async fn get(&self, hash: &str) -> MithrilResult<Option<CardanoDRepStakeDistribution>> {
+        self.fetch_with_aggregator_request(
+            AggregatorRequest::GetCardanoDRepStakeDistribution {
+                hash: hash.to_string(),
+            },
+            hash,
+        )
+        .await
+    }
+
+    /// Get the given Cardano DRep stake distribution data by epoch.
+    pub async fn get_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> MithrilResult<Option<CardanoDRepStakeDistribution>> {
+        self.fetch_with_aggregator_request(
+            AggregatorRequest::GetCardanoDRepStakeDistributionByEpoch { epoch },
+            &format!("epoch {epoch}"),
+        )
+        .await
+    }
+
+    /// Fetch the given Cardano DRep stake distribution data with an aggregator request.
+    /// If it cannot be found, a None is returned.
+    async fn fetch_with_aggregator_request(
+        &self,
+        request: AggregatorRequest,
+        identifier: &str,
+    ) -> MithrilResult<Option<CardanoDRepStakeDistribution>> {
+        let stake_distribution_retrieval_id = MithrilEvent::new_stake_distribution_retrieval_id();
+        self.feedback_sender
+            .send_event(MithrilEvent::StakeDistributionRetrievalStarted {
+                stake_distribution_retrieval_id: stake_distribution_retrieval_id.clone(),
+                stake_distribution_type: StakeDistributionType::CardanoDRep,
+                identifier: identifier.to_string(),
+            })
+            .await;
+
+        let cardano_drep_stake_distribution = match self.aggregator_client.get_content(request).await {
+            Ok(content) => {
+                let cardano_drep_stake_distribution: CardanoDRepStakeDistribution =
+                    serde_json::from_str(&content).with_context(|| {
+                        "CardanoDRepStakeDistribution client can not deserialize artifact"
+                    })?;
+
+                Some(cardano_drep_stake_distribution)
+            }
+            Err(AggregatorClientError::RemoteServerLogical(_)) => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        self.feedback_sender
+            .send_event(MithrilEvent::StakeDistributionRetrievalCompleted {
+                stake_distribution_retrieval_id,
+                found: cardano_drep_stake_distribution.is_some(),
+            })
+            .await;
+
+        Ok(cardano_drep_stake_distribution)
+    }
+
+    /// Compute the message of the given Cardano DRep stake distribution signed by the given
+    /// certificate, see [MessageBuilder::compute_cardano_drep_stake_distribution_message].
+    pub async fn compute_message(
+        &self,
+        certificate: &MithrilCertificate,
+        cardano_drep_stake_distribution: &CardanoDRepStakeDistribution,
+    ) -> MithrilResult<ProtocolMessage> {
+        self.feedback_sender
+            .compute_stake_distribution_message(
+                StakeDistributionType::CardanoDRep,
+                cardano_drep_stake_distribution.drep_stake_distribution.len(),
+                || MessageBuilder::new().compute_cardano_drep_stake_distribution_message(certificate, cardano_drep_stake_distribution),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::{DateTime, Utc};
+    use mockall::predicate::eq;
+
+    use crate::aggregator_client::MockAggregatorHTTPClient;
+    use crate::common::DRepStakeDistribution;
+
+    use super::*;
+
+    fn fake_messages() -> Vec<CardanoDRepStakeDistributionListItem> {
+        vec![
+            CardanoDRepStakeDistributionListItem {
+                epoch: Epoch(1),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2024-08-06T12:13:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            },
+            CardanoDRepStakeDistributionListItem {
+                epoch: Epoch(2),
+                hash: "hash-456".to_string(),
+                certificate_hash: "cert-hash-456".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2024-08-06T12:13:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn list_cardano_drep_stake_distributions_returns_messages() {
+        let message = fake_messages();
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::ListCardanoDRepStakeDistributions))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        let messages = client.list().await.unwrap();
+
+        assert_eq!(2, messages.len());
+        assert_eq!("hash-123".to_string(), messages[0].hash);
+        assert_eq!("hash-456".to_string(), messages[1].hash);
+    }
+
+    #[tokio::test]
+    async fn list_cardano_drep_stake_distributions_returns_error_when_invalid_json_structure_in_response(
+    ) {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .return_once(move |_| Ok("invalid json structure".to_string()));
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        client
+            .list()
+            .await
+            .expect_err("List Cardano DRep stake distributions should return an error");
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_returns_message() {
+        let expected_drep_stake_distribution = DRepStakeDistribution::from([("drep123".to_string(), 123)]);
+        let message = CardanoDRepStakeDistribution {
+            epoch: Epoch(3),
+            hash: "hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            drep_stake_distribution: expected_drep_stake_distribution.clone(),
+            created_at: DateTime::<Utc>::default(),
+        };
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetCardanoDRepStakeDistribution {
+                hash: "hash-123".to_string(),
+            }))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        let cardano_drep_stake_distribution = client
+            .get("hash-123")
+            .await
+            .unwrap()
+            .expect("This test returns a Cardano DRep stake distribution");
+
+        assert_eq!("hash-123".to_string(), cardano_drep_stake_distribution.hash);
+        assert_eq!(Epoch(3), cardano_drep_stake_distribution.epoch);
+        assert_eq!(
+            expected_drep_stake_distribution,
+            cardano_drep_stake_distribution.drep_stake_distribution
+        );
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_returns_error_when_invalid_json_structure_in_response()
+    {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .return_once(move |_| Ok("invalid json structure".to_string()));
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        client
+            .get("hash-123")
+            .await
+            .expect_err("Get Cardano DRep stake distribution should return an error");
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_returns_none_when_not_found_or_remote_server_logical_error(
+    ) {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client.expect_get_content().return_once(move |_| {
+            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                "not found"
+            )))
+        });
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        let result = client.get("hash-123").await.unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_returns_error() {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .return_once(move |_| Err(AggregatorClientError::SubsystemError(anyhow!("error"))));
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        client
+            .get("hash-123")
+            .await
+            .expect_err("Get Cardano DRep stake distribution should return an error");
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_by_epoch_returns_message() {
+        let expected_drep_stake_distribution = DRepStakeDistribution::from([("drep123".to_string(), 123)]);
+        let message = CardanoDRepStakeDistribution {
+            epoch: Epoch(3),
+            hash: "hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            drep_stake_distribution: expected_drep_stake_distribution.clone(),
+            created_at: DateTime::<Utc>::default(),
+        };
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetCardanoDRepStakeDistributionByEpoch {
+                epoch: Epoch(3),
+            }))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        let cardano_drep_stake_distribution = client
+            .get_by_epoch(Epoch(3))
+            .await
+            .unwrap()
+            .expect("This test returns a Cardano DRep stake distribution");
+
+        assert_eq!("hash-123".to_string(), cardano_drep_stake_distribution.hash);
+        assert_eq!(Epoch(3), cardano_drep_stake_distribution.epoch);
+        assert_eq!(
+            expected_drep_stake_distribution,
+            cardano_drep_stake_distribution.drep_stake_distribution
+        );
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_by_epoch_returns_error_when_invalid_json_structure_in_response(
+    ) {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .return_once(move |_| Ok("invalid json structure".to_string()));
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        client
+            .get_by_epoch(Epoch(3))
+            .await
+            .expect_err("Get Cardano DRep stake distribution by epoch should return an error");
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_by_epoch_returns_none_when_not_found_or_remote_server_logical_error(
+    ) {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client.expect_get_content().return_once(move |_| {
+            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                "not found"
+            )))
+        });
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        let result = client.get_by_epoch(Epoch(3)).await.unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_cardano_drep_stake_distribution_by_epoch_returns_error() {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .return_once(move |_| Err(AggregatorClientError::SubsystemError(anyhow!("error"))));
+        let client = CardanoDRepStakeDistributionClient::new(Arc::new(http_client));
+
+        client
+            .get_by_epoch(Epoch(3))
+            .await
+            .expect_err("Get Cardano DRep stake distribution by epoch should return an error");
+    }
+}
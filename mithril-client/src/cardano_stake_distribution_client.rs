@@ -4,6 +4,7 @@
 //!  - [get][CardanoStakeDistributionClient::get]: get a Cardano stake distribution data from its hash
 //!  - [get_by_epoch][CardanoStakeDistributionClient::get_by_epoch]: get a Cardano stake distribution data from its epoch
 //!  - [list][CardanoStakeDistributionClient::list]: get the list of available Cardano stake distribution
+//!  - [compute_message][CardanoStakeDistributionClient::compute_message]: compute the message of a Cardano stake distribution signed by a certificate
 //!
 //! # Get a Cardano stake distribution
 //!
@@ -72,17 +73,32 @@ use std::sync::Arc;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
 use crate::common::Epoch;
-use crate::{CardanoStakeDistribution, CardanoStakeDistributionListItem, MithrilResult};
+use crate::feedback::{FeedbackSender, MithrilEvent, StakeDistributionType};
+use crate::common::ProtocolMessage;
+use crate::{
+    CardanoStakeDistribution, CardanoStakeDistributionListItem, MessageBuilder, MithrilCertificate, MithrilResult,
+};
 
 /// HTTP client for CardanoStakeDistribution API from the Aggregator
 pub struct CardanoStakeDistributionClient {
     aggregator_client: Arc<dyn AggregatorClient>,
+    feedback_sender: FeedbackSender,
 }
 
 impl CardanoStakeDistributionClient {
     /// Constructs a new `CardanoStakeDistribution`.
     pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
-        Self { aggregator_client }
+        Self {
+            aggregator_client,
+            feedback_sender: FeedbackSender::new(&[]),
+        }
+    }
+
+    /// Send the [events][MithrilEvent] of the stake distribution retrievals and message
+    /// computations to the given sender.
+    pub fn with_feedback_sender(mut self, feedback_sender: FeedbackSender) -> Self {
+        self.feedback_sender = feedback_sender;
+        self
     }
 
     /// Fetch a list of signed CardanoStakeDistribution
@@ -100,9 +116,12 @@ impl CardanoStakeDistributionClient {
 
     /// Get the given Cardano stake distribution data by hash.
     pub async fn get(&self, hash: &str) -> MithrilResult<Option<CardanoStakeDistribution>> {
-        self.fetch_with_aggregator_request(AggregatorRequest::GetCardanoStakeDistribution {
-            hash: hash.to_string(),
-        })
+        self.fetch_with_aggregator_request(
+            AggregatorRequest::GetCardanoStakeDistribution {
+                hash: hash.to_string(),
+            },
+            hash,
+        )
         .await
     }
 
@@ -111,9 +130,10 @@ impl CardanoStakeDistributionClient {
         &self,
         epoch: Epoch,
     ) -> MithrilResult<Option<CardanoStakeDistribution>> {
-        self.fetch_with_aggregator_request(AggregatorRequest::GetCardanoStakeDistributionByEpoch {
-            epoch,
-        })
+        self.fetch_with_aggregator_request(
+            AggregatorRequest::GetCardanoStakeDistributionByEpoch { epoch },
+            &format!("epoch {epoch}"),
+        )
         .await
     }
 
@@ -122,19 +142,54 @@ impl CardanoStakeDistributionClient {
     async fn fetch_with_aggregator_request(
         &self,
         request: AggregatorRequest,
+        identifier: &str,
     ) -> MithrilResult<Option<CardanoStakeDistribution>> {
-        match self.aggregator_client.get_content(request).await {
+        let stake_distribution_retrieval_id = MithrilEvent::new_stake_distribution_retrieval_id();
+        self.feedback_sender
+            .send_event(MithrilEvent::StakeDistributionRetrievalStarted {
+                stake_distribution_retrieval_id: stake_distribution_retrieval_id.clone(),
+                stake_distribution_type: StakeDistributionType::Cardano,
+                identifier: identifier.to_string(),
+            })
+            .await;
+
+        let cardano_stake_distribution = match self.aggregator_client.get_content(request).await {
             Ok(content) => {
                 let cardano_stake_distribution: CardanoStakeDistribution =
                     serde_json::from_str(&content).with_context(|| {
                         "CardanoStakeDistribution client can not deserialize artifact"
                     })?;
 
-                Ok(Some(cardano_stake_distribution))
+                Some(cardano_stake_distribution)
             }
-            Err(AggregatorClientError::RemoteServerLogical(_)) => Ok(None),
-            Err(e) => Err(e.into()),
-        }
+            Err(AggregatorClientError::RemoteServerLogical(_)) => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        self.feedback_sender
+            .send_event(MithrilEvent::StakeDistributionRetrievalCompleted {
+                stake_distribution_retrieval_id,
+                found: cardano_stake_distribution.is_some(),
+            })
+            .await;
+
+        Ok(cardano_stake_distribution)
+    }
+
+    /// Compute the message of the given Cardano stake distribution signed by the given
+    /// certificate, see [MessageBuilder::compute_cardano_stake_distribution_message].
+    pub async fn compute_message(
+        &self,
+        certificate: &MithrilCertificate,
+        cardano_stake_distribution: &CardanoStakeDistribution,
+    ) -> MithrilResult<ProtocolMessage> {
+        self.feedback_sender
+            .compute_stake_distribution_message(
+                StakeDistributionType::Cardano,
+                cardano_stake_distribution.stake_distribution.len(),
+                || MessageBuilder::new().compute_cardano_stake_distribution_message(certificate, cardano_stake_distribution),
+            )
+            .await
     }
 }
 
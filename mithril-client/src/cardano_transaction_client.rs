@@ -3,6 +3,8 @@
 //! In order to do so it defines a [CardanoTransactionClient] which exposes the following features:
 //!  - [get_proofs][CardanoTransactionClient::get_proofs]: get a [cryptographic proof][CardanoTransactionsProofs]
 //!    that the transactions with given hash are included in the global Cardano transactions set.
+//!  - [get_proofs_by_chunks][CardanoTransactionClient::get_proofs_by_chunks]: get the proofs of an arbitrarily
+//!    large stream of transactions hashes, in chunks sized according to the aggregator prover capabilities.
 //!  - [get][CardanoTransactionClient::get_snapshot]: get a [Cardano transaction snapshot][CardanoTransactionSnapshot]
 //!    data from its hash.
 //!  - [list][CardanoTransactionClient::list_snapshots]: get the list of the latest available Cardano transaction
@@ -40,6 +42,36 @@
 //! # }
 //! ```
 //!
+//! # Get and verify the proofs of a large set of Cardano transactions
+//!
+//! The aggregator limits the number of transactions hashes of a single proof request, to certify a
+//! larger set use [get_proofs_by_chunks][CardanoTransactionClient::get_proofs_by_chunks] with a
+//! stream of hashes, ie from an iterator using [futures::stream::iter].
+//!
+//! Chunks can be certified by different certificates if a new one was issued while the proofs were
+//! fetched: each of them must be verified.
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, MessageBuilder};
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let transactions_hashes = vec!["tx-1".to_string(), "tx-2".to_string()];
+//!
+//! let aggregated_proofs = client
+//!     .cardano_transaction()
+//!     .get_proofs_by_chunks(futures::stream::iter(transactions_hashes))
+//!     .await?;
+//!
+//! for verified_transactions in aggregated_proofs.verify()? {
+//!     let certificate = client.certificate().verify_chain(verified_transactions.certificate_hash()).await?;
+//!     let message = MessageBuilder::new().compute_cardano_transactions_proofs_message(&certificate, &verified_transactions);
+//!     assert!(certificate.match_message(&message));
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+//!
 //! # Get a Cardano transaction snapshot
 //!
 //! To get a Cardano transaction snapshot using the [ClientBuilder][crate::client::ClientBuilder].
@@ -75,28 +107,168 @@
 //! ```
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::common::TransactionHash;
+use crate::feedback::{FeedbackSender, MithrilEvent};
 use crate::{
     CardanoTransactionSnapshot, CardanoTransactionSnapshotListItem, CardanoTransactionsProofs,
-    MithrilResult,
+    CardanoTransactionsProverCapabilities, MithrilResult, VerifiedCardanoTransactions,
+    VerifyCardanoTransactionsProofsError,
 };
 use anyhow::Context;
+use futures::{Stream, StreamExt};
+use mithril_common::messages::AggregatorFeaturesMessage;
 use std::sync::Arc;
 
+/// Number of transactions hashes sent by proof request when the aggregator does not advertise
+/// its [prover capabilities][CardanoTransactionsProverCapabilities].
+pub const DEFAULT_MAX_HASHES_ALLOWED_BY_REQUEST: usize = 100;
+
+/// Proofs of a set of Cardano transactions fetched by chunks, merged by the certificate that
+/// signs them.
+///
+/// All the proofs are usually signed by the same certificate, but a new certificate may have
+/// been issued while the chunks were fetched: since a transaction never leaves the Cardano
+/// transactions set once certified, proofs signed by different certificates are compatible as
+/// long as each one is verified against its own certificate.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AggregatedCardanoTransactionsProofs {
+    proofs: Vec<CardanoTransactionsProofs>,
+}
+
+impl AggregatedCardanoTransactionsProofs {
+    /// Merge the proofs of a chunk with the proofs signed by the same certificate.
+    pub fn merge(&mut self, chunk_proofs: CardanoTransactionsProofs) {
+        match self
+            .proofs
+            .iter_mut()
+            .find(|proofs| proofs.certificate_hash == chunk_proofs.certificate_hash)
+        {
+            Some(proofs) => {
+                proofs
+                    .certified_transactions
+                    .extend(chunk_proofs.certified_transactions);
+                proofs
+                    .non_certified_transactions
+                    .extend(chunk_proofs.non_certified_transactions);
+            }
+            None => self.proofs.push(chunk_proofs),
+        }
+    }
+
+    /// Proofs, one for each certificate that signs some of the transactions.
+    pub fn proofs(&self) -> &[CardanoTransactionsProofs] {
+        &self.proofs
+    }
+
+    /// Hashes of the certificates that sign the proofs.
+    pub fn certificate_hashes(&self) -> Vec<&str> {
+        self.proofs
+            .iter()
+            .filter(|proofs| !proofs.certified_transactions.is_empty())
+            .map(|proofs| proofs.certificate_hash.as_str())
+            .collect()
+    }
+
+    /// Number of transactions that are certified by the proofs.
+    pub fn certified_transactions_count(&self) -> usize {
+        self.proofs
+            .iter()
+            .flat_map(|proofs| &proofs.certified_transactions)
+            .map(|set_proof| set_proof.transactions_hashes.len())
+            .sum()
+    }
+
+    /// Transactions that could not be certified.
+    pub fn non_certified_transactions(&self) -> Vec<TransactionHash> {
+        self.proofs
+            .iter()
+            .flat_map(|proofs| proofs.non_certified_transactions.clone())
+            .collect()
+    }
+
+    /// Verify the proofs of each certificate, see [CardanoTransactionsProofs::verify].
+    ///
+    /// Each returned [VerifiedCardanoTransactions] must then be checked against its certificate.
+    pub fn verify(
+        &self,
+    ) -> Result<Vec<VerifiedCardanoTransactions>, VerifyCardanoTransactionsProofsError> {
+        let verified_transactions = self
+            .proofs
+            .iter()
+            .filter(|proofs| !proofs.certified_transactions.is_empty())
+            .map(|proofs| proofs.verify())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if verified_transactions.is_empty() {
+            return Err(VerifyCardanoTransactionsProofsError::NoCertifiedTransaction);
+        }
+
+        Ok(verified_transactions)
+    }
+}
+
 /// HTTP client for CardanoTransactionsAPI from the Aggregator
 pub struct CardanoTransactionClient {
     aggregator_client: Arc<dyn AggregatorClient>,
+    feedback_sender: FeedbackSender,
 }
 
 impl CardanoTransactionClient {
     /// Constructs a new `CardanoTransactionClient`.
     pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
-        Self { aggregator_client }
+        Self {
+            aggregator_client,
+            feedback_sender: FeedbackSender::new(&[]),
+        }
+    }
+
+    /// Send the [events][MithrilEvent] of the proofs retrievals and verifications to the given
+    /// sender.
+    pub fn with_feedback_sender(mut self, feedback_sender: FeedbackSender) -> Self {
+        self.feedback_sender = feedback_sender;
+        self
     }
 
     /// Get proofs that the given subset of transactions is included in the Cardano transactions set.
     pub async fn get_proofs<T: ToString>(
         &self,
         transactions_hashes: &[T],
+    ) -> MithrilResult<CardanoTransactionsProofs> {
+        let proofs_retrieval_id = MithrilEvent::new_proofs_retrieval_id();
+        self.feedback_sender
+            .send_event(MithrilEvent::CardanoTransactionsProofsRetrievalStarted {
+                proofs_retrieval_id: proofs_retrieval_id.clone(),
+            })
+            .await;
+
+        let transactions_proofs = self.fetch_proofs(transactions_hashes).await?;
+        self.feedback_sender
+            .send_event(MithrilEvent::CardanoTransactionsProofsChunkRetrieved {
+                proofs_retrieval_id: proofs_retrieval_id.clone(),
+                chunk_transactions_count: transactions_hashes.len(),
+                retrieved_transactions_count: transactions_hashes.len(),
+            })
+            .await;
+        self.feedback_sender
+            .send_event(MithrilEvent::CardanoTransactionsProofsRetrievalCompleted {
+                proofs_retrieval_id,
+                certified_transactions_count: transactions_proofs
+                    .certified_transactions
+                    .iter()
+                    .map(|set_proof| set_proof.transactions_hashes.len())
+                    .sum(),
+                non_certified_transactions_count: transactions_proofs
+                    .non_certified_transactions
+                    .len(),
+            })
+            .await;
+
+        Ok(transactions_proofs)
+    }
+
+    async fn fetch_proofs<T: ToString>(
+        &self,
+        transactions_hashes: &[T],
     ) -> MithrilResult<CardanoTransactionsProofs> {
         match self
             .aggregator_client
@@ -117,6 +289,130 @@ impl CardanoTransactionClient {
         }
     }
 
+    /// Get the capabilities of the Cardano transactions prover advertised by the aggregator, if any.
+    pub async fn get_prover_capabilities(
+        &self,
+    ) -> MithrilResult<Option<CardanoTransactionsProverCapabilities>> {
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::GetAggregatorFeatures)
+            .await
+            .with_context(|| "CardanoTransactionClient Client can not get the aggregator features")?;
+        let features = serde_json::from_str::<AggregatorFeaturesMessage>(&response)
+            .with_context(|| {
+                "CardanoTransactionClient Client can not deserialize the aggregator features"
+            })?;
+
+        Ok(features.capabilities.cardano_transactions_prover)
+    }
+
+    /// Get proofs that the given stream of transactions is included in the Cardano transactions
+    /// set.
+    ///
+    /// The hashes are sent in chunks no larger than the maximum number of hashes by request
+    /// advertised by the aggregator, and the proofs of all chunks are merged.
+    pub async fn get_proofs_by_chunks<S, T>(
+        &self,
+        transactions_hashes: S,
+    ) -> MithrilResult<AggregatedCardanoTransactionsProofs>
+    where
+        S: Stream<Item = T>,
+        T: ToString,
+    {
+        let chunk_size = self
+            .get_prover_capabilities()
+            .await?
+            .map(|capabilities| capabilities.max_hashes_allowed_by_request)
+            .unwrap_or(DEFAULT_MAX_HASHES_ALLOWED_BY_REQUEST)
+            .max(1);
+
+        let proofs_retrieval_id = MithrilEvent::new_proofs_retrieval_id();
+        self.feedback_sender
+            .send_event(MithrilEvent::CardanoTransactionsProofsRetrievalStarted {
+                proofs_retrieval_id: proofs_retrieval_id.clone(),
+            })
+            .await;
+
+        let mut chunks = std::pin::pin!(transactions_hashes.chunks(chunk_size));
+        let mut aggregated_proofs = AggregatedCardanoTransactionsProofs::default();
+        let mut retrieved_transactions_count = 0;
+        while let Some(chunk) = chunks.next().await {
+            let chunk_proofs = self.fetch_proofs(&chunk).await.with_context(|| {
+                format!(
+                    "CardanoTransactionClient Client can not get the proofs of a chunk of {} transactions",
+                    chunk.len()
+                )
+            })?;
+            aggregated_proofs.merge(chunk_proofs);
+
+            retrieved_transactions_count += chunk.len();
+            self.feedback_sender
+                .send_event(MithrilEvent::CardanoTransactionsProofsChunkRetrieved {
+                    proofs_retrieval_id: proofs_retrieval_id.clone(),
+                    chunk_transactions_count: chunk.len(),
+                    retrieved_transactions_count,
+                })
+                .await;
+        }
+
+        self.feedback_sender
+            .send_event(MithrilEvent::CardanoTransactionsProofsRetrievalCompleted {
+                proofs_retrieval_id,
+                certified_transactions_count: aggregated_proofs.certified_transactions_count(),
+                non_certified_transactions_count: aggregated_proofs
+                    .non_certified_transactions()
+                    .len(),
+            })
+            .await;
+
+        Ok(aggregated_proofs)
+    }
+
+    /// Verify the proofs of each certificate, see [AggregatedCardanoTransactionsProofs::verify],
+    /// sending an [event][MithrilEvent] each time the proofs of a certificate are verified.
+    ///
+    /// Each returned [VerifiedCardanoTransactions] must then be checked against its certificate.
+    pub async fn verify_proofs(
+        &self,
+        aggregated_proofs: &AggregatedCardanoTransactionsProofs,
+    ) -> Result<Vec<VerifiedCardanoTransactions>, VerifyCardanoTransactionsProofsError> {
+        let proofs_verification_id = MithrilEvent::new_proofs_verification_id();
+        self.feedback_sender
+            .send_event(MithrilEvent::CardanoTransactionsProofsVerificationStarted {
+                proofs_verification_id: proofs_verification_id.clone(),
+            })
+            .await;
+
+        let mut verified_transactions = vec![];
+        for proofs in aggregated_proofs
+            .proofs()
+            .iter()
+            .filter(|proofs| !proofs.certified_transactions.is_empty())
+        {
+            let verified = proofs.verify()?;
+            self.feedback_sender
+                .send_event(MithrilEvent::CardanoTransactionsProofsVerified {
+                    proofs_verification_id: proofs_verification_id.clone(),
+                    certificate_hash: verified.certificate_hash().to_string(),
+                    certified_transactions_count: verified.certified_transactions().len(),
+                })
+                .await;
+            verified_transactions.push(verified);
+        }
+
+        if verified_transactions.is_empty() {
+            return Err(VerifyCardanoTransactionsProofsError::NoCertifiedTransaction);
+        }
+
+        self.feedback_sender
+            .send_event(MithrilEvent::CardanoTransactionsProofsVerificationCompleted {
+                proofs_verification_id,
+            })
+            .await;
+
+        Ok(verified_transactions)
+    }
+
     /// Fetch a list of signed Cardano transaction snapshots.
     pub async fn list_snapshots(&self) -> MithrilResult<Vec<CardanoTransactionSnapshotListItem>> {
         let response = self
@@ -293,4 +589,250 @@ mod tests {
             .await
             .expect_err("The certificate client should fail here.");
     }
+
+    mod get_proofs_by_chunks {
+        use mithril_common::messages::{
+            AggregatorFeaturesMessage, CardanoTransactionsProverCapabilities,
+        };
+
+        use crate::feedback::StackFeedbackReceiver;
+
+        use super::*;
+
+        fn features_with_max_hashes_allowed_by_request(max_hashes: Option<usize>) -> String {
+            let mut features = AggregatorFeaturesMessage::dummy();
+            features.capabilities.cardano_transactions_prover =
+                max_hashes.map(|max_hashes| CardanoTransactionsProverCapabilities {
+                    max_hashes_allowed_by_request: max_hashes,
+                    is_cache_ready: None,
+                });
+
+            serde_json::to_string(&features).unwrap()
+        }
+
+        fn proofs_of(
+            certificate_hash: &str,
+            transactions_hashes: Vec<String>,
+        ) -> CardanoTransactionsProofs {
+            CardanoTransactionsProofs::new(
+                certificate_hash,
+                vec![],
+                transactions_hashes,
+                BlockNumber(99),
+            )
+        }
+
+        fn hashes(range: std::ops::Range<usize>) -> Vec<String> {
+            range.map(|i| format!("tx-{i}")).collect()
+        }
+
+        #[tokio::test]
+        async fn split_hashes_in_chunks_of_the_advertised_max_hashes_allowed_by_request() {
+            let mut aggregator_client = MockAggregatorHTTPClient::new();
+            aggregator_client
+                .expect_get_content()
+                .with(eq(AggregatorRequest::GetAggregatorFeatures))
+                .return_once(|_| Ok(features_with_max_hashes_allowed_by_request(Some(2))));
+            for chunk in [hashes(0..2), hashes(2..4), hashes(4..5)] {
+                let proofs = proofs_of("cert-hash", chunk.clone());
+                aggregator_client
+                    .expect_get_content()
+                    .with(eq(AggregatorRequest::GetTransactionsProofs {
+                        transactions_hashes: chunk,
+                    }))
+                    .return_once(move |_| Ok(serde_json::to_string(&proofs).unwrap()))
+                    .times(1);
+            }
+            let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client));
+
+            let aggregated_proofs = cardano_tx_client
+                .get_proofs_by_chunks(futures::stream::iter(hashes(0..5)))
+                .await
+                .unwrap();
+
+            assert_eq!(1, aggregated_proofs.proofs().len());
+            assert_eq!(hashes(0..5), aggregated_proofs.non_certified_transactions());
+        }
+
+        #[tokio::test]
+        async fn use_default_max_hashes_allowed_by_request_if_not_advertised() {
+            let mut aggregator_client = MockAggregatorHTTPClient::new();
+            aggregator_client
+                .expect_get_content()
+                .with(eq(AggregatorRequest::GetAggregatorFeatures))
+                .return_once(|_| Ok(features_with_max_hashes_allowed_by_request(None)));
+            let all_hashes = hashes(0..DEFAULT_MAX_HASHES_ALLOWED_BY_REQUEST + 1);
+            for chunk in all_hashes.chunks(DEFAULT_MAX_HASHES_ALLOWED_BY_REQUEST) {
+                let proofs = proofs_of("cert-hash", chunk.to_vec());
+                aggregator_client
+                    .expect_get_content()
+                    .with(eq(AggregatorRequest::GetTransactionsProofs {
+                        transactions_hashes: chunk.to_vec(),
+                    }))
+                    .return_once(move |_| Ok(serde_json::to_string(&proofs).unwrap()))
+                    .times(1);
+            }
+            let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client));
+
+            cardano_tx_client
+                .get_proofs_by_chunks(futures::stream::iter(all_hashes))
+                .await
+                .unwrap();
+        }
+
+        #[test]
+        fn merge_proofs_by_certificate() {
+            let mut aggregated_proofs = AggregatedCardanoTransactionsProofs::default();
+
+            aggregated_proofs.merge(proofs_of("cert-1", hashes(0..2)));
+            aggregated_proofs.merge(proofs_of("cert-2", hashes(2..3)));
+            aggregated_proofs.merge(proofs_of("cert-1", hashes(3..4)));
+
+            assert_eq!(
+                vec![
+                    proofs_of("cert-1", vec!["tx-0", "tx-1", "tx-3"].into_iter().map(String::from).collect()),
+                    proofs_of("cert-2", hashes(2..3)),
+                ],
+                aggregated_proofs.proofs()
+            );
+        }
+
+        #[test]
+        fn verify_fails_if_no_transaction_is_certified() {
+            let mut aggregated_proofs = AggregatedCardanoTransactionsProofs::default();
+            aggregated_proofs.merge(proofs_of("cert-1", hashes(0..2)));
+
+            let error = aggregated_proofs
+                .verify()
+                .expect_err("Verify should fail without certified transactions");
+
+            assert!(matches!(
+                error,
+                VerifyCardanoTransactionsProofsError::NoCertifiedTransaction
+            ));
+        }
+
+        #[test]
+        fn verify_each_certificate_proofs() {
+            let mut aggregated_proofs = AggregatedCardanoTransactionsProofs::default();
+            let set_proof = CardanoTransactionsSetProof::dummy();
+            aggregated_proofs.merge(CardanoTransactionsProofs::new(
+                "cert-1",
+                vec![set_proof.clone()],
+                vec![],
+                BlockNumber(99),
+            ));
+            aggregated_proofs.merge(proofs_of("cert-2", hashes(0..1)));
+            aggregated_proofs.merge(CardanoTransactionsProofs::new(
+                "cert-3",
+                vec![set_proof],
+                vec![],
+                BlockNumber(99),
+            ));
+
+            let verified_transactions = aggregated_proofs.verify().unwrap();
+
+            assert_eq!(
+                vec!["cert-1", "cert-3"],
+                verified_transactions
+                    .iter()
+                    .map(|verified| verified.certificate_hash())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(vec!["cert-1", "cert-3"], aggregated_proofs.certificate_hashes());
+        }
+
+        #[tokio::test]
+        async fn send_an_event_for_each_retrieved_chunk() {
+            let mut aggregator_client = MockAggregatorHTTPClient::new();
+            aggregator_client
+                .expect_get_content()
+                .with(eq(AggregatorRequest::GetAggregatorFeatures))
+                .return_once(|_| Ok(features_with_max_hashes_allowed_by_request(Some(2))));
+            for chunk in [hashes(0..2), hashes(2..3)] {
+                let proofs = proofs_of("cert-hash", chunk.clone());
+                aggregator_client
+                    .expect_get_content()
+                    .with(eq(AggregatorRequest::GetTransactionsProofs {
+                        transactions_hashes: chunk,
+                    }))
+                    .return_once(move |_| Ok(serde_json::to_string(&proofs).unwrap()));
+            }
+            let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+            let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client))
+                .with_feedback_sender(FeedbackSender::new(&[feedback_receiver.clone()]));
+
+            cardano_tx_client
+                .get_proofs_by_chunks(futures::stream::iter(hashes(0..3)))
+                .await
+                .unwrap();
+
+            let events = feedback_receiver.stacked_events();
+            let proofs_retrieval_id = events[0].event_id().to_string();
+            assert_eq!(
+                vec![
+                    MithrilEvent::CardanoTransactionsProofsRetrievalStarted {
+                        proofs_retrieval_id: proofs_retrieval_id.clone(),
+                    },
+                    MithrilEvent::CardanoTransactionsProofsChunkRetrieved {
+                        proofs_retrieval_id: proofs_retrieval_id.clone(),
+                        chunk_transactions_count: 2,
+                        retrieved_transactions_count: 2,
+                    },
+                    MithrilEvent::CardanoTransactionsProofsChunkRetrieved {
+                        proofs_retrieval_id: proofs_retrieval_id.clone(),
+                        chunk_transactions_count: 1,
+                        retrieved_transactions_count: 3,
+                    },
+                    MithrilEvent::CardanoTransactionsProofsRetrievalCompleted {
+                        proofs_retrieval_id,
+                        certified_transactions_count: 0,
+                        non_certified_transactions_count: 3,
+                    },
+                ],
+                events
+            );
+        }
+
+        #[tokio::test]
+        async fn verify_proofs_send_an_event_for_each_verified_certificate() {
+            let mut aggregated_proofs = AggregatedCardanoTransactionsProofs::default();
+            let set_proof = CardanoTransactionsSetProof::dummy();
+            aggregated_proofs.merge(CardanoTransactionsProofs::new(
+                "cert-1",
+                vec![set_proof.clone()],
+                vec![],
+                BlockNumber(99),
+            ));
+            aggregated_proofs.merge(proofs_of("cert-2", hashes(0..1)));
+            let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+            let cardano_tx_client =
+                CardanoTransactionClient::new(Arc::new(MockAggregatorHTTPClient::new()))
+                    .with_feedback_sender(FeedbackSender::new(&[feedback_receiver.clone()]));
+
+            cardano_tx_client
+                .verify_proofs(&aggregated_proofs)
+                .await
+                .unwrap();
+
+            let events = feedback_receiver.stacked_events();
+            let proofs_verification_id = events[0].event_id().to_string();
+            assert_eq!(
+                vec![
+                    MithrilEvent::CardanoTransactionsProofsVerificationStarted {
+                        proofs_verification_id: proofs_verification_id.clone(),
+                    },
+                    MithrilEvent::CardanoTransactionsProofsVerified {
+                        proofs_verification_id: proofs_verification_id.clone(),
+                        certificate_hash: "cert-1".to_string(),
+                        certified_transactions_count: set_proof.transactions_hashes.len(),
+                    },
+                    MithrilEvent::CardanoTransactionsProofsVerificationCompleted {
+                        proofs_verification_id,
+                    },
+                ],
+                events
+            );
+        }
+    }
 }
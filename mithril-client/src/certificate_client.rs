@@ -4,6 +4,8 @@
 //!  - [get][CertificateClient::get]: get a certificate data from its hash
 //!  - [list][CertificateClient::list]: get the list of available certificates
 //!  - [verify_chain][CertificateClient::verify_chain]: verify a certificate chain
+//!  - [get_chain][CertificateClient::get_chain]: get the certificates of a chain, down to the genesis certificate
+//!  - [get_verified_chain][CertificateClient::get_verified_chain]: verify a certificate chain and get its certificates
 //!
 //! # Get a certificate
 //!
@@ -55,6 +57,7 @@
 //! # }
 //! ```
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
@@ -149,6 +152,81 @@ impl CertificateClient {
 
         Ok(certificate)
     }
+
+    /// Validate the chain starting with the certificate with given `certificate_hash`, and return
+    /// the certificates of this chain, ordered from this certificate to the genesis certificate.
+    ///
+    /// The returned certificates are checked to be the validated ones: each certificate must
+    /// match its hash and be chained to the next one by its previous hash.
+    ///
+    /// Note: the chain is fetched again once validated, use an
+    /// [AggregatorResponseCache][crate::response_cache::AggregatorResponseCache] to only fetch
+    /// its certificates once from the aggregator.
+    pub async fn get_verified_chain(
+        &self,
+        certificate_hash: &str,
+    ) -> MithrilResult<Vec<MithrilCertificate>> {
+        let certificate = self.verify_chain(certificate_hash).await?;
+        let certificate_chain = self.get_chain(&certificate.hash).await?;
+
+        for (index, message) in certificate_chain.iter().enumerate() {
+            let certificate: Certificate = message.clone().try_into()?;
+            if certificate.hash != certificate.compute_hash() {
+                return Err(anyhow!(
+                    "Certificate '{}' of the chain does not match its hash",
+                    message.hash
+                ));
+            }
+            if let Some(previous_message) = certificate_chain.get(index + 1) {
+                if message.previous_hash != previous_message.hash {
+                    return Err(anyhow!(
+                        "Certificate '{}' of the chain is not chained to certificate '{}'",
+                        message.hash,
+                        previous_message.hash
+                    ));
+                }
+            }
+        }
+
+        Ok(certificate_chain)
+    }
+
+    /// Get the certificates of the chain starting with the certificate with given
+    /// `certificate_hash`, ordered from this certificate to the genesis certificate.
+    ///
+    /// Note: the chain is only fetched, use [verify_chain][CertificateClient::verify_chain] to
+    /// validate it.
+    pub async fn get_chain(
+        &self,
+        certificate_hash: &str,
+    ) -> MithrilResult<Vec<MithrilCertificate>> {
+        let mut certificate_chain = vec![];
+        let mut visited_certificate_hashes = HashSet::new();
+        let mut next_certificate_hash = certificate_hash.to_string();
+        loop {
+            if !visited_certificate_hashes.insert(next_certificate_hash.clone()) {
+                return Err(anyhow!(
+                    "Certificate chain has a loop: certificate '{next_certificate_hash}' is already in the chain"
+                ));
+            }
+            let certificate = self
+                .retriever
+                .get(&next_certificate_hash)
+                .await?
+                .ok_or(anyhow!(
+                    "No certificate exist for hash '{next_certificate_hash}'"
+                ))?;
+            let is_genesis = !certificate.genesis_signature.is_empty();
+            next_certificate_hash = certificate.previous_hash.clone();
+            certificate_chain.push(certificate);
+
+            if is_genesis {
+                break;
+            }
+        }
+
+        Ok(certificate_chain)
+    }
 }
 
 /// Internal type to implement the [InternalCertificateRetriever] trait and avoid a circular
@@ -516,4 +594,169 @@ mod tests {
 
         assert_eq!(certificate.hash, last_certificate_hash);
     }
+
+    #[tokio::test]
+    async fn get_chain_return_certificates_down_to_the_genesis_certificate() {
+        let (chain, _) = setup_certificate_chain(5, 2);
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        let last_certificate_hash = chain.first().unwrap().hash.clone();
+
+        for certificate in chain.clone() {
+            let hash = certificate.hash.clone();
+            let message = serde_json::to_string(
+                &TryInto::<CertificateMessage>::try_into(certificate).unwrap(),
+            )
+            .unwrap();
+            aggregator_client
+                .expect_get_content()
+                .with(eq(AggregatorRequest::GetCertificate { hash }))
+                .returning(move |_| Ok(message.to_owned()));
+        }
+        let certificate_client = build_client(Arc::new(aggregator_client), None);
+
+        let certificate_chain = certificate_client
+            .get_chain(&last_certificate_hash)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            chain.into_iter().map(|c| c.hash).collect::<Vec<_>>(),
+            certificate_chain
+                .into_iter()
+                .map(|c| c.hash)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_chain_fails_if_a_certificate_of_the_chain_does_not_exist() {
+        let (chain, _) = setup_certificate_chain(3, 1);
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        let last_certificate = chain.first().unwrap().clone();
+        let last_certificate_hash = last_certificate.hash.clone();
+        let message = serde_json::to_string(
+            &TryInto::<CertificateMessage>::try_into(last_certificate).unwrap(),
+        )
+        .unwrap();
+        aggregator_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetCertificate {
+                hash: last_certificate_hash.clone(),
+            }))
+            .returning(move |_| Ok(message.to_owned()));
+        aggregator_client.expect_get_content().returning(|_| {
+            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                "not found"
+            )))
+        });
+        let certificate_client = build_client(Arc::new(aggregator_client), None);
+
+        certificate_client
+            .get_chain(&last_certificate_hash)
+            .await
+            .expect_err("Getting a chain with a missing certificate should fail");
+    }
+
+    #[tokio::test]
+    async fn get_chain_fails_if_the_chain_has_a_loop() {
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        for (hash, previous_hash) in [("hash-1", "hash-2"), ("hash-2", "hash-1")] {
+            let message = serde_json::to_string(&MithrilCertificate {
+                hash: hash.to_string(),
+                previous_hash: previous_hash.to_string(),
+                ..MithrilCertificate::dummy()
+            })
+            .unwrap();
+            aggregator_client
+                .expect_get_content()
+                .with(eq(AggregatorRequest::GetCertificate {
+                    hash: hash.to_string(),
+                }))
+                .times(1)
+                .returning(move |_| Ok(message.to_owned()));
+        }
+        let certificate_client = build_client(Arc::new(aggregator_client), None);
+
+        certificate_client
+            .get_chain("hash-1")
+            .await
+            .expect_err("Getting a chain with a loop should fail");
+    }
+
+    fn build_client_serving_chain(
+        messages: Vec<CertificateMessage>,
+        verifier: MockCertificateVerifier,
+    ) -> CertificateClient {
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        for message in messages {
+            let hash = message.hash.clone();
+            let message = serde_json::to_string(&message).unwrap();
+            aggregator_client
+                .expect_get_content()
+                .with(eq(AggregatorRequest::GetCertificate { hash }))
+                .returning(move |_| Ok(message.to_owned()));
+        }
+
+        build_client(Arc::new(aggregator_client), Some(Arc::new(verifier)))
+    }
+
+    #[tokio::test]
+    async fn get_verified_chain_return_the_verified_certificates_down_to_the_genesis_certificate() {
+        let (chain, _) = setup_certificate_chain(5, 2);
+        let messages: Vec<CertificateMessage> = chain
+            .into_iter()
+            .map(|certificate| certificate.try_into().unwrap())
+            .collect();
+        let mut verifier = MockCertificateVerifier::new();
+        verifier
+            .expect_verify_chain()
+            .with(eq(messages[0].clone()))
+            .times(1)
+            .returning(|_| Ok(()));
+        let certificate_client = build_client_serving_chain(messages.clone(), verifier);
+
+        let certificate_chain = certificate_client
+            .get_verified_chain(&messages[0].hash)
+            .await
+            .unwrap();
+
+        assert_eq!(messages, certificate_chain);
+    }
+
+    #[tokio::test]
+    async fn get_verified_chain_fails_if_the_chain_is_invalid() {
+        let (chain, _) = setup_certificate_chain(3, 1);
+        let messages: Vec<CertificateMessage> = chain
+            .into_iter()
+            .map(|certificate| certificate.try_into().unwrap())
+            .collect();
+        let mut verifier = MockCertificateVerifier::new();
+        verifier
+            .expect_verify_chain()
+            .returning(|_| Err(anyhow!("invalid chain")));
+        let certificate_client = build_client_serving_chain(messages.clone(), verifier);
+
+        certificate_client
+            .get_verified_chain(&messages[0].hash)
+            .await
+            .expect_err("Getting the verified chain of an invalid chain should fail");
+    }
+
+    #[tokio::test]
+    async fn get_verified_chain_fails_if_a_certificate_does_not_match_its_hash() {
+        let (chain, _) = setup_certificate_chain(3, 1);
+        let mut messages: Vec<CertificateMessage> = chain
+            .into_iter()
+            .map(|certificate| certificate.try_into().unwrap())
+            .collect();
+        messages[1].signed_message = "tampered".to_string();
+        let mut verifier = MockCertificateVerifier::new();
+        verifier.expect_verify_chain().returning(|_| Ok(()));
+        let certificate_client = build_client_serving_chain(messages.clone(), verifier);
+
+        certificate_client
+            .get_verified_chain(&messages[0].hash)
+            .await
+            .expect_err("Getting a verified chain with a tampered certificate should fail");
+    }
 }
@@ -4,11 +4,14 @@ use serde::{Deserialize, Serialize};
 use slog::{o, Logger};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use mithril_common::api_version::APIVersionProvider;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorHTTPClient};
 #[cfg(feature = "unstable")]
+use crate::cardano_drep_stake_distribution_client::CardanoDRepStakeDistributionClient;
+#[cfg(feature = "unstable")]
 use crate::cardano_stake_distribution_client::CardanoStakeDistributionClient;
 #[cfg(feature = "unstable")]
 use crate::cardano_transaction_client::CardanoTransactionClient;
@@ -17,6 +20,8 @@ use crate::certificate_client::{
 };
 use crate::feedback::{FeedbackReceiver, FeedbackSender};
 use crate::mithril_stake_distribution_client::MithrilStakeDistributionClient;
+use crate::multi_aggregator_client::{AggregatorEndpoint, MultiAggregatorClient};
+use crate::response_cache::{AggregatorResponseCache, DEFAULT_LISTS_RESPONSE_CACHE_TTL};
 use crate::snapshot_client::SnapshotClient;
 #[cfg(feature = "fs")]
 use crate::snapshot_downloader::{HttpSnapshotDownloader, SnapshotDownloader};
@@ -28,6 +33,21 @@ pub struct ClientOptions {
     /// HTTP headers to include in the client requests.
     pub http_headers: Option<HashMap<String, String>>,
 
+    /// Number of aggregators that must return the same certificate or artifact before it's
+    /// accepted, only meaningful when several aggregators are configured.
+    #[serde(default)]
+    pub required_aggregators_agreement: Option<usize>,
+
+    /// Time to live, in seconds, of the cached responses of the routes that list the latest
+    /// artifacts, only meaningful when an aggregator response cache is set.
+    #[serde(default)]
+    pub lists_response_cache_ttl_seconds: Option<u64>,
+
+    /// Whether to cache the aggregator responses in the IndexedDB of the browser in the WASM client.
+    #[cfg(target_family = "wasm")]
+    #[cfg_attr(target_family = "wasm", serde(default))]
+    pub enable_response_cache: bool,
+
     /// Whether to enable unstable features in the WASM client.
     #[cfg(target_family = "wasm")]
     #[cfg_attr(target_family = "wasm", serde(default))]
@@ -39,16 +59,49 @@ impl ClientOptions {
     pub fn new(http_headers: Option<HashMap<String, String>>) -> Self {
         Self {
             http_headers,
+            required_aggregators_agreement: None,
+            lists_response_cache_ttl_seconds: None,
+            #[cfg(target_family = "wasm")]
+            enable_response_cache: false,
             #[cfg(target_family = "wasm")]
             unstable: false,
         }
     }
 
+    /// Set the time to live, in seconds, of the cached responses of the routes that list the
+    /// latest artifacts.
+    pub fn with_lists_response_cache_ttl_seconds(self, ttl_seconds: u64) -> Self {
+        Self {
+            lists_response_cache_ttl_seconds: Some(ttl_seconds),
+            ..self
+        }
+    }
+
+    /// Set the number of aggregators that must return the same certificate or artifact.
+    pub fn with_required_aggregators_agreement(
+        self,
+        required_aggregators_agreement: usize,
+    ) -> Self {
+        Self {
+            required_aggregators_agreement: Some(required_aggregators_agreement),
+            ..self
+        }
+    }
+
     /// Enable unstable features in the WASM client.
     #[cfg(target_family = "wasm")]
     pub fn with_unstable_features(self, unstable: bool) -> Self {
         Self { unstable, ..self }
     }
+
+    /// Enable the cache of the aggregator responses in the IndexedDB of the browser.
+    #[cfg(target_family = "wasm")]
+    pub fn with_response_cache(self, enable_response_cache: bool) -> Self {
+        Self {
+            enable_response_cache,
+            ..self
+        }
+    }
 }
 
 /// Structure that aggregates the available clients for each of the Mithril types of certified data.
@@ -60,6 +113,8 @@ pub struct Client {
     cardano_transaction_client: Arc<CardanoTransactionClient>,
     #[cfg(feature = "unstable")]
     cardano_stake_distribution_client: Arc<CardanoStakeDistributionClient>,
+    #[cfg(feature = "unstable")]
+    cardano_drep_stake_distribution_client: Arc<CardanoDRepStakeDistributionClient>,
     certificate_client: Arc<CertificateClient>,
     mithril_stake_distribution_client: Arc<MithrilStakeDistributionClient>,
     snapshot_client: Arc<SnapshotClient>,
@@ -92,13 +147,20 @@ impl Client {
     pub fn cardano_stake_distribution(&self) -> Arc<CardanoStakeDistributionClient> {
         self.cardano_stake_distribution_client.clone()
     }
+
+    /// Get the client that fetches Cardano DRep stake distributions.
+    #[cfg(feature = "unstable")]
+    pub fn cardano_drep_stake_distribution(&self) -> Arc<CardanoDRepStakeDistributionClient> {
+        self.cardano_drep_stake_distribution_client.clone()
+    }
 }
 
 /// Builder than can be used to create a [Client] easily or with custom dependencies.
 pub struct ClientBuilder {
-    aggregator_endpoint: Option<String>,
+    aggregator_endpoints: Vec<String>,
     genesis_verification_key: String,
     aggregator_client: Option<Arc<dyn AggregatorClient>>,
+    aggregator_response_cache: Option<Arc<dyn AggregatorResponseCache>>,
     certificate_verifier: Option<Arc<dyn CertificateVerifier>>,
     #[cfg(feature = "fs")]
     snapshot_downloader: Option<Arc<dyn SnapshotDownloader>>,
@@ -111,10 +173,20 @@ impl ClientBuilder {
     /// Constructs a new `ClientBuilder` that fetches data from the aggregator at the given
     /// endpoint and with the given genesis verification key.
     pub fn aggregator(endpoint: &str, genesis_verification_key: &str) -> ClientBuilder {
+        Self::aggregators(&[endpoint], genesis_verification_key)
+    }
+
+    /// Constructs a new `ClientBuilder` that fetches data from the aggregators at the given
+    /// endpoints, in order, and with the given genesis verification key.
+    ///
+    /// The client fails over to the next aggregator when one is unavailable, set
+    /// [ClientOptions::required_aggregators_agreement] to also cross-check their responses.
+    pub fn aggregators(endpoints: &[&str], genesis_verification_key: &str) -> ClientBuilder {
         Self {
-            aggregator_endpoint: Some(endpoint.to_string()),
+            aggregator_endpoints: endpoints.iter().map(|e| e.to_string()).collect(),
             genesis_verification_key: genesis_verification_key.to_string(),
             aggregator_client: None,
+            aggregator_response_cache: None,
             certificate_verifier: None,
             #[cfg(feature = "fs")]
             snapshot_downloader: None,
@@ -130,9 +202,10 @@ impl ClientBuilder {
     /// to request data from the aggregator.
     pub fn new(genesis_verification_key: &str) -> ClientBuilder {
         Self {
-            aggregator_endpoint: None,
+            aggregator_endpoints: vec![],
             genesis_verification_key: genesis_verification_key.to_string(),
             aggregator_client: None,
+            aggregator_response_cache: None,
             certificate_verifier: None,
             #[cfg(feature = "fs")]
             snapshot_downloader: None,
@@ -155,23 +228,48 @@ impl ClientBuilder {
 
         let aggregator_client = match self.aggregator_client {
             None => {
-                let endpoint = self
-                    .aggregator_endpoint
-                    .ok_or(anyhow!("No aggregator endpoint set: \
-                    You must either provide an aggregator endpoint or your own AggregatorClient implementation"))?;
-                let endpoint_url = Url::parse(&endpoint)
-                    .with_context(|| format!("Invalid aggregator endpoint, it must be a correctly formed url: '{endpoint}'"))?;
-
-                Arc::new(
-                    AggregatorHTTPClient::new(
+                if self.aggregator_endpoints.is_empty() {
+                    return Err(anyhow!("No aggregator endpoint set: \
+                    You must either provide an aggregator endpoint or your own AggregatorClient implementation"));
+                }
+
+                let mut aggregators = vec![];
+                for endpoint in &self.aggregator_endpoints {
+                    let endpoint_url = Url::parse(endpoint)
+                        .with_context(|| format!("Invalid aggregator endpoint, it must be a correctly formed url: '{endpoint}'"))?;
+                    let mut http_client = AggregatorHTTPClient::new(
                         endpoint_url,
                         APIVersionProvider::compute_all_versions_sorted()
                             .with_context(|| "Could not compute aggregator api versions")?,
                         logger.clone(),
-                        self.options.http_headers,
+                        self.options.http_headers.clone(),
                     )
-                    .with_context(|| "Building aggregator client failed")?,
-                )
+                    .with_context(|| "Building aggregator client failed")?
+                    .with_feedback_sender(feedback_sender.clone());
+                    if let Some(response_cache) = &self.aggregator_response_cache {
+                        http_client = http_client.with_response_cache(
+                            response_cache.clone(),
+                            self.options
+                                .lists_response_cache_ttl_seconds
+                                .map(Duration::from_secs)
+                                .unwrap_or(DEFAULT_LISTS_RESPONSE_CACHE_TTL),
+                        );
+                    }
+                    let client: Arc<dyn AggregatorClient> = Arc::new(http_client);
+                    aggregators.push(AggregatorEndpoint::new(endpoint, client));
+                }
+
+                let required_agreements = self.options.required_aggregators_agreement.unwrap_or(1);
+                if aggregators.len() == 1 && required_agreements <= 1 {
+                    aggregators.remove(0).client
+                } else {
+                    Arc::new(
+                        MultiAggregatorClient::new(aggregators, logger.clone())?
+                            .with_required_agreements(required_agreements)
+                            .with_context(|| "Building multi aggregator client failed")?
+                            .with_feedback_sender(feedback_sender.clone()),
+                    )
+                }
             }
             Some(client) => client,
         };
@@ -186,8 +284,10 @@ impl ClientBuilder {
         };
 
         #[cfg(feature = "unstable")]
-        let cardano_transaction_client =
-            Arc::new(CardanoTransactionClient::new(aggregator_client.clone()));
+        let cardano_transaction_client = Arc::new(
+            CardanoTransactionClient::new(aggregator_client.clone())
+                .with_feedback_sender(feedback_sender.clone()),
+        );
 
         let certificate_verifier = match self.certificate_verifier {
             None => Arc::new(
@@ -207,11 +307,25 @@ impl ClientBuilder {
             logger.clone(),
         ));
 
-        let mithril_stake_distribution_client = Arc::new(MithrilStakeDistributionClient::new(
-            aggregator_client.clone(),
-        ));
+        let mithril_stake_distribution_client = Arc::new(
+            MithrilStakeDistributionClient::new(aggregator_client.clone())
+                .with_feedback_sender(feedback_sender.clone()),
+        );
+
+        #[cfg(feature = "unstable")]
+        let cardano_stake_distribution_client = Arc::new(
+            CardanoStakeDistributionClient::new(aggregator_client.clone())
+                .with_feedback_sender(feedback_sender.clone()),
+        );
+
+        #[cfg(feature = "unstable")]
+        let cardano_drep_stake_distribution_client = Arc::new(
+            CardanoDRepStakeDistributionClient::new(aggregator_client.clone())
+                .with_feedback_sender(feedback_sender.clone()),
+        );
+
         let snapshot_client = Arc::new(SnapshotClient::new(
-            aggregator_client.clone(),
+            aggregator_client,
             #[cfg(feature = "fs")]
             snapshot_downloader,
             #[cfg(feature = "fs")]
@@ -220,15 +334,13 @@ impl ClientBuilder {
             logger,
         ));
 
-        #[cfg(feature = "unstable")]
-        let cardano_stake_distribution_client =
-            Arc::new(CardanoStakeDistributionClient::new(aggregator_client));
-
         Ok(Client {
             #[cfg(feature = "unstable")]
             cardano_transaction_client,
             #[cfg(feature = "unstable")]
             cardano_stake_distribution_client,
+            #[cfg(feature = "unstable")]
+            cardano_drep_stake_distribution_client,
             certificate_client,
             mithril_stake_distribution_client,
             snapshot_client,
@@ -244,6 +356,16 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the [AggregatorResponseCache] that will be used to keep the responses of the
+    /// aggregator, ignored if a custom [AggregatorClient] is set.
+    pub fn with_aggregator_response_cache(
+        mut self,
+        aggregator_response_cache: Arc<dyn AggregatorResponseCache>,
+    ) -> ClientBuilder {
+        self.aggregator_response_cache = Some(aggregator_response_cache);
+        self
+    }
+
     /// Set the [CertificateVerifier] that will be used to validate certificates.
     pub fn with_certificate_verifier(
         mut self,
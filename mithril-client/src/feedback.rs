@@ -6,6 +6,9 @@
 //! Those tasks are:
 //! - Snapshot download
 //! - Certificate chain validation
+//! - Cardano transactions proofs retrieval and verification
+//! - Stake distribution retrieval and message computation
+//! - Retries of the requests sent to an aggregator
 //!
 //! In order to have feedbacks for those tasks, a mechanism is available.
 //!
@@ -58,10 +61,14 @@ use std::sync::{Arc, RwLock};
 use strum::Display;
 use uuid::Uuid;
 
+use crate::common::ProtocolMessage;
+use crate::MithrilResult;
+
 /// Event that can be reported by a [FeedbackReceiver].
 #[derive(Debug, Clone, Eq, PartialEq, Display, Serialize)]
 #[strum(serialize_all = "PascalCase")]
 #[serde(untagged)]
+#[non_exhaustive]
 pub enum MithrilEvent {
     /// A snapshot download has started
     SnapshotDownloadStarted {
@@ -103,6 +110,100 @@ pub enum MithrilEvent {
         /// Unique identifier used to track this specific certificate chain validation
         certificate_chain_validation_id: String,
     },
+    /// A retrieval of the proofs of a set of Cardano transactions has started
+    CardanoTransactionsProofsRetrievalStarted {
+        /// Unique identifier used to track this specific proofs retrieval
+        proofs_retrieval_id: String,
+    },
+    /// The proofs of a chunk of Cardano transactions have been retrieved
+    CardanoTransactionsProofsChunkRetrieved {
+        /// Unique identifier used to track this specific proofs retrieval
+        proofs_retrieval_id: String,
+        /// Number of transactions in the retrieved chunk
+        chunk_transactions_count: usize,
+        /// Number of transactions which proofs have been retrieved so far
+        retrieved_transactions_count: usize,
+    },
+    /// A retrieval of the proofs of a set of Cardano transactions has completed
+    CardanoTransactionsProofsRetrievalCompleted {
+        /// Unique identifier used to track this specific proofs retrieval
+        proofs_retrieval_id: String,
+        /// Number of transactions that are certified
+        certified_transactions_count: usize,
+        /// Number of transactions that could not be certified
+        non_certified_transactions_count: usize,
+    },
+    /// A verification of the proofs of a set of Cardano transactions has started
+    CardanoTransactionsProofsVerificationStarted {
+        /// Unique identifier used to track this specific proofs verification
+        proofs_verification_id: String,
+    },
+    /// The proofs of the Cardano transactions signed by a certificate have been verified
+    CardanoTransactionsProofsVerified {
+        /// Unique identifier used to track this specific proofs verification
+        proofs_verification_id: String,
+        /// Hash of the certificate that signs the verified proofs
+        certificate_hash: String,
+        /// Number of transactions that are certified by the verified proofs
+        certified_transactions_count: usize,
+    },
+    /// All the proofs of a set of Cardano transactions are valid
+    CardanoTransactionsProofsVerificationCompleted {
+        /// Unique identifier used to track this specific proofs verification
+        proofs_verification_id: String,
+    },
+    /// A stake distribution retrieval has started
+    StakeDistributionRetrievalStarted {
+        /// Unique identifier used to track this specific stake distribution retrieval
+        stake_distribution_retrieval_id: String,
+        /// Type of the retrieved stake distribution
+        stake_distribution_type: StakeDistributionType,
+        /// Hash, or epoch, that identifies the retrieved stake distribution
+        identifier: String,
+    },
+    /// A stake distribution retrieval has completed
+    StakeDistributionRetrievalCompleted {
+        /// Unique identifier used to track this specific stake distribution retrieval
+        stake_distribution_retrieval_id: String,
+        /// Whether the stake distribution was found on the aggregator
+        found: bool,
+    },
+    /// A computation of the message of a stake distribution has started
+    StakeDistributionMessageComputationStarted {
+        /// Unique identifier used to track this specific message computation
+        message_computation_id: String,
+        /// Type of the stake distribution
+        stake_distribution_type: StakeDistributionType,
+        /// Number of stakes in the stake distribution
+        stakes_count: usize,
+    },
+    /// The message of a stake distribution has been computed
+    StakeDistributionMessageComputed {
+        /// Unique identifier used to track this specific message computation
+        message_computation_id: String,
+    },
+    /// A request sent to an aggregator failed and is retried
+    AggregatorRequestRetried {
+        /// Unique identifier used to track the retries of this specific request
+        aggregator_request_id: String,
+        /// Endpoint of the aggregator the request is retried on
+        aggregator_endpoint: String,
+        /// Number of the upcoming attempt, starting at 2 for the first retry
+        attempt: usize,
+        /// Why the request is retried
+        reason: String,
+    },
+}
+
+/// Type of stake distribution reported by a [MithrilEvent].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Display, Serialize)]
+pub enum StakeDistributionType {
+    /// A [Mithril stake distribution][crate::MithrilStakeDistribution]
+    Mithril,
+    /// A Cardano stake distribution
+    Cardano,
+    /// A Cardano DRep stake distribution
+    CardanoDRep,
 }
 
 impl MithrilEvent {
@@ -116,6 +217,31 @@ impl MithrilEvent {
         Uuid::new_v4().to_string()
     }
 
+    /// Generate a random unique identifier to identify a Cardano transactions proofs retrieval
+    pub fn new_proofs_retrieval_id() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    /// Generate a random unique identifier to identify a Cardano transactions proofs verification
+    pub fn new_proofs_verification_id() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    /// Generate a random unique identifier to identify a stake distribution retrieval
+    pub fn new_stake_distribution_retrieval_id() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    /// Generate a random unique identifier to identify a message computation
+    pub fn new_message_computation_id() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    /// Generate a random unique identifier to identify a request sent to an aggregator
+    pub fn new_aggregator_request_id() -> String {
+        Uuid::new_v4().to_string()
+    }
+
     #[cfg(test)]
     pub(crate) fn event_id(&self) -> &str {
         match self {
@@ -132,6 +258,46 @@ impl MithrilEvent {
             MithrilEvent::CertificateChainValidated {
                 certificate_chain_validation_id,
             } => certificate_chain_validation_id,
+            MithrilEvent::CardanoTransactionsProofsRetrievalStarted {
+                proofs_retrieval_id,
+            }
+            | MithrilEvent::CardanoTransactionsProofsChunkRetrieved {
+                proofs_retrieval_id,
+                ..
+            }
+            | MithrilEvent::CardanoTransactionsProofsRetrievalCompleted {
+                proofs_retrieval_id,
+                ..
+            } => proofs_retrieval_id,
+            MithrilEvent::CardanoTransactionsProofsVerificationStarted {
+                proofs_verification_id,
+            }
+            | MithrilEvent::CardanoTransactionsProofsVerified {
+                proofs_verification_id,
+                ..
+            }
+            | MithrilEvent::CardanoTransactionsProofsVerificationCompleted {
+                proofs_verification_id,
+            } => proofs_verification_id,
+            MithrilEvent::StakeDistributionRetrievalStarted {
+                stake_distribution_retrieval_id,
+                ..
+            }
+            | MithrilEvent::StakeDistributionRetrievalCompleted {
+                stake_distribution_retrieval_id,
+                ..
+            } => stake_distribution_retrieval_id,
+            MithrilEvent::StakeDistributionMessageComputationStarted {
+                message_computation_id,
+                ..
+            }
+            | MithrilEvent::StakeDistributionMessageComputed {
+                message_computation_id,
+            } => message_computation_id,
+            MithrilEvent::AggregatorRequestRetried {
+                aggregator_request_id,
+                ..
+            } => aggregator_request_id,
         }
     }
 }
@@ -158,6 +324,34 @@ impl FeedbackSender {
             receiver.handle_event(event.clone()).await;
         }
     }
+
+    /// Run the given computation of the message of a stake distribution, and send the events
+    /// reporting its progress to the known receivers.
+    pub(crate) async fn compute_stake_distribution_message<F>(
+        &self,
+        stake_distribution_type: StakeDistributionType,
+        stakes_count: usize,
+        compute_message: F,
+    ) -> MithrilResult<ProtocolMessage>
+    where
+        F: FnOnce() -> MithrilResult<ProtocolMessage>,
+    {
+        let message_computation_id = MithrilEvent::new_message_computation_id();
+        self.send_event(MithrilEvent::StakeDistributionMessageComputationStarted {
+            message_computation_id: message_computation_id.clone(),
+            stake_distribution_type,
+            stakes_count,
+        })
+        .await;
+
+        let message = compute_message()?;
+        self.send_event(MithrilEvent::StakeDistributionMessageComputed {
+            message_computation_id,
+        })
+        .await;
+
+        Ok(message)
+    }
 }
 
 /// A receiver of [MithrilEvent].
@@ -243,6 +437,133 @@ impl FeedbackReceiver for SlogFeedbackReceiver {
                     "certificate_chain_validation_id" => certificate_chain_validation_id,
                 );
             }
+            MithrilEvent::CardanoTransactionsProofsRetrievalStarted {
+                proofs_retrieval_id,
+            } => {
+                info!(
+                    self.logger,
+                    "Cardano transactions proofs retrieval started";
+                    "proofs_retrieval_id" => proofs_retrieval_id,
+                );
+            }
+            MithrilEvent::CardanoTransactionsProofsChunkRetrieved {
+                proofs_retrieval_id,
+                chunk_transactions_count,
+                retrieved_transactions_count,
+            } => {
+                info!(
+                    self.logger,
+                    "Cardano transactions proofs chunk retrieved";
+                    "chunk_transactions_count" => chunk_transactions_count,
+                    "retrieved_transactions_count" => retrieved_transactions_count,
+                    "proofs_retrieval_id" => proofs_retrieval_id,
+                );
+            }
+            MithrilEvent::CardanoTransactionsProofsRetrievalCompleted {
+                proofs_retrieval_id,
+                certified_transactions_count,
+                non_certified_transactions_count,
+            } => {
+                info!(
+                    self.logger,
+                    "Cardano transactions proofs retrieval completed";
+                    "certified_transactions_count" => certified_transactions_count,
+                    "non_certified_transactions_count" => non_certified_transactions_count,
+                    "proofs_retrieval_id" => proofs_retrieval_id,
+                );
+            }
+            MithrilEvent::CardanoTransactionsProofsVerificationStarted {
+                proofs_verification_id,
+            } => {
+                info!(
+                    self.logger,
+                    "Cardano transactions proofs verification started";
+                    "proofs_verification_id" => proofs_verification_id,
+                );
+            }
+            MithrilEvent::CardanoTransactionsProofsVerified {
+                proofs_verification_id,
+                certificate_hash,
+                certified_transactions_count,
+            } => {
+                info!(
+                    self.logger,
+                    "Cardano transactions proofs verified";
+                    "certificate_hash" => certificate_hash,
+                    "certified_transactions_count" => certified_transactions_count,
+                    "proofs_verification_id" => proofs_verification_id,
+                );
+            }
+            MithrilEvent::CardanoTransactionsProofsVerificationCompleted {
+                proofs_verification_id,
+            } => {
+                info!(
+                    self.logger,
+                    "Cardano transactions proofs verification completed";
+                    "proofs_verification_id" => proofs_verification_id,
+                );
+            }
+            MithrilEvent::StakeDistributionRetrievalStarted {
+                stake_distribution_retrieval_id,
+                stake_distribution_type,
+                identifier,
+            } => {
+                info!(
+                    self.logger,
+                    "Stake distribution retrieval started";
+                    "stake_distribution_type" => %stake_distribution_type,
+                    "identifier" => identifier,
+                    "stake_distribution_retrieval_id" => stake_distribution_retrieval_id,
+                );
+            }
+            MithrilEvent::StakeDistributionRetrievalCompleted {
+                stake_distribution_retrieval_id,
+                found,
+            } => {
+                info!(
+                    self.logger,
+                    "Stake distribution retrieval completed";
+                    "found" => found,
+                    "stake_distribution_retrieval_id" => stake_distribution_retrieval_id,
+                );
+            }
+            MithrilEvent::StakeDistributionMessageComputationStarted {
+                message_computation_id,
+                stake_distribution_type,
+                stakes_count,
+            } => {
+                info!(
+                    self.logger,
+                    "Stake distribution message computation started";
+                    "stake_distribution_type" => %stake_distribution_type,
+                    "stakes_count" => stakes_count,
+                    "message_computation_id" => message_computation_id,
+                );
+            }
+            MithrilEvent::StakeDistributionMessageComputed {
+                message_computation_id,
+            } => {
+                info!(
+                    self.logger,
+                    "Stake distribution message computed";
+                    "message_computation_id" => message_computation_id,
+                );
+            }
+            MithrilEvent::AggregatorRequestRetried {
+                aggregator_request_id,
+                aggregator_endpoint,
+                attempt,
+                reason,
+            } => {
+                info!(
+                    self.logger,
+                    "Aggregator request retried";
+                    "aggregator_endpoint" => aggregator_endpoint,
+                    "attempt" => attempt,
+                    "reason" => reason,
+                    "aggregator_request_id" => aggregator_request_id,
+                );
+            }
         };
     }
 }
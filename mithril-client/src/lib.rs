@@ -11,6 +11,9 @@
 //! - [Cardano transactions][cardano_transaction_client] list & get snapshot, get proofs
 //!   _(available using crate feature_ **unstable**_)_.
 //! - [Certificates][certificate_client] list, get, and chain validation.
+//! - [Verification bundles][verification_bundle] export and offline verification.
+//! - [Several aggregators][multi_aggregator_client] with fail over and cross-checking.
+//! - [Response cache][response_cache] to avoid fetching again the aggregator responses.
 //!
 //! The [Client] aggregates the queries of all of those types.
 //!
@@ -84,6 +87,7 @@ macro_rules! cfg_unstable {
 
 pub mod aggregator_client;
 cfg_unstable! {
+    pub mod cardano_drep_stake_distribution_client;
     pub mod cardano_stake_distribution_client;
     pub mod cardano_transaction_client;
 }
@@ -92,10 +96,13 @@ mod client;
 pub mod feedback;
 mod message;
 pub mod mithril_stake_distribution_client;
+pub mod multi_aggregator_client;
+pub mod response_cache;
 pub mod snapshot_client;
 cfg_fs! {
     pub mod snapshot_downloader;
 }
+pub mod verification_bundle;
 
 mod type_alias;
 mod utils;
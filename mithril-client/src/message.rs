@@ -1,7 +1,9 @@
 use anyhow::Context;
 use mithril_common::protocol::SignerBuilder;
 #[cfg(feature = "unstable")]
-use mithril_common::signable_builder::CardanoStakeDistributionSignableBuilder;
+use mithril_common::signable_builder::{
+    CardanoDRepStakeDistributionSignableBuilder, CardanoStakeDistributionSignableBuilder,
+};
 #[cfg(feature = "fs")]
 use mithril_common::{
     digesters::{CardanoImmutableDigester, ImmutableDigester},
@@ -14,11 +16,11 @@ use std::path::Path;
 use std::sync::Arc;
 
 use crate::common::{ProtocolMessage, ProtocolMessagePartKey};
-#[cfg(feature = "unstable")]
-use crate::CardanoStakeDistribution;
 use crate::MithrilCertificate;
 #[cfg(feature = "unstable")]
 use crate::VerifiedCardanoTransactions;
+#[cfg(feature = "unstable")]
+use crate::{CardanoDRepStakeDistribution, CardanoStakeDistribution};
 use crate::{MithrilResult, MithrilSigner, MithrilStakeDistribution};
 
 /// A [MessageBuilder] can be used to compute the message of Mithril artifacts.
@@ -165,6 +167,29 @@ impl MessageBuilder {
 
             Ok(message)
         }
+
+        /// Compute message for a Cardano DRep stake distribution.
+        pub fn compute_cardano_drep_stake_distribution_message(
+            &self,
+            certificate: &MithrilCertificate,
+            cardano_drep_stake_distribution: &CardanoDRepStakeDistribution,
+        ) -> MithrilResult<ProtocolMessage> {
+            let mk_tree = CardanoDRepStakeDistributionSignableBuilder::compute_merkle_tree_from_drep_stake_distribution(
+                cardano_drep_stake_distribution.drep_stake_distribution.clone(),
+            )?;
+
+            let mut message = certificate.protocol_message.clone();
+            message.set_message_part(
+                ProtocolMessagePartKey::CardanoDRepStakeDistributionEpoch,
+                cardano_drep_stake_distribution.epoch.to_string(),
+            );
+            message.set_message_part(
+                ProtocolMessagePartKey::CardanoDRepStakeDistributionMerkleRoot,
+                mk_tree.compute_root()?.to_hex(),
+            );
+
+            Ok(message)
+        }
     }
 }
 
@@ -3,6 +3,7 @@
 //! In order to do so it defines a [MithrilStakeDistributionClient] which exposes the following features:
 //!  - [get][MithrilStakeDistributionClient::get]: get a Mithril stake distribution data from its hash
 //!  - [list][MithrilStakeDistributionClient::list]: get the list of available Mithril stake distribution
+//!  - [compute_message][MithrilStakeDistributionClient::compute_message]: compute the message of a Mithril stake distribution signed by a certificate
 //!
 //! # Get a Mithril stake distribution
 //!
@@ -43,17 +44,33 @@ use std::sync::Arc;
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
 use anyhow::Context;
 
-use crate::{MithrilResult, MithrilStakeDistribution, MithrilStakeDistributionListItem};
+use crate::common::ProtocolMessage;
+use crate::feedback::{FeedbackSender, MithrilEvent, StakeDistributionType};
+use crate::{
+    MessageBuilder, MithrilCertificate, MithrilResult, MithrilStakeDistribution,
+    MithrilStakeDistributionListItem,
+};
 
 /// HTTP client for MithrilStakeDistribution API from the Aggregator
 pub struct MithrilStakeDistributionClient {
     aggregator_client: Arc<dyn AggregatorClient>,
+    feedback_sender: FeedbackSender,
 }
 
 impl MithrilStakeDistributionClient {
     /// Constructs a new `MithrilStakeDistributionClient`.
     pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
-        Self { aggregator_client }
+        Self {
+            aggregator_client,
+            feedback_sender: FeedbackSender::new(&[]),
+        }
+    }
+
+    /// Send the [events][MithrilEvent] of the stake distribution retrievals and message
+    /// computations to the given sender.
+    pub fn with_feedback_sender(mut self, feedback_sender: FeedbackSender) -> Self {
+        self.feedback_sender = feedback_sender;
+        self
     }
 
     /// Fetch a list of signed MithrilStakeDistribution
@@ -71,7 +88,16 @@ impl MithrilStakeDistributionClient {
 
     /// Get the given stake distribution data. If it cannot be found, a None is returned.
     pub async fn get(&self, hash: &str) -> MithrilResult<Option<MithrilStakeDistribution>> {
-        match self
+        let stake_distribution_retrieval_id = MithrilEvent::new_stake_distribution_retrieval_id();
+        self.feedback_sender
+            .send_event(MithrilEvent::StakeDistributionRetrievalStarted {
+                stake_distribution_retrieval_id: stake_distribution_retrieval_id.clone(),
+                stake_distribution_type: StakeDistributionType::Mithril,
+                identifier: hash.to_string(),
+            })
+            .await;
+
+        let stake_distribution = match self
             .aggregator_client
             .get_content(AggregatorRequest::GetMithrilStakeDistribution {
                 hash: hash.to_string(),
@@ -84,11 +110,41 @@ impl MithrilStakeDistributionClient {
                         "MithrilStakeDistribution Client can not deserialize artifact"
                     })?;
 
-                Ok(Some(stake_distribution_entity))
+                Some(stake_distribution_entity)
             }
-            Err(AggregatorClientError::RemoteServerLogical(_)) => Ok(None),
-            Err(e) => Err(e.into()),
-        }
+            Err(AggregatorClientError::RemoteServerLogical(_)) => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        self.feedback_sender
+            .send_event(MithrilEvent::StakeDistributionRetrievalCompleted {
+                stake_distribution_retrieval_id,
+                found: stake_distribution.is_some(),
+            })
+            .await;
+
+        Ok(stake_distribution)
+    }
+
+    /// Compute the message of the given Mithril stake distribution signed by the given
+    /// certificate, see [MessageBuilder::compute_mithril_stake_distribution_message].
+    pub async fn compute_message(
+        &self,
+        certificate: &MithrilCertificate,
+        mithril_stake_distribution: &MithrilStakeDistribution,
+    ) -> MithrilResult<ProtocolMessage> {
+        self.feedback_sender
+            .compute_stake_distribution_message(
+                StakeDistributionType::Mithril,
+                mithril_stake_distribution.signers_with_stake.len(),
+                || {
+                    MessageBuilder::new().compute_mithril_stake_distribution_message(
+                        certificate,
+                        mithril_stake_distribution,
+                    )
+                },
+            )
+            .await
     }
 }
 
@@ -0,0 +1,496 @@
+//! An [AggregatorClient] that dispatches the requests to several aggregators.
+//!
+//! The [MultiAggregatorClient] queries the aggregators in their configuration order and fails
+//! over to the next one when an aggregator is unreachable, or answers with a server error.
+//!
+//! It can also cross-check the aggregators: when a number of required agreements greater than
+//! one is set, the requests that target an identified certificate or artifact (ie: get a
+//! certificate from its hash) are sent to all the aggregators, and a response is only returned
+//! if at least this number of aggregators returned the same content.
+//!
+//! Requests that list the latest artifacts or post data are never cross-checked since the
+//! aggregators can legitimately answer them differently.
+//!
+//! # Use several aggregators
+//!
+//! Use the [ClientBuilder][crate::client::ClientBuilder] to create a client with several
+//! aggregators that requires that two of them agree on a certificate:
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, ClientOptions};
+//!
+//! let client = ClientBuilder::aggregators(
+//!         &["YOUR_AGGREGATOR_ENDPOINT", "YOUR_OTHER_AGGREGATOR_ENDPOINT"],
+//!         "YOUR_GENESIS_VERIFICATION_KEY",
+//!     )
+//!     .with_options(ClientOptions::new(None).with_required_aggregators_agreement(2))
+//!     .build()?;
+//! let certificate = client.certificate().verify_chain("CERTIFICATE_HASH").await?;
+//!
+//! println!("Chain of Certificate (hash: {}) is valid", certificate.hash);
+//! #    Ok(())
+//! # }
+//! ```
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use slog::{debug, warn, Logger};
+use std::sync::Arc;
+
+use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::feedback::{FeedbackSender, MithrilEvent};
+use crate::MithrilResult;
+
+/// An aggregator used by a [MultiAggregatorClient], identified by its endpoint.
+pub struct AggregatorEndpoint {
+    /// Endpoint of the aggregator, used to identify it in the logs and errors
+    pub endpoint: String,
+
+    /// Client used to query the aggregator
+    pub client: Arc<dyn AggregatorClient>,
+}
+
+impl AggregatorEndpoint {
+    /// Constructs a new `AggregatorEndpoint`.
+    pub fn new(endpoint: &str, client: Arc<dyn AggregatorClient>) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            client,
+        }
+    }
+}
+
+/// An [AggregatorClient] that fails over between several aggregators and optionally
+/// cross-checks their responses.
+pub struct MultiAggregatorClient {
+    aggregators: Vec<AggregatorEndpoint>,
+    required_agreements: usize,
+    feedback_sender: FeedbackSender,
+    logger: Logger,
+}
+
+impl MultiAggregatorClient {
+    /// Constructs a new `MultiAggregatorClient` that fails over between the given aggregators,
+    /// in order, without cross-checking them.
+    pub fn new(aggregators: Vec<AggregatorEndpoint>, logger: Logger) -> MithrilResult<Self> {
+        if aggregators.is_empty() {
+            return Err(anyhow!(
+                "At least one aggregator is needed by the multi aggregator client"
+            ));
+        }
+
+        Ok(Self {
+            aggregators,
+            required_agreements: 1,
+            feedback_sender: FeedbackSender::new(&[]),
+            logger,
+        })
+    }
+
+    /// Send an [event][MithrilEvent] to the given sender each time a request fails over to the
+    /// next aggregator.
+    pub fn with_feedback_sender(mut self, feedback_sender: FeedbackSender) -> Self {
+        self.feedback_sender = feedback_sender;
+        self
+    }
+
+    /// Set the number of aggregators that must return the same content for a request that
+    /// targets an identified certificate or artifact.
+    pub fn with_required_agreements(mut self, required_agreements: usize) -> MithrilResult<Self> {
+        if required_agreements == 0 || required_agreements > self.aggregators.len() {
+            return Err(anyhow!(
+                "The number of required aggregators agreements must be between 1 and the number of aggregators ({}), got: {required_agreements}",
+                self.aggregators.len()
+            ));
+        }
+        self.required_agreements = required_agreements;
+
+        Ok(self)
+    }
+
+    /// Only errors that signal an unavailable aggregator trigger a fail over, a logical error
+    /// (ie: a not found artifact) is a valid answer.
+    fn should_fail_over(error: &AggregatorClientError) -> bool {
+        !matches!(error, AggregatorClientError::RemoteServerLogical(_))
+    }
+
+    /// Notify that the request will be retried on the aggregator at the given index, if any.
+    async fn send_fail_over_event(
+        &self,
+        aggregator_request_id: &str,
+        next_aggregator_index: usize,
+        error: &AggregatorClientError,
+    ) {
+        if let Some(next_aggregator) = self.aggregators.get(next_aggregator_index) {
+            self.feedback_sender
+                .send_event(MithrilEvent::AggregatorRequestRetried {
+                    aggregator_request_id: aggregator_request_id.to_string(),
+                    aggregator_endpoint: next_aggregator.endpoint.clone(),
+                    attempt: next_aggregator_index + 1,
+                    reason: format!("Previous aggregator unavailable: {error}"),
+                })
+                .await;
+        }
+    }
+
+    async fn get_content_with_fail_over(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        let aggregator_request_id = MithrilEvent::new_aggregator_request_id();
+        let mut last_error = None;
+        for (index, aggregator) in self.aggregators.iter().enumerate() {
+            match aggregator.client.get_content(request.clone()).await {
+                Ok(content) => return Ok(content),
+                Err(error) if Self::should_fail_over(&error) => {
+                    warn!(
+                        self.logger, "Aggregator unavailable, failing over to the next one";
+                        "endpoint" => &aggregator.endpoint, "request" => ?request, "error" => ?error
+                    );
+                    self.send_fail_over_event(&aggregator_request_id, index + 1, &error)
+                        .await;
+                    last_error = Some(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        Err(last_error.unwrap())
+    }
+
+    async fn get_content_with_cross_check(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        let responses = futures::future::join_all(
+            self.aggregators
+                .iter()
+                .map(|aggregator| aggregator.client.get_content(request.clone())),
+        )
+        .await;
+
+        // Responses are compared as JSON values so that formatting differences are ignored
+        let mut agreeing_groups: Vec<(serde_json::Value, String, usize)> = vec![];
+        let mut errors = vec![];
+        for (aggregator, response) in self.aggregators.iter().zip(responses) {
+            match response {
+                Ok(content) => {
+                    let value = serde_json::from_str::<serde_json::Value>(&content)
+                        .unwrap_or_else(|_| serde_json::Value::String(content.clone()));
+                    match agreeing_groups.iter_mut().find(|(v, _, _)| *v == value) {
+                        Some((_, _, count)) => *count += 1,
+                        None => agreeing_groups.push((value, content, 1)),
+                    }
+                }
+                Err(error) => {
+                    debug!(
+                        self.logger, "Aggregator returned an error while cross-checking";
+                        "endpoint" => &aggregator.endpoint, "request" => ?request, "error" => ?error
+                    );
+                    errors.push(error);
+                }
+            }
+        }
+
+        if let Some((_, content, _)) = agreeing_groups
+            .iter()
+            .find(|(_, _, count)| *count >= self.required_agreements)
+        {
+            return Ok(content.clone());
+        }
+
+        if agreeing_groups.is_empty() {
+            return Err(errors.remove(0));
+        }
+
+        Err(AggregatorClientError::AggregatorsDisagreement(anyhow!(
+            "Less than {} aggregators agreed on the response of request {request:?}: {} distinct responses and {} errors",
+            self.required_agreements,
+            agreeing_groups.len(),
+            errors.len()
+        )))
+    }
+}
+
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl AggregatorClient for MultiAggregatorClient {
+    async fn get_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        if self.required_agreements > 1 && request.is_content_addressed() {
+            self.get_content_with_cross_check(request).await
+        } else {
+            self.get_content_with_fail_over(request).await
+        }
+    }
+
+    async fn post_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        let aggregator_request_id = MithrilEvent::new_aggregator_request_id();
+        let mut last_error = None;
+        for (index, aggregator) in self.aggregators.iter().enumerate() {
+            match aggregator.client.post_content(request.clone()).await {
+                Ok(content) => return Ok(content),
+                Err(error) if Self::should_fail_over(&error) => {
+                    warn!(
+                        self.logger, "Aggregator unavailable, failing over to the next one";
+                        "endpoint" => &aggregator.endpoint, "error" => ?error
+                    );
+                    self.send_fail_over_event(&aggregator_request_id, index + 1, &error)
+                        .await;
+                    last_error = Some(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        Err(last_error.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use crate::aggregator_client::MockAggregatorHTTPClient;
+    use crate::feedback::StackFeedbackReceiver;
+    use crate::test_utils;
+
+    use super::*;
+
+    fn aggregator_returning(
+        response: Result<&'static str, fn() -> AggregatorClientError>,
+    ) -> AggregatorEndpoint {
+        let mut client = MockAggregatorHTTPClient::new();
+        client
+            .expect_get_content()
+            .returning(move |_| response.map(|r| r.to_string()).map_err(|e| e()));
+        AggregatorEndpoint::new("endpoint", Arc::new(client))
+    }
+
+    fn unavailable() -> AggregatorClientError {
+        AggregatorClientError::SubsystemError(anyhow!("unreachable"))
+    }
+
+    fn not_found() -> AggregatorClientError {
+        AggregatorClientError::RemoteServerLogical(anyhow!("not found"))
+    }
+
+    fn get_certificate_request() -> AggregatorRequest {
+        AggregatorRequest::GetCertificate {
+            hash: "hash".to_string(),
+        }
+    }
+
+    #[test]
+    fn fail_if_no_aggregator_is_given() {
+        MultiAggregatorClient::new(vec![], test_utils::test_logger())
+            .err()
+            .expect("Building a client without aggregator should fail");
+    }
+
+    #[test]
+    fn fail_if_required_agreements_is_greater_than_the_number_of_aggregators() {
+        MultiAggregatorClient::new(
+            vec![
+                aggregator_returning(Ok("{}")),
+                aggregator_returning(Ok("{}")),
+            ],
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_required_agreements(3)
+        .err()
+        .expect("Requiring more agreements than aggregators should fail");
+    }
+
+    #[tokio::test]
+    async fn fail_over_to_the_next_aggregator_when_one_is_unavailable() {
+        let client = MultiAggregatorClient::new(
+            vec![
+                aggregator_returning(Err(unavailable)),
+                aggregator_returning(Ok(r#"{"hash":"hash"}"#)),
+            ],
+            test_utils::test_logger(),
+        )
+        .unwrap();
+
+        let content = client.get_content(get_certificate_request()).await.unwrap();
+
+        assert_eq!(r#"{"hash":"hash"}"#, content);
+    }
+
+    #[tokio::test]
+    async fn send_an_event_when_failing_over_to_the_next_aggregator() {
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = MultiAggregatorClient::new(
+            vec![
+                aggregator_returning(Err(unavailable)),
+                AggregatorEndpoint {
+                    endpoint: "second".to_string(),
+                    ..aggregator_returning(Ok(r#"{"hash":"hash"}"#))
+                },
+            ],
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_feedback_sender(FeedbackSender::new(&[feedback_receiver.clone()]));
+
+        client.get_content(get_certificate_request()).await.unwrap();
+
+        let events = feedback_receiver.stacked_events();
+        assert_eq!(
+            vec![MithrilEvent::AggregatorRequestRetried {
+                aggregator_request_id: events[0].event_id().to_string(),
+                aggregator_endpoint: "second".to_string(),
+                attempt: 2,
+                reason: format!("Previous aggregator unavailable: {}", unavailable()),
+            }],
+            events
+        );
+    }
+
+    #[tokio::test]
+    async fn do_not_fail_over_when_an_aggregator_returns_a_logical_error() {
+        let mut second_client = MockAggregatorHTTPClient::new();
+        second_client.expect_get_content().never();
+        let client = MultiAggregatorClient::new(
+            vec![
+                aggregator_returning(Err(not_found)),
+                AggregatorEndpoint::new("second", Arc::new(second_client)),
+            ],
+            test_utils::test_logger(),
+        )
+        .unwrap();
+
+        let error = client
+            .get_content(get_certificate_request())
+            .await
+            .expect_err("A logical error should be returned");
+
+        assert!(matches!(
+            error,
+            AggregatorClientError::RemoteServerLogical(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn return_the_last_error_when_all_aggregators_are_unavailable() {
+        let client = MultiAggregatorClient::new(
+            vec![
+                aggregator_returning(Err(unavailable)),
+                aggregator_returning(Err(unavailable)),
+            ],
+            test_utils::test_logger(),
+        )
+        .unwrap();
+
+        let error = client
+            .get_content(get_certificate_request())
+            .await
+            .expect_err("An error should be returned");
+
+        assert!(matches!(error, AggregatorClientError::SubsystemError(_)));
+    }
+
+    #[tokio::test]
+    async fn cross_check_return_the_content_when_enough_aggregators_agree() {
+        let client = MultiAggregatorClient::new(
+            vec![
+                aggregator_returning(Ok(r#"{"hash":"hash","epoch":1}"#)),
+                aggregator_returning(Ok(r#"{"hash":"forged","epoch":1}"#)),
+                aggregator_returning(Ok(r#"{ "epoch": 1, "hash": "hash" }"#)),
+            ],
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_required_agreements(2)
+        .unwrap();
+
+        let content = client.get_content(get_certificate_request()).await.unwrap();
+
+        assert_eq!(r#"{"hash":"hash","epoch":1}"#, content);
+    }
+
+    #[tokio::test]
+    async fn cross_check_fails_when_not_enough_aggregators_agree() {
+        let client = MultiAggregatorClient::new(
+            vec![
+                aggregator_returning(Ok(r#"{"hash":"hash"}"#)),
+                aggregator_returning(Ok(r#"{"hash":"forged"}"#)),
+                aggregator_returning(Err(unavailable)),
+            ],
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_required_agreements(2)
+        .unwrap();
+
+        let error = client
+            .get_content(get_certificate_request())
+            .await
+            .expect_err("Cross-check should fail");
+
+        assert!(matches!(
+            error,
+            AggregatorClientError::AggregatorsDisagreement(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn cross_check_return_the_first_error_when_all_aggregators_fail() {
+        let client = MultiAggregatorClient::new(
+            vec![
+                aggregator_returning(Err(not_found)),
+                aggregator_returning(Err(unavailable)),
+            ],
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_required_agreements(2)
+        .unwrap();
+
+        let error = client
+            .get_content(get_certificate_request())
+            .await
+            .expect_err("An error should be returned");
+
+        assert!(matches!(
+            error,
+            AggregatorClientError::RemoteServerLogical(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn list_requests_are_not_cross_checked() {
+        let mut second_client = MockAggregatorHTTPClient::new();
+        second_client.expect_get_content().never();
+        let mut first_client = MockAggregatorHTTPClient::new();
+        first_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::ListCertificates))
+            .returning(|_| Ok("[]".to_string()));
+        let client = MultiAggregatorClient::new(
+            vec![
+                AggregatorEndpoint::new("first", Arc::new(first_client)),
+                AggregatorEndpoint::new("second", Arc::new(second_client)),
+            ],
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_required_agreements(2)
+        .unwrap();
+
+        let content = client
+            .get_content(AggregatorRequest::ListCertificates)
+            .await
+            .unwrap();
+
+        assert_eq!("[]", content);
+    }
+}
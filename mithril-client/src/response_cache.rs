@@ -0,0 +1,281 @@
+//! Persistent cache of the responses of an Aggregator.
+//!
+//! The [AggregatorResponseCache] trait abstracts where the responses fetched by the
+//! [AggregatorHTTPClient][crate::aggregator_client::AggregatorHTTPClient] are kept.
+//!
+//! Certificates and signed artifacts are immutable once created and addressed by their hash,
+//! so their responses are cached without expiration. Responses of the routes that list the
+//! latest artifacts change over time and are cached with an expiration date.
+//!
+//! Available implementations:
+//! - [MemoryAggregatorResponseCache]: keep the responses in memory, for the lifetime of the
+//!   process.
+//! - [FsAggregatorResponseCache]: keep the responses as files in a directory
+//!   _(available using crate feature_ **fs**_)_.
+//!
+//! # Use a response cache
+//!
+//! ```no_run
+//! # #[cfg(feature = "fs")]
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//! use mithril_client::response_cache::FsAggregatorResponseCache;
+//! use std::path::Path;
+//! use std::sync::Arc;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY")
+//!     .with_aggregator_response_cache(Arc::new(FsAggregatorResponseCache::new(Path::new(
+//!         "YOUR_CACHE_DIRECTORY",
+//!     ))?))
+//!     .build()?;
+//! let certificate = client.certificate().verify_chain("CERTIFICATE_HASH").await?;
+//!
+//! println!("Chain of Certificate (hash: {}) is valid", certificate.hash);
+//! #    Ok(())
+//! # }
+//! ```
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use crate::MithrilResult;
+
+/// Default time to live of the cached responses of the routes that list the latest artifacts.
+pub const DEFAULT_LISTS_RESPONSE_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// A response of an Aggregator kept in an [AggregatorResponseCache].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    /// Content of the response
+    pub content: String,
+
+    /// Date after which the response must be fetched again, `None` if it never expires
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl CachedResponse {
+    /// Constructs a new `CachedResponse`.
+    pub fn new(content: &str, expires_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            content: content.to_string(),
+            expires_at,
+        }
+    }
+
+    /// Check if the response is expired at the given date.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// API that defines a cache of the responses of an Aggregator, keyed by the requested url.
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+pub trait AggregatorResponseCache: Sync + Send {
+    /// Get the cached response for the given key, if any.
+    async fn get(&self, key: &str) -> MithrilResult<Option<CachedResponse>>;
+
+    /// Store the given response for the given key, replacing the previous one if any.
+    async fn store(&self, key: &str, response: &CachedResponse) -> MithrilResult<()>;
+}
+
+/// An [AggregatorResponseCache] that keeps the responses in memory.
+#[derive(Default)]
+pub struct MemoryAggregatorResponseCache {
+    responses: RwLock<HashMap<String, CachedResponse>>,
+}
+
+impl MemoryAggregatorResponseCache {
+    /// Constructs a new empty `MemoryAggregatorResponseCache`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl AggregatorResponseCache for MemoryAggregatorResponseCache {
+    async fn get(&self, key: &str) -> MithrilResult<Option<CachedResponse>> {
+        Ok(self.responses.read().await.get(key).cloned())
+    }
+
+    async fn store(&self, key: &str, response: &CachedResponse) -> MithrilResult<()> {
+        self.responses
+            .write()
+            .await
+            .insert(key.to_string(), response.clone());
+
+        Ok(())
+    }
+}
+
+cfg_fs! {
+    use anyhow::Context;
+    use sha2::{Digest, Sha256};
+    use std::path::{Path, PathBuf};
+
+    /// An [AggregatorResponseCache] that keeps each response as a JSON file in a directory.
+    pub struct FsAggregatorResponseCache {
+        cache_dir: PathBuf,
+    }
+
+    impl FsAggregatorResponseCache {
+        /// Constructs a new `FsAggregatorResponseCache`, creating the cache directory if needed.
+        pub fn new(cache_dir: &Path) -> MithrilResult<Self> {
+            std::fs::create_dir_all(cache_dir).with_context(|| {
+                format!(
+                    "Could not create the response cache directory: '{}'",
+                    cache_dir.display()
+                )
+            })?;
+
+            Ok(Self {
+                cache_dir: cache_dir.to_path_buf(),
+            })
+        }
+
+        fn response_path(&self, key: &str) -> PathBuf {
+            self.cache_dir
+                .join(format!("{}.json", hex::encode(Sha256::digest(key))))
+        }
+    }
+
+    #[async_trait]
+    impl AggregatorResponseCache for FsAggregatorResponseCache {
+        async fn get(&self, key: &str) -> MithrilResult<Option<CachedResponse>> {
+            let response_path = self.response_path(key);
+            if !response_path.exists() {
+                return Ok(None);
+            }
+
+            let content = std::fs::read_to_string(&response_path).with_context(|| {
+                format!(
+                    "Could not read the cached response file: '{}'",
+                    response_path.display()
+                )
+            })?;
+            let response = serde_json::from_str(&content).with_context(|| {
+                format!(
+                    "Could not deserialize the cached response file: '{}'",
+                    response_path.display()
+                )
+            })?;
+
+            Ok(Some(response))
+        }
+
+        async fn store(&self, key: &str, response: &CachedResponse) -> MithrilResult<()> {
+            let response_path = self.response_path(key);
+            // Write to a temporary file first so a concurrent reader never sees a partial file
+            let temporary_path = response_path.with_extension("json.tmp");
+            std::fs::write(&temporary_path, serde_json::to_string(response)?).with_context(
+                || {
+                    format!(
+                        "Could not write the cached response file: '{}'",
+                        temporary_path.display()
+                    )
+                },
+            )?;
+            std::fs::rename(&temporary_path, &response_path).with_context(|| {
+                format!(
+                    "Could not move the cached response file to: '{}'",
+                    response_path.display()
+                )
+            })?;
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    #[test]
+    fn cached_response_without_expiration_date_never_expires() {
+        let response = CachedResponse::new("content", None);
+
+        assert!(!response.is_expired(DateTime::<Utc>::MAX_UTC));
+    }
+
+    #[test]
+    fn cached_response_expires_at_its_expiration_date() {
+        let now = Utc::now();
+        let response = CachedResponse::new("content", Some(now));
+
+        assert!(!response.is_expired(now - TimeDelta::seconds(1)));
+        assert!(response.is_expired(now));
+    }
+
+    #[tokio::test]
+    async fn memory_cache_get_stored_response() {
+        let cache = MemoryAggregatorResponseCache::new();
+        let response = CachedResponse::new("content", None);
+
+        assert_eq!(None, cache.get("key").await.unwrap());
+
+        cache.store("key", &response).await.unwrap();
+
+        assert_eq!(Some(response), cache.get("key").await.unwrap());
+    }
+
+    #[cfg(feature = "fs")]
+    mod fs_cache {
+        use mithril_common::test_utils::TempDir;
+
+        use super::*;
+
+        #[tokio::test]
+        async fn get_stored_response() {
+            let cache_dir = TempDir::create("response_cache", "fs_cache_get_stored_response");
+            let cache = FsAggregatorResponseCache::new(&cache_dir).unwrap();
+            let response = CachedResponse::new("content", Some(Utc::now()));
+
+            assert_eq!(
+                None,
+                cache
+                    .get("http://aggregator/certificate/abc")
+                    .await
+                    .unwrap()
+            );
+
+            cache
+                .store("http://aggregator/certificate/abc", &response)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                Some(response),
+                cache
+                    .get("http://aggregator/certificate/abc")
+                    .await
+                    .unwrap()
+            );
+        }
+
+        #[tokio::test]
+        async fn responses_are_kept_across_cache_instances() {
+            let cache_dir = TempDir::create(
+                "response_cache",
+                "fs_cache_responses_are_kept_across_cache_instances",
+            );
+            let response = CachedResponse::new("content", None);
+            FsAggregatorResponseCache::new(&cache_dir)
+                .unwrap()
+                .store("key", &response)
+                .await
+                .unwrap();
+
+            let cache = FsAggregatorResponseCache::new(&cache_dir).unwrap();
+
+            assert_eq!(Some(response), cache.get("key").await.unwrap());
+        }
+    }
+}
@@ -4,6 +4,7 @@
 //!  - [get][SnapshotClient::get]: get a single snapshot data from its digest
 //!  - [list][SnapshotClient::list]: get the list of available snapshots
 //!  - [download_unpack][SnapshotClient::download_unpack]: download and unpack the tarball of a snapshot to a directory
+//!  - [verify_local_db][SnapshotClient::verify_local_db]: verify an already restored Cardano node database against the certificate of a snapshot
 //!
 //! # Get a single snapshot
 //!
@@ -90,11 +91,44 @@
 //! #    Ok(())
 //! # }
 //! ```
+//!
+//! # Verify an already restored Cardano node database
+//! **Note:** _Available on crate feature_ **fs** _only._
+//!
+//! To verify the database of a running Cardano node against a certified snapshot, without
+//! downloading it again, using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # #[cfg(feature = "fs")]
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//! use std::path::Path;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let snapshot = client.snapshot().get("SNAPSHOT_DIGEST").await?.unwrap();
+//! let certificate = client.certificate().verify_chain(&snapshot.certificate_hash).await?;
+//!
+//! let report = client
+//!    .snapshot()
+//!    .verify_local_db(&certificate, Path::new("/var/lib/cardano/db"), None)
+//!    .await?;
+//!
+//! if !report.is_valid {
+//!     println!("Missing immutable files: {:?}", report.missing_immutable_files);
+//! }
+//! #
+//! #    Ok(())
+//! # }
+//! ```
 
 use anyhow::Context;
 #[cfg(feature = "fs")]
+use serde::Serialize;
+#[cfg(feature = "fs")]
 use slog::Logger;
 use std::sync::Arc;
+#[cfg(feature = "fs")]
+use std::{collections::BTreeMap, path::Path};
 use thiserror::Error;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
@@ -102,6 +136,11 @@ use crate::aggregator_client::{AggregatorClient, AggregatorClientError, Aggregat
 use crate::feedback::FeedbackSender;
 #[cfg(feature = "fs")]
 use crate::snapshot_downloader::SnapshotDownloader;
+#[cfg(feature = "fs")]
+use crate::{
+    common::{HexEncodedDigest, ImmutableFileName, ImmutableFileNumber, ProtocolMessagePartKey},
+    MithrilCertificate,
+};
 use crate::{MithrilResult, Snapshot, SnapshotListItem};
 
 /// Error for the Snapshot client
@@ -118,6 +157,37 @@ pub enum SnapshotClientError {
     },
 }
 
+cfg_fs! {
+    /// Report of the verification of an already restored Cardano node database against the
+    /// certificate of a snapshot.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub struct LocalDbVerificationReport {
+        /// Hash of the certificate the database was verified against
+        pub certificate_hash: String,
+
+        /// Number of the last immutable file certified by the certificate
+        pub immutable_file_number: ImmutableFileNumber,
+
+        /// Digest of the immutable files certified by the certificate
+        pub expected_digest: Option<HexEncodedDigest>,
+
+        /// Digest of the immutable files of the database
+        pub computed_digest: HexEncodedDigest,
+
+        /// `true` if the immutable files of the database are signed by the certificate
+        pub is_valid: bool,
+
+        /// Numbers of the immutable files that are missing from the database
+        pub missing_immutable_files: Vec<ImmutableFileNumber>,
+
+        /// Immutable files of the database whose digest differs from the reference digests
+        pub differing_immutable_files: Vec<ImmutableFileName>,
+
+        /// Digest of each immutable file of the database, up to the certified immutable file
+        pub immutable_files_digests: BTreeMap<ImmutableFileName, HexEncodedDigest>,
+    }
+}
+
 /// Aggregator client for the snapshot artifact
 pub struct SnapshotClient {
     aggregator_client: Arc<dyn AggregatorClient>,
@@ -241,6 +311,92 @@ impl SnapshotClient {
         }
     }
 
+    cfg_fs! {
+        /// Verify the immutable files of an already restored Cardano node database against the
+        /// certificate of a snapshot, without downloading the snapshot again.
+        ///
+        /// The certificate chain of the given certificate must have been verified beforehand,
+        /// ie with [CertificateClient::verify_chain][crate::certificate_client::CertificateClient::verify_chain].
+        ///
+        /// If `reference_digests` are given, ie the digests of the immutable files of a database
+        /// known to be valid, the report lists the immutable files whose digest differs.
+        ///
+        /// Warning: this operation can be quite long depending on the database size.
+        pub async fn verify_local_db(
+            &self,
+            certificate: &MithrilCertificate,
+            db_dir: &Path,
+            reference_digests: Option<&BTreeMap<ImmutableFileName, HexEncodedDigest>>,
+        ) -> MithrilResult<LocalDbVerificationReport> {
+            use mithril_common::digesters::CardanoImmutableDigester;
+            use mithril_common::entities::SignedEntityType;
+
+            let beacon = match &certificate.signed_entity_type {
+                SignedEntityType::CardanoImmutableFilesFull(beacon) => beacon,
+                other => {
+                    return Err(anyhow::anyhow!(
+                        "Can't verify database: Given certificate `{}` does not certify a snapshot, certificate signed entity: {:?}",
+                        certificate.hash,
+                        other
+                    ))
+                }
+            };
+
+            let digester = CardanoImmutableDigester::new(None, self.logger.clone());
+            let digests = digester
+                .compute_immutable_files_digests(db_dir, beacon)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Immutable files digests computation failed: db_dir: '{}'",
+                        db_dir.display()
+                    )
+                })?;
+            let computed_digest =
+                CardanoImmutableDigester::compute_digest_from_immutable_files_digests(
+                    beacon, &digests,
+                );
+
+            let mut message = certificate.protocol_message.clone();
+            message.set_message_part(ProtocolMessagePartKey::SnapshotDigest, computed_digest.clone());
+
+            let missing_immutable_files = (0..=beacon.immutable_file_number)
+                .filter(|number| !digests.keys().any(|file| file.number == *number))
+                .collect();
+            let immutable_files_digests: BTreeMap<ImmutableFileName, HexEncodedDigest> = digests
+                .into_iter()
+                .map(|(file, digest)| (file.filename, digest))
+                .collect();
+            let differing_immutable_files = reference_digests
+                .map(|reference_digests| {
+                    immutable_files_digests
+                        .iter()
+                        .filter(|(filename, digest)| {
+                            reference_digests
+                                .get(*filename)
+                                .is_some_and(|reference_digest| reference_digest != *digest)
+                        })
+                        .map(|(filename, _)| filename.clone())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Ok(LocalDbVerificationReport {
+                certificate_hash: certificate.hash.clone(),
+                immutable_file_number: beacon.immutable_file_number,
+                expected_digest: certificate
+                    .protocol_message
+                    .get_message_part(&ProtocolMessagePartKey::SnapshotDigest)
+                    .cloned(),
+                computed_digest,
+                is_valid: certificate.match_message(&message),
+                missing_immutable_files,
+                differing_immutable_files,
+                immutable_files_digests,
+            })
+        }
+    }
+
     /// Increments the aggregator snapshot download statistics
     pub async fn add_statistics(&self, snapshot: &Snapshot) -> MithrilResult<()> {
         let _response = self
@@ -303,3 +459,146 @@ mod tests_download {
         assert_eq!(actual, expected);
     }
 }
+
+#[cfg(all(test, feature = "fs"))]
+mod tests_verify_local_db {
+    use mithril_common::digesters::{
+        CardanoImmutableDigester, DummyImmutableDb, DummyImmutablesDbBuilder, ImmutableDigester,
+    };
+    use mithril_common::entities::{CardanoDbBeacon, Epoch, SignedEntityType};
+
+    use crate::{
+        aggregator_client::MockAggregatorHTTPClient,
+        snapshot_downloader::MockHttpSnapshotDownloader, test_utils,
+    };
+
+    use super::*;
+
+    fn build_client() -> SnapshotClient {
+        SnapshotClient::new(
+            Arc::new(MockAggregatorHTTPClient::new()),
+            Arc::new(MockHttpSnapshotDownloader::new()),
+            FeedbackSender::new(&[]),
+            test_utils::test_logger(),
+        )
+    }
+
+    fn build_immutable_db(dir_name: &str, immutables: &[ImmutableFileNumber]) -> DummyImmutableDb {
+        DummyImmutablesDbBuilder::new(&format!("snapshot_client/{dir_name}"))
+            .with_immutables(immutables)
+            .append_immutable_trio()
+            .build()
+    }
+
+    async fn certificate_signing_db(
+        immutable_db: &DummyImmutableDb,
+        beacon: &CardanoDbBeacon,
+    ) -> MithrilCertificate {
+        let digest = CardanoImmutableDigester::new(None, test_utils::test_logger())
+            .compute_digest(&immutable_db.dir, beacon)
+            .await
+            .unwrap();
+        let mut certificate = MithrilCertificate {
+            signed_entity_type: SignedEntityType::CardanoImmutableFilesFull(beacon.clone()),
+            ..MithrilCertificate::dummy()
+        };
+        certificate
+            .protocol_message
+            .set_message_part(ProtocolMessagePartKey::SnapshotDigest, digest);
+        certificate.signed_message = certificate.protocol_message.compute_hash();
+
+        certificate
+    }
+
+    #[tokio::test]
+    async fn verify_local_db_signed_by_the_certificate() {
+        let immutable_db =
+            build_immutable_db("verify_local_db_signed_by_the_certificate", &[0, 1, 2, 3]);
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 3);
+        let certificate = certificate_signing_db(&immutable_db, &beacon).await;
+
+        let report = build_client()
+            .verify_local_db(&certificate, &immutable_db.dir, None)
+            .await
+            .unwrap();
+
+        assert!(report.is_valid);
+        assert_eq!(report.expected_digest, Some(report.computed_digest.clone()));
+        assert!(report.missing_immutable_files.is_empty());
+        assert_eq!(
+            immutable_db
+                .immutables_files
+                .into_iter()
+                .map(|file| file.filename)
+                .collect::<Vec<_>>(),
+            report
+                .immutable_files_digests
+                .into_keys()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_local_db_with_missing_immutable_files() {
+        let valid_immutable_db = build_immutable_db(
+            "verify_local_db_with_missing_immutable_files_valid",
+            &[0, 1, 2, 3],
+        );
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 3);
+        let certificate = certificate_signing_db(&valid_immutable_db, &beacon).await;
+        let immutable_db =
+            build_immutable_db("verify_local_db_with_missing_immutable_files", &[0, 2, 3]);
+
+        let report = build_client()
+            .verify_local_db(&certificate, &immutable_db.dir, None)
+            .await
+            .unwrap();
+
+        assert!(!report.is_valid);
+        assert_eq!(vec![1], report.missing_immutable_files);
+    }
+
+    #[tokio::test]
+    async fn verify_local_db_report_immutable_files_differing_from_reference_digests() {
+        let immutable_db = build_immutable_db(
+            "verify_local_db_report_immutable_files_differing_from_reference_digests",
+            &[0, 1, 2],
+        );
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 2);
+        let certificate = certificate_signing_db(&immutable_db, &beacon).await;
+        let client = build_client();
+        let mut reference_digests = client
+            .verify_local_db(&certificate, &immutable_db.dir, None)
+            .await
+            .unwrap()
+            .immutable_files_digests;
+        reference_digests.insert("00001.chunk".to_string(), "other-digest".to_string());
+
+        let report = client
+            .verify_local_db(&certificate, &immutable_db.dir, Some(&reference_digests))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec!["00001.chunk".to_string()],
+            report.differing_immutable_files
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_local_db_fails_if_the_certificate_does_not_certify_a_snapshot() {
+        let immutable_db = build_immutable_db(
+            "verify_local_db_fails_if_the_certificate_does_not_certify_a_snapshot",
+            &[0, 1],
+        );
+        let certificate = MithrilCertificate {
+            signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(1)),
+            ..MithrilCertificate::dummy()
+        };
+
+        build_client()
+            .verify_local_db(&certificate, &immutable_db.dir, None)
+            .await
+            .expect_err("Verifying a database with a non snapshot certificate should fail");
+    }
+}
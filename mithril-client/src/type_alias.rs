@@ -43,6 +43,12 @@ cfg_unstable! {
     /// List item of Cardano stake distributions.
     pub use mithril_common::messages::CardanoStakeDistributionListItemMessage as CardanoStakeDistributionListItem;
 
+    /// A Cardano DRep stake distribution.
+    pub use mithril_common::messages::CardanoDRepStakeDistributionMessage as CardanoDRepStakeDistribution;
+
+    /// List item of Cardano DRep stake distributions.
+    pub use mithril_common::messages::CardanoDRepStakeDistributionListItemMessage as CardanoDRepStakeDistributionListItem;
+
     pub use mithril_common::messages::CardanoTransactionsProofsMessage as CardanoTransactionsProofs;
 
     pub use mithril_common::messages::CardanoTransactionsSetProofMessagePart as CardanoTransactionsSetProof;
@@ -51,6 +57,9 @@ cfg_unstable! {
 
     pub use mithril_common::messages::VerifyCardanoTransactionsProofsError;
 
+    /// Capabilities of the Cardano transactions prover of an aggregator.
+    pub use mithril_common::messages::CardanoTransactionsProverCapabilities;
+
     /// A snapshot that allow to know up to which [point of time][common::CardanoDbBeacon] Mithril have certified Cardano transactions.
     pub use mithril_common::messages::CardanoTransactionSnapshotMessage as CardanoTransactionSnapshot;
 
@@ -61,11 +70,14 @@ cfg_unstable! {
 /// `mithril-common` re-exports
 pub mod common {
     pub use mithril_common::entities::{
-        CardanoDbBeacon, CompressionAlgorithm, Epoch, ImmutableFileNumber, ProtocolMessage,
-        ProtocolMessagePartKey, ProtocolParameters,
+        CardanoDbBeacon, CompressionAlgorithm, Epoch, HexEncodedDigest, ImmutableFileName,
+        ImmutableFileNumber, ProtocolMessage, ProtocolMessagePartKey, ProtocolParameters,
     };
+    cfg_fs! {
+        pub use mithril_common::digesters::{ImmutableFile, ImmutableFileListingError};
+    }
     cfg_unstable! {
         pub use mithril_common::entities::{ChainPoint, TransactionHash, SlotNumber, BlockHash, BlockNumber};
-        pub use mithril_common::entities::{StakeDistribution};
+        pub use mithril_common::entities::{DRepId, DRepStakeDistribution, StakeDistribution};
     }
 }
@@ -0,0 +1,404 @@
+//! Offline verification of Mithril artifacts.
+//!
+//! A [VerificationBundle] gathers everything that is needed to verify an artifact without any
+//! access to an aggregator:
+//!  - the artifact message,
+//!  - the segment of the certificate chain from the certificate of the artifact to the genesis
+//!    certificate,
+//!  - the genesis verification key used to sign the genesis certificate.
+//!
+//! A bundle is produced online, for example with the `mithril-client` CLI, and can then be
+//! verified offline with an [OfflineVerifier] that checks the certificate chain and that the
+//! artifact is signed by its certificate.
+//!
+//! # Export a verification bundle
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, verification_bundle::{VerifiableArtifact, VerificationBundle}};
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let mithril_stake_distribution = client.mithril_stake_distribution().get("MITHRIL_STAKE_DISTRIBUTION_HASH").await?.unwrap();
+//! let certificate_chain = client.certificate().get_chain(&mithril_stake_distribution.certificate_hash).await?;
+//!
+//! let bundle = VerificationBundle {
+//!     genesis_verification_key: "YOUR_GENESIS_VERIFICATION_KEY".to_string(),
+//!     artifact: VerifiableArtifact::MithrilStakeDistribution(mithril_stake_distribution),
+//!     certificate_chain,
+//! };
+//! std::fs::write("bundle.json", serde_json::to_string(&bundle)?)?;
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # Verify a verification bundle offline
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::verification_bundle::{OfflineVerifier, VerificationBundle};
+//!
+//! let bundle: VerificationBundle = serde_json::from_str(&std::fs::read_to_string("bundle.json")?)?;
+//! let certificate = OfflineVerifier::new("YOUR_GENESIS_VERIFICATION_KEY")?
+//!     .verify(&bundle)
+//!     .await?;
+//!
+//! println!("Artifact signed by certificate '{}' is valid", certificate.hash);
+//! #    Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use slog::{o, Logger};
+
+use mithril_common::certificate_chain::{
+    CertificateRetriever, CertificateRetrieverError, CertificateVerifier,
+    MithrilCertificateVerifier,
+};
+use mithril_common::crypto_helper::ProtocolGenesisVerificationKey;
+use mithril_common::entities::Certificate;
+
+use crate::common::ProtocolMessage;
+#[cfg(feature = "unstable")]
+use crate::{CardanoDRepStakeDistribution, CardanoStakeDistribution, CardanoTransactionsProofs};
+use crate::{MessageBuilder, MithrilCertificate, MithrilResult, MithrilStakeDistribution};
+
+/// An artifact that can be verified offline with its certificate chain.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "message")]
+pub enum VerifiableArtifact {
+    /// A Mithril stake distribution
+    MithrilStakeDistribution(MithrilStakeDistribution),
+
+    /// A Cardano stake distribution
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    CardanoStakeDistribution(CardanoStakeDistribution),
+
+    /// A Cardano DRep stake distribution
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    CardanoDRepStakeDistribution(CardanoDRepStakeDistribution),
+
+    /// Proofs of membership of Cardano transactions
+    #[cfg(feature = "unstable")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    CardanoTransactionsProofs(CardanoTransactionsProofs),
+}
+
+impl VerifiableArtifact {
+    /// Hash of the certificate that signed the artifact
+    pub fn certificate_hash(&self) -> &str {
+        match self {
+            Self::MithrilStakeDistribution(artifact) => &artifact.certificate_hash,
+            #[cfg(feature = "unstable")]
+            Self::CardanoStakeDistribution(artifact) => &artifact.certificate_hash,
+            #[cfg(feature = "unstable")]
+            Self::CardanoDRepStakeDistribution(artifact) => &artifact.certificate_hash,
+            #[cfg(feature = "unstable")]
+            Self::CardanoTransactionsProofs(artifact) => &artifact.certificate_hash,
+        }
+    }
+
+    fn compute_protocol_message(
+        &self,
+        message_builder: &MessageBuilder,
+        certificate: &MithrilCertificate,
+    ) -> MithrilResult<ProtocolMessage> {
+        match self {
+            Self::MithrilStakeDistribution(artifact) => {
+                message_builder.compute_mithril_stake_distribution_message(certificate, artifact)
+            }
+            #[cfg(feature = "unstable")]
+            Self::CardanoStakeDistribution(artifact) => {
+                message_builder.compute_cardano_stake_distribution_message(certificate, artifact)
+            }
+            #[cfg(feature = "unstable")]
+            Self::CardanoDRepStakeDistribution(artifact) => message_builder
+                .compute_cardano_drep_stake_distribution_message(certificate, artifact),
+            #[cfg(feature = "unstable")]
+            Self::CardanoTransactionsProofs(artifact) => {
+                let verified_transactions = artifact
+                    .verify()
+                    .with_context(|| "Cardano transactions proofs verification failed")?;
+
+                Ok(message_builder.compute_cardano_transactions_proofs_message(
+                    certificate,
+                    &verified_transactions,
+                ))
+            }
+        }
+    }
+}
+
+/// Everything that is needed to verify an artifact offline.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VerificationBundle {
+    /// Genesis verification key that signed the genesis certificate of the chain
+    pub genesis_verification_key: String,
+
+    /// The artifact to verify
+    pub artifact: VerifiableArtifact,
+
+    /// Certificate chain from the certificate of the artifact to the genesis certificate
+    pub certificate_chain: Vec<MithrilCertificate>,
+}
+
+/// Verify a [VerificationBundle] without any access to an aggregator.
+pub struct OfflineVerifier {
+    genesis_verification_key: String,
+    logger: Logger,
+}
+
+impl OfflineVerifier {
+    /// Constructs a new `OfflineVerifier` that trusts the given genesis verification key.
+    pub fn new(genesis_verification_key: &str) -> MithrilResult<Self> {
+        ProtocolGenesisVerificationKey::try_from(genesis_verification_key)
+            .with_context(|| "Invalid genesis verification key")?;
+
+        Ok(Self {
+            genesis_verification_key: genesis_verification_key.to_string(),
+            logger: Logger::root(slog::Discard, o!()),
+        })
+    }
+
+    /// Set the [Logger] to use.
+    pub fn with_logger(mut self, logger: Logger) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    /// Verify the certificate chain of the bundle and that its artifact is signed by the first
+    /// certificate of the chain, return this certificate if the bundle is valid.
+    pub async fn verify(&self, bundle: &VerificationBundle) -> MithrilResult<MithrilCertificate> {
+        let certificate = self.verify_certificate_chain(bundle).await?;
+        self.verify_artifact_is_signed(&bundle.artifact, &certificate)?;
+
+        Ok(certificate)
+    }
+
+    async fn verify_certificate_chain(
+        &self,
+        bundle: &VerificationBundle,
+    ) -> MithrilResult<MithrilCertificate> {
+        if bundle.genesis_verification_key != self.genesis_verification_key {
+            return Err(anyhow!(
+                "The genesis verification key of the bundle is not the trusted one"
+            ));
+        }
+        let certificate = bundle
+            .certificate_chain
+            .first()
+            .ok_or_else(|| anyhow!("The certificate chain of the bundle is empty"))?;
+        if certificate.hash != bundle.artifact.certificate_hash() {
+            return Err(anyhow!(
+                "The certificate chain of the bundle does not start with the certificate of the artifact: '{}'",
+                bundle.artifact.certificate_hash()
+            ));
+        }
+
+        let genesis_verification_key =
+            ProtocolGenesisVerificationKey::try_from(self.genesis_verification_key.as_str())
+                .with_context(|| "Invalid genesis verification key")?;
+        let verifier = MithrilCertificateVerifier::new(
+            self.logger.clone(),
+            Arc::new(BundleCertificateRetriever::new(&bundle.certificate_chain)),
+        );
+        verifier
+            .verify_certificate_chain(certificate.clone().try_into()?, &genesis_verification_key)
+            .await
+            .with_context(|| {
+                format!(
+                    "Certificate chain of certificate '{}' is invalid",
+                    certificate.hash
+                )
+            })?;
+
+        Ok(certificate.clone())
+    }
+
+    fn verify_artifact_is_signed(
+        &self,
+        artifact: &VerifiableArtifact,
+        certificate: &MithrilCertificate,
+    ) -> MithrilResult<()> {
+        let message = artifact
+            .compute_protocol_message(
+                &MessageBuilder::new().with_logger(self.logger.clone()),
+                certificate,
+            )
+            .with_context(|| "Can not compute the message of the artifact")?;
+
+        if !certificate.match_message(&message) {
+            return Err(anyhow!(
+                "Certificate and artifact message did not match:\ncertificate_message: '{}'\n computed_message: '{}'",
+                certificate.signed_message,
+                message.compute_hash()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// A [CertificateRetriever] that only knows the certificates of a [VerificationBundle].
+struct BundleCertificateRetriever {
+    certificates: HashMap<String, MithrilCertificate>,
+}
+
+impl BundleCertificateRetriever {
+    fn new(certificate_chain: &[MithrilCertificate]) -> Self {
+        Self {
+            certificates: certificate_chain
+                .iter()
+                .map(|certificate| (certificate.hash.clone(), certificate.clone()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl CertificateRetriever for BundleCertificateRetriever {
+    async fn get_certificate_details(
+        &self,
+        certificate_hash: &str,
+    ) -> Result<Certificate, CertificateRetrieverError> {
+        self.certificates
+            .get(certificate_hash)
+            .cloned()
+            .ok_or_else(|| {
+                CertificateRetrieverError(anyhow!(
+                    "Certificate '{certificate_hash}' is missing from the bundle"
+                ))
+            })?
+            .try_into()
+            .map_err(CertificateRetrieverError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::tests_setup::setup_certificate_chain;
+    use mithril_common::test_utils::fake_keys;
+
+    use super::*;
+
+    fn build_bundle_with_valid_chain() -> VerificationBundle {
+        let (chain, genesis_verifier) = setup_certificate_chain(5, 2);
+        let certificate_chain: Vec<MithrilCertificate> = chain
+            .into_iter()
+            .map(|certificate| certificate.try_into().unwrap())
+            .collect();
+
+        VerificationBundle {
+            genesis_verification_key: genesis_verifier.to_verification_key().try_into().unwrap(),
+            artifact: VerifiableArtifact::MithrilStakeDistribution(MithrilStakeDistribution {
+                certificate_hash: certificate_chain[0].hash.clone(),
+                ..MithrilStakeDistribution::dummy()
+            }),
+            certificate_chain,
+        }
+    }
+
+    fn signed_mithril_stake_distribution() -> (VerifiableArtifact, MithrilCertificate) {
+        let mithril_stake_distribution = MithrilStakeDistribution::dummy();
+        let mut certificate = MithrilCertificate::dummy();
+        let message = MessageBuilder::new()
+            .compute_mithril_stake_distribution_message(&certificate, &mithril_stake_distribution)
+            .unwrap();
+        certificate.signed_message = message.compute_hash();
+
+        (
+            VerifiableArtifact::MithrilStakeDistribution(mithril_stake_distribution),
+            certificate,
+        )
+    }
+
+    #[tokio::test]
+    async fn verify_valid_certificate_chain() {
+        let bundle = build_bundle_with_valid_chain();
+        let verifier = OfflineVerifier::new(&bundle.genesis_verification_key).unwrap();
+
+        let certificate = verifier.verify_certificate_chain(&bundle).await.unwrap();
+
+        assert_eq!(bundle.certificate_chain[0], certificate);
+    }
+
+    #[tokio::test]
+    async fn verify_certificate_chain_fails_if_a_certificate_is_missing_from_the_bundle() {
+        let mut bundle = build_bundle_with_valid_chain();
+        bundle.certificate_chain.remove(2);
+        let verifier = OfflineVerifier::new(&bundle.genesis_verification_key).unwrap();
+
+        verifier
+            .verify_certificate_chain(&bundle)
+            .await
+            .expect_err("Verifying a chain with a missing certificate should fail");
+    }
+
+    #[tokio::test]
+    async fn verify_certificate_chain_fails_if_the_genesis_key_is_not_the_trusted_one() {
+        let mut bundle = build_bundle_with_valid_chain();
+        let trusted_genesis_verification_key = fake_keys::genesis_verification_key()[1];
+        let verifier = OfflineVerifier::new(trusted_genesis_verification_key).unwrap();
+
+        verifier
+            .verify_certificate_chain(&bundle)
+            .await
+            .expect_err("Verifying a bundle with an untrusted genesis key should fail");
+
+        bundle.genesis_verification_key = trusted_genesis_verification_key.to_string();
+        verifier
+            .verify_certificate_chain(&bundle)
+            .await
+            .expect_err("Verifying a chain signed with another genesis key should fail");
+    }
+
+    #[tokio::test]
+    async fn verify_certificate_chain_fails_if_it_does_not_start_with_the_artifact_certificate() {
+        let mut bundle = build_bundle_with_valid_chain();
+        bundle.certificate_chain.remove(0);
+        let verifier = OfflineVerifier::new(&bundle.genesis_verification_key).unwrap();
+
+        verifier.verify_certificate_chain(&bundle).await.expect_err(
+            "Verifying a chain that does not start with the artifact certificate should fail",
+        );
+    }
+
+    #[test]
+    fn verify_artifact_signed_by_certificate() {
+        let (artifact, certificate) = signed_mithril_stake_distribution();
+        let verifier = OfflineVerifier::new(fake_keys::genesis_verification_key()[0]).unwrap();
+
+        verifier
+            .verify_artifact_is_signed(&artifact, &certificate)
+            .unwrap();
+    }
+
+    #[test]
+    fn verify_artifact_not_signed_by_certificate_fails() {
+        let (artifact, mut certificate) = signed_mithril_stake_distribution();
+        certificate.signed_message = "tampered".to_string();
+        let verifier = OfflineVerifier::new(fake_keys::genesis_verification_key()[0]).unwrap();
+
+        verifier
+            .verify_artifact_is_signed(&artifact, &certificate)
+            .expect_err("Verifying an artifact not signed by the certificate should fail");
+    }
+
+    #[test]
+    fn verification_bundle_json_roundtrip() {
+        let bundle = build_bundle_with_valid_chain();
+
+        let json = serde_json::to_string(&bundle).unwrap();
+        let deserialized: VerificationBundle = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(bundle, deserialized);
+        assert!(json.contains(r#""type":"MithrilStakeDistribution""#));
+    }
+}
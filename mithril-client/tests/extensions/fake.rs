@@ -160,6 +160,7 @@ mod proof {
                 certificate_hash: certificate_hash.to_string(),
                 certified_transactions: vec![CardanoTransactionsSetProof {
                     transactions_hashes: tx_hashes.iter().map(|h| h.to_string()).collect(),
+                    transactions_contents_hashes: vec![],
                     proof: ProtocolMkProof::new(proof.to_owned().into())
                         .to_json_hex()
                         .unwrap(),
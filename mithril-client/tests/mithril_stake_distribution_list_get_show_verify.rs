@@ -1,6 +1,9 @@
 mod extensions;
 
+use std::sync::Arc;
+
 use crate::extensions::fake::{FakeAggregator, FakeCertificateVerifier};
+use mithril_client::feedback::{MithrilEvent, StackFeedbackReceiver, StakeDistributionType};
 use mithril_client::{aggregator_client::AggregatorRequest, ClientBuilder, MessageBuilder};
 
 #[tokio::test]
@@ -12,8 +15,10 @@ async fn mithril_stake_distribution_list_get_show_verify() {
     let fake_aggregator = FakeAggregator::new();
     let test_http_server =
         fake_aggregator.spawn_with_mithril_stake_distribution(msd_hash, certificate_hash);
+    let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
     let client = ClientBuilder::aggregator(&test_http_server.url(), genesis_verification_key)
         .with_certificate_verifier(FakeCertificateVerifier::build_that_validate_any_certificate())
+        .add_feedback_receiver(feedback_receiver.clone())
         .build()
         .expect("Should be able to create a Client");
     let mithril_stake_distribution_client = client.mithril_stake_distribution();
@@ -76,4 +81,28 @@ async fn mithril_stake_distribution_list_get_show_verify() {
         certificate.signed_message,
         message.compute_hash()
     );
+
+    let message_with_feedback = mithril_stake_distribution_client
+        .compute_message(&certificate, &mithril_stake_distribution)
+        .await
+        .expect("Computing msd message with the client should not fail");
+    assert_eq!(message, message_with_feedback);
+
+    let events = feedback_receiver.stacked_events();
+    assert!(matches!(
+        &events[..],
+        [
+            MithrilEvent::StakeDistributionRetrievalStarted {
+                stake_distribution_type: StakeDistributionType::Mithril,
+                identifier,
+                ..
+            },
+            MithrilEvent::StakeDistributionRetrievalCompleted { found: true, .. },
+            MithrilEvent::StakeDistributionMessageComputationStarted {
+                stake_distribution_type: StakeDistributionType::Mithril,
+                ..
+            },
+            MithrilEvent::StakeDistributionMessageComputed { .. },
+        ] if identifier == last_hash
+    ));
 }
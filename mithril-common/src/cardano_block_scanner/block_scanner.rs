@@ -1,6 +1,8 @@
+use std::fmt::Display;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use slog::Logger;
 use tokio::sync::Mutex;
 
@@ -11,6 +13,29 @@ use crate::StdResult;
 
 use super::ChainReaderBlockStreamer;
 
+/// Type of block scanners available
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BlockScannerType {
+    /// [CardanoBlockScanner]: read all the blocks with the chain-sync mini-protocol of the
+    /// Cardano node.
+    #[default]
+    ChainSync,
+    /// [ImmutableFileBlockScanner][super::ImmutableFileBlockScanner]: read the blocks from the
+    /// immutable files of the Cardano node database, then the blocks that are not yet immutable
+    /// with the chain-sync mini-protocol.
+    ImmutableFiles,
+}
+
+impl Display for BlockScannerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ChainSync => write!(f, "chain-sync"),
+            Self::ImmutableFiles => write!(f, "immutable-files"),
+        }
+    }
+}
+
 /// Cardano block scanner
 ///
 /// This scanner reads the blocks with a chain block reader
@@ -0,0 +1,247 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use slog::Logger;
+use tokio::sync::Mutex;
+
+use crate::cardano_block_scanner::{BlockScanner, BlockStreamer, ImmutableFileBlockStreamer};
+use crate::chain_reader::ChainBlockReader;
+use crate::entities::{BlockNumber, ChainPoint};
+use crate::StdResult;
+
+/// Cardano block scanner that reads the blocks from the immutable files
+///
+/// This scanner reads the blocks directly from the immutable files of a Cardano node database,
+/// which is much faster than reading them with the chain-sync mini-protocol of the node, then
+/// hands off to a chain block reader to read the blocks that are not yet immutable.
+pub struct ImmutableFileBlockScanner {
+    immutable_directory: PathBuf,
+    chain_reader: Arc<Mutex<dyn ChainBlockReader>>,
+    max_roll_forwards_per_poll: usize,
+    is_extended_mode_enabled: bool,
+    logger: Logger,
+}
+
+impl ImmutableFileBlockScanner {
+    /// Factory
+    pub fn new(
+        db_directory: &Path,
+        chain_reader: Arc<Mutex<dyn ChainBlockReader>>,
+        max_roll_forwards_per_poll: usize,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            immutable_directory: db_directory.join("immutable"),
+            chain_reader,
+            max_roll_forwards_per_poll,
+            is_extended_mode_enabled: false,
+            logger,
+        }
+    }
+
+    /// Enable the extended mode: the contents of the transactions of the immutable blocks are
+    /// parsed.
+    ///
+    /// The chain reader must also enable it to parse the contents of the blocks that are not
+    /// yet immutable.
+    pub fn with_extended_mode(mut self, is_extended_mode_enabled: bool) -> Self {
+        self.is_extended_mode_enabled = is_extended_mode_enabled;
+        self
+    }
+}
+
+#[async_trait]
+impl BlockScanner for ImmutableFileBlockScanner {
+    async fn scan(
+        &self,
+        from: Option<ChainPoint>,
+        until: BlockNumber,
+    ) -> StdResult<Box<dyn BlockStreamer>> {
+        Ok(Box::new(
+            ImmutableFileBlockStreamer::try_new(
+                &self.immutable_directory,
+                self.chain_reader.clone(),
+                from,
+                until,
+                self.max_roll_forwards_per_poll,
+                self.logger.clone(),
+            )
+            .await?
+            .with_extended_mode(self.is_extended_mode_enabled),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pallas_hardano::storage::immutable::chunk;
+    use pallas_traverse::MultiEraBlock;
+
+    use crate::cardano_block_scanner::{CardanoBlockScanner, ChainScannedBlocks, ScannedBlock};
+    use crate::chain_reader::{ChainBlockNextAction, FakeChainReader};
+    use crate::test_utils::TestLogger;
+
+    use super::*;
+
+    const MAX_ROLL_FORWARDS_PER_POLL: usize = 50;
+
+    fn fixture_db_directory() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../mithril-test-lab/test_data/")
+            .canonicalize()
+            .unwrap()
+    }
+
+    fn read_chunk_blocks(chunk_name: &str) -> Vec<ScannedBlock> {
+        chunk::read_blocks(&fixture_db_directory().join("immutable"), chunk_name)
+            .unwrap()
+            .map(|block| ScannedBlock::convert(MultiEraBlock::decode(&block.unwrap()).unwrap()))
+            .collect()
+    }
+
+    fn roll_forwards(blocks: &[ScannedBlock]) -> Vec<ChainBlockNextAction> {
+        blocks
+            .iter()
+            .map(|block| ChainBlockNextAction::RollForward {
+                parsed_block: block.clone(),
+            })
+            .collect()
+    }
+
+    /// All the blocks of the fixture, as a node would serve them with the chain-sync
+    /// mini-protocol: the last chunk, that is not yet completed, plays the role of the volatile
+    /// tip of the chain.
+    struct FixtureChain {
+        immutable_blocks: Vec<ScannedBlock>,
+        volatile_blocks: Vec<ScannedBlock>,
+    }
+
+    impl FixtureChain {
+        fn read() -> Self {
+            Self {
+                immutable_blocks: ["00000", "00001", "00002"]
+                    .iter()
+                    .flat_map(|chunk_name| read_chunk_blocks(chunk_name))
+                    .collect(),
+                volatile_blocks: read_chunk_blocks("00003"),
+            }
+        }
+
+        fn all_blocks(&self) -> Vec<ScannedBlock> {
+            [self.immutable_blocks.clone(), self.volatile_blocks.clone()].concat()
+        }
+
+        fn chain_sync_scanner(&self, from: Option<ChainPoint>) -> CardanoBlockScanner {
+            let blocks: Vec<ScannedBlock> = self
+                .all_blocks()
+                .into_iter()
+                .filter(|block| {
+                    !matches!(&from, Some(from) if block.slot_number <= from.slot_number)
+                })
+                .collect();
+
+            CardanoBlockScanner::new(
+                Arc::new(Mutex::new(FakeChainReader::new(roll_forwards(&blocks)))),
+                MAX_ROLL_FORWARDS_PER_POLL,
+                TestLogger::stdout(),
+            )
+        }
+
+        fn immutable_file_scanner(&self) -> ImmutableFileBlockScanner {
+            let last_immutable_block = self.immutable_blocks.last().unwrap();
+            let mut chain_reader_next_actions = vec![ChainBlockNextAction::RollBackward {
+                slot_number: last_immutable_block.slot_number,
+            }];
+            chain_reader_next_actions.extend(roll_forwards(&self.volatile_blocks));
+
+            ImmutableFileBlockScanner::new(
+                &fixture_db_directory(),
+                Arc::new(Mutex::new(FakeChainReader::new(chain_reader_next_actions))),
+                MAX_ROLL_FORWARDS_PER_POLL,
+                TestLogger::stdout(),
+            )
+        }
+    }
+
+    /// Scan all the raw stream of the scanner, roll forwards are split to one block per item so
+    /// that the streams can be compared whatever the sizes of their polls.
+    async fn scan_all(
+        scanner: &dyn BlockScanner,
+        from: Option<ChainPoint>,
+        until: BlockNumber,
+    ) -> Vec<ChainScannedBlocks> {
+        let mut streamer = scanner.scan(from, until).await.unwrap();
+        let mut scanned = vec![];
+        while let Some(next) = streamer.poll_next().await.unwrap() {
+            match next {
+                ChainScannedBlocks::RollForwards(blocks) => scanned.extend(
+                    blocks
+                        .into_iter()
+                        .map(|block| ChainScannedBlocks::RollForwards(vec![block])),
+                ),
+                roll_backward => scanned.push(roll_backward),
+            }
+        }
+
+        scanned
+    }
+
+    fn scanned_roll_forwards(blocks: &[ScannedBlock]) -> Vec<ChainScannedBlocks> {
+        blocks
+            .iter()
+            .map(|block| ChainScannedBlocks::RollForwards(vec![block.clone()]))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn scan_the_same_blocks_than_the_chain_sync_scanner_from_origin() {
+        let chain = FixtureChain::read();
+        let until = chain.all_blocks().last().unwrap().block_number;
+
+        let chain_sync_blocks = scan_all(&chain.chain_sync_scanner(None), None, until).await;
+        let immutable_file_blocks =
+            scan_all(&chain.immutable_file_scanner(), None, until).await;
+
+        assert_eq!(scanned_roll_forwards(&chain.all_blocks()), chain_sync_blocks);
+        assert_eq!(chain_sync_blocks, immutable_file_blocks);
+    }
+
+    #[tokio::test]
+    async fn scan_the_same_blocks_than_the_chain_sync_scanner_from_an_immutable_block() {
+        let chain = FixtureChain::read();
+        let from = ChainPoint::from(&chain.immutable_blocks[42]);
+        let until = chain.all_blocks().last().unwrap().block_number;
+
+        let chain_sync_blocks = scan_all(
+            &chain.chain_sync_scanner(Some(from.clone())),
+            Some(from.clone()),
+            until,
+        )
+        .await;
+        let immutable_file_blocks =
+            scan_all(&chain.immutable_file_scanner(), Some(from), until).await;
+
+        assert_eq!(
+            scanned_roll_forwards(&chain.all_blocks()[43..]),
+            chain_sync_blocks
+        );
+        assert_eq!(chain_sync_blocks, immutable_file_blocks);
+    }
+
+    #[tokio::test]
+    async fn scan_the_same_blocks_than_the_chain_sync_scanner_until_an_immutable_block() {
+        let chain = FixtureChain::read();
+        let until = chain.immutable_blocks[150].block_number;
+
+        let chain_sync_blocks = scan_all(&chain.chain_sync_scanner(None), None, until).await;
+        let immutable_file_blocks =
+            scan_all(&chain.immutable_file_scanner(), None, until).await;
+
+        assert_eq!(
+            scanned_roll_forwards(&chain.immutable_blocks[..=150]),
+            chain_sync_blocks
+        );
+        assert_eq!(chain_sync_blocks, immutable_file_blocks);
+    }
+}
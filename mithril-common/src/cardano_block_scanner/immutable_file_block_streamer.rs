@@ -0,0 +1,557 @@
+use std::iter;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use pallas_hardano::storage::immutable::{chunk, FallibleBlock};
+use pallas_traverse::MultiEraBlock;
+use slog::{debug, Logger};
+use tokio::sync::Mutex;
+
+use crate::cardano_block_scanner::{
+    BlockStreamer, ChainReaderBlockStreamer, ChainScannedBlocks, ScannedBlock,
+};
+use crate::chain_reader::ChainBlockReader;
+use crate::digesters::ImmutableFile;
+use crate::entities::{BlockNumber, ChainPoint, SlotNumber};
+use crate::logging::LoggerExtensions;
+use crate::StdResult;
+
+type ImmutableBlocksIterator = Box<dyn Iterator<Item = FallibleBlock> + Send + Sync>;
+
+/// [Block streamer][BlockStreamer] that streams the blocks read from the immutable files of a
+/// Cardano node database, then hands off to a [ChainReaderBlockStreamer] to stream the blocks that
+/// are not yet immutable.
+pub struct ImmutableFileBlockStreamer {
+    immutable_blocks: Option<ImmutableBlocksIterator>,
+    chain_reader: Arc<Mutex<dyn ChainBlockReader>>,
+    chain_reader_block_streamer: Option<ChainReaderBlockStreamer>,
+    last_streamed_point: Option<ChainPoint>,
+    until: BlockNumber,
+    is_until_reached: bool,
+    max_roll_forwards_per_poll: usize,
+    is_extended_mode_enabled: bool,
+    logger: Logger,
+}
+
+#[async_trait]
+impl BlockStreamer for ImmutableFileBlockStreamer {
+    async fn poll_next(&mut self) -> StdResult<Option<ChainScannedBlocks>> {
+        debug!(self.logger, "polls next");
+
+        if self.is_until_reached {
+            return Ok(None);
+        }
+
+        if self.immutable_blocks.is_some() {
+            let roll_forwards = self.read_next_immutable_blocks().await?;
+            if !roll_forwards.is_empty() {
+                return Ok(Some(ChainScannedBlocks::RollForwards(roll_forwards)));
+            }
+            if self.is_until_reached {
+                return Ok(None);
+            }
+
+            self.hand_off_to_chain_reader().await?;
+        }
+
+        match &mut self.chain_reader_block_streamer {
+            Some(chain_reader_block_streamer) => chain_reader_block_streamer.poll_next().await,
+            None => Ok(None),
+        }
+    }
+}
+
+impl ImmutableFileBlockStreamer {
+    /// Factory
+    pub async fn try_new(
+        immutable_directory: &Path,
+        chain_reader: Arc<Mutex<dyn ChainBlockReader>>,
+        from: Option<ChainPoint>,
+        until: BlockNumber,
+        max_roll_forwards_per_poll: usize,
+        logger: Logger,
+    ) -> StdResult<Self> {
+        let logger = logger.new_with_component_name::<Self>();
+        // the chunk files are read in a separate thread because it is blocking the whole task
+        let immutable_blocks = {
+            let immutable_directory = immutable_directory.to_path_buf();
+            let from = from.clone();
+            tokio::task::spawn_blocking(move || {
+                Self::read_immutable_blocks(&immutable_directory, from.as_ref())
+            })
+            .await
+            .with_context(|| "Could not read the immutable files: the reading task crashed")??
+        };
+        let mut streamer = Self {
+            immutable_blocks,
+            chain_reader,
+            chain_reader_block_streamer: None,
+            last_streamed_point: from,
+            until,
+            is_until_reached: false,
+            max_roll_forwards_per_poll,
+            is_extended_mode_enabled: false,
+            logger,
+        };
+        if streamer.immutable_blocks.is_none() {
+            debug!(
+                streamer.logger,
+                "no immutable blocks to read after the starting point"
+            );
+            streamer.hand_off_to_chain_reader().await?;
+        }
+
+        Ok(streamer)
+    }
+
+    /// Enable the extended mode: the contents of the transactions of the immutable blocks are
+    /// parsed, the ones of the blocks that are not yet immutable are parsed by the chain reader.
+    pub fn with_extended_mode(mut self, is_extended_mode_enabled: bool) -> Self {
+        self.is_extended_mode_enabled = is_extended_mode_enabled;
+        self
+    }
+
+    /// Read the blocks of the completed immutable files that are strictly after the given point.
+    ///
+    /// The last immutable file is not read since the node is still writing it, its blocks are
+    /// streamed by the chain reader after the hand-off.
+    ///
+    /// Returns `None` if the point is not in the completed immutable files, ie if it has not yet
+    /// been written in them or if there is no completed immutable file.
+    fn read_immutable_blocks(
+        immutable_directory: &Path,
+        from: Option<&ChainPoint>,
+    ) -> StdResult<Option<ImmutableBlocksIterator>> {
+        if !immutable_directory.is_dir() {
+            return Ok(None);
+        }
+        let chunk_names = Self::list_completed_chunk_names(immutable_directory)?;
+        if chunk_names.is_empty() {
+            return Ok(None);
+        }
+
+        match from {
+            None => Ok(Some(Self::read_chunks_blocks(
+                immutable_directory,
+                chunk_names,
+            ))),
+            Some(from) => {
+                let first_chunk_index = Self::find_chunk_index_of_slot(
+                    immutable_directory,
+                    &chunk_names,
+                    from.slot_number,
+                )?;
+                let Some(first_chunk_index) = first_chunk_index else {
+                    return Ok(None);
+                };
+                let mut blocks = Self::read_chunks_blocks(
+                    immutable_directory,
+                    chunk_names[first_chunk_index..].to_vec(),
+                )
+                .peekable();
+
+                while let Some(Ok(block)) = blocks.peek() {
+                    if MultiEraBlock::decode(block)?.slot() >= *from.slot_number {
+                        break;
+                    }
+                    blocks.next();
+                }
+                // The starting point was already streamed, the iterator starts right after it
+                match blocks.next() {
+                    Some(Ok(block))
+                        if MultiEraBlock::decode(&block)?.hash().to_string() == from.block_hash =>
+                    {
+                        Ok(Some(Box::new(blocks)))
+                    }
+                    Some(Err(error)) => Err(anyhow!(error)).with_context(|| {
+                        format!(
+                            "Could not read the immutable files of directory '{}' from point '{:?}'",
+                            immutable_directory.display(),
+                            from
+                        )
+                    }),
+                    _ => Ok(None),
+                }
+            }
+        }
+    }
+
+    fn list_completed_chunk_names(immutable_directory: &Path) -> StdResult<Vec<String>> {
+        let immutable_files = ImmutableFile::list_completed_in_dir(immutable_directory)
+            .with_context(|| {
+                format!(
+                    "Could not list the immutable files of directory '{}'",
+                    immutable_directory.display()
+                )
+            })?;
+
+        Ok(immutable_files
+            .into_iter()
+            .filter(|file| file.path.extension().is_some_and(|extension| extension == "chunk"))
+            .filter_map(|file| {
+                file.path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+            })
+            .collect())
+    }
+
+    fn read_chunks_blocks(
+        immutable_directory: &Path,
+        chunk_names: Vec<String>,
+    ) -> ImmutableBlocksIterator {
+        let immutable_directory = immutable_directory.to_path_buf();
+        Box::new(chunk_names.into_iter().flat_map(move |chunk_name| {
+            let blocks: ImmutableBlocksIterator =
+                match chunk::read_blocks(&immutable_directory, &chunk_name) {
+                    Ok(blocks) => Box::new(blocks),
+                    Err(error) => Box::new(iter::once(Err(error))),
+                };
+            blocks
+        }))
+    }
+
+    /// Binary search of the chunk that may contain the given slot, ie the last chunk whose first
+    /// block is at or before it.
+    fn find_chunk_index_of_slot(
+        immutable_directory: &Path,
+        chunk_names: &[String],
+        slot_number: SlotNumber,
+    ) -> StdResult<Option<usize>> {
+        let (mut low, mut high) = (0, chunk_names.len());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let mut chunk_blocks = chunk::read_blocks(immutable_directory, &chunk_names[middle])?;
+            let first_block_slot = match chunk_blocks.next() {
+                Some(block) => Some(MultiEraBlock::decode(&block?)?.slot()),
+                None => None,
+            };
+            match first_block_slot {
+                Some(slot) if slot <= *slot_number => low = middle + 1,
+                _ => high = middle,
+            }
+        }
+
+        Ok(low.checked_sub(1))
+    }
+
+    async fn read_next_immutable_blocks(&mut self) -> StdResult<Vec<ScannedBlock>> {
+        let Some(mut immutable_blocks) = self.immutable_blocks.take() else {
+            return Ok(vec![]);
+        };
+        let until = self.until;
+        let max_roll_forwards_per_poll = self.max_roll_forwards_per_poll;
+        let is_extended_mode_enabled = self.is_extended_mode_enabled;
+
+        // the chunk files are read and their blocks decoded in a separate thread because it is
+        // blocking the whole task
+        let (immutable_blocks, read_result) = tokio::task::spawn_blocking(move || {
+            let read_result = Self::read_blocks_until(
+                &mut immutable_blocks,
+                until,
+                max_roll_forwards_per_poll,
+                is_extended_mode_enabled,
+            );
+            (immutable_blocks, read_result)
+        })
+        .await
+        .with_context(|| "Could not read the immutable files: the reading task crashed")?;
+        self.immutable_blocks = Some(immutable_blocks);
+
+        let (roll_forwards, is_until_reached) = read_result?;
+        if let Some(last_block) = roll_forwards.last() {
+            self.last_streamed_point = Some(ChainPoint::from(last_block));
+        }
+        self.is_until_reached = is_until_reached;
+
+        Ok(roll_forwards)
+    }
+
+    /// Read at most `max_roll_forwards_per_poll` blocks, stopping at the `until` block number.
+    ///
+    /// Returns the read blocks and whether the `until` block number is reached.
+    fn read_blocks_until(
+        immutable_blocks: &mut ImmutableBlocksIterator,
+        until: BlockNumber,
+        max_roll_forwards_per_poll: usize,
+        is_extended_mode_enabled: bool,
+    ) -> StdResult<(Vec<ScannedBlock>, bool)> {
+        let mut roll_forwards = vec![];
+
+        for block in immutable_blocks.by_ref() {
+            let block = block.with_context(|| "Could not read a block of the immutable files")?;
+            let block = MultiEraBlock::decode(&block)
+                .with_context(|| "Could not decode a block of the immutable files")?;
+            let scanned_block = if is_extended_mode_enabled {
+                ScannedBlock::convert_with_contents(block)?
+            } else {
+                ScannedBlock::convert(block)
+            };
+
+            if scanned_block.block_number > until {
+                return Ok((roll_forwards, true));
+            }
+
+            let scanned_block_number = scanned_block.block_number;
+            roll_forwards.push(scanned_block);
+            if scanned_block_number >= until {
+                return Ok((roll_forwards, true));
+            }
+            if roll_forwards.len() >= max_roll_forwards_per_poll {
+                break;
+            }
+        }
+
+        Ok((roll_forwards, false))
+    }
+
+    async fn hand_off_to_chain_reader(&mut self) -> StdResult<()> {
+        debug!(
+            self.logger,
+            "hands off to the chain reader";
+            "from" => ?self.last_streamed_point
+        );
+        self.immutable_blocks = None;
+        self.chain_reader_block_streamer = Some(
+            ChainReaderBlockStreamer::try_new(
+                self.chain_reader.clone(),
+                self.last_streamed_point.clone(),
+                self.until,
+                self.max_roll_forwards_per_poll,
+                self.logger.clone(),
+            )
+            .await?,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::cardano_block_scanner::BlockStreamerTestExtensions;
+    use crate::chain_reader::{ChainBlockNextAction, FakeChainReader};
+    use crate::test_utils::TestLogger;
+
+    use super::*;
+
+    fn fixture_immutable_directory() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../mithril-test-lab/test_data/immutable/")
+            .canonicalize()
+            .unwrap()
+    }
+
+    fn read_chunk_blocks(chunk_name: &str) -> Vec<ScannedBlock> {
+        chunk::read_blocks(&fixture_immutable_directory(), chunk_name)
+            .unwrap()
+            .map(|block| ScannedBlock::convert(MultiEraBlock::decode(&block.unwrap()).unwrap()))
+            .collect()
+    }
+
+    /// Blocks of the completed immutable files of the fixture, the last immutable file,
+    /// `00003`, is not completed.
+    fn fixture_immutable_blocks() -> Vec<ScannedBlock> {
+        ["00000", "00001", "00002"]
+            .iter()
+            .flat_map(|chunk_name| read_chunk_blocks(chunk_name))
+            .collect()
+    }
+
+    fn roll_forwards(blocks: &[ScannedBlock]) -> Vec<ChainBlockNextAction> {
+        blocks
+            .iter()
+            .map(|block| ChainBlockNextAction::RollForward {
+                parsed_block: block.clone(),
+            })
+            .collect()
+    }
+
+    async fn poll_all(
+        from: Option<ChainPoint>,
+        until: BlockNumber,
+        chain_reader_next_actions: Vec<ChainBlockNextAction>,
+    ) -> Vec<ScannedBlock> {
+        let mut streamer = ImmutableFileBlockStreamer::try_new(
+            &fixture_immutable_directory(),
+            Arc::new(Mutex::new(FakeChainReader::new(chain_reader_next_actions))),
+            from,
+            until,
+            100,
+            TestLogger::stdout(),
+        )
+        .await
+        .unwrap();
+
+        streamer.poll_all().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn stream_all_immutable_blocks_from_origin() {
+        let expected_blocks = fixture_immutable_blocks();
+
+        let blocks = poll_all(None, BlockNumber(u64::MAX), vec![]).await;
+
+        assert_eq!(expected_blocks, blocks);
+    }
+
+    #[tokio::test]
+    async fn do_not_stream_the_blocks_of_the_last_immutable_file() {
+        let last_immutable_file_blocks = read_chunk_blocks("00003");
+        assert!(!last_immutable_file_blocks.is_empty());
+
+        let blocks = poll_all(None, BlockNumber(u64::MAX), vec![]).await;
+
+        assert!(blocks
+            .iter()
+            .all(|block| !last_immutable_file_blocks.contains(block)));
+    }
+
+    #[tokio::test]
+    async fn hand_off_directly_to_the_chain_reader_if_starting_point_is_in_the_last_immutable_file(
+    ) {
+        let last_immutable_file_blocks = read_chunk_blocks("00003");
+        let from = ChainPoint::from(&last_immutable_file_blocks[0]);
+
+        let blocks = poll_all(
+            Some(from),
+            BlockNumber(u64::MAX),
+            roll_forwards(&last_immutable_file_blocks[1..]),
+        )
+        .await;
+
+        assert_eq!(last_immutable_file_blocks[1..].to_vec(), blocks);
+    }
+
+    #[tokio::test]
+    async fn stream_immutable_blocks_strictly_after_the_starting_point() {
+        let immutable_blocks = fixture_immutable_blocks();
+        let from = ChainPoint::from(&immutable_blocks[10]);
+
+        let blocks = poll_all(Some(from), BlockNumber(u64::MAX), vec![]).await;
+
+        assert_eq!(immutable_blocks[11..].to_vec(), blocks);
+    }
+
+    #[tokio::test]
+    async fn stream_immutable_blocks_until_the_given_block_number() {
+        let immutable_blocks = fixture_immutable_blocks();
+        let until = immutable_blocks[20].block_number;
+
+        let blocks = poll_all(None, until, vec![]).await;
+
+        assert_eq!(
+            immutable_blocks
+                .into_iter()
+                .filter(|block| block.block_number <= until)
+                .collect::<Vec<_>>(),
+            blocks
+        );
+    }
+
+    #[tokio::test]
+    async fn poll_at_most_max_roll_forwards_per_poll_immutable_blocks() {
+        let mut streamer = ImmutableFileBlockStreamer::try_new(
+            &fixture_immutable_directory(),
+            Arc::new(Mutex::new(FakeChainReader::new(vec![]))),
+            None,
+            BlockNumber(u64::MAX),
+            5,
+            TestLogger::stdout(),
+        )
+        .await
+        .unwrap();
+
+        let next = streamer.poll_next().await.unwrap();
+
+        assert_eq!(
+            Some(ChainScannedBlocks::RollForwards(
+                fixture_immutable_blocks()[..5].to_vec()
+            )),
+            next
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_immutable_blocks_with_their_transactions_contents_if_extended_mode_enabled() {
+        let mut streamer = ImmutableFileBlockStreamer::try_new(
+            &fixture_immutable_directory(),
+            Arc::new(Mutex::new(FakeChainReader::new(vec![]))),
+            None,
+            BlockNumber(u64::MAX),
+            100,
+            TestLogger::stdout(),
+        )
+        .await
+        .unwrap()
+        .with_extended_mode(true);
+
+        let blocks = streamer.poll_all().await.unwrap();
+
+        let blocks_with_transactions: Vec<_> = blocks
+            .iter()
+            .filter(|block| block.transactions_len() > 0)
+            .collect();
+        assert!(!blocks_with_transactions.is_empty());
+        for block in blocks_with_transactions {
+            assert_eq!(block.transactions_len(), block.transactions_contents.len());
+        }
+    }
+
+    #[tokio::test]
+    async fn hand_off_to_the_chain_reader_after_the_last_immutable_block() {
+        let immutable_blocks = fixture_immutable_blocks();
+        let last_immutable_block = immutable_blocks.last().unwrap().clone();
+        let volatile_block = ScannedBlock::new(
+            "volatile-hash",
+            last_immutable_block.block_number + 1,
+            last_immutable_block.slot_number + 1,
+            vec!["tx-1"],
+        );
+
+        let blocks = poll_all(
+            None,
+            BlockNumber(u64::MAX),
+            vec![
+                ChainBlockNextAction::RollBackward {
+                    slot_number: last_immutable_block.slot_number,
+                },
+                ChainBlockNextAction::RollForward {
+                    parsed_block: volatile_block.clone(),
+                },
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            [immutable_blocks, vec![volatile_block]].concat(),
+            blocks
+        );
+    }
+
+    #[tokio::test]
+    async fn hand_off_directly_to_the_chain_reader_if_starting_point_is_not_immutable() {
+        let from = ChainPoint::new(SlotNumber(u64::MAX - 1), BlockNumber(u64::MAX - 1), "0a0b");
+        let volatile_block = ScannedBlock::new(
+            "volatile-hash",
+            BlockNumber(u64::MAX),
+            SlotNumber(u64::MAX),
+            vec!["tx-1"],
+        );
+
+        let blocks = poll_all(
+            Some(from),
+            BlockNumber(u64::MAX),
+            vec![ChainBlockNextAction::RollForward {
+                parsed_block: volatile_block.clone(),
+            }],
+        )
+        .await;
+
+        assert_eq!(vec![volatile_block], blocks);
+    }
+}
@@ -2,11 +2,15 @@
 mod block_scanner;
 mod chain_reader_block_streamer;
 mod dumb_block_scanner;
+mod immutable_file_block_scanner;
+mod immutable_file_block_streamer;
 mod interface;
 mod scanned_block;
 
 pub use block_scanner::*;
 pub use chain_reader_block_streamer::*;
 pub use dumb_block_scanner::*;
+pub use immutable_file_block_scanner::*;
+pub use immutable_file_block_streamer::*;
 pub use interface::*;
 pub use scanned_block::*;
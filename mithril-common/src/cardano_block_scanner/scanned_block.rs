@@ -1,6 +1,12 @@
-use pallas_traverse::MultiEraBlock;
+use anyhow::Context;
+use pallas_traverse::{MultiEraBlock, MultiEraTx};
 
-use crate::entities::{BlockHash, BlockNumber, CardanoTransaction, SlotNumber, TransactionHash};
+use crate::entities::{
+    BlockHash, BlockNumber, CardanoMintedAsset, CardanoTransaction, CardanoTransactionContent,
+    CardanoTransactionInput, CardanoTransactionOutput, ChainPoint, ExtendedCardanoTransaction,
+    SlotNumber, TransactionHash,
+};
+use crate::StdResult;
 
 /// A block scanned from a Cardano database
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +19,10 @@ pub struct ScannedBlock {
     pub slot_number: SlotNumber,
     /// Hashes of the transactions in the block
     pub transactions_hashes: Vec<TransactionHash>,
+    /// Contents of the transactions in the block, in the same order as their hashes
+    ///
+    /// Only parsed when the extended mode is enabled, empty otherwise.
+    pub transactions_contents: Vec<CardanoTransactionContent>,
 }
 
 impl ScannedBlock {
@@ -28,9 +38,19 @@ impl ScannedBlock {
             block_number,
             slot_number,
             transactions_hashes: transaction_hashes.into_iter().map(|h| h.into()).collect(),
+            transactions_contents: vec![],
         }
     }
 
+    /// Set the contents of the transactions of the block, in the same order as their hashes
+    pub fn with_transactions_contents(
+        mut self,
+        transactions_contents: Vec<CardanoTransactionContent>,
+    ) -> Self {
+        self.transactions_contents = transactions_contents;
+        self
+    }
+
     pub(crate) fn convert(multi_era_block: MultiEraBlock) -> Self {
         let mut transactions = Vec::new();
         for tx in &multi_era_block.txs() {
@@ -45,6 +65,62 @@ impl ScannedBlock {
         )
     }
 
+    /// Convert the block with the contents of its transactions, used by the extended mode
+    pub(crate) fn convert_with_contents(multi_era_block: MultiEraBlock) -> StdResult<Self> {
+        let transactions_contents = multi_era_block
+            .txs()
+            .iter()
+            .map(Self::convert_transaction_content)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(Self::convert(multi_era_block).with_transactions_contents(transactions_contents))
+    }
+
+    fn convert_transaction_content(tx: &MultiEraTx) -> StdResult<CardanoTransactionContent> {
+        let outputs = tx
+            .outputs()
+            .iter()
+            .map(|output| {
+                let address = output.address().with_context(|| {
+                    format!("Could not decode an output address of transaction '{}'", tx.hash())
+                })?;
+
+                Ok(CardanoTransactionOutput {
+                    address: address.to_string(),
+                    lovelace: output.lovelace_amount(),
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(CardanoTransactionContent {
+            inputs: tx
+                .inputs()
+                .iter()
+                .map(|input| CardanoTransactionInput {
+                    transaction_hash: input.hash().to_string(),
+                    output_index: input.index(),
+                })
+                .collect(),
+            outputs,
+            minted_assets: tx
+                .mints()
+                .iter()
+                .flat_map(|policy_assets| policy_assets.assets())
+                .map(|asset| CardanoMintedAsset {
+                    policy_id: asset.policy().to_string(),
+                    asset_name: hex::encode(asset.name()),
+                    quantity: asset.mint_coin().unwrap_or_default(),
+                })
+                .collect(),
+            metadata_labels: tx
+                .metadata()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|(label, _)| label)
+                .collect(),
+        })
+    }
+
     /// Number of transactions in the block
     pub fn transactions_len(&self) -> usize {
         self.transactions_hashes.len()
@@ -66,4 +142,126 @@ impl ScannedBlock {
             })
             .collect::<Vec<_>>()
     }
+
+    /// Convert the scanned block into a list of extended Cardano transactions.
+    ///
+    /// Transactions without known contents, ie for blocks that were not parsed from the chain,
+    /// get an empty content.
+    ///
+    /// Consume the block.
+    pub fn into_extended_transactions(self) -> Vec<ExtendedCardanoTransaction> {
+        let mut transactions_contents = self.transactions_contents.into_iter();
+        self.transactions_hashes
+            .into_iter()
+            .map(|transaction_hash| {
+                ExtendedCardanoTransaction::new(
+                    CardanoTransaction::new(
+                        transaction_hash,
+                        self.block_number,
+                        self.slot_number,
+                        self.block_hash.clone(),
+                    ),
+                    transactions_contents.next().unwrap_or_default(),
+                )
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+impl From<&ScannedBlock> for ChainPoint {
+    fn from(scanned_block: &ScannedBlock) -> Self {
+        ChainPoint::new(
+            scanned_block.slot_number,
+            scanned_block.block_number,
+            scanned_block.block_hash.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_test_block(block_name: &str) -> Vec<u8> {
+        let raw_block = std::fs::read_to_string(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../mithril-test-lab/test_data/blocks")
+                .join(block_name),
+        )
+        .unwrap();
+
+        hex::decode(raw_block.trim()).unwrap()
+    }
+
+    #[test]
+    fn convert_does_not_parse_the_contents_of_the_transactions() {
+        let raw_block = read_test_block("conway1.block");
+
+        let scanned_block = ScannedBlock::convert(MultiEraBlock::decode(&raw_block).unwrap());
+
+        assert!(!scanned_block.transactions_hashes.is_empty());
+        assert!(scanned_block.transactions_contents.is_empty());
+    }
+
+    #[test]
+    fn convert_with_contents_parses_the_contents_of_all_transactions() {
+        for block_name in [
+            "byron2.block",
+            "shelley1.block",
+            "allegra1.block",
+            "mary1.block",
+            "alonzo1.block",
+            "conway1.block",
+        ] {
+            let raw_block = read_test_block(block_name);
+            let scanned_block =
+                ScannedBlock::convert_with_contents(MultiEraBlock::decode(&raw_block).unwrap())
+                    .unwrap();
+
+            assert_eq!(
+                scanned_block.transactions_hashes.len(),
+                scanned_block.transactions_contents.len(),
+                "block '{block_name}'"
+            );
+            for content in &scanned_block.transactions_contents {
+                assert!(!content.inputs.is_empty(), "block '{block_name}'");
+                assert!(!content.outputs.is_empty(), "block '{block_name}'");
+                assert!(
+                    content.outputs.iter().all(|output| !output.address.is_empty()),
+                    "block '{block_name}'"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn into_extended_transactions_pairs_transactions_with_their_contents() {
+        let content = CardanoTransactionContent {
+            metadata_labels: vec![674],
+            ..CardanoTransactionContent::default()
+        };
+        let block = ScannedBlock::new(
+            "block_hash",
+            BlockNumber(10),
+            SlotNumber(20),
+            vec!["tx_hash-1", "tx_hash-2"],
+        )
+        .with_transactions_contents(vec![content.clone()]);
+
+        let extended_transactions = block.into_extended_transactions();
+
+        assert_eq!(
+            vec![
+                ExtendedCardanoTransaction::new(
+                    CardanoTransaction::new("tx_hash-1", BlockNumber(10), SlotNumber(20), "block_hash"),
+                    content
+                ),
+                ExtendedCardanoTransaction::new(
+                    CardanoTransaction::new("tx_hash-2", BlockNumber(10), SlotNumber(20), "block_hash"),
+                    CardanoTransactionContent::default()
+                ),
+            ],
+            extended_transactions
+        );
+    }
 }
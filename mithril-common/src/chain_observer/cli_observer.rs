@@ -294,6 +294,26 @@ impl CardanoCliChainObserver {
         Self { cli_runner }
     }
 
+    /// Get the KES period info of an operational certificate with the `kes-period-info` command.
+    async fn get_kes_period_info(&self, opcert: &OpCert) -> Result<Value, ChainObserverError> {
+        let dir = std::env::temp_dir().join("mithril_kes_period");
+        fs::create_dir_all(&dir).map_err(|e| ChainObserverError::General(e.into()))?;
+        let opcert_file = dir.join(format!("opcert_kes_period-{}", opcert.compute_hash()));
+        opcert
+            .to_file(&opcert_file)
+            .map_err(|e| ChainObserverError::General(e.into()))?;
+        let output = self
+            .cli_runner
+            .launch_kes_period(opcert_file.to_str().unwrap())
+            .await
+            .map_err(ChainObserverError::General)?;
+        let first_left_curly_bracket_index = output.find('{').unwrap_or_default();
+        let output_cleaned = output.split_at(first_left_curly_bracket_index).1;
+        serde_json::from_str(output_cleaned)
+            .with_context(|| format!("output was = '{output}'"))
+            .map_err(ChainObserverError::InvalidContent)
+    }
+
     // This is the only way I found to tell the compiler the correct types
     // and lifetimes for the function `double`.
     fn parse_string<'a>(&'a self, string: &'a str) -> IResult<&str, f64> {
@@ -490,29 +510,27 @@ impl ChainObserver for CardanoCliChainObserver {
         &self,
         opcert: &OpCert,
     ) -> Result<Option<KESPeriod>, ChainObserverError> {
-        let dir = std::env::temp_dir().join("mithril_kes_period");
-        fs::create_dir_all(&dir).map_err(|e| ChainObserverError::General(e.into()))?;
-        let opcert_file = dir.join(format!("opcert_kes_period-{}", opcert.compute_hash()));
-        opcert
-            .to_file(&opcert_file)
-            .map_err(|e| ChainObserverError::General(e.into()))?;
-        let output = self
-            .cli_runner
-            .launch_kes_period(opcert_file.to_str().unwrap())
-            .await
-            .map_err(ChainObserverError::General)?;
-        let first_left_curly_bracket_index = output.find('{').unwrap_or_default();
-        let output_cleaned = output.split_at(first_left_curly_bracket_index).1;
-        let v: Value = serde_json::from_str(output_cleaned)
-            .with_context(|| format!("output was = '{output}'"))
-            .map_err(ChainObserverError::InvalidContent)?;
+        let kes_period_info = self.get_kes_period_info(opcert).await?;
 
-        if let Value::Number(kes_period) = &v["qKesCurrentKesPeriod"] {
+        if let Value::Number(kes_period) = &kes_period_info["qKesCurrentKesPeriod"] {
             Ok(kes_period.as_u64().map(|p| p as KESPeriod))
         } else {
             Ok(None)
         }
     }
+
+    async fn get_max_kes_evolutions(
+        &self,
+        opcert: &OpCert,
+    ) -> Result<Option<KESPeriod>, ChainObserverError> {
+        let kes_period_info = self.get_kes_period_info(opcert).await?;
+
+        if let Value::Number(max_kes_evolutions) = &kes_period_info["qKesMaxKESEvolutions"] {
+            Ok(max_kes_evolutions.as_u64().map(|p| p as KESPeriod))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -748,4 +766,20 @@ mod tests {
             .unwrap();
         assert_eq!(404, kes_period);
     }
+
+    #[tokio::test]
+    async fn test_get_max_kes_evolutions() {
+        let keypair = ColdKeyGenerator::create_deterministic_keypair([0u8; 32]);
+        let mut dummy_key_buffer = [0u8; Sum6Kes::SIZE + 4];
+        let mut dummy_seed = [0u8; 32];
+        let (_, kes_verification_key) = Sum6Kes::keygen(&mut dummy_key_buffer, &mut dummy_seed);
+        let operational_certificate = OpCert::new(kes_verification_key, 0, 0, keypair);
+        let observer = CardanoCliChainObserver::new(Box::<TestCliRunner>::default());
+        let max_kes_evolutions = observer
+            .get_max_kes_evolutions(&operational_certificate)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(62, max_kes_evolutions);
+    }
 }
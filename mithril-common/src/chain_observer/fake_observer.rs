@@ -22,6 +22,16 @@ pub struct FakeObserver {
     ///
     /// [get_current_datums]: ChainObserver::get_current_datums
     pub datums: RwLock<Vec<TxDatum>>,
+
+    /// A [DRepStakeDistribution], used by [get_current_drep_stake_distribution]
+    ///
+    /// [get_current_drep_stake_distribution]: ChainObserver::get_current_drep_stake_distribution
+    pub drep_stake_distribution: RwLock<DRepStakeDistribution>,
+
+    /// A [KESPeriod], used by [get_current_kes_period]
+    ///
+    /// [get_current_kes_period]: ChainObserver::get_current_kes_period
+    pub current_kes_period: RwLock<Option<KESPeriod>>,
 }
 
 impl FakeObserver {
@@ -31,6 +41,8 @@ impl FakeObserver {
             signers: RwLock::new(vec![]),
             current_time_point: RwLock::new(current_time_point.clone()),
             datums: RwLock::new(vec![]),
+            drep_stake_distribution: RwLock::new(DRepStakeDistribution::new()),
+            current_kes_period: RwLock::new(Some(0)),
         }
     }
 
@@ -118,6 +130,13 @@ impl FakeObserver {
         *signers = new_signers;
     }
 
+    /// Set the KES period returned by
+    /// [get_current_kes_period][ChainObserver::get_current_kes_period].
+    pub async fn set_current_kes_period(&self, new_current_kes_period: Option<KESPeriod>) {
+        let mut current_kes_period = self.current_kes_period.write().await;
+        *current_kes_period = new_current_kes_period;
+    }
+
     /// Set the time point
     pub async fn set_current_time_point(&self, new_current_time_point: Option<TimePoint>) {
         let mut current_time_point = self.current_time_point.write().await;
@@ -130,12 +149,23 @@ impl FakeObserver {
         let mut datums = self.datums.write().await;
         *datums = new_datums;
     }
+
+    /// Set the DRep stake distribution that will be returned by
+    /// [get_current_drep_stake_distribution][ChainObserver::get_current_drep_stake_distribution].
+    pub async fn set_drep_stake_distribution(
+        &self,
+        new_drep_stake_distribution: DRepStakeDistribution,
+    ) {
+        let mut drep_stake_distribution = self.drep_stake_distribution.write().await;
+        *drep_stake_distribution = new_drep_stake_distribution;
+    }
 }
 
 impl Default for FakeObserver {
     fn default() -> Self {
         let mut observer = Self::new(Some(TimePoint::dummy()));
         observer.signers = RwLock::new(fake_data::signers_with_stakes(2));
+        observer.drep_stake_distribution = RwLock::new(fake_data::drep_stake_distribution(2));
 
         observer
     }
@@ -182,11 +212,24 @@ impl ChainObserver for FakeObserver {
         ))
     }
 
+    async fn get_current_drep_stake_distribution(
+        &self,
+    ) -> Result<Option<DRepStakeDistribution>, ChainObserverError> {
+        Ok(Some(self.drep_stake_distribution.read().await.clone()))
+    }
+
     async fn get_current_kes_period(
         &self,
         _opcert: &OpCert,
     ) -> Result<Option<KESPeriod>, ChainObserverError> {
-        Ok(Some(0))
+        Ok(*self.current_kes_period.read().await)
+    }
+
+    async fn get_max_kes_evolutions(
+        &self,
+        _opcert: &OpCert,
+    ) -> Result<Option<KESPeriod>, ChainObserverError> {
+        Ok(Some(62))
     }
 }
 
@@ -235,6 +278,23 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_current_drep_stake_distribution() {
+        let fake_observer = FakeObserver::new(None);
+        fake_observer
+            .set_drep_stake_distribution(fake_data::drep_stake_distribution(3))
+            .await;
+        let drep_stake_distribution = fake_observer
+            .get_current_drep_stake_distribution()
+            .await
+            .expect("get current DRep stake distribution should not fail");
+
+        assert_eq!(
+            Some(fake_data::drep_stake_distribution(3)),
+            drep_stake_distribution
+        );
+    }
+
     #[tokio::test]
     async fn test_get_current_datums() {
         let fake_address = "addr_test_123456".to_string();
@@ -339,4 +399,18 @@ mod tests {
             "get current chain point should not fail"
         );
     }
+
+    #[tokio::test]
+    async fn test_get_current_kes_period() {
+        let fake_observer = FakeObserver::new(None);
+        fake_observer.set_current_kes_period(Some(12)).await;
+        let opcert: OpCert = fake_data::signers_with_stakes(1)[0]
+            .operational_certificate
+            .clone()
+            .unwrap()
+            .into();
+        let kes_period = fake_observer.get_current_kes_period(&opcert).await.unwrap();
+
+        assert_eq!(Some(12), kes_period);
+    }
 }
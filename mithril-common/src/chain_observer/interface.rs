@@ -44,6 +44,13 @@ pub trait ChainObserver: Sync + Send {
         &self,
     ) -> Result<Option<StakeDistribution>, ChainObserverError>;
 
+    /// Retrieve the current DRep stake distribution of the Cardano network
+    async fn get_current_drep_stake_distribution(
+        &self,
+    ) -> Result<Option<DRepStakeDistribution>, ChainObserverError> {
+        Ok(None)
+    }
+
     /// Retrieve the KES period of an operational certificate
     async fn get_current_kes_period(
         &self,
@@ -51,4 +58,12 @@ pub trait ChainObserver: Sync + Send {
     ) -> Result<Option<KESPeriod>, ChainObserverError> {
         Ok(None)
     }
+
+    /// Retrieve the maximum number of KES evolutions of an operational certificate
+    async fn get_max_kes_evolutions(
+        &self,
+        _opcert: &OpCert,
+    ) -> Result<Option<KESPeriod>, ChainObserverError> {
+        Ok(None)
+    }
 }
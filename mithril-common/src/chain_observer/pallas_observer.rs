@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use pallas_addresses::Address;
+use pallas_codec::minicbor::{decode, encode, Decode, Decoder, Encode, Encoder};
 use pallas_codec::utils::{Bytes, CborWrap, TagWrap};
 use pallas_network::{
     facades::NodeClient,
@@ -23,12 +24,152 @@ use pallas_primitives::ToCanonicalJson;
 use crate::{
     chain_observer::{interface::*, ChainAddress, TxDatum},
     crypto_helper::{encode_bech32, KESPeriod, OpCert},
-    entities::{BlockNumber, ChainPoint, Epoch, SlotNumber, StakeDistribution},
+    entities::{
+        BlockNumber, ChainPoint, DRepStakeDistribution, Epoch, SlotNumber, StakeDistribution,
+    },
     CardanoNetwork, StdResult,
 };
 
 use super::model::{try_inspect, Datum, Datums};
 
+/// Index of the Conway era in the hard fork combinator, first era with on-chain governance
+const CONWAY_ERA: u16 = 6;
+
+/// Tag of the `GetDRepStakeDistr` ledger block query
+const GET_DREP_STAKE_DISTRIBUTION_QUERY_TAG: u16 = 26;
+
+/// Local state query of the DRep stake distribution of all the delegated representatives.
+///
+/// This query is not yet available in the `pallas-network` queries, its encoding follows the
+/// `LedgerQuery::BlockQuery` encoding of `queries_v16::Request`.
+#[derive(Debug, Clone, PartialEq)]
+struct DRepStakeDistributionQuery {
+    era: u16,
+}
+
+impl Encode<()> for DRepStakeDistributionQuery {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut (),
+    ) -> Result<(), encode::Error<W::Error>> {
+        // Request::LedgerQuery
+        e.array(2)?.u16(0)?;
+        // LedgerQuery::BlockQuery
+        e.array(2)?.u16(0)?;
+        e.array(2)?.u16(self.era)?;
+        // GetDRepStakeDistr with an empty set of DReps, meaning all of them
+        e.array(2)?.u16(GET_DREP_STAKE_DISTRIBUTION_QUERY_TAG)?;
+        e.array(0)?;
+
+        Ok(())
+    }
+}
+
+impl<'b> Decode<'b, ()> for DRepStakeDistributionQuery {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut ()) -> Result<Self, decode::Error> {
+        fn expect_tag(d: &mut Decoder, expected_tag: u16) -> Result<(), decode::Error> {
+            d.array()?;
+            match d.u16()? {
+                tag if tag == expected_tag => Ok(()),
+                _ => Err(decode::Error::message("invalid tag")),
+            }
+        }
+
+        expect_tag(d, 0)?;
+        expect_tag(d, 0)?;
+        d.array()?;
+        let era = d.u16()?;
+        expect_tag(d, GET_DREP_STAKE_DISTRIBUTION_QUERY_TAG)?;
+        d.skip()?;
+
+        Ok(Self { era })
+    }
+}
+
+/// Delegated representative as encoded by the Cardano ledger
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum DRep {
+    KeyHash(Vec<u8>),
+    ScriptHash(Vec<u8>),
+    AlwaysAbstain,
+    AlwaysNoConfidence,
+}
+
+impl DRep {
+    /// Compute the identifier of the DRep, following the CIP-0105 bech32 prefixes
+    fn to_drep_id(&self) -> StdResult<String> {
+        match self {
+            DRep::KeyHash(hash) => encode_bech32("drep", hash),
+            DRep::ScriptHash(hash) => encode_bech32("drep_script", hash),
+            DRep::AlwaysAbstain => Ok("drep_always_abstain".to_string()),
+            DRep::AlwaysNoConfidence => Ok("drep_always_no_confidence".to_string()),
+        }
+    }
+}
+
+impl Encode<()> for DRep {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut (),
+    ) -> Result<(), encode::Error<W::Error>> {
+        match self {
+            DRep::KeyHash(hash) => e.array(2)?.u8(0)?.bytes(hash)?,
+            DRep::ScriptHash(hash) => e.array(2)?.u8(1)?.bytes(hash)?,
+            DRep::AlwaysAbstain => e.array(1)?.u8(2)?,
+            DRep::AlwaysNoConfidence => e.array(1)?.u8(3)?,
+        };
+
+        Ok(())
+    }
+}
+
+impl<'b> Decode<'b, ()> for DRep {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut ()) -> Result<Self, decode::Error> {
+        d.array()?;
+        match d.u8()? {
+            0 => Ok(DRep::KeyHash(d.bytes()?.to_vec())),
+            1 => Ok(DRep::ScriptHash(d.bytes()?.to_vec())),
+            2 => Ok(DRep::AlwaysAbstain),
+            3 => Ok(DRep::AlwaysNoConfidence),
+            _ => Err(decode::Error::message("invalid DRep tag")),
+        }
+    }
+}
+
+/// Result of the [DRepStakeDistributionQuery], wrapped in the era match result
+#[derive(Debug, Clone, PartialEq)]
+struct DRepStakeDistributionQueryResult(Vec<(DRep, u64)>);
+
+impl Encode<()> for DRepStakeDistributionQueryResult {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        ctx: &mut (),
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.array(1)?;
+        e.map(self.0.len() as u64)?;
+        for (drep, stake) in &self.0 {
+            drep.encode(e, ctx)?;
+            e.u64(*stake)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'b> Decode<'b, ()> for DRepStakeDistributionQueryResult {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut ()) -> Result<Self, decode::Error> {
+        d.array()?;
+        let entries = d
+            .map_iter::<DRep, u64>()?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(entries))
+    }
+}
+
 /// A runner that uses Pallas library to interact with a Cardano node using N2C Ouroboros mini-protocols
 pub struct PallasChainObserver {
     socket: PathBuf,
@@ -230,6 +371,54 @@ impl PallasChainObserver {
         Ok(Some(stake_distribution))
     }
 
+    /// Fetches the current DRep stake distribution using the provided `statequery` client.
+    async fn do_drep_stake_distribution_state_query(
+        &self,
+        statequery: &mut Client,
+    ) -> StdResult<Option<DRepStakeDistributionQueryResult>> {
+        statequery
+            .acquire(None)
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to acquire statequery")?;
+
+        let era = self.do_get_current_era_state_query(statequery).await?;
+        if era < CONWAY_ERA {
+            return Ok(None);
+        }
+
+        let drep_stake_distribution = statequery
+            .query(DRepStakeDistributionQuery { era })
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to get DRep stake distribution")?;
+
+        Ok(Some(drep_stake_distribution))
+    }
+
+    /// Fetches the current DRep stake distribution using the provided `NodeClient`.
+    async fn get_drep_stake_distribution(
+        &self,
+        client: &mut NodeClient,
+    ) -> Result<Option<DRepStakeDistribution>, ChainObserverError> {
+        let statequery = client.statequery();
+
+        let Some(query_result) = self.do_drep_stake_distribution_state_query(statequery).await?
+        else {
+            return Ok(None);
+        };
+
+        let mut drep_stake_distribution = DRepStakeDistribution::new();
+        for (drep, stake) in query_result.0 {
+            let drep_id = drep
+                .to_drep_id()
+                .with_context(|| "PallasChainObserver failed to encode DRep id")?;
+            drep_stake_distribution.insert(drep_id, stake);
+        }
+
+        Ok(Some(drep_stake_distribution))
+    }
+
     /// # Calculate Current KES Period
     ///
     /// It calculates the current Key Evolving Signature (KES) period
@@ -364,6 +553,29 @@ impl PallasChainObserver {
         Ok(Some(current_kes_period))
     }
 
+    /// Fetches the genesis config through the local statequery to get the maximum number of
+    /// KES evolutions.
+    async fn get_max_kes_evolutions_from_genesis(
+        &self,
+        client: &mut NodeClient,
+    ) -> Result<Option<KESPeriod>, ChainObserverError> {
+        let statequery = client.statequery();
+
+        statequery
+            .acquire(None)
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to acquire statequery")?;
+
+        let genesis_config = self.do_get_genesis_config_state_query(statequery).await?;
+
+        let config = genesis_config
+            .first()
+            .with_context(|| "PallasChainObserver failed to extract the config")?;
+
+        Ok(Some(config.max_kes_evolutions as KESPeriod))
+    }
+
     /// Processes a state query with the `NodeClient`, releasing the state query.
     async fn process_statequery(&self, client: &mut NodeClient) -> StdResult<()> {
         let statequery = client.statequery();
@@ -457,6 +669,20 @@ impl ChainObserver for PallasChainObserver {
         Ok(stake_distribution)
     }
 
+    async fn get_current_drep_stake_distribution(
+        &self,
+    ) -> Result<Option<DRepStakeDistribution>, ChainObserverError> {
+        let mut client = self.get_client().await?;
+
+        let drep_stake_distribution = self.get_drep_stake_distribution(&mut client).await?;
+
+        self.post_process_statequery(&mut client).await?;
+
+        client.abort().await;
+
+        Ok(drep_stake_distribution)
+    }
+
     async fn get_current_kes_period(
         &self,
         _opcert: &OpCert,
@@ -471,6 +697,21 @@ impl ChainObserver for PallasChainObserver {
 
         Ok(current_kes_period)
     }
+
+    async fn get_max_kes_evolutions(
+        &self,
+        _opcert: &OpCert,
+    ) -> Result<Option<KESPeriod>, ChainObserverError> {
+        let mut client = self.get_client().await?;
+
+        let max_kes_evolutions = self.get_max_kes_evolutions_from_genesis(&mut client).await?;
+
+        self.post_process_statequery(&mut client).await?;
+
+        client.abort().await;
+
+        Ok(max_kes_evolutions)
+    }
 }
 
 #[cfg(test)]
@@ -609,13 +850,44 @@ mod tests {
         vec![genesis]
     }
 
+    fn get_fake_drep_stake_distribution() -> DRepStakeDistributionQueryResult {
+        DRepStakeDistributionQueryResult(vec![
+            (
+                DRep::KeyHash(
+                    hex::decode("00000036d515e12e18cd3c88c74f09a67984c2c279a5296aa96efe89")
+                        .unwrap(),
+                ),
+                300000000001,
+            ),
+            (
+                DRep::ScriptHash(
+                    hex::decode("000000f66e28b0f18aef20555f4c4954234e3270dfbbdcc13f54e799")
+                        .unwrap(),
+                ),
+                600000000001,
+            ),
+            (DRep::AlwaysAbstain, 1200000000001),
+            (DRep::AlwaysNoConfidence, 1300000000001),
+        ])
+    }
+
     /// pallas responses mock server.
-    async fn mock_server(server: &mut pallas_network::facades::NodeServer) -> AnyCbor {
-        let query: queries_v16::Request =
-            match server.statequery().recv_while_acquired().await.unwrap() {
-                ClientQueryRequest::Query(q) => q.into_decode().unwrap(),
-                x => panic!("unexpected message from client: {x:?}"),
-            };
+    async fn mock_server(
+        server: &mut pallas_network::facades::NodeServer,
+        current_era: u16,
+    ) -> AnyCbor {
+        let raw_query = match server.statequery().recv_while_acquired().await.unwrap() {
+            ClientQueryRequest::Query(q) => q,
+            x => panic!("unexpected message from client: {x:?}"),
+        };
+        if raw_query
+            .clone()
+            .into_decode::<DRepStakeDistributionQuery>()
+            .is_ok()
+        {
+            return AnyCbor::from_encode(get_fake_drep_stake_distribution());
+        }
+        let query: queries_v16::Request = raw_query.into_decode().unwrap();
 
         match query {
             Request::GetChainPoint => {
@@ -626,7 +898,7 @@ mod tests {
                 block_number: 52851885,
             }),
             Request::LedgerQuery(LedgerQuery::HardForkQuery(HardForkQuery::GetCurrentEra)) => {
-                AnyCbor::from_encode(4)
+                AnyCbor::from_encode(current_era)
             }
             Request::LedgerQuery(LedgerQuery::BlockQuery(_, BlockQuery::GetEpochNo)) => {
                 AnyCbor::from_encode([8])
@@ -655,6 +927,15 @@ mod tests {
     /// local state queries should be intercepted by the `mock_server`
     /// and avoid any panic errors.
     async fn setup_server(socket_path: PathBuf, intersections: u32) -> tokio::task::JoinHandle<()> {
+        setup_server_in_era(socket_path, intersections, 4).await
+    }
+
+    /// Sets up a mock server for related tests, answering the given era to current era queries.
+    async fn setup_server_in_era(
+        socket_path: PathBuf,
+        intersections: u32,
+        current_era: u16,
+    ) -> tokio::task::JoinHandle<()> {
         tokio::spawn({
             async move {
                 if socket_path.exists() {
@@ -670,7 +951,7 @@ mod tests {
                 server.statequery().send_acquired().await.unwrap();
 
                 for _ in 0..intersections {
-                    let result = mock_server(&mut server).await;
+                    let result = mock_server(&mut server, current_era).await;
                     server.statequery().send_result(result).await.unwrap();
                 }
             }
@@ -739,6 +1020,77 @@ mod tests {
         assert_eq!(expected_stake_distribution, computed_stake_distribution);
     }
 
+    #[tokio::test]
+    async fn get_current_drep_stake_distribution() {
+        let socket_path =
+            create_temp_dir("get_current_drep_stake_distribution").join("node.socket");
+        let server = setup_server_in_era(socket_path.clone(), 2, CONWAY_ERA).await;
+        let client = tokio::spawn(async move {
+            let observer =
+                PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+            observer.get_current_drep_stake_distribution().await.unwrap()
+        });
+
+        let (_, client_res) = tokio::join!(server, client);
+        let computed_drep_stake_distribution = client_res.unwrap().unwrap();
+
+        let expected_drep_stake_distribution = DRepStakeDistribution::from([
+            (
+                "drep1qqqqqdk4zhsjuxxd8jyvwncf5eucfskz0xjjj64fdmlgj9307yl".to_string(),
+                300000000001,
+            ),
+            (
+                "drep_script1qqqqpanw9zc0rzh0yp247nzf2s35uvnsm7aaesfl2nnejfm2x8r".to_string(),
+                600000000001,
+            ),
+            ("drep_always_abstain".to_string(), 1200000000001),
+            ("drep_always_no_confidence".to_string(), 1300000000001),
+        ]);
+        assert_eq!(
+            expected_drep_stake_distribution,
+            computed_drep_stake_distribution
+        );
+    }
+
+    #[tokio::test]
+    async fn get_current_drep_stake_distribution_returns_none_before_conway_era() {
+        let socket_path = create_temp_dir("get_current_drep_stake_distribution_before_conway")
+            .join("node.socket");
+        let server = setup_server_in_era(socket_path.clone(), 1, CONWAY_ERA - 1).await;
+        let client = tokio::spawn(async move {
+            let observer =
+                PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+            observer.get_current_drep_stake_distribution().await.unwrap()
+        });
+
+        let (_, client_res) = tokio::join!(server, client);
+        let computed_drep_stake_distribution = client_res.unwrap();
+
+        assert_eq!(None, computed_drep_stake_distribution);
+    }
+
+    #[test]
+    fn drep_stake_distribution_query_encoding_follows_pallas_block_query_encoding() {
+        let era = CONWAY_ERA;
+        let pallas_query = Request::LedgerQuery(LedgerQuery::BlockQuery(
+            era,
+            BlockQuery::GetConstitutionHash,
+        ));
+        let pallas_encoded_query = AnyCbor::from_encode(pallas_query).unwrap();
+        let encoded_query = AnyCbor::from_encode(DRepStakeDistributionQuery { era }).unwrap();
+
+        // Same envelope as the pallas queries, only the block query itself differs
+        let block_query_offset = pallas_encoded_query.len() - 2;
+        assert_eq!(
+            pallas_encoded_query[..block_query_offset],
+            encoded_query[..block_query_offset]
+        );
+        assert_eq!(
+            [0x82, 0x18, 26, 0x80],
+            encoded_query[block_query_offset..]
+        );
+    }
+
     #[tokio::test]
     async fn get_current_kes_period() {
         let socket_path = create_temp_dir("get_current_kes_period").join("node.socket");
@@ -763,6 +1115,30 @@ mod tests {
         assert_eq!(407, kes_period);
     }
 
+    #[tokio::test]
+    async fn get_max_kes_evolutions() {
+        let socket_path = create_temp_dir("get_max_kes_evolutions").join("node.socket");
+        let server = setup_server(socket_path.clone(), 2).await;
+        let client = tokio::spawn(async move {
+            let observer =
+                PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+
+            let keypair = ColdKeyGenerator::create_deterministic_keypair([0u8; 32]);
+            let mut dummy_key_buffer = [0u8; Sum6Kes::SIZE + 4];
+            let mut dummy_seed = [0u8; 32];
+            let (_, kes_verification_key) = Sum6Kes::keygen(&mut dummy_key_buffer, &mut dummy_seed);
+            let operational_certificate = OpCert::new(kes_verification_key, 0, 0, keypair);
+            observer
+                .get_max_kes_evolutions(&operational_certificate)
+                .await
+                .unwrap()
+        });
+
+        let (_, client_res) = tokio::join!(server, client);
+        let max_kes_evolutions = client_res.unwrap().unwrap();
+        assert_eq!(62, max_kes_evolutions);
+    }
+
     #[tokio::test]
     async fn calculate_kes_period() {
         let socket_path = create_temp_dir("get_current_kes_period").join("node.socket");
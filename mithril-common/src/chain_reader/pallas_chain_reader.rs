@@ -19,6 +19,7 @@ pub struct PallasChainReader {
     socket: PathBuf,
     network: CardanoNetwork,
     client: Option<NodeClient>,
+    is_extended_mode_enabled: bool,
     logger: Logger,
 }
 
@@ -29,10 +30,17 @@ impl PallasChainReader {
             socket: socket.to_owned(),
             network,
             client: None,
+            is_extended_mode_enabled: false,
             logger: logger.new_with_component_name::<Self>(),
         }
     }
 
+    /// Enable the extended mode: the contents of the transactions of the blocks are parsed.
+    pub fn with_extended_mode(mut self, is_extended_mode_enabled: bool) -> Self {
+        self.is_extended_mode_enabled = is_extended_mode_enabled;
+        self
+    }
+
     /// Creates and returns a new `NodeClient` connected to the specified socket.
     async fn new_client(&self) -> StdResult<NodeClient> {
         let magic = self.network.code();
@@ -81,7 +89,11 @@ impl PallasChainReader {
             NextResponse::RollForward(raw_block, _forward_tip) => {
                 let multi_era_block = MultiEraBlock::decode(&raw_block)
                     .with_context(|| "PallasChainReader failed to decode raw block")?;
-                let parsed_block = ScannedBlock::convert(multi_era_block);
+                let parsed_block = if self.is_extended_mode_enabled {
+                    ScannedBlock::convert_with_contents(multi_era_block)?
+                } else {
+                    ScannedBlock::convert(multi_era_block)
+                };
                 Ok(Some(ChainBlockNextAction::RollForward { parsed_block }))
             }
             NextResponse::RollBackward(rollback_point, _) => {
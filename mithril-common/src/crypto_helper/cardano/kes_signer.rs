@@ -0,0 +1,460 @@
+//! API for the KES signature of the Mithril verification keys.
+//!
+//! The KES secret key can be used by the signer process itself ([KesSignerFile]), or be kept in a
+//! separate signing process reached through a Unix socket ([KesSignerUnixSocket]). A reference
+//! implementation of such a signing process is provided by [KesSignerUnixSocketDaemon].
+
+use anyhow::{anyhow, Context};
+use kes_summed_ed25519::kes::{Sum6Kes, Sum6KesSig};
+use kes_summed_ed25519::traits::KesSk;
+use std::path::{Path, PathBuf};
+
+use crate::crypto_helper::cardano::{
+    KESPeriod, ProtocolInitializerErrorWrapper, SerDeShelleyFileFormat, Sum6KesBytes,
+};
+use crate::StdResult;
+
+#[cfg(unix)]
+pub use unix_socket::*;
+
+/// A KES signer signs messages with the KES secret key of a pool.
+#[cfg_attr(test, mockall::automock)]
+pub trait KesSigner: Send + Sync {
+    /// Sign a message with the KES secret key evolved to the given KES period.
+    fn sign(&self, message: &[u8], kes_period: KESPeriod) -> StdResult<Sum6KesSig>;
+}
+
+/// A KES signer that reads the KES secret key from a file.
+#[derive(Debug, Clone)]
+pub struct KesSignerFile {
+    kes_sk_path: PathBuf,
+}
+
+impl KesSignerFile {
+    /// KesSignerFile factory
+    pub fn new<P: AsRef<Path>>(kes_sk_path: P) -> Self {
+        Self {
+            kes_sk_path: kes_sk_path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl KesSigner for KesSignerFile {
+    fn sign(&self, message: &[u8], kes_period: KESPeriod) -> StdResult<Sum6KesSig> {
+        let mut kes_sk_bytes = Sum6KesBytes::from_file(&self.kes_sk_path)
+            .map_err(|e| anyhow!(e))
+            .with_context(|| "KesSignerFile can not read KES secret key from file")?;
+        let mut kes_sk = Sum6Kes::try_from(&mut kes_sk_bytes)
+            .map_err(|e| ProtocolInitializerErrorWrapper::ProtocolInitializer(anyhow!(e)))
+            .with_context(|| "KesSignerFile can not use KES secret key")?;
+        let kes_sk_period = kes_sk.get_period();
+        if kes_sk_period > kes_period {
+            return Err(anyhow!(ProtocolInitializerErrorWrapper::KesMismatch(
+                kes_sk_period,
+                kes_period,
+            )));
+        }
+
+        // We need to perform the evolutions
+        for period in kes_sk_period..kes_period {
+            kes_sk
+                .update()
+                .map_err(|_| ProtocolInitializerErrorWrapper::KesUpdate(period))?;
+        }
+
+        Ok(kes_sk.sign(message))
+    }
+}
+
+#[cfg(unix)]
+mod unix_socket {
+    use anyhow::{anyhow, Context};
+    use kes_summed_ed25519::kes::Sum6KesSig;
+    use serde::{Deserialize, Serialize};
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use mithril_stm::stm::StmVerificationKeyPoP;
+
+    use crate::crypto_helper::cardano::KESPeriod;
+    use crate::StdResult;
+
+    use super::KesSigner;
+
+    /// Default timeout of the exchanges with a remote KES signer
+    const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Size of a serialized Mithril verification key, the only message signed by the daemon
+    const VERIFICATION_KEY_SIZE: usize = 192;
+
+    /// Request sent to a remote KES signer, as a JSON line.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct KesSignRequest {
+        /// Hex encoded message to sign
+        pub message: String,
+
+        /// KES period of the signature
+        pub kes_period: KESPeriod,
+    }
+
+    /// Response of a remote KES signer, as a JSON line.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum KesSignResponse {
+        /// Hex encoded KES signature of the message
+        Signature(String),
+
+        /// The message could not be signed
+        Error(String),
+    }
+
+    /// A KES signer that delegates the signatures to a remote signing process listening on a
+    /// Unix socket.
+    #[derive(Debug, Clone)]
+    pub struct KesSignerUnixSocket {
+        socket_path: PathBuf,
+        timeout: Duration,
+    }
+
+    impl KesSignerUnixSocket {
+        /// KesSignerUnixSocket factory
+        pub fn new<P: AsRef<Path>>(socket_path: P) -> Self {
+            Self {
+                socket_path: socket_path.as_ref().to_path_buf(),
+                timeout: DEFAULT_TIMEOUT,
+            }
+        }
+
+        /// Set the timeout of the exchanges with the remote KES signer
+        pub fn with_timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = timeout;
+            self
+        }
+    }
+
+    impl KesSigner for KesSignerUnixSocket {
+        fn sign(&self, message: &[u8], kes_period: KESPeriod) -> StdResult<Sum6KesSig> {
+            let mut stream = UnixStream::connect(&self.socket_path).with_context(|| {
+                format!(
+                    "KesSignerUnixSocket can not connect to '{}'",
+                    self.socket_path.display()
+                )
+            })?;
+            stream.set_read_timeout(Some(self.timeout))?;
+            stream.set_write_timeout(Some(self.timeout))?;
+
+            let request = KesSignRequest {
+                message: hex::encode(message),
+                kes_period,
+            };
+            writeln!(stream, "{}", serde_json::to_string(&request)?)
+                .with_context(|| "KesSignerUnixSocket can not send the sign request")?;
+
+            let mut response_line = String::new();
+            BufReader::new(&stream)
+                .read_line(&mut response_line)
+                .with_context(|| "KesSignerUnixSocket can not read the sign response")?;
+            let response: KesSignResponse = serde_json::from_str(&response_line)
+                .with_context(|| "KesSignerUnixSocket can not decode the sign response")?;
+
+            match response {
+                KesSignResponse::Signature(signature) => {
+                    let signature_bytes = hex::decode(signature)
+                        .with_context(|| "KesSignerUnixSocket can not decode the KES signature")?;
+                    Sum6KesSig::from_bytes(&signature_bytes).map_err(|e| {
+                        anyhow!("KesSignerUnixSocket can not decode the KES signature: {e:?}")
+                    })
+                }
+                KesSignResponse::Error(message) => Err(anyhow!(
+                    "Remote KES signer failed to sign the message: {message}"
+                )),
+            }
+        }
+    }
+
+    /// Reference remote KES signer: answers the sign requests received on a Unix socket with a
+    /// KES signer, one JSON line per request and per response.
+    ///
+    /// Only Mithril verification keys are signed, at a KES period that is not older than the last
+    /// signed one.
+    pub struct KesSignerUnixSocketDaemon {
+        kes_signer: Arc<dyn KesSigner>,
+        last_signed_kes_period: Mutex<Option<KESPeriod>>,
+        timeout: Duration,
+    }
+
+    impl KesSignerUnixSocketDaemon {
+        /// KesSignerUnixSocketDaemon factory
+        pub fn new(kes_signer: Arc<dyn KesSigner>) -> Self {
+            Self {
+                kes_signer,
+                last_signed_kes_period: Mutex::new(None),
+                timeout: DEFAULT_TIMEOUT,
+            }
+        }
+
+        /// Set the timeout of the exchanges with the clients of the daemon
+        pub fn with_timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = timeout;
+            self
+        }
+
+        /// Answer the sign requests of the connections accepted by the listener, each connection
+        /// is handled concurrently and answers a single request.
+        pub fn serve(&self, listener: UnixListener) -> StdResult<()> {
+            std::thread::scope(|scope| {
+                for stream in listener.incoming() {
+                    let stream =
+                        stream.with_context(|| "KES signer daemon can not accept connection")?;
+                    scope.spawn(move || {
+                        // A failing connection must not stop the daemon
+                        let _ = self.handle_connection(stream);
+                    });
+                }
+
+                Ok(())
+            })
+        }
+
+        fn handle_connection(&self, stream: UnixStream) -> StdResult<()> {
+            stream.set_read_timeout(Some(self.timeout))?;
+            stream.set_write_timeout(Some(self.timeout))?;
+
+            let mut request_line = String::new();
+            BufReader::new(&stream).read_line(&mut request_line)?;
+            let response = self.handle_request(&request_line);
+            writeln!(&stream, "{}", serde_json::to_string(&response)?)?;
+
+            Ok(())
+        }
+
+        fn handle_request(&self, request_line: &str) -> KesSignResponse {
+            match self.sign(request_line) {
+                Ok(signature) => KesSignResponse::Signature(hex::encode(signature.to_bytes())),
+                Err(error) => KesSignResponse::Error(format!("{error:?}")),
+            }
+        }
+
+        fn sign(&self, request_line: &str) -> StdResult<Sum6KesSig> {
+            let request: KesSignRequest =
+                serde_json::from_str(request_line).with_context(|| "invalid sign request")?;
+            let message =
+                hex::decode(&request.message).with_context(|| "invalid message encoding")?;
+            if message.len() != VERIFICATION_KEY_SIZE {
+                return Err(anyhow!(
+                    "only Mithril verification keys can be signed, got a message of {} bytes",
+                    message.len()
+                ));
+            }
+            StmVerificationKeyPoP::from_bytes(&message)
+                .and_then(|verification_key| verification_key.check())
+                .map_err(|e| anyhow!("only Mithril verification keys can be signed: {e}"))?;
+
+            let mut last_signed_kes_period = self
+                .last_signed_kes_period
+                .lock()
+                .map_err(|_| anyhow!("KES signer daemon state is poisoned"))?;
+            if let Some(last_kes_period) = *last_signed_kes_period {
+                if request.kes_period < last_kes_period {
+                    return Err(anyhow!(
+                        "KES period {} is older than the last signed KES period {last_kes_period}",
+                        request.kes_period
+                    ));
+                }
+            }
+            let signature = self.kes_signer.sign(&message, request.kes_period)?;
+            *last_signed_kes_period = Some(request.kes_period);
+
+            Ok(signature)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kes_summed_ed25519::kes::Sum6Kes;
+    use kes_summed_ed25519::traits::{KesSig, KesSk};
+    use kes_summed_ed25519::PublicKey;
+
+    use crate::test_utils::TempDir;
+
+    use super::*;
+
+    fn setup_temp_directory(test_name: &str) -> PathBuf {
+        TempDir::create("mithril_cardano_kes_signer", test_name)
+    }
+
+    fn create_kes_secret_key_file(test_name: &str) -> (PathBuf, PublicKey) {
+        let mut dummy_buffer = [0u8; Sum6Kes::SIZE + 4];
+        let mut dummy_seed = [1u8; 32];
+        let (kes_secret_key, kes_verification_key) =
+            Sum6Kes::keygen(&mut dummy_buffer, &mut dummy_seed);
+        let mut kes_bytes = Sum6KesBytes([0u8; Sum6Kes::SIZE + 4]);
+        kes_bytes.0.copy_from_slice(&kes_secret_key.clone_sk());
+        let kes_secret_key_file = setup_temp_directory(test_name).join("kes.skey");
+        kes_bytes
+            .to_file(&kes_secret_key_file)
+            .expect("KES secret key file export should not fail");
+
+        (kes_secret_key_file, kes_verification_key)
+    }
+
+    #[test]
+    fn file_kes_signer_signs_with_the_evolved_kes_secret_key() {
+        let (kes_secret_key_file, kes_verification_key) =
+            create_kes_secret_key_file("file_kes_signer_signs_with_the_evolved_kes_secret_key");
+        let kes_signer = KesSignerFile::new(kes_secret_key_file);
+
+        let signature = kes_signer.sign(b"message", 3).unwrap();
+
+        signature
+            .verify(3, &kes_verification_key, b"message")
+            .expect("signature should be valid at the requested KES period");
+    }
+
+    #[test]
+    fn file_kes_signer_fails_with_an_unreadable_kes_secret_key() {
+        let kes_signer = KesSignerFile::new(
+            setup_temp_directory("file_kes_signer_fails_with_an_unreadable_kes_secret_key")
+                .join("missing.skey"),
+        );
+
+        kes_signer
+            .sign(b"message", 0)
+            .expect_err("signing without a KES secret key should fail");
+    }
+
+    #[cfg(unix)]
+    mod unix_socket {
+        use std::os::unix::net::{UnixListener, UnixStream};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        use crate::crypto_helper::ProtocolSignerVerificationKey;
+        use crate::test_utils::fake_keys;
+
+        use super::*;
+
+        fn start_daemon(test_name: &str, kes_signer: Arc<dyn KesSigner>) -> PathBuf {
+            let socket_path =
+                TempDir::create_with_short_path("mithril_cardano_kes_signer", test_name)
+                    .join("kes-signer.sock");
+            let listener = UnixListener::bind(&socket_path).unwrap();
+            let daemon = KesSignerUnixSocketDaemon::new(kes_signer);
+            std::thread::spawn(move || daemon.serve(listener));
+
+            socket_path
+        }
+
+        fn verification_key_bytes() -> Vec<u8> {
+            ProtocolSignerVerificationKey::from_json_hex(fake_keys::signer_verification_key()[0])
+                .unwrap()
+                .to_bytes()
+                .to_vec()
+        }
+
+        #[test]
+        fn unix_socket_kes_signer_signs_with_the_remote_kes_secret_key() {
+            let test_name = "unix_socket_kes_signer_signs_with_the_remote_kes_secret_key";
+            let (kes_secret_key_file, kes_verification_key) = create_kes_secret_key_file(test_name);
+            let socket_path =
+                start_daemon(test_name, Arc::new(KesSignerFile::new(kes_secret_key_file)));
+            let kes_signer = KesSignerUnixSocket::new(socket_path);
+            let message = verification_key_bytes();
+
+            let signature = kes_signer.sign(&message, 2).unwrap();
+
+            signature
+                .verify(2, &kes_verification_key, &message)
+                .expect("signature should be valid at the requested KES period");
+        }
+
+        #[test]
+        fn unix_socket_kes_signer_forwards_the_remote_signer_error() {
+            let mut remote_kes_signer = MockKesSigner::new();
+            remote_kes_signer
+                .expect_sign()
+                .returning(|_, _| Err(anyhow!("KES key unavailable")));
+            let socket_path = start_daemon(
+                "unix_socket_kes_signer_forwards_the_remote_signer_error",
+                Arc::new(remote_kes_signer),
+            );
+            let kes_signer = KesSignerUnixSocket::new(socket_path);
+
+            let error = kes_signer
+                .sign(&verification_key_bytes(), 0)
+                .expect_err("signing should fail when the remote signer fails");
+
+            assert!(
+                error.to_string().contains("KES key unavailable"),
+                "unexpected error: {error}"
+            );
+        }
+
+        #[test]
+        fn unix_socket_kes_signer_fails_without_remote_signer() {
+            let kes_signer = KesSignerUnixSocket::new(
+                setup_temp_directory("unix_socket_kes_signer_fails_without_remote_signer")
+                    .join("kes-signer.sock"),
+            );
+
+            kes_signer
+                .sign(&verification_key_bytes(), 0)
+                .expect_err("signing without a remote signer should fail");
+        }
+
+        #[test]
+        fn unix_socket_daemon_refuses_to_sign_a_message_that_is_not_a_verification_key() {
+            let mut remote_kes_signer = MockKesSigner::new();
+            remote_kes_signer.expect_sign().never();
+            let socket_path = start_daemon(
+                "unix_socket_daemon_refuses_to_sign_a_message_that_is_not_a_verification_key",
+                Arc::new(remote_kes_signer),
+            );
+            let kes_signer = KesSignerUnixSocket::new(socket_path);
+
+            kes_signer
+                .sign(b"message", 0)
+                .expect_err("a message that is not a verification key should be refused");
+            kes_signer
+                .sign(&[1u8; 192], 0)
+                .expect_err("an invalid verification key should be refused");
+        }
+
+        #[test]
+        fn unix_socket_daemon_refuses_to_sign_at_a_kes_period_older_than_the_last_signed_one() {
+            let test_name =
+                "unix_socket_daemon_refuses_to_sign_at_a_kes_period_older_than_the_last_signed_one";
+            let (kes_secret_key_file, _) = create_kes_secret_key_file(test_name);
+            let socket_path =
+                start_daemon(test_name, Arc::new(KesSignerFile::new(kes_secret_key_file)));
+            let kes_signer = KesSignerUnixSocket::new(socket_path);
+            let message = verification_key_bytes();
+
+            kes_signer.sign(&message, 3).unwrap();
+            kes_signer.sign(&message, 3).unwrap();
+            kes_signer
+                .sign(&message, 2)
+                .expect_err("a KES period older than the last signed one should be refused");
+            kes_signer.sign(&message, 4).unwrap();
+        }
+
+        #[test]
+        fn unix_socket_daemon_answers_while_a_client_stays_silent() {
+            let test_name = "unix_socket_daemon_answers_while_a_client_stays_silent";
+            let (kes_secret_key_file, _) = create_kes_secret_key_file(test_name);
+            let socket_path =
+                start_daemon(test_name, Arc::new(KesSignerFile::new(kes_secret_key_file)));
+            let _silent_client = UnixStream::connect(&socket_path).unwrap();
+            let kes_signer =
+                KesSignerUnixSocket::new(socket_path).with_timeout(Duration::from_secs(2));
+
+            kes_signer
+                .sign(&verification_key_bytes(), 0)
+                .expect("the daemon should answer while another client stays silent");
+        }
+    }
+}
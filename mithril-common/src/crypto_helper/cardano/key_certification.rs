@@ -5,7 +5,6 @@
 
 use crate::{
     crypto_helper::{
-        cardano::SerDeShelleyFileFormat,
         types::{
             ProtocolParameters, ProtocolPartyId, ProtocolSignerVerificationKey,
             ProtocolSignerVerificationKeySignature, ProtocolStakeDistribution,
@@ -19,14 +18,14 @@ use mithril_stm::key_reg::{ClosedKeyReg, KeyReg};
 use mithril_stm::stm::{Stake, StmInitializer, StmParameters, StmSigner, StmVerificationKeyPoP};
 use mithril_stm::RegisterError;
 
-use crate::crypto_helper::cardano::Sum6KesBytes;
-use anyhow::{anyhow, Context};
+use crate::crypto_helper::cardano::{KesSigner, KesSignerFile};
+use anyhow::Context;
 use blake2::{
     digest::{consts::U32, FixedOutput},
     Blake2b, Digest,
 };
-use kes_summed_ed25519::kes::{Sum6Kes, Sum6KesSig};
-use kes_summed_ed25519::traits::{KesSig, KesSk};
+use kes_summed_ed25519::kes::Sum6KesSig;
+use kes_summed_ed25519::traits::KesSig;
 use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -128,31 +127,37 @@ impl StmInitializerWrapper {
         stake: Stake,
         rng: &mut R,
     ) -> StdResult<Self> {
-        let stm_initializer = StmInitializer::setup(params, stake, rng);
-        let kes_signature = if let Some(kes_sk_path) = kes_sk_path {
-            let mut kes_sk_bytes = Sum6KesBytes::from_file(kes_sk_path)
-                .map_err(|e| anyhow!(e))
-                .with_context(|| "StmInitializerWrapper can not read KES secret key from file")?;
-            let mut kes_sk = Sum6Kes::try_from(&mut kes_sk_bytes)
-                .map_err(|e| ProtocolInitializerErrorWrapper::ProtocolInitializer(anyhow!(e)))
-                .with_context(|| "StmInitializerWrapper can not use KES secret key")?;
-            let kes_sk_period = kes_sk.get_period();
-            let provided_period = kes_period.unwrap_or_default();
-            if kes_sk_period > provided_period {
-                return Err(anyhow!(ProtocolInitializerErrorWrapper::KesMismatch(
-                    kes_sk_period,
-                    provided_period,
-                )));
-            }
+        let kes_signer = kes_sk_path.map(KesSignerFile::new);
 
-            // We need to perform the evolutions
-            for period in kes_sk_period..provided_period {
-                kes_sk
-                    .update()
-                    .map_err(|_| ProtocolInitializerErrorWrapper::KesUpdate(period))?;
-            }
+        Self::setup_with_kes_signer(
+            params,
+            kes_signer.as_ref().map(|signer| signer as &dyn KesSigner),
+            kes_period,
+            stake,
+            rng,
+        )
+    }
 
-            Some(kes_sk.sign(&stm_initializer.verification_key().to_bytes()))
+    /// Builds an `StmInitializer` that is ready to register with the key registration service.
+    /// This function generates the signing and verification key with a PoP, signs the verification
+    /// key with the provided KES signer, and initializes the structure.
+    pub fn setup_with_kes_signer<R: RngCore + CryptoRng>(
+        params: StmParameters,
+        kes_signer: Option<&dyn KesSigner>,
+        kes_period: Option<KESPeriod>,
+        stake: Stake,
+        rng: &mut R,
+    ) -> StdResult<Self> {
+        let stm_initializer = StmInitializer::setup(params, stake, rng);
+        let kes_signature = if let Some(kes_signer) = kes_signer {
+            Some(
+                kes_signer
+                    .sign(
+                        &stm_initializer.verification_key().to_bytes(),
+                        kes_period.unwrap_or_default(),
+                    )
+                    .with_context(|| "StmInitializerWrapper can not sign the verification key")?,
+            )
         } else {
             println!("WARNING: Non certified signer registration by providing only a Pool Id is decommissioned and must be used for tests only!");
             None
@@ -305,7 +310,13 @@ impl KeyRegWrapper {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::crypto_helper::{cardano::ColdKeyGenerator, OpCert};
+    use crate::crypto_helper::{
+        cardano::{ColdKeyGenerator, SerDeShelleyFileFormat, Sum6KesBytes},
+        OpCert,
+    };
+
+    use kes_summed_ed25519::kes::Sum6Kes;
+    use kes_summed_ed25519::traits::KesSk;
 
     use crate::test_utils::TempDir;
     use rand_chacha::ChaCha20Rng;
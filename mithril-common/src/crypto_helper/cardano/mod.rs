@@ -1,8 +1,10 @@
 mod codec;
+mod kes_signer;
 mod key_certification;
 mod opcert;
 
 pub use codec::*;
+pub use kes_signer::*;
 pub use key_certification::*;
 pub use opcert::*;
 
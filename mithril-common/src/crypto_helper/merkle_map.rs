@@ -64,6 +64,49 @@ impl<K: MKMapKey, V: MKMapValue<K>, S: MKTreeStorer> MKMap<K, V, S> {
         Ok(mk_map)
     }
 
+    /// MKMap factory from a Merkle tree that already contains the leaves of the first entries
+    ///
+    /// The first `merkle_tree.total_leaves()` sorted entries are expected to be already
+    /// appended to the Merkle tree (e.g. when it is restored from a persisted storer),
+    /// the remaining entries are inserted in the map.
+    pub fn new_from_tree<T: IntoIterator<Item = (K, V)>>(
+        entries: T,
+        merkle_tree: MKTree<S>,
+    ) -> StdResult<Self> {
+        let sorted_entries = BTreeMap::from_iter(entries);
+        let total_leaves = merkle_tree.total_leaves();
+        if total_leaves > sorted_entries.len() {
+            return Err(anyhow!(
+                "MKMap could not be created from a Merkle tree with more leaves ({total_leaves}) than entries ({})",
+                sorted_entries.len()
+            ));
+        }
+
+        let mut mk_map = Self {
+            inner_map_values: BTreeMap::default(),
+            inner_merkle_tree: merkle_tree,
+            provable_keys: BTreeSet::default(),
+        };
+        let mut sorted_entries = sorted_entries.into_iter();
+        for (key, value) in sorted_entries.by_ref().take(total_leaves) {
+            mk_map.update_provable_keys(&key, &value)?;
+            mk_map.inner_map_values.insert(key, value);
+        }
+        if let Some((key, value)) = mk_map.inner_map_values.last_key_value() {
+            let last_leaf = key.to_owned().into() + value.compute_root()?;
+            if !mk_map.inner_merkle_tree.contains(&last_leaf) {
+                return Err(anyhow!(
+                    "MKMap could not be created from a Merkle tree that does not match its entries"
+                ));
+            }
+        }
+        for (key, value) in sorted_entries {
+            mk_map.insert_unchecked(key, value)?;
+        }
+
+        Ok(mk_map)
+    }
+
     /// Insert a new key-value pair
     /// Important: keys must be inserted in order to guarantee
     /// that the same set of key/values results in the same computation for the root.
@@ -538,6 +581,54 @@ mod tests {
         assert_eq!(mk_map_full_root, mk_map_nodes_root);
     }
 
+    #[test]
+    fn test_mk_map_should_be_created_from_merkle_tree_containing_first_entries() {
+        let block_ranges = (0..10)
+            .map(|i| BlockRange::new(i * 3, i * 3 + 3))
+            .collect::<Vec<_>>();
+        let entries = into_mkmap_tree_node_entries(generate_merkle_trees_for_ranges(&block_ranges));
+        let mk_map_expected = MKMap::<_, _, MKTreeStoreInMemory>::new(&entries).unwrap();
+        let storer = MKTreeStoreInMemory::build().unwrap();
+        let mk_map_partial = MKMap::<_, _, MKTreeStoreInMemory>::new_from_tree(
+            entries[0..6].to_vec(),
+            MKTree::new_from_storer(storer.clone()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(6, mk_map_partial.len());
+
+        let mk_map = MKMap::<_, _, MKTreeStoreInMemory>::new_from_tree(
+            entries,
+            MKTree::new_from_storer(storer).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(mk_map_expected.len(), mk_map.len());
+        assert_eq!(
+            mk_map_expected.compute_root().unwrap(),
+            mk_map.compute_root().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_mk_map_should_not_be_created_from_merkle_tree_not_matching_entries() {
+        let block_ranges = (0..10)
+            .map(|i| BlockRange::new(i * 3, i * 3 + 3))
+            .collect::<Vec<_>>();
+        let entries = into_mkmap_tree_node_entries(generate_merkle_trees_for_ranges(&block_ranges));
+        let mk_tree = MKTree::<MKTreeStoreInMemory>::new(&["test-1", "test-2"]).unwrap();
+        assert!(
+            MKMap::<_, _, MKTreeStoreInMemory>::new_from_tree(entries.clone(), mk_tree).is_err(),
+            "MKMap creation should fail when the Merkle tree does not match entries"
+        );
+
+        let leaves = (0..20).map(|i| format!("test-{i}")).collect::<Vec<_>>();
+        let mk_tree = MKTree::<MKTreeStoreInMemory>::new(&leaves).unwrap();
+        assert!(
+            MKMap::<_, _, MKTreeStoreInMemory>::new_from_tree(entries, mk_tree).is_err(),
+            "MKMap creation should fail when the Merkle tree has more leaves than entries"
+        );
+    }
+
     #[test]
     fn test_mk_map_should_accept_replacement_with_same_root_value() {
         let entries = generate_merkle_trees_for_ranges(&[
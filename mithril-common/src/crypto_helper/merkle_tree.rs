@@ -1,8 +1,8 @@
 use anyhow::{anyhow, Context};
 use blake2::{Blake2s256, Digest};
 use ckb_merkle_mountain_range::{
-    Error as MMRError, MMRStoreReadOps, MMRStoreWriteOps, Merge, MerkleProof, Result as MMRResult,
-    MMR,
+    leaf_index_to_mmr_size, Error as MMRError, MMRStoreReadOps, MMRStoreWriteOps, Merge,
+    MerkleProof, Result as MMRResult, MMR,
 };
 use serde::{Deserialize, Serialize};
 use std::{
@@ -293,6 +293,12 @@ impl<S: MKTreeStorer> MKTreeStore<S> {
         let storer = Box::new(S::build()?);
         Ok(Self { storer })
     }
+
+    fn from_storer(storer: S) -> Self {
+        Self {
+            storer: Box::new(storer),
+        }
+    }
 }
 
 impl<S: MKTreeStorer> MMRStoreReadOps<Arc<MKTreeNode>> for MKTreeStore<S> {
@@ -327,6 +333,18 @@ impl<S: MKTreeStorer> MKTreeLeafIndexer for MKTreeStore<S> {
     fn leaves(&self) -> Vec<MKTreeNode> {
         self.storer.leaves()
     }
+
+    fn try_get_leaf_position(&self, leaf: &MKTreeNode) -> StdResult<Option<MKTreeLeafPosition>> {
+        self.storer.try_get_leaf_position(leaf)
+    }
+
+    fn try_total_leaves(&self) -> StdResult<usize> {
+        self.storer.try_total_leaves()
+    }
+
+    fn try_leaves(&self) -> StdResult<Vec<MKTreeNode>> {
+        self.storer.try_leaves()
+    }
 }
 
 /// The Merkle tree leaves indexer trait
@@ -347,6 +365,30 @@ pub trait MKTreeLeafIndexer {
     fn contains_leaf(&self, leaf: &MKTreeNode) -> bool {
         self.get_leaf_position(leaf).is_some()
     }
+
+    /// Get the position of the leaf in the Merkle tree, failing if the indexer can not be read
+    ///
+    /// Indexers backed by a fallible storage should override it, the default implementation
+    /// never fails.
+    fn try_get_leaf_position(&self, leaf: &MKTreeNode) -> StdResult<Option<MKTreeLeafPosition>> {
+        Ok(self.get_leaf_position(leaf))
+    }
+
+    /// Number of leaves in the Merkle tree, failing if the indexer can not be read
+    ///
+    /// Indexers backed by a fallible storage should override it, the default implementation
+    /// never fails.
+    fn try_total_leaves(&self) -> StdResult<usize> {
+        Ok(self.total_leaves())
+    }
+
+    /// List of leaves with their positions in the Merkle tree, failing if the indexer can not be read
+    ///
+    /// Indexers backed by a fallible storage should override it, the default implementation
+    /// never fails.
+    fn try_leaves(&self) -> StdResult<Vec<MKTreeNode>> {
+        Ok(self.leaves())
+    }
 }
 
 /// A Merkle tree
@@ -370,6 +412,30 @@ impl<S: MKTreeStorer> MKTree<S> {
         Ok(Self { inner_tree })
     }
 
+    /// MKTree factory from a storer that already contains the nodes of a Merkle tree
+    ///
+    /// Useful to reopen a Merkle tree persisted by a storer, without recomputing its nodes.
+    pub fn new_from_storer(storer: S) -> StdResult<Self> {
+        let total_leaves = storer
+            .try_total_leaves()
+            .with_context(|| "MKTree could not count the leaves of the storer")?
+            as u64;
+        let mmr_size = match total_leaves {
+            0 => 0,
+            _ => leaf_index_to_mmr_size(total_leaves - 1),
+        };
+        let mk_tree = Self {
+            inner_tree: MMR::<_, _, _>::new(mmr_size, MKTreeStore::<S>::from_storer(storer)),
+        };
+        if total_leaves > 0 {
+            mk_tree
+                .compute_root()
+                .with_context(|| "MKTree could not be restored from storer")?;
+        }
+
+        Ok(mk_tree)
+    }
+
     /// Append leaves to the Merkle tree
     pub fn append<T: Into<MKTreeNode> + Clone>(&mut self, leaves: &[T]) -> StdResult<()> {
         for leaf in leaves {
@@ -413,7 +479,7 @@ impl<S: MKTreeStorer> MKTree<S> {
         let inner_leaves = leaves
             .iter()
             .map(|leaf| {
-                if let Some(leaf_position) = self.inner_tree.store().get_leaf_position(leaf) {
+                if let Some(leaf_position) = self.inner_tree.store().try_get_leaf_position(leaf)? {
                     Ok((leaf_position, Arc::new(leaf.to_owned())))
                 } else {
                     Err(anyhow!("Leaf not found in the Merkle tree"))
@@ -452,6 +518,41 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn test_should_restore_merkle_tree_from_storer() {
+        let leaves = generate_leaves(10);
+        let storer = MKTreeStoreInMemory::build().unwrap();
+        let mut mktree = MKTree::new_from_storer(storer.clone()).unwrap();
+        mktree.append(&leaves[0..7]).unwrap();
+
+        let mut mktree_restored = MKTree::new_from_storer(storer).unwrap();
+        assert_eq!(
+            mktree.compute_root().unwrap(),
+            mktree_restored.compute_root().unwrap()
+        );
+
+        mktree_restored.append(&leaves[7..]).unwrap();
+        let mktree_expected = MKTree::<MKTreeStoreInMemory>::new(&leaves).unwrap();
+        assert_eq!(
+            mktree_expected.compute_root().unwrap(),
+            mktree_restored.compute_root().unwrap()
+        );
+        assert_eq!(leaves.len(), mktree_restored.total_leaves());
+        mktree_restored
+            .compute_proof(&leaves[3..9])
+            .unwrap()
+            .verify()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_should_restore_empty_merkle_tree_from_storer() {
+        let storer = MKTreeStoreInMemory::build().unwrap();
+        let mktree = MKTree::new_from_storer(storer).unwrap();
+
+        assert_eq!(0, mktree.total_leaves());
+    }
+
     #[test]
     fn test_golden_merkle_root() {
         let leaves = vec!["golden-1", "golden-2", "golden-3", "golden-4", "golden-5"];
@@ -18,8 +18,12 @@ cfg_random! {
 }
 
 pub use cardano::{
-    KESPeriod, OpCert, ProtocolInitializerErrorWrapper, ProtocolRegistrationErrorWrapper,
-    SerDeShelleyFileFormat, Sum6KesBytes,
+    KESPeriod, KesSigner, KesSignerFile, OpCert, ProtocolInitializerErrorWrapper,
+    ProtocolRegistrationErrorWrapper, SerDeShelleyFileFormat, Sum6KesBytes,
+};
+#[cfg(unix)]
+pub use cardano::{
+    KesSignRequest, KesSignResponse, KesSignerUnixSocket, KesSignerUnixSocketDaemon,
 };
 pub use codec::*;
 pub use era::{
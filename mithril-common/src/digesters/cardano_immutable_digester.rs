@@ -11,10 +11,15 @@ use sha2::{Digest, Sha256};
 use slog::{debug, info, warn, Logger};
 use std::{collections::BTreeMap, io, path::Path, sync::Arc};
 
-/// Result of a cache computation, contains the digest and the list of new entries to add
-/// to the [ImmutableFileDigestCacheProvider].
-type CacheComputationResult =
-    Result<([u8; 32], Vec<(ImmutableFileName, HexEncodedDigest)>), io::Error>;
+/// Result of a cache computation, contains the digest of each immutable file and the list of
+/// new entries to add to the [ImmutableFileDigestCacheProvider].
+type CacheComputationResult = Result<
+    (
+        BTreeMap<ImmutableFile, HexEncodedDigest>,
+        Vec<(ImmutableFileName, HexEncodedDigest)>,
+    ),
+    io::Error,
+>;
 
 /// A digester working directly on a Cardano DB immutables files
 pub struct CardanoImmutableDigester {
@@ -36,15 +41,14 @@ impl CardanoImmutableDigester {
             logger: logger.new_with_component_name::<Self>(),
         }
     }
-}
 
-#[async_trait]
-impl ImmutableDigester for CardanoImmutableDigester {
-    async fn compute_digest(
+    /// Compute the digest of each immutable file of the given directory, up to the immutable
+    /// file number of the given beacon.
+    pub async fn compute_immutable_files_digests(
         &self,
         dirpath: &Path,
         beacon: &CardanoDbBeacon,
-    ) -> Result<String, ImmutableDigesterError> {
+    ) -> Result<BTreeMap<ImmutableFile, HexEncodedDigest>, ImmutableDigesterError> {
         let up_to_file_number = beacon.immutable_file_number;
         let immutables = ImmutableFile::list_completed_in_dir(dirpath)?
             .into_iter()
@@ -65,7 +69,7 @@ impl ImmutableDigester for CardanoImmutableDigester {
                 })
             }
             Some(_) => {
-                info!(self.logger, "#compute_digest"; "beacon" => #?beacon, "nb_of_immutables" => immutables.len());
+                info!(self.logger, "#compute_immutable_files_digests"; "beacon" => #?beacon, "nb_of_immutables" => immutables.len());
 
                 let cached_values = match self.cache_provider.as_ref() {
                     None => BTreeMap::from_iter(immutables.into_iter().map(|i| (i, None))),
@@ -81,18 +85,14 @@ impl ImmutableDigester for CardanoImmutableDigester {
                     },
                 };
 
-                // digest is done in a separate thread because it is blocking the whole task
+                // digests are computed in a separate thread because it is blocking the whole task
                 let logger = self.logger.clone();
-                let thread_beacon = beacon.clone();
-                let (hash, new_cache_entries) =
+                let (digests, new_cache_entries) =
                     tokio::task::spawn_blocking(move || -> CacheComputationResult {
-                        compute_hash(logger, &thread_beacon, cached_values)
+                        compute_immutable_files_hashes(logger, cached_values)
                     })
                     .await
                     .map_err(|e| ImmutableDigesterError::DigestComputationError(e.into()))??;
-                let digest = hex::encode(hash);
-
-                debug!(self.logger, "#computed digest: {:?}", digest);
 
                 if let Some(cache_provider) = self.cache_provider.as_ref() {
                     if let Err(error) = cache_provider.store(new_cache_entries).await {
@@ -103,44 +103,74 @@ impl ImmutableDigester for CardanoImmutableDigester {
                     }
                 }
 
-                Ok(digest)
+                Ok(digests)
             }
         }
     }
+
+    /// Compute the digest of a Cardano DB from the digests of its immutable files, as computed
+    /// by [compute_immutable_files_digests][Self::compute_immutable_files_digests].
+    pub fn compute_digest_from_immutable_files_digests(
+        beacon: &CardanoDbBeacon,
+        immutable_files_digests: &BTreeMap<ImmutableFile, HexEncodedDigest>,
+    ) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(beacon.compute_hash().as_bytes());
+        for digest in immutable_files_digests.values() {
+            hasher.update(digest);
+        }
+
+        hex::encode(hasher.finalize())
+    }
 }
 
-fn compute_hash(
+#[async_trait]
+impl ImmutableDigester for CardanoImmutableDigester {
+    async fn compute_digest(
+        &self,
+        dirpath: &Path,
+        beacon: &CardanoDbBeacon,
+    ) -> Result<String, ImmutableDigesterError> {
+        let immutable_files_digests = self
+            .compute_immutable_files_digests(dirpath, beacon)
+            .await?;
+        let digest =
+            Self::compute_digest_from_immutable_files_digests(beacon, &immutable_files_digests);
+
+        debug!(self.logger, "#computed digest: {:?}", digest);
+
+        Ok(digest)
+    }
+}
+
+fn compute_immutable_files_hashes(
     logger: Logger,
-    beacon: &CardanoDbBeacon,
     entries: BTreeMap<ImmutableFile, Option<HexEncodedDigest>>,
 ) -> CacheComputationResult {
-    let mut hasher = Sha256::new();
+    let mut digests = BTreeMap::new();
     let mut new_cached_entries = Vec::new();
     let mut progress = Progress {
         index: 0,
         total: entries.len(),
     };
 
-    hasher.update(beacon.compute_hash().as_bytes());
-
-    for (ix, (entry, cache)) in entries.iter().enumerate() {
-        match cache {
+    for (ix, (entry, cache)) in entries.into_iter().enumerate() {
+        let digest = match cache {
             None => {
                 let data = hex::encode(entry.compute_raw_hash::<Sha256>()?);
-                hasher.update(&data);
-                new_cached_entries.push((entry.filename.clone(), data));
-            }
-            Some(digest) => {
-                hasher.update(digest);
+                new_cached_entries.push((entry.filename.clone(), data.clone()));
+                data
             }
+            Some(digest) => digest,
         };
+        digests.insert(entry, digest);
 
         if progress.report(ix) {
             info!(logger, "hashing: {}", &progress);
         }
     }
 
-    Ok((hasher.finalize().into(), new_cached_entries))
+    Ok((digests, new_cached_entries))
 }
 
 struct Progress {
@@ -486,4 +516,40 @@ mod tests {
             .await
             .expect("compute_digest must not fail even with cache read failure");
     }
+
+    #[tokio::test]
+    async fn compute_immutable_files_digests_return_the_digest_of_each_immutable_file() {
+        let immutable_db = db_builder(
+            "compute_immutable_files_digests_return_the_digest_of_each_immutable_file",
+        )
+        .with_immutables(&[1, 2])
+        .append_immutable_trio()
+        .build();
+        let digester = CardanoImmutableDigester::new(None, TestLogger::stdout());
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 2);
+
+        let digests = digester
+            .compute_immutable_files_digests(&immutable_db.dir, &beacon)
+            .await
+            .expect("compute_immutable_files_digests must not fail");
+
+        let expected: BTreeMap<_, _> = immutable_db
+            .immutables_files
+            .into_iter()
+            .map(|i| {
+                let digest = hex::encode(i.compute_raw_hash::<Sha256>().unwrap());
+                (i, digest)
+            })
+            .collect();
+        assert_eq!(expected, digests);
+        assert_eq!(
+            digester
+                .compute_digest(&immutable_db.dir, &beacon)
+                .await
+                .unwrap(),
+            CardanoImmutableDigester::compute_digest_from_immutable_files_digests(
+                &beacon, &digests
+            )
+        );
+    }
 }
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::signable_builder::Artifact;
+
+use super::{DRepStakeDistribution, Epoch};
+
+/// Cardano DRep Stake Distribution
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CardanoDRepStakeDistribution {
+    /// Unique hash of the Cardano DRep Stake Distribution
+    pub hash: String,
+
+    /// Epoch at which the Cardano DRep Stake Distribution is computed
+    pub epoch: Epoch,
+
+    /// DRepStakeDistribution represents the list of delegated representatives in the Cardano
+    /// governance with their associated voting power
+    pub drep_stake_distribution: DRepStakeDistribution,
+}
+
+impl CardanoDRepStakeDistribution {
+    /// Constructor
+    pub fn new(
+        epoch: Epoch,
+        drep_stake_distribution: DRepStakeDistribution,
+    ) -> CardanoDRepStakeDistribution {
+        let mut cardano_drep_stake_distribution = CardanoDRepStakeDistribution {
+            hash: "".to_string(),
+            epoch,
+            drep_stake_distribution,
+        };
+        cardano_drep_stake_distribution.hash = cardano_drep_stake_distribution.compute_hash();
+
+        cardano_drep_stake_distribution
+    }
+
+    /// Cardano DRep stake distribution hash computation
+    fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.epoch.to_be_bytes());
+        self.drep_stake_distribution.iter().for_each(|(k, v)| {
+            hasher.update(k.as_bytes());
+            hasher.update(v.to_be_bytes());
+        });
+
+        hex::encode(hasher.finalize())
+    }
+}
+
+#[typetag::serde]
+impl Artifact for CardanoDRepStakeDistribution {
+    fn get_id(&self) -> String {
+        self.hash.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drep_stake_distribution(entries: &[(&str, u64)]) -> DRepStakeDistribution {
+        entries
+            .iter()
+            .map(|(drep_id, stake)| (drep_id.to_string(), *stake))
+            .collect()
+    }
+
+    #[test]
+    fn compute_hash_returns_same_hash_with_same_cardano_drep_stake_distribution() {
+        let epoch = Epoch(1);
+
+        assert_eq!(
+            CardanoDRepStakeDistribution::new(
+                epoch,
+                drep_stake_distribution(&[("drep-1", 100), ("drep-2", 200)])
+            )
+            .compute_hash(),
+            CardanoDRepStakeDistribution::new(
+                epoch,
+                drep_stake_distribution(&[("drep-1", 100), ("drep-2", 200)])
+            )
+            .compute_hash()
+        );
+    }
+
+    #[test]
+    fn compute_hash_returns_same_hash_whatever_the_drep_stake_distribution_order() {
+        let epoch = Epoch(1);
+
+        assert_eq!(
+            CardanoDRepStakeDistribution::new(
+                epoch,
+                drep_stake_distribution(&[("drep-1", 100), ("drep-2", 200)])
+            )
+            .compute_hash(),
+            CardanoDRepStakeDistribution::new(
+                epoch,
+                drep_stake_distribution(&[("drep-2", 200), ("drep-1", 100)])
+            )
+            .compute_hash()
+        );
+    }
+
+    #[test]
+    fn compute_hash_returns_different_hash_with_different_epoch() {
+        assert_ne!(
+            CardanoDRepStakeDistribution::new(
+                Epoch(1),
+                drep_stake_distribution(&[("drep-1", 100)])
+            )
+            .compute_hash(),
+            CardanoDRepStakeDistribution::new(
+                Epoch(2),
+                drep_stake_distribution(&[("drep-1", 100)])
+            )
+            .compute_hash()
+        );
+    }
+
+    #[test]
+    fn compute_hash_returns_different_hash_with_different_drep_stake_distribution() {
+        let epoch = Epoch(1);
+        let reference_hash =
+            CardanoDRepStakeDistribution::new(epoch, drep_stake_distribution(&[("drep-1", 100)]))
+                .compute_hash();
+
+        assert_ne!(
+            reference_hash,
+            CardanoDRepStakeDistribution::new(epoch, drep_stake_distribution(&[("drep-2", 100)]))
+                .compute_hash()
+        );
+        assert_ne!(
+            reference_hash,
+            CardanoDRepStakeDistribution::new(epoch, drep_stake_distribution(&[("drep-1", 150)]))
+                .compute_hash()
+        );
+        assert_ne!(
+            reference_hash,
+            CardanoDRepStakeDistribution::new(
+                epoch,
+                drep_stake_distribution(&[("drep-1", 100), ("drep-2", 150)])
+            )
+            .compute_hash()
+        );
+    }
+}
@@ -1,12 +1,14 @@
-use crate::crypto_helper::{MKMapProof, ProtocolMkProof};
-use crate::entities::TransactionHash;
+use anyhow::anyhow;
+
+use crate::crypto_helper::{MKMapProof, MKTreeNode, ProtocolMkProof};
+use crate::entities::{ExtendedCardanoTransaction, TransactionHash};
 use crate::messages::CardanoTransactionsSetProofMessagePart;
 use crate::{StdError, StdResult};
 
 use super::BlockRange;
 
 cfg_test_tools! {
-    use crate::crypto_helper::{MKMap, MKTree, MKTreeNode, MKMapNode, MKTreeStorer, MKTreeStoreInMemory};
+    use crate::crypto_helper::{MKMap, MKTree, MKMapNode, MKTreeStorer, MKTreeStoreInMemory};
     use crate::entities::BlockNumber;
     use std::collections::HashMap;
 }
@@ -17,6 +19,11 @@ pub struct CardanoTransactionsSetProof {
     /// Hashes of the certified transactions
     transactions_hashes: Vec<TransactionHash>,
 
+    /// Hashes of the contents of the certified transactions, in the same order as their hashes
+    ///
+    /// Only set when the proof is computed in the extended mode, empty otherwise.
+    transactions_contents_hashes: Vec<String>,
+
     /// Proof of the transactions
     transactions_proof: ProtocolMkProof,
 }
@@ -29,10 +36,21 @@ impl CardanoTransactionsSetProof {
     ) -> Self {
         Self {
             transactions_hashes,
+            transactions_contents_hashes: vec![],
             transactions_proof: ProtocolMkProof::new(transactions_proof.into()),
         }
     }
 
+    /// Set the hashes of the contents of the certified transactions, for a proof computed in
+    /// the extended mode
+    pub fn with_transactions_contents_hashes(
+        mut self,
+        transactions_contents_hashes: Vec<String>,
+    ) -> Self {
+        self.transactions_contents_hashes = transactions_contents_hashes;
+        self
+    }
+
     /// Return the hex encoded merkle root of this proof
     pub fn merkle_root(&self) -> String {
         self.transactions_proof.compute_root().to_hex()
@@ -43,16 +61,48 @@ impl CardanoTransactionsSetProof {
         &self.transactions_hashes
     }
 
+    /// Get the hashes of the contents of the transactions certified by this proof, empty if the
+    /// proof was not computed in the extended mode
+    pub fn transactions_contents_hashes(&self) -> &[String] {
+        &self.transactions_contents_hashes
+    }
+
     /// Verify that transactions set proof is valid
     pub fn verify(&self) -> StdResult<()> {
         self.transactions_proof.verify()?;
-        for hash in &self.transactions_hashes {
-            self.transactions_proof.contains(&hash.to_owned().into())?;
+        for leaf in self.transactions_leaves()? {
+            self.transactions_proof.contains(&leaf)?;
         }
 
         Ok(())
     }
 
+    fn transactions_leaves(&self) -> StdResult<Vec<MKTreeNode>> {
+        if self.transactions_contents_hashes.is_empty() {
+            return Ok(self
+                .transactions_hashes
+                .iter()
+                .map(|hash| hash.to_owned().into())
+                .collect());
+        }
+        if self.transactions_contents_hashes.len() != self.transactions_hashes.len() {
+            return Err(anyhow!(
+                "The proof must have one content hash per transaction hash, got {} contents hashes for {} transactions hashes",
+                self.transactions_contents_hashes.len(),
+                self.transactions_hashes.len()
+            ));
+        }
+
+        Ok(self
+            .transactions_hashes
+            .iter()
+            .zip(&self.transactions_contents_hashes)
+            .map(|(hash, content_hash)| {
+                ExtendedCardanoTransaction::compute_leaf(hash, content_hash)
+            })
+            .collect())
+    }
+
     cfg_test_tools! {
         /// Retrieve a dummy proof (for test only)
         pub fn dummy() -> Self {
@@ -110,6 +160,7 @@ impl TryFrom<CardanoTransactionsSetProof> for CardanoTransactionsSetProofMessage
     fn try_from(proof: CardanoTransactionsSetProof) -> Result<Self, Self::Error> {
         Ok(Self {
             transactions_hashes: proof.transactions_hashes,
+            transactions_contents_hashes: proof.transactions_contents_hashes,
             proof: proof.transactions_proof.to_json_hex()?,
         })
     }
@@ -121,6 +172,7 @@ impl TryFrom<CardanoTransactionsSetProofMessagePart> for CardanoTransactionsSetP
     fn try_from(proof: CardanoTransactionsSetProofMessagePart) -> Result<Self, Self::Error> {
         Ok(Self {
             transactions_hashes: proof.transactions_hashes,
+            transactions_contents_hashes: proof.transactions_contents_hashes,
             transactions_proof: ProtocolMkProof::from_json_hex(&proof.proof)?,
         })
     }
@@ -167,4 +219,60 @@ mod tests {
 
         proof.verify().expect_err("The proof should be invalid");
     }
+
+    fn extended_proof(
+        leaves: &[(BlockNumber, TransactionHash, String)],
+    ) -> CardanoTransactionsSetProof {
+        let extended_leaves: Vec<(BlockNumber, String)> = leaves
+            .iter()
+            .map(|(block_number, hash, content_hash)| {
+                (*block_number, format!("{hash}:{content_hash}"))
+            })
+            .collect();
+        let proof =
+            CardanoTransactionsSetProof::from_leaves::<MKTreeStoreInMemory>(&extended_leaves)
+                .unwrap();
+
+        CardanoTransactionsSetProof {
+            transactions_hashes: leaves.iter().map(|(_, hash, _)| hash.clone()).collect(),
+            ..proof
+        }
+        .with_transactions_contents_hashes(
+            leaves
+                .iter()
+                .map(|(_, _, content_hash)| content_hash.clone())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn should_verify_extended_proof_where_all_hashes_and_contents_are_contained_in_the_proof() {
+        let proof = extended_proof(&[
+            (BlockNumber(0), "tx-1".to_string(), "content-1".to_string()),
+            (BlockNumber(10), "tx-2".to_string(), "content-2".to_string()),
+        ]);
+
+        proof.verify().expect("The proof should be valid");
+    }
+
+    #[test]
+    fn shouldnt_verify_extended_proof_where_a_content_hash_is_tampered() {
+        let proof = extended_proof(&[
+            (BlockNumber(0), "tx-1".to_string(), "content-1".to_string()),
+            (BlockNumber(10), "tx-2".to_string(), "content-2".to_string()),
+        ]);
+
+        let tampered_proof = proof
+            .clone()
+            .with_transactions_contents_hashes(vec!["content-1".to_string(), "other".to_string()]);
+        tampered_proof
+            .verify()
+            .expect_err("The proof should be invalid");
+
+        let missing_content_proof =
+            proof.with_transactions_contents_hashes(vec!["content-1".to_string()]);
+        missing_content_proof
+            .verify()
+            .expect_err("The proof should be invalid");
+    }
 }
@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    crypto_helper::MKTreeNode,
+    entities::{CardanoTransaction, TransactionHash},
+};
+
+/// Reference to an output of a previous transaction spent by a transaction
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CardanoTransactionInput {
+    /// Hash of the transaction that produced the spent output
+    pub transaction_hash: TransactionHash,
+
+    /// Index of the spent output in the outputs of its transaction
+    pub output_index: u64,
+}
+
+/// Output produced by a transaction
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CardanoTransactionOutput {
+    /// Address of the output, bech32 encoded for Shelley addresses or base58 encoded for Byron ones
+    pub address: String,
+
+    /// Amount of lovelace held by the output
+    pub lovelace: u64,
+}
+
+/// Asset minted, or burned if its quantity is negative, by a transaction
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CardanoMintedAsset {
+    /// Hex encoded hash of the minting policy
+    pub policy_id: String,
+
+    /// Hex encoded name of the asset
+    pub asset_name: String,
+
+    /// Minted quantity, negative if the asset is burned
+    pub quantity: i64,
+}
+
+/// Contents of a Cardano transaction kept in the extended mode
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CardanoTransactionContent {
+    /// Outputs spent by the transaction
+    pub inputs: Vec<CardanoTransactionInput>,
+
+    /// Outputs produced by the transaction
+    pub outputs: Vec<CardanoTransactionOutput>,
+
+    /// Assets minted or burned by the transaction
+    pub minted_assets: Vec<CardanoMintedAsset>,
+
+    /// Labels of the metadata attached to the transaction
+    pub metadata_labels: Vec<u64>,
+}
+
+impl CardanoTransactionContent {
+    /// Compute the hash of the content.
+    ///
+    /// Every list and variable length field is prefixed by its length so two different contents
+    /// can't be hashed from the same sequence of bytes.
+    pub fn compute_hash(&self) -> String {
+        fn update_with_str(hasher: &mut Sha256, value: &str) {
+            hasher.update((value.len() as u64).to_be_bytes());
+            hasher.update(value.as_bytes());
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update((self.inputs.len() as u64).to_be_bytes());
+        for input in &self.inputs {
+            update_with_str(&mut hasher, &input.transaction_hash);
+            hasher.update(input.output_index.to_be_bytes());
+        }
+        hasher.update((self.outputs.len() as u64).to_be_bytes());
+        for output in &self.outputs {
+            update_with_str(&mut hasher, &output.address);
+            hasher.update(output.lovelace.to_be_bytes());
+        }
+        hasher.update((self.minted_assets.len() as u64).to_be_bytes());
+        for minted_asset in &self.minted_assets {
+            update_with_str(&mut hasher, &minted_asset.policy_id);
+            update_with_str(&mut hasher, &minted_asset.asset_name);
+            hasher.update(minted_asset.quantity.to_be_bytes());
+        }
+        hasher.update((self.metadata_labels.len() as u64).to_be_bytes());
+        for metadata_label in &self.metadata_labels {
+            hasher.update(metadata_label.to_be_bytes());
+        }
+
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// Cardano transaction with its contents, only stored by the nodes that enable the extended mode
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExtendedCardanoTransaction {
+    /// The transaction
+    pub transaction: CardanoTransaction,
+
+    /// Contents of the transaction
+    pub content: CardanoTransactionContent,
+}
+
+impl ExtendedCardanoTransaction {
+    /// ExtendedCardanoTransaction factory
+    pub fn new(transaction: CardanoTransaction, content: CardanoTransactionContent) -> Self {
+        Self {
+            transaction,
+            content,
+        }
+    }
+
+    /// Compute the Merkle tree leaf of a transaction in the extended mode from its hash and the
+    /// [hash of its content][CardanoTransactionContent::compute_hash]
+    pub fn compute_leaf(transaction_hash: &str, content_hash: &str) -> MKTreeNode {
+        MKTreeNode::new(format!("{transaction_hash}:{content_hash}").into_bytes())
+    }
+}
+
+impl From<ExtendedCardanoTransaction> for CardanoTransaction {
+    fn from(other: ExtendedCardanoTransaction) -> Self {
+        other.transaction
+    }
+}
+
+impl From<ExtendedCardanoTransaction> for MKTreeNode {
+    fn from(other: ExtendedCardanoTransaction) -> Self {
+        (&other).into()
+    }
+}
+
+/// The leaf of an extended transaction commits to its contents: `<transaction_hash>:<content_hash>`.
+///
+/// Note: the nodes that enable the extended mode compute their block range roots with these
+/// leaves, so it must be enabled on all the nodes of a network or on none of them.
+impl From<&ExtendedCardanoTransaction> for MKTreeNode {
+    fn from(other: &ExtendedCardanoTransaction) -> Self {
+        ExtendedCardanoTransaction::compute_leaf(
+            &other.transaction.transaction_hash,
+            &other.content.compute_hash(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::{BlockNumber, SlotNumber};
+
+    use super::*;
+
+    fn dummy_content() -> CardanoTransactionContent {
+        CardanoTransactionContent {
+            inputs: vec![CardanoTransactionInput {
+                transaction_hash: "tx-hash-000".to_string(),
+                output_index: 1,
+            }],
+            outputs: vec![CardanoTransactionOutput {
+                address: "addr_test1".to_string(),
+                lovelace: 1_000_000,
+            }],
+            minted_assets: vec![CardanoMintedAsset {
+                policy_id: "a0b1".to_string(),
+                asset_name: "c2d3".to_string(),
+                quantity: 10,
+            }],
+            metadata_labels: vec![674],
+        }
+    }
+
+    #[test]
+    fn content_hash_changes_when_any_field_changes() {
+        let content = dummy_content();
+        let hash = content.compute_hash();
+
+        let mut modified_content = content.clone();
+        modified_content.inputs[0].output_index = 2;
+        assert_ne!(hash, modified_content.compute_hash());
+
+        let mut modified_content = content.clone();
+        modified_content.outputs[0].lovelace = 2_000_000;
+        assert_ne!(hash, modified_content.compute_hash());
+
+        let mut modified_content = content.clone();
+        modified_content.minted_assets[0].quantity = -10;
+        assert_ne!(hash, modified_content.compute_hash());
+
+        let mut modified_content = content.clone();
+        modified_content.metadata_labels.push(721);
+        assert_ne!(hash, modified_content.compute_hash());
+
+        assert_eq!(hash, content.compute_hash());
+    }
+
+    #[test]
+    fn content_hash_is_not_ambiguous_when_strings_are_split_differently() {
+        let mut content = dummy_content();
+        content.minted_assets[0].policy_id = "a0b1c2".to_string();
+        content.minted_assets[0].asset_name = "d3".to_string();
+
+        assert_ne!(dummy_content().compute_hash(), content.compute_hash());
+    }
+
+    #[test]
+    fn extended_transaction_leaf_commits_to_the_transaction_hash_and_its_content() {
+        let transaction =
+            CardanoTransaction::new("tx-hash-123", BlockNumber(10), SlotNumber(4), "block_hash");
+        let content = dummy_content();
+        let extended_transaction =
+            ExtendedCardanoTransaction::new(transaction.clone(), content.clone());
+
+        let computed_mktree_node: MKTreeNode = extended_transaction.into();
+
+        assert_eq!(
+            MKTreeNode::new(format!("tx-hash-123:{}", content.compute_hash()).into_bytes()),
+            computed_mktree_node
+        );
+        assert_ne!(MKTreeNode::from(transaction), computed_mktree_node);
+    }
+}
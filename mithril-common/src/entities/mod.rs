@@ -5,6 +5,7 @@ mod block_number;
 mod block_range;
 mod cardano_chain_point;
 mod cardano_db_beacon;
+mod cardano_drep_stake_distribution;
 mod cardano_network;
 mod cardano_stake_distribution;
 mod cardano_transaction;
@@ -14,6 +15,7 @@ mod certificate;
 mod certificate_metadata;
 mod certificate_pending;
 mod epoch;
+mod extended_cardano_transaction;
 mod http_server_error;
 mod mithril_stake_distribution;
 mod protocol_message;
@@ -32,6 +34,7 @@ pub use block_number::BlockNumber;
 pub use block_range::{BlockRange, BlockRangeLength, BlockRangesSequence};
 pub use cardano_chain_point::{BlockHash, ChainPoint};
 pub use cardano_db_beacon::CardanoDbBeacon;
+pub use cardano_drep_stake_distribution::CardanoDRepStakeDistribution;
 pub use cardano_network::CardanoNetwork;
 pub use cardano_stake_distribution::CardanoStakeDistribution;
 pub use cardano_transaction::{CardanoTransaction, TransactionHash};
@@ -41,6 +44,10 @@ pub use certificate::{Certificate, CertificateSignature};
 pub use certificate_metadata::{CertificateMetadata, StakeDistributionParty};
 pub use certificate_pending::CertificatePending;
 pub use epoch::{Epoch, EpochError};
+pub use extended_cardano_transaction::{
+    CardanoMintedAsset, CardanoTransactionContent, CardanoTransactionInput,
+    CardanoTransactionOutput, ExtendedCardanoTransaction,
+};
 pub use http_server_error::{ClientError, ServerError};
 pub use mithril_stake_distribution::MithrilStakeDistribution;
 pub use protocol_message::{ProtocolMessage, ProtocolMessagePartKey, ProtocolMessagePartValue};
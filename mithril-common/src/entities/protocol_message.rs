@@ -46,6 +46,14 @@ pub enum ProtocolMessagePartKey {
     /// The ProtocolMessage part key associated to the Cardano stake distribution Merkle root
     #[serde(rename = "cardano_stake_distribution_merkle_root")]
     CardanoStakeDistributionMerkleRoot,
+
+    /// The ProtocolMessage part key associated to the epoch for which the Cardano DRep stake distribution is computed
+    #[serde(rename = "cardano_drep_stake_distribution_epoch")]
+    CardanoDRepStakeDistributionEpoch,
+
+    /// The ProtocolMessage part key associated to the Cardano DRep stake distribution Merkle root
+    #[serde(rename = "cardano_drep_stake_distribution_merkle_root")]
+    CardanoDRepStakeDistributionMerkleRoot,
 }
 
 impl Display for ProtocolMessagePartKey {
@@ -61,6 +69,12 @@ impl Display for ProtocolMessagePartKey {
             Self::CardanoStakeDistributionMerkleRoot => {
                 write!(f, "cardano_stake_distribution_merkle_root")
             }
+            Self::CardanoDRepStakeDistributionEpoch => {
+                write!(f, "cardano_drep_stake_distribution_epoch")
+            }
+            Self::CardanoDRepStakeDistributionMerkleRoot => {
+                write!(f, "cardano_drep_stake_distribution_merkle_root")
+            }
         }
     }
 }
@@ -193,6 +207,34 @@ mod tests {
         assert_ne!(hash_expected, protocol_message_modified.compute_hash());
     }
 
+    #[test]
+    fn test_protocol_message_compute_hash_include_cardano_drep_stake_distribution_epoch() {
+        let protocol_message = build_protocol_message_reference();
+        let hash_expected = protocol_message.compute_hash();
+
+        let mut protocol_message_modified = protocol_message.clone();
+        protocol_message_modified.set_message_part(
+            ProtocolMessagePartKey::CardanoDRepStakeDistributionEpoch,
+            "cardano-drep-stake-distribution-epoch-456".to_string(),
+        );
+
+        assert_ne!(hash_expected, protocol_message_modified.compute_hash());
+    }
+
+    #[test]
+    fn test_protocol_message_compute_hash_include_cardano_drep_stake_distribution_merkle_root() {
+        let protocol_message = build_protocol_message_reference();
+        let hash_expected = protocol_message.compute_hash();
+
+        let mut protocol_message_modified = protocol_message.clone();
+        protocol_message_modified.set_message_part(
+            ProtocolMessagePartKey::CardanoDRepStakeDistributionMerkleRoot,
+            "cardano-drep-stake-distribution-merkle-root-456".to_string(),
+        );
+
+        assert_ne!(hash_expected, protocol_message_modified.compute_hash());
+    }
+
     #[test]
     fn test_protocol_message_compute_hash_include_lastest_immutable_file_number() {
         let protocol_message = build_protocol_message_reference();
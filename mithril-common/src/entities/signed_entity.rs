@@ -7,7 +7,7 @@ use crate::signable_builder::Artifact;
 #[cfg(any(test, feature = "test_tools"))]
 use crate::test_utils::fake_data;
 
-use super::CardanoStakeDistribution;
+use super::{CardanoDRepStakeDistribution, CardanoStakeDistribution};
 #[cfg(any(test, feature = "test_tools"))]
 use super::{CardanoDbBeacon, Epoch};
 
@@ -101,3 +101,20 @@ impl SignedEntity<CardanoStakeDistribution> {
         }
     }
 }
+
+impl SignedEntity<CardanoDRepStakeDistribution> {
+    cfg_test_tools! {
+        /// Create a dummy [SignedEntity] for [CardanoDRepStakeDistribution] entity
+        pub fn dummy() -> Self {
+            SignedEntity {
+                signed_entity_id: "cardano-drep-stake-distribution-id-123".to_string(),
+                signed_entity_type: SignedEntityType::CardanoDRepStakeDistribution(Epoch(1)),
+                certificate_id: "certificate-hash-123".to_string(),
+                artifact: fake_data::cardano_drep_stake_distributions(1)[0].to_owned(),
+                created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
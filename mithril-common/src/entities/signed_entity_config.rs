@@ -79,6 +79,9 @@ impl SignedEntityConfig {
                         .compute_block_number_to_be_signed(time_point.chain_point.block_number),
                 )
             }
+            SignedEntityTypeDiscriminants::CardanoDRepStakeDistribution => {
+                SignedEntityType::CardanoDRepStakeDistribution(time_point.epoch)
+            }
         };
 
         Ok(signed_entity_type)
@@ -235,6 +238,16 @@ mod tests {
                 )
                 .unwrap()
         );
+
+        assert_eq!(
+            SignedEntityType::CardanoDRepStakeDistribution(Epoch(1)),
+            config
+                .time_point_to_signed_entity(
+                    SignedEntityTypeDiscriminants::CardanoDRepStakeDistribution,
+                    &time_point
+                )
+                .unwrap()
+        );
     }
 
     #[test]
@@ -24,6 +24,9 @@ const ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL: usize = 2;
 /// Database representation of the SignedEntityType::CardanoTransactions value
 const ENTITY_TYPE_CARDANO_TRANSACTIONS: usize = 3;
 
+/// Database representation of the SignedEntityType::CardanoDRepStakeDistribution value
+const ENTITY_TYPE_CARDANO_DREP_STAKE_DISTRIBUTION: usize = 4;
+
 /// The signed entity type that represents a type of data signed by the Mithril
 /// protocol Note: Each variant of this enum must be associated to an entry in
 /// the `signed_entity_type` table of the signer/aggregator nodes. The variant
@@ -55,6 +58,9 @@ pub enum SignedEntityType {
 
     /// Cardano Transactions
     CardanoTransactions(Epoch, BlockNumber),
+
+    /// Cardano DRep Stake Distribution
+    CardanoDRepStakeDistribution(Epoch),
 }
 
 impl SignedEntityType {
@@ -74,7 +80,8 @@ impl SignedEntityType {
             Self::CardanoImmutableFilesFull(b) => b.epoch,
             Self::CardanoStakeDistribution(e)
             | Self::MithrilStakeDistribution(e)
-            | Self::CardanoTransactions(e, _) => *e,
+            | Self::CardanoTransactions(e, _)
+            | Self::CardanoDRepStakeDistribution(e) => *e,
         }
     }
 
@@ -83,7 +90,9 @@ impl SignedEntityType {
         match self {
             Self::CardanoImmutableFilesFull(beacon) => beacon.epoch,
             Self::CardanoStakeDistribution(epoch) => epoch.next(),
-            Self::MithrilStakeDistribution(epoch) | Self::CardanoTransactions(epoch, _) => *epoch,
+            Self::MithrilStakeDistribution(epoch)
+            | Self::CardanoTransactions(epoch, _)
+            | Self::CardanoDRepStakeDistribution(epoch) => *epoch,
         }
     }
 
@@ -94,6 +103,7 @@ impl SignedEntityType {
             Self::CardanoStakeDistribution(_) => ENTITY_TYPE_CARDANO_STAKE_DISTRIBUTION,
             Self::CardanoImmutableFilesFull(_) => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions(_, _) => ENTITY_TYPE_CARDANO_TRANSACTIONS,
+            Self::CardanoDRepStakeDistribution(_) => ENTITY_TYPE_CARDANO_DREP_STAKE_DISTRIBUTION,
         }
     }
 
@@ -101,9 +111,9 @@ impl SignedEntityType {
     pub fn get_json_beacon(&self) -> StdResult<String> {
         let value = match self {
             Self::CardanoImmutableFilesFull(value) => serde_json::to_string(value)?,
-            Self::CardanoStakeDistribution(value) | Self::MithrilStakeDistribution(value) => {
-                serde_json::to_string(value)?
-            }
+            Self::CardanoStakeDistribution(value)
+            | Self::MithrilStakeDistribution(value)
+            | Self::CardanoDRepStakeDistribution(value) => serde_json::to_string(value)?,
             Self::CardanoTransactions(epoch, block_number) => {
                 let json = serde_json::json!({
                     "epoch": epoch,
@@ -120,7 +130,9 @@ impl SignedEntityType {
     pub fn get_open_message_timeout(&self) -> Option<Duration> {
         match self {
             Self::MithrilStakeDistribution(_) | Self::CardanoImmutableFilesFull(_) => None,
-            Self::CardanoStakeDistribution(_) => Some(Duration::from_secs(600)),
+            Self::CardanoStakeDistribution(_) | Self::CardanoDRepStakeDistribution(_) => {
+                Some(Duration::from_secs(600))
+            }
             Self::CardanoTransactions(_, _) => Some(Duration::from_secs(1800)),
         }
     }
@@ -128,7 +140,8 @@ impl SignedEntityType {
     pub(crate) fn feed_hash(&self, hasher: &mut Sha256) {
         match self {
             SignedEntityType::MithrilStakeDistribution(epoch)
-            | SignedEntityType::CardanoStakeDistribution(epoch) => {
+            | SignedEntityType::CardanoStakeDistribution(epoch)
+            | SignedEntityType::CardanoDRepStakeDistribution(epoch) => {
                 hasher.update(&epoch.to_be_bytes())
             }
             SignedEntityType::CardanoImmutableFilesFull(db_beacon) => {
@@ -157,6 +170,7 @@ impl SignedEntityTypeDiscriminants {
             Self::CardanoStakeDistribution => ENTITY_TYPE_CARDANO_STAKE_DISTRIBUTION,
             Self::CardanoImmutableFilesFull => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions => ENTITY_TYPE_CARDANO_TRANSACTIONS,
+            Self::CardanoDRepStakeDistribution => ENTITY_TYPE_CARDANO_DREP_STAKE_DISTRIBUTION,
         }
     }
 
@@ -167,6 +181,7 @@ impl SignedEntityTypeDiscriminants {
             ENTITY_TYPE_CARDANO_STAKE_DISTRIBUTION => Ok(Self::CardanoStakeDistribution),
             ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL => Ok(Self::CardanoImmutableFilesFull),
             ENTITY_TYPE_CARDANO_TRANSACTIONS => Ok(Self::CardanoTransactions),
+            ENTITY_TYPE_CARDANO_DREP_STAKE_DISTRIBUTION => Ok(Self::CardanoDRepStakeDistribution),
             index => Err(anyhow!("Invalid entity_type_id {index}.")),
         }
     }
@@ -272,6 +287,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_epoch_when_signed_entity_type_is_signed_for_cardano_drep_stake_distribution_return_epoch_stored_in_signed_entity_type(
+    ) {
+        let signed_entity_type = SignedEntityType::CardanoDRepStakeDistribution(Epoch(3));
+        assert_eq!(
+            signed_entity_type.get_epoch_when_signed_entity_type_is_signed(),
+            Epoch(3)
+        );
+    }
+
     #[test]
     fn verify_signed_entity_type_properties_are_included_in_computed_hash() {
         fn hash(signed_entity_type: SignedEntityType) -> String {
@@ -292,6 +317,12 @@ mod tests {
             hash(SignedEntityType::CardanoStakeDistribution(Epoch(15)))
         );
 
+        let reference_hash = hash(SignedEntityType::CardanoDRepStakeDistribution(Epoch(5)));
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoDRepStakeDistribution(Epoch(15)))
+        );
+
         let reference_hash = hash(SignedEntityType::CardanoImmutableFilesFull(
             CardanoDbBeacon::new("network", 5, 100),
         ));
@@ -363,13 +394,20 @@ mod tests {
             .get_json_beacon()
             .unwrap();
         assert_same_json!("15", &msd_json);
+
+        let cardano_drep_stake_distribution_json =
+            SignedEntityType::CardanoDRepStakeDistribution(Epoch(45))
+                .get_json_beacon()
+                .unwrap();
+        assert_same_json!("45", &cardano_drep_stake_distribution_json);
     }
 
     // Expected ord:
-    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoTransactions
+    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoTransactions < CardanoDRepStakeDistribution
     #[test]
     fn ordering_discriminant() {
         let mut list = vec![
+            SignedEntityTypeDiscriminants::CardanoDRepStakeDistribution,
             SignedEntityTypeDiscriminants::CardanoStakeDistribution,
             SignedEntityTypeDiscriminants::CardanoTransactions,
             SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
@@ -384,6 +422,7 @@ mod tests {
                 SignedEntityTypeDiscriminants::CardanoStakeDistribution,
                 SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
                 SignedEntityTypeDiscriminants::CardanoTransactions,
+                SignedEntityTypeDiscriminants::CardanoDRepStakeDistribution,
             ]
         );
     }
@@ -458,6 +497,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_signed_entity_types_discriminants_with_cardano_drep_stake_distribution() {
+        let discriminants_str = "CardanoDRepStakeDistribution";
+        let discriminants = SignedEntityTypeDiscriminants::parse_list(discriminants_str).unwrap();
+
+        assert_eq!(
+            BTreeSet::from([SignedEntityTypeDiscriminants::CardanoDRepStakeDistribution]),
+            discriminants
+        );
+    }
+
     #[test]
     fn parse_signed_entity_types_discriminants_should_remove_duplicates() {
         let discriminants_str =
@@ -15,6 +15,13 @@ pub type Stake = u64;
 /// StakeDistribution represents the stakes of multiple participants in the Cardano chain
 pub type StakeDistribution = BTreeMap<PartyId, Stake>;
 
+/// DRepId represents a delegated representative in the Cardano governance
+pub type DRepId = String;
+
+/// DRepStakeDistribution represents the voting power delegated to multiple delegated representatives
+/// in the Cardano governance
+pub type DRepStakeDistribution = BTreeMap<DRepId, Stake>;
+
 /// LotteryIndex represents the index of a Mithril single signature lottery
 pub type LotteryIndex = u64;
 
@@ -54,6 +54,12 @@ pub struct AggregatorCapabilities {
 pub struct CardanoTransactionsProverCapabilities {
     /// Maximum number of hashes allowed for a single request
     pub max_hashes_allowed_by_request: usize,
+
+    /// Is the prover cache ready to compute proofs
+    ///
+    /// While it's not ready, the cache is being warmed up and no proofs can be computed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_cache_ready: Option<bool>,
 }
 
 #[cfg(test)]
@@ -73,7 +79,12 @@ mod tests {
     struct AggregatorCapabilitiesPrevious {
         pub signed_entity_types: BTreeSet<SignedEntityTypeDiscriminants>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub cardano_transactions_prover: Option<CardanoTransactionsProverCapabilities>,
+        pub cardano_transactions_prover: Option<CardanoTransactionsProverCapabilitiesPrevious>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct CardanoTransactionsProverCapabilitiesPrevious {
+        pub max_hashes_allowed_by_request: usize,
     }
 
     fn golden_message_previous() -> AggregatorFeaturesMessagePrevious {
@@ -84,7 +95,7 @@ mod tests {
                 signed_entity_types: BTreeSet::from([
                     SignedEntityTypeDiscriminants::CardanoTransactions,
                 ]),
-                cardano_transactions_prover: Some(CardanoTransactionsProverCapabilities {
+                cardano_transactions_prover: Some(CardanoTransactionsProverCapabilitiesPrevious {
                     max_hashes_allowed_by_request: 100,
                 }),
             },
@@ -101,6 +112,7 @@ mod tests {
                 ]),
                 cardano_transactions_prover: Some(CardanoTransactionsProverCapabilities {
                     max_hashes_allowed_by_request: 100,
+                    is_cache_ready: Some(true),
                 }),
                 cardano_transactions_signing_config: Some(CardanoTransactionsSigningConfig {
                     security_parameter: BlockNumber(70),
@@ -116,7 +128,8 @@ mod tests {
         "capabilities": {
             "signed_entity_types": ["CardanoTransactions"],
             "cardano_transactions_prover": {
-                "max_hashes_allowed_by_request": 100
+                "max_hashes_allowed_by_request": 100,
+                "is_cache_ready": true
             },
             "cardano_transactions_signing_config": {
                 "security_parameter": 70,
@@ -0,0 +1,82 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::DRepStakeDistribution;
+use crate::entities::Epoch;
+
+/// Message structure of a Cardano DRep Stake Distribution
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CardanoDRepStakeDistributionMessage {
+    /// Epoch at which the Cardano DRep stake distribution is computed by the Cardano node
+    pub epoch: Epoch,
+
+    /// Hash of the Cardano DRep Stake Distribution
+    pub hash: String,
+
+    /// Hash of the associated certificate
+    pub certificate_hash: String,
+
+    /// Represents the list of delegated representatives in the Cardano governance with their
+    /// associated voting power
+    pub drep_stake_distribution: DRepStakeDistribution,
+
+    /// DateTime of creation
+    pub created_at: DateTime<Utc>,
+}
+
+impl CardanoDRepStakeDistributionMessage {
+    cfg_test_tools! {
+        /// Return a dummy test entity (test-only).
+        pub fn dummy() -> Self {
+            Self {
+                epoch: Epoch(1),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                drep_stake_distribution: DRepStakeDistribution::from([
+                    ("drep-123".to_string(), 1000),
+                ]),
+                created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message() -> CardanoDRepStakeDistributionMessage {
+        CardanoDRepStakeDistributionMessage {
+            epoch: Epoch(1),
+            hash: "hash-123".to_string(),
+            certificate_hash: "cert-hash-123".to_string(),
+            drep_stake_distribution: DRepStakeDistribution::from([
+                ("drep-123".to_string(), 1000),
+                ("drep-456".to_string(), 2000),
+            ]),
+            created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+
+    // Test the backward compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"{
+            "epoch": 1,
+            "hash": "hash-123",
+            "certificate_hash": "cert-hash-123",
+            "drep_stake_distribution": { "drep-123": 1000, "drep-456": 2000 },
+            "created_at": "2024-07-29T16:15:05.618857482Z"
+        }"#;
+        let message: CardanoDRepStakeDistributionMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a CardanoDRepStakeDistributionMessage instance.",
+        );
+
+        assert_eq!(golden_message(), message);
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::Epoch;
+
+/// Message structure of a Cardano DRep Stake Distribution list
+pub type CardanoDRepStakeDistributionListMessage = Vec<CardanoDRepStakeDistributionListItemMessage>;
+
+/// Message structure of a Cardano DRep Stake Distribution list item
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CardanoDRepStakeDistributionListItemMessage {
+    /// Epoch at which the Cardano DRep stake distribution is computed by the Cardano node
+    pub epoch: Epoch,
+
+    /// Hash of the Cardano DRep Stake Distribution
+    pub hash: String,
+
+    /// Hash of the associated certificate
+    pub certificate_hash: String,
+
+    /// Date and time at which the Cardano DRep Stake Distribution was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl CardanoDRepStakeDistributionListItemMessage {
+    /// Return a dummy test entity (test-only).
+    pub fn dummy() -> Self {
+        Self {
+            epoch: Epoch(1),
+            hash: "hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message() -> CardanoDRepStakeDistributionListMessage {
+        vec![CardanoDRepStakeDistributionListItemMessage {
+            epoch: Epoch(1),
+            hash: "hash-123".to_string(),
+            certificate_hash: "cert-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2024-07-29T16:15:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }]
+    }
+
+    // Test the backward compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"[{
+            "epoch": 1,
+            "hash": "hash-123",
+            "certificate_hash": "cert-hash-123",
+            "created_at": "2024-07-29T16:15:05.618857482Z"
+        }]"#;
+        let message: CardanoDRepStakeDistributionListMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a CardanoDRepStakeDistributionListMessage instance.",
+        );
+
+        assert_eq!(golden_message(), message);
+    }
+}
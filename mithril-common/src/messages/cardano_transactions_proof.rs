@@ -191,6 +191,7 @@ mod tests {
             "whatever",
             vec![CardanoTransactionsSetProofMessagePart {
                 transactions_hashes: vec![],
+                transactions_contents_hashes: vec![],
                 proof: "invalid".to_string(),
             }],
             vec![],
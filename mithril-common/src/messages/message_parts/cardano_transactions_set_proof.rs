@@ -11,6 +11,12 @@ pub struct CardanoTransactionsSetProofMessagePart {
     /// Hashes of the certified transactions
     pub transactions_hashes: Vec<TransactionHash>,
 
+    /// Hashes of the contents of the certified transactions, in the same order as their hashes
+    ///
+    /// Only set when the proof is computed by an aggregator that enables the extended mode.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transactions_contents_hashes: Vec<String>,
+
     /// Proof of the transactions
     pub proof: HexEncodedKey,
 }
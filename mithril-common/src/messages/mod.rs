@@ -1,6 +1,8 @@
 //! Messages module
 //! This module aims at providing shared structures for API communications.
 mod aggregator_features;
+mod cardano_drep_stake_distribution;
+mod cardano_drep_stake_distribution_list;
 mod cardano_stake_distribution;
 mod cardano_stake_distribution_list;
 mod cardano_transaction_snapshot;
@@ -23,6 +25,10 @@ mod snapshot_list;
 pub use aggregator_features::{
     AggregatorCapabilities, AggregatorFeaturesMessage, CardanoTransactionsProverCapabilities,
 };
+pub use cardano_drep_stake_distribution::CardanoDRepStakeDistributionMessage;
+pub use cardano_drep_stake_distribution_list::{
+    CardanoDRepStakeDistributionListItemMessage, CardanoDRepStakeDistributionListMessage,
+};
 pub use cardano_stake_distribution::CardanoStakeDistributionMessage;
 pub use cardano_stake_distribution_list::{
     CardanoStakeDistributionListItemMessage, CardanoStakeDistributionListMessage,
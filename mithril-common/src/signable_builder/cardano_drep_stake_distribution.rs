@@ -0,0 +1,296 @@
+use anyhow::{anyhow, Context};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    chain_observer::ChainObserver,
+    crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory},
+    entities::{DRepStakeDistribution, Epoch, ProtocolMessage, ProtocolMessagePartKey},
+    signable_builder::SignableBuilder,
+    StdResult,
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+/// DRep Stake Distribution Retriever
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DRepStakeDistributionRetriever: Send + Sync {
+    /// Retrieve the [DRepStakeDistribution] for a given epoch
+    async fn retrieve(&self, epoch: Epoch) -> StdResult<Option<DRepStakeDistribution>>;
+}
+
+/// A [DRepStakeDistributionRetriever] that reads the DRep stake distribution from a [ChainObserver].
+///
+/// The Cardano node only exposes the DRep stake distribution of its current epoch, so no
+/// distribution is returned for any other epoch.
+pub struct ChainObserverDRepStakeDistributionRetriever {
+    chain_observer: Arc<dyn ChainObserver>,
+}
+
+impl ChainObserverDRepStakeDistributionRetriever {
+    /// Constructor
+    pub fn new(chain_observer: Arc<dyn ChainObserver>) -> Self {
+        Self { chain_observer }
+    }
+}
+
+#[async_trait]
+impl DRepStakeDistributionRetriever for ChainObserverDRepStakeDistributionRetriever {
+    async fn retrieve(&self, epoch: Epoch) -> StdResult<Option<DRepStakeDistribution>> {
+        let current_epoch = self
+            .chain_observer
+            .get_current_epoch()
+            .await
+            .with_context(|| "DRep stake distribution retriever can not get current epoch")?;
+        if current_epoch != Some(epoch) {
+            return Ok(None);
+        }
+
+        let drep_stake_distribution = self
+            .chain_observer
+            .get_current_drep_stake_distribution()
+            .await
+            .with_context(|| {
+                "DRep stake distribution retriever can not get current DRep stake distribution"
+            })?;
+
+        Ok(drep_stake_distribution)
+    }
+}
+
+struct DRepStakeDistributionEntry(String, u64);
+
+impl DRepStakeDistributionEntry {
+    pub fn new(drep_id: &str, stake: u64) -> Self {
+        Self(drep_id.to_string(), stake)
+    }
+}
+
+impl From<DRepStakeDistributionEntry> for MKTreeNode {
+    fn from(entry: DRepStakeDistributionEntry) -> Self {
+        MKTreeNode::new(format!("{}{}", entry.0, entry.1).into())
+    }
+}
+
+/// A [CardanoDRepStakeDistributionSignableBuilder] builder
+pub struct CardanoDRepStakeDistributionSignableBuilder {
+    drep_stake_distribution_retriever: Arc<dyn DRepStakeDistributionRetriever>,
+}
+
+impl CardanoDRepStakeDistributionSignableBuilder {
+    /// Constructor
+    pub fn new(drep_stake_distribution_retriever: Arc<dyn DRepStakeDistributionRetriever>) -> Self {
+        Self {
+            drep_stake_distribution_retriever,
+        }
+    }
+
+    /// Compute the Merkle tree of a given [DRepStakeDistribution]
+    pub fn compute_merkle_tree_from_drep_stake_distribution(
+        dreps_with_stake: DRepStakeDistribution,
+    ) -> StdResult<MKTree<MKTreeStoreInMemory>> {
+        let leaves: Vec<MKTreeNode> = dreps_with_stake
+            .iter()
+            .map(|(k, v)| DRepStakeDistributionEntry::new(k, *v).into())
+            .collect();
+
+        MKTree::new(&leaves)
+    }
+}
+
+#[async_trait]
+impl SignableBuilder<Epoch> for CardanoDRepStakeDistributionSignableBuilder {
+    async fn compute_protocol_message(&self, epoch: Epoch) -> StdResult<ProtocolMessage> {
+        let dreps_with_stake = self
+            .drep_stake_distribution_retriever
+            .retrieve(epoch)
+            .await?
+            .ok_or(anyhow!(
+                "CardanoDRepStakeDistributionSignableBuilder could not find the DRep stake distribution for epoch: '{epoch}'"
+            ))?;
+
+        let mk_tree = Self::compute_merkle_tree_from_drep_stake_distribution(dreps_with_stake)?;
+
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoDRepStakeDistributionEpoch,
+            epoch.to_string(),
+        );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoDRepStakeDistributionMerkleRoot,
+            mk_tree.compute_root()?.to_hex(),
+        );
+
+        Ok(protocol_message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use crate::chain_observer::{ChainObserverError, MockChainObserver};
+    use crate::entities::ProtocolMessagePartKey;
+
+    use super::*;
+
+    fn drep_stake_distribution(entries: &[(&str, u64)]) -> DRepStakeDistribution {
+        entries
+            .iter()
+            .map(|(drep_id, stake)| (drep_id.to_string(), *stake))
+            .collect()
+    }
+
+    fn is_merkle_tree_equals(
+        first_dreps_with_stake: DRepStakeDistribution,
+        second_dreps_with_stake: DRepStakeDistribution,
+    ) -> bool {
+        let first_merkle_tree =
+            CardanoDRepStakeDistributionSignableBuilder::compute_merkle_tree_from_drep_stake_distribution(
+                first_dreps_with_stake,
+            )
+            .unwrap();
+        let second_merkle_tree =
+            CardanoDRepStakeDistributionSignableBuilder::compute_merkle_tree_from_drep_stake_distribution(
+                second_dreps_with_stake,
+            )
+            .unwrap();
+
+        first_merkle_tree.compute_root().unwrap() == second_merkle_tree.compute_root().unwrap()
+    }
+
+    #[test]
+    fn compute_merkle_tree_equals() {
+        assert!(is_merkle_tree_equals(
+            drep_stake_distribution(&[("drep-123", 100)]),
+            drep_stake_distribution(&[("drep-123", 100)]),
+        ));
+
+        assert!(is_merkle_tree_equals(
+            drep_stake_distribution(&[("drep-123", 100), ("drep-456", 150)]),
+            drep_stake_distribution(&[("drep-456", 150), ("drep-123", 100)]),
+        ));
+    }
+
+    #[test]
+    fn compute_merkle_tree_not_equals() {
+        assert!(!is_merkle_tree_equals(
+            drep_stake_distribution(&[("drep-123", 100)]),
+            drep_stake_distribution(&[("drep-456", 100)]),
+        ));
+
+        assert!(!is_merkle_tree_equals(
+            drep_stake_distribution(&[("drep-123", 100)]),
+            drep_stake_distribution(&[("drep-123", 999)]),
+        ));
+    }
+
+    #[tokio::test]
+    async fn compute_protocol_message_returns_error_when_no_drep_stake_distribution_found() {
+        let mut drep_stake_distribution_retriever = MockDRepStakeDistributionRetriever::new();
+        drep_stake_distribution_retriever
+            .expect_retrieve()
+            .return_once(move |_| Ok(None));
+        let signable_builder = CardanoDRepStakeDistributionSignableBuilder::new(Arc::new(
+            drep_stake_distribution_retriever,
+        ));
+
+        signable_builder
+            .compute_protocol_message(Epoch(1))
+            .await
+            .expect_err("Should return an error when no DRep stake distribution found");
+    }
+
+    #[tokio::test]
+    async fn compute_protocol_message_returns_signable_and_retrieve_without_epoch_offset() {
+        let epoch = Epoch(4);
+        let dreps_with_stake = drep_stake_distribution(&[("drep-123", 100)]);
+        let dreps_with_stake_clone = dreps_with_stake.clone();
+
+        let mut drep_stake_distribution_retriever = MockDRepStakeDistributionRetriever::new();
+        drep_stake_distribution_retriever
+            .expect_retrieve()
+            .with(eq(epoch))
+            .return_once(move |_| Ok(Some(dreps_with_stake)));
+        let signable_builder = CardanoDRepStakeDistributionSignableBuilder::new(Arc::new(
+            drep_stake_distribution_retriever,
+        ));
+
+        let signable = signable_builder
+            .compute_protocol_message(epoch)
+            .await
+            .unwrap();
+
+        let expected_mktree =
+            CardanoDRepStakeDistributionSignableBuilder::compute_merkle_tree_from_drep_stake_distribution(
+                dreps_with_stake_clone,
+            )
+            .unwrap();
+        let mut signable_expected = ProtocolMessage::new();
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::CardanoDRepStakeDistributionEpoch,
+            epoch.to_string(),
+        );
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::CardanoDRepStakeDistributionMerkleRoot,
+            expected_mktree.compute_root().unwrap().to_hex(),
+        );
+        assert_eq!(signable_expected, signable);
+    }
+
+    #[tokio::test]
+    async fn chain_observer_retriever_returns_distribution_for_current_epoch() {
+        let expected_distribution = drep_stake_distribution(&[("drep-123", 100)]);
+        let mut chain_observer = MockChainObserver::new();
+        chain_observer
+            .expect_get_current_epoch()
+            .return_once(|| Ok(Some(Epoch(7))));
+        chain_observer
+            .expect_get_current_drep_stake_distribution()
+            .return_once({
+                let distribution = expected_distribution.clone();
+                move || Ok(Some(distribution))
+            });
+        let retriever = ChainObserverDRepStakeDistributionRetriever::new(Arc::new(chain_observer));
+
+        let drep_stake_distribution = retriever.retrieve(Epoch(7)).await.unwrap();
+
+        assert_eq!(Some(expected_distribution), drep_stake_distribution);
+    }
+
+    #[tokio::test]
+    async fn chain_observer_retriever_returns_none_for_another_epoch_than_the_current_one() {
+        let mut chain_observer = MockChainObserver::new();
+        chain_observer
+            .expect_get_current_epoch()
+            .return_once(|| Ok(Some(Epoch(8))));
+        chain_observer
+            .expect_get_current_drep_stake_distribution()
+            .never();
+        let retriever = ChainObserverDRepStakeDistributionRetriever::new(Arc::new(chain_observer));
+
+        let drep_stake_distribution = retriever.retrieve(Epoch(7)).await.unwrap();
+
+        assert_eq!(None, drep_stake_distribution);
+    }
+
+    #[tokio::test]
+    async fn chain_observer_retriever_fails_if_chain_observer_fails() {
+        let mut chain_observer = MockChainObserver::new();
+        chain_observer
+            .expect_get_current_epoch()
+            .return_once(|| Ok(Some(Epoch(7))));
+        chain_observer
+            .expect_get_current_drep_stake_distribution()
+            .return_once(|| Err(ChainObserverError::General(anyhow!("error"))));
+        let retriever = ChainObserverDRepStakeDistributionRetriever::new(Arc::new(chain_observer));
+
+        retriever
+            .retrieve(Epoch(7))
+            .await
+            .expect_err("Should fail when the chain observer fails");
+    }
+}
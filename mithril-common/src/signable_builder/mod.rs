@@ -1,10 +1,12 @@
 //! The module used for building signables
 
+mod cardano_drep_stake_distribution;
 mod cardano_stake_distribution;
 mod interface;
 mod mithril_stake_distribution;
 mod signable_builder_service;
 
+pub use cardano_drep_stake_distribution::*;
 pub use cardano_stake_distribution::*;
 pub use interface::*;
 pub use mithril_stake_distribution::*;
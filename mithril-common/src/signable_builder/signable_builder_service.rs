@@ -31,6 +31,7 @@ pub struct MithrilSignableBuilderService {
     immutable_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
     cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
     cardano_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
+    cardano_drep_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
 }
 
 impl MithrilSignableBuilderService {
@@ -42,6 +43,7 @@ impl MithrilSignableBuilderService {
         immutable_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
         cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
         cardano_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
+        cardano_drep_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
     ) -> Self {
         Self {
             era_checker,
@@ -50,6 +52,7 @@ impl MithrilSignableBuilderService {
             immutable_signable_builder,
             cardano_transactions_signable_builder,
             cardano_stake_distribution_builder,
+            cardano_drep_stake_distribution_builder,
         }
     }
 
@@ -84,6 +87,13 @@ impl MithrilSignableBuilderService {
                 .with_context(|| format!(
                     "Signable builder service can not compute protocol message with block_number: '{block_number}'"
                 ))?,
+            SignedEntityType::CardanoDRepStakeDistribution(e) => self
+                .cardano_drep_stake_distribution_builder
+                .compute_protocol_message(e)
+                .await
+                .with_context(|| format!(
+                    "Signable builder service can not compute protocol message for Cardano DRep stake distribution with epoch: '{e}'"
+                ))?,
         };
 
         Ok(protocol_message)
@@ -169,6 +179,7 @@ mod tests {
             MockSignableBuilderImpl<CardanoDbBeacon>,
         mock_cardano_transactions_signable_builder: MockSignableBuilderImpl<BlockNumber>,
         mock_cardano_stake_distribution_signable_builder: MockSignableBuilderImpl<Epoch>,
+        mock_cardano_drep_stake_distribution_signable_builder: MockSignableBuilderImpl<Epoch>,
     }
 
     impl MockDependencyInjector {
@@ -180,6 +191,8 @@ mod tests {
                 mock_cardano_immutable_files_full_signable_builder: MockSignableBuilderImpl::new(),
                 mock_cardano_stake_distribution_signable_builder: MockSignableBuilderImpl::new(),
                 mock_cardano_transactions_signable_builder: MockSignableBuilderImpl::new(),
+                mock_cardano_drep_stake_distribution_signable_builder: MockSignableBuilderImpl::new(
+                ),
             }
         }
 
@@ -191,6 +204,7 @@ mod tests {
                 Arc::new(self.mock_cardano_immutable_files_full_signable_builder),
                 Arc::new(self.mock_cardano_transactions_signable_builder),
                 Arc::new(self.mock_cardano_stake_distribution_signable_builder),
+                Arc::new(self.mock_cardano_drep_stake_distribution_signable_builder),
             )
         }
     }
@@ -294,6 +308,25 @@ mod tests {
                 .await
                 .unwrap();
         }
+
+        #[tokio::test]
+        async fn build_cardano_drep_stake_distribution_signable_when_given_cardano_drep_stake_distribution_entity_type(
+        ) {
+            let current_era = SupportedEra::Pythagoras;
+            let mut mock_container = build_mock_container(current_era);
+            mock_container
+                .mock_cardano_drep_stake_distribution_signable_builder
+                .expect_compute_protocol_message()
+                .once()
+                .return_once(|_| Ok(ProtocolMessage::new()));
+            let signable_builder_service = mock_container.build_signable_builder_service();
+            let signed_entity_type = SignedEntityType::CardanoDRepStakeDistribution(Epoch(5));
+
+            signable_builder_service
+                .compute_protocol_message(signed_entity_type)
+                .await
+                .unwrap();
+        }
     }
 
     mod thales_era {
@@ -385,5 +418,24 @@ mod tests {
                 .await
                 .unwrap();
         }
+
+        #[tokio::test]
+        async fn build_cardano_drep_stake_distribution_signable_when_given_cardano_drep_stake_distribution_entity_type(
+        ) {
+            let current_era = SupportedEra::Thales;
+            let mut mock_container = build_mock_container(current_era);
+            mock_container
+                .mock_cardano_drep_stake_distribution_signable_builder
+                .expect_compute_protocol_message()
+                .once()
+                .return_once(|_| Ok(ProtocolMessage::new()));
+            let signable_builder_service = mock_container.build_signable_builder_service();
+            let signed_entity_type = SignedEntityType::CardanoDRepStakeDistribution(Epoch(5));
+
+            signable_builder_service
+                .compute_protocol_message(signed_entity_type)
+                .await
+                .unwrap();
+        }
     }
 }
@@ -5,7 +5,8 @@ use semver::Version;
 
 use crate::crypto_helper::{self, ProtocolMultiSignature};
 use crate::entities::{
-    self, BlockNumber, CertificateMetadata, CertificateSignature, CompressionAlgorithm, Epoch,
+    self, BlockNumber, CertificateMetadata, CertificateSignature, CompressionAlgorithm,
+    DRepStakeDistribution, Epoch,
     LotteryIndex, ProtocolMessage, ProtocolMessagePartKey, SignedEntityType, SingleSignatures,
     SlotNumber, StakeDistribution, StakeDistributionParty,
 };
@@ -259,3 +260,28 @@ pub fn cardano_stake_distribution(epoch: Epoch) -> entities::CardanoStakeDistrib
         stake_distribution,
     }
 }
+
+/// Fake DRep Stake Distribution
+pub fn drep_stake_distribution(total: u64) -> DRepStakeDistribution {
+    (1..total + 1)
+        .map(|idx| (format!("drep-{idx}"), idx * 100))
+        .collect()
+}
+
+/// Fake Cardano DRep Stake Distributions
+pub fn cardano_drep_stake_distributions(
+    total: u64,
+) -> Vec<entities::CardanoDRepStakeDistribution> {
+    (1..total + 1)
+        .map(|epoch_idx| cardano_drep_stake_distribution(Epoch(epoch_idx)))
+        .collect::<Vec<entities::CardanoDRepStakeDistribution>>()
+}
+
+/// Fake Cardano DRep Stake Distribution
+pub fn cardano_drep_stake_distribution(epoch: Epoch) -> entities::CardanoDRepStakeDistribution {
+    entities::CardanoDRepStakeDistribution {
+        hash: format!("drep-hash-epoch-{epoch}"),
+        epoch,
+        drep_stake_distribution: drep_stake_distribution(1),
+    }
+}
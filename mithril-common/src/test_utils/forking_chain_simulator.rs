@@ -0,0 +1,214 @@
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+
+use crate::cardano_block_scanner::ScannedBlock;
+use crate::chain_reader::ChainBlockNextAction;
+use crate::entities::{BlockNumber, CardanoTransaction, SlotNumber};
+
+/// Simulate a chain that randomly forks, to generate the actions a [ChainBlockReader][crate::chain_reader::ChainBlockReader]
+/// would yield while following it.
+///
+/// The generated actions are deterministic for a given seed.
+pub struct ForkingChainSimulator {
+    rng: ChaCha20Rng,
+    max_rollback_depth: u64,
+    blocks: Vec<ScannedBlock>,
+    forks_count: u64,
+}
+
+impl ForkingChainSimulator {
+    /// Number of rolled forward blocks out of which one is preceded by a rollback
+    const ROLLBACK_FREQUENCY: u64 = 10;
+
+    /// Maximum number of transactions in a block
+    const MAX_TRANSACTIONS_PER_BLOCK: u64 = 3;
+
+    /// [ForkingChainSimulator] factory
+    pub fn new(seed: u64, max_rollback_depth: u64) -> Self {
+        Self {
+            rng: ChaCha20Rng::seed_from_u64(seed),
+            max_rollback_depth,
+            blocks: vec![],
+            forks_count: 0,
+        }
+    }
+
+    /// Block number of the tip of the chain
+    pub fn tip_block_number(&self) -> BlockNumber {
+        self.blocks
+            .last()
+            .map(|block| block.block_number)
+            .unwrap_or(BlockNumber(0))
+    }
+
+    /// Transactions of the blocks that are currently on the chain
+    pub fn transactions(&self) -> Vec<CardanoTransaction> {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.clone().into_transactions())
+            .collect()
+    }
+
+    /// Roll forward `number_of_blocks` blocks, with random rollbacks along the way, and return
+    /// the actions that a chain reader would yield while following the chain.
+    ///
+    /// The actions start with a rollback if the chain is not empty. The round ends with a tip
+    /// above the tip of the previous round and above all the blocks rolled forward during the
+    /// round, so it can be imported with the tip block number as upper bound.
+    pub fn next_round(&mut self, number_of_blocks: u64) -> Vec<ChainBlockNextAction> {
+        let mut highest_block_number = self.tip_block_number() + 1;
+        let mut actions = vec![];
+        if !self.blocks.is_empty() {
+            actions.push(self.roll_backward());
+        }
+
+        for _ in 0..number_of_blocks {
+            let rollback_draw = self.rng.next_u64() % Self::ROLLBACK_FREQUENCY;
+            if rollback_draw == 0 && !self.blocks.is_empty() {
+                actions.push(self.roll_backward());
+            }
+            actions.push(self.roll_forward());
+            highest_block_number = highest_block_number.max(self.tip_block_number());
+        }
+        while self.tip_block_number() < highest_block_number {
+            actions.push(self.roll_forward());
+        }
+
+        actions
+    }
+
+    fn roll_forward(&mut self) -> ChainBlockNextAction {
+        let block_number = self.tip_block_number() + 1;
+        // The first block always has a transaction, so the rollbacks can always be matched
+        // with a stored transaction
+        let number_of_transactions = match block_number {
+            BlockNumber(1) => 1,
+            _ => self.rng.next_u64() % (Self::MAX_TRANSACTIONS_PER_BLOCK + 1),
+        };
+        let block = ScannedBlock::new(
+            format!("block_hash-{block_number}-fork-{}", self.forks_count),
+            block_number,
+            SlotNumber(*block_number * 10),
+            (0..number_of_transactions)
+                .map(|index| format!("tx_hash-{block_number}-{index}-fork-{}", self.forks_count))
+                .collect::<Vec<_>>(),
+        );
+        self.blocks.push(block.clone());
+
+        ChainBlockNextAction::RollForward {
+            parsed_block: block,
+        }
+    }
+
+    fn roll_backward(&mut self) -> ChainBlockNextAction {
+        let depth = self.rng.next_u64() % (self.max_rollback_depth + 1);
+        // The first block is never rolled back
+        let kept_blocks = (self.blocks.len() as u64).saturating_sub(depth).max(1);
+        self.blocks.truncate(kept_blocks as usize);
+        self.forks_count += 1;
+
+        ChainBlockNextAction::RollBackward {
+            slot_number: self.blocks.last().unwrap().slot_number,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rolled_forward_blocks(actions: &[ChainBlockNextAction]) -> Vec<ScannedBlock> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                ChainBlockNextAction::RollForward { parsed_block } => Some(parsed_block.clone()),
+                ChainBlockNextAction::RollBackward { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn first_round_does_not_start_with_a_rollback_and_ends_at_the_tip() {
+        let mut simulator = ForkingChainSimulator::new(42, 20);
+
+        let actions = simulator.next_round(100);
+
+        assert!(matches!(
+            actions.first(),
+            Some(ChainBlockNextAction::RollForward { .. })
+        ));
+        let blocks = rolled_forward_blocks(&actions);
+        assert_eq!(
+            simulator.tip_block_number(),
+            blocks.iter().map(|block| block.block_number).max().unwrap()
+        );
+        assert_eq!(
+            Some(simulator.tip_block_number()),
+            blocks.last().map(|block| block.block_number)
+        );
+    }
+
+    #[test]
+    fn following_rounds_start_with_a_rollback() {
+        let mut simulator = ForkingChainSimulator::new(42, 20);
+        simulator.next_round(100);
+
+        let actions = simulator.next_round(100);
+
+        assert!(matches!(
+            actions.first(),
+            Some(ChainBlockNextAction::RollBackward { .. })
+        ));
+    }
+
+    #[test]
+    fn tip_is_above_the_tip_of_the_previous_round() {
+        let mut simulator = ForkingChainSimulator::new(42, 50);
+        let mut previous_tip_block_number = simulator.tip_block_number();
+
+        for _ in 0..10 {
+            let actions = simulator.next_round(20);
+
+            assert!(simulator.tip_block_number() > previous_tip_block_number);
+            assert_eq!(
+                Some(simulator.tip_block_number()),
+                rolled_forward_blocks(&actions)
+                    .last()
+                    .map(|block| block.block_number)
+            );
+            previous_tip_block_number = simulator.tip_block_number();
+        }
+    }
+
+    #[test]
+    fn transactions_are_the_ones_of_the_blocks_on_the_chain() {
+        let mut simulator = ForkingChainSimulator::new(42, 20);
+        let mut chain: Vec<ScannedBlock> = vec![];
+        for _ in 0..5 {
+            for action in simulator.next_round(50) {
+                match action {
+                    ChainBlockNextAction::RollForward { parsed_block } => chain.push(parsed_block),
+                    ChainBlockNextAction::RollBackward { slot_number } => {
+                        chain.retain(|block| block.slot_number <= slot_number)
+                    }
+                }
+            }
+        }
+
+        let expected_transactions: Vec<CardanoTransaction> = chain
+            .into_iter()
+            .flat_map(|block| block.into_transactions())
+            .collect();
+        assert_eq!(expected_transactions, simulator.transactions());
+        assert!(!expected_transactions.is_empty());
+    }
+
+    #[test]
+    fn actions_are_deterministic_for_a_given_seed() {
+        let mut simulator = ForkingChainSimulator::new(42, 20);
+        let mut same_seed_simulator = ForkingChainSimulator::new(42, 20);
+
+        assert_eq!(simulator.next_round(100), same_seed_simulator.next_round(100));
+        assert_eq!(simulator.next_round(100), same_seed_simulator.next_round(100));
+    }
+}
@@ -4,6 +4,7 @@
 //! * A Open Api Spec tester
 //! * Some precomputed fake data and keys
 //! * A builder of [MithrilFixture] to generate signers alongside a stake distribution
+//! * A [ForkingChainSimulator] to generate the actions of a chain that randomly forks
 //!
 
 #[cfg(feature = "apispec")]
@@ -15,6 +16,8 @@ pub mod fake_keys;
 
 mod cardano_transactions_builder;
 mod fixture_builder;
+#[cfg(feature = "fs")]
+mod forking_chain_simulator;
 mod mithril_fixture;
 
 mod temp_dir;
@@ -25,6 +28,8 @@ pub mod test_http_server;
 
 pub use cardano_transactions_builder::CardanoTransactionsBuilder;
 pub use fixture_builder::{MithrilFixtureBuilder, StakeDistributionGenerationMethod};
+#[cfg(feature = "fs")]
+pub use forking_chain_simulator::ForkingChainSimulator;
 pub use mithril_fixture::{MithrilFixture, SignerFixture};
 pub use temp_dir::*;
 #[cfg(test)]
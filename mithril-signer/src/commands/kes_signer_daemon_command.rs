@@ -0,0 +1,58 @@
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::UnixListener;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use slog_scope::info;
+
+use mithril_common::crypto_helper::{
+    KesSignerFile, KesSignerUnixSocketDaemon, SerDeShelleyFileFormat, Sum6KesBytes,
+};
+use mithril_common::StdResult;
+
+/// Run a reference remote KES signer.
+///
+/// It holds the KES secret key of a pool and signs the requests of the signers configured with
+/// its socket as `kes_signer_socket_path`, so the KES secret key is kept out of the signer
+/// process. Intended for testing the remote signing setup.
+#[derive(Parser, Debug, Clone)]
+pub struct KesSignerDaemonCommand {
+    /// File path to the KES secret key of the pool
+    #[clap(long)]
+    kes_secret_key_path: PathBuf,
+
+    /// File path of the Unix socket to listen on
+    #[clap(long)]
+    socket_path: PathBuf,
+}
+
+impl KesSignerDaemonCommand {
+    /// Execute the KES signer daemon command
+    pub async fn execute(&self) -> StdResult<()> {
+        Sum6KesBytes::from_file(&self.kes_secret_key_path)
+            .map_err(|e| anyhow!(e))
+            .with_context(|| {
+                format!(
+                    "KES signer daemon can not read the KES secret key from '{}'",
+                    self.kes_secret_key_path.display()
+                )
+            })?;
+        let listener = UnixListener::bind(&self.socket_path).with_context(|| {
+            format!(
+                "KES signer daemon can not listen on '{}'",
+                self.socket_path.display()
+            )
+        })?;
+        // Only the owner of the daemon is allowed to request signatures
+        fs::set_permissions(&self.socket_path, fs::Permissions::from_mode(0o600))?;
+
+        info!("KES signer daemon listening"; "socket_path" => self.socket_path.display());
+        let daemon =
+            KesSignerUnixSocketDaemon::new(Arc::new(KesSignerFile::new(&self.kes_secret_key_path)));
+
+        tokio::task::spawn_blocking(move || daemon.serve(listener)).await?
+    }
+}
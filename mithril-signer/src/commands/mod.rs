@@ -0,0 +1,7 @@
+//! Commands of the signer that are not part of its nominal run.
+
+mod kes_signer_daemon_command;
+mod tools_command;
+
+pub use kes_signer_daemon_command::*;
+pub use tools_command::*;
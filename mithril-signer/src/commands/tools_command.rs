@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use clap::{Parser, Subcommand};
+use slog_scope::debug;
+
+use mithril_common::StdResult;
+use mithril_persistence::database::repository::CardanoTransactionRepository;
+use mithril_persistence::database::CardanoTransactionsIntegrityChecker;
+use mithril_persistence::sqlite::DatabaseConnectionPool;
+
+use crate::dependency_injection::DependenciesBuilder;
+use crate::{Configuration, SQLITE_FILE_CARDANO_TRANSACTION};
+
+/// List of tools to upkeep the signer
+#[derive(Parser, Debug, Clone)]
+pub struct ToolsCommand {
+    /// commands
+    #[clap(subcommand)]
+    pub tools_subcommand: ToolsSubCommand,
+}
+
+impl ToolsCommand {
+    /// Execute the tools command
+    pub async fn execute(&self, config: &Configuration) -> StdResult<()> {
+        self.tools_subcommand.execute(config).await
+    }
+}
+
+/// Tools subcommands.
+#[derive(Debug, Clone, Subcommand)]
+pub enum ToolsSubCommand {
+    /// Check that the block range roots stored in the Cardano transactions database are the ones
+    /// computed from the stored transactions.
+    ///
+    /// Reports the roots that don't match their transactions, the block ranges with transactions
+    /// but without a root (gaps) and the roots without transactions (orphans).
+    CheckCardanoTransactions(CheckCardanoTransactionsCommand),
+}
+
+impl ToolsSubCommand {
+    /// Execute the tools subcommand
+    pub async fn execute(&self, config: &Configuration) -> StdResult<()> {
+        match self {
+            Self::CheckCardanoTransactions(cmd) => cmd.execute(config).await,
+        }
+    }
+}
+
+/// Check Cardano transactions command.
+#[derive(Parser, Debug, Clone)]
+pub struct CheckCardanoTransactionsCommand {
+    /// Replace the inconsistent block range roots by the ones computed from the stored
+    /// transactions.
+    ///
+    /// Since it will modify the Cardano transactions database it's strongly recommended to
+    /// backup it before running this command.
+    #[clap(long)]
+    repair: bool,
+}
+
+impl CheckCardanoTransactionsCommand {
+    /// Execute the check Cardano transactions command
+    pub async fn execute(&self, config: &Configuration) -> StdResult<()> {
+        debug!("CHECK CARDANO TRANSACTIONS command"; "config" => format!("{config:?}"));
+        println!("Checking the Cardano transactions block range roots");
+        let connection = DependenciesBuilder::new(config)
+            .build_sqlite_connection(
+                SQLITE_FILE_CARDANO_TRANSACTION,
+                mithril_persistence::database::cardano_transaction_migration::get_migrations(),
+            )
+            .await?;
+        let transaction_repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            DatabaseConnectionPool::build_from_connection(connection),
+        )));
+        let checker = CardanoTransactionsIntegrityChecker::new(transaction_repository)
+            .with_number_of_blocks_to_keep(
+                config
+                    .enable_transaction_pruning
+                    .then_some(config.network_security_parameter),
+            )
+            .with_extended_mode(config.enable_cardano_transactions_extended_mode)
+            .with_logger(slog_scope::logger());
+
+        let report = checker
+            .check()
+            .await
+            .with_context(|| "check-cardano-transactions: integrity check error")?;
+        println!("{report}");
+
+        if report.is_valid() {
+            println!("The Cardano transactions block range roots are consistent");
+        } else if self.repair {
+            checker
+                .repair(&report)
+                .await
+                .with_context(|| "check-cardano-transactions: repair error")?;
+            println!("The Cardano transactions block range roots have been repaired");
+        } else {
+            return Err(anyhow!(
+                "The Cardano transactions block range roots are inconsistent, run the command with `--repair` to fix them"
+            ));
+        }
+
+        Ok(())
+    }
+}
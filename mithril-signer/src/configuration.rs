@@ -5,8 +5,9 @@ use serde::{Deserialize, Serialize};
 use std::{path::PathBuf, sync::Arc};
 
 use mithril_common::{
+    cardano_block_scanner::BlockScannerType,
     chain_observer::ChainObserver,
-    crypto_helper::tests_setup,
+    crypto_helper::{tests_setup, KESPeriod},
     entities::{BlockNumber, PartyId},
     era::{
         adapters::{EraReaderAdapterBuilder, EraReaderAdapterType},
@@ -76,6 +77,32 @@ pub struct Configuration {
     /// File path to the operational certificate of the pool
     pub operational_certificate_path: Option<PathBuf>,
 
+    /// File path to the Unix socket of a remote KES signer holding the KES secret key of the
+    /// pool, used instead of [kes_secret_key_path][Self::kes_secret_key_path] if set.
+    #[example = "`/run/mithril/kes-signer.sock`"]
+    pub kes_signer_socket_path: Option<PathBuf>,
+
+    /// Other pools operated by this signer, in addition to the one configured with
+    /// [party_id][Self::party_id], [kes_secret_key_path][Self::kes_secret_key_path] and
+    /// [operational_certificate_path][Self::operational_certificate_path].
+    ///
+    /// The chain observer, the imported transactions and the immutables digests are shared by all
+    /// the pools, each pool registers and signs with its own keys.
+    #[example = "`[{ \"operational_certificate_path\": \"/pool2/opcert.cert\", \"kes_secret_key_path\": \"/pool2/kes.sk\" }]`"]
+    #[serde(default)]
+    pub additional_pools: Vec<PoolConfiguration>,
+
+    /// Number of KES periods before the expiry of the KES key of a pool from which the signer
+    /// warns that the key should be rotated `[default: 14]`.
+    pub kes_expiry_warning_threshold: KESPeriod,
+
+    /// Number of KES periods before the expiry of the KES key of a pool from which the signer
+    /// reports the rotation of the key as critical `[default: 4]`.
+    pub kes_expiry_critical_threshold: KESPeriod,
+
+    /// Interval between two checks of the KES keys of the pools, in seconds `[default: 600]`.
+    pub kes_expiry_check_interval_in_seconds: u64,
+
     /// Disable immutables digests cache.
     pub disable_digests_cache: bool,
 
@@ -117,6 +144,23 @@ pub struct Configuration {
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: usize,
 
+    /// Number of workers computing the roots of the block ranges concurrently when importing
+    /// transactions, while the blocks are still being streamed.
+    pub cardano_transactions_import_block_range_roots_workers: usize,
+
+    /// Block scanner used to import the transactions, see [BlockScannerType] for the available
+    /// scanners.
+    #[example = "`chain-sync` or `immutable-files`"]
+    pub cardano_transactions_block_scanner_type: BlockScannerType,
+
+    /// If set, the contents of the transactions (inputs, outputs, minted assets and metadata
+    /// labels) are stored alongside them when importing transactions `[default: false]`.
+    ///
+    /// This increases the storage footprint of the transactions database. The block range roots
+    /// are then computed with leaves that commit to these contents: it must match the setting of
+    /// the aggregator.
+    pub enable_cardano_transactions_extended_mode: bool,
+
     /// Preloading refresh interval in seconds
     pub preloading_refresh_interval_in_seconds: u64,
 }
@@ -145,6 +189,11 @@ impl Configuration {
             operational_certificate_path: signer_temp_dir
                 .as_ref()
                 .map(|dir| dir.join("opcert.cert")),
+            kes_signer_socket_path: None,
+            additional_pools: vec![],
+            kes_expiry_warning_threshold: 14,
+            kes_expiry_critical_threshold: 4,
+            kes_expiry_check_interval_in_seconds: 600,
             disable_digests_cache: false,
             reset_digests_cache: false,
             era_reader_adapter_type: EraReaderAdapterType::Bootstrap,
@@ -156,6 +205,9 @@ impl Configuration {
             enable_transaction_pruning: false,
             transactions_import_block_chunk_size: BlockNumber(1000),
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 1000,
+            cardano_transactions_import_block_range_roots_workers: 1,
+            cardano_transactions_block_scanner_type: BlockScannerType::ChainSync,
+            enable_cardano_transactions_extended_mode: false,
             preloading_refresh_interval_in_seconds: 60,
         }
     }
@@ -170,6 +222,19 @@ impl Configuration {
         })
     }
 
+    /// Return the configuration of all the pools operated by the signer, starting with the
+    /// main one.
+    pub fn pools(&self) -> Vec<PoolConfiguration> {
+        let main_pool = PoolConfiguration {
+            party_id: self.party_id.clone(),
+            kes_secret_key_path: self.kes_secret_key_path.clone(),
+            operational_certificate_path: self.operational_certificate_path.clone(),
+            kes_signer_socket_path: self.kes_signer_socket_path.clone(),
+        };
+
+        [vec![main_pool], self.additional_pools.clone()].concat()
+    }
+
     /// Create the SQL store directory if not exist and return the path of the
     /// SQLite3 file.
     pub fn get_sqlite_file(&self, sqlite_file_name: &str) -> StdResult<PathBuf> {
@@ -206,6 +271,22 @@ impl Configuration {
     }
 }
 
+/// Identity and keys of a pool operated by the signer
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolConfiguration {
+    /// Party Id, only used if no operational certificate is provided
+    pub party_id: Option<PartyId>,
+
+    /// File path to the KES secret key of the pool
+    pub kes_secret_key_path: Option<PathBuf>,
+
+    /// File path to the operational certificate of the pool
+    pub operational_certificate_path: Option<PathBuf>,
+
+    /// File path to the Unix socket of a remote KES signer holding the KES secret key of the pool
+    pub kes_signer_socket_path: Option<PathBuf>,
+}
+
 /// Default configuration with all the default values for configurations.
 #[derive(Debug, Clone, DocumenterDefault)]
 pub struct DefaultConfiguration {
@@ -232,6 +313,24 @@ pub struct DefaultConfiguration {
 
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: u32,
+
+    /// Number of workers computing the roots of the block ranges when importing transactions.
+    pub cardano_transactions_import_block_range_roots_workers: u32,
+
+    /// Type of block scanner used when importing transactions.
+    pub cardano_transactions_block_scanner_type: String,
+
+    /// Enable the storage of the contents of the transactions when importing them.
+    pub enable_cardano_transactions_extended_mode: bool,
+
+    /// Number of KES periods before the expiry of a KES key from which the signer warns.
+    pub kes_expiry_warning_threshold: u32,
+
+    /// Number of KES periods before the expiry of a KES key from which the signer reports it as critical.
+    pub kes_expiry_critical_threshold: u32,
+
+    /// Interval between two checks of the KES keys of the pools, in seconds.
+    pub kes_expiry_check_interval_in_seconds: u64,
 }
 
 impl DefaultConfiguration {
@@ -251,6 +350,12 @@ impl Default for DefaultConfiguration {
             enable_transaction_pruning: true,
             transactions_import_block_chunk_size: 1500,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 10000,
+            cardano_transactions_import_block_range_roots_workers: 1,
+            cardano_transactions_block_scanner_type: "chain-sync".to_string(),
+            enable_cardano_transactions_extended_mode: false,
+            kes_expiry_warning_threshold: 14,
+            kes_expiry_critical_threshold: 4,
+            kes_expiry_check_interval_in_seconds: 600,
         }
     }
 }
@@ -287,6 +392,15 @@ impl Source for DefaultConfiguration {
             result,
             myself.cardano_transactions_block_streamer_max_roll_forwards_per_poll
         );
+        insert_default_configuration!(
+            result,
+            myself.cardano_transactions_import_block_range_roots_workers
+        );
+        insert_default_configuration!(result, myself.cardano_transactions_block_scanner_type);
+        insert_default_configuration!(result, myself.enable_cardano_transactions_extended_mode);
+        insert_default_configuration!(result, myself.kes_expiry_warning_threshold);
+        insert_default_configuration!(result, myself.kes_expiry_critical_threshold);
+        insert_default_configuration!(result, myself.kes_expiry_check_interval_in_seconds);
 
         Ok(result)
     }
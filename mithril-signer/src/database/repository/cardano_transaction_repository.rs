@@ -4,7 +4,7 @@ use async_trait::async_trait;
 
 use mithril_common::crypto_helper::MKTreeNode;
 use mithril_common::entities::{
-    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber,
+    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, ExtendedCardanoTransaction, SlotNumber,
 };
 use mithril_common::StdResult;
 use mithril_persistence::database::repository::CardanoTransactionRepository;
@@ -26,6 +26,13 @@ impl TransactionStore for CardanoTransactionRepository {
         self.store_transactions(transactions).await
     }
 
+    async fn store_extended_transactions(
+        &self,
+        transactions: Vec<ExtendedCardanoTransaction>,
+    ) -> StdResult<()> {
+        self.store_extended_transactions(transactions).await
+    }
+
     async fn get_transactions_in_range(
         &self,
         range: Range<BlockNumber>,
@@ -37,6 +44,13 @@ impl TransactionStore for CardanoTransactionRepository {
         })
     }
 
+    async fn get_extended_transactions_in_range(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<ExtendedCardanoTransaction>> {
+        self.get_extended_transactions_in_range_blocks(range).await
+    }
+
     async fn store_block_range_roots(
         &self,
         block_ranges: Vec<(BlockRange, MKTreeNode)>,
@@ -7,7 +7,9 @@ use anyhow::{anyhow, Context};
 use tokio::sync::{Mutex, RwLock};
 
 use mithril_common::api_version::APIVersionProvider;
-use mithril_common::cardano_block_scanner::CardanoBlockScanner;
+use mithril_common::cardano_block_scanner::{
+    BlockScanner, BlockScannerType, CardanoBlockScanner, ImmutableFileBlockScanner,
+};
 use mithril_common::cardano_transactions_preloader::CardanoTransactionsPreloader;
 use mithril_common::chain_observer::{
     CardanoCliRunner, ChainObserver, ChainObserverBuilder, ChainObserverType,
@@ -22,8 +24,9 @@ use mithril_common::digesters::{
 };
 use mithril_common::era::{EraChecker, EraReader};
 use mithril_common::signable_builder::{
-    CardanoImmutableFilesFullSignableBuilder, CardanoStakeDistributionSignableBuilder,
-    CardanoTransactionsSignableBuilder, MithrilSignableBuilderService,
+    CardanoDRepStakeDistributionSignableBuilder, CardanoImmutableFilesFullSignableBuilder,
+    CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
+    ChainObserverDRepStakeDistributionRetriever, MithrilSignableBuilderService,
     MithrilStakeDistributionSignableBuilder,
 };
 use mithril_common::signed_entity_type_lock::SignedEntityTypeLock;
@@ -31,20 +34,20 @@ use mithril_common::{MithrilTickerService, StdResult, TickerService};
 
 use mithril_persistence::database::repository::CardanoTransactionRepository;
 use mithril_persistence::database::{ApplicationNodeType, SqlMigration};
-use mithril_persistence::sqlite::{ConnectionBuilder, SqliteConnection, SqliteConnectionPool};
+use mithril_persistence::sqlite::{ConnectionBuilder, DatabaseConnectionPool, SqliteConnection};
 use mithril_persistence::store::adapter::SQLiteAdapter;
 use mithril_persistence::store::StakeStore;
 
-use crate::dependency_injection::SignerDependencyContainer;
+use crate::dependency_injection::{SignerDependencyContainer, SignerPool};
 use crate::services::{
     AggregatorHTTPClient, CardanoTransactionsImporter,
-    CardanoTransactionsPreloaderActivationSigner, MithrilEpochService, MithrilSingleSigner,
-    SignerSignableSeedBuilder, SignerUpkeepService, TransactionsImporterByChunk,
-    TransactionsImporterWithPruner, TransactionsImporterWithVacuum,
+    CardanoTransactionsPreloaderActivationSigner, KesExpiryMonitor, KesExpiryThresholds,
+    MithrilEpochService, MithrilSingleSigner, SignerSignableSeedBuilder, SignerUpkeepService,
+    TransactionsImporterByChunk, TransactionsImporterWithPruner, TransactionsImporterWithVacuum,
 };
-use crate::store::{MKTreeStoreSqlite, ProtocolInitializerStore};
+use crate::store::{MKTreeStoreSqlite, ProtocolInitializerStore, ProtocolInitializerStorer};
 use crate::{
-    Configuration, MetricsService, HTTP_REQUEST_TIMEOUT_DURATION, SQLITE_FILE,
+    Configuration, MetricsService, PoolConfiguration, HTTP_REQUEST_TIMEOUT_DURATION, SQLITE_FILE,
     SQLITE_FILE_CARDANO_TRANSACTION,
 };
 
@@ -123,9 +126,9 @@ impl<'a> DependenciesBuilder<'a> {
         self
     }
 
-    /// Compute protocol party id
-    fn compute_protocol_party_id(&self) -> StdResult<ProtocolPartyId> {
-        match &self.config.operational_certificate_path {
+    /// Compute protocol party id of a pool
+    fn compute_protocol_party_id(pool: &PoolConfiguration) -> StdResult<ProtocolPartyId> {
+        match &pool.operational_certificate_path {
             Some(operational_certificate_path) => {
                 let opcert: OpCert = OpCert::from_file(operational_certificate_path)
                     .with_context(|| "Could not decode operational certificate")?;
@@ -133,14 +136,50 @@ impl<'a> DependenciesBuilder<'a> {
                     .compute_protocol_party_id()
                     .with_context(|| "Could not compute party_id from operational certificate")?)
             }
-            _ => Ok(self
-                .config
+            _ => Ok(pool
                 .party_id
                 .to_owned()
                 .ok_or(anyhow!("A party_id should at least be provided"))?),
         }
     }
 
+    /// Build the additional pools operated by the signer, each one gets its own protocol
+    /// initializers table.
+    fn build_additional_pools(
+        &self,
+        main_party_id: &ProtocolPartyId,
+        sqlite_connection: Arc<SqliteConnection>,
+    ) -> StdResult<Vec<SignerPool>> {
+        let mut party_ids = vec![main_party_id.to_owned()];
+        let mut pools = vec![];
+        for pool in &self.config.additional_pools {
+            let party_id = Self::compute_protocol_party_id(pool)
+                .with_context(|| "Could not compute the party_id of an additional pool")?;
+            if party_ids.contains(&party_id) {
+                return Err(anyhow!(
+                    "Pool '{party_id}' is configured more than once in the signer"
+                ));
+            }
+            let table_name = format!(
+                "protocol_initializer_{}",
+                party_id.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+            );
+            pools.push(SignerPool {
+                single_signer: Arc::new(MithrilSingleSigner::new(party_id.clone())),
+                protocol_initializer_store: Arc::new(ProtocolInitializerStore::new(
+                    Box::new(SQLiteAdapter::new(&table_name, sqlite_connection.clone())?),
+                    self.config.store_retention_limit,
+                )),
+                kes_secret_key_path: pool.kes_secret_key_path.clone(),
+                operational_certificate_path: pool.operational_certificate_path.clone(),
+                kes_signer_socket_path: pool.kes_signer_socket_path.clone(),
+            });
+            party_ids.push(party_id);
+        }
+
+        Ok(pools)
+    }
+
     async fn build_digester_cache_provider(
         &self,
     ) -> StdResult<Option<Arc<dyn ImmutableFileDigestCacheProvider>>> {
@@ -165,6 +204,14 @@ impl<'a> DependenciesBuilder<'a> {
         &self,
         sqlite_file_name: &str,
         migrations: Vec<SqlMigration>,
+    ) -> StdResult<SqliteConnection> {
+        self.open_sqlite_connection(sqlite_file_name, migrations)
+    }
+
+    fn open_sqlite_connection(
+        &self,
+        sqlite_file_name: &str,
+        migrations: Vec<SqlMigration>,
     ) -> StdResult<SqliteConnection> {
         let sqlite_db_path = self.config.get_sqlite_file(sqlite_file_name)?;
         let logger = slog_scope::logger();
@@ -194,15 +241,28 @@ impl<'a> DependenciesBuilder<'a> {
             self.build_sqlite_connection(SQLITE_FILE, crate::database::migration::get_migrations())
                 .await?,
         );
-        let transaction_sqlite_connection = self
+        // Apply the migrations once before opening the pooled connections
+        let _transaction_sqlite_connection = self
             .build_sqlite_connection(
                 SQLITE_FILE_CARDANO_TRANSACTION,
                 mithril_persistence::database::cardano_transaction_migration::get_migrations(),
             )
             .await?;
-        let sqlite_connection_cardano_transaction_pool = Arc::new(
-            SqliteConnectionPool::build_from_connection(transaction_sqlite_connection),
-        );
+        // One connection for each block range roots worker plus one for the blocks streaming
+        let cardano_transaction_pool_size = self
+            .config
+            .cardano_transactions_import_block_range_roots_workers
+            .max(1)
+            + 1;
+        let sqlite_connection_cardano_transaction_pool = Arc::new(DatabaseConnectionPool::build(
+            cardano_transaction_pool_size,
+            || {
+                self.open_sqlite_connection(SQLITE_FILE_CARDANO_TRANSACTION, vec![])
+                    .with_context(|| {
+                        "Dependencies Builder can not build SQLite connection for Cardano transactions"
+                    })
+            },
+        )?);
 
         let signed_entity_type_lock = Arc::new(SignedEntityTypeLock::default());
         let protocol_initializer_store = Arc::new(ProtocolInitializerStore::new(
@@ -212,7 +272,9 @@ impl<'a> DependenciesBuilder<'a> {
             )?),
             self.config.store_retention_limit,
         ));
-        let single_signer = Arc::new(MithrilSingleSigner::new(self.compute_protocol_party_id()?));
+        let party_id = Self::compute_protocol_party_id(&self.config.pools()[0])?;
+        let single_signer = Arc::new(MithrilSingleSigner::new(party_id.clone()));
+        let additional_pools = self.build_additional_pools(&party_id, sqlite_connection.clone())?;
         let digester = Arc::new(CardanoImmutableDigester::new(
             self.build_digester_cache_provider().await?,
             slog_scope::logger(),
@@ -268,18 +330,40 @@ impl<'a> DependenciesBuilder<'a> {
             &self.config.cardano_node_socket_path,
             network,
             slog_scope::logger(),
+        )
+        .with_extended_mode(self.config.enable_cardano_transactions_extended_mode);
+        let chain_block_reader = Arc::new(Mutex::new(chain_block_reader));
+        let block_scanner: Arc<dyn BlockScanner> =
+            match self.config.cardano_transactions_block_scanner_type {
+                BlockScannerType::ChainSync => Arc::new(CardanoBlockScanner::new(
+                    chain_block_reader,
+                    self.config
+                        .cardano_transactions_block_streamer_max_roll_forwards_per_poll,
+                    slog_scope::logger(),
+                )),
+                BlockScannerType::ImmutableFiles => Arc::new(
+                    ImmutableFileBlockScanner::new(
+                        &self.config.db_directory,
+                        chain_block_reader,
+                        self.config
+                            .cardano_transactions_block_streamer_max_roll_forwards_per_poll,
+                        slog_scope::logger(),
+                    )
+                    .with_extended_mode(self.config.enable_cardano_transactions_extended_mode),
+                ),
+            };
+        let transactions_importer = Arc::new(
+            CardanoTransactionsImporter::new(
+                block_scanner,
+                transaction_store.clone(),
+                slog_scope::logger(),
+            )
+            .with_extended_mode(self.config.enable_cardano_transactions_extended_mode)
+            .with_block_range_roots_workers(
+                self.config
+                    .cardano_transactions_import_block_range_roots_workers,
+            ),
         );
-        let block_scanner = Arc::new(CardanoBlockScanner::new(
-            Arc::new(Mutex::new(chain_block_reader)),
-            self.config
-                .cardano_transactions_block_streamer_max_roll_forwards_per_poll,
-            slog_scope::logger(),
-        ));
-        let transactions_importer = Arc::new(CardanoTransactionsImporter::new(
-            block_scanner,
-            transaction_store.clone(),
-            slog_scope::logger(),
-        ));
         // Wrap the transaction importer with decorator to prune the transactions after import
         let transactions_importer = Arc::new(TransactionsImporterWithPruner::new(
             self.config
@@ -320,11 +404,22 @@ impl<'a> DependenciesBuilder<'a> {
         let cardano_stake_distribution_signable_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
         );
+        let cardano_drep_stake_distribution_signable_builder =
+            Arc::new(CardanoDRepStakeDistributionSignableBuilder::new(Arc::new(
+                ChainObserverDRepStakeDistributionRetriever::new(chain_observer.clone()),
+            )));
         let epoch_service = Arc::new(RwLock::new(MithrilEpochService::new(stake_store.clone())));
         let signable_seed_builder_service = Arc::new(SignerSignableSeedBuilder::new(
             epoch_service.clone(),
             single_signer.clone(),
-            protocol_initializer_store.clone(),
+            [protocol_initializer_store.clone() as Arc<dyn ProtocolInitializerStorer>]
+                .into_iter()
+                .chain(
+                    additional_pools
+                        .iter()
+                        .map(|pool| pool.protocol_initializer_store.clone()),
+                )
+                .collect(),
         ));
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             era_checker.clone(),
@@ -333,6 +428,7 @@ impl<'a> DependenciesBuilder<'a> {
             cardano_immutable_snapshot_builder,
             cardano_transactions_builder,
             cardano_stake_distribution_signable_builder,
+            cardano_drep_stake_distribution_signable_builder,
         ));
         let metrics_service = Arc::new(MetricsService::new()?);
         let preloader_activation =
@@ -351,6 +447,14 @@ impl<'a> DependenciesBuilder<'a> {
             signed_entity_type_lock.clone(),
             slog_scope::logger(),
         ));
+        let kes_expiry_monitor = Arc::new(KesExpiryMonitor::new(
+            KesExpiryThresholds::new(
+                self.config.kes_expiry_warning_threshold,
+                self.config.kes_expiry_critical_threshold,
+            )?,
+            metrics_service.clone(),
+            slog_scope::logger(),
+        ));
 
         let services = SignerDependencyContainer {
             ticker_service,
@@ -360,6 +464,7 @@ impl<'a> DependenciesBuilder<'a> {
             single_signer,
             stake_store,
             protocol_initializer_store,
+            additional_pools,
             era_checker,
             era_reader,
             api_version_provider,
@@ -369,6 +474,7 @@ impl<'a> DependenciesBuilder<'a> {
             cardano_transactions_preloader,
             upkeep_service,
             epoch_service,
+            kes_expiry_monitor,
         };
 
         Ok(services)
@@ -416,4 +522,44 @@ mod tests {
             .expect("service builder build should not fail");
         assert!(stores_dir.exists());
     }
+
+    #[tokio::test]
+    async fn test_build_fails_if_a_pool_is_configured_more_than_once() {
+        let stores_dir =
+            get_test_dir("test_build_fails_if_a_pool_is_configured_more_than_once").join("stores");
+        let config = Configuration {
+            data_stores_directory: stores_dir,
+            operational_certificate_path: None,
+            additional_pools: vec![
+                PoolConfiguration {
+                    party_id: Some("pool-2".to_string()),
+                    ..PoolConfiguration::default()
+                },
+                PoolConfiguration {
+                    party_id: Some("pool-1".to_string()),
+                    ..PoolConfiguration::default()
+                },
+            ],
+            ..Configuration::new_sample("pool-1")
+        };
+        let chain_observer_builder: fn(&Configuration) -> StdResult<Arc<dyn ChainObserver>> =
+            |_config| Ok(Arc::new(FakeObserver::new(Some(TimePoint::dummy()))));
+        let immutable_file_observer_builder: fn(
+            &Configuration,
+        )
+            -> StdResult<Arc<dyn ImmutableFileObserver>> =
+            |_config: &Configuration| Ok(Arc::new(DumbImmutableFileObserver::default()));
+
+        let mut dependencies_builder = DependenciesBuilder::new(&config);
+        let result = dependencies_builder
+            .override_chain_observer_builder(chain_observer_builder)
+            .override_immutable_file_observer_builder(immutable_file_observer_builder)
+            .build()
+            .await;
+
+        assert!(
+            result.is_err(),
+            "building with a duplicated pool should fail"
+        );
+    }
 }
@@ -1,8 +1,10 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use mithril_common::api_version::APIVersionProvider;
 use mithril_common::cardano_transactions_preloader::CardanoTransactionsPreloader;
 use mithril_common::chain_observer::ChainObserver;
+use mithril_common::crypto_helper::{KesSigner, KesSignerFile, KesSignerUnixSocket};
 use mithril_common::digesters::ImmutableDigester;
 use mithril_common::era::{EraChecker, EraReader};
 use mithril_common::signable_builder::SignableBuilderService;
@@ -11,9 +13,11 @@ use mithril_common::TickerService;
 use mithril_persistence::store::StakeStore;
 use tokio::sync::RwLock;
 
-use crate::services::{AggregatorClient, EpochService, SingleSigner, UpkeepService};
+use crate::services::{
+    AggregatorClient, EpochService, KesExpiryMonitor, PoolKeysPaths, SingleSigner, UpkeepService,
+};
 use crate::store::ProtocolInitializerStorer;
-use crate::MetricsService;
+use crate::{Configuration, MetricsService};
 
 type StakeStoreService = Arc<StakeStore>;
 type CertificateHandlerService = Arc<dyn AggregatorClient>;
@@ -43,12 +47,15 @@ pub struct SignerDependencyContainer {
     /// Digester service
     pub digester: DigesterService,
 
-    /// SingleSigner service
+    /// SingleSigner service of the main pool
     pub single_signer: SingleSignerService,
 
-    /// ProtocolInitializer store
+    /// ProtocolInitializer store of the main pool
     pub protocol_initializer_store: ProtocolInitializerStoreService,
 
+    /// Other pools operated by the signer
+    pub additional_pools: Vec<SignerPool>,
+
     /// Era checker service
     pub era_checker: Arc<EraChecker>,
 
@@ -75,4 +82,73 @@ pub struct SignerDependencyContainer {
 
     /// Epoch service
     pub epoch_service: EpochServiceWrapper,
+
+    /// KES expiry monitor
+    pub kes_expiry_monitor: Arc<KesExpiryMonitor>,
+}
+
+impl SignerDependencyContainer {
+    /// Pools operated by the signer: the main pool first, then the additional pools.
+    pub fn pools(&self, config: &Configuration) -> Vec<SignerPool> {
+        let main_pool = SignerPool {
+            single_signer: self.single_signer.clone(),
+            protocol_initializer_store: self.protocol_initializer_store.clone(),
+            kes_secret_key_path: config.kes_secret_key_path.clone(),
+            operational_certificate_path: config.operational_certificate_path.clone(),
+            kes_signer_socket_path: config.kes_signer_socket_path.clone(),
+        };
+
+        [vec![main_pool], self.additional_pools.clone()].concat()
+    }
+}
+
+/// A pool operated by the signer, with its own identity, keys and protocol initializers.
+#[derive(Clone)]
+pub struct SignerPool {
+    /// SingleSigner service of the pool
+    pub single_signer: SingleSignerService,
+
+    /// ProtocolInitializer store of the pool
+    pub protocol_initializer_store: ProtocolInitializerStoreService,
+
+    /// File path to the KES secret key of the pool
+    pub kes_secret_key_path: Option<PathBuf>,
+
+    /// File path to the operational certificate of the pool
+    pub operational_certificate_path: Option<PathBuf>,
+
+    /// File path to the Unix socket of the remote KES signer of the pool
+    pub kes_signer_socket_path: Option<PathBuf>,
+}
+
+impl SignerPool {
+    /// KES signer of the pool: the remote KES signer if a socket is configured, else the KES
+    /// secret key file if any.
+    pub fn kes_signer(&self) -> Option<Arc<dyn KesSigner>> {
+        match (&self.kes_signer_socket_path, &self.kes_secret_key_path) {
+            (Some(kes_signer_socket_path), _) => {
+                Some(Arc::new(KesSignerUnixSocket::new(kes_signer_socket_path)))
+            }
+            (None, Some(kes_secret_key_path)) => {
+                Some(Arc::new(KesSignerFile::new(kes_secret_key_path)))
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Paths of the keys of the pool checked by the [KesExpiryMonitor], if the pool has an
+    /// operational certificate.
+    pub fn keys_paths(&self) -> Option<PoolKeysPaths> {
+        self.operational_certificate_path
+            .as_ref()
+            .map(|operational_certificate_path| PoolKeysPaths {
+                party_id: self.single_signer.get_party_id(),
+                operational_certificate_path: operational_certificate_path.to_owned(),
+                // The KES secret key is held by the remote KES signer if any
+                kes_secret_key_path: self
+                    .kes_secret_key_path
+                    .clone()
+                    .filter(|_| self.kes_signer_socket_path.is_none()),
+            })
+    }
 }
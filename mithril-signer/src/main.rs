@@ -15,7 +15,9 @@ use tokio::{
 
 use mithril_common::StdResult;
 use mithril_doc::{Documenter, DocumenterDefault, GenerateDocCommands, StructDoc};
-use mithril_signer::dependency_injection::DependenciesBuilder;
+use mithril_signer::commands::{KesSignerDaemonCommand, ToolsCommand};
+use mithril_signer::dependency_injection::{DependenciesBuilder, SignerPool};
+use mithril_signer::services::PoolKeysPaths;
 use mithril_signer::{
     Configuration, DefaultConfiguration, MetricsServer, SignerRunner, SignerState, StateMachine,
 };
@@ -116,6 +118,12 @@ fn build_logger(min_level: Level) -> Logger {
 
 #[derive(Subcommand, Debug, Clone)]
 enum SignerCommands {
+    /// List of tools to upkeep the signer
+    Tools(ToolsCommand),
+
+    /// Run a reference remote KES signer, holding the KES secret key out of the signer process
+    KesSignerDaemon(KesSignerDaemonCommand),
+
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
 }
@@ -142,6 +150,10 @@ async fn main() -> StdResult<()> {
 
     debug!("Starting"; "node_version" => env!("CARGO_PKG_VERSION"));
 
+    if let Some(SignerCommands::KesSignerDaemon(cmd)) = &args.command {
+        return cmd.execute().await;
+    }
+
     // Load config
     let config: Configuration = config::Config::builder()
         .set_default("disable_digests_cache", args.disable_digests_cache)
@@ -174,6 +186,10 @@ async fn main() -> StdResult<()> {
         .try_deserialize()
         .with_context(|| "configuration deserialize error")?;
 
+    if let Some(SignerCommands::Tools(cmd)) = &args.command {
+        return cmd.execute(&config).await;
+    }
+
     let services = DependenciesBuilder::new(&config)
         .build()
         .await
@@ -181,6 +197,13 @@ async fn main() -> StdResult<()> {
 
     let metrics_service = services.metrics_service.clone();
     let cardano_transaction_preloader = services.cardano_transactions_preloader.clone();
+    let kes_expiry_monitor = services.kes_expiry_monitor.clone();
+    let chain_observer = services.chain_observer.clone();
+    let pools_keys_paths: Vec<PoolKeysPaths> = services
+        .pools(&config)
+        .iter()
+        .filter_map(SignerPool::keys_paths)
+        .collect();
 
     debug!("Started"; "run_mode" => &args.run_mode, "config" => format!("{config:?}"));
 
@@ -212,6 +235,17 @@ async fn main() -> StdResult<()> {
         }
     });
 
+    let kes_expiry_check_interval = config.kes_expiry_check_interval_in_seconds;
+    join_set.spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(kes_expiry_check_interval));
+        loop {
+            interval.tick().await;
+            kes_expiry_monitor
+                .check_pools_keys(&pools_keys_paths, chain_observer.as_ref())
+                .await;
+        }
+    });
+
     let (metrics_server_shutdown_tx, metrics_server_shutdown_rx) = oneshot::channel();
     if config.enable_metrics_server {
         join_set.spawn(async move {
@@ -62,3 +62,9 @@ pub const RUNTIME_CYCLE_TOTAL_SINCE_STARTUP_METRIC_NAME: &str =
 /// 'runtime_cycle_total_since_startup' metric help
 pub const RUNTIME_CYCLE_TOTAL_SINCE_STARTUP_METRIC_HELP: &str =
     "Number of runtime cycles since startup on a Mithril signer node";
+
+/// 'kes_periods_before_expiry' metric name
+pub const KES_PERIODS_BEFORE_EXPIRY_METRIC_NAME: &str = "mithril_signer_kes_periods_before_expiry";
+/// 'kes_periods_before_expiry' metric help
+pub const KES_PERIODS_BEFORE_EXPIRY_METRIC_HELP: &str =
+    "Number of KES periods before the expiry of the KES key of a pool on a Mithril signer node";
@@ -1,8 +1,9 @@
-use mithril_common::{entities::Epoch, StdResult};
-use prometheus::{Counter, Encoder, Gauge, Opts, Registry, TextEncoder};
+use mithril_common::{crypto_helper::KESPeriod, entities::Epoch, entities::PartyId, StdResult};
+use prometheus::{Counter, Encoder, Gauge, GaugeVec, Opts, Registry, TextEncoder};
 use slog_scope::debug;
 
 use super::{
+    KES_PERIODS_BEFORE_EXPIRY_METRIC_HELP, KES_PERIODS_BEFORE_EXPIRY_METRIC_NAME,
     RUNTIME_CYCLE_SUCCESS_SINCE_STARTUP_METRIC_HELP,
     RUNTIME_CYCLE_SUCCESS_SINCE_STARTUP_METRIC_NAME, RUNTIME_CYCLE_TOTAL_SINCE_STARTUP_METRIC_HELP,
     RUNTIME_CYCLE_TOTAL_SINCE_STARTUP_METRIC_NAME,
@@ -37,6 +38,7 @@ pub struct MetricsService {
     signature_registration_success_last_epoch_gauge: Box<Gauge>,
     runtime_cycle_success_since_startup_counter: Box<Counter>,
     runtime_cycle_total_since_startup_counter: Box<Counter>,
+    kes_periods_before_expiry_gauge: Box<GaugeVec>,
 }
 
 impl MetricsService {
@@ -99,6 +101,16 @@ impl MetricsService {
         )?);
         registry.register(runtime_cycle_total_since_startup_counter.clone())?;
 
+        // KES metrics
+        let kes_periods_before_expiry_gauge = Box::new(GaugeVec::new(
+            Opts::new(
+                KES_PERIODS_BEFORE_EXPIRY_METRIC_NAME,
+                KES_PERIODS_BEFORE_EXPIRY_METRIC_HELP,
+            ),
+            &["party_id"],
+        )?);
+        registry.register(kes_periods_before_expiry_gauge.clone())?;
+
         Ok(Self {
             registry,
             signer_registration_success_since_startup_counter,
@@ -109,6 +121,7 @@ impl MetricsService {
             signature_registration_success_last_epoch_gauge,
             runtime_cycle_success_since_startup_counter,
             runtime_cycle_total_since_startup_counter,
+            kes_periods_before_expiry_gauge,
         })
     }
 
@@ -248,6 +261,22 @@ impl MetricsService {
             .get()
             .round() as CounterValue
     }
+
+    /// Set the `kes_periods_before_expiry` gauge value of a pool.
+    pub fn kes_periods_before_expiry_gauge_set(&self, party_id: &PartyId, value: KESPeriod) {
+        debug!("MetricsService: set 'kes_periods_before_expiry' gauge value of pool {party_id} to {value}");
+        self.kes_periods_before_expiry_gauge
+            .with_label_values(&[party_id])
+            .set(value as f64);
+    }
+
+    /// Get the `kes_periods_before_expiry` gauge value of a pool.
+    pub fn kes_periods_before_expiry_gauge_get(&self, party_id: &PartyId) -> KESPeriod {
+        self.kes_periods_before_expiry_gauge
+            .with_label_values(&[party_id])
+            .get()
+            .round() as KESPeriod
+    }
 }
 
 #[cfg(test)]
@@ -430,4 +459,22 @@ mod tests {
             metrics_service.runtime_cycle_total_since_startup_counter_get(),
         );
     }
+
+    #[test]
+    fn test_kes_periods_before_expiry_gauge_set() {
+        let metrics_service = MetricsService::new().unwrap();
+        let (pool_1, pool_2) = ("pool-1".to_string(), "pool-2".to_string());
+
+        metrics_service.kes_periods_before_expiry_gauge_set(&pool_1, 12);
+        metrics_service.kes_periods_before_expiry_gauge_set(&pool_2, 3);
+
+        assert_eq!(
+            12,
+            metrics_service.kes_periods_before_expiry_gauge_get(&pool_1)
+        );
+        assert_eq!(
+            3,
+            metrics_service.kes_periods_before_expiry_gauge_get(&pool_2)
+        );
+    }
 }
@@ -4,7 +4,7 @@ use slog_scope::{debug, info, warn};
 use thiserror::Error;
 use tokio::sync::RwLockReadGuard;
 
-use mithril_common::crypto_helper::{KESPeriod, OpCert, ProtocolOpCert, SerDeShelleyFileFormat};
+use mithril_common::crypto_helper::{KESPeriod, ProtocolOpCert};
 use mithril_common::entities::{
     CertificatePending, Epoch, PartyId, ProtocolMessage, SignedEntityType, Signer, SignerWithStake,
     SingleSignatures, TimePoint,
@@ -12,9 +12,9 @@ use mithril_common::entities::{
 use mithril_common::StdResult;
 use mithril_persistence::store::StakeStorer;
 
-use crate::dependency_injection::SignerDependencyContainer;
+use crate::dependency_injection::{SignerDependencyContainer, SignerPool};
 use crate::entities::SignerEpochSettings;
-use crate::services::{EpochService, MithrilProtocolInitializerBuilder};
+use crate::services::{EpochService, KesExpiryLevel, MithrilProtocolInitializerBuilder};
 use crate::Configuration;
 
 /// This trait is mainly intended for mocking.
@@ -29,14 +29,17 @@ pub trait Runner: Send + Sync {
     /// Fetch the current time point from the Cardano node.
     async fn get_current_time_point(&self) -> StdResult<TimePoint>;
 
-    /// Register the signer verification key to the aggregator.
-    async fn register_signer_to_aggregator(&self) -> StdResult<()>;
+    /// Get the party ids of the pools operated by the signer.
+    fn get_party_ids(&self) -> Vec<PartyId>;
+
+    /// Register the signer verification key of the given pool to the aggregator.
+    async fn register_signer_to_aggregator(&self, party_id: &PartyId) -> StdResult<()>;
 
     /// Read the stake distribution and store it.
     async fn update_stake_distribution(&self, epoch: Epoch) -> StdResult<()>;
 
-    /// Check if the signer can sign the current epoch.
-    async fn can_sign_current_epoch(&self) -> StdResult<bool>;
+    /// Check if the given pool can sign the current epoch.
+    async fn can_sign_current_epoch(&self, party_id: &PartyId) -> StdResult<bool>;
 
     /// Check if the signer can sign the given signed entity type.
     async fn can_sign_signed_entity_type(&self, signed_entity_type: &SignedEntityType) -> bool;
@@ -50,9 +53,10 @@ pub trait Runner: Send + Sync {
         signed_entity_type: &SignedEntityType,
     ) -> StdResult<ProtocolMessage>;
 
-    /// Create the single signature.
+    /// Create the single signature of the given pool.
     async fn compute_single_signature(
         &self,
+        party_id: &PartyId,
         epoch: Epoch,
         message: &ProtocolMessage,
     ) -> StdResult<Option<SingleSignatures>>;
@@ -87,18 +91,33 @@ pub enum RunnerError {
     /// Parse file error
     #[error("File parse failed: {0}.")]
     FileParse(String),
+    /// The pool is not operated by this signer.
+    #[error("Pool not operated by this signer, party_id: {0}.")]
+    UnknownPool(PartyId),
+    /// The KES key of the pool has expired.
+    #[error("KES key expired, it must be rotated, party_id: {0}.")]
+    KesKeyExpired(PartyId),
 }
 
 /// Controller methods for the Signer's state machine.
 pub struct SignerRunner {
-    config: Configuration,
     services: SignerDependencyContainer,
+    pools: Vec<SignerPool>,
 }
 
 impl SignerRunner {
     /// Create a new Runner instance.
     pub fn new(config: Configuration, services: SignerDependencyContainer) -> Self {
-        Self { services, config }
+        let pools = services.pools(&config);
+
+        Self { services, pools }
+    }
+
+    fn get_pool(&self, party_id: &PartyId) -> StdResult<&SignerPool> {
+        self.pools
+            .iter()
+            .find(|pool| &pool.single_signer.get_party_id() == party_id)
+            .ok_or_else(|| RunnerError::UnknownPool(party_id.to_owned()).into())
     }
 
     /// Get the current signers with their stake.
@@ -146,8 +165,16 @@ impl Runner for SignerRunner {
             .with_context(|| "Runner can not get current time point")
     }
 
-    async fn register_signer_to_aggregator(&self) -> StdResult<()> {
-        debug!("RUNNER: register_signer_to_aggregator");
+    fn get_party_ids(&self) -> Vec<PartyId> {
+        self.pools
+            .iter()
+            .map(|pool| pool.single_signer.get_party_id())
+            .collect()
+    }
+
+    async fn register_signer_to_aggregator(&self, party_id: &PartyId) -> StdResult<()> {
+        debug!("RUNNER: register_signer_to_aggregator"; "party_id" => party_id);
+        let pool = self.get_pool(party_id)?;
 
         let (epoch, protocol_parameters) = {
             let epoch_service = self.services.epoch_service.read().await;
@@ -169,42 +196,70 @@ impl Runner for SignerRunner {
                 ))
             })?;
         let stake = stake_distribution
-            .get(&self.services.single_signer.get_party_id())
+            .get(party_id)
             .ok_or_else(RunnerError::NoStakeForSelf)?;
-        let (operational_certificate, protocol_operational_certificate) = match &self
-            .config
-            .operational_certificate_path
-        {
-            Some(operational_certificate_path) => {
-                let opcert: OpCert = OpCert::from_file(operational_certificate_path)
-                    .map_err(|_| RunnerError::FileParse("operational_certificate_path".to_string()))
-                    .with_context(|| {
-                        "register_signer_to_aggregator can not decode OpCert from file"
-                    })?;
-                (Some(opcert.clone()), Some(ProtocolOpCert::new(opcert)))
-            }
-            _ => (None, None),
+        let operational_certificate = match pool.keys_paths() {
+            Some(keys_paths) => Some(
+                self.services
+                    .kes_expiry_monitor
+                    .reload_keys(
+                        party_id,
+                        &keys_paths.operational_certificate_path,
+                        keys_paths.kes_secret_key_path.as_deref(),
+                    )
+                    .await
+                    .with_context(|| "register_signer_to_aggregator can not reload the KES keys")?,
+            ),
+            None => None,
         };
 
-        let kes_period = match operational_certificate {
-            Some(operational_certificate) => Some(
-                self.services
+        let kes_period = match &operational_certificate {
+            Some(operational_certificate) => {
+                let current_kes_period = self
+                    .services
                     .chain_observer
-                    .get_current_kes_period(&operational_certificate)
+                    .get_current_kes_period(operational_certificate)
                     .await?
-                    .unwrap_or_default()
-                    - operational_certificate.start_kes_period as KESPeriod,
-            ),
+                    .unwrap_or_default();
+                if let Some(max_kes_evolutions) = self
+                    .services
+                    .chain_observer
+                    .get_max_kes_evolutions(operational_certificate)
+                    .await?
+                {
+                    let kes_expiry_status = self.services.kes_expiry_monitor.check_expiry(
+                        party_id,
+                        operational_certificate,
+                        current_kes_period,
+                        max_kes_evolutions,
+                    );
+                    if kes_expiry_status.level == KesExpiryLevel::Expired {
+                        return Err(RunnerError::KesKeyExpired(party_id.to_owned()).into());
+                    }
+                }
+
+                Some(current_kes_period - operational_certificate.start_kes_period as KESPeriod)
+            }
             None => None,
         };
-        let protocol_initializer = MithrilProtocolInitializerBuilder::build(
-            stake,
-            &protocol_parameters,
-            self.config.kes_secret_key_path.clone(),
-            kes_period,
-        )?;
+        let protocol_operational_certificate = operational_certificate.map(ProtocolOpCert::new);
+        // The KES signature may be delegated to a remote KES signer through blocking socket I/O
+        let protocol_initializer = tokio::task::spawn_blocking({
+            let stake = stake.to_owned();
+            let kes_signer = pool.kes_signer();
+            move || {
+                MithrilProtocolInitializerBuilder::build(
+                    &stake,
+                    &protocol_parameters,
+                    kes_signer,
+                    kes_period,
+                )
+            }
+        })
+        .await
+        .with_context(|| "Protocol initializer thread crashed")??;
         let signer = Signer::new(
-            self.services.single_signer.get_party_id(),
+            party_id.to_owned(),
             protocol_initializer.verification_key().into(),
             protocol_initializer.verification_key_signature(),
             protocol_operational_certificate,
@@ -214,8 +269,7 @@ impl Runner for SignerRunner {
             .certificate_handler
             .register_signer(epoch_offset_to_recording_epoch, &signer)
             .await?;
-        self.services
-            .protocol_initializer_store
+        pool.protocol_initializer_store
             .save_protocol_initializer(epoch_offset_to_recording_epoch, protocol_initializer)
             .await?;
 
@@ -250,18 +304,18 @@ impl Runner for SignerRunner {
         Ok(())
     }
 
-    async fn can_sign_current_epoch(&self) -> StdResult<bool> {
+    async fn can_sign_current_epoch(&self, party_id: &PartyId) -> StdResult<bool> {
+        let pool = self.get_pool(party_id)?;
         let epoch_service = self.epoch_service_read().await;
         let epoch = epoch_service.epoch_of_current_data()?;
-        if let Some(protocol_initializer) = self
-            .services
+        if let Some(protocol_initializer) = pool
             .protocol_initializer_store
             .get_protocol_initializer(epoch.offset_to_signer_retrieval_epoch()?)
             .await?
         {
             debug!(" > got protocol initializer for this epoch ({epoch})");
             if epoch_service.is_signer_included_in_current_stake_distribution(
-                self.services.single_signer.get_party_id(),
+                party_id.to_owned(),
                 protocol_initializer,
             )? {
                 return Ok(true);
@@ -316,10 +370,12 @@ impl Runner for SignerRunner {
 
     async fn compute_single_signature(
         &self,
+        party_id: &PartyId,
         epoch: Epoch,
         message: &ProtocolMessage,
     ) -> StdResult<Option<SingleSignatures>> {
-        debug!("RUNNER: compute_single_signature");
+        debug!("RUNNER: compute_single_signature"; "party_id" => party_id);
+        let pool = self.get_pool(party_id)?;
 
         let signers = self
             .get_current_signers_with_stake()
@@ -327,8 +383,7 @@ impl Runner for SignerRunner {
             .with_context(|| "Runner can not not retrieve signers")?;
 
         let signer_retrieval_epoch = epoch.offset_to_signer_retrieval_epoch()?;
-        let protocol_initializer = self
-            .services
+        let protocol_initializer = pool
             .protocol_initializer_store
             .get_protocol_initializer(signer_retrieval_epoch)
             .await?
@@ -337,7 +392,7 @@ impl Runner for SignerRunner {
                     "protocol_initializer at epoch {signer_retrieval_epoch}"
                 ))
             })?;
-        let signature = self.services.single_signer.compute_single_signatures(
+        let signature = pool.single_signer.compute_single_signatures(
             message,
             &signers,
             &protocol_initializer,
@@ -419,6 +474,7 @@ mod tests {
     use rand_chacha::ChaCha20Rng;
     use rand_core::SeedableRng;
 
+    use std::os::unix::net::UnixListener;
     use std::{path::Path, path::PathBuf, sync::Arc};
     use tokio::sync::RwLock;
 
@@ -430,7 +486,9 @@ mod tests {
         },
         chain_observer::FakeObserver,
         crypto_helper::{
-            MKMap, MKMapNode, MKTreeNode, MKTreeStoreInMemory, MKTreeStorer, ProtocolInitializer,
+            tests_setup::setup_temp_directory_for_signer, KesSignerFile, KesSignerUnixSocketDaemon,
+            MKMap, MKMapNode, MKTreeNode, MKTreeStoreInMemory, MKTreeStorer, OpCert,
+            ProtocolInitializer, SerDeShelleyFileFormat,
         },
         digesters::{DumbImmutableDigester, DumbImmutableFileObserver},
         entities::{
@@ -439,12 +497,13 @@ mod tests {
         },
         era::{adapters::EraReaderBootstrapAdapter, EraChecker, EraReader},
         signable_builder::{
-            BlockRangeRootRetriever, CardanoImmutableFilesFullSignableBuilder,
-            CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
+            BlockRangeRootRetriever, CardanoDRepStakeDistributionSignableBuilder,
+            CardanoImmutableFilesFullSignableBuilder, CardanoStakeDistributionSignableBuilder,
+            CardanoTransactionsSignableBuilder, ChainObserverDRepStakeDistributionRetriever,
             MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
         },
         signed_entity_type_lock::SignedEntityTypeLock,
-        test_utils::{fake_data, MithrilFixtureBuilder},
+        test_utils::{fake_data, MithrilFixtureBuilder, TempDir},
         MithrilTickerService, TickerService,
     };
     use mithril_persistence::store::adapter::{DumbStoreAdapter, MemoryAdapter};
@@ -453,9 +512,9 @@ mod tests {
     use crate::{
         metrics::MetricsService,
         services::{
-            CardanoTransactionsImporter, DumbAggregatorClient, MithrilEpochService,
-            MithrilSingleSigner, MockAggregatorClient, MockTransactionStore, MockUpkeepService,
-            SignerSignableSeedBuilder, SingleSigner,
+            CardanoTransactionsImporter, DumbAggregatorClient, KesExpiryMonitor,
+            KesExpiryThresholds, MithrilEpochService, MithrilSingleSigner, MockAggregatorClient,
+            MockTransactionStore, MockUpkeepService, SignerSignableSeedBuilder, SingleSigner,
         },
         store::ProtocolInitializerStore,
     };
@@ -601,6 +660,10 @@ mod tests {
         let cardano_stake_distribution_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
         );
+        let cardano_drep_stake_distribution_builder =
+            Arc::new(CardanoDRepStakeDistributionSignableBuilder::new(Arc::new(
+                ChainObserverDRepStakeDistributionRetriever::new(chain_observer.clone()),
+            )));
         let epoch_service = Arc::new(RwLock::new(MithrilEpochService::new(stake_store.clone())));
         let single_signer = Arc::new(MithrilSingleSigner::new(party_id));
         let protocol_initializer_store =
@@ -608,7 +671,7 @@ mod tests {
         let signable_seed_builder_service = Arc::new(SignerSignableSeedBuilder::new(
             epoch_service.clone(),
             single_signer.clone(),
-            protocol_initializer_store.clone(),
+            vec![protocol_initializer_store.clone()],
         ));
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             era_checker.clone(),
@@ -617,6 +680,7 @@ mod tests {
             cardano_immutable_signable_builder,
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
+            cardano_drep_stake_distribution_builder,
         ));
         let metrics_service = Arc::new(MetricsService::new().unwrap());
         let signed_entity_type_lock = Arc::new(SignedEntityTypeLock::default());
@@ -630,6 +694,14 @@ mod tests {
             Arc::new(CardanoTransactionsPreloaderActivation::new(true)),
         ));
         let upkeep_service = Arc::new(MockUpkeepService::new());
+        let kes_expiry_monitor = Arc::new(KesExpiryMonitor::new(
+            KesExpiryThresholds {
+                warning: 14,
+                critical: 4,
+            },
+            metrics_service.clone(),
+            slog_scope::logger(),
+        ));
 
         SignerDependencyContainer {
             stake_store,
@@ -639,6 +711,7 @@ mod tests {
             single_signer,
             ticker_service,
             protocol_initializer_store,
+            additional_pools: vec![],
             era_checker,
             era_reader,
             api_version_provider,
@@ -648,6 +721,7 @@ mod tests {
             cardano_transactions_preloader,
             upkeep_service,
             epoch_service,
+            kes_expiry_monitor,
         }
     }
 
@@ -734,6 +808,7 @@ mod tests {
             .await
             .unwrap();
 
+        let party_id = services.single_signer.get_party_id();
         let runner = init_runner(Some(services), None).await;
         // inform epoch settings
         let epoch_settings = SignerEpochSettings {
@@ -745,7 +820,7 @@ mod tests {
         runner.inform_epoch_settings(epoch_settings).await.unwrap();
 
         runner
-            .register_signer_to_aggregator()
+            .register_signer_to_aggregator(&party_id)
             .await
             .expect("registering a signer to the aggregator should not fail");
 
@@ -763,6 +838,218 @@ mod tests {
         );
     }
 
+    fn additional_pool(party_id: &str) -> SignerPool {
+        SignerPool {
+            single_signer: Arc::new(MithrilSingleSigner::new(party_id.to_string())),
+            protocol_initializer_store: Arc::new(ProtocolInitializerStore::new(
+                Box::new(MemoryAdapter::new(None).unwrap()),
+                None,
+            )),
+            kes_secret_key_path: None,
+            operational_certificate_path: None,
+            kes_signer_socket_path: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_party_ids_starts_with_the_main_pool() {
+        let mut services = init_services().await;
+        let main_party_id = services.single_signer.get_party_id();
+        services.additional_pools = vec![additional_pool("pool-2"), additional_pool("pool-3")];
+        let runner = init_runner(Some(services), None).await;
+
+        assert_eq!(
+            vec![main_party_id, "pool-2".to_string(), "pool-3".to_string()],
+            runner.get_party_ids()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_signer_to_aggregator_for_an_additional_pool() {
+        let mut services = init_services().await;
+        let fixture = MithrilFixtureBuilder::default().with_signers(5).build();
+        let certificate_handler = Arc::new(DumbAggregatorClient::default());
+        services.certificate_handler = certificate_handler.clone();
+        let additional_party_id = fake_data::signers_with_stakes(2)[0].party_id.clone();
+        let pool = additional_pool(&additional_party_id);
+        let pool_protocol_initializer_store = pool.protocol_initializer_store.clone();
+        services.additional_pools = vec![pool];
+        let main_protocol_initializer_store = services.protocol_initializer_store.clone();
+        let current_epoch = services.ticker_service.get_current_epoch().await.unwrap();
+        let stakes = services
+            .chain_observer
+            .get_current_stake_distribution()
+            .await
+            .unwrap()
+            .unwrap();
+        services
+            .stake_store
+            .save_stakes(current_epoch.offset_to_recording_epoch(), stakes)
+            .await
+            .unwrap();
+        let runner = init_runner(Some(services), None).await;
+        let epoch_settings = SignerEpochSettings {
+            epoch: current_epoch,
+            current_signers: fixture.signers(),
+            next_signers: fixture.signers(),
+            ..SignerEpochSettings::dummy().clone()
+        };
+        runner.inform_epoch_settings(epoch_settings).await.unwrap();
+
+        runner
+            .register_signer_to_aggregator(&additional_party_id)
+            .await
+            .expect("registering an additional pool to the aggregator should not fail");
+
+        assert_eq!(
+            Some(additional_party_id),
+            certificate_handler
+                .get_last_registered_signer()
+                .await
+                .map(|signer| signer.party_id)
+        );
+        let recording_epoch = current_epoch.offset_to_recording_epoch();
+        assert!(pool_protocol_initializer_store
+            .get_protocol_initializer(recording_epoch)
+            .await
+            .unwrap()
+            .is_some());
+        assert!(main_protocol_initializer_store
+            .get_protocol_initializer(recording_epoch)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_register_signer_to_aggregator_fails_if_the_kes_key_has_expired() {
+        let mut services = init_services().await;
+        let fixture = MithrilFixtureBuilder::default().with_signers(2).build();
+        let certificate_handler = Arc::new(DumbAggregatorClient::default());
+        services.certificate_handler = certificate_handler.clone();
+        let party_id = fixture.signers_fixture()[0].party_id();
+        let keys_dir = setup_temp_directory_for_signer(&party_id, false)
+            .expect("the keys of a certified pool should be generated");
+        services.additional_pools = vec![SignerPool {
+            kes_secret_key_path: Some(keys_dir.join("kes.sk")),
+            operational_certificate_path: Some(keys_dir.join("opcert.cert")),
+            ..additional_pool(&party_id)
+        }];
+        let operational_certificate = OpCert::from_file(keys_dir.join("opcert.cert")).unwrap();
+        let chain_observer = FakeObserver::new(None);
+        chain_observer
+            .set_current_kes_period(Some(
+                operational_certificate.start_kes_period as KESPeriod + 62,
+            ))
+            .await;
+        services.chain_observer = Arc::new(chain_observer);
+        let current_epoch = services.ticker_service.get_current_epoch().await.unwrap();
+        services
+            .stake_store
+            .save_stakes(
+                current_epoch.offset_to_recording_epoch(),
+                fixture.stake_distribution(),
+            )
+            .await
+            .unwrap();
+        let metrics_service = services.metrics_service.clone();
+        let runner = init_runner(Some(services), None).await;
+        let epoch_settings = SignerEpochSettings {
+            epoch: current_epoch,
+            current_signers: fixture.signers(),
+            next_signers: fixture.signers(),
+            ..SignerEpochSettings::dummy().clone()
+        };
+        runner.inform_epoch_settings(epoch_settings).await.unwrap();
+
+        let error = runner
+            .register_signer_to_aggregator(&party_id)
+            .await
+            .expect_err("registering a pool with an expired KES key should fail");
+
+        assert_eq!(
+            Some(&RunnerError::KesKeyExpired(party_id.clone())),
+            error.downcast_ref::<RunnerError>()
+        );
+        assert_eq!(
+            0,
+            metrics_service.kes_periods_before_expiry_gauge_get(&party_id)
+        );
+        assert!(certificate_handler
+            .get_last_registered_signer()
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_register_signer_to_aggregator_with_a_remote_kes_signer() {
+        let mut services = init_services().await;
+        let fixture = MithrilFixtureBuilder::default().with_signers(2).build();
+        let certificate_handler = Arc::new(DumbAggregatorClient::default());
+        services.certificate_handler = certificate_handler.clone();
+        let party_id = fixture.signers_fixture()[0].party_id();
+        let keys_dir = setup_temp_directory_for_signer(&party_id, false)
+            .expect("the keys of a certified pool should be generated");
+        let kes_signer_socket_path = TempDir::create_with_short_path(
+            "signer_runner",
+            "register_signer_with_a_remote_kes_signer",
+        )
+        .join("kes-signer.sock");
+        let listener = UnixListener::bind(&kes_signer_socket_path).unwrap();
+        let daemon =
+            KesSignerUnixSocketDaemon::new(Arc::new(KesSignerFile::new(keys_dir.join("kes.sk"))));
+        std::thread::spawn(move || daemon.serve(listener));
+        services.additional_pools = vec![SignerPool {
+            operational_certificate_path: Some(keys_dir.join("opcert.cert")),
+            kes_signer_socket_path: Some(kes_signer_socket_path),
+            ..additional_pool(&party_id)
+        }];
+        let current_epoch = services.ticker_service.get_current_epoch().await.unwrap();
+        services
+            .stake_store
+            .save_stakes(
+                current_epoch.offset_to_recording_epoch(),
+                fixture.stake_distribution(),
+            )
+            .await
+            .unwrap();
+        let runner = init_runner(Some(services), None).await;
+        let epoch_settings = SignerEpochSettings {
+            epoch: current_epoch,
+            current_signers: fixture.signers(),
+            next_signers: fixture.signers(),
+            ..SignerEpochSettings::dummy().clone()
+        };
+        runner.inform_epoch_settings(epoch_settings).await.unwrap();
+
+        runner
+            .register_signer_to_aggregator(&party_id)
+            .await
+            .expect("registering a pool with a remote KES signer should not fail");
+
+        let registered_signer = certificate_handler
+            .get_last_registered_signer()
+            .await
+            .expect("a signer should have been registered");
+        assert_eq!(party_id, registered_signer.party_id);
+        assert!(registered_signer.verification_key_signature.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_register_signer_to_aggregator_fails_for_a_pool_not_operated_by_the_signer() {
+        let runner = init_runner(None, None).await;
+
+        let error = runner
+            .register_signer_to_aggregator(&"unknown-pool".to_string())
+            .await
+            .expect_err("registering an unknown pool should fail");
+
+        assert_eq!(
+            Some(&RunnerError::UnknownPool("unknown-pool".to_string())),
+            error.downcast_ref::<RunnerError>()
+        );
+    }
+
     #[tokio::test]
     async fn can_sign_signed_entity_type_when_signed_entity_type_is_locked() {
         let signed_entity_type = SignedEntityType::MithrilStakeDistribution(Epoch(9));
@@ -811,9 +1098,10 @@ mod tests {
             .await
             .unwrap();
 
+        let party_id = services.single_signer.get_party_id();
         let runner = init_runner(Some(services), None).await;
 
-        assert!(!runner.can_sign_current_epoch().await.unwrap());
+        assert!(!runner.can_sign_current_epoch(&party_id).await.unwrap());
     }
 
     #[tokio::test]
@@ -832,9 +1120,10 @@ mod tests {
             .await
             .unwrap();
 
+        let party_id = services.single_signer.get_party_id();
         let runner = init_runner(Some(services), None).await;
 
-        assert!(runner.can_sign_current_epoch().await.unwrap());
+        assert!(runner.can_sign_current_epoch(&party_id).await.unwrap());
     }
 
     #[tokio::test]
@@ -853,9 +1142,10 @@ mod tests {
             .await
             .unwrap();
 
+        let party_id = services.single_signer.get_party_id();
         let runner = init_runner(Some(services), None).await;
 
-        assert!(runner.can_sign_current_epoch().await.is_err());
+        assert!(runner.can_sign_current_epoch(&party_id).await.is_err());
     }
 
     #[tokio::test]
@@ -869,6 +1159,7 @@ mod tests {
         };
         services.epoch_service = Arc::new(RwLock::new(epoch_service));
 
+        let party_id = services.single_signer.get_party_id();
         let runner = init_runner(Some(services), None).await;
 
         assert!(runner
@@ -879,7 +1170,7 @@ mod tests {
             .unwrap()
             .is_none());
 
-        assert!(!runner.can_sign_current_epoch().await.unwrap());
+        assert!(!runner.can_sign_current_epoch(&party_id).await.unwrap());
     }
 
     #[tokio::test]
@@ -1026,7 +1317,11 @@ mod tests {
         runner.inform_epoch_settings(epoch_settings).await.unwrap();
 
         let single_signature = runner
-            .compute_single_signature(current_time_point.epoch, &message)
+            .compute_single_signature(
+                &signer_with_stake.party_id,
+                current_time_point.epoch,
+                &message,
+            )
             .await
             .expect("compute_message should not fail");
         assert_eq!(expected, single_signature);
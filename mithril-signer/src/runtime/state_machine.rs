@@ -4,7 +4,7 @@ use tokio::{sync::Mutex, time::sleep};
 
 use mithril_common::{
     crypto_helper::ProtocolInitializerError,
-    entities::{CertificatePending, Epoch, SignedEntityType, TimePoint},
+    entities::{CertificatePending, Epoch, PartyId, ProtocolMessage, SignedEntityType, TimePoint},
 };
 
 use crate::{entities::SignerEpochSettings, MetricsService};
@@ -351,14 +351,35 @@ impl StateMachine {
                 nested_error: Some(e),
             })?;
 
-        self.runner.register_signer_to_aggregator()
-            .await.map_err(|e| {
-            if e.downcast_ref::<ProtocolInitializerError>().is_some() {
-                RuntimeError::Critical { message: format!("Could not register to aggregator in 'unregistered → registered' phase for epoch {:?}.", epoch), nested_error: Some(e) }
-            } else {
-                RuntimeError::KeepState { message: format!("Could not register to aggregator in 'unregistered → registered' phase for epoch {:?}.", epoch), nested_error: Some(e) }
+        let registration_error_message = format!(
+            "Could not register to aggregator in 'unregistered → registered' phase for epoch {:?}.",
+            epoch
+        );
+        // Every pool must be registered before leaving the state, the registration of all the
+        // pools is retried on the next cycle otherwise.
+        let party_ids = self.runner.get_party_ids();
+        let mut registration_error = None;
+        for party_id in &party_ids {
+            match self.runner.register_signer_to_aggregator(party_id).await {
+                Ok(()) => {}
+                Err(e) if e.downcast_ref::<ProtocolInitializerError>().is_some() => {
+                    return Err(RuntimeError::Critical {
+                        message: registration_error_message,
+                        nested_error: Some(e),
+                    });
+                }
+                Err(e) => {
+                    error!(" > could not register pool to aggregator"; "party_id" => party_id, "error" => ?e);
+                    registration_error.get_or_insert(e);
+                }
             }
-        })?;
+        }
+        if let Some(e) = registration_error {
+            return Err(RuntimeError::KeepState {
+                message: registration_error_message,
+                nested_error: Some(e),
+            });
+        }
 
         self.metrics_service
             .signer_registration_success_since_startup_counter_increment();
@@ -373,17 +394,24 @@ impl StateMachine {
                 nested_error: Some(e),
             })?;
 
-        match self
-            .runner
-            .can_sign_current_epoch()
-            .await
-            .map_err(|e| RuntimeError::KeepState {
-                message: "Failed to check if signer can sign in the current epoch in 'unregistered → ?' phase".to_string(),
-                nested_error: Some(e),
-            })? {
-            true => Ok(SignerState::ReadyToSign { epoch, last_signed_entity_type: None }),
-            false => Ok(SignerState::RegisteredNotAbleToSign { epoch }),
+        for party_id in &party_ids {
+            if self
+                .runner
+                .can_sign_current_epoch(party_id)
+                .await
+                .map_err(|e| RuntimeError::KeepState {
+                    message: "Failed to check if signer can sign in the current epoch in 'unregistered → ?' phase".to_string(),
+                    nested_error: Some(e),
+                })?
+            {
+                return Ok(SignerState::ReadyToSign {
+                    epoch,
+                    last_signed_entity_type: None,
+                });
+            }
         }
+
+        Ok(SignerState::RegisteredNotAbleToSign { epoch })
     }
 
     async fn transition_from_registered_not_able_to_sign_to_unregistered(
@@ -436,29 +464,83 @@ impl StateMachine {
                 nested_error: Some(e)
             })?;
 
+        // The signed entity type is only marked as signed once every pool able to sign has sent
+        // its signature, it is signed again on the next cycle otherwise.
+        let mut has_any_pool_sent_signature = false;
+        let mut signature_error = None;
+        for party_id in self.runner.get_party_ids() {
+            match self
+                .sign_and_send_single_signature(
+                    &party_id,
+                    current_epoch,
+                    signed_entity_type,
+                    &message,
+                )
+                .await
+            {
+                Ok(is_signature_sent) => has_any_pool_sent_signature |= is_signature_sent,
+                Err(e) => {
+                    error!(" > could not sign for pool"; "party_id" => &party_id, "error" => ?e);
+                    signature_error.get_or_insert(e);
+                }
+            }
+        }
+        if let Some(e) = signature_error {
+            return Err(e);
+        }
+
+        if has_any_pool_sent_signature {
+            self.metrics_service
+                .signature_registration_success_since_startup_counter_increment();
+            self.metrics_service
+                .signature_registration_success_last_epoch_gauge_set(current_epoch);
+        }
+
+        Ok(SignerState::ReadyToSign {
+            epoch: current_epoch,
+            last_signed_entity_type: Some(signed_entity_type.to_owned()),
+        })
+    }
+
+    /// Compute and send the single signature of the given pool, if it can sign in the current epoch.
+    ///
+    /// Returns `true` if a signature was sent.
+    async fn sign_and_send_single_signature(
+        &self,
+        party_id: &PartyId,
+        current_epoch: Epoch,
+        signed_entity_type: &SignedEntityType,
+        message: &ProtocolMessage,
+    ) -> Result<bool, RuntimeError> {
+        if !self
+            .runner
+            .can_sign_current_epoch(party_id)
+            .await
+            .map_err(|e| RuntimeError::KeepState {
+                message: format!("Failed to check if signer can sign during 'ready to sign → ready to sign' phase (current epoch {current_epoch:?})"),
+                nested_error: Some(e)
+            })?
+        {
+            debug!(" > pool can NOT sign in the current epoch"; "party_id" => party_id);
+            return Ok(false);
+        }
+
         let single_signatures = self
             .runner
-            .compute_single_signature(current_epoch, &message)
+            .compute_single_signature(party_id, current_epoch, message)
             .await
             .map_err(|e| RuntimeError::KeepState {
                 message: format!("Could not compute single signature during 'ready to sign → ready to sign' phase (current epoch {current_epoch:?})"),
                 nested_error: Some(e)
             })?;
-        self.runner.send_single_signature(signed_entity_type, single_signatures, &message).await
+        let is_signature_sent = single_signatures.is_some();
+        self.runner.send_single_signature(signed_entity_type, single_signatures, message).await
             .map_err(|e| RuntimeError::KeepState {
                 message: format!("Could not send single signature during 'ready to sign → ready to sign' phase (current epoch {current_epoch:?})"),
                 nested_error: Some(e)
             })?;
 
-        self.metrics_service
-            .signature_registration_success_since_startup_counter_increment();
-        self.metrics_service
-            .signature_registration_success_last_epoch_gauge_set(current_epoch);
-
-        Ok(SignerState::ReadyToSign {
-            epoch: current_epoch,
-            last_signed_entity_type: Some(signed_entity_type.to_owned()),
-        })
+        Ok(is_signature_sent)
     }
 
     async fn get_current_time_point(&self, context: &str) -> Result<TimePoint, RuntimeError> {
@@ -598,15 +680,19 @@ mod tests {
             .expect_update_stake_distribution()
             .once()
             .returning(|_| Ok(()));
+        runner
+            .expect_get_party_ids()
+            .once()
+            .returning(|| vec!["pool-1".to_string()]);
         runner
             .expect_register_signer_to_aggregator()
             .once()
-            .returning(|| Ok(()));
+            .returning(|_| Ok(()));
 
         runner
             .expect_can_sign_current_epoch()
             .once()
-            .returning(|| Ok(false));
+            .returning(|_| Ok(false));
 
         let state_machine = init_state_machine(
             SignerState::Unregistered {
@@ -652,15 +738,19 @@ mod tests {
             .expect_update_stake_distribution()
             .once()
             .returning(|_| Ok(()));
+        runner
+            .expect_get_party_ids()
+            .once()
+            .returning(|| vec!["pool-1".to_string()]);
         runner
             .expect_register_signer_to_aggregator()
             .once()
-            .returning(|| Ok(()));
+            .returning(|_| Ok(()));
 
         runner
             .expect_can_sign_current_epoch()
             .once()
-            .returning(|| Ok(true));
+            .returning(|_| Ok(true));
 
         let state_machine = init_state_machine(
             SignerState::Unregistered {
@@ -683,6 +773,343 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn unregistered_stays_unregistered_when_some_pools_could_not_register() {
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_get_epoch_settings()
+            .once()
+            .returning(|| Ok(Some(SignerEpochSettings::dummy())));
+        runner
+            .expect_inform_epoch_settings()
+            .once()
+            .returning(|_| Ok(()));
+        runner
+            .expect_get_current_time_point()
+            .times(2)
+            .returning(|| Ok(TimePoint::dummy()));
+        runner
+            .expect_update_stake_distribution()
+            .once()
+            .returning(|_| Ok(()));
+        runner
+            .expect_get_party_ids()
+            .once()
+            .returning(|| vec!["pool-1".to_string(), "pool-2".to_string()]);
+        runner
+            .expect_register_signer_to_aggregator()
+            .with(predicate::eq("pool-1".to_string()))
+            .once()
+            .returning(|_| Err(anyhow::anyhow!("pool-1 has no stake")));
+        runner
+            .expect_register_signer_to_aggregator()
+            .with(predicate::eq("pool-2".to_string()))
+            .once()
+            .returning(|_| Ok(()));
+
+        let state_machine = init_state_machine(
+            SignerState::Unregistered {
+                epoch: TimePoint::dummy().epoch,
+            },
+            runner,
+        );
+
+        let error = state_machine
+            .cycle()
+            .await
+            .expect_err("Cycling the state machine should fail");
+
+        assert!(!error.is_critical());
+        assert_eq!(
+            SignerState::Unregistered {
+                epoch: TimePoint::dummy().epoch,
+            },
+            state_machine.get_state().await
+        );
+        assert_eq!(
+            0,
+            state_machine
+                .metrics_service
+                .signer_registration_success_since_startup_counter_get()
+        );
+    }
+
+    #[tokio::test]
+    async fn unregistered_stays_unregistered_when_no_pool_could_register() {
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_get_epoch_settings()
+            .once()
+            .returning(|| Ok(Some(SignerEpochSettings::dummy())));
+        runner
+            .expect_inform_epoch_settings()
+            .once()
+            .returning(|_| Ok(()));
+        runner
+            .expect_get_current_time_point()
+            .times(2)
+            .returning(|| Ok(TimePoint::dummy()));
+        runner
+            .expect_update_stake_distribution()
+            .once()
+            .returning(|_| Ok(()));
+        runner
+            .expect_get_party_ids()
+            .once()
+            .returning(|| vec!["pool-1".to_string(), "pool-2".to_string()]);
+        runner
+            .expect_register_signer_to_aggregator()
+            .times(2)
+            .returning(|_| Err(anyhow::anyhow!("no stake")));
+
+        let state_machine = init_state_machine(
+            SignerState::Unregistered {
+                epoch: TimePoint::dummy().epoch,
+            },
+            runner,
+        );
+
+        let error = state_machine
+            .cycle()
+            .await
+            .expect_err("Cycling the state machine should fail");
+
+        assert!(!error.is_critical());
+        assert_eq!(
+            SignerState::Unregistered {
+                epoch: TimePoint::dummy().epoch,
+            },
+            state_machine.get_state().await
+        );
+    }
+
+    #[tokio::test]
+    async fn ready_to_sign_to_ready_to_sign_signs_for_each_pool_able_to_sign() {
+        let time_point = TimePoint::dummy();
+        let certificate_pending = CertificatePending {
+            epoch: time_point.epoch,
+            signed_entity_type: SignedEntityType::MithrilStakeDistribution(time_point.epoch),
+            ..fake_data::certificate_pending()
+        };
+        let signed_entity_type = certificate_pending.signed_entity_type.clone();
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_get_current_time_point()
+            .once()
+            .returning(move || Ok(time_point.to_owned()));
+        runner
+            .expect_get_pending_certificate()
+            .once()
+            .returning(move || Ok(Some(certificate_pending.clone())));
+        runner
+            .expect_can_sign_signed_entity_type()
+            .once()
+            .returning(|_| true);
+        runner
+            .expect_compute_message()
+            .once()
+            .returning(|_| Ok(ProtocolMessage::new()));
+        runner.expect_get_party_ids().once().returning(|| {
+            vec![
+                "pool-1".to_string(),
+                "pool-2".to_string(),
+                "pool-3".to_string(),
+            ]
+        });
+        runner
+            .expect_can_sign_current_epoch()
+            .times(3)
+            .returning(|party_id| Ok(party_id != "pool-2"));
+        runner
+            .expect_compute_single_signature()
+            .times(2)
+            .returning(|party_id, _, _| {
+                assert_ne!("pool-2", party_id);
+                Ok(Some(fake_data::single_signatures(vec![1, 5, 23])))
+            });
+        runner
+            .expect_send_single_signature()
+            .times(2)
+            .returning(|_, _, _| Ok(()));
+
+        let state_machine = init_state_machine(
+            SignerState::ReadyToSign {
+                epoch: TimePoint::dummy().epoch,
+                last_signed_entity_type: None,
+            },
+            runner,
+        );
+
+        state_machine
+            .cycle()
+            .await
+            .expect("Cycling the state machine should not fail");
+
+        assert_eq!(
+            SignerState::ReadyToSign {
+                epoch: TimePoint::dummy().epoch,
+                last_signed_entity_type: Some(signed_entity_type),
+            },
+            state_machine.get_state().await
+        );
+        assert_eq!(
+            1,
+            state_machine
+                .metrics_service
+                .signature_registration_success_since_startup_counter_get()
+        );
+    }
+
+    #[tokio::test]
+    async fn ready_to_sign_stays_on_the_signed_entity_type_when_a_pool_could_not_send_its_signature(
+    ) {
+        let time_point = TimePoint::dummy();
+        let certificate_pending = CertificatePending {
+            epoch: time_point.epoch,
+            signed_entity_type: SignedEntityType::MithrilStakeDistribution(time_point.epoch),
+            ..fake_data::certificate_pending()
+        };
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_get_current_time_point()
+            .once()
+            .returning(move || Ok(time_point.to_owned()));
+        runner
+            .expect_get_pending_certificate()
+            .once()
+            .returning(move || Ok(Some(certificate_pending.clone())));
+        runner
+            .expect_can_sign_signed_entity_type()
+            .once()
+            .returning(|_| true);
+        runner
+            .expect_compute_message()
+            .once()
+            .returning(|_| Ok(ProtocolMessage::new()));
+        runner
+            .expect_get_party_ids()
+            .once()
+            .returning(|| vec!["pool-1".to_string(), "pool-2".to_string()]);
+        runner
+            .expect_can_sign_current_epoch()
+            .times(2)
+            .returning(|_| Ok(true));
+        runner
+            .expect_compute_single_signature()
+            .times(2)
+            .returning(|_, _, _| Ok(Some(fake_data::single_signatures(vec![1, 5, 23]))));
+        let mut sent_signatures_count = 0;
+        runner
+            .expect_send_single_signature()
+            .times(2)
+            .returning(move |_, _, _| {
+                sent_signatures_count += 1;
+                match sent_signatures_count {
+                    1 => Ok(()),
+                    _ => Err(anyhow::anyhow!("aggregator unreachable")),
+                }
+            });
+
+        let state_machine = init_state_machine(
+            SignerState::ReadyToSign {
+                epoch: TimePoint::dummy().epoch,
+                last_signed_entity_type: None,
+            },
+            runner,
+        );
+
+        let error = state_machine
+            .cycle()
+            .await
+            .expect_err("Cycling the state machine should fail");
+
+        assert!(!error.is_critical());
+        assert_eq!(
+            SignerState::ReadyToSign {
+                epoch: TimePoint::dummy().epoch,
+                last_signed_entity_type: None,
+            },
+            state_machine.get_state().await
+        );
+        assert_eq!(
+            0,
+            state_machine
+                .metrics_service
+                .signature_registration_success_since_startup_counter_get()
+        );
+    }
+
+    #[tokio::test]
+    async fn ready_to_sign_does_not_count_a_successful_signature_when_no_pool_sent_one() {
+        let time_point = TimePoint::dummy();
+        let certificate_pending = CertificatePending {
+            epoch: time_point.epoch,
+            signed_entity_type: SignedEntityType::MithrilStakeDistribution(time_point.epoch),
+            ..fake_data::certificate_pending()
+        };
+        let signed_entity_type = certificate_pending.signed_entity_type.clone();
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_get_current_time_point()
+            .once()
+            .returning(move || Ok(time_point.to_owned()));
+        runner
+            .expect_get_pending_certificate()
+            .once()
+            .returning(move || Ok(Some(certificate_pending.clone())));
+        runner
+            .expect_can_sign_signed_entity_type()
+            .once()
+            .returning(|_| true);
+        runner
+            .expect_compute_message()
+            .once()
+            .returning(|_| Ok(ProtocolMessage::new()));
+        runner
+            .expect_get_party_ids()
+            .once()
+            .returning(|| vec!["pool-1".to_string()]);
+        runner
+            .expect_can_sign_current_epoch()
+            .once()
+            .returning(|_| Ok(true));
+        runner
+            .expect_compute_single_signature()
+            .once()
+            .returning(|_, _, _| Ok(None));
+        runner
+            .expect_send_single_signature()
+            .once()
+            .returning(|_, _, _| Ok(()));
+
+        let state_machine = init_state_machine(
+            SignerState::ReadyToSign {
+                epoch: TimePoint::dummy().epoch,
+                last_signed_entity_type: None,
+            },
+            runner,
+        );
+
+        state_machine
+            .cycle()
+            .await
+            .expect("Cycling the state machine should not fail");
+
+        assert_eq!(
+            SignerState::ReadyToSign {
+                epoch: TimePoint::dummy().epoch,
+                last_signed_entity_type: Some(signed_entity_type),
+            },
+            state_machine.get_state().await
+        );
+        assert_eq!(
+            0,
+            state_machine
+                .metrics_service
+                .signature_registration_success_since_startup_counter_get()
+        );
+    }
+
     #[tokio::test]
     async fn registered_not_able_to_sign_to_unregistered() {
         let mut runner = MockSignerRunner::new();
@@ -943,10 +1370,18 @@ mod tests {
             .expect_get_pending_certificate()
             .once()
             .returning(move || Ok(Some(certificate_pending.clone())));
+        runner
+            .expect_get_party_ids()
+            .once()
+            .returning(|| vec!["pool-1".to_string()]);
+        runner
+            .expect_can_sign_current_epoch()
+            .once()
+            .returning(|_| Ok(true));
         runner
             .expect_compute_single_signature()
             .once()
-            .returning(|_, _| Ok(Some(fake_data::single_signatures(vec![1, 5, 23]))));
+            .returning(|_, _, _| Ok(Some(fake_data::single_signatures(vec![1, 5, 23]))));
         runner
             .expect_compute_message()
             .once()
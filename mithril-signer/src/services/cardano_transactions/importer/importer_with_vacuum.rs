@@ -6,11 +6,11 @@ use slog::{debug, Logger};
 use mithril_common::entities::BlockNumber;
 use mithril_common::signable_builder::TransactionsImporter;
 use mithril_common::StdResult;
-use mithril_persistence::sqlite::{SqliteCleaner, SqliteCleaningTask, SqliteConnectionPool};
+use mithril_persistence::sqlite::{DatabaseConnectionPool, SqliteCleaner, SqliteCleaningTask};
 
 /// A decorator of [TransactionsImporter] that vacuums the database after running the import.
 pub struct TransactionsImporterWithVacuum {
-    connection_pool: Arc<SqliteConnectionPool>,
+    connection_pool: Arc<DatabaseConnectionPool>,
     wrapped_importer: Arc<dyn TransactionsImporter>,
     logger: Logger,
 }
@@ -18,7 +18,7 @@ pub struct TransactionsImporterWithVacuum {
 impl TransactionsImporterWithVacuum {
     /// Create a new instance of [TransactionsImporterWithVacuum].
     pub fn new(
-        connection_pool: Arc<SqliteConnectionPool>,
+        connection_pool: Arc<DatabaseConnectionPool>,
         wrapped_importer: Arc<dyn TransactionsImporter>,
         logger: Logger,
     ) -> Self {
@@ -35,15 +35,16 @@ impl TransactionsImporter for TransactionsImporterWithVacuum {
     async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
         self.wrapped_importer.import(up_to_beacon).await?;
 
-        debug!(
-            self.logger,
-            "Transaction Import finished - Vacuuming database to reclaim disk space"
-        );
         let connection = self.connection_pool.connection()?;
-
-        SqliteCleaner::new(&connection)
-            .with_tasks(&[SqliteCleaningTask::Vacuum])
-            .run()?;
+        if let Some(connection) = connection.as_sqlite() {
+            debug!(
+                self.logger,
+                "Transaction Import finished - Vacuuming database to reclaim disk space"
+            );
+            SqliteCleaner::new(connection)
+                .with_tasks(&[SqliteCleaningTask::Vacuum])
+                .run()?;
+        }
 
         Ok(())
     }
@@ -72,7 +73,7 @@ mod tests {
 
     impl TransactionsImporterWithVacuum {
         pub fn new_with_mock<I>(
-            connection_pool: Arc<SqliteConnectionPool>,
+            connection_pool: Arc<DatabaseConnectionPool>,
             importer_mock_config: I,
         ) -> Self
         where
@@ -114,7 +115,7 @@ mod tests {
         mangle_db(&connection);
 
         let importer = TransactionsImporterWithVacuum::new_with_mock(
-            Arc::new(SqliteConnectionPool::build_from_connection(connection)),
+            Arc::new(DatabaseConnectionPool::build_from_connection(connection)),
             |mock| {
                 mock.expect_import().once().returning(|_| Ok(()));
             },
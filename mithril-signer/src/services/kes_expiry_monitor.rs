@@ -0,0 +1,508 @@
+//! ## KES Expiry Monitor
+//!
+//! This service tracks the evolutions of the KES keys of the pools operated by the signer.
+//!
+//! It is in charge of the following tasks:
+//! * reload the KES secret key and the operational certificate of a pool each time it registers,
+//!   so a rotated key is registered at the next epoch without restarting the signer
+//! * compute the number of KES periods left before the expiry of the KES key, report it as a
+//!   metric and warn when it goes below the configured thresholds
+//! * check periodically the keys of all the pools, so a rotated key or an approaching expiry is
+//!   noticed between two registrations
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use sha2::{Digest, Sha256};
+use slog::{crit, info, warn, Logger};
+use tokio::sync::Mutex;
+
+use mithril_common::chain_observer::ChainObserver;
+use mithril_common::crypto_helper::{KESPeriod, OpCert, SerDeShelleyFileFormat, Sum6KesBytes};
+use mithril_common::entities::PartyId;
+use mithril_common::StdResult;
+
+use crate::MetricsService;
+
+/// Number of KES periods before the expiry of a KES key from which the signer warns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KesExpiryThresholds {
+    /// Threshold from which a warning is emitted
+    pub warning: KESPeriod,
+
+    /// Threshold from which the expiry is reported as critical
+    pub critical: KESPeriod,
+}
+
+impl KesExpiryThresholds {
+    /// Create the thresholds, the critical threshold must not be greater than the warning one.
+    pub fn new(warning: KESPeriod, critical: KESPeriod) -> StdResult<Self> {
+        if critical > warning {
+            return Err(anyhow!(
+                "The KES expiry critical threshold ({critical}) must not be greater than the warning threshold ({warning})"
+            ));
+        }
+
+        Ok(Self { warning, critical })
+    }
+}
+
+/// Paths of the key files of a pool checked by the monitor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolKeysPaths {
+    /// Party id of the pool
+    pub party_id: PartyId,
+
+    /// File path to the operational certificate of the pool
+    pub operational_certificate_path: PathBuf,
+
+    /// File path to the KES secret key of the pool, `None` if it is held by a remote KES signer
+    pub kes_secret_key_path: Option<PathBuf>,
+}
+
+/// Urgency of the rotation of a KES key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KesExpiryLevel {
+    /// The KES key is far from its expiry
+    Healthy,
+    /// The KES key expires soon and should be rotated
+    Warning,
+    /// The KES key is about to expire and must be rotated
+    Critical,
+    /// The KES key has expired, the pool can not register anymore
+    Expired,
+}
+
+/// KES evolution of the operational certificate of a pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KesExpiryStatus {
+    /// Party id of the pool
+    pub party_id: PartyId,
+
+    /// Number of KES periods before the expiry of the KES key
+    pub kes_periods_before_expiry: KESPeriod,
+
+    /// Urgency of the rotation of the KES key
+    pub level: KesExpiryLevel,
+}
+
+impl KesExpiryStatus {
+    /// Compute the KES expiry status of an operational certificate at the given KES period.
+    pub fn compute(
+        party_id: PartyId,
+        operational_certificate: &OpCert,
+        current_kes_period: KESPeriod,
+        max_kes_evolutions: KESPeriod,
+        thresholds: KesExpiryThresholds,
+    ) -> Self {
+        let expiry_kes_period =
+            operational_certificate.start_kes_period as KESPeriod + max_kes_evolutions;
+        let kes_periods_before_expiry = expiry_kes_period.saturating_sub(current_kes_period);
+        let level = match kes_periods_before_expiry {
+            0 => KesExpiryLevel::Expired,
+            n if n <= thresholds.critical => KesExpiryLevel::Critical,
+            n if n <= thresholds.warning => KesExpiryLevel::Warning,
+            _ => KesExpiryLevel::Healthy,
+        };
+
+        Self {
+            party_id,
+            kes_periods_before_expiry,
+            level,
+        }
+    }
+}
+
+/// Monitor of the KES keys of the pools operated by the signer
+pub struct KesExpiryMonitor {
+    thresholds: KesExpiryThresholds,
+    metrics_service: Arc<MetricsService>,
+    keys_fingerprints: Mutex<HashMap<PartyId, String>>,
+    logger: Logger,
+}
+
+impl KesExpiryMonitor {
+    /// Create a new instance of the KES expiry monitor.
+    pub fn new(
+        thresholds: KesExpiryThresholds,
+        metrics_service: Arc<MetricsService>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            thresholds,
+            metrics_service,
+            keys_fingerprints: Mutex::new(HashMap::new()),
+            logger,
+        }
+    }
+
+    /// Read the operational certificate and the KES secret key of a pool from their files.
+    ///
+    /// The files are checked before being used for a registration: the operational certificate
+    /// must belong to the pool and the KES secret key must be readable. A change of the files
+    /// since the previous reload is logged.
+    pub async fn reload_keys(
+        &self,
+        party_id: &PartyId,
+        operational_certificate_path: &Path,
+        kes_secret_key_path: Option<&Path>,
+    ) -> StdResult<OpCert> {
+        let operational_certificate = OpCert::from_file(operational_certificate_path)
+            .with_context(|| {
+                format!(
+                    "Could not decode the operational certificate of pool '{party_id}' from '{}'",
+                    operational_certificate_path.display()
+                )
+            })?;
+        let operational_certificate_party_id =
+            operational_certificate.compute_protocol_party_id()?;
+        if &operational_certificate_party_id != party_id {
+            return Err(anyhow!(
+                "The operational certificate '{}' belongs to pool '{operational_certificate_party_id}' instead of pool '{party_id}'",
+                operational_certificate_path.display()
+            ));
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(operational_certificate.compute_hash());
+        if let Some(kes_secret_key_path) = kes_secret_key_path {
+            let kes_secret_key =
+                Sum6KesBytes::from_file(kes_secret_key_path).with_context(|| {
+                    format!(
+                        "Could not decode the KES secret key of pool '{party_id}' from '{}'",
+                        kes_secret_key_path.display()
+                    )
+                })?;
+            hasher.update(kes_secret_key.0);
+        }
+        let fingerprint = hex::encode(hasher.finalize());
+
+        let mut keys_fingerprints = self.keys_fingerprints.lock().await;
+        match keys_fingerprints.insert(party_id.to_owned(), fingerprint.clone()) {
+            Some(previous_fingerprint) if previous_fingerprint != fingerprint => {
+                info!(
+                    self.logger, "KES secret key or operational certificate changed, registering the new keys";
+                    "party_id" => party_id,
+                    "start_kes_period" => operational_certificate.start_kes_period,
+                );
+            }
+            _ => {}
+        }
+
+        Ok(operational_certificate)
+    }
+
+    /// Compute the KES expiry status of a pool, report it as a metric and warn if the KES key
+    /// should be rotated.
+    pub fn check_expiry(
+        &self,
+        party_id: &PartyId,
+        operational_certificate: &OpCert,
+        current_kes_period: KESPeriod,
+        max_kes_evolutions: KESPeriod,
+    ) -> KesExpiryStatus {
+        let status = KesExpiryStatus::compute(
+            party_id.to_owned(),
+            operational_certificate,
+            current_kes_period,
+            max_kes_evolutions,
+            self.thresholds,
+        );
+        self.metrics_service
+            .kes_periods_before_expiry_gauge_set(party_id, status.kes_periods_before_expiry);
+
+        match status.level {
+            KesExpiryLevel::Healthy => {}
+            KesExpiryLevel::Warning => {
+                warn!(
+                    self.logger, "KES key expires soon, it should be rotated";
+                    "party_id" => party_id,
+                    "kes_periods_before_expiry" => status.kes_periods_before_expiry,
+                );
+            }
+            KesExpiryLevel::Critical => {
+                crit!(
+                    self.logger, "KES key is about to expire, it must be rotated";
+                    "party_id" => party_id,
+                    "kes_periods_before_expiry" => status.kes_periods_before_expiry,
+                );
+            }
+            KesExpiryLevel::Expired => {
+                crit!(
+                    self.logger, "KES key has expired, the pool can not register until it is rotated";
+                    "party_id" => party_id,
+                );
+            }
+        }
+
+        status
+    }
+
+    /// Reload the keys of each pool and check their expiry.
+    ///
+    /// The errors are logged: the keys of a pool that can't be read don't prevent the check of
+    /// the other pools.
+    pub async fn check_pools_keys(
+        &self,
+        pools_keys_paths: &[PoolKeysPaths],
+        chain_observer: &dyn ChainObserver,
+    ) {
+        for pool_keys_paths in pools_keys_paths {
+            if let Err(error) = self.check_pool_keys(pool_keys_paths, chain_observer).await {
+                warn!(
+                    self.logger, "Could not check the KES key of the pool";
+                    "party_id" => &pool_keys_paths.party_id,
+                    "error" => ?error,
+                );
+            }
+        }
+    }
+
+    async fn check_pool_keys(
+        &self,
+        pool_keys_paths: &PoolKeysPaths,
+        chain_observer: &dyn ChainObserver,
+    ) -> StdResult<Option<KesExpiryStatus>> {
+        let operational_certificate = self
+            .reload_keys(
+                &pool_keys_paths.party_id,
+                &pool_keys_paths.operational_certificate_path,
+                pool_keys_paths.kes_secret_key_path.as_deref(),
+            )
+            .await?;
+        let current_kes_period = chain_observer
+            .get_current_kes_period(&operational_certificate)
+            .await?
+            .unwrap_or_default();
+        let Some(max_kes_evolutions) = chain_observer
+            .get_max_kes_evolutions(&operational_certificate)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(self.check_expiry(
+            &pool_keys_paths.party_id,
+            &operational_certificate,
+            current_kes_period,
+            max_kes_evolutions,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use mithril_common::chain_observer::FakeObserver;
+    use mithril_common::crypto_helper::tests_setup::setup_temp_directory_for_signer;
+    use mithril_common::test_utils::{MithrilFixtureBuilder, StakeDistributionGenerationMethod};
+
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    const THRESHOLDS: KesExpiryThresholds = KesExpiryThresholds {
+        warning: 10,
+        critical: 3,
+    };
+
+    /// Party id with the paths of its operational certificate and KES secret key.
+    fn pool_keys() -> (PartyId, PathBuf, PathBuf) {
+        let fixture = MithrilFixtureBuilder::default()
+            .with_signers(1)
+            .with_stake_distribution(StakeDistributionGenerationMethod::Uniform(100))
+            .build();
+        let signer = &fixture.signers_fixture()[0];
+        let party_id = signer.party_id();
+        let keys_dir = setup_temp_directory_for_signer(&party_id, false)
+            .expect("the keys of a certified pool should be generated");
+
+        (
+            party_id,
+            keys_dir.join("opcert.cert"),
+            keys_dir.join("kes.sk"),
+        )
+    }
+
+    fn build_monitor() -> KesExpiryMonitor {
+        KesExpiryMonitor::new(
+            THRESHOLDS,
+            Arc::new(MetricsService::new().unwrap()),
+            TestLogger::stdout(),
+        )
+    }
+
+    #[test]
+    fn compute_kes_expiry_status_levels() {
+        let (party_id, opcert_path, _) = pool_keys();
+        let opcert = OpCert::from_file(opcert_path).unwrap();
+        let expiry_kes_period = opcert.start_kes_period as KESPeriod + 62;
+        let level_at = |current_kes_period: KESPeriod| {
+            KesExpiryStatus::compute(
+                party_id.clone(),
+                &opcert,
+                current_kes_period,
+                62,
+                THRESHOLDS,
+            )
+            .level
+        };
+
+        assert_eq!(KesExpiryLevel::Healthy, level_at(expiry_kes_period - 11));
+        assert_eq!(KesExpiryLevel::Warning, level_at(expiry_kes_period - 10));
+        assert_eq!(KesExpiryLevel::Warning, level_at(expiry_kes_period - 4));
+        assert_eq!(KesExpiryLevel::Critical, level_at(expiry_kes_period - 3));
+        assert_eq!(KesExpiryLevel::Critical, level_at(expiry_kes_period - 1));
+        assert_eq!(KesExpiryLevel::Expired, level_at(expiry_kes_period));
+        assert_eq!(KesExpiryLevel::Expired, level_at(expiry_kes_period + 5));
+    }
+
+    #[test]
+    fn kes_expiry_thresholds_reject_a_critical_threshold_greater_than_the_warning_one() {
+        assert_eq!(
+            KesExpiryThresholds {
+                warning: 10,
+                critical: 3
+            },
+            KesExpiryThresholds::new(10, 3).unwrap()
+        );
+        KesExpiryThresholds::new(10, 10).unwrap();
+
+        KesExpiryThresholds::new(3, 10)
+            .expect_err("a critical threshold greater than the warning one should be rejected");
+    }
+
+    #[test]
+    fn check_expiry_sets_the_kes_periods_before_expiry_metric() {
+        let (party_id, opcert_path, _) = pool_keys();
+        let opcert = OpCert::from_file(opcert_path).unwrap();
+        let monitor = build_monitor();
+
+        let status = monitor.check_expiry(&party_id, &opcert, opcert.start_kes_period as u32, 62);
+
+        assert_eq!(62, status.kes_periods_before_expiry);
+        assert_eq!(KesExpiryLevel::Healthy, status.level);
+        assert_eq!(
+            62,
+            monitor
+                .metrics_service
+                .kes_periods_before_expiry_gauge_get(&party_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn reload_keys_returns_the_operational_certificate_of_the_pool() {
+        let (party_id, opcert_path, kes_secret_key_path) = pool_keys();
+        let monitor = build_monitor();
+
+        let opcert = monitor
+            .reload_keys(&party_id, &opcert_path, Some(&kes_secret_key_path))
+            .await
+            .unwrap();
+
+        assert_eq!(OpCert::from_file(opcert_path).unwrap(), opcert);
+    }
+
+    #[tokio::test]
+    async fn reload_keys_fails_if_the_operational_certificate_belongs_to_another_pool() {
+        let (_, opcert_path, kes_secret_key_path) = pool_keys();
+        let monitor = build_monitor();
+
+        monitor
+            .reload_keys(
+                &"another-pool".to_string(),
+                &opcert_path,
+                Some(&kes_secret_key_path),
+            )
+            .await
+            .expect_err("reloading the keys of another pool should fail");
+    }
+
+    #[tokio::test]
+    async fn reload_keys_fails_if_the_kes_secret_key_is_unreadable() {
+        let (party_id, opcert_path, kes_secret_key_path) = pool_keys();
+        let monitor = build_monitor();
+        monitor
+            .reload_keys(&party_id, &opcert_path, Some(&kes_secret_key_path))
+            .await
+            .unwrap();
+
+        let truncated_kes_secret_key_path = kes_secret_key_path.with_file_name("truncated-kes.sk");
+        fs::write(&truncated_kes_secret_key_path, "{").unwrap();
+
+        monitor
+            .reload_keys(
+                &party_id,
+                &opcert_path,
+                Some(&truncated_kes_secret_key_path),
+            )
+            .await
+            .expect_err("reloading an unreadable KES secret key should fail");
+    }
+
+    #[tokio::test]
+    async fn reload_keys_tracks_the_keys_of_each_pool() {
+        let (party_id, opcert_path, kes_secret_key_path) = pool_keys();
+        let monitor = build_monitor();
+
+        monitor
+            .reload_keys(&party_id, &opcert_path, Some(&kes_secret_key_path))
+            .await
+            .unwrap();
+        let first_fingerprint = monitor.keys_fingerprints.lock().await[&party_id].clone();
+        monitor
+            .reload_keys(&party_id, &opcert_path, None)
+            .await
+            .unwrap();
+
+        assert_ne!(
+            first_fingerprint,
+            monitor.keys_fingerprints.lock().await[&party_id]
+        );
+    }
+
+    #[tokio::test]
+    async fn check_pools_keys_reloads_the_keys_and_sets_the_metric_of_each_readable_pool() {
+        let (party_id, opcert_path, kes_secret_key_path) = pool_keys();
+        let opcert = OpCert::from_file(&opcert_path).unwrap();
+        let chain_observer = FakeObserver::default();
+        chain_observer
+            .set_current_kes_period(Some(opcert.start_kes_period as KESPeriod + 50))
+            .await;
+        let monitor = build_monitor();
+
+        monitor
+            .check_pools_keys(
+                &[
+                    PoolKeysPaths {
+                        party_id: "unreadable-pool".to_string(),
+                        operational_certificate_path: opcert_path.with_file_name("missing.cert"),
+                        kes_secret_key_path: None,
+                    },
+                    PoolKeysPaths {
+                        party_id: party_id.clone(),
+                        operational_certificate_path: opcert_path,
+                        kes_secret_key_path: Some(kes_secret_key_path),
+                    },
+                ],
+                &chain_observer,
+            )
+            .await;
+
+        assert!(monitor
+            .keys_fingerprints
+            .lock()
+            .await
+            .contains_key(&party_id));
+        assert_eq!(
+            12,
+            monitor
+                .metrics_service
+                .kes_periods_before_expiry_gauge_get(&party_id)
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //!
 //! * Aggregator Client: communicate with the Aggregator
 //! * Cardano Transactions: handle Cardano transactions (import, preload, etc.)
+//! * KES Expiry Monitor: track the expiry of the KES keys of the pools
 //! * Single Signer: create single signatures
 //! * Upkeep: perform maintenance tasks
 //!
@@ -12,6 +13,7 @@
 mod aggregator_client;
 mod cardano_transactions;
 mod epoch_service;
+mod kes_expiry_monitor;
 mod signable_builder;
 mod single_signer;
 mod upkeep_service;
@@ -21,6 +23,7 @@ pub use aggregator_client::dumb::DumbAggregatorClient;
 pub use aggregator_client::*;
 pub use cardano_transactions::*;
 pub use epoch_service::*;
+pub use kes_expiry_monitor::*;
 pub use signable_builder::*;
 pub use single_signer::*;
 pub use upkeep_service::*;
@@ -9,7 +9,8 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use mithril_common::{
-    entities::{ProtocolMessagePartValue, ProtocolParameters},
+    crypto_helper::ProtocolInitializer,
+    entities::{Epoch, ProtocolMessagePartValue, ProtocolParameters},
     signable_builder::SignableSeedBuilder,
     StdResult,
 };
@@ -23,22 +24,38 @@ use crate::{
 pub struct SignerSignableSeedBuilder {
     epoch_service: Arc<RwLock<dyn EpochService>>,
     single_signer: Arc<dyn SingleSigner>,
-    protocol_initializer_store: Arc<dyn ProtocolInitializerStorer>,
+    protocol_initializer_stores: Vec<Arc<dyn ProtocolInitializerStorer>>,
 }
 
 impl SignerSignableSeedBuilder {
     /// SignerSignableSeedBuilder factory
+    ///
+    /// The protocol initializer stores are the ones of the pools operated by the signer, the
+    /// first one holding a protocol initializer for an epoch is used.
     pub fn new(
         epoch_service: Arc<RwLock<dyn EpochService>>,
         single_signer: Arc<dyn SingleSigner>,
-        protocol_initializer_store: Arc<dyn ProtocolInitializerStorer>,
+        protocol_initializer_stores: Vec<Arc<dyn ProtocolInitializerStorer>>,
     ) -> Self {
         Self {
             epoch_service,
             single_signer,
-            protocol_initializer_store,
+            protocol_initializer_stores,
         }
     }
+
+    async fn get_protocol_initializer(&self, epoch: Epoch) -> StdResult<ProtocolInitializer> {
+        for protocol_initializer_store in &self.protocol_initializer_stores {
+            if let Some(protocol_initializer) = protocol_initializer_store
+                .get_protocol_initializer(epoch)
+                .await?
+            {
+                return Ok(protocol_initializer);
+            }
+        }
+
+        Err(anyhow!("can not get protocol_initializer at epoch {epoch}"))
+    }
 }
 
 #[async_trait]
@@ -48,12 +65,8 @@ impl SignableSeedBuilder for SignerSignableSeedBuilder {
         let epoch = (*epoch_service).epoch_of_current_data()?;
         let next_signer_retrieval_epoch = epoch.offset_to_next_signer_retrieval_epoch();
         let next_protocol_initializer = self
-            .protocol_initializer_store
             .get_protocol_initializer(next_signer_retrieval_epoch)
-            .await?
-            .ok_or_else(|| {
-                anyhow!("can not get protocol_initializer at epoch {next_signer_retrieval_epoch}")
-            })?;
+            .await?;
         let next_signers_with_stake = epoch_service.next_signers_with_stake().await?;
         let next_aggregate_verification_key = self
             .single_signer
@@ -71,12 +84,8 @@ impl SignableSeedBuilder for SignerSignableSeedBuilder {
         let epoch = (*epoch_service).epoch_of_current_data()?;
         let next_signer_retrieval_epoch = epoch.offset_to_next_signer_retrieval_epoch();
         let next_protocol_initializer = self
-            .protocol_initializer_store
             .get_protocol_initializer(next_signer_retrieval_epoch)
-            .await?
-            .ok_or_else(|| {
-                anyhow!("can not get protocol_initializer at epoch {next_signer_retrieval_epoch}")
-            })?;
+            .await?;
         let next_protocol_parameters: ProtocolParameters =
             next_protocol_initializer.get_protocol_parameters().into();
 
@@ -124,7 +133,7 @@ mod tests {
             SignerSignableSeedBuilder::new(
                 Arc::new(RwLock::new(self.mock_epoch_service)),
                 Arc::new(self.mock_single_signer),
-                Arc::new(self.mock_protocol_initializer_store),
+                vec![Arc::new(self.mock_protocol_initializer_store)],
             )
         }
     }
@@ -207,6 +216,50 @@ mod tests {
         assert_eq!(next_protocol_parameters, expected_next_protocol_parameters);
     }
 
+    #[tokio::test]
+    async fn test_compute_next_protocol_parameters_with_the_first_pool_having_a_protocol_initializer(
+    ) {
+        let epoch = Epoch(5);
+        let next_fixture = MithrilFixtureBuilder::default().with_signers(4).build();
+        let protocol_initializer = next_fixture.signers_fixture()[0]
+            .protocol_initializer
+            .clone();
+        let protocol_parameters: ProtocolParameters =
+            protocol_initializer.get_protocol_parameters().into();
+        let mut not_registered_pool_store = MockProtocolInitializerStorer::new();
+        not_registered_pool_store
+            .expect_get_protocol_initializer()
+            .return_once(|_| Ok(None))
+            .once();
+        let mut registered_pool_store = MockProtocolInitializerStorer::new();
+        registered_pool_store
+            .expect_get_protocol_initializer()
+            .return_once(move |_| Ok(Some(protocol_initializer)))
+            .once();
+        let signable_seed_builder = SignerSignableSeedBuilder::new(
+            Arc::new(RwLock::new(MockEpochServiceImpl::new_with_config(
+                |mock_epoch_service| {
+                    mock_epoch_service
+                        .expect_epoch_of_current_data()
+                        .return_once(move || Ok(epoch))
+                        .once();
+                },
+            ))),
+            Arc::new(MockSingleSigner::new()),
+            vec![
+                Arc::new(not_registered_pool_store),
+                Arc::new(registered_pool_store),
+            ],
+        );
+
+        let next_protocol_parameters = signable_seed_builder
+            .compute_next_protocol_parameters()
+            .await
+            .unwrap();
+
+        assert_eq!(next_protocol_parameters, protocol_parameters.compute_hash());
+    }
+
     #[tokio::test]
     async fn test_compute_current_epoch_protocol_message_value() {
         let epoch = Epoch(5);
@@ -1,10 +1,10 @@
 use anyhow::{anyhow, Context};
 use hex::ToHex;
 use slog_scope::{info, trace, warn};
-use std::path::PathBuf;
+use std::sync::Arc;
 use thiserror::Error;
 
-use mithril_common::crypto_helper::{KESPeriod, ProtocolInitializer};
+use mithril_common::crypto_helper::{KESPeriod, KesSigner, ProtocolInitializer};
 use mithril_common::entities::{
     PartyId, ProtocolMessage, ProtocolParameters, SignerWithStake, SingleSignatures, Stake,
 };
@@ -22,13 +22,13 @@ impl MithrilProtocolInitializerBuilder {
     pub fn build(
         stake: &Stake,
         protocol_parameters: &ProtocolParameters,
-        kes_secret_key_path: Option<PathBuf>,
+        kes_signer: Option<Arc<dyn KesSigner>>,
         kes_period: Option<KESPeriod>,
     ) -> StdResult<ProtocolInitializer> {
         let mut rng = rand_core::OsRng;
-        let protocol_initializer = ProtocolInitializer::setup(
+        let protocol_initializer = ProtocolInitializer::setup_with_kes_signer(
             protocol_parameters.to_owned().into(),
-            kes_secret_key_path,
+            kes_signer.as_deref(),
             kes_period,
             stake.to_owned(),
             &mut rng,
@@ -14,7 +14,7 @@ use slog::{info, Logger};
 use mithril_common::signed_entity_type_lock::SignedEntityTypeLock;
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{
-    SqliteCleaner, SqliteCleaningTask, SqliteConnection, SqliteConnectionPool,
+    DatabaseConnectionPool, SqliteCleaner, SqliteCleaningTask, SqliteConnection,
 };
 
 /// Define the service responsible for the upkeep of the application.
@@ -31,7 +31,7 @@ pub trait UpkeepService: Send + Sync {
 /// instead of relying on a connection pool or a shared connection.
 pub struct SignerUpkeepService {
     main_db_connection: Arc<SqliteConnection>,
-    cardano_tx_connection_pool: Arc<SqliteConnectionPool>,
+    cardano_tx_connection_pool: Arc<DatabaseConnectionPool>,
     signed_entity_type_lock: Arc<SignedEntityTypeLock>,
     logger: Logger,
 }
@@ -40,7 +40,7 @@ impl SignerUpkeepService {
     /// Create a new instance of the aggregator upkeep service.
     pub fn new(
         main_db_connection: Arc<SqliteConnection>,
-        cardano_tx_connection_pool: Arc<SqliteConnectionPool>,
+        cardano_tx_connection_pool: Arc<DatabaseConnectionPool>,
         signed_entity_type_lock: Arc<SignedEntityTypeLock>,
         logger: Logger,
     ) -> Self {
@@ -76,15 +76,17 @@ impl SignerUpkeepService {
                 ])
                 .run()?;
 
-            info!(
-                db_upkeep_logger,
-                "UpkeepService::Cleaning cardano transactions database"
-            );
             let cardano_tx_db_connection = cardano_tx_db_connection_pool.connection()?;
-            SqliteCleaner::new(&cardano_tx_db_connection)
-                .with_logger(db_upkeep_logger.clone())
-                .with_tasks(&[SqliteCleaningTask::WalCheckpointTruncate])
-                .run()?;
+            if let Some(cardano_tx_db_connection) = cardano_tx_db_connection.as_sqlite() {
+                info!(
+                    db_upkeep_logger,
+                    "UpkeepService::Cleaning cardano transactions database"
+                );
+                SqliteCleaner::new(cardano_tx_db_connection)
+                    .with_logger(db_upkeep_logger.clone())
+                    .with_tasks(&[SqliteCleaningTask::WalCheckpointTruncate])
+                    .run()?;
+            }
 
             Ok(())
         });
@@ -140,7 +142,7 @@ mod tests {
         {
             let service = SignerUpkeepService::new(
                 Arc::new(main_db_connection),
-                Arc::new(SqliteConnectionPool::build_from_connection(
+                Arc::new(DatabaseConnectionPool::build_from_connection(
                     cardano_tx_connection,
                 )),
                 Arc::new(SignedEntityTypeLock::default()),
@@ -183,7 +185,7 @@ mod tests {
         {
             let service = SignerUpkeepService::new(
                 Arc::new(main_db_connection().unwrap()),
-                Arc::new(SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap()),
+                Arc::new(DatabaseConnectionPool::build(1, cardano_tx_db_connection).unwrap()),
                 signed_entity_type_lock.clone(),
                 TestLogger::file(&log_path),
             );
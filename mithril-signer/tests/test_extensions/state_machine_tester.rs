@@ -14,8 +14,9 @@ use mithril_common::{
     },
     era::{adapters::EraReaderDummyAdapter, EraChecker, EraMarker, EraReader, SupportedEra},
     signable_builder::{
-        CardanoImmutableFilesFullSignableBuilder, CardanoStakeDistributionSignableBuilder,
-        CardanoTransactionsSignableBuilder, MithrilSignableBuilderService,
+        CardanoDRepStakeDistributionSignableBuilder, CardanoImmutableFilesFullSignableBuilder,
+        CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
+        ChainObserverDRepStakeDistributionRetriever, MithrilSignableBuilderService,
         MithrilStakeDistributionSignableBuilder,
     },
     signed_entity_type_lock::SignedEntityTypeLock,
@@ -24,15 +25,15 @@ use mithril_common::{
 use mithril_persistence::database::repository::CardanoTransactionRepository;
 use mithril_persistence::store::adapter::SQLiteAdapter;
 use mithril_persistence::{
-    sqlite::SqliteConnectionPool,
+    sqlite::DatabaseConnectionPool,
     store::{StakeStore, StakeStorer},
 };
 use mithril_signer::{
     dependency_injection::{DependenciesBuilder, SignerDependencyContainer},
     metrics::*,
     services::{
-        AggregatorClient, CardanoTransactionsImporter, MithrilEpochService, MithrilSingleSigner,
-        SignerSignableSeedBuilder, SignerUpkeepService,
+        AggregatorClient, CardanoTransactionsImporter, KesExpiryMonitor, KesExpiryThresholds,
+        MithrilEpochService, MithrilSingleSigner, SignerSignableSeedBuilder, SignerUpkeepService,
     },
     store::{MKTreeStoreSqlite, ProtocolInitializerStore, ProtocolInitializerStorer},
     Configuration, MetricsService, RuntimeError, SignerRunner, SignerState, StateMachine,
@@ -116,7 +117,7 @@ impl StateMachineTester {
             .await
             .unwrap();
         let sqlite_connection_cardano_transaction_pool = Arc::new(
-            SqliteConnectionPool::build_from_connection(transaction_sqlite_connection),
+            DatabaseConnectionPool::build_from_connection(transaction_sqlite_connection),
         );
 
         let decorator = slog_term::PlainDecorator::new(slog_term::TestStdoutWriter);
@@ -205,11 +206,15 @@ impl StateMachineTester {
         let cardano_stake_distribution_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
         );
+        let cardano_drep_stake_distribution_builder =
+            Arc::new(CardanoDRepStakeDistributionSignableBuilder::new(Arc::new(
+                ChainObserverDRepStakeDistributionRetriever::new(chain_observer.clone()),
+            )));
         let epoch_service = Arc::new(RwLock::new(MithrilEpochService::new(stake_store.clone())));
         let signable_seed_builder_service = Arc::new(SignerSignableSeedBuilder::new(
             epoch_service.clone(),
             single_signer.clone(),
-            protocol_initializer_store.clone(),
+            vec![protocol_initializer_store.clone()],
         ));
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             era_checker.clone(),
@@ -218,6 +223,7 @@ impl StateMachineTester {
             cardano_immutable_snapshot_builder,
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
+            cardano_drep_stake_distribution_builder,
         ));
         let metrics_service = Arc::new(MetricsService::new().unwrap());
         let expected_metrics_service = Arc::new(MetricsService::new().unwrap());
@@ -237,6 +243,15 @@ impl StateMachineTester {
             signed_entity_type_lock.clone(),
             slog_scope::logger(),
         ));
+        let kes_expiry_monitor = Arc::new(KesExpiryMonitor::new(
+            KesExpiryThresholds::new(
+                config.kes_expiry_warning_threshold,
+                config.kes_expiry_critical_threshold,
+            )
+            .unwrap(),
+            metrics_service.clone(),
+            slog_scope::logger(),
+        ));
 
         let services = SignerDependencyContainer {
             certificate_handler: certificate_handler.clone(),
@@ -245,6 +260,7 @@ impl StateMachineTester {
             digester: digester.clone(),
             protocol_initializer_store: protocol_initializer_store.clone(),
             single_signer: single_signer.clone(),
+            additional_pools: vec![],
             stake_store: stake_store.clone(),
             era_checker: era_checker.clone(),
             era_reader,
@@ -255,6 +271,7 @@ impl StateMachineTester {
             cardano_transactions_preloader,
             upkeep_service,
             epoch_service,
+            kes_expiry_monitor,
         };
         // set up stake distribution
         chain_observer
@@ -674,6 +691,11 @@ impl StateMachineTester {
             SIGNER_REGISTRATION_TOTAL_SINCE_STARTUP_METRIC_NAME.to_string(),
             Value::Counter(total_signer_registrations_expected as f64),
         );
+        // The fake chain observer is at the start KES period of the operational certificate
+        expected_metrics.insert(
+            KES_PERIODS_BEFORE_EXPIRY_METRIC_NAME.to_string(),
+            Value::Gauge(62.0),
+        );
         self.assert(
             expected_metrics == metrics,
             format!("Metrics service should export expected metrics: given {metrics:?}, expected {expected_metrics:?}"),
@@ -1,7 +1,7 @@
 use std::future::IntoFuture;
 
 use anyhow::{anyhow, Context};
-use axum::Router;
+use axum::{routing::get, Router};
 use futures::stream::StreamExt;
 use signal_hook::consts::*;
 use signal_hook_tokio::Signals;
@@ -64,6 +64,8 @@ impl Application {
 
         trace!("configuring router…");
         let router = Router::new()
+            // The clients request the aggregator features with a trailing slash
+            .route("/aggregator/", get(handlers::aggregator_features))
             .nest("/aggregator", handlers::aggregator_router().await)
             .with_state(shared_state);
         let listener = {
@@ -628,4 +630,29 @@ mod tests {
 
         test(task, PORT).await;
     }
+
+    #[tokio::test]
+    async fn get_aggregator_features() {
+        const PORT: u16 = 3020;
+        let task = tokio::spawn(async move {
+            // Yield back to Tokio's scheduler to ensure the web server is ready before going on.
+            yield_now().await;
+
+            let path = "/";
+            let response = http_request(PORT, path).await;
+
+            APISpec::verify_conformity(
+                get_spec_files(),
+                "GET",
+                path,
+                "application/json",
+                &Null,
+                &response,
+                &StatusCode::OK,
+            )
+            .map_err(|e| anyhow!(e))
+        });
+
+        test(task, PORT).await;
+    }
 }
@@ -23,6 +23,7 @@ use crate::AppError;
 
 pub async fn aggregator_router() -> Router<SharedState> {
     Router::new()
+        .route("/", get(aggregator_features))
         .route("/epoch-settings", get(epoch_settings))
         .route("/artifact/snapshots", get(snapshots))
         .route("/artifact/mithril-stake-distributions", get(msds))
@@ -40,8 +41,12 @@ pub async fn aggregator_router() -> Router<SharedState> {
         .route("/certificates", get(certificates))
         .route("/certificate/:hash", get(certificate))
         .route("/statistics/snapshot", post(statistics))
-        .layer(CorsLayer::permissive())
         .layer(from_fn(set_json_app_header))
+        .route(
+            "/artifact/snapshot/:digest/download",
+            get(snapshot_download),
+        )
+        .layer(CorsLayer::permissive())
         .layer(
             TraceLayer::new_for_http()
                 .make_span_with(
@@ -59,6 +64,30 @@ pub async fn aggregator_router() -> Router<SharedState> {
         )
 }
 
+/// HTTP: Return the Aggregator features.
+///
+/// Proofs are only served for a single transaction hash, so clients must request them one by one.
+pub async fn aggregator_features() -> Result<String, AppError> {
+    let features = serde_json::json!({
+        "open_api_version": "0.0.1",
+        "documentation_url": "https://mithril.network/doc",
+        "capabilities": {
+            "signed_entity_types": [
+                "MithrilStakeDistribution",
+                "CardanoStakeDistribution",
+                "CardanoImmutableFilesFull",
+                "CardanoTransactions"
+            ],
+            "cardano_transactions_prover": {
+                "max_hashes_allowed_by_request": 1,
+                "is_cache_ready": true
+            }
+        }
+    });
+
+    Ok(features.to_string())
+}
+
 /// HTTP: Return the Epoch Settings.
 pub async fn epoch_settings(State(state): State<SharedState>) -> Result<String, AppError> {
     let app_state = state.read().await;
@@ -84,6 +113,23 @@ pub async fn snapshot(
         })
 }
 
+/// HTTP: Return the gzip archive of a snapshot identified by its digest.
+pub async fn snapshot_download(
+    Path(key): Path<String>,
+    State(state): State<SharedState>,
+) -> Result<Response<Body>, AppError> {
+    let app_state = state.read().await;
+
+    app_state
+        .get_snapshot_archive(&key)
+        .await?
+        .map(|archive| ([("Content-Type", "application/gzip")], archive).into_response())
+        .ok_or_else(|| {
+            debug!("snapshot archive digest={key} NOT FOUND.");
+            AppError::NotFound
+        })
+}
+
 /// HTTP: return the list of snapshots.
 pub async fn snapshots(State(state): State<SharedState>) -> Result<String, AppError> {
     let app_state = state.read().await;
@@ -285,6 +331,23 @@ mod tests {
 
     pub use super::*;
 
+    #[tokio::test]
+    async fn aggregator_features_allow_a_single_transaction_hash_by_proof_request() {
+        let response = aggregator_features()
+            .await
+            .expect("The handler was expected to succeed.");
+
+        let features: mithril_common::messages::AggregatorFeaturesMessage =
+            serde_json::from_str(&response).unwrap();
+        assert_eq!(
+            Some(1),
+            features
+                .capabilities
+                .cardano_transactions_prover
+                .map(|capabilities| capabilities.max_hashes_allowed_by_request)
+        );
+    }
+
     #[tokio::test]
     async fn invalid_snapshot_digest() {
         let state: State<SharedState> = State(AppState::default().into());
@@ -309,6 +372,37 @@ mod tests {
         assert_eq!(StatusCode::OK, response.status());
     }
 
+    #[tokio::test]
+    async fn invalid_snapshot_archive_digest() {
+        let state: State<SharedState> = State(AppState::default().into());
+        let digest = Path("whatever".to_string());
+
+        let error = snapshot_download(digest, state).await.expect_err(
+            "The handler was expected to fail since there's no archive for the snapshot's digest.",
+        );
+
+        assert!(matches!(error, AppError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn existing_snapshot_archive_digest() {
+        let state: State<SharedState> = State(AppState::default().into());
+        let digest = default_values::snapshot_archives()
+            .into_keys()
+            .next()
+            .expect("The default data should contain a snapshot archive");
+
+        let response = snapshot_download(Path(digest), state).await.expect(
+            "The handler was expected to succeed since there's an archive for the snapshot's digest.",
+        );
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            "application/gzip",
+            response.headers().get("Content-Type").unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn invalid_msd_hash() {
         let state: State<SharedState> = State(AppState::default().into());
@@ -23,6 +23,7 @@ pub struct AppState {
     ctx_proofs: BTreeMap<String, String>,
     csd_list: String,
     csds: BTreeMap<String, String>,
+    snapshot_archives: BTreeMap<String, Vec<u8>>,
 }
 
 /// Wrapper to access the application state in shared execution.
@@ -50,6 +51,10 @@ impl Default for AppState {
             ctx_proofs: default_values::ctx_proofs(),
             csd_list: default_values::csd_list().to_owned(),
             csds: default_values::csds(),
+            snapshot_archives: default_values::snapshot_archives()
+                .into_iter()
+                .map(|(digest, archive)| (digest, archive.to_vec()))
+                .collect(),
         }
     }
 }
@@ -66,6 +71,7 @@ impl AppState {
         let (ctx_snapshot_list, ctx_snapshots) = reader.read_files("ctx-snapshot")?;
         let (_, ctx_proofs) = reader.read_files("ctx-proof")?;
         let (csd_list, csds) = reader.read_files("cardano-stake-distribution")?;
+        let snapshot_archives = reader.read_snapshot_archives()?;
 
         let instance = Self {
             epoch_settings,
@@ -80,6 +86,7 @@ impl AppState {
             ctx_proofs,
             csd_list,
             csds,
+            snapshot_archives,
         };
 
         Ok(instance)
@@ -110,6 +117,11 @@ impl AppState {
         Ok(self.snapshots.get(key).cloned())
     }
 
+    /// return the gzip archive of the snapshot identified by the given key if any.
+    pub async fn get_snapshot_archive(&self, key: &str) -> StdResult<Option<Vec<u8>>> {
+        Ok(self.snapshot_archives.get(key).cloned())
+    }
+
     /// return the Mithril stake distribution identified by the given key if any.
     pub async fn get_msd(&self, key: &str) -> StdResult<Option<String>> {
         Ok(self.msds.get(key).cloned())
@@ -226,6 +238,40 @@ impl DataDir {
 
         Ok((list, collection))
     }
+
+    /// Read the optional gzip snapshot archives, named `{digest}.tar.gz`, in the
+    /// `snapshot-archives` sub directory.
+    pub fn read_snapshot_archives(&self) -> StdResult<BTreeMap<String, Vec<u8>>> {
+        let archives_dir = self.data_dir.join("snapshot-archives");
+        let mut archives = BTreeMap::new();
+        if !archives_dir.is_dir() {
+            return Ok(archives);
+        }
+
+        for entry in std::fs::read_dir(&archives_dir).with_context(|| {
+            format!(
+                "Error while reading directory '{}'.",
+                archives_dir.display()
+            )
+        })? {
+            let path = entry?.path();
+            let Some(digest) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".tar.gz"))
+                .map(str::to_string)
+            else {
+                continue;
+            };
+
+            trace!("Reading snapshot archive '{}'.", path.display());
+            let archive = std::fs::read(&path)
+                .with_context(|| format!("Error while reading file '{}'.", path.display()))?;
+            archives.insert(digest, archive);
+        }
+
+        Ok(archives)
+    }
 }
 
 #[cfg(test)]
@@ -237,4 +283,15 @@ mod tests {
         AppState::from_directory(Path::new("./default_data"))
             .expect("Should be able to construct an AppState from the default_data");
     }
+
+    #[test]
+    fn appstate_from_default_data_contains_the_default_snapshot_archives() {
+        let state = AppState::from_directory(Path::new("./default_data")).unwrap();
+
+        assert_eq!(
+            AppState::default().snapshot_archives,
+            state.snapshot_archives
+        );
+        assert!(!state.snapshot_archives.is_empty());
+    }
 }
@@ -60,6 +60,10 @@ impl<'a> Spec<'a> {
             "epoch after which the protocol parameters will change".to_string(),
         )
         .await?;
+        // Update the standby aggregator first so that the primary aggregator keeps the leadership
+        if let Some(standby_aggregator) = self.infrastructure.standby_aggregator_mut() {
+            assertions::update_protocol_parameters(standby_aggregator).await?;
+        }
         assertions::update_protocol_parameters(self.infrastructure.aggregator_mut()).await?;
 
         // Wait 6 epochs after protocol parameters update, so that we make sure that we use new protocol parameters as well as new stake distribution a few times
@@ -173,6 +177,36 @@ impl<'a> Spec<'a> {
             }
         }
 
+        // Verify that the standby aggregator takes over the certification when the primary stops
+        if self.infrastructure.has_standby_aggregator() {
+            self.infrastructure.failover_to_standby_aggregator().await?;
+            let aggregator_endpoint = self.infrastructure.aggregator().endpoint();
+
+            target_epoch += 3;
+            assertions::wait_for_target_epoch(
+                self.infrastructure.chain_observer(),
+                target_epoch,
+                "epoch after which the standby aggregator has taken over the certification"
+                    .to_string(),
+            )
+            .await?;
+
+            let hash =
+                assertions::assert_node_producing_mithril_stake_distribution(&aggregator_endpoint)
+                    .await?;
+            assertions::assert_signer_is_signing_mithril_stake_distribution(
+                &aggregator_endpoint,
+                &hash,
+                target_epoch - 1,
+            )
+            .await?;
+            // The certificate chain is verified up to the genesis, so a fork of the chain
+            // created by the failover would be detected here
+            let mut client = self.infrastructure.build_client()?;
+            assertions::assert_client_can_verify_mithril_stake_distribution(&mut client, &hash)
+                .await?;
+        }
+
         Ok(())
     }
 }
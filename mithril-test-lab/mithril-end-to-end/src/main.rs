@@ -96,6 +96,11 @@ pub struct Args {
     #[clap(long, default_value = "true")]
     use_p2p_passive_relays: bool,
 
+    /// Run a standby aggregator sharing the stores of the primary aggregator, and fail over to it
+    /// at the end of the test
+    #[clap(long)]
+    use_high_availability_aggregators: bool,
+
     /// Skip cardano binaries download
     #[clap(long)]
     skip_cardano_bin_download: bool,
@@ -195,6 +200,7 @@ async fn main() -> StdResult<()> {
         run_only_mode,
         use_p2p_network_mode,
         use_p2p_passive_relays,
+        use_high_availability_aggregators: args.use_high_availability_aggregators,
     })
     .await?;
 
@@ -25,12 +25,14 @@ pub struct AggregatorConfig<'a> {
     pub mithril_era_marker_address: &'a str,
     pub signed_entity_types: &'a [String],
     pub chain_observer_type: &'a str,
+    pub high_availability_node_id: Option<&'a str>,
 }
 
 #[derive(Debug)]
 pub struct Aggregator {
     server_port: u64,
     db_directory: PathBuf,
+    log_name: String,
     command: MithrilCommand,
     process: Option<Child>,
 }
@@ -53,7 +55,7 @@ impl Aggregator {
             };
         let signed_entity_types = aggregator_config.signed_entity_types.join(",");
         let mithril_run_interval = format!("{}", aggregator_config.mithril_run_interval);
-        let env = HashMap::from([
+        let mut env = HashMap::from([
             ("NETWORK", "devnet"),
             ("RUN_INTERVAL", &mithril_run_interval),
             ("SERVER_IP", "0.0.0.0"),
@@ -93,23 +95,33 @@ impl Aggregator {
             ),
             ("CARDANO_TRANSACTIONS_SIGNING_CONFIG__STEP", "15"),
         ]);
+        if let Some(node_id) = aggregator_config.high_availability_node_id {
+            env.insert("HIGH_AVAILABILITY_NODE_ID", node_id);
+            env.insert("HIGH_AVAILABILITY_LEADER_LEASE_DURATION", "15000");
+        }
         let args = vec![
             "--db-directory",
             aggregator_config.pool_node.db_path.to_str().unwrap(),
             "-vvv",
         ];
 
-        let command = MithrilCommand::new(
+        let mut command = MithrilCommand::new(
             "mithril-aggregator",
             aggregator_config.work_dir,
             aggregator_config.bin_dir,
             env,
             &args,
         )?;
+        let log_name = match aggregator_config.high_availability_node_id {
+            Some(node_id) => format!("mithril-aggregator-{node_id}"),
+            None => "mithril-aggregator".to_string(),
+        };
+        command.set_log_name(&log_name);
 
         Ok(Self {
             server_port: aggregator_config.server_port,
             db_directory: aggregator_config.pool_node.db_path.clone(),
+            log_name,
             command,
             process: None,
         })
@@ -119,6 +131,7 @@ impl Aggregator {
         Self {
             server_port: other.server_port,
             db_directory: other.db_directory.clone(),
+            log_name: other.log_name.clone(),
             command: other.command.clone(),
             process: None,
         }
@@ -252,6 +265,8 @@ impl Aggregator {
     }
 
     pub async fn tail_logs(&self, number_of_line: u64) -> StdResult<()> {
-        self.command.tail_logs(None, number_of_line).await
+        self.command
+            .tail_logs(Some(&self.log_name), number_of_line)
+            .await
     }
 }
@@ -2,6 +2,7 @@ use crate::{
     assertions, Aggregator, AggregatorConfig, Client, Devnet, PoolNode, RelayAggregator,
     RelayPassive, RelaySigner, Signer, DEVNET_MAGIC_ID,
 };
+use anyhow::anyhow;
 use mithril_common::chain_observer::{ChainObserver, PallasChainObserver};
 use mithril_common::entities::{PartyId, ProtocolParameters, SignedEntityTypeDiscriminants};
 use mithril_common::{CardanoNetwork, StdResult};
@@ -27,6 +28,7 @@ pub struct MithrilInfrastructureConfig {
     pub run_only_mode: bool,
     pub use_p2p_network_mode: bool,
     pub use_p2p_passive_relays: bool,
+    pub use_high_availability_aggregators: bool,
 }
 
 pub struct MithrilInfrastructure {
@@ -34,6 +36,8 @@ pub struct MithrilInfrastructure {
     bin_dir: PathBuf,
     devnet: Devnet,
     aggregator: Aggregator,
+    standby_aggregator: Option<Aggregator>,
+    stopped_aggregators: Vec<Aggregator>,
     signers: Vec<Signer>,
     relay_aggregators: Vec<RelayAggregator>,
     relay_signers: Vec<RelaySigner>,
@@ -57,8 +61,26 @@ impl MithrilInfrastructure {
             .map(|s| s.party_id())
             .collect::<StdResult<Vec<PartyId>>>()?;
 
+        if config.use_high_availability_aggregators && config.use_p2p_network_mode {
+            return Err(anyhow!(
+                "High availability aggregators are not supported in P2P network mode"
+            ));
+        }
+
         let aggregator =
             Self::start_aggregator(config, aggregator_cardano_node, chain_observer_type).await?;
+        let standby_aggregator = if config.use_high_availability_aggregators {
+            Some(
+                Self::start_standby_aggregator(
+                    config,
+                    aggregator_cardano_node,
+                    chain_observer_type,
+                )
+                .await?,
+            )
+        } else {
+            None
+        };
 
         let (relay_aggregators, relay_signers, relay_passives) =
             Self::start_relays(config, aggregator.endpoint(), &signer_party_ids)?;
@@ -80,6 +102,8 @@ impl MithrilInfrastructure {
             bin_dir: config.bin_dir.to_path_buf(),
             devnet: config.devnet.clone(),
             aggregator,
+            standby_aggregator,
+            stopped_aggregators: vec![],
             signers,
             relay_aggregators,
             relay_signers,
@@ -99,13 +123,15 @@ impl MithrilInfrastructure {
         })
     }
 
-    async fn start_aggregator(
+    fn build_aggregator(
         config: &MithrilInfrastructureConfig,
         pool_node: &PoolNode,
         chain_observer_type: &str,
+        server_port: u64,
+        high_availability_node_id: Option<&str>,
     ) -> StdResult<Aggregator> {
         let mut aggregator = Aggregator::new(&AggregatorConfig {
-            server_port: config.server_port,
+            server_port,
             pool_node,
             cardano_cli_path: &config.devnet.cardano_cli_path(),
             work_dir: &config.work_dir,
@@ -117,6 +143,7 @@ impl MithrilInfrastructure {
             mithril_era_marker_address: &config.devnet.mithril_era_marker_address()?,
             signed_entity_types: &config.signed_entity_types,
             chain_observer_type,
+            high_availability_node_id,
         })?;
 
         aggregator.set_protocol_parameters(&ProtocolParameters {
@@ -124,6 +151,26 @@ impl MithrilInfrastructure {
             m: 105,
             phi_f: 0.95,
         });
+
+        Ok(aggregator)
+    }
+
+    async fn start_aggregator(
+        config: &MithrilInfrastructureConfig,
+        pool_node: &PoolNode,
+        chain_observer_type: &str,
+    ) -> StdResult<Aggregator> {
+        let high_availability_node_id = config
+            .use_high_availability_aggregators
+            .then_some("primary");
+        let mut aggregator = Self::build_aggregator(
+            config,
+            pool_node,
+            chain_observer_type,
+            config.server_port,
+            high_availability_node_id,
+        )?;
+
         if config.mithril_era_reader_adapter == "cardano-chain" {
             assertions::register_era_marker(&mut aggregator, &config.devnet, &config.mithril_era)
                 .await?;
@@ -134,6 +181,28 @@ impl MithrilInfrastructure {
         Ok(aggregator)
     }
 
+    /// Start a standby aggregator sharing the stores of the primary aggregator, it takes over the
+    /// certification if the primary aggregator stops refreshing its leadership.
+    async fn start_standby_aggregator(
+        config: &MithrilInfrastructureConfig,
+        pool_node: &PoolNode,
+        chain_observer_type: &str,
+    ) -> StdResult<Aggregator> {
+        info!("Starting a standby aggregator for high availability");
+        // Let the primary aggregator acquire the leadership first
+        sleep(Duration::from_secs(5)).await;
+        let mut aggregator = Self::build_aggregator(
+            config,
+            pool_node,
+            chain_observer_type,
+            config.server_port + 1,
+            Some("standby"),
+        )?;
+        aggregator.serve()?;
+
+        Ok(aggregator)
+    }
+
     fn start_relays(
         config: &MithrilInfrastructureConfig,
         aggregator_endpoint: String,
@@ -255,6 +324,37 @@ impl MithrilInfrastructure {
         self.aggregator.borrow_mut()
     }
 
+    pub fn standby_aggregator_mut(&mut self) -> Option<&mut Aggregator> {
+        self.standby_aggregator.as_mut()
+    }
+
+    pub fn has_standby_aggregator(&self) -> bool {
+        self.standby_aggregator.is_some()
+    }
+
+    /// Stop the primary aggregator and make the standby aggregator the one used by the signers
+    /// and the clients.
+    pub async fn failover_to_standby_aggregator(&mut self) -> StdResult<()> {
+        let standby_aggregator = self
+            .standby_aggregator
+            .take()
+            .ok_or_else(|| anyhow!("No standby aggregator to fail over to"))?;
+
+        info!("Failing over to the standby aggregator");
+        self.aggregator.stop().await?;
+        let previous_aggregator = std::mem::replace(&mut self.aggregator, standby_aggregator);
+        self.stopped_aggregators.push(previous_aggregator);
+
+        let aggregator_endpoint = self.aggregator.endpoint();
+        for signer in self.signers.iter_mut() {
+            signer.stop().await?;
+            signer.set_aggregator_endpoint(&aggregator_endpoint);
+            signer.start()?;
+        }
+
+        Ok(())
+    }
+
     pub fn signers(&self) -> &[Signer] {
         &self.signers
     }
@@ -297,6 +397,12 @@ impl MithrilInfrastructure {
 
     pub async fn tail_logs(&self, number_of_line: u64) -> StdResult<()> {
         self.aggregator().tail_logs(number_of_line).await?;
+        if let Some(standby_aggregator) = &self.standby_aggregator {
+            standby_aggregator.tail_logs(number_of_line).await?;
+        }
+        for stopped_aggregator in &self.stopped_aggregators {
+            stopped_aggregator.tail_logs(number_of_line).await?;
+        }
         for signer in self.signers() {
             signer.tail_logs(number_of_line).await?;
         }
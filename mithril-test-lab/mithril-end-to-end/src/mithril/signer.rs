@@ -1,6 +1,7 @@
 use crate::devnet::PoolNode;
 use crate::utils::MithrilCommand;
 use crate::{DEVNET_MAGIC_ID, ERA_MARKERS_VERIFICATION_KEY};
+use anyhow::Context;
 use mithril_common::entities::PartyId;
 use mithril_common::StdResult;
 use std::collections::HashMap;
@@ -115,6 +116,21 @@ impl Signer {
         Ok(())
     }
 
+    pub async fn stop(&mut self) -> StdResult<()> {
+        if let Some(process) = self.process.as_mut() {
+            process
+                .kill()
+                .await
+                .with_context(|| format!("Could not kill signer {}", self.party_id))?;
+        }
+        Ok(())
+    }
+
+    pub fn set_aggregator_endpoint(&mut self, aggregator_endpoint: &str) {
+        self.command
+            .set_env_var("AGGREGATOR_ENDPOINT", aggregator_endpoint);
+    }
+
     pub async fn tail_logs(&self, number_of_line: u64) -> StdResult<()> {
         self.command
             .tail_logs(
@@ -31,6 +31,7 @@ pub async fn bootstrap_aggregator(
         mithril_era_reader_adapter: "dummy",
         signed_entity_types: &signed_entity_types,
         chain_observer_type,
+        high_availability_node_id: None,
     })
     .unwrap();
 